@@ -735,6 +735,7 @@ impl JsDataFrame {
                 SortOptions {
                     descending: reverse,
                     nulls_last,
+                    ..Default::default()
                 },
             )
             .map_err(JsPolarsErr::from)?;
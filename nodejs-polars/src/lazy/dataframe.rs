@@ -137,6 +137,7 @@ impl JsLazyFrame {
             SortOptions {
                 descending: reverse,
                 nulls_last,
+                ..Default::default()
             },
         )
         .into()
@@ -149,8 +150,13 @@ impl JsLazyFrame {
         nulls_last: bool,
     ) -> JsLazyFrame {
         let ldf = self.ldf.clone();
-        ldf.sort_by_exprs(by_column.to_exprs(), reverse, nulls_last)
-            .into()
+        ldf.sort_by_exprs(
+            by_column.to_exprs(),
+            reverse,
+            nulls_last,
+            Default::default(),
+        )
+        .into()
     }
     #[napi]
     pub fn cache(&self) -> JsLazyFrame {
@@ -300,6 +300,7 @@ impl JsExpr {
             .sort_with(SortOptions {
                 descending,
                 nulls_last,
+                ..Default::default()
             })
             .into()
     }
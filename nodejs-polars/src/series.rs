@@ -443,6 +443,7 @@ impl JsSeries {
             .argsort(SortOptions {
                 descending: reverse,
                 nulls_last,
+                ..Default::default()
             })
             .into_series()
             .into()
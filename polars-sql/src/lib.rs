@@ -58,6 +58,7 @@ mod test {
                 SortOptions {
                     descending: false,
                     nulls_last: false,
+                    ..Default::default()
                 },
             )
             .collect()?;
@@ -75,6 +76,7 @@ mod test {
                 SortOptions {
                     descending: false,
                     nulls_last: false,
+                    ..Default::default()
                 },
             )
             .collect()?;
@@ -7,6 +7,7 @@ pub mod float;
 pub mod list;
 pub mod rolling;
 pub mod set;
+pub mod simd;
 pub mod sort_partition;
 #[cfg(feature = "performant")]
 pub mod sorted_join;
@@ -0,0 +1,301 @@
+//! Kernels on the values of arrays without nulls that are compiled for multiple CPU targets.
+//!
+//! The kernels are written as plain loops over fixed size lanes, which the compiler vectorizes.
+//! Every kernel is compiled once for the target of the build and once with AVX2 enabled; the
+//! AVX2 version is selected at runtime if the CPU supports it. This way release builds for a
+//! generic `x86_64` target (which only may assume SSE2) still use the 256 bit registers.
+use crate::bit_util::ceil;
+use arrow::bitmap::Bitmap;
+use arrow::types::NativeType;
+use std::ops::Add;
+
+// number of independent accumulators of the reductions
+const LANES: usize = 32;
+
+/// Check if the AVX2 versions of the kernels are used.
+#[inline]
+pub fn using_avx2() -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        std::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        false
+    }
+}
+
+/// Compile a generic kernel for the build target and for AVX2, and dispatch at runtime.
+macro_rules! multiversion {
+    (
+        $(#[$meta:meta])*
+        pub fn $name:ident<$($T:ident: $bound:path),*>($($arg:ident: $arg_ty:ty),*) -> $ret:ty
+        => $kernel:ident
+    ) => {
+        $(#[$meta])*
+        pub fn $name<$($T: $bound),*>($($arg: $arg_ty),*) -> $ret {
+            #[cfg(target_arch = "x86_64")]
+            #[target_feature(enable = "avx2")]
+            unsafe fn avx2<$($T: $bound),*>($($arg: $arg_ty),*) -> $ret {
+                $kernel($($arg),*)
+            }
+
+            #[cfg(target_arch = "x86_64")]
+            if using_avx2() {
+                // Safety: the CPU supports AVX2
+                return unsafe { avx2($($arg),*) };
+            }
+            $kernel($($arg),*)
+        }
+    };
+}
+
+pub trait SumNative: NativeType + Add<Output = Self> {}
+impl<T: NativeType + Add<Output = T>> SumNative for T {}
+
+pub trait OrdNative: NativeType + PartialOrd {}
+impl<T: NativeType + PartialOrd> OrdNative for T {}
+
+#[inline(always)]
+fn sum_kernel<T: SumNative>(values: &[T]) -> T {
+    let mut acc = [T::default(); LANES];
+    let chunks = values.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        for (acc, v) in acc.iter_mut().zip(chunk) {
+            *acc = *acc + *v;
+        }
+    }
+    let mut sum = T::default();
+    for v in acc.iter().chain(remainder) {
+        sum = sum + *v
+    }
+    sum
+}
+
+#[inline(always)]
+fn reduce_kernel<T: OrdNative>(values: &[T], take_rhs: impl Fn(T, T) -> bool) -> Option<T> {
+    if values.len() < LANES {
+        return values
+            .iter()
+            .copied()
+            .reduce(|acc, v| if take_rhs(acc, v) { v } else { acc });
+    }
+    let (first, rest) = values.split_at(LANES);
+    let mut acc = [T::default(); LANES];
+    acc.copy_from_slice(first);
+    let chunks = rest.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        for (acc, v) in acc.iter_mut().zip(chunk) {
+            if take_rhs(*acc, *v) {
+                *acc = *v
+            }
+        }
+    }
+    acc.iter()
+        .chain(remainder)
+        .copied()
+        .reduce(|acc, v| if take_rhs(acc, v) { v } else { acc })
+}
+
+// only NaN is not equal to itself
+#[inline(always)]
+#[allow(clippy::eq_op)]
+fn is_nan<T: OrdNative>(v: T) -> bool {
+    v != v
+}
+
+#[inline(always)]
+fn min_kernel<T: OrdNative>(values: &[T]) -> Option<T> {
+    reduce_kernel(values, |acc, v| v < acc || is_nan(acc))
+}
+
+#[inline(always)]
+fn max_kernel<T: OrdNative>(values: &[T]) -> Option<T> {
+    reduce_kernel(values, |acc, v| v > acc || is_nan(acc))
+}
+
+multiversion! {
+    /// Sum the values; an empty slice sums to the default (zero) value.
+    pub fn sum_slice<T: SumNative>(values: &[T]) -> T => sum_kernel
+}
+
+multiversion! {
+    /// The minimum of the values, `None` if the slice is empty.
+    /// A NaN is only returned if the values contain nothing but NaN.
+    pub fn min_slice<T: OrdNative>(values: &[T]) -> Option<T> => min_kernel
+}
+
+multiversion! {
+    /// The maximum of the values, `None` if the slice is empty.
+    /// A NaN is only returned if the values contain nothing but NaN.
+    pub fn max_slice<T: OrdNative>(values: &[T]) -> Option<T> => max_kernel
+}
+
+#[inline(always)]
+fn compare_scalar_kernel<T: NativeType>(values: &[T], rhs: T, op: impl Fn(T, T) -> bool) -> Bitmap {
+    let mut bytes = Vec::with_capacity(ceil(values.len(), 8) + 8);
+    let chunks = values.chunks_exact(64);
+    let remainder = chunks.remainder();
+    let to_bits = |chunk: &[T]| {
+        chunk
+            .iter()
+            .enumerate()
+            .fold(0u64, |bits, (i, v)| bits | ((op(*v, rhs) as u64) << i))
+    };
+    for chunk in chunks {
+        bytes.extend_from_slice(&to_bits(chunk).to_le_bytes());
+    }
+    if !remainder.is_empty() {
+        let n_bytes = ceil(remainder.len(), 8);
+        bytes.extend_from_slice(&to_bits(remainder).to_le_bytes()[..n_bytes]);
+    }
+    Bitmap::from_u8_vec(bytes, values.len())
+}
+
+macro_rules! compare_scalar {
+    ($(#[$meta:meta])* $name:ident, $kernel:ident, $bound:path, $op:expr) => {
+        #[inline(always)]
+        fn $kernel<T: $bound>(values: &[T], rhs: T) -> Bitmap {
+            compare_scalar_kernel(values, rhs, $op)
+        }
+
+        multiversion! {
+            $(#[$meta])*
+            pub fn $name<T: $bound>(values: &[T], rhs: T) -> Bitmap => $kernel
+        }
+    };
+}
+
+compare_scalar!(
+    /// A bitmap that is set where the values equal `rhs`.
+    eq_scalar, eq_scalar_kernel, OrdNative, |l, r| l == r
+);
+compare_scalar!(
+    /// A bitmap that is set where the values differ from `rhs`.
+    neq_scalar, neq_scalar_kernel, OrdNative, |l, r| l != r
+);
+compare_scalar!(
+    /// A bitmap that is set where the values are greater than `rhs`.
+    gt_scalar, gt_scalar_kernel, OrdNative, |l, r| l > r
+);
+compare_scalar!(
+    /// A bitmap that is set where the values are greater than or equal to `rhs`.
+    gt_eq_scalar, gt_eq_scalar_kernel, OrdNative, |l, r| l >= r
+);
+compare_scalar!(
+    /// A bitmap that is set where the values are less than `rhs`.
+    lt_scalar, lt_scalar_kernel, OrdNative, |l, r| l < r
+);
+compare_scalar!(
+    /// A bitmap that is set where the values are less than or equal to `rhs`.
+    lt_eq_scalar, lt_eq_scalar_kernel, OrdNative, |l, r| l <= r
+);
+
+#[inline(always)]
+fn filter_kernel<T: NativeType>(values: &[T], mask: &Bitmap) -> Vec<T> {
+    assert_eq!(values.len(), mask.len());
+    let selected = mask.len() - mask.unset_bits();
+    // every value is written at the end of the output, which only grows if the value
+    // is selected; so there must be room for one more value.
+    let mut out = Vec::<T>::with_capacity(selected + 1);
+    let dst = out.as_mut_ptr();
+    let mut len = 0;
+
+    let mut chunks = mask.chunks::<u64>();
+    let mut offset = 0;
+    for bits in chunks.by_ref() {
+        let chunk = &values[offset..offset + 64];
+        match bits {
+            0 => {}
+            u64::MAX => {
+                // Safety: the 64 values are selected, so they fit in the output
+                unsafe { std::ptr::copy_nonoverlapping(chunk.as_ptr(), dst.add(len), 64) };
+                len += 64;
+            }
+            _ => {
+                for (i, v) in chunk.iter().enumerate() {
+                    // Safety: `len` never exceeds the number of selected values
+                    unsafe { dst.add(len).write(*v) };
+                    len += ((bits >> i) & 1) as usize;
+                }
+            }
+        }
+        offset += 64;
+    }
+    let bits = chunks.remainder();
+    for (i, v) in values[offset..].iter().enumerate() {
+        // Safety: see above
+        unsafe { dst.add(len).write(*v) };
+        len += ((bits >> i) & 1) as usize;
+    }
+    debug_assert_eq!(len, selected);
+    // Safety: the first `len` values are initialized
+    unsafe { out.set_len(len) };
+    out
+}
+
+multiversion! {
+    /// Take the values where the mask is set.
+    ///
+    /// # Panics
+    /// Panics if the lengths of the values and the mask differ.
+    pub fn filter_values<T: NativeType>(values: &[T], mask: &Bitmap) -> Vec<T> => filter_kernel
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_simd_kernels() {
+        let values = (0..1000).map(|v| (v * 7919) % 1000).collect::<Vec<i32>>();
+        assert_eq!(sum_slice(&values), values.iter().sum::<i32>());
+        assert_eq!(min_slice(&values), Some(0));
+        assert_eq!(max_slice(&values), Some(999));
+        assert_eq!(min_slice(&values[..5]), values[..5].iter().min().copied());
+        assert_eq!(min_slice::<i32>(&[]), None);
+        assert_eq!(sum_slice::<i32>(&[]), 0);
+
+        let floats = (0..100).map(|v| v as f64).collect::<Vec<_>>();
+        let mut with_nan = floats.clone();
+        with_nan[0] = f64::NAN;
+        with_nan[40] = f64::NAN;
+        assert_eq!(max_slice(&with_nan), Some(99.0));
+        assert_eq!(min_slice(&with_nan), Some(1.0));
+        assert!(max_slice(&[f64::NAN]).unwrap().is_nan());
+
+        // a length that is not a multiple of 64 and a mask with an offset
+        let values = &values[..997];
+        let mask = gt_scalar(values, 500);
+        assert_eq!(mask.len(), 997);
+        for (i, v) in values.iter().enumerate() {
+            assert_eq!(mask.get_bit(i), *v > 500);
+        }
+        assert_eq!(
+            lt_eq_scalar(values, 500).unset_bits(),
+            997 - mask.unset_bits()
+        );
+        let filtered = filter_values(values, &mask);
+        let expected = values
+            .iter()
+            .copied()
+            .filter(|v| *v > 500)
+            .collect::<Vec<_>>();
+        assert_eq!(filtered, expected);
+
+        let sliced_mask = mask.clone().slice(3, 900);
+        let filtered = filter_values(&values[3..903], &sliced_mask);
+        let expected = values[3..903]
+            .iter()
+            .copied()
+            .filter(|v| *v > 500)
+            .collect::<Vec<_>>();
+        assert_eq!(filtered, expected);
+
+        let all = eq_scalar(&[1u8; 130], 1);
+        assert_eq!(filter_values(&[2u8; 130], &all), vec![2u8; 130]);
+        assert!(filter_values(&[2u8; 130], &neq_scalar(&[1u8; 130], 1)).is_empty());
+    }
+}
@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A handle to abort a running query, passed to `LazyFrame::collect_with_cancellation`.
+///
+/// The token is checked before and after every node of the physical plan and between the
+/// chunks of the readers and the partitions of groupbys, so a cancelled query stops soon
+/// and returns a [`PolarsError::Cancelled`]. Clones share the cancellation, so a clone can
+/// be moved to the thread that cancels.
+#[derive(Clone, Default, Debug)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the queries that run with this token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Error if this token is cancelled.
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(PolarsError::Cancelled("the query was cancelled".into()))
+        } else {
+            Ok(())
+        }
+    }
+}
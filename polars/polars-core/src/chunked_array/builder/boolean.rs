@@ -44,4 +44,17 @@ impl BooleanChunkedBuilder {
             field: Field::new(name, DataType::Boolean),
         }
     }
+
+    /// Discard the appended values and reserve space for `capacity` values,
+    /// so that the builder can be reused.
+    pub fn reset(&mut self, capacity: usize) {
+        *self = Self::new(self.field.name(), capacity);
+    }
+
+    /// Finish the current array and reset the builder, so that it can be reused
+    /// to build a new array with the same name and capacity.
+    pub fn finish_reset(&mut self) -> BooleanChunked {
+        let new = Self::new(self.field.name(), self.array_builder.len());
+        std::mem::replace(self, new).finish()
+    }
 }
@@ -73,9 +73,10 @@ where
         name: &str,
         it: impl Iterator<Item = Option<T::Native>>,
     ) -> ChunkedArray<T> {
-        let mut builder = PrimitiveChunkedBuilder::new(name, get_iter_capacity(&it));
-        it.for_each(|opt| builder.append_option(opt));
-        builder.finish()
+        // the `FromIterator` implementation has faster paths than the builder
+        let mut ca: ChunkedArray<T> = it.collect();
+        ca.rename(name);
+        ca
     }
 
     /// Create a new ChunkedArray from an iterator.
@@ -100,9 +101,9 @@ impl NewChunkedArray<BooleanType, bool> for BooleanChunked {
         name: &str,
         it: impl Iterator<Item = Option<bool>>,
     ) -> ChunkedArray<BooleanType> {
-        let mut builder = BooleanChunkedBuilder::new(name, get_iter_capacity(&it));
-        it.for_each(|opt| builder.append_option(opt));
-        builder.finish()
+        let mut ca: ChunkedArray<_> = it.collect();
+        ca.rename(name);
+        ca
     }
 
     /// Create a new ChunkedArray from an iterator.
@@ -170,6 +171,42 @@ mod test {
         assert_eq!(Vec::from(&ca), values);
     }
 
+    #[test]
+    fn test_builder_reuse() {
+        let mut builder = PrimitiveChunkedBuilder::<Int32Type>::new("foo", 4);
+        builder.append_slice(&[1, 2]);
+        builder.append_null();
+        let ca = builder.finish_reset();
+        assert_eq!(Vec::from(&ca), &[Some(1), Some(2), None]);
+
+        builder.append_value(3);
+        let ca = builder.finish_reset();
+        assert_eq!(ca.name(), "foo");
+        assert_eq!(Vec::from(&ca), &[Some(3)]);
+
+        builder.append_value(4);
+        builder.reset(2);
+        assert_eq!(builder.finish().len(), 0);
+
+        let mut builder = Utf8ChunkedBuilder::new("bar", 2, 2);
+        builder.append_value("a");
+        assert_eq!(Vec::from(&builder.finish_reset()), &[Some("a")]);
+        builder.append_null();
+        assert_eq!(Vec::from(&builder.finish_reset()), &[None]);
+
+        let ca = Int32Chunked::from_iter_options("baz", [Some(1), None].into_iter());
+        assert_eq!(ca.name(), "baz");
+        assert_eq!(Vec::from(&ca), &[Some(1), None]);
+
+        // a validity without nulls is dropped
+        let ca = Int32Chunked::from_iter_options("baz", [Some(1), Some(2)].into_iter());
+        assert!(ca.downcast_iter().next().unwrap().validity().is_none());
+        let ca = BooleanChunked::from_iter_options("baz", [Some(true), None].into_iter());
+        assert_eq!(Vec::from(&ca), &[Some(true), None]);
+        let ca = BooleanChunked::from_iter_options("baz", [Some(true)].into_iter());
+        assert!(ca.downcast_iter().next().unwrap().validity().is_none());
+    }
+
     #[test]
     fn test_list_builder() {
         let mut builder =
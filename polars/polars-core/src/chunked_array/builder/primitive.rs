@@ -55,4 +55,26 @@ where
             field: Field::new(name, T::get_dtype()),
         }
     }
+
+    /// Appends a slice of non-null values into the builder.
+    #[inline]
+    pub fn append_slice(&mut self, values: &[T::Native]) {
+        self.array_builder.extend_from_slice(values)
+    }
+
+    /// Discard the appended values and reserve space for `capacity` values,
+    /// so that the builder can be reused.
+    pub fn reset(&mut self, capacity: usize) {
+        *self = Self::new(self.field.name(), capacity);
+    }
+
+    /// Finish the current array and reset the builder, so that it can be reused
+    /// to build a new array with the same name and capacity.
+    ///
+    /// The finished array owns the buffers, so they cannot be reused. Instead the next
+    /// array is allocated once with the length of the finished array, rather than growing.
+    pub fn finish_reset(&mut self) -> ChunkedArray<T> {
+        let new = Self::new(self.field.name(), self.array_builder.len());
+        std::mem::replace(self, new).finish()
+    }
 }
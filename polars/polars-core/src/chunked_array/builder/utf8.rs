@@ -55,6 +55,23 @@ impl Utf8ChunkedBuilder {
     fn shrink_to_fit(&mut self) {
         self.builder.shrink_to_fit()
     }
+
+    /// Discard the appended values and reserve space for `capacity` values of
+    /// `bytes_capacity` bytes in total, so that the builder can be reused.
+    pub fn reset(&mut self, capacity: usize, bytes_capacity: usize) {
+        *self = Self::new(self.field.name(), capacity, bytes_capacity);
+    }
+
+    /// Finish the current array and reset the builder, so that it can be reused
+    /// to build a new array with the same name and capacities.
+    pub fn finish_reset(&mut self) -> Utf8Chunked {
+        let new = Self::new(
+            self.field.name(),
+            self.builder.len(),
+            self.builder.values().len(),
+        );
+        std::mem::replace(self, new).finish()
+    }
 }
 
 pub struct Utf8ChunkedBuilderCow {
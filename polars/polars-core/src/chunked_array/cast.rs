@@ -137,22 +137,69 @@ impl ChunkCast for BooleanChunked {
     }
 }
 
+fn list_depth(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::List(inner) => 1 + list_depth(inner),
+        _ => 0,
+    }
+}
+
+fn arrow_list_depth(data_type: &ArrowDataType) -> usize {
+    match data_type {
+        ArrowDataType::LargeList(field) => 1 + arrow_list_depth(field.data_type()),
+        _ => 0,
+    }
+}
+
 fn cast_inner_list_type(list: &ListArray<i64>, child_type: &DataType) -> Result<ArrayRef> {
-    let child = list.values();
-    let offsets = list.offsets();
-    let child = cast::cast(child.as_ref(), &child_type.to_arrow())?;
+    let mut child = list.values().clone();
+    let mut offsets = list.offsets().clone();
 
-    let data_type = ListArray::<i64>::default_datatype(child_type.to_arrow());
-    // Safety:
-    // offsets are correct as they have not changed
-    let list = unsafe {
-        ListArray::new_unchecked(data_type, offsets.clone(), child, list.validity().cloned())
+    // casting to a lower nesting level concatenates the sublists of every list,
+    // once for every level that is removed
+    let target_depth = list_depth(child_type);
+    while arrow_list_depth(child.data_type()) > target_depth {
+        let inner = child.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+        let inner_offsets = inner.offsets().as_slice();
+        offsets = offsets
+            .iter()
+            .map(|o| inner_offsets[*o as usize])
+            .collect::<Vec<_>>()
+            .into();
+        let values = inner.values().clone();
+        child = values;
+    }
+
+    let child = match (child.data_type(), child_type) {
+        (ArrowDataType::LargeList(_), DataType::List(inner_type)) => {
+            let child = child.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            cast_inner_list_type(child, inner_type)?
+        }
+        // casting to a higher nesting level wraps every value in a list of length 1
+        (_, DataType::List(inner_type)) => {
+            let values = ListArray::<i64>::try_new(
+                ListArray::<i64>::default_datatype(child.data_type().clone()),
+                (0..=child.len() as i64).collect::<Vec<_>>().into(),
+                child.clone(),
+                None,
+            )?;
+            cast_inner_list_type(&values, inner_type)?
+        }
+        _ => cast::cast(child.as_ref(), &child_type.to_physical().to_arrow())?,
     };
+
+    let data_type = ListArray::<i64>::default_datatype(child.data_type().clone());
+    // Safety:
+    // the offsets point into the (possibly concatenated) child values
+    let list =
+        unsafe { ListArray::new_unchecked(data_type, offsets, child, list.validity().cloned()) };
     Ok(Box::new(list) as ArrayRef)
 }
 
 /// We cannot cast anything to or from List/LargeList
-/// So this implementation casts the inner type
+/// So this implementation casts the inner type.
+/// Casting between nesting levels wraps the values in lists of length 1 (e.g. `List(Int32)` to
+/// `List(List(Int32))`), or concatenates the sublists (e.g. `List(List(Int32))` to `List(Int32)`).
 impl ChunkCast for ListChunked {
     fn cast(&self, data_type: &DataType) -> Result<Series> {
         match data_type {
@@ -196,6 +243,57 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_cast_nesting_levels() -> Result<()> {
+        let a = Series::new("", &[1i32, 2]);
+        let b = Series::new("", &[3i32]);
+        let flat = Series::new("a", &[a.clone(), b.clone()]);
+        let nested_dtype = DataType::List(Box::new(DataType::List(Box::new(DataType::Float64))));
+
+        let nested = flat.cast(&nested_dtype)?;
+        assert_eq!(nested.dtype(), &nested_dtype);
+        let first = nested.list()?.get(0).unwrap();
+        assert_eq!(first.len(), 2);
+        assert_eq!(
+            Vec::from(first.list()?.get(1).unwrap().f64()?),
+            &[Some(2.0)]
+        );
+
+        // back to a single level concatenates the sublists
+        let nested = Series::new("a", &[flat.clone(), flat.slice(1, 1)]);
+        let out = nested
+            .slice(1, 1)
+            .cast(&DataType::List(Box::new(DataType::Int64)))?;
+        assert_eq!(Vec::from(out.list()?.get(0).unwrap().i64()?), &[Some(3)]);
+        let out = nested.cast(&DataType::List(Box::new(DataType::Int32)))?;
+        assert_eq!(
+            Vec::from(out.list()?.get(0).unwrap().i32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+
+        // removing a single level keeps the lists of the level below
+        // [[[1], [2]], [[3]]] -> [[1], [2], [3]]
+        let s = |v: i32| Series::new("", &[v]);
+        let deep = Series::new(
+            "a",
+            &[Series::new(
+                "",
+                &[Series::new("", &[s(1), s(2)]), Series::new("", &[s(3)])],
+            )],
+        );
+        let out = deep.cast(&DataType::List(Box::new(DataType::List(Box::new(
+            DataType::Int32,
+        )))))?;
+        assert_eq!(out.len(), 1);
+        let out = out.list()?.get(0).unwrap();
+        assert_eq!(out.len(), 3);
+        let out = out.list()?;
+        for (i, v) in [1, 2, 3].into_iter().enumerate() {
+            assert_eq!(Vec::from(out.get(i).unwrap().i32()?), &[Some(v)]);
+        }
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "dtype-categorical")]
     fn test_cast_noop() {
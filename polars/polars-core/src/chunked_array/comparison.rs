@@ -1,5 +1,6 @@
 use crate::utils::align_chunks_binary;
 use crate::{prelude::*, utils::NoNull};
+use arrow::bitmap::Bitmap;
 use arrow::scalar::{PrimitiveScalar, Scalar, Utf8Scalar};
 use arrow::{
     array::{BooleanArray, PrimitiveArray, Utf8Array},
@@ -7,6 +8,7 @@ use arrow::{
     compute::comparison,
 };
 use num::{NumCast, ToPrimitive};
+use polars_arrow::kernels::simd;
 use polars_arrow::prelude::FromData;
 use std::ops::Not;
 
@@ -644,11 +646,18 @@ where
         &self,
         rhs: Rhs,
         f: impl Fn(&PrimitiveArray<T::Native>, &dyn Scalar) -> BooleanArray,
+        no_nulls: impl Fn(&[T::Native], T::Native) -> Bitmap,
     ) -> BooleanChunked {
         let rhs: T::Native =
             NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
         let scalar = PrimitiveScalar::new(T::get_dtype().to_arrow(), Some(rhs));
-        self.apply_kernel_cast(&|arr| Box::new(f(arr, &scalar)))
+        self.apply_kernel_cast(&|arr| match arr.null_count() {
+            0 => Box::new(BooleanArray::from_data_default(
+                no_nulls(arr.values(), rhs),
+                None,
+            )),
+            _ => Box::new(f(arr, &scalar)),
+        })
     }
 }
 
@@ -663,27 +672,43 @@ where
     }
 
     fn equal(&self, rhs: Rhs) -> BooleanChunked {
-        self.primitive_compare_scalar(rhs, |l, rhs| comparison::eq_scalar_and_validity(l, rhs))
+        self.primitive_compare_scalar(
+            rhs,
+            |l, rhs| comparison::eq_scalar_and_validity(l, rhs),
+            simd::eq_scalar,
+        )
     }
 
     fn not_equal(&self, rhs: Rhs) -> BooleanChunked {
-        self.primitive_compare_scalar(rhs, |l, rhs| comparison::neq_scalar_and_validity(l, rhs))
+        self.primitive_compare_scalar(
+            rhs,
+            |l, rhs| comparison::neq_scalar_and_validity(l, rhs),
+            simd::neq_scalar,
+        )
     }
 
     fn gt(&self, rhs: Rhs) -> BooleanChunked {
-        self.primitive_compare_scalar(rhs, |l, rhs| comparison::gt_scalar(l, rhs))
+        self.primitive_compare_scalar(rhs, |l, rhs| comparison::gt_scalar(l, rhs), simd::gt_scalar)
     }
 
     fn gt_eq(&self, rhs: Rhs) -> BooleanChunked {
-        self.primitive_compare_scalar(rhs, |l, rhs| comparison::gt_eq_scalar(l, rhs))
+        self.primitive_compare_scalar(
+            rhs,
+            |l, rhs| comparison::gt_eq_scalar(l, rhs),
+            simd::gt_eq_scalar,
+        )
     }
 
     fn lt(&self, rhs: Rhs) -> BooleanChunked {
-        self.primitive_compare_scalar(rhs, |l, rhs| comparison::lt_scalar(l, rhs))
+        self.primitive_compare_scalar(rhs, |l, rhs| comparison::lt_scalar(l, rhs), simd::lt_scalar)
     }
 
     fn lt_eq(&self, rhs: Rhs) -> BooleanChunked {
-        self.primitive_compare_scalar(rhs, |l, rhs| comparison::lt_eq_scalar(l, rhs))
+        self.primitive_compare_scalar(
+            rhs,
+            |l, rhs| comparison::lt_eq_scalar(l, rhs),
+            simd::lt_eq_scalar,
+        )
     }
 }
 
@@ -30,4 +30,17 @@ where
             .collect();
         ChunkedArray::from_chunks(self.name(), chunks)
     }
+
+    #[must_use]
+    /// Replace every `NaN` value by the same `NaN`, so that all `NaN` values have the same
+    /// bit representation and hash equal.
+    pub fn canonicalize_nans(&self) -> Self {
+        self.apply(|v| {
+            if Float::is_nan(v) {
+                T::Native::nan()
+            } else {
+                v
+            }
+        })
+    }
 }
@@ -0,0 +1,269 @@
+//! Building blocks to write vectorized operations on [`ChunkedArray`]s.
+//!
+//! The functions in this module take care of the chunk alignment, the validity (null) masks and
+//! the broadcasting of unit length arrays, so that a custom operation only has to define what
+//! happens to the values.
+//!
+//! The existing kernels are available through the chunked array traits:
+//! comparisons via [`ChunkCompare`], arithmetic via the `std::ops` traits,
+//! take via [`ChunkTake`] and filter via [`ChunkFilter`]. The arrow level kernels that they
+//! use are re-exported in [`crate::kernels`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # use polars_core::prelude::*;
+//! use polars_core::kernels::arity::binary_elementwise_values;
+//!
+//! let a = Int32Chunked::new("a", &[Some(1), None, Some(3)]);
+//! let b = Int32Chunked::new("b", &[Some(4), Some(5), Some(6)]);
+//! let out: Float64Chunked =
+//!     binary_elementwise_values(&a, &b, |a, b| (a as f64).hypot(b as f64)).unwrap();
+//! assert_eq!(out.null_count(), 1);
+//! ```
+use crate::chunked_array::to_array;
+use crate::prelude::*;
+use crate::utils::{align_chunks_binary, combine_validities, CustomIterTools};
+use arrow::array::BooleanArray;
+use arrow::bitmap::Bitmap;
+use polars_arrow::prelude::FromData;
+use std::borrow::Cow;
+
+/// Broadcast unit length arrays to the length of the other array.
+#[allow(clippy::type_complexity)]
+fn broadcast<'a, T, U>(
+    lhs: &'a ChunkedArray<T>,
+    rhs: &'a ChunkedArray<U>,
+) -> Result<(Cow<'a, ChunkedArray<T>>, Cow<'a, ChunkedArray<U>>)>
+where
+    T: PolarsNumericType,
+    U: PolarsNumericType,
+{
+    match (lhs.len(), rhs.len()) {
+        (l, r) if l == r => Ok((Cow::Borrowed(lhs), Cow::Borrowed(rhs))),
+        (l, 1) => Ok((Cow::Borrowed(lhs), Cow::Owned(rhs.expand_at_index(0, l)))),
+        (1, r) => Ok((Cow::Owned(lhs.expand_at_index(0, r)), Cow::Borrowed(rhs))),
+        (l, r) => Err(PolarsError::ShapeMisMatch(
+            format!(
+                "cannot apply a binary operation on arrays of different lengths: {} and {}",
+                l, r
+            )
+            .into(),
+        )),
+    }
+}
+
+/// Apply `op` to the values of every chunk of two aligned arrays.
+fn binary_chunks<T, U, V, F>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<U>,
+    mut op: F,
+) -> Result<ChunkedArray<V>>
+where
+    T: PolarsNumericType,
+    U: PolarsNumericType,
+    V: PolarsNumericType,
+    F: FnMut(&[T::Native], &[U::Native]) -> Result<Vec<V::Native>>,
+{
+    let (lhs, rhs) = broadcast(lhs, rhs)?;
+    let (lhs, rhs) = align_chunks_binary(&lhs, &rhs);
+    let chunks = lhs
+        .downcast_iter()
+        .zip(rhs.downcast_iter())
+        .map(|(l_arr, r_arr)| {
+            let validity = combine_validities(l_arr.validity(), r_arr.validity());
+            let values = op(l_arr.values().as_slice(), r_arr.values().as_slice())?;
+            Ok(to_array::<V>(values, validity))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(ChunkedArray::from_chunks(lhs.name(), chunks))
+}
+
+/// Apply `op` to all values of `ca`. Null values stay null.
+///
+/// `op` is also called on the (arbitrary) values behind the null slots, so it should not panic
+/// on any input.
+pub fn unary_elementwise_values<T, V, F>(ca: &ChunkedArray<T>, op: F) -> ChunkedArray<V>
+where
+    T: PolarsNumericType,
+    V: PolarsNumericType,
+    F: Fn(T::Native) -> V::Native,
+{
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| {
+            let values = arr
+                .values()
+                .iter()
+                .map(|v| op(*v))
+                .collect_trusted::<Vec<_>>();
+            to_array::<V>(values, arr.validity().cloned())
+        })
+        .collect();
+    ChunkedArray::from_chunks(ca.name(), chunks)
+}
+
+/// Apply `op` to the pairs of values of `lhs` and `rhs`. The output is null where
+/// either input is null.
+///
+/// If one of the arrays has length 1 it is broadcasted to the length of the other array.
+/// The output has the name of `lhs`.
+///
+/// `op` is also called on the (arbitrary) values behind the null slots, so it should not panic
+/// on any input.
+///
+/// # Errors
+///
+/// Fails if the arrays have different lengths that cannot be broadcasted.
+pub fn binary_elementwise_values<T, U, V, F>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<U>,
+    op: F,
+) -> Result<ChunkedArray<V>>
+where
+    T: PolarsNumericType,
+    U: PolarsNumericType,
+    V: PolarsNumericType,
+    F: Fn(T::Native, U::Native) -> V::Native,
+{
+    binary_chunks(lhs, rhs, |l, r| {
+        Ok(l.iter()
+            .zip(r)
+            .map(|(l, r)| op(*l, *r))
+            .collect_trusted::<Vec<_>>())
+    })
+}
+
+/// Fallible version of [`binary_elementwise_values`]. The first error returned by `op`
+/// is propagated.
+///
+/// Note that `op` is also called on the values behind the null slots.
+pub fn try_binary_elementwise_values<T, U, V, F>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<U>,
+    op: F,
+) -> Result<ChunkedArray<V>>
+where
+    T: PolarsNumericType,
+    U: PolarsNumericType,
+    V: PolarsNumericType,
+    F: Fn(T::Native, U::Native) -> Result<V::Native>,
+{
+    binary_chunks(lhs, rhs, |l, r| {
+        l.iter().zip(r).map(|(l, r)| op(*l, *r)).collect()
+    })
+}
+
+/// Compare the pairs of values of `lhs` and `rhs` with `op`. The output is null where
+/// either input is null.
+///
+/// If one of the arrays has length 1 it is broadcasted to the length of the other array.
+/// The output has the name of `lhs`.
+///
+/// # Errors
+///
+/// Fails if the arrays have different lengths that cannot be broadcasted.
+pub fn binary_compare_values<T, U, F>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<U>,
+    op: F,
+) -> Result<BooleanChunked>
+where
+    T: PolarsNumericType,
+    U: PolarsNumericType,
+    F: Fn(T::Native, U::Native) -> bool,
+{
+    let (lhs, rhs) = broadcast(lhs, rhs)?;
+    let (lhs, rhs) = align_chunks_binary(&lhs, &rhs);
+    let chunks = lhs
+        .downcast_iter()
+        .zip(rhs.downcast_iter())
+        .map(|(l_arr, r_arr)| {
+            let validity = combine_validities(l_arr.validity(), r_arr.validity());
+            let values = Bitmap::from_trusted_len_iter(
+                l_arr
+                    .values()
+                    .iter()
+                    .zip(r_arr.values().iter())
+                    .map(|(l, r)| op(*l, *r)),
+            );
+            Box::new(BooleanArray::from_data_default(values, validity)) as ArrayRef
+        })
+        .collect();
+    Ok(ChunkedArray::from_chunks(lhs.name(), chunks))
+}
+
+/// Apply `op` to the pairs of optional values of `lhs` and `rhs`. In contrast to
+/// [`binary_elementwise_values`], `op` determines how null values are handled.
+///
+/// If one of the arrays has length 1 it is broadcasted to the length of the other array.
+/// The output has the name of `lhs`.
+///
+/// # Errors
+///
+/// Fails if the arrays have different lengths that cannot be broadcasted.
+pub fn binary_elementwise<T, U, V, F>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<U>,
+    mut op: F,
+) -> Result<ChunkedArray<V>>
+where
+    T: PolarsNumericType,
+    U: PolarsNumericType,
+    V: PolarsNumericType,
+    F: FnMut(Option<T::Native>, Option<U::Native>) -> Option<V::Native>,
+{
+    let (lhs, rhs) = broadcast(lhs, rhs)?;
+    let mut out: ChunkedArray<V> = lhs
+        .into_iter()
+        .zip(rhs.as_ref())
+        .map(|(l, r)| op(l, r))
+        .collect_trusted();
+    out.rename(lhs.name());
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_binary_elementwise() -> Result<()> {
+        let mut a = Int32Chunked::new("a", &[Some(1), None]);
+        a.append(&Int32Chunked::new("a", &[Some(3)]));
+        let b = Int64Chunked::new("b", &[Some(4), Some(5), None]);
+
+        let out: Int64Chunked = binary_elementwise_values(&a, &b, |a, b| a as i64 * b)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(4), None, None]);
+
+        // broadcast
+        let c = Int64Chunked::new("c", &[Some(2)]);
+        let out: Int64Chunked = binary_elementwise_values(&a, &c, |a, b| a as i64 - b)?;
+        assert_eq!(Vec::from(&out), &[Some(-1), None, Some(1)]);
+
+        let out: Int64Chunked = binary_elementwise(&a, &b, |a, b| match (a, b) {
+            (Some(a), Some(b)) => Some(a as i64 + b),
+            (a, b) => a.map(|a| a as i64).or(b),
+        })?;
+        assert_eq!(Vec::from(&out), &[Some(5), Some(5), Some(3)]);
+
+        let out: Result<Int32Chunked> = try_binary_elementwise_values(&a, &a, |a, b| {
+            a.checked_add(b)
+                .ok_or_else(|| PolarsError::ComputeError("overflow".into()))
+        });
+        assert!(out.is_ok());
+
+        let d = Int64Chunked::new("d", &[1, 2]);
+        assert!(
+            binary_elementwise_values::<_, _, Int64Type, _>(&a, &d, |a, b| a as i64 + b).is_err()
+        );
+
+        let out = binary_compare_values(&a, &b, |a, b| a as i64 * 2 > b)?;
+        assert_eq!(Vec::from(&out), &[Some(false), None, None]);
+
+        let out: Float64Chunked = unary_elementwise_values(&a, |a| a as f64 / 2.0);
+        assert_eq!(Vec::from(&out), &[Some(0.5), None, Some(1.5)]);
+        Ok(())
+    }
+}
@@ -1 +1,9 @@
+//! Kernels to write custom operations on [`ChunkedArray`](crate::prelude::ChunkedArray)s.
+//!
+//! [`arity`] handles the chunks and validities of `ChunkedArray`s. The arrow level comparison,
+//! arithmetic and filter kernels of `arrow2` are re-exported here, the take kernels are in
+//! `polars_arrow::compute::take`.
+pub mod arity;
 pub(crate) mod take;
+
+pub use arrow::compute::{arithmetics, comparison, filter};
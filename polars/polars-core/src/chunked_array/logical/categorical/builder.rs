@@ -477,4 +477,39 @@ mod test {
             assert_eq!(s.str_value(2), "world");
         }
     }
+
+    #[test]
+    fn test_append_different_caches() -> Result<()> {
+        let _lock = crate::SINGLE_LOCK.lock();
+        reset_string_cache();
+        toggle_string_cache(true);
+        let ca1 = Utf8Chunked::new("a", &[Some("a"), None, Some("b")])
+            .cast(&DataType::Categorical(None))?;
+        let mut ca1 = ca1.categorical()?.clone();
+
+        // a new string cache
+        reset_string_cache();
+        let ca2 = Utf8Chunked::new("a", &["c", "b", "a"]).cast(&DataType::Categorical(None))?;
+        ca1.append(ca2.categorical()?)?;
+        toggle_string_cache(false);
+
+        let values = ca1.iter_str().collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            &[Some("a"), None, Some("b"), Some("c"), Some("b"), Some("a")]
+        );
+        assert_eq!(
+            Vec::from(&ca1.get_categories()),
+            &[Some("a"), Some("b"), Some("c")]
+        );
+
+        let sliced = ca1.into_series().slice(3, 2);
+        let local = sliced.categorical()?.to_local();
+        assert_eq!(Vec::from(&local.get_categories()), &[Some("c"), Some("b")]);
+        assert_eq!(
+            local.logical().into_iter().collect::<Vec<_>>(),
+            &[Some(0), Some(1)]
+        );
+        Ok(())
+    }
 }
@@ -59,14 +59,128 @@ impl CategoricalChunked {
                 if std::ptr::eq(arr_l, arr_r) {
                     return Ok(self.get_rev_map().clone())
                 }
+                // the physical keys of `other` would not point into a merged map,
+                // these arrays have to be re-encoded, see `encode_local`
+                Err(PolarsError::ComputeError("cannot merge the categories of local categoricals from different sources".into()))
+            }
+            _ => Err(PolarsError::ComputeError("cannot combine categorical under a global string cache with a non cached categorical".into()))
+        }
+    }
+}
 
-                let arr = arrow::compute::concatenate::concatenate(&[arr_l, arr_r]).unwrap();
-                let arr = arr.as_any().downcast_ref::<Utf8Array<i64>>().unwrap().clone();
+/// The categories of `rev_map`.
+fn categories(rev_map: &RevMapping) -> &Utf8Array<i64> {
+    match rev_map {
+        RevMapping::Global(_, categories, _) => categories,
+        RevMapping::Local(categories) => categories,
+    }
+}
+
+/// The index of the physical key `cat` in the categories of `rev_map`. This is `None` for the
+/// keys that are not in `rev_map`, like the values behind nulls.
+fn category_idx(rev_map: &RevMapping, cat: u32) -> Option<usize> {
+    match rev_map {
+        RevMapping::Global(map, _, _) => map.get(&cat).map(|idx| *idx as usize),
+        RevMapping::Local(categories) => Some(cat as usize).filter(|idx| *idx < categories.len()),
+    }
+}
 
-                Ok(Arc::new(RevMapping::Local(arr)))
+impl CategoricalChunked {
+    /// Encode the categoricals `cas` under one new local [`RevMapping`] that merges their
+    /// categories. This can combine categoricals that are built under different string
+    /// caches. Only the categories are hashed, the physical keys are remapped by index.
+    pub(crate) fn encode_local(cas: &[&Self]) -> (Vec<UInt32Chunked>, Arc<RevMapping>) {
+        let mut map = PlHashMap::new();
+        let mut categories = MutableUtf8Array::<i64>::new();
+        let cats = cas
+            .iter()
+            .map(|ca| {
+                let rev_map = &**ca.get_rev_map();
+                let remap = self::categories(rev_map)
+                    .values_iter()
+                    .map(|s| {
+                        *map.entry(s).or_insert_with(|| {
+                            categories.push(Some(s));
+                            (categories.len() - 1) as u32
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                let remap = remap.as_slice();
+                // the values behind nulls are mapped to the first category
+                ca.logical().apply(|cat| {
+                    category_idx(rev_map, cat)
+                        .map(|idx| remap[idx])
+                        .unwrap_or_default()
+                })
+            })
+            .collect();
+        (cats, Arc::new(RevMapping::Local(categories.into())))
+    }
+
+    /// Concatenate the categoricals `cas` under one [`RevMapping`]. If they are not built from
+    /// the same source, the categories of all arrays are merged once, instead of re-encoding
+    /// the concatenated arrays on every append.
+    pub fn concat_local(cas: &[&Self]) -> Result<Self> {
+        let first = cas[0];
+        if cas[1..]
+            .iter()
+            .all(|ca| ca.get_rev_map().same_src(first.get_rev_map()))
+        {
+            let mut out = first.clone();
+            for ca in &cas[1..] {
+                out.append(ca)?;
             }
-            _ => Err(PolarsError::ComputeError("cannot combine categorical under a global string cache with a non cached categorical".into()))
+            return Ok(out);
+        }
+        let (cats, rev_map) = Self::encode_local(cas);
+        let mut cats = cats.into_iter();
+        let mut out = cats.next().unwrap();
+        for ca in cats {
+            out.append(&ca);
         }
+        // Safety:
+        // all arrays are encoded under `rev_map`
+        let mut out = unsafe { Self::from_cats_and_rev_map_unchecked(out, rev_map) };
+        out.set_lexical_sorted(first.use_lexical_sort());
+        Ok(out)
+    }
+
+    /// Get the categories of the [`RevMapping`], including the categories that don't occur
+    /// in this array.
+    pub fn get_categories(&self) -> Utf8Chunked {
+        let categories = categories(self.get_rev_map());
+        Utf8Chunked::from_chunks(self.name(), vec![Box::new(categories.clone())])
+    }
+
+    /// Re-encode this array under a local [`RevMapping`] that only holds the categories
+    /// that occur in this array, in order of their first occurrence.
+    pub fn to_local(&self) -> Self {
+        let rev_map = &**self.get_rev_map();
+        let old_categories = categories(rev_map);
+        let mut remap = vec![None; old_categories.len()];
+        let mut categories = MutableUtf8Array::<i64>::new();
+        let mut cats: UInt32Chunked = self
+            .logical()
+            .into_iter()
+            .map(|opt_cat| {
+                opt_cat.map(|cat| {
+                    let idx = category_idx(rev_map, cat).unwrap();
+                    *remap[idx].get_or_insert_with(|| {
+                        categories.push(Some(old_categories.value(idx)));
+                        (categories.len() - 1) as u32
+                    })
+                })
+            })
+            .collect();
+        cats.rename(self.name());
+        let rev_map = Arc::new(RevMapping::Local(categories.into()));
+        // Safety:
+        // the categories are encoded under `rev_map`
+        let mut out = unsafe { Self::from_cats_and_rev_map_unchecked(cats, rev_map) };
+        out.set_lexical_sorted(self.use_lexical_sort());
+        // all categories occur in the array
+        out.set_fast_unique(true);
+        out
     }
 }
 
@@ -20,8 +20,8 @@ pub struct CategoricalChunked {
 
 impl CategoricalChunked {
     pub(crate) fn field(&self) -> Field {
-        let name = self.logical().name();
-        Field::new(name, self.dtype().clone())
+        let field = self.logical().ref_field();
+        Field::new(field.name(), self.dtype().clone()).with_metadata(field.metadata().clone())
     }
 
     pub fn is_empty(&self) -> bool {
@@ -254,6 +254,36 @@ mod test {
         assert_eq!(appended.str_value(5), "y");
     }
 
+    #[test]
+    fn test_append_local_categoricals() -> Result<()> {
+        let _lock = SINGLE_LOCK.lock();
+        reset_string_cache();
+        toggle_string_cache(false);
+
+        // the categories of both arrays are merged and the keys of `s2` are remapped
+        let mut s1 =
+            Series::new("a", &[Some("a"), None, Some("b")]).cast(&DataType::Categorical(None))?;
+        let s2 =
+            Series::new("a", &[Some("c"), Some("a"), None]).cast(&DataType::Categorical(None))?;
+        s1.append(&s2)?;
+        let ca = s1.categorical()?;
+        assert_eq!(
+            ca.iter_str().collect::<Vec<_>>(),
+            &[Some("a"), None, Some("b"), Some("c"), Some("a"), None]
+        );
+        assert_eq!(
+            Vec::from(&ca.get_categories()),
+            &[Some("a"), Some("b"), Some("c")]
+        );
+
+        let mask = BooleanChunked::new("", &[true, false, true]);
+        let out = s2
+            .categorical()?
+            .zip_with(&mask, s1.slice(0, 3).categorical()?)?;
+        assert_eq!(out.iter_str().collect::<Vec<_>>(), &[Some("c"), None, None]);
+        Ok(())
+    }
+
     #[test]
     fn test_fast_unique() {
         let _lock = SINGLE_LOCK.lock();
@@ -4,6 +4,9 @@ use crate::series::IsSorted;
 
 impl CategoricalChunked {
     pub fn append(&mut self, other: &Self) -> Result<()> {
+        if !self.get_rev_map().same_src(other.get_rev_map()) {
+            return self.append_reencoded(other);
+        }
         let new_rev_map = self.merge_categorical_map(other)?;
         unsafe { self.set_rev_map(new_rev_map, false) };
 
@@ -13,4 +16,21 @@ impl CategoricalChunked {
         self.logical.set_sorted2(IsSorted::Not);
         Ok(())
     }
+
+    /// Append an array of which the categories are encoded differently, e.g. because it is
+    /// built under another string cache, by re-encoding both arrays under a new local
+    /// [`RevMapping`].
+    fn append_reencoded(&mut self, other: &Self) -> Result<()> {
+        let (cats, rev_map) = Self::encode_local(&[self, other]);
+        let mut cats = cats.into_iter();
+        let mut cats_self = cats.next().unwrap();
+        cats_self.append(&cats.next().unwrap());
+
+        let lexical = self.use_lexical_sort();
+        // Safety:
+        // both arrays are encoded under `rev_map`
+        *self = unsafe { Self::from_cats_and_rev_map_unchecked(cats_self, rev_map) };
+        self.set_lexical_sorted(lexical);
+        Ok(())
+    }
 }
@@ -6,15 +6,16 @@ impl CategoricalChunked {
         mask: &BooleanChunked,
         other: &CategoricalChunked,
     ) -> Result<Self> {
-        let cats = match &**self.get_rev_map() {
-            RevMapping::Local(rev_map) => {
-                // the logic for merging the rev maps will concatenate utf8 arrays
-                // to make sure the indexes still make sense we need to offset the right hand side
-                self.logical()
-                    .zip_with(mask, &(other.logical() + rev_map.len() as u32))?
-            }
-            _ => self.logical().zip_with(mask, other.logical())?,
-        };
+        if !self.get_rev_map().same_src(other.get_rev_map()) {
+            // built under different string caches or from different sources, so re-encode
+            // both arrays
+            let (cats, rev_map) = Self::encode_local(&[self, other]);
+            let cats = cats[0].zip_with(mask, &cats[1])?;
+            // Safety:
+            // both arrays are encoded under `rev_map`
+            return unsafe { Ok(Self::from_cats_and_rev_map_unchecked(cats, rev_map)) };
+        }
+        let cats = self.logical().zip_with(mask, other.logical())?;
         let new_state = self.merge_categorical_map(other)?;
 
         // Safety:
@@ -7,7 +7,6 @@ use std::borrow::Borrow;
 use std::hash::{Hash, Hasher};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, MutexGuard};
-use std::time::{SystemTime, UNIX_EPOCH};
 
 pub(crate) static USE_STRING_CACHE: AtomicBool = AtomicBool::new(false);
 
@@ -52,10 +51,7 @@ impl Default for SCacheInner {
                 HASHMAP_INIT_SIZE,
                 StringCache::get_hash_builder(),
             ),
-            uuid: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_nanos(),
+            uuid: crate::unique_id(),
         }
     }
 }
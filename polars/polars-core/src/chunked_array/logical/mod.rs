@@ -85,7 +85,8 @@ where
     Self: LogicalType,
 {
     pub fn field(&self) -> Field {
-        let name = self.0.ref_field().name();
-        Field::new(name, LogicalType::dtype(self).clone())
+        let field = self.0.ref_field();
+        Field::new(field.name(), LogicalType::dtype(self).clone())
+            .with_metadata(field.metadata().clone())
     }
 }
@@ -20,7 +20,10 @@ pub struct StructChunked {
 fn fields_to_struct_array(fields: &[Series]) -> (ArrayRef, Vec<Series>) {
     let fields = fields.iter().map(|s| s.rechunk()).collect::<Vec<_>>();
 
-    let new_fields = fields.iter().map(|s| s.field().to_arrow()).collect();
+    let new_fields = fields
+        .iter()
+        .map(|s| ArrowField::new(s.name(), s.dtype().to_arrow(), true))
+        .collect();
     let field_arrays = fields.iter().map(|s| s.to_arrow(0)).collect::<Vec<_>>();
     let arr = StructArray::new(ArrowDataType::Struct(new_fields), field_arrays, None);
     (Box::new(arr), fields)
@@ -60,7 +63,7 @@ impl StructChunked {
         let new_fields = self
             .fields
             .iter()
-            .map(|s| s.field().to_arrow())
+            .map(|s| ArrowField::new(s.name(), s.dtype().to_arrow(), true))
             .collect::<Vec<_>>();
         let n_chunks = self.fields[0].chunks().len();
         for i in offset..n_chunks {
@@ -139,6 +142,10 @@ impl StructChunked {
         self.field.set_name(name.to_string())
     }
 
+    pub fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.field.metadata = metadata
+    }
+
     pub(crate) fn try_apply_fields<F>(&self, func: F) -> Result<Self>
     where
         F: Fn(&Series) -> Result<Series>,
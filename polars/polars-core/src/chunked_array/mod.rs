@@ -446,9 +446,17 @@ impl<T: PolarsDataType> ChunkedArray<T> {
         &self.field
     }
 
-    /// Rename this ChunkedArray.
+    /// Rename this ChunkedArray. The metadata of the field is kept.
     pub fn rename(&mut self, name: &str) {
-        self.field = Arc::new(Field::new(name, self.field.data_type().clone()))
+        let mut field = Field::new(name, self.field.data_type().clone());
+        field.metadata = self.field.metadata.clone();
+        self.field = Arc::new(field)
+    }
+
+    /// Set the key/value metadata of the field of this ChunkedArray.
+    pub fn set_metadata(&mut self, metadata: FieldMetadata) {
+        let field = Field::new(self.name(), self.field.data_type().clone());
+        self.field = Arc::new(field.with_metadata(metadata))
     }
 }
 
@@ -606,7 +614,10 @@ impl ListChunked {
     }
 
     pub(crate) fn with_inner_type(&mut self, dtype: DataType) {
-        assert_eq!(dtype.to_physical(), self.inner_dtype());
+        // nested lists store the physical types of their values
+        if !matches!(dtype, DataType::List(_)) {
+            assert_eq!(dtype.to_physical(), self.inner_dtype());
+        }
         let field = Arc::make_mut(&mut self.field);
         field.coerce(DataType::List(Box::new(dtype)));
     }
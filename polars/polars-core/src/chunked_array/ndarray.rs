@@ -191,6 +191,11 @@ mod test {
         builder.append_slice(Some(&[6.0, 7.0, 8.0]));
         let list = builder.finish();
         assert!(list.to_ndarray::<Float64Type>().is_err());
+
+        // a reshaped series is a 2 dimensional array
+        let s = Series::new("", &[1i32, 2, 3, 4, 5, 6]).reshape(&[-1, 3])?;
+        let ndarr = s.list()?.to_ndarray::<Float64Type>()?;
+        assert_eq!(ndarr, array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
         Ok(())
     }
 
@@ -8,6 +8,7 @@ use arrow::compute;
 use arrow::types::simd::Simd;
 use num::Float;
 use num::ToPrimitive;
+use polars_arrow::kernels::simd;
 use polars_arrow::prelude::QuantileInterpolOptions;
 use std::ops::Add;
 
@@ -58,7 +59,10 @@ where
 {
     fn sum(&self) -> Option<T::Native> {
         self.downcast_iter()
-            .map(compute::aggregate::sum_primitive)
+            .map(|arr| match arr.null_count() {
+                0 if !arr.is_empty() => Some(simd::sum_slice(arr.values())),
+                _ => compute::aggregate::sum_primitive(arr),
+            })
             .fold(None, |acc, v| match v {
                 Some(v) => match acc {
                     None => Some(v),
@@ -86,7 +90,10 @@ where
             }
             IsSorted::Not => self
                 .downcast_iter()
-                .filter_map(compute::aggregate::min_primitive)
+                .filter_map(|arr| match arr.null_count() {
+                    0 => simd::min_slice(arr.values()),
+                    _ => compute::aggregate::min_primitive(arr),
+                })
                 .fold_first_(|acc, v| if acc < v { acc } else { v }),
         }
     }
@@ -109,7 +116,10 @@ where
             }
             IsSorted::Not => self
                 .downcast_iter()
-                .filter_map(compute::aggregate::max_primitive)
+                .filter_map(|arr| match arr.null_count() {
+                    0 => simd::max_slice(arr.values()),
+                    _ => compute::aggregate::max_primitive(arr),
+                })
                 .fold_first_(|acc, v| if acc > v { acc } else { v }),
         }
     }
@@ -427,7 +437,8 @@ where
 {
     fn var(&self, ddof: u8) -> Option<f64> {
         if self.len() == 1 {
-            return Some(0.0);
+            // a single null value has no variance
+            return self.get(0).map(|_| 0.0);
         }
         let n_values = self.len() - self.null_count();
 
@@ -454,7 +465,8 @@ where
 impl ChunkVar<f32> for Float32Chunked {
     fn var(&self, ddof: u8) -> Option<f32> {
         if self.len() == 1 {
-            return Some(0.0);
+            // a single null value has no variance
+            return self.get(0).map(|_| 0.0);
         }
         let n_values = self.len() - self.null_count();
 
@@ -478,7 +490,8 @@ impl ChunkVar<f32> for Float32Chunked {
 impl ChunkVar<f64> for Float64Chunked {
     fn var(&self, ddof: u8) -> Option<f64> {
         if self.len() == 1 {
-            return Some(0.0);
+            // a single null value has no variance
+            return self.get(0).map(|_| 0.0);
         }
         let n_values = self.len() - self.null_count();
 
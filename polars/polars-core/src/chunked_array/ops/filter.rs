@@ -4,7 +4,9 @@ use crate::prelude::*;
 use crate::utils::align_chunks_binary;
 #[cfg(feature = "object")]
 use arrow::array::Array;
+use arrow::array::PrimitiveArray;
 use arrow::compute::filter::filter as filter_fn;
+use polars_arrow::kernels::simd;
 
 macro_rules! check_filter_len {
     ($self:expr, $filter:expr) => {{
@@ -43,7 +45,22 @@ where
         let chunks = left
             .downcast_iter()
             .zip(filter.downcast_iter())
-            .map(|(left, mask)| filter_fn(left, mask).unwrap())
+            .map(|(left, mask)| {
+                if left.null_count() > 0 {
+                    return filter_fn(left, mask).unwrap();
+                }
+                // null values in the mask are not selected
+                let mask = match mask.validity() {
+                    Some(validity) if mask.null_count() > 0 => mask.values() & validity,
+                    _ => mask.values().clone(),
+                };
+                let values = simd::filter_values(left.values(), &mask);
+                Box::new(PrimitiveArray::from_data(
+                    left.data_type().clone(),
+                    values.into(),
+                    None,
+                )) as ArrayRef
+            })
             .collect::<Vec<_>>();
         Ok(self.copy_with_chunks(chunks, true))
     }
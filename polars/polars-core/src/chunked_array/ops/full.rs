@@ -1,20 +1,14 @@
 use crate::chunked_array::builder::get_list_builder;
 use crate::prelude::*;
-use crate::utils::NoNull;
 use arrow::bitmap::MutableBitmap;
-use polars_arrow::array::default_arrays::FromData;
+use polars_arrow::array::default_arrays::{FromData, FromDataUtf8};
 
 impl<T> ChunkFull<T::Native> for ChunkedArray<T>
 where
     T: PolarsNumericType,
 {
     fn full(name: &str, value: T::Native, length: usize) -> Self {
-        let mut ca = (0..length)
-            .map(|_| value)
-            .collect::<NoNull<ChunkedArray<T>>>()
-            .into_inner();
-        ca.rename(name);
-        ca
+        ChunkedArray::from_vec(name, vec![value; length])
     }
 }
 
@@ -44,12 +38,16 @@ impl ChunkFullNull for BooleanChunked {
 
 impl<'a> ChunkFull<&'a str> for Utf8Chunked {
     fn full(name: &str, value: &'a str, length: usize) -> Self {
-        let mut builder = Utf8ChunkedBuilder::new(name, length, length * value.len());
-
-        for _ in 0..length {
-            builder.append_value(value);
-        }
-        builder.finish()
+        let offsets = (0..=length)
+            .map(|i| (i * value.len()) as i64)
+            .collect::<Vec<_>>();
+        let values = value.as_bytes().repeat(length);
+        // Safety:
+        // the values are `length` copies of a valid utf8 str between the offsets
+        let arr = unsafe {
+            Utf8Array::<i64>::from_data_unchecked_default(offsets.into(), values.into(), None)
+        };
+        Utf8Chunked::from_chunks(name, vec![Box::new(arr)])
     }
 }
 
@@ -3,6 +3,28 @@ use crate::utils::{get_supertype, CustomIterTools};
 use hashbrown::hash_set::HashSet;
 use std::hash::Hash;
 
+/// A single list on the right hand side holds the values for every element of the left hand
+/// side. Those are hashed once instead of searching the list for every element.
+fn is_in_single_list<F>(
+    name: &str,
+    len: usize,
+    other: &Series,
+    is_in: F,
+) -> Option<Result<BooleanChunked>>
+where
+    F: FnOnce(&Series) -> Result<BooleanChunked>,
+{
+    if len == 1 || other.len() != 1 {
+        return None;
+    }
+    let out = match other.list().ok()?.get(0) {
+        Some(values) => is_in(&values),
+        // nothing is in a missing list
+        None => Ok(BooleanChunked::full(name, false, len)),
+    };
+    Some(out)
+}
+
 unsafe fn is_in_helper<T, P>(ca: &ChunkedArray<T>, other: &Series) -> Result<BooleanChunked>
 where
     T: PolarsNumericType,
@@ -50,6 +72,11 @@ where
                     let right = other.cast(&DataType::List(Box::new(st)))?;
                     return left.is_in(&right);
                 }
+                if let Some(out) =
+                    is_in_single_list(self.name(), self.len(), other, |values| self.is_in(values))
+                {
+                    return out;
+                }
 
                 let mut ca: BooleanChunked = if self.len() == 1 && other.len() != 1 {
                     let value = self.get(0);
@@ -124,6 +151,10 @@ impl IsIn for Utf8Chunked {
         match other.dtype() {
             #[cfg(feature = "dtype-categorical")]
             DataType::List(dt) if matches!(&**dt, DataType::Categorical(_)) => {
+                if self.len() != 1 {
+                    let other = other.cast(&DataType::List(Box::new(DataType::Utf8)))?;
+                    return self.is_in(&other);
+                }
                 if let DataType::Categorical(Some(rev_map)) = &**dt {
                     let opt_val = self.get(0);
 
@@ -169,6 +200,11 @@ impl IsIn for Utf8Chunked {
                 }
             }
             DataType::List(dt) if DataType::Utf8 == **dt => {
+                if let Some(out) =
+                    is_in_single_list(self.name(), self.len(), other, |values| self.is_in(values))
+                {
+                    return out;
+                }
                 let mut ca: BooleanChunked = if self.len() == 1 && other.len() != 1 {
                     let value = self.get(0);
                     other
@@ -212,6 +248,8 @@ impl IsIn for Utf8Chunked {
                 ca.rename(self.name());
                 Ok(ca)
             }
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(_) => self.is_in(&other.cast(&DataType::Utf8)?),
             _ => Err(PolarsError::SchemaMisMatch(
                 format!(
                     "cannot do is_in operation with left a dtype: {:?} and right a dtype {:?}",
@@ -232,6 +270,11 @@ impl IsIn for BooleanChunked {
     fn is_in(&self, other: &Series) -> Result<BooleanChunked> {
         match other.dtype() {
             DataType::List(dt) if self.dtype() == &**dt => {
+                if let Some(out) =
+                    is_in_single_list(self.name(), self.len(), other, |values| self.is_in(values))
+                {
+                    return out;
+                }
                 let mut ca: BooleanChunked = if self.len() == 1 && other.len() != 1 {
                     let value = self.get(0);
                     // safety: we know the iterators len
@@ -263,6 +306,13 @@ impl IsIn for BooleanChunked {
                 ca.rename(self.name());
                 Ok(ca)
             }
+            DataType::Boolean => {
+                let set = other.bool()?.into_iter().collect::<HashSet<_>>();
+                Ok(self
+                    .into_iter()
+                    .map(|opt_val| set.contains(&opt_val))
+                    .collect_trusted())
+            }
             _ => Err(PolarsError::SchemaMisMatch(
                 format!(
                     "cannot do is_in operation with left a dtype: {:?} and right a dtype {:?}",
@@ -284,6 +334,11 @@ impl IsIn for StructChunked {
     fn is_in(&self, other: &Series) -> Result<BooleanChunked> {
         match other.dtype() {
             DataType::List(_) => {
+                if let Some(out) =
+                    is_in_single_list(self.name(), self.len(), other, |values| self.is_in(values))
+                {
+                    return out;
+                }
                 let mut ca: BooleanChunked = if self.len() == 1 && other.len() != 1 {
                     let mut value = vec![];
                     let left = self.clone().into_series();
@@ -366,6 +421,37 @@ mod test {
             Vec::from(&out),
             [Some(false), Some(false), Some(true), Some(true)]
         );
+
+        let a = BooleanChunked::new("a", &[true, false]);
+        let out = a.is_in(&Series::new("b", &[true]))?;
+        assert_eq!(Vec::from(&out), [Some(true), Some(false)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_in_list() -> Result<()> {
+        let a = Int32Chunked::new("a", &[1, 2, 3]);
+        let lists = Series::new(
+            "b",
+            &[
+                Series::new("", &[1i64, 5]),
+                Series::new("", &[1i64, 5]),
+                Series::new("", &[3i64]),
+            ],
+        );
+        // row-wise membership with promoted dtypes
+        let out = a.is_in(&lists)?;
+        assert_eq!(Vec::from(&out), [Some(true), Some(false), Some(true)]);
+        assert_eq!(out.name(), "a");
+
+        // a single list is checked for every row
+        let out = a.is_in(&lists.slice(2, 1))?;
+        assert_eq!(Vec::from(&out), [Some(false), Some(false), Some(true)]);
+
+        let a = Utf8Chunked::new("a", &["x", "y", "z"]);
+        let list = Series::new("b", &[Series::new("", &["z", "x"])]);
+        let out = a.is_in(&list)?;
+        assert_eq!(Vec::from(&out), [Some(true), Some(false), Some(true)]);
         Ok(())
     }
 }
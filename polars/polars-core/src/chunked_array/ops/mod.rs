@@ -446,11 +446,11 @@ pub trait ChunkCompare<Rhs> {
 /// Get unique values in a `ChunkedArray`
 pub trait ChunkUnique<T: PolarsDataType> {
     // We don't return Self to be able to use AutoRef specialization
-    /// Get unique values of a ChunkedArray
+    /// Get unique values of a ChunkedArray. The order of the values is unspecified.
     fn unique(&self) -> Result<ChunkedArray<T>>;
 
     /// Get first index of the unique values in a `ChunkedArray`.
-    /// This Vec is sorted.
+    /// The indices are sorted, so they are in the order in which the values first occur.
     fn arg_unique(&self) -> Result<IdxCa>;
 
     /// Number of unique values in the `ChunkedArray`
@@ -482,11 +482,32 @@ pub trait ChunkUnique<T: PolarsDataType> {
     }
 }
 
+/// Where the `NaN` values of a float array are placed by a sort.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum NanPosition {
+    /// `NaN` is larger than any other value, so it is placed last in an ascending and
+    /// first in a descending sort.
+    Largest,
+    /// `NaN` is placed before the other values, regardless of the sort direction.
+    First,
+    /// `NaN` is placed after the other values, regardless of the sort direction.
+    Last,
+}
+
+impl Default for NanPosition {
+    fn default() -> Self {
+        NanPosition::Largest
+    }
+}
+
 #[derive(Default, Copy, Clone, Eq, PartialEq, Debug)]
 #[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct SortOptions {
     pub descending: bool,
     pub nulls_last: bool,
+    /// Only applies to float arrays. The nulls are placed by `nulls_last`.
+    pub nan_position: NanPosition,
 }
 
 /// Sort operations on `ChunkedArray`.
@@ -1,64 +1,127 @@
 use super::RepeatBy;
 use crate::prelude::*;
 use arrow::array::ListArray;
-use polars_arrow::array::ListFromIter;
+use arrow::bitmap::Bitmap;
 
 type LargeListArray = ListArray<i64>;
 
+/// Compute the offsets, the validity and the indices of the values of all lists at once, so that
+/// the values are gathered with a single take instead of being pushed one by one.
+///
+/// A `by` or a `ca` of length 1 is broadcasted. A null in `by` gives a null list.
+fn repeat_by_impl<T>(ca: &ChunkedArray<T>, by: &IdxCa) -> ListChunked
+where
+    T: PolarsDataType,
+    ChunkedArray<T>: ChunkTake,
+{
+    let len = match (ca.len(), by.len()) {
+        (len, 1) => len,
+        (1, len) => len,
+        (a, b) => std::cmp::min(a, b),
+    };
+    let by = by.rechunk();
+    let by_arr = by.downcast_iter().next().unwrap();
+    let by_idx = |i: usize| if by_arr.len() == 1 { 0 } else { i };
+    let value_idx = |i: usize| if ca.len() == 1 { 0 } else { i as IdxSize };
+
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0i64);
+    let mut idx = Vec::with_capacity(len);
+    let mut has_empty = false;
+    for i in 0..len {
+        let j = by_idx(i);
+        let n = if by_arr.is_valid(j) {
+            by_arr.value(j) as usize
+        } else {
+            0
+        };
+        has_empty |= n == 0;
+        idx.resize(idx.len() + n, value_idx(i));
+        offsets.push(idx.len() as i64);
+    }
+    let validity = if by_arr.null_count() > 0 {
+        Some(
+            (0..len)
+                .map(|i| by_arr.is_valid(by_idx(i)))
+                .collect::<Bitmap>(),
+        )
+    } else {
+        None
+    };
+
+    let idx = IdxCa::from_vec("", idx);
+    // Safety:
+    // the indices are in bounds of `ca`
+    let values = unsafe { ca.take_unchecked((&idx).into()) }.rechunk();
+    let values = match values.chunks().first() {
+        Some(arr) => arr.clone(),
+        None => new_empty_array(ca.dtype().to_arrow()),
+    };
+    let dtype = LargeListArray::default_datatype(values.data_type().clone());
+
+    // Safety:
+    // the offsets are monotonically increasing and in bounds of the values
+    let arr = unsafe { LargeListArray::new_unchecked(dtype, offsets.into(), values, validity) };
+    let mut out = ListChunked::from_chunks(ca.name(), vec![Box::new(arr)]);
+    if !has_empty {
+        out.set_fast_explode();
+    }
+    out
+}
+
 impl<T> RepeatBy for ChunkedArray<T>
 where
     T: PolarsNumericType,
 {
     fn repeat_by(&self, by: &IdxCa) -> ListChunked {
-        let iter = self
-            .into_iter()
-            .zip(by.into_iter())
-            .map(|(opt_v, opt_by)| opt_by.map(|by| std::iter::repeat(opt_v).take(by as usize)));
-
-        // Safety:
-        // Length of iter is trusted
-        ListChunked::from_chunks(
-            self.name(),
-            vec![Box::new(unsafe {
-                LargeListArray::from_iter_primitive_trusted_len::<T::Native, _, _>(
-                    iter,
-                    T::get_dtype().to_arrow(),
-                )
-            })],
-        )
+        repeat_by_impl(self, by)
     }
 }
 impl RepeatBy for BooleanChunked {
     fn repeat_by(&self, by: &IdxCa) -> ListChunked {
-        let iter = self
-            .into_iter()
-            .zip(by.into_iter())
-            .map(|(opt_v, opt_by)| opt_by.map(|by| std::iter::repeat(opt_v).take(by as usize)));
-
-        // Safety:
-        // Length of iter is trusted
-        ListChunked::from_chunks(
-            self.name(),
-            vec![Box::new(unsafe {
-                LargeListArray::from_iter_bool_trusted_len(iter)
-            })],
-        )
+        repeat_by_impl(self, by)
     }
 }
 impl RepeatBy for Utf8Chunked {
     fn repeat_by(&self, by: &IdxCa) -> ListChunked {
-        let iter = self
-            .into_iter()
-            .zip(by.into_iter())
-            .map(|(opt_v, opt_by)| opt_by.map(|by| std::iter::repeat(opt_v).take(by as usize)));
+        repeat_by_impl(self, by)
+    }
+}
 
-        // Safety:
-        // Length of iter is trusted
-        ListChunked::from_chunks(
-            self.name(),
-            vec![Box::new(unsafe {
-                LargeListArray::from_iter_utf8_trusted_len(iter, self.len())
-            })],
-        )
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_repeat_by() -> Result<()> {
+        let ca = Int32Chunked::new("a", &[Some(1), None, Some(3)]);
+        let by = IdxCa::new("by", &[Some(2), Some(1), None]);
+        let out = ca.repeat_by(&by);
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.null_count(), 1);
+        assert_eq!(Vec::from(out.get(0).unwrap().i32()?), &[Some(1), Some(1)]);
+        assert_eq!(Vec::from(out.get(1).unwrap().i32()?), &[None]);
+
+        // `by` is broadcasted
+        let ca = Utf8Chunked::new("a", &["x", "y"]);
+        let out = ca.repeat_by(&IdxCa::new("by", &[3]));
+        let out = out.explode()?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[
+                Some("x"),
+                Some("x"),
+                Some("x"),
+                Some("y"),
+                Some("y"),
+                Some("y")
+            ]
+        );
+
+        let ca = BooleanChunked::new("a", &[true, false]);
+        let out = ca.repeat_by(&IdxCa::new("by", &[0, 1]));
+        assert_eq!(out.get(0).unwrap().len(), 0);
+        assert_eq!(Vec::from(out.get(1).unwrap().bool()?), &[Some(false)]);
+        Ok(())
     }
 }
@@ -94,6 +94,7 @@ impl CategoricalChunked {
         self.sort_with(SortOptions {
             nulls_last: false,
             descending: reverse,
+            ..Default::default()
         })
     }
 
@@ -249,7 +249,12 @@ where
             vals.extend_trusted_len(iter);
         });
 
-        argsort_no_nulls(vals.as_mut_slice(), reverse);
+        argsort_branch(
+            vals.as_mut_slice(),
+            reverse,
+            |(_, a), (_, b)| sort_cmp(a, b),
+            |(_, a), (_, b)| sort_cmp(b, a),
+        );
 
         let out: NoNull<IdxCa> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
         let mut out = out.into_inner();
@@ -263,6 +268,106 @@ where
     }
 }
 
+/// Move the `NaN` values of a sorted float array to `options.nan_position`.
+fn place_nans<T>(sorted: ChunkedArray<T>, options: SortOptions) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+{
+    if options.nan_position == NanPosition::Largest {
+        return sorted;
+    }
+    let null_count = sorted.null_count();
+    let n_values = sorted.len() - null_count;
+    let nulls_first = null_count > 0 && sorted.get(0).is_none();
+    let (values, nulls) = if nulls_first {
+        (
+            sorted.slice(null_count as i64, n_values),
+            sorted.slice(0, null_count),
+        )
+    } else {
+        (
+            sorted.slice(0, n_values),
+            sorted.slice(n_values as i64, null_count),
+        )
+    };
+    let nan_count = values
+        .into_iter()
+        .filter(|v| matches!(v, Some(v) if v.is_nan()))
+        .count();
+    // the sort puts `NaN` at one end of the values, as the largest value
+    let nans_first = values.get(0).map_or(false, |v| v.is_nan());
+    let keep = match options.nan_position {
+        NanPosition::First => nans_first,
+        _ => !nans_first,
+    };
+    if nan_count == 0 || nan_count == n_values || keep {
+        return sorted;
+    }
+
+    let (mut out, other) = if nans_first {
+        (
+            values.slice(nan_count as i64, n_values - nan_count),
+            values.slice(0, nan_count),
+        )
+    } else {
+        (
+            values.slice((n_values - nan_count) as i64, nan_count),
+            values.slice(0, n_values - nan_count),
+        )
+    };
+    out.append(&other);
+    let out = if nulls_first {
+        let mut nulls = nulls;
+        nulls.append(&out);
+        nulls
+    } else {
+        out.append(&nulls);
+        out
+    };
+    out.rechunk()
+}
+
+/// Move the indices of the `NaN` values in the argsort `idx` of a float array to
+/// `nan_position`, keeping the indices of the nulls in place.
+fn place_nan_indices<T>(ca: &ChunkedArray<T>, idx: IdxCa, nan_position: NanPosition) -> IdxCa
+where
+    T: PolarsFloatType,
+{
+    if nan_position == NanPosition::Largest {
+        return idx;
+    }
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let is_valid = |i: IdxSize| arr.is_valid(i as usize);
+
+    let idx_vals = idx.into_no_null_iter().collect::<Vec<_>>();
+    let (nans, values): (Vec<IdxSize>, Vec<IdxSize>) = idx_vals
+        .iter()
+        .copied()
+        .filter(|i| is_valid(*i))
+        .partition(|i| arr.value(*i as usize).is_nan());
+    if nans.is_empty() {
+        return idx;
+    }
+    let mut valid = match nan_position {
+        NanPosition::First => nans.into_iter().chain(values),
+        _ => values.into_iter().chain(nans),
+    };
+    let out: NoNull<IdxCa> = idx_vals
+        .iter()
+        .map(|&i| {
+            if is_valid(i) {
+                valid.next().unwrap()
+            } else {
+                i
+            }
+        })
+        .collect_trusted();
+    let mut out = out.into_inner();
+    out.rename(ca.name());
+    out
+}
+
 #[cfg(feature = "sort_multiple")]
 fn argsort_multiple_numeric<T: PolarsNumericType>(
     ca: &ChunkedArray<T>,
@@ -315,7 +420,8 @@ where
 
 impl ChunkSort<Float32Type> for Float32Chunked {
     fn sort_with(&self, options: SortOptions) -> Float32Chunked {
-        sort_with_numeric(self, options, order_default_flt, order_reverse_flt)
+        let sorted = sort_with_numeric(self, options, order_default_flt, order_reverse_flt);
+        place_nans(sorted, options)
     }
 
     fn sort(&self, reverse: bool) -> Float32Chunked {
@@ -326,7 +432,8 @@ impl ChunkSort<Float32Type> for Float32Chunked {
     }
 
     fn argsort(&self, options: SortOptions) -> IdxCa {
-        argsort_numeric(self, options)
+        let idx = argsort_numeric(self, options);
+        place_nan_indices(self, idx, options.nan_position)
     }
 
     #[cfg(feature = "sort_multiple")]
@@ -341,7 +448,8 @@ impl ChunkSort<Float32Type> for Float32Chunked {
 
 impl ChunkSort<Float64Type> for Float64Chunked {
     fn sort_with(&self, options: SortOptions) -> Float64Chunked {
-        sort_with_numeric(self, options, order_default_flt, order_reverse_flt)
+        let sorted = sort_with_numeric(self, options, order_default_flt, order_reverse_flt);
+        place_nans(sorted, options)
     }
 
     fn sort(&self, reverse: bool) -> Float64Chunked {
@@ -352,7 +460,8 @@ impl ChunkSort<Float64Type> for Float64Chunked {
     }
 
     fn argsort(&self, options: SortOptions) -> IdxCa {
-        argsort_numeric(self, options)
+        let idx = argsort_numeric(self, options);
+        place_nan_indices(self, idx, options.nan_position)
     }
 
     #[cfg(feature = "sort_multiple")]
@@ -477,6 +586,7 @@ impl ChunkSort<Utf8Type> for Utf8Chunked {
         self.sort_with(SortOptions {
             descending: reverse,
             nulls_last: false,
+            ..Default::default()
         })
     }
 
@@ -539,6 +649,7 @@ impl ChunkSort<BooleanType> for BooleanChunked {
         self.sort_with(SortOptions {
             descending: reverse,
             nulls_last: false,
+            ..Default::default()
         })
     }
 
@@ -557,9 +668,47 @@ impl ChunkSort<BooleanType> for BooleanChunked {
 pub(crate) fn prepare_argsort(
     columns: Vec<Series>,
     mut reverse: Vec<bool>,
+    nan_position: NanPosition,
 ) -> Result<(Series, Vec<Series>, Vec<bool>)> {
     let n_cols = columns.len();
 
+    // broadcast ordering
+    if n_cols > reverse.len() && reverse.len() == 1 {
+        while n_cols != reverse.len() {
+            reverse.push(reverse[0]);
+        }
+    }
+    if n_cols != reverse.len() {
+        return Err(PolarsError::ComputeError(
+            format!(
+                "The amount of ordering booleans: {} does not match that no. of Series: {}",
+                reverse.len(),
+                n_cols
+            )
+            .into(),
+        ));
+    }
+
+    // `NaN` is the largest value in the comparisons of the columns, so to place the `NaN`
+    // values regardless of the sort direction, a float column is preceded by a key that
+    // orders the `NaN` values before or after the other values.
+    let (columns, reverse) = if nan_position == NanPosition::Largest {
+        (columns, reverse)
+    } else {
+        let mut keys = Vec::with_capacity(n_cols * 2);
+        let mut keys_reverse = Vec::with_capacity(n_cols * 2);
+        for (s, reverse) in columns.into_iter().zip(reverse) {
+            if matches!(s.dtype(), DataType::Float32 | DataType::Float64) {
+                let is_nan = s.is_nan()?.fill_null_with_values(false)?;
+                keys.push(is_nan.into_series());
+                keys_reverse.push(nan_position == NanPosition::First);
+            }
+            keys.push(s);
+            keys_reverse.push(reverse);
+        }
+        (keys, keys_reverse)
+    };
+
     let mut columns = columns
         .iter()
         .map(|s| {
@@ -582,13 +731,6 @@ pub(crate) fn prepare_argsort(
         .collect::<Vec<_>>();
 
     let first = columns.remove(0);
-
-    // broadcast ordering
-    if n_cols > reverse.len() && reverse.len() == 1 {
-        while n_cols != reverse.len() {
-            reverse.push(reverse[0]);
-        }
-    }
     Ok((first, columns, reverse))
 }
 
@@ -649,6 +791,7 @@ mod test {
         let out = a.sort_with(SortOptions {
             descending: false,
             nulls_last: false,
+            ..Default::default()
         });
         assert_eq!(
             Vec::from(&out),
@@ -666,6 +809,7 @@ mod test {
         let out = a.sort_with(SortOptions {
             descending: false,
             nulls_last: true,
+            ..Default::default()
         });
         assert_eq!(
             Vec::from(&out),
@@ -746,6 +890,7 @@ mod test {
         let out = ca.sort_with(SortOptions {
             descending: false,
             nulls_last: false,
+            ..Default::default()
         });
         let expected = &[None, None, Some("a"), Some("b"), Some("c")];
         assert_eq!(Vec::from(&out), expected);
@@ -753,6 +898,7 @@ mod test {
         let out = ca.sort_with(SortOptions {
             descending: true,
             nulls_last: false,
+            ..Default::default()
         });
 
         let expected = &[None, None, Some("c"), Some("b"), Some("a")];
@@ -761,6 +907,7 @@ mod test {
         let out = ca.sort_with(SortOptions {
             descending: false,
             nulls_last: true,
+            ..Default::default()
         });
         let expected = &[Some("a"), Some("b"), Some("c"), None, None];
         assert_eq!(Vec::from(&out), expected);
@@ -768,6 +915,7 @@ mod test {
         let out = ca.sort_with(SortOptions {
             descending: true,
             nulls_last: true,
+            ..Default::default()
         });
         let expected = &[Some("c"), Some("b"), Some("a"), None, None];
         assert_eq!(Vec::from(&out), expected);
@@ -782,4 +930,50 @@ mod test {
         let expected = &[Some("c"), Some("b"), Some("a")];
         assert_eq!(Vec::from(&out), expected);
     }
+
+    #[test]
+    fn test_sort_nan_position() {
+        let ca = Float64Chunked::new(
+            "a",
+            &[Some(2.0), Some(f64::NAN), None, Some(1.0), Some(f64::NAN)],
+        );
+        let out = ca.sort(false);
+        assert_eq!(
+            format!("{:?}", Vec::from(&out)),
+            "[None, Some(1.0), Some(2.0), Some(NaN), Some(NaN)]"
+        );
+
+        let out = ca.sort_with(SortOptions {
+            nan_position: NanPosition::First,
+            ..Default::default()
+        });
+        assert_eq!(
+            format!("{:?}", Vec::from(&out)),
+            "[None, Some(NaN), Some(NaN), Some(1.0), Some(2.0)]"
+        );
+
+        let out = ca.sort_with(SortOptions {
+            descending: true,
+            nulls_last: true,
+            nan_position: NanPosition::Last,
+        });
+        assert_eq!(
+            format!("{:?}", Vec::from(&out)),
+            "[Some(2.0), Some(1.0), Some(NaN), Some(NaN), None]"
+        );
+
+        let idx = ca.argsort(SortOptions {
+            nan_position: NanPosition::First,
+            ..Default::default()
+        });
+        assert_eq!(
+            Vec::from(&idx),
+            &[Some(2), Some(1), Some(4), Some(3), Some(0)]
+        );
+
+        // NaN without nulls
+        let ca = Float64Chunked::new("a", &[1.0, f64::NAN, 0.0]);
+        let idx = ca.argsort(Default::default());
+        assert_eq!(Vec::from(&idx), &[Some(2), Some(0), Some(1)]);
+    }
 }
@@ -440,6 +440,34 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg(all(feature = "dtype-struct", feature = "groupby_list"))]
+    fn unique_stable() -> Result<()> {
+        let a = Series::new("a", &[3, 1, 3, 2, 1]);
+        let b = Series::new("b", &["x", "y", "x", "z", "y"]);
+        let s = StructChunked::new("s", &[a.clone(), b])?.into_series();
+        assert_eq!(
+            s.arg_unique()?.into_iter().collect::<Vec<_>>(),
+            vec![Some(0), Some(1), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(a.unique_stable()?.i32()?),
+            &[Some(3), Some(1), Some(2)]
+        );
+
+        let lists = Series::new("l", &[a.slice(0, 1), a.slice(1, 1), a.slice(0, 1)]);
+        assert_eq!(lists.n_unique()?, 2);
+        let unique = lists.unique_stable()?;
+        assert_eq!(unique.len(), 2);
+        let firsts = unique
+            .list()?
+            .into_iter()
+            .map(|s| s.unwrap().i32().unwrap().get(0))
+            .collect::<Vec<_>>();
+        assert_eq!(firsts, &[Some(3), Some(1)]);
+        Ok(())
+    }
+
     #[test]
     fn is_unique() {
         let ca = Float32Chunked::from_slice("a", &[1., 2., 1., 1., 3.]);
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::utils::{CustomIterTools, NoNull};
 use num::{Float, NumCast};
+use rand::distributions::uniform::SampleUniform;
 use rand::distributions::Bernoulli;
 use rand::prelude::*;
 use rand_distr::{Distribution, Normal, Standard, StandardNormal, Uniform};
@@ -203,12 +204,24 @@ where
 {
     /// Create `ChunkedArray` with samples from a Normal distribution.
     pub fn rand_normal(name: &str, length: usize, mean: f64, std_dev: f64) -> Result<Self> {
+        Self::rand_normal_seeded(name, length, mean, std_dev, None)
+    }
+
+    /// Create `ChunkedArray` with samples from a Normal distribution. The same `seed` gives
+    /// the same samples.
+    pub fn rand_normal_seeded(
+        name: &str,
+        length: usize,
+        mean: f64,
+        std_dev: f64,
+        seed: Option<u64>,
+    ) -> Result<Self> {
         let normal = match Normal::new(mean, std_dev) {
             Ok(dist) => dist,
             Err(e) => return Err(PolarsError::ComputeError(format!("{:?}", e).into())),
         };
         let mut builder = PrimitiveChunkedBuilder::<T>::new(name, length);
-        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_random_seed));
         for _ in 0..length {
             let smpl = normal.sample(&mut rng);
             let smpl = NumCast::from(smpl).unwrap();
@@ -230,16 +243,71 @@ where
     }
 
     /// Create `ChunkedArray` with samples from a Uniform distribution.
+    ///
+    /// # Panics
+    /// If `low` is not smaller than `high`.
     pub fn rand_uniform(name: &str, length: usize, low: f64, high: f64) -> Self {
+        Self::rand_uniform_seeded(name, length, low, high, None).unwrap()
+    }
+
+    /// Create `ChunkedArray` with samples from a Uniform distribution over `[low, high)`.
+    /// The same `seed` gives the same samples.
+    pub fn rand_uniform_seeded(
+        name: &str,
+        length: usize,
+        low: f64,
+        high: f64,
+        seed: Option<u64>,
+    ) -> Result<Self> {
+        if low.is_nan() || high.is_nan() || low >= high {
+            return Err(PolarsError::ComputeError(
+                "the lower bound must be smaller than the upper bound".into(),
+            ));
+        }
+        if !(high - low).is_finite() {
+            return Err(PolarsError::ComputeError(
+                "the range of a uniform distribution must be finite".into(),
+            ));
+        }
         let uniform = Uniform::new(low, high);
         let mut builder = PrimitiveChunkedBuilder::<T>::new(name, length);
-        let mut rng = rand::thread_rng();
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_random_seed));
         for _ in 0..length {
             let smpl = uniform.sample(&mut rng);
             let smpl = NumCast::from(smpl).unwrap();
             builder.append_value(smpl)
         }
-        builder.finish()
+        Ok(builder.finish())
+    }
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsIntegerType,
+    T::Native: SampleUniform,
+{
+    /// Create `ChunkedArray` with integers sampled uniformly from `[low, high)`.
+    /// The same `seed` gives the same samples.
+    pub fn rand_int(
+        name: &str,
+        length: usize,
+        low: T::Native,
+        high: T::Native,
+        seed: Option<u64>,
+    ) -> Result<Self> {
+        if low >= high {
+            return Err(PolarsError::ComputeError(
+                "the lower bound must be smaller than the upper bound".into(),
+            ));
+        }
+        let uniform = Uniform::new(low, high);
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_random_seed));
+        let mut ca: Self = (0..length)
+            .map(|_| uniform.sample(&mut rng))
+            .collect_trusted::<NoNull<Self>>()
+            .into_inner();
+        ca.rename(name);
+        Ok(ca)
     }
 }
 
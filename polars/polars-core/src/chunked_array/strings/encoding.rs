@@ -3,53 +3,138 @@ use crate::prelude::*;
 use base64;
 use hex;
 
+/// The encodings of [`Utf8Chunked::encode`] and [`Utf8Chunked::decode`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StringEncoding {
+    Hex,
+    Base64,
+}
+
+/// What to do with values that cannot be decoded by [`Utf8Chunked::decode`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DecodeStrategy {
+    /// Raise an error.
+    Error,
+    /// Set the value to null.
+    Null,
+    /// Replace invalid utf8 sequences in the decoded bytes by the replacement char `U+FFFD`.
+    /// Values that are no valid hex or base64 are set to null.
+    Replace,
+}
+
 impl Utf8Chunked {
-    #[cfg(feature = "string_encoding")]
-    pub fn hex_decode(&self, strict: Option<bool>) -> Result<Utf8Chunked> {
-        let ca = self.apply_on_opt(|e| {
-            e.and_then(|s| {
-                hex::decode(s)
-                    // Safety
-                    // We already know that it is a valid utf8.
-                    .map(|bytes| Some(unsafe { String::from_utf8_unchecked(bytes) }.into()))
-                    .unwrap_or(None)
+    /// Encode the strings with the given encoding.
+    #[must_use]
+    pub fn encode(&self, encoding: StringEncoding) -> Utf8Chunked {
+        let mut ca = match encoding {
+            StringEncoding::Hex => self.apply(|s| hex::encode(s).into()),
+            StringEncoding::Base64 => self.apply(|s| base64::encode(s).into()),
+        };
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Decode the strings with the given encoding. The decoded bytes should be valid utf8,
+    /// `strategy` determines what happens to values for which this fails.
+    pub fn decode(
+        &self,
+        encoding: StringEncoding,
+        strategy: DecodeStrategy,
+    ) -> Result<Utf8Chunked> {
+        let decode = |s: &str| match encoding {
+            StringEncoding::Hex => hex::decode(s).ok(),
+            StringEncoding::Base64 => base64::decode(s).ok(),
+        };
+        let mut ca = self
+            .into_iter()
+            .map(|opt_s| {
+                let s = match opt_s {
+                    Some(s) => s,
+                    None => return Ok(None),
+                };
+                let bytes = match (decode(s), strategy) {
+                    (Some(bytes), _) => bytes,
+                    (None, DecodeStrategy::Error) => {
+                        return Err(PolarsError::ComputeError(
+                            format!("unable to decode {:?} as {:?}", s, encoding).into(),
+                        ))
+                    }
+                    (None, _) => return Ok(None),
+                };
+                match (String::from_utf8(bytes), strategy) {
+                    (Ok(s), _) => Ok(Some(s)),
+                    (Err(e), DecodeStrategy::Replace) => {
+                        Ok(Some(String::from_utf8_lossy(e.as_bytes()).into_owned()))
+                    }
+                    (Err(_), DecodeStrategy::Null) => Ok(None),
+                    (Err(_), DecodeStrategy::Error) => Err(PolarsError::ComputeError(
+                        format!("decoded {:?} is not valid utf8", s).into(),
+                    )),
+                }
             })
-        });
+            .collect::<Result<Utf8Chunked>>()?;
+        ca.rename(self.name());
+        Ok(ca)
+    }
 
-        if strict.unwrap_or(false) && (ca.null_count() != self.null_count()) {
-            Err(PolarsError::ComputeError("Unable to decode inputs".into()))
-        } else {
-            Ok(ca)
-        }
+    #[cfg(feature = "string_encoding")]
+    pub fn hex_decode(&self, strict: Option<bool>) -> Result<Utf8Chunked> {
+        self.decode(StringEncoding::Hex, decode_strategy(strict))
+            .map_err(|_| PolarsError::ComputeError("Unable to decode inputs".into()))
     }
+
     #[cfg(feature = "string_encoding")]
     #[must_use]
     pub fn hex_encode(&self) -> Utf8Chunked {
-        self.apply(|s| hex::encode(s).into())
+        self.encode(StringEncoding::Hex)
     }
 
     #[cfg(feature = "string_encoding")]
     pub fn base64_decode(&self, strict: Option<bool>) -> Result<Utf8Chunked> {
-        let ca = self.apply_on_opt(|e| {
-            e.and_then(|s| {
-                base64::decode(s)
-                    // Safety
-                    // We already know that it is a valid utf8.
-                    .map(|bytes| Some(unsafe { String::from_utf8_unchecked(bytes) }.into()))
-                    .unwrap_or(None)
-            })
-        });
-
-        if strict.unwrap_or(false) && (ca.null_count() != self.null_count()) {
-            Err(PolarsError::ComputeError("Unable to decode inputs".into()))
-        } else {
-            Ok(ca)
-        }
+        self.decode(StringEncoding::Base64, decode_strategy(strict))
+            .map_err(|_| PolarsError::ComputeError("Unable to decode inputs".into()))
     }
 
     #[cfg(feature = "string_encoding")]
     #[must_use]
     pub fn base64_encode(&self) -> Utf8Chunked {
-        self.apply(|s| base64::encode(s).into())
+        self.encode(StringEncoding::Base64)
+    }
+}
+
+fn decode_strategy(strict: Option<bool>) -> DecodeStrategy {
+    if strict.unwrap_or(false) {
+        DecodeStrategy::Error
+    } else {
+        DecodeStrategy::Null
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() -> Result<()> {
+        let ca = Utf8Chunked::new("a", &[Some("foo"), None, Some("ü")]);
+        for encoding in [StringEncoding::Hex, StringEncoding::Base64] {
+            let encoded = ca.encode(encoding);
+            let decoded = encoded.decode(encoding, DecodeStrategy::Error)?;
+            assert_eq!(decoded.name(), "a");
+            assert_eq!(Vec::from(&decoded), Vec::from(&ca));
+        }
+        assert_eq!(ca.hex_encode().get(0), Some("666f6f"));
+
+        // "ff" is no valid utf8 and "zz" no valid hex
+        let ca = Utf8Chunked::new("a", &["66ff", "zz", "66"]);
+        assert!(ca
+            .decode(StringEncoding::Hex, DecodeStrategy::Error)
+            .is_err());
+        assert!(ca.hex_decode(Some(true)).is_err());
+        let out = ca.decode(StringEncoding::Hex, DecodeStrategy::Null)?;
+        assert_eq!(Vec::from(&out), &[None, None, Some("f")]);
+        let out = ca.decode(StringEncoding::Hex, DecodeStrategy::Replace)?;
+        assert_eq!(Vec::from(&out), &[Some("f\u{FFFD}"), None, Some("f")]);
+        Ok(())
     }
 }
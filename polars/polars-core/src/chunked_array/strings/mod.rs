@@ -1,4 +1,6 @@
 #[cfg(feature = "string_encoding")]
 mod encoding;
+#[cfg(feature = "string_encoding")]
+pub use encoding::{DecodeStrategy, StringEncoding};
 #[cfg(feature = "extract_jsonpath")]
 mod json_path;
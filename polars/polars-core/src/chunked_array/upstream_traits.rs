@@ -8,6 +8,7 @@ use crate::prelude::*;
 use crate::utils::NoNull;
 use crate::utils::{get_iter_capacity, CustomIterTools};
 use arrow::array::{BooleanArray, PrimitiveArray, Utf8Array};
+use arrow::bitmap::Bitmap;
 use polars_arrow::utils::TrustMyLength;
 use rayon::iter::{FromParallelIterator, IntoParallelIterator};
 use rayon::prelude::*;
@@ -32,6 +33,11 @@ impl<T: PolarsDataType> Default for ChunkedArray<T> {
 
 /// FromIterator trait
 
+/// A validity that has no unset bits can be dropped.
+fn no_nulls(validity: Option<&Bitmap>) -> bool {
+    matches!(validity, Some(validity) if validity.unset_bits() == 0)
+}
+
 impl<T> FromIterator<Option<T::Native>> for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -61,6 +67,12 @@ where
                 .collect::<PrimitiveArray<T::Native>>()
                 .to(T::get_dtype().to_arrow()),
         };
+        // without a validity the kernels take their faster null-free paths
+        let arr = if no_nulls(arr.validity()) {
+            arr.with_validity(None)
+        } else {
+            arr
+        };
         ChunkedArray::from_chunks("", vec![Box::new(arr)])
     }
 }
@@ -81,7 +93,22 @@ where
 
 impl FromIterator<Option<bool>> for ChunkedArray<BooleanType> {
     fn from_iter<I: IntoIterator<Item = Option<bool>>>(iter: I) -> Self {
-        let arr = BooleanArray::from_iter(iter);
+        let iter = iter.into_iter();
+        let arr = match iter.size_hint() {
+            // see the primitive implementation
+            #[cfg(feature = "performant")]
+            (a, Some(b)) if a == b => unsafe {
+                let arr = BooleanArray::from_trusted_len_iter_unchecked(iter);
+                assert_eq!(arr.len(), a);
+                arr
+            },
+            _ => BooleanArray::from_iter(iter),
+        };
+        let arr = if no_nulls(arr.validity()) {
+            arr.with_validity(None)
+        } else {
+            arr
+        };
         Self::from_chunks("", vec![Box::new(arr)])
     }
 }
@@ -271,7 +298,6 @@ impl FromIterator<Option<Box<dyn Array>>> for ListChunked {
 #[cfg(feature = "object")]
 impl<T: PolarsObject> FromIterator<Option<T>> for ObjectChunked<T> {
     fn from_iter<I: IntoIterator<Item = Option<T>>>(iter: I) -> Self {
-        use arrow::bitmap::Bitmap;
         use arrow::bitmap::MutableBitmap;
 
         let iter = iter.into_iter();
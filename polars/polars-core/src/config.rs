@@ -1,3 +1,149 @@
+//! Formatting options of `DataFrame` and `Series` and the deterministic execution mode.
+//!
+//! The options default to environment variables, so that they can be set from the
+//! environment and from python. From rust they are set per process or per scope, without
+//! modifying the environment.
+use once_cell::sync::Lazy;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
 pub(crate) const FMT_STR_LEN: &str = "POLARS_FMT_STR_LEN";
 pub(crate) const FMT_MAX_COLS: &str = "POLARS_FMT_MAX_COLS";
 pub(crate) const FMT_MAX_ROWS: &str = "POLARS_FMT_MAX_ROWS";
+pub(crate) const FMT_NO_UTF8: &str = "POLARS_FMT_NO_UTF8";
+pub(crate) const FMT_TABLE_WIDTH: &str = "POLARS_TABLE_WIDTH";
+pub(crate) const FMT_FLOAT_PRECISION: &str = "POLARS_FMT_FLOAT_PRECISION";
+pub(crate) const FMT_THOUSANDS_SEPARATOR: &str = "POLARS_FMT_THOUSANDS_SEPARATOR";
+pub(crate) const DETERMINISTIC: &str = "POLARS_DETERMINISTIC";
+
+fn parse_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+    std::env::var(key).ok().and_then(|v| v.parse().ok())
+}
+
+/// The deterministic execution mode, see [`deterministic`].
+static DETERMINISTIC_MODE: Lazy<AtomicBool> =
+    Lazy::new(|| AtomicBool::new(std::env::var(DETERMINISTIC).is_ok()));
+
+/// The options set with [`FmtConfig::apply`].
+static GLOBAL_FMT_CONFIG: Lazy<RwLock<Option<FmtConfig>>> = Lazy::new(Default::default);
+
+thread_local! {
+    /// The options set with [`FmtConfig::scoped`] on this thread.
+    static SCOPED_FMT_CONFIG: RefCell<Option<FmtConfig>> = RefCell::new(None);
+}
+
+/// Options that determine how a `DataFrame` and `Series` are printed.
+///
+/// A `None` means that the default is used.
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::config::FmtConfig;
+///
+/// let _guard = FmtConfig {
+///     max_rows: Some(20),
+///     float_precision: Some(2),
+///     ascii_tables: true,
+///     ..Default::default()
+/// }
+/// .scoped();
+/// // formatting on this thread uses these options until `_guard` is dropped
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FmtConfig {
+    /// Width of the tables in characters.
+    pub table_width: Option<u16>,
+    /// Maximum number of rows shown.
+    pub max_rows: Option<usize>,
+    /// Maximum number of columns shown.
+    pub max_cols: Option<usize>,
+    /// Maximum number of characters shown per string value.
+    pub str_len: Option<usize>,
+    /// Number of decimals shown for floating point values.
+    pub float_precision: Option<usize>,
+    /// Character that is used to group the digits of numeric values per thousand.
+    pub thousands_separator: Option<char>,
+    /// Draw the tables with ascii characters instead of utf8 box-drawing characters.
+    pub ascii_tables: bool,
+}
+
+impl FmtConfig {
+    /// Read the current options from the environment.
+    pub fn from_env() -> Self {
+        FmtConfig {
+            table_width: parse_env(FMT_TABLE_WIDTH),
+            max_rows: parse_env(FMT_MAX_ROWS),
+            max_cols: parse_env(FMT_MAX_COLS),
+            str_len: parse_env(FMT_STR_LEN),
+            float_precision: parse_env(FMT_FLOAT_PRECISION),
+            thousands_separator: parse_env(FMT_THOUSANDS_SEPARATOR),
+            ascii_tables: std::env::var(FMT_NO_UTF8).is_ok(),
+        }
+    }
+
+    /// The options in effect on this thread: the [scoped](FmtConfig::scoped) options, else
+    /// the [applied](FmtConfig::apply) options, else the options from the environment.
+    pub fn current() -> Self {
+        if let Some(config) = SCOPED_FMT_CONFIG.with(|config| config.borrow().clone()) {
+            return config;
+        }
+        if let Some(config) = GLOBAL_FMT_CONFIG.read().unwrap().clone() {
+            return config;
+        }
+        Self::from_env()
+    }
+
+    /// Use these options for all subsequent formatting in this process, instead of the
+    /// environment variables.
+    pub fn apply(&self) {
+        *GLOBAL_FMT_CONFIG.write().unwrap() = Some(self.clone());
+    }
+
+    /// Use the environment variables again for all subsequent formatting in this process.
+    pub fn reset() {
+        *GLOBAL_FMT_CONFIG.write().unwrap() = None;
+    }
+
+    /// Use these options for formatting on this thread until the returned guard is dropped.
+    #[must_use]
+    pub fn scoped(self) -> FmtConfigGuard {
+        let prev = SCOPED_FMT_CONFIG.with(|config| config.borrow_mut().replace(self));
+        FmtConfigGuard { prev }
+    }
+}
+
+/// Restores the previous formatting options of the thread when dropped,
+/// see [`FmtConfig::scoped`].
+pub struct FmtConfigGuard {
+    prev: Option<FmtConfig>,
+}
+
+impl Drop for FmtConfigGuard {
+    fn drop(&mut self) {
+        let prev = self.prev.take();
+        SCOPED_FMT_CONFIG.with(|config| *config.borrow_mut() = prev);
+    }
+}
+
+/// Whether the deterministic execution mode is on. In this mode groupbys, distincts and the
+/// expressions `unique` and `value_counts` maintain the order of their groups and the rows of
+/// joins are ordered by the rows of the left and then the right table, so that these operations
+/// give identical outputs for identical inputs, at the expense of performance. Scans always
+/// concatenate the results of their files and parallel readers in order.
+///
+/// A lazy query reads the mode when it is collected, unless it is set for the query with
+/// `LazyFrame::with_deterministic`.
+///
+/// The mode is turned on by the `POLARS_DETERMINISTIC` environment variable or
+/// [`set_deterministic`].
+pub fn deterministic() -> bool {
+    DETERMINISTIC_MODE.load(Ordering::Relaxed)
+}
+
+/// Toggle the deterministic execution mode for all subsequent operations in this process,
+/// see [`deterministic`]. The environment is not modified.
+pub fn set_deterministic(toggle: bool) {
+    DETERMINISTIC_MODE.store(toggle, Ordering::Relaxed)
+}
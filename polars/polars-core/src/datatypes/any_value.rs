@@ -0,0 +1,312 @@
+use super::*;
+
+fn conversion_err<T>(av: &AnyValue, to: &str) -> Result<T> {
+    Err(PolarsError::ComputeError(
+        format!("cannot convert AnyValue {} into {}", av, to).into(),
+    ))
+}
+
+macro_rules! impl_try_from_integer {
+    ($native:ty) => {
+        impl TryFrom<AnyValue<'_>> for $native {
+            type Error = PolarsError;
+
+            /// Converts all integers and the physical values of the temporal types.
+            /// Fails if the value does not fit.
+            fn try_from(av: AnyValue<'_>) -> Result<Self> {
+                use AnyValue::*;
+                let out = match &av {
+                    UInt8(v) => <$native>::try_from(*v).ok(),
+                    UInt16(v) => <$native>::try_from(*v).ok(),
+                    UInt32(v) => <$native>::try_from(*v).ok(),
+                    UInt64(v) => <$native>::try_from(*v).ok(),
+                    Int8(v) => <$native>::try_from(*v).ok(),
+                    Int16(v) => <$native>::try_from(*v).ok(),
+                    Int32(v) => <$native>::try_from(*v).ok(),
+                    Int64(v) => <$native>::try_from(*v).ok(),
+                    #[cfg(feature = "dtype-date")]
+                    Date(v) => <$native>::try_from(*v).ok(),
+                    #[cfg(feature = "dtype-datetime")]
+                    Datetime(v, _, _) => <$native>::try_from(*v).ok(),
+                    #[cfg(feature = "dtype-duration")]
+                    Duration(v, _) => <$native>::try_from(*v).ok(),
+                    #[cfg(feature = "dtype-time")]
+                    Time(v) => <$native>::try_from(*v).ok(),
+                    _ => None,
+                };
+                match out {
+                    Some(v) => Ok(v),
+                    None => conversion_err(&av, stringify!($native)),
+                }
+            }
+        }
+    };
+}
+
+impl_try_from_integer!(u8);
+impl_try_from_integer!(u16);
+impl_try_from_integer!(u32);
+impl_try_from_integer!(u64);
+impl_try_from_integer!(i8);
+impl_try_from_integer!(i16);
+impl_try_from_integer!(i32);
+impl_try_from_integer!(i64);
+
+macro_rules! impl_try_from_float {
+    ($native:ty) => {
+        impl TryFrom<AnyValue<'_>> for $native {
+            type Error = PolarsError;
+
+            /// Converts all numeric values, large integers may lose precision.
+            fn try_from(av: AnyValue<'_>) -> Result<Self> {
+                use AnyValue::*;
+                match av {
+                    Float32(v) => Ok(v as $native),
+                    Float64(v) => Ok(v as $native),
+                    UInt8(v) => Ok(v as $native),
+                    UInt16(v) => Ok(v as $native),
+                    UInt32(v) => Ok(v as $native),
+                    UInt64(v) => Ok(v as $native),
+                    Int8(v) => Ok(v as $native),
+                    Int16(v) => Ok(v as $native),
+                    Int32(v) => Ok(v as $native),
+                    Int64(v) => Ok(v as $native),
+                    av => conversion_err(&av, stringify!($native)),
+                }
+            }
+        }
+    };
+}
+
+impl_try_from_float!(f32);
+impl_try_from_float!(f64);
+
+impl TryFrom<AnyValue<'_>> for bool {
+    type Error = PolarsError;
+
+    fn try_from(av: AnyValue<'_>) -> Result<Self> {
+        match av {
+            AnyValue::Boolean(v) => Ok(v),
+            av => conversion_err(&av, "bool"),
+        }
+    }
+}
+
+impl TryFrom<AnyValue<'_>> for String {
+    type Error = PolarsError;
+
+    /// Converts strings and categoricals.
+    fn try_from(av: AnyValue<'_>) -> Result<Self> {
+        match av {
+            AnyValue::Utf8(v) => Ok(v.to_string()),
+            AnyValue::Utf8Owned(v) => Ok(v),
+            #[cfg(feature = "dtype-categorical")]
+            AnyValue::Categorical(idx, rev) => Ok(rev.get(idx).to_string()),
+            av => conversion_err(&av, "String"),
+        }
+    }
+}
+
+impl TryFrom<AnyValue<'_>> for Series {
+    type Error = PolarsError;
+
+    fn try_from(av: AnyValue<'_>) -> Result<Self> {
+        match av {
+            AnyValue::List(s) => Ok(s),
+            av => conversion_err(&av, "Series"),
+        }
+    }
+}
+
+/// The order of the kinds of values in [`AnyValue::total_cmp`].
+fn kind_rank(av: &AnyValue) -> u8 {
+    use AnyValue::*;
+    match av {
+        Null => 0,
+        Boolean(_) => 1,
+        UInt8(_) | UInt16(_) | UInt32(_) | UInt64(_) | Int8(_) | Int16(_) | Int32(_) | Int64(_)
+        | Float32(_) | Float64(_) => 2,
+        Utf8(_) | Utf8Owned(_) => 3,
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) => 3,
+        #[cfg(feature = "dtype-date")]
+        Date(_) => 4,
+        #[cfg(feature = "dtype-datetime")]
+        Datetime(_, _, _) => 5,
+        #[cfg(feature = "dtype-duration")]
+        Duration(_, _) => 6,
+        #[cfg(feature = "dtype-time")]
+        Time(_) => 7,
+        List(_) => 8,
+        #[cfg(feature = "dtype-struct")]
+        Struct(_, _) | StructOwned(_) => 9,
+        #[cfg(feature = "object")]
+        Object(_) => 10,
+    }
+}
+
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-duration"))]
+fn timestamp_ns(v: i64, tu: TimeUnit) -> i128 {
+    match tu {
+        TimeUnit::Nanoseconds => v as i128,
+        TimeUnit::Microseconds => v as i128 * 1_000,
+        TimeUnit::Milliseconds => v as i128 * 1_000_000,
+    }
+}
+
+fn as_i128(av: &AnyValue) -> Option<i128> {
+    use AnyValue::*;
+    match av {
+        UInt8(v) => Some(*v as i128),
+        UInt16(v) => Some(*v as i128),
+        UInt32(v) => Some(*v as i128),
+        UInt64(v) => Some(*v as i128),
+        Int8(v) => Some(*v as i128),
+        Int16(v) => Some(*v as i128),
+        Int32(v) => Some(*v as i128),
+        Int64(v) => Some(*v as i128),
+        _ => None,
+    }
+}
+
+fn as_f64(av: &AnyValue) -> f64 {
+    match av {
+        AnyValue::Float32(v) => *v as f64,
+        AnyValue::Float64(v) => *v,
+        av => as_i128(av).unwrap() as f64,
+    }
+}
+
+/// Total order of floats, NaN is larger than all other values.
+fn float_cmp(l: f64, r: f64) -> Ordering {
+    match (l.is_nan(), r.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => l.partial_cmp(&r).unwrap(),
+    }
+}
+
+fn str_value<'b>(av: &'b AnyValue) -> &'b str {
+    match av {
+        AnyValue::Utf8(v) => v,
+        AnyValue::Utf8Owned(v) => v,
+        #[cfg(feature = "dtype-categorical")]
+        AnyValue::Categorical(idx, rev) => rev.get(*idx),
+        _ => unreachable!(),
+    }
+}
+
+fn cmp_slices(l: &[AnyValue], r: &[AnyValue]) -> Ordering {
+    l.iter()
+        .zip(r)
+        .map(|(l, r)| l.total_cmp(r))
+        .find(|ord| *ord != Ordering::Equal)
+        .unwrap_or_else(|| l.len().cmp(&r.len()))
+}
+
+impl AnyValue<'_> {
+    /// Compare with a total order, so that values of different types can be sorted.
+    ///
+    /// * `Null` is smaller than any other value.
+    /// * Numeric values are compared by their value, regardless of their type. `NaN` is
+    ///   larger than all other numbers.
+    /// * Strings and categoricals are compared by their (string) value.
+    /// * Temporal values are compared by their point in time or duration.
+    /// * Lists and structs are compared lexicographically.
+    /// * Otherwise values are ordered by their kind:
+    ///   null < boolean < numeric < string < date < datetime < duration < time < list < struct.
+    ///
+    /// Objects are always equal to each other.
+    pub fn total_cmp(&self, other: &AnyValue) -> Ordering {
+        use AnyValue::*;
+        let rank = kind_rank(self).cmp(&kind_rank(other));
+        if rank != Ordering::Equal {
+            return rank;
+        }
+        match (self, other) {
+            (Boolean(l), Boolean(r)) => l.cmp(r),
+            #[cfg(feature = "dtype-date")]
+            (Date(l), Date(r)) => l.cmp(r),
+            #[cfg(feature = "dtype-datetime")]
+            (Datetime(l, tu_l, _), Datetime(r, tu_r, _)) => {
+                timestamp_ns(*l, *tu_l).cmp(&timestamp_ns(*r, *tu_r))
+            }
+            #[cfg(feature = "dtype-duration")]
+            (Duration(l, tu_l), Duration(r, tu_r)) => {
+                timestamp_ns(*l, *tu_l).cmp(&timestamp_ns(*r, *tu_r))
+            }
+            #[cfg(feature = "dtype-time")]
+            (Time(l), Time(r)) => l.cmp(r),
+            (List(l), List(r)) => {
+                let l = (0..l.len()).map(|i| l.get(i)).collect::<Vec<_>>();
+                let r = (0..r.len()).map(|i| r.get(i)).collect::<Vec<_>>();
+                cmp_slices(&l, &r)
+            }
+            #[cfg(feature = "dtype-struct")]
+            (Struct(l, _), Struct(r, _)) => cmp_slices(l, r),
+            #[cfg(feature = "dtype-struct")]
+            (Struct(l, _), StructOwned(r)) => cmp_slices(l, &r.0),
+            #[cfg(feature = "dtype-struct")]
+            (StructOwned(l), Struct(r, _)) => cmp_slices(&l.0, r),
+            #[cfg(feature = "dtype-struct")]
+            (StructOwned(l), StructOwned(r)) => cmp_slices(&l.0, &r.0),
+            (l, r) => match kind_rank(l) {
+                2 => match (as_i128(l), as_i128(r)) {
+                    (Some(l), Some(r)) => l.cmp(&r),
+                    _ => float_cmp(as_f64(l), as_f64(r)),
+                },
+                3 => str_value(l).cmp(str_value(r)),
+                // null and objects
+                _ => Ordering::Equal,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_any_value_try_from() {
+        assert_eq!(i64::try_from(AnyValue::UInt8(3)).unwrap(), 3);
+        assert!(u8::try_from(AnyValue::Int64(300)).is_err());
+        assert!(u32::try_from(AnyValue::Int32(-1)).is_err());
+        assert_eq!(f64::try_from(AnyValue::Int32(2)).unwrap(), 2.0);
+        assert_eq!(String::try_from(AnyValue::Utf8("a")).unwrap(), "a");
+        assert!(bool::try_from(AnyValue::Null).is_err());
+        assert!(i64::try_from(AnyValue::Utf8("1")).is_err());
+    }
+
+    #[test]
+    fn test_any_value_total_cmp() {
+        let mut values = [
+            AnyValue::Utf8("b"),
+            AnyValue::Float64(f64::NAN),
+            AnyValue::Int64(3),
+            AnyValue::Null,
+            AnyValue::Float32(2.5),
+            AnyValue::Boolean(true),
+            AnyValue::Utf8Owned("a".into()),
+            AnyValue::UInt8(1),
+        ];
+        values.sort_by(|l, r| l.total_cmp(r));
+        assert_eq!(
+            values.iter().map(|av| av.to_string()).collect::<Vec<_>>(),
+            &["null", "true", "1", "2.5", "3", "NaN", "\"a\"", "\"b\""]
+        );
+        assert_eq!(
+            AnyValue::UInt64(u64::MAX).total_cmp(&AnyValue::Int64(-1)),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_any_value_into_static() {
+        let s = String::from("a");
+        let av = AnyValue::Utf8(&s).into_static().unwrap();
+        drop(s);
+        assert_eq!(String::try_from(av).unwrap(), "a");
+    }
+}
@@ -8,6 +8,7 @@
 //!
 #[cfg(feature = "serde")]
 mod _serde;
+mod any_value;
 
 pub use crate::chunked_array::logical::*;
 #[cfg(feature = "object")]
@@ -27,6 +28,7 @@ use polars_arrow::data_types::IsFloat;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign, Div, Mul, Rem, Sub, SubAssign};
@@ -394,6 +396,22 @@ impl From<u8> for AnyValue<'_> {
     }
 }
 
+impl From<bool> for AnyValue<'_> {
+    fn from(a: bool) -> Self {
+        AnyValue::Boolean(a)
+    }
+}
+impl<'a> From<&'a str> for AnyValue<'a> {
+    fn from(a: &'a str) -> Self {
+        AnyValue::Utf8(a)
+    }
+}
+impl From<String> for AnyValue<'_> {
+    fn from(a: String) -> Self {
+        AnyValue::Utf8Owned(a)
+    }
+}
+
 impl<'a, T> From<Option<T>> for AnyValue<'a>
 where
     T: Into<AnyValue<'a>>,
@@ -461,6 +479,9 @@ impl<'a> AnyValue<'a> {
 
     /// Try to coerce to an AnyValue with static lifetime.
     /// This can be done if it does not borrow any values.
+    ///
+    /// Borrowed strings are copied and categoricals become (owned) strings.
+    /// Datetimes with a time zone and objects cannot be coerced.
     pub fn into_static(self) -> Result<AnyValue<'static>> {
         use AnyValue::*;
         let av = match self {
@@ -480,8 +501,34 @@ impl<'a> AnyValue<'a> {
             Date(v) => AnyValue::Date(v),
             #[cfg(feature = "dtype-time")]
             Time(v) => AnyValue::Time(v),
+            #[cfg(feature = "dtype-datetime")]
+            Datetime(v, tu, None) => AnyValue::Datetime(v, tu, &None),
+            #[cfg(feature = "dtype-duration")]
+            Duration(v, tu) => AnyValue::Duration(v, tu),
+            #[cfg(feature = "dtype-categorical")]
+            Categorical(idx, rev) => AnyValue::Utf8Owned(rev.get(idx).to_string()),
             List(v) => AnyValue::List(v),
             Utf8(s) => AnyValue::Utf8Owned(s.to_string()),
+            Utf8Owned(s) => AnyValue::Utf8Owned(s),
+            #[cfg(feature = "dtype-struct")]
+            Struct(vals, fields) => AnyValue::StructOwned(Box::new((
+                vals.into_iter()
+                    .map(AnyValue::into_static)
+                    .collect::<Result<_>>()?,
+                fields.to_vec(),
+            ))),
+            #[cfg(feature = "dtype-struct")]
+            StructOwned(payload) => {
+                let (vals, fields) = *payload;
+                AnyValue::StructOwned(Box::new((
+                    vals.into_iter()
+                        .map(AnyValue::into_static)
+                        .collect::<Result<_>>()?,
+                    fields,
+                )))
+            }
+            // unreachable if neither datetimes nor objects are activated
+            #[allow(unreachable_patterns)]
             dt => {
                 return Err(PolarsError::ComputeError(
                     format!("cannot get static AnyValue from {}", dt).into(),
@@ -880,6 +927,9 @@ impl PartialEq<ArrowDataType> for DataType {
     }
 }
 
+/// Key/value metadata of a [`Field`], e.g. the unit or a description of a column.
+pub type FieldMetadata = BTreeMap<String, String>;
+
 /// Characterizes the name and the [`DataType`] of a column.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(
@@ -889,6 +939,8 @@ impl PartialEq<ArrowDataType> for DataType {
 pub struct Field {
     pub name: String,
     pub dtype: DataType,
+    #[cfg_attr(any(feature = "serde", feature = "serde-lazy"), serde(default))]
+    pub metadata: FieldMetadata,
 }
 
 impl Field {
@@ -907,11 +959,39 @@ impl Field {
         Field {
             name: name.to_string(),
             dtype,
+            metadata: FieldMetadata::new(),
         }
     }
 
     pub fn from_owned(name: String, dtype: DataType) -> Self {
-        Field { name, dtype }
+        Field {
+            name,
+            dtype,
+            metadata: FieldMetadata::new(),
+        }
+    }
+
+    /// Set the key/value metadata of the `Field`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let f = Field::new("Distance", DataType::Float64)
+    ///     .with_metadata([("unit".to_string(), "km".to_string())].into());
+    ///
+    /// assert_eq!(f.metadata()["unit"], "km");
+    /// ```
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: FieldMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Returns a reference to the key/value metadata of the `Field`.
+    #[inline]
+    pub fn metadata(&self) -> &FieldMetadata {
+        &self.metadata
     }
 
     /// Returns a reference to the `Field` name.
@@ -987,6 +1067,7 @@ impl Field {
     /// ```
     pub fn to_arrow(&self) -> ArrowField {
         ArrowField::new(&self.name, self.dtype.to_arrow(), true)
+            .with_metadata(self.metadata.clone())
     }
 }
 
@@ -1018,7 +1099,11 @@ impl From<&ArrowDataType> for DataType {
             ArrowDataType::Dictionary(_, _, _) => DataType::Categorical(None),
             #[cfg(feature = "dtype-struct")]
             ArrowDataType::Struct(fields) => {
-                let fields: Vec<Field> = fields.iter().map(|fld| fld.into()).collect();
+                // the metadata of the fields is not part of the data type
+                let fields: Vec<Field> = fields
+                    .iter()
+                    .map(|fld| Field::new(&fld.name, fld.data_type().into()))
+                    .collect();
                 DataType::Struct(fields)
             }
             ArrowDataType::Extension(name, _, _) if name == "POLARS_EXTENSION_TYPE" => {
@@ -1038,7 +1123,7 @@ impl From<&ArrowDataType> for DataType {
 
 impl From<&ArrowField> for Field {
     fn from(f: &ArrowField) -> Self {
-        Field::new(&f.name, f.data_type().into())
+        Field::new(&f.name, f.data_type().into()).with_metadata(f.metadata.clone())
     }
 }
 #[cfg(feature = "private")]
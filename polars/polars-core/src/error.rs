@@ -1,9 +1,78 @@
+use crate::datatypes::DataType;
 use anyhow::Error;
 use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
 use thiserror::Error as ThisError;
 
 type ErrString = Cow<'static, str>;
 
+/// The class of a [`PolarsError`], to branch on the kind of failure without matching on
+/// the error message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    Arrow,
+    InvalidOperation,
+    SchemaMisMatch,
+    NotFound,
+    ShapeMisMatch,
+    Compute,
+    NoData,
+    Io,
+    Duplicate,
+    Cancelled,
+}
+
+impl ErrorCode {
+    /// A stable name of the error class, for instance to use in logs.
+    pub fn as_str(&self) -> &'static str {
+        use ErrorCode::*;
+        match self {
+            Arrow => "arrow",
+            InvalidOperation => "invalid_operation",
+            SchemaMisMatch => "schema_mismatch",
+            NotFound => "not_found",
+            ShapeMisMatch => "shape_mismatch",
+            Compute => "compute",
+            NoData => "no_data",
+            Io => "io",
+            Duplicate => "duplicate",
+            Cancelled => "cancelled",
+        }
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Where a [`PolarsError`] happened.
+#[derive(Debug, Clone, Default)]
+pub struct ErrorContext {
+    /// The name of the column that failed.
+    pub column: Option<String>,
+    /// The data types of the operands of the failing operation.
+    pub dtypes: Option<(DataType, DataType)>,
+    /// A description of the node in the logical plan that failed.
+    pub plan_node: Option<String>,
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(column) = &self.column {
+            write!(f, "\n\tcolumn: '{}'", column)?;
+        }
+        if let Some((left, right)) = &self.dtypes {
+            write!(f, "\n\tdtypes: {:?} and {:?}", left, right)?;
+        }
+        if let Some(plan_node) = &self.plan_node {
+            write!(f, "\n\terror originated in: {}", plan_node)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, ThisError)]
 pub enum PolarsError {
     #[error(transparent)]
@@ -24,6 +93,97 @@ pub enum PolarsError {
     Io(#[from] std::io::Error),
     #[error("DuplicateError: {0}")]
     Duplicate(ErrString),
+    /// The query was aborted by its [`CancellationToken`](crate::cancel::CancellationToken).
+    #[error("Cancelled: {0}")]
+    Cancelled(ErrString),
+    /// An error with the context in which it happened.
+    ///
+    /// Note that this is a breaking change: the errors of lazy queries are wrapped in this
+    /// variant, so matching on e.g. `PolarsError::ComputeError` directly no longer works for
+    /// them. Match on [`PolarsError::root`] or branch on [`PolarsError::code`] instead.
+    #[error("{error}{context}")]
+    Context {
+        error: Box<PolarsError>,
+        context: Box<ErrorContext>,
+    },
+}
+
+impl PolarsError {
+    /// Get the class of this error.
+    pub fn code(&self) -> ErrorCode {
+        use PolarsError::*;
+        match self {
+            ArrowError(_) => ErrorCode::Arrow,
+            InvalidOperation(_) => ErrorCode::InvalidOperation,
+            SchemaMisMatch(_) => ErrorCode::SchemaMisMatch,
+            NotFound(_) => ErrorCode::NotFound,
+            ShapeMisMatch(_) => ErrorCode::ShapeMisMatch,
+            ComputeError(_) => ErrorCode::Compute,
+            NoData(_) => ErrorCode::NoData,
+            Io(_) => ErrorCode::Io,
+            Duplicate(_) => ErrorCode::Duplicate,
+            Cancelled(_) => ErrorCode::Cancelled,
+            Context { error, .. } => error.code(),
+        }
+    }
+
+    /// Get the error without its context. Errors of lazy queries point to the failing
+    /// plan node, so match on the root or use [`PolarsError::code`] to branch on the kind
+    /// of error.
+    pub fn root(&self) -> &PolarsError {
+        match self {
+            PolarsError::Context { error, .. } => error.root(),
+            err => err,
+        }
+    }
+
+    /// Get the context in which this error happened, if any.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            PolarsError::Context { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    fn update_context(self, f: impl FnOnce(&mut ErrorContext)) -> Self {
+        match self {
+            PolarsError::Context { error, mut context } => {
+                f(context.as_mut());
+                PolarsError::Context { error, context }
+            }
+            error => {
+                let mut context = ErrorContext::default();
+                f(&mut context);
+                PolarsError::Context {
+                    error: Box::new(error),
+                    context: Box::new(context),
+                }
+            }
+        }
+    }
+
+    /// Add the name of the failing column, unless it is already known.
+    pub fn with_column(self, name: &str) -> Self {
+        self.update_context(|ctx| {
+            ctx.column.get_or_insert_with(|| name.to_string());
+        })
+    }
+
+    /// Add the data types of the operands of the failing operation, unless they are already known.
+    pub fn with_dtypes(self, left: &DataType, right: &DataType) -> Self {
+        self.update_context(|ctx| {
+            ctx.dtypes
+                .get_or_insert_with(|| (left.clone(), right.clone()));
+        })
+    }
+
+    /// Add a description of the failing node in the logical plan, unless the error already
+    /// points to a node. The innermost node is the one that failed.
+    pub fn with_plan_node(self, node: &str) -> Self {
+        self.update_context(|ctx| {
+            ctx.plan_node.get_or_insert_with(|| node.to_string());
+        })
+    }
 }
 
 impl From<ArrowError> for PolarsError {
@@ -44,7 +204,7 @@ impl From<polars_arrow::error::PolarsError> for PolarsError {
     }
 }
 
-#[cfg(any(feature = "strings", feature = "temporal"))]
+#[cfg(feature = "strings")]
 impl From<regex::Error> for PolarsError {
     fn from(err: regex::Error) -> Self {
         PolarsError::ComputeError(format!("regex error: {:?}", err).into())
@@ -11,7 +11,7 @@ pub use once_cell;
 #[cfg(feature = "private")]
 pub use rayon;
 #[cfg(feature = "private")]
-#[cfg(any(feature = "strings", feature = "temporal"))]
+#[cfg(feature = "strings")]
 pub use regex;
 
 pub use crate::vector_hasher::_boost_hash_combine;
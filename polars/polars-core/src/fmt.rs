@@ -1,4 +1,4 @@
-use crate::config::{FMT_MAX_COLS, FMT_MAX_ROWS, FMT_STR_LEN};
+use crate::config::FmtConfig;
 use crate::prelude::*;
 
 #[cfg(feature = "timezones")]
@@ -34,19 +34,13 @@ macro_rules! format_array {
             $name,
             $dtype
         )?;
+        let config = FmtConfig::current();
         let truncate = matches!($a.dtype(), DataType::Utf8);
-        let truncate_len = if truncate {
-            std::env::var(FMT_STR_LEN)
-                .as_deref()
-                .unwrap_or("")
-                .parse()
-                .unwrap_or(15)
-        } else {
-            15
-        };
-        let limit = std::cmp::min(LIMIT, $a.len());
+        let truncate_len = config.str_len.unwrap_or(15);
+        let limit = std::cmp::min(config.max_rows.unwrap_or(LIMIT), $a.len());
 
-        let write_fn = |v, f: &mut Formatter| {
+        let write_fn = |v: AnyValue, f: &mut Formatter| {
+            let v = AnyValueFmt(&v, &config);
             if truncate {
                 let v = format!("{}", v);
                 let v_trunc = &v[..v
@@ -278,7 +272,7 @@ impl Debug for DataFrame {
     }
 }
 #[cfg(feature = "fmt")]
-fn make_str_val(v: &str, truncate: usize) -> String {
+pub(crate) fn make_str_val(v: &str, truncate: usize) -> String {
     let v_trunc = &v[..v
         .char_indices()
         .take(truncate)
@@ -292,6 +286,18 @@ fn make_str_val(v: &str, truncate: usize) -> String {
     }
 }
 
+/// Like [`Series::str_value`], but formats with the options of the running render.
+#[cfg(feature = "fmt")]
+fn str_value<'a>(s: &'a Series, index: usize, config: &FmtConfig) -> Cow<'a, str> {
+    match s.get(index) {
+        AnyValue::Utf8(s) => Cow::Borrowed(s),
+        AnyValue::Null => Cow::Borrowed("null"),
+        #[cfg(feature = "dtype-categorical")]
+        AnyValue::Categorical(idx, rev) => Cow::Borrowed(rev.get(idx)),
+        av => Cow::Owned(AnyValueFmt(&av, config).to_string()),
+    }
+}
+
 #[cfg(feature = "fmt")]
 fn prepare_row(
     row: Vec<Cow<'_, str>>,
@@ -323,30 +329,10 @@ impl Display for DataFrame {
                 "The columns lengths in the DataFrame are not equal."
             );
 
-            let str_truncate = std::env::var(FMT_STR_LEN)
-                .as_deref()
-                .unwrap_or("")
-                .parse()
-                .unwrap_or(32);
-
-            let max_n_cols = std::env::var(FMT_MAX_COLS)
-                .as_deref()
-                .unwrap_or("")
-                .parse()
-                .unwrap_or(8);
-
-            let max_n_rows = {
-                let max_n_rows = std::env::var(FMT_MAX_ROWS)
-                    .as_deref()
-                    .unwrap_or("")
-                    .parse()
-                    .unwrap_or(8);
-                if max_n_rows < 2 {
-                    2
-                } else {
-                    max_n_rows
-                }
-            };
+            let config = FmtConfig::current();
+            let str_truncate = config.str_len.unwrap_or(32);
+            let max_n_cols = config.max_cols.unwrap_or(8);
+            let max_n_rows = std::cmp::max(config.max_rows.unwrap_or(8), 2);
             let (n_first, n_last) = if self.width() > max_n_cols {
                 ((max_n_cols + 1) / 2, max_n_cols / 2)
             } else {
@@ -382,7 +368,7 @@ impl Display for DataFrame {
                 constraints.push(tbl_lower_bounds(l));
             }
             let mut table = Table::new();
-            let preset = if std::env::var("POLARS_FMT_NO_UTF8").is_ok() {
+            let preset = if config.ascii_tables {
                 ASCII_FULL
             } else {
                 UTF8_FULL
@@ -395,13 +381,21 @@ impl Display for DataFrame {
             let mut rows = Vec::with_capacity(max_n_rows);
             if self.height() > max_n_rows {
                 for i in 0..(max_n_rows / 2) {
-                    let row = self.columns.iter().map(|s| s.str_value(i)).collect();
+                    let row = self
+                        .columns
+                        .iter()
+                        .map(|s| str_value(s, i, &config))
+                        .collect();
                     rows.push(prepare_row(row, n_first, n_last, str_truncate));
                 }
                 let dots = rows[0].iter().map(|_| "...".to_string()).collect();
                 rows.push(dots);
                 for i in (self.height() - (max_n_rows + 1) / 2)..self.height() {
-                    let row = self.columns.iter().map(|s| s.str_value(i)).collect();
+                    let row = self
+                        .columns
+                        .iter()
+                        .map(|s| str_value(s, i, &config))
+                        .collect();
                     rows.push(prepare_row(row, n_first, n_last, str_truncate));
                 }
                 for row in rows {
@@ -410,7 +404,11 @@ impl Display for DataFrame {
             } else {
                 for i in 0..self.height() {
                     if self.width() > 0 {
-                        let row = self.columns.iter().map(|s| s.str_value(i)).collect();
+                        let row = self
+                            .columns
+                            .iter()
+                            .map(|s| str_value(s, i, &config))
+                            .collect();
                         table.add_row(prepare_row(row, n_first, n_last, str_truncate));
                     } else {
                         break;
@@ -420,16 +418,8 @@ impl Display for DataFrame {
 
             table.set_header(names).set_constraints(constraints);
 
-            let tbl_width = std::env::var("POLARS_TABLE_WIDTH")
-                .map(|s| {
-                    Some(
-                        s.parse::<u16>()
-                            .expect("could not parse table width argument"),
-                    )
-                })
-                .unwrap_or(None);
             // if tbl_width is explicitly set, use it
-            if let Some(w) = tbl_width {
+            if let Some(w) = config.table_width {
                 table.set_table_width(w);
             }
 
@@ -455,17 +445,97 @@ impl Display for DataFrame {
     }
 }
 
+/// Insert `sep` between every group of three digits of the integer part of a formatted number.
+fn fmt_thousands(s: &str, sep: char) -> String {
+    let s = s.trim_start();
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => ("-", s),
+        None => ("", s),
+    };
+    let int_len = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (int_part, rest) = s.split_at(int_len);
+
+    let mut out = String::with_capacity(s.len() + int_len / 3 + 1);
+    out.push_str(sign);
+    for (i, c) in int_part.chars().enumerate() {
+        if i > 0 && (int_len - i) % 3 == 0 {
+            out.push(sep);
+        }
+        out.push(c);
+    }
+    out.push_str(rest);
+    out
+}
+
 fn fmt_integer<T: Num + NumCast + Display>(
     f: &mut Formatter<'_>,
     width: usize,
     v: T,
+    config: &FmtConfig,
 ) -> fmt::Result {
-    write!(f, "{:>width$}", v, width = width)
+    match config.thousands_separator {
+        Some(sep) => write!(
+            f,
+            "{:>width$}",
+            fmt_thousands(&v.to_string(), sep),
+            width = width
+        ),
+        None => write!(f, "{:>width$}", v, width = width),
+    }
 }
 
-const SCIENTIFIC_BOUND: f64 = 999999.0;
-fn fmt_float<T: Num + NumCast>(f: &mut Formatter<'_>, width: usize, v: T) -> fmt::Result {
+fn fmt_float<T: Num + NumCast>(
+    f: &mut Formatter<'_>,
+    width: usize,
+    v: T,
+    config: &FmtConfig,
+) -> fmt::Result {
     let v: f64 = NumCast::from(v).unwrap();
+    let precision = config.float_precision;
+    match config.thousands_separator {
+        Some(sep) => {
+            let s = FloatFmt(v, precision).to_string();
+            write!(f, "{:>width$}", fmt_thousands(&s, sep), width = width)
+        }
+        None => fmt_float_impl(f, width, v, precision),
+    }
+}
+
+struct FloatFmt(f64, Option<usize>);
+
+impl Display for FloatFmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_float_impl(f, 0, self.0, self.1)
+    }
+}
+
+const SCIENTIFIC_BOUND: f64 = 999999.0;
+fn fmt_float_impl(
+    f: &mut Formatter<'_>,
+    width: usize,
+    v: f64,
+    precision: Option<usize>,
+) -> fmt::Result {
+    if let Some(precision) = precision {
+        // large and small floats in scientific notation
+        return if v != 0.0 && v.is_finite() && !(0.000001..=SCIENTIFIC_BOUND).contains(&v.abs()) {
+            write!(
+                f,
+                "{:>width$.precision$e}",
+                v,
+                width = width,
+                precision = precision
+            )
+        } else {
+            write!(
+                f,
+                "{:>width$.precision$}",
+                v,
+                width = width,
+                precision = precision
+            )
+        };
+    }
     // show integers as 0.0, 1.0 ... 101.0
     if v.fract() == 0.0 && v.abs() < SCIENTIFIC_BOUND {
         write!(f, "{:>width$.1}", v, width = width)
@@ -576,19 +646,29 @@ fn format_duration(f: &mut Formatter, v: i64, sizes: &[i64], names: &[&str]) ->
 
 impl Display for AnyValue<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        AnyValueFmt(self, &FmtConfig::current()).fmt(f)
+    }
+}
+
+/// Formats an [`AnyValue`] with options that are resolved once for all values of a render.
+struct AnyValueFmt<'a, 'b>(&'a AnyValue<'b>, &'a FmtConfig);
+
+impl Display for AnyValueFmt<'_, '_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let AnyValueFmt(value, config) = *self;
         let width = 0;
-        match self {
+        match value {
             AnyValue::Null => write!(f, "null"),
-            AnyValue::UInt8(v) => write!(f, "{}", v),
-            AnyValue::UInt16(v) => write!(f, "{}", v),
-            AnyValue::UInt32(v) => write!(f, "{}", v),
-            AnyValue::UInt64(v) => write!(f, "{}", v),
-            AnyValue::Int8(v) => fmt_integer(f, width, *v),
-            AnyValue::Int16(v) => fmt_integer(f, width, *v),
-            AnyValue::Int32(v) => fmt_integer(f, width, *v),
-            AnyValue::Int64(v) => fmt_integer(f, width, *v),
-            AnyValue::Float32(v) => fmt_float(f, width, *v),
-            AnyValue::Float64(v) => fmt_float(f, width, *v),
+            AnyValue::UInt8(v) => fmt_integer(f, width, *v, config),
+            AnyValue::UInt16(v) => fmt_integer(f, width, *v, config),
+            AnyValue::UInt32(v) => fmt_integer(f, width, *v, config),
+            AnyValue::UInt64(v) => fmt_integer(f, width, *v, config),
+            AnyValue::Int8(v) => fmt_integer(f, width, *v, config),
+            AnyValue::Int16(v) => fmt_integer(f, width, *v, config),
+            AnyValue::Int32(v) => fmt_integer(f, width, *v, config),
+            AnyValue::Int64(v) => fmt_integer(f, width, *v, config),
+            AnyValue::Float32(v) => fmt_float(f, width, *v, config),
+            AnyValue::Float64(v) => fmt_float(f, width, *v, config),
             AnyValue::Boolean(v) => write!(f, "{}", *v),
             AnyValue::Utf8(v) => write!(f, "{}", format_args!("\"{}\"", v)),
             AnyValue::Utf8Owned(v) => write!(f, "{}", format_args!("\"{}\"", v)),
@@ -625,7 +705,7 @@ impl Display for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(_) => {
-                let nt: chrono::NaiveTime = self.into();
+                let nt: chrono::NaiveTime = value.into();
                 write!(f, "{}", nt)
             }
             #[cfg(feature = "dtype-categorical")]
@@ -637,22 +717,22 @@ impl Display for AnyValue<'_> {
             #[cfg(feature = "object")]
             AnyValue::Object(v) => write!(f, "{}", v),
             #[cfg(feature = "dtype-struct")]
-            AnyValue::Struct(vals, _) => fmt_struct(f, vals),
+            AnyValue::Struct(vals, _) => fmt_struct(f, vals, config),
             #[cfg(feature = "dtype-struct")]
-            AnyValue::StructOwned(payload) => fmt_struct(f, &payload.0),
+            AnyValue::StructOwned(payload) => fmt_struct(f, &payload.0, config),
         }
     }
 }
 
 #[cfg(feature = "dtype-struct")]
-fn fmt_struct(f: &mut Formatter<'_>, vals: &[AnyValue]) -> fmt::Result {
+fn fmt_struct(f: &mut Formatter<'_>, vals: &[AnyValue], config: &FmtConfig) -> fmt::Result {
     write!(f, "{{")?;
     if !vals.is_empty() {
         for v in &vals[..vals.len() - 1] {
-            write!(f, "{},", v)?;
+            write!(f, "{},", AnyValueFmt(v, config))?;
         }
         // last value has no trailing comma
-        write!(f, "{}", vals[vals.len() - 1])?;
+        write!(f, "{}", AnyValueFmt(&vals[vals.len() - 1], config))?;
     }
     write!(f, "}}")
 }
@@ -841,6 +921,35 @@ ChunkedArray: 'name' [str]
         );
     }
 
+    #[test]
+    fn test_fmt_numeric_options() {
+        use super::{fmt_thousands, FloatFmt};
+        use crate::config::FmtConfig;
+
+        assert_eq!(fmt_thousands("1234567", ','), "1,234,567");
+        assert_eq!(fmt_thousands("-123456.789", '_'), "-123_456.789");
+        assert_eq!(fmt_thousands("999", ','), "999");
+        assert_eq!(fmt_thousands("NaN", ','), "NaN");
+
+        assert_eq!(FloatFmt(1.23456, Some(2)).to_string(), "1.23");
+        assert_eq!(FloatFmt(1.0, Some(3)).to_string(), "1.000");
+        assert_eq!(FloatFmt(12345678.9, Some(2)).to_string(), "1.23e7");
+        assert_eq!(FloatFmt(1.0, None).to_string(), "1.0");
+
+        let s = Series::new("a", &[1234.5f64]);
+        {
+            let _guard = FmtConfig {
+                float_precision: Some(2),
+                thousands_separator: Some(','),
+                ..Default::default()
+            }
+            .scoped();
+            assert_eq!(format!("{}", s.get(0)), "1,234.50");
+        }
+        // the options are restored when the guard is dropped
+        assert_eq!(format!("{}", s.get(0)), "1234.5");
+    }
+
     #[test]
     fn test_fmt_series() {
         let s = Series::new("foo", &["Somelongstringto eeat wit me oundaf"]);
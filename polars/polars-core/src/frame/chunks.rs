@@ -12,9 +12,81 @@ impl std::convert::TryFrom<(ArrowChunk, &[ArrowField])> for DataFrame {
             .columns()
             .iter()
             .zip(arg.1)
-            .map(|(arr, field)| Series::try_from((field.name.as_ref(), arr.clone())))
+            .map(|(arr, field)| Series::try_from((field, arr.clone())))
             .collect();
 
         DataFrame::new(columns?)
     }
 }
+
+/// Statistics of the chunks of the columns of a [`DataFrame`].
+///
+/// Many small chunks slow down the kernels that operate on them, see
+/// [`DataFrame::rechunk_if_fragmented`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChunkStats {
+    /// The least number of chunks of a column.
+    pub min_chunks: usize,
+    /// The largest number of chunks of a column.
+    pub max_chunks: usize,
+    /// The number of chunks of all columns together.
+    pub total_chunks: usize,
+    /// The length of the smallest chunk.
+    pub min_chunk_len: usize,
+    /// Whether all columns have the same chunk lengths.
+    pub aligned: bool,
+}
+
+impl DataFrame {
+    /// Get statistics of the chunks of all columns, where [`DataFrame::n_chunks`] only
+    /// considers the first column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut df = df!("a" => &[1, 2, 3])?;
+    /// df.vstack_mut(&df.clone())?;
+    /// df.with_column(Series::new("b", &[1, 2, 3, 4, 5, 6]))?;
+    ///
+    /// let stats = df.chunk_stats();
+    /// assert_eq!((stats.min_chunks, stats.max_chunks, stats.total_chunks), (1, 2, 3));
+    /// assert_eq!(stats.min_chunk_len, 3);
+    /// assert!(!stats.aligned);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn chunk_stats(&self) -> ChunkStats {
+        if self.columns.is_empty() {
+            return ChunkStats {
+                aligned: true,
+                ..Default::default()
+            };
+        }
+        let n_chunks = self.columns.iter().map(|s| s.n_chunks());
+        ChunkStats {
+            min_chunks: n_chunks.clone().min().unwrap(),
+            max_chunks: n_chunks.clone().max().unwrap(),
+            total_chunks: n_chunks.sum(),
+            min_chunk_len: self
+                .columns
+                .iter()
+                .flat_map(|s| s.chunk_lengths())
+                .min()
+                .unwrap_or(0),
+            aligned: !self.should_rechunk(),
+        }
+    }
+
+    /// Rechunk all columns to a single chunk if any column consists of more than `threshold`
+    /// chunks. Returns whether the `DataFrame` was rechunked.
+    ///
+    /// Rechunking copies the data, but this pays off if the `DataFrame` is used by multiple
+    /// operations, as their kernels run per chunk and need aligned chunks.
+    pub fn rechunk_if_fragmented(&mut self, threshold: usize) -> bool {
+        let fragmented = self.columns.iter().any(|s| s.n_chunks() > threshold);
+        if fragmented {
+            self.as_single_chunk_par();
+        }
+        fragmented
+    }
+}
@@ -8,7 +8,18 @@ use serde::{Deserialize, Serialize};
 
 fn get_exploded(series: &Series) -> Result<(Series, Buffer<i64>)> {
     match series.dtype() {
-        DataType::List(_) => series.list().unwrap().explode_and_offsets(),
+        DataType::List(_) => {
+            let ca = series.list().unwrap();
+            let (exploded, offsets) = ca.explode_and_offsets()?;
+            if offsets.is_empty() && !ca.is_empty() {
+                // all lists are empty, they explode to nulls, like the empty lists among other lists
+                let offsets = vec![0i64; ca.len() + 1].into();
+                let exploded = Series::full_null(ca.name(), ca.len(), &ca.inner_dtype());
+                Ok((exploded, offsets))
+            } else {
+                Ok((exploded, offsets))
+            }
+        }
         DataType::Utf8 => series.utf8().unwrap().explode_and_offsets(),
         _ => Err(PolarsError::InvalidOperation(
             format!("cannot explode dtype: {:?}", series.dtype()).into(),
@@ -16,6 +27,39 @@ fn get_exploded(series: &Series) -> Result<(Series, Buffer<i64>)> {
     }
 }
 
+/// Options for [`DataFrame::explode_with_options`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExplodeOptions {
+    /// Explode empty lists and nulls to a single null row. If `false` these rows are dropped.
+    pub empty_as_null: bool,
+}
+
+impl Default for ExplodeOptions {
+    fn default() -> Self {
+        Self {
+            empty_as_null: true,
+        }
+    }
+}
+
+/// Check if two offsets buffers have the same number of elements in every row.
+fn same_row_lengths(a: &[i64], b: &[i64]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(oa, ob)| oa - a[0] == ob - b[0])
+}
+
+/// The rows of an exploded column that are not created by an empty list or null.
+fn non_empty_mask(offsets: &[i64], len: usize) -> BooleanChunked {
+    let mut mask = Vec::with_capacity(len);
+    for w in offsets.windows(2) {
+        match (w[1] - w[0]) as usize {
+            0 => mask.push(false),
+            n => mask.resize(mask.len() + n, true),
+        }
+    }
+    BooleanChunked::from_slice("", &mask)
+}
+
 /// Arguments for `[DataFrame::melt]` function
 #[derive(Clone, Default, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -27,7 +71,15 @@ pub struct MeltArgs {
 }
 
 impl DataFrame {
-    pub fn explode_impl(&self, mut columns: Vec<Series>) -> Result<DataFrame> {
+    pub fn explode_impl(&self, columns: Vec<Series>) -> Result<DataFrame> {
+        self.explode_impl_with_options(columns, ExplodeOptions::default())
+    }
+
+    fn explode_impl_with_options(
+        &self,
+        mut columns: Vec<Series>,
+        options: ExplodeOptions,
+    ) -> Result<DataFrame> {
         let mut df = self.clone();
         if self.height() == 0 {
             for s in &columns {
@@ -47,6 +99,7 @@ impl DataFrame {
             df = df.drop(s.name())?;
         }
 
+        let mut first_offsets = None;
         for (i, s) in columns.iter().enumerate() {
             // Safety:
             // offsets don't have indices exceeding Series length.
@@ -62,6 +115,16 @@ impl DataFrame {
                     // Safety
                     // We just created indices that are in bounds.
                     df = unsafe { df.take_unchecked(&row_idx) };
+                    first_offsets = Some(offsets);
+                } else if !same_row_lengths(first_offsets.as_ref().unwrap(), &offsets) {
+                    return Err(PolarsError::ShapeMisMatch(
+                        format!(
+                            "exploded columns must have matching element counts, but {} doesn't match {}",
+                            s.name(),
+                            columns[0].name()
+                        )
+                        .into(),
+                    ));
                 }
                 if exploded.len() == df.height() || df.width() == 0 {
                     df.columns.insert(col_idx, exploded);
@@ -76,7 +139,12 @@ impl DataFrame {
                 ));
             }
         }
-        Ok(df)
+        match first_offsets {
+            Some(offsets) if !options.empty_as_null => {
+                df.filter(&non_empty_mask(&offsets, df.height()))
+            }
+            _ => Ok(df),
+        }
     }
     /// Explode `DataFrame` to long format by exploding a column with Lists.
     ///
@@ -138,6 +206,22 @@ impl DataFrame {
     ///  +-----+-----+-----+
     /// ```
     pub fn explode<I, S>(&self, columns: I) -> Result<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.explode_with_options(columns, ExplodeOptions::default())
+    }
+
+    /// Explode `DataFrame` to long format by exploding columns with Lists.
+    ///
+    /// Multiple columns are exploded in lockstep, they should have the same number of elements in
+    /// every row. `options` determine if empty lists and nulls explode to a null row or are dropped.
+    pub fn explode_with_options<I, S>(
+        &self,
+        columns: I,
+        options: ExplodeOptions,
+    ) -> Result<DataFrame>
     where
         I: IntoIterator<Item = S>,
         S: AsRef<str>,
@@ -145,7 +229,7 @@ impl DataFrame {
         // We need to sort the column by order of original occurrence. Otherwise the insert by index
         // below will panic
         let columns = self.select_series(columns)?;
-        self.explode_impl(columns)
+        self.explode_impl_with_options(columns, options)
     }
 
     ///
@@ -394,6 +478,46 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_explode_multiple_with_options() -> Result<()> {
+        let s0 = Series::new("", &[1i32, 2]);
+        let s1 = Series::new("", &[3i32]);
+        let empty = s1.slice(0, 0);
+        let a = Series::new("a", &[s0.clone(), empty.clone(), s1.clone()]);
+        let b = Series::new("b", &[s1.clone(), empty.clone(), s0.clone()]);
+        let c = Series::new("c", &[s0.clone(), empty.clone(), s1.clone()]);
+        let id = Series::new("id", &[1, 2, 3]);
+        let df = DataFrame::new(vec![id, a, b, c])?;
+
+        // same total length, but not in every row
+        assert!(df.explode(["a", "b"]).is_err());
+
+        let out = df.explode(["a", "c"])?;
+        let expected = df![
+            "id" => [1, 1, 2, 3],
+            "a" => [Some(1), Some(2), None, Some(3)],
+            "c" => [Some(1), Some(2), None, Some(3)],
+        ]?;
+        assert!(out.select(["id", "a", "c"])?.frame_equal_missing(&expected));
+
+        let options = ExplodeOptions {
+            empty_as_null: false,
+        };
+        let out = df.explode_with_options(["a", "c"], options)?;
+        assert_eq!(
+            Vec::from(out.column("id")?.i32()?),
+            &[Some(1), Some(1), Some(3)]
+        );
+        assert_eq!(out.column("a")?.null_count(), 0);
+
+        // all lists empty
+        let df = DataFrame::new(vec![Series::new("a", &[empty.clone(), empty])])?;
+        assert_eq!(df.explode(["a"])?.shape(), (2, 1));
+        assert_eq!(df.explode_with_options(["a"], options)?.shape(), (0, 1));
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_melt() -> Result<()> {
@@ -0,0 +1,157 @@
+//! Summarize a [`DataFrame`] with a line per column, for frames that are too wide for a table.
+use crate::fmt::make_str_val;
+use crate::prelude::*;
+use std::fmt::Write;
+
+/// Options of [`DataFrame::glimpse`] and [`DataFrame::glimpse_df`].
+#[derive(Clone, Debug)]
+pub struct GlimpseOptions {
+    /// Number of values shown per column, counted from the first row.
+    pub n_values: usize,
+    /// Maximum number of characters per value.
+    pub str_len: usize,
+}
+
+impl Default for GlimpseOptions {
+    fn default() -> Self {
+        GlimpseOptions {
+            n_values: 5,
+            str_len: 20,
+        }
+    }
+}
+
+/// The summary of a single column.
+struct ColumnSummary {
+    name: String,
+    dtype: String,
+    null_count: usize,
+    values: String,
+}
+
+impl ColumnSummary {
+    fn new(s: &Series, options: &GlimpseOptions) -> Self {
+        let n = std::cmp::min(options.n_values, s.len());
+        let mut values = (0..n)
+            .map(|i| match s.get(i) {
+                AnyValue::Null => "null".to_string(),
+                // quote strings, so that a separator in a value is not mistaken for the next one
+                AnyValue::Utf8(v) => format!("\"{}\"", make_str_val(v, options.str_len)),
+                _ => make_str_val(&s.str_value(i), options.str_len),
+            })
+            .collect::<Vec<_>>();
+        if n < s.len() {
+            values.push("...".to_string());
+        }
+        ColumnSummary {
+            name: s.name().to_string(),
+            dtype: s.dtype().to_string(),
+            null_count: s.null_count(),
+            values: values.join(", "),
+        }
+    }
+}
+
+impl DataFrame {
+    fn column_summaries(&self, options: &GlimpseOptions) -> Vec<ColumnSummary> {
+        self.get_columns()
+            .iter()
+            .map(|s| ColumnSummary::new(s, options))
+            .collect()
+    }
+
+    /// Summarize the `DataFrame` with a line per column: its name, dtype, null count and
+    /// first values. Unlike the table display, this shows every column of a wide frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [Some(1), None], "bb" => ["x", "y"]).unwrap();
+    /// assert_eq!(
+    ///     df.glimpse(&Default::default()),
+    ///     "Rows: 2\nColumns: 2\n$ a  <i32> [nulls: 1]  1, null\n$ bb <str> [nulls: 0]  \"x\", \"y\"\n"
+    /// );
+    /// ```
+    pub fn glimpse(&self, options: &GlimpseOptions) -> String {
+        let summaries = self.column_summaries(options);
+        let name_width = summaries.iter().map(|c| c.name.len()).max().unwrap_or(0);
+        let dtype_width = summaries.iter().map(|c| c.dtype.len()).max().unwrap_or(0);
+        let nulls = summaries
+            .iter()
+            .map(|c| format!("[nulls: {}]", c.null_count))
+            .collect::<Vec<_>>();
+        let nulls_width = nulls.iter().map(|v| v.len()).max().unwrap_or(0);
+
+        let mut out = String::new();
+        // writing to a string cannot fail
+        writeln!(out, "Rows: {}", self.height()).unwrap();
+        writeln!(out, "Columns: {}", self.width()).unwrap();
+        for (c, nulls) in summaries.iter().zip(&nulls) {
+            writeln!(
+                out,
+                "$ {:name_width$} {:dtype_width$} {:nulls_width$}  {}",
+                c.name,
+                format!("<{}>", c.dtype),
+                nulls,
+                c.values,
+                name_width = name_width,
+                dtype_width = dtype_width + 2,
+                nulls_width = nulls_width,
+            )
+            .unwrap();
+        }
+        out
+    }
+
+    /// Summarize the `DataFrame` like [`DataFrame::glimpse`], but as a `DataFrame` with a row
+    /// per column and the columns `column`, `dtype`, `null_count` and `values`.
+    pub fn glimpse_df(&self, options: &GlimpseOptions) -> Result<DataFrame> {
+        let summaries = self.column_summaries(options);
+        let strings = |name: &str, f: fn(&ColumnSummary) -> &str| {
+            Series::new(name, summaries.iter().map(f).collect::<Vec<_>>())
+        };
+        let null_count = summaries
+            .iter()
+            .map(|c| c.null_count as IdxSize)
+            .collect::<Vec<_>>();
+        DataFrame::new(vec![
+            strings("column", |c| &c.name),
+            strings("dtype", |c| &c.dtype),
+            IdxCa::from_vec("null_count", null_count).into_series(),
+            strings("values", |c| &c.values),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_glimpse() -> Result<()> {
+        let df = df!(
+            "a" => (0..10).collect::<Vec<i32>>(),
+            "long_name" => (0..10).map(|i| format!("value_{}", i)).collect::<Vec<_>>()
+        )?;
+        let options = GlimpseOptions {
+            n_values: 2,
+            str_len: 5,
+        };
+        assert_eq!(
+            df.glimpse(&options),
+            "Rows: 10\nColumns: 2\n\
+             $ a         <i32> [nulls: 0]  0, 1, ...\n\
+             $ long_name <str> [nulls: 0]  \"value...\", \"value...\", ...\n"
+        );
+
+        let out = df.glimpse_df(&options)?;
+        assert_eq!(out.shape(), (2, 4));
+        assert_eq!(
+            Vec::from(out.column("dtype")?.utf8()?),
+            &[Some("i32"), Some("str")]
+        );
+        assert_eq!(out.column("values")?.utf8()?.get(0), Some("0, 1, ..."));
+        Ok(())
+    }
+}
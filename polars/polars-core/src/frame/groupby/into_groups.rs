@@ -142,7 +142,13 @@ where
 {
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> GroupsProxy {
         // sorted path
-        if self.is_sorted() || self.is_sorted_reverse() && self.chunks().len() == 1 {
+        if self.is_sorted() || self.is_sorted_reverse() {
+            // the sorted path works on a single chunk
+            if self.chunks().len() > 1 {
+                let mut ca = self.rechunk();
+                ca.set_sorted2(self.is_sorted2());
+                return ca.group_tuples(multithreaded, sorted);
+            }
             // don't have to pass `sorted` arg, GroupSlice is always sorted.
             return GroupsProxy::Slice {
                 groups: self.create_groups_from_sorted(multithreaded),
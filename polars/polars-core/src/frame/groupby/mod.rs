@@ -1,9 +1,10 @@
 use self::hashing::*;
 use crate::prelude::*;
+use crate::series::IsSorted;
 #[cfg(feature = "groupby_list")]
 use crate::utils::Wrap;
 use crate::utils::{accumulate_dataframes_vertical, set_partition_size, split_offsets};
-use crate::vector_hasher::{get_null_hash_value, AsU64, StrHash};
+use crate::vector_hasher::{df_rows_to_hashes, get_null_hash_value, AsU64, StrHash};
 use crate::POOL;
 use ahash::{CallHasher, RandomState};
 use num::NumCast;
@@ -50,6 +51,105 @@ fn prepare_dataframe_unsorted(by: &[Series]) -> DataFrame {
     )
 }
 
+/// Mark the rows where a new run of equal values starts.
+fn mark_run_starts<T: PartialEq>(values: impl Iterator<Item = T>, is_start: &mut [bool]) {
+    let mut prev = None;
+    for (v, is_start) in values.zip(is_start.iter_mut()) {
+        if prev.as_ref() != Some(&v) {
+            *is_start = true;
+        }
+        prev = Some(v);
+    }
+}
+
+/// Create the groups of multiple keys that are all sorted.
+/// Equal keys are then stored contiguously, so we only have to find the rows where one of
+/// the keys changes. Returns `None` if the physical type of a key is not supported.
+fn groups_from_sorted_keys(keys_df: &DataFrame) -> Option<GroupsProxy> {
+    if std::env::var("POLARS_VERBOSE").is_ok() {
+        eprintln!("groupby keys are sorted; running sorted multiple keys fast path");
+    }
+    let mut is_start = vec![false; keys_df.height()];
+    for s in keys_df.get_columns() {
+        match s.dtype() {
+            DataType::UInt32 => mark_run_starts(s.u32().unwrap().into_iter(), &mut is_start),
+            DataType::UInt64 => mark_run_starts(s.u64().unwrap().into_iter(), &mut is_start),
+            DataType::Utf8 => mark_run_starts(s.utf8().unwrap().into_iter(), &mut is_start),
+            DataType::Boolean => mark_run_starts(s.bool().unwrap().into_iter(), &mut is_start),
+            _ => return None,
+        }
+    }
+
+    let starts = is_start
+        .iter()
+        .enumerate()
+        .filter_map(|(i, is_start)| is_start.then_some(i as IdxSize))
+        .collect::<Vec<_>>();
+    let ends = starts
+        .iter()
+        .skip(1)
+        .copied()
+        .chain(std::iter::once(keys_df.height() as IdxSize));
+    let groups = starts
+        .iter()
+        .zip(ends)
+        .map(|(first, end)| [*first, end - first])
+        .collect();
+    Some(GroupsProxy::Slice {
+        groups,
+        rolling: false,
+    })
+}
+
+/// The name of the key of [`_null_group_key`].
+#[doc(hidden)]
+pub const _NULL_GROUP_KEY: &str = "__POLARS_NULL_GROUP";
+
+/// A key that, grouped on together with the `keys`, puts every row of which a key is null in
+/// a group of its own.
+#[doc(hidden)]
+pub fn _null_group_key(keys: &[Series]) -> Series {
+    let len = keys.first().map_or(0, |s| s.len());
+    let null_rows = keys
+        .iter()
+        .filter(|s| s.null_count() > 0)
+        .map(|s| s.is_null())
+        .reduce(|acc, is_null| &acc | &is_null);
+    let key = match null_rows {
+        Some(null_rows) => null_rows
+            .into_iter()
+            .enumerate()
+            .map(|(i, is_null)| match is_null {
+                Some(true) => i as IdxSize + 1,
+                _ => 0,
+            })
+            .collect(),
+        None => vec![0; len],
+    };
+    IdxCa::from_vec(_NULL_GROUP_KEY, key).into_series()
+}
+
+/// Split the row indices in `n_partitions` partitions by the hash of the `keys`.
+/// Equal keys always end up in the same partition, so that the partitions can be
+/// aggregated independently.
+#[doc(hidden)]
+pub fn _hash_partition_indices(keys: &[Series], n_partitions: usize) -> Vec<IdxCa> {
+    let keys_df = DataFrame::new_no_checks(keys.to_vec());
+    let (hashes, _) = df_rows_to_hashes(&keys_df, None);
+
+    let capacity = keys_df.height() / n_partitions + 1;
+    let mut partitions = (0..n_partitions)
+        .map(|_| Vec::with_capacity(capacity))
+        .collect::<Vec<_>>();
+    for (idx, h) in hashes.into_no_null_iter().enumerate() {
+        partitions[(h % n_partitions as u64) as usize].push(idx as IdxSize);
+    }
+    partitions
+        .into_iter()
+        .map(|idx| IdxCa::from_vec("", idx))
+        .collect()
+}
+
 impl DataFrame {
     pub fn groupby_with_series(
         &self,
@@ -104,6 +204,14 @@ impl DataFrame {
             ));
         };
 
+        // if all keys are sorted, equal keys are contiguous and we don't need to hash
+        if by.len() > 1 && by.iter().all(|s| s.is_sorted() != IsSorted::Not) {
+            let keys_df = prepare_dataframe_unsorted(&by);
+            if let Some(groups) = groups_from_sorted_keys(&keys_df) {
+                return Ok(GroupBy::new(self, by, groups, None));
+            }
+        }
+
         let n_partitions = set_partition_size();
 
         let groups = match by.len() {
@@ -169,7 +277,8 @@ impl DataFrame {
         Ok(GroupBy::new(self, by, groups, None))
     }
 
-    /// Group DataFrame using a Series column.
+    /// Group DataFrame using a Series column. The order of the groups is not stable, unless
+    /// the [deterministic mode](crate::config::deterministic) is on.
     ///
     /// # Example
     ///
@@ -187,7 +296,34 @@ impl DataFrame {
         S: AsRef<str>,
     {
         let selected_keys = self.select_series(by)?;
-        self.groupby_with_series(selected_keys, true, false)
+        self.groupby_with_series(selected_keys, true, crate::config::deterministic())
+    }
+
+    /// Group DataFrame using a Series column, where `nulls_equal` determines whether the rows
+    /// with a null key form one group, as with [`DataFrame::groupby`], or a group each.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => [Some(1), None, None], "b" => [1, 2, 3])?;
+    /// assert_eq!(df.groupby_with_nulls(["a"], true)?.sum()?.height(), 2);
+    /// assert_eq!(df.groupby_with_nulls(["a"], false)?.sum()?.height(), 3);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn groupby_with_nulls<I, S>(&self, by: I, nulls_equal: bool) -> Result<GroupBy>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let selected_keys = self.select_series(by)?;
+        if nulls_equal {
+            return self.groupby_with_series(selected_keys, true, false);
+        }
+        let mut keys = selected_keys.clone();
+        keys.push(_null_group_key(&selected_keys));
+        let groups = self.groupby_with_series(keys, true, false)?.take_groups();
+        Ok(GroupBy::new(self, selected_keys, groups, None))
     }
 
     /// Group DataFrame using a Series column.
@@ -827,6 +963,55 @@ impl<'df> GroupBy<'df> {
         DataFrame::new(cols)
     }
 
+    fn slice_groups(&self, n: Option<usize>, tail: bool) -> Result<DataFrame> {
+        let (_, agg_cols) = self.prepare_agg()?;
+        let mut idx = Vec::with_capacity(match n {
+            Some(n) => std::cmp::min(n * self.groups.len(), self.df.height()),
+            None => self.df.height(),
+        });
+        for g in self.groups.iter() {
+            let len = g.len();
+            let n = std::cmp::min(n.unwrap_or(len), len);
+            let start = if tail { len - n } else { 0 };
+            match g {
+                GroupsIndicator::Idx((_, group)) => idx.extend_from_slice(&group[start..start + n]),
+                GroupsIndicator::Slice([first, _]) => {
+                    let first = first + start as IdxSize;
+                    idx.extend(first..first + n as IdxSize)
+                }
+            }
+        }
+        let idx = IdxCa::from_vec("", idx);
+        let df =
+            DataFrame::new_no_checks(self.selected_keys.iter().cloned().chain(agg_cols).collect());
+        // Safety:
+        // the group indices are in bounds
+        Ok(unsafe { df.take_unchecked(&idx) })
+    }
+
+    /// Get the first `n` rows of every group, the keys are the first columns.
+    /// The rows of a group keep their order, `None` takes all rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2, 1, 1], "b" => &[1, 2, 3, 4])?;
+    /// let out = df.groupby_stable(["a"])?.head(Some(2))?;
+    /// assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(1), Some(2)]);
+    /// assert_eq!(Vec::from(out.column("b")?.i32()?), &[Some(1), Some(3), Some(2)]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn head(&self, n: Option<usize>) -> Result<DataFrame> {
+        self.slice_groups(n, false)
+    }
+
+    /// Get the last `n` rows of every group, the keys are the first columns.
+    /// The rows of a group keep their order, `None` takes all rows.
+    pub fn tail(&self, n: Option<usize>) -> Result<DataFrame> {
+        self.slice_groups(n, true)
+    }
+
     fn prepare_apply(&self) -> Result<DataFrame> {
         if let Some(agg) = &self.selected_agg {
             if agg.is_empty() {
@@ -843,7 +1028,58 @@ impl<'df> GroupBy<'df> {
         }
     }
 
+    /// Concatenate the `DataFrame`s returned for every group, which must have the same schema.
+    fn finish_apply<F>(&self, df: &DataFrame, dfs: Vec<DataFrame>, f: F) -> Result<DataFrame>
+    where
+        F: Fn(DataFrame) -> Result<DataFrame>,
+    {
+        let first = match dfs.first() {
+            Some(first) => first,
+            // no groups, let the function determine the schema of the output
+            None => return f(df.slice(0, 0)),
+        };
+        for (i, out) in dfs.iter().enumerate().skip(1) {
+            let same_schema = out.width() == first.width()
+                && out
+                    .iter()
+                    .zip(first.iter())
+                    .all(|(l, r)| l.name() == r.name() && l.dtype() == r.dtype());
+            if !same_schema {
+                return Err(PolarsError::SchemaMisMatch(
+                    format!(
+                        "the function applied to group {} returned a DataFrame with schema {:?}, \
+                        but the first group returned a DataFrame with schema {:?}",
+                        i,
+                        out.schema(),
+                        first.schema()
+                    )
+                    .into(),
+                ));
+            }
+        }
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+        df.as_single_chunk();
+        Ok(df)
+    }
+
     /// Apply a closure over the groups as a new DataFrame in parallel.
+    ///
+    /// The `DataFrame`s returned for the groups are concatenated in the order of the groups and
+    /// must all have the same schema. Use this for logic that cannot be expressed with
+    /// expressions, as materializing a `DataFrame` per group is expensive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 1, 2], "b" => &[1.0, 2.0, 3.0])?;
+    /// let out = df
+    ///     .groupby_stable(["a"])?
+    ///     .par_apply(|df| df.tail(Some(1)).with_row_count("row", None))?;
+    /// assert_eq!(out.get_column_names(), &["row", "a", "b"]);
+    /// assert_eq!(Vec::from(out.column("b")?.f64()?), &[Some(2.0), Some(3.0)]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
     pub fn par_apply<F>(&self, f: F) -> Result<DataFrame>
     where
         F: Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
@@ -859,13 +1095,12 @@ impl<'df> GroupBy<'df> {
                 f(sub_df)
             })
             .collect::<Result<Vec<_>>>()?;
-
-        let mut df = accumulate_dataframes_vertical(dfs)?;
-        df.as_single_chunk();
-        Ok(df)
+        self.finish_apply(&df, dfs, f)
     }
 
     /// Apply a closure over the groups as a new DataFrame.
+    ///
+    /// This is the single threaded version of [`GroupBy::par_apply`].
     pub fn apply<F>(&self, f: F) -> Result<DataFrame>
     where
         F: Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
@@ -881,10 +1116,7 @@ impl<'df> GroupBy<'df> {
                 f(sub_df)
             })
             .collect::<Result<Vec<_>>>()?;
-
-        let mut df = accumulate_dataframes_vertical(dfs)?;
-        df.as_single_chunk();
-        Ok(df)
+        self.finish_apply(&df, dfs, f)
     }
 }
 
@@ -936,8 +1168,9 @@ pub fn fmt_groupby_column(name: &str, method: GroupByMethod) -> String {
 
 #[cfg(test)]
 mod test {
-    use crate::frame::groupby::{groupby, groupby_threaded_num};
+    use crate::frame::groupby::{_hash_partition_indices, groupby, groupby_threaded_num};
     use crate::prelude::*;
+    use crate::series::IsSorted;
     use crate::utils::split_ca;
     use num::traits::FloatConst;
 
@@ -1135,6 +1368,69 @@ mod test {
 
         let out = df.groupby(["a"]).unwrap().apply(Ok).unwrap();
         assert!(out.sort(["b"], false).unwrap().frame_equal(&df));
+
+        // the parallel version concatenates in the order of the groups
+        let gb = df.groupby_stable(["a"]).unwrap();
+        let out = gb.par_apply(|df| Ok(df.sum())).unwrap();
+        assert_eq!(
+            Vec::from(out.column("b").unwrap().i32().unwrap()),
+            &[Some(3), Some(12)]
+        );
+
+        // the groups must return the same schema
+        let err = gb
+            .par_apply(|df| {
+                if df.column("a")?.i32()?.get(0) == Some(1) {
+                    Ok(df)
+                } else {
+                    df.select(["b"])
+                }
+            })
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::SchemaMisMatch(_)));
+
+        // without groups the function is applied to the empty frame
+        let out = df
+            .head(Some(0))
+            .groupby(["a"])
+            .unwrap()
+            .par_apply(|df| df.select(["b"]))
+            .unwrap();
+        assert_eq!(out.shape(), (0, 1));
+    }
+
+    #[test]
+    fn test_groupby_head_tail() -> Result<()> {
+        let df = df! {
+            "a" => [1, 2, 1, 1, 2],
+            "b" => [1, 2, 3, 4, 5],
+            "c" => ["a", "b", "c", "d", "e"]
+        }?;
+        let gb = df.groupby_stable(["a"])?;
+        let out = gb.tail(Some(2))?;
+        assert_eq!(out.get_column_names(), &["a", "b", "c"]);
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(3), Some(4), Some(2), Some(5)]
+        );
+        assert!(gb.head(None)?.frame_equal(&gb.tail(Some(10))?));
+        assert_eq!(gb.head(Some(0))?.height(), 0);
+
+        let out = df.groupby_stable(["c"])?.select(["a"]).head(Some(1))?;
+        assert_eq!(out.get_column_names(), &["c", "a"]);
+        assert_eq!(out.height(), 5);
+
+        // slice groups
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 2], [2, 3]],
+            rolling: false,
+        };
+        let gb = super::GroupBy::new(&df, vec![df.column("a")?.clone()], groups, None);
+        assert_eq!(
+            Vec::from(gb.tail(Some(2))?.column("b")?.i32()?),
+            &[Some(1), Some(2), Some(4), Some(5)]
+        );
+        Ok(())
     }
 
     #[test]
@@ -1209,4 +1505,50 @@ mod test {
         let _ = df.groupby(["g"])?.sum()?;
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_groupby_sorted_keys() -> Result<()> {
+        let mut df = df![
+            "a" => [Some(1), Some(1), Some(1), Some(2), None, None],
+            "b" => ["x", "x", "y", "y", "y", "y"],
+            "c" => [1, 2, 3, 4, 5, 6]
+        ]?;
+        let expected = df.groupby_stable(["a", "b"])?.sum()?;
+
+        df.try_apply("a", |s| {
+            let mut s = s.clone();
+            s.set_sorted(IsSorted::Ascending);
+            Ok(s)
+        })?;
+        df.try_apply("b", |s| {
+            let mut s = s.clone();
+            s.set_sorted(IsSorted::Ascending);
+            Ok(s)
+        })?;
+        let gb = df.groupby(["a", "b"])?;
+        assert!(matches!(gb.get_groups(), GroupsProxy::Slice { .. }));
+        assert!(gb.sum()?.frame_equal_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_partition_indices() -> Result<()> {
+        let a = Series::new("a", [1, 2, 1, 3, 2, 1]);
+        let b = Series::new("b", ["x", "y", "x", "z", "y", "x"]);
+        let partitions = _hash_partition_indices(&[a.clone(), b], 3);
+        assert_eq!(partitions.len(), 3);
+        assert_eq!(partitions.iter().map(|idx| idx.len()).sum::<usize>(), 6);
+        let keys = partitions
+            .iter()
+            .map(|idx| Ok(Vec::from(a.take(idx)?.i32()?)))
+            .collect::<Result<Vec<_>>>()?;
+        // a key is never split over multiple partitions
+        for (i, part) in keys.iter().enumerate() {
+            for (j, other) in keys.iter().enumerate() {
+                assert!(i == j || part.iter().all(|v| !other.contains(v)));
+            }
+        }
+        Ok(())
+    }
 }
@@ -24,7 +24,8 @@ impl Drop for GroupsIdx {
         let v = std::mem::take(&mut self.all);
         // ~65k took approximately 1ms on local machine, so from that point we drop on other thread
         // to stop query from being blocked
-        if v.len() > 1 << 16 {
+        // wasm can't spawn threads
+        if v.len() > 1 << 16 && cfg!(not(target_family = "wasm")) {
             std::thread::spawn(move || drop(v));
         } else {
             drop(v);
@@ -7,6 +7,7 @@ mod single_keys_outer;
 #[cfg(feature = "semi_anti_join")]
 mod single_keys_semi_anti;
 pub(super) mod sort_merge;
+mod validate;
 
 #[cfg(feature = "chunked_ids")]
 use arrow::Either;
@@ -54,6 +55,7 @@ use crate::utils::series::to_physical_and_bit_repr;
 pub(crate) use single_keys::create_probe_table;
 #[cfg(feature = "asof_join")]
 pub(crate) use single_keys_dispatch::prepare_strs;
+pub use validate::JoinValidation;
 
 pub type LeftJoinIds = (JoinIds, JoinOptIds);
 
@@ -102,6 +104,13 @@ macro_rules! det_hash_prone_order {
 use crate::series::IsSorted;
 pub(super) use det_hash_prone_order;
 
+/// Name prefix of the join keys that are temporarily added to a `DataFrame`.
+#[doc(hidden)]
+pub const _TEMPORARY_JOIN_KEY: &str = "__POLARS_JOIN_KEY_";
+// the row numbers of the tables that order the output of a join in the deterministic mode
+const ROW_LEFT: &str = "__POLARS_JOIN_ROW_LEFT";
+const ROW_RIGHT: &str = "__POLARS_JOIN_ROW_RIGHT";
+
 /// If Categorical types are created without a global string cache or under
 /// a different global string cache the mapping will be incorrect.
 #[cfg(feature = "dtype-categorical")]
@@ -127,12 +136,27 @@ pub enum JoinType {
     #[cfg_attr(feature = "serde", serde(skip))]
     AsOf(AsOfOptions),
     Cross,
+    /// Inner join on the inequality predicates `left_on[i] <op[i]> right_on[i]`.
+    #[cfg(feature = "join_where")]
+    Inequality(Vec<InequalityOperator>),
     #[cfg(feature = "semi_anti_join")]
     Semi,
     #[cfg(feature = "semi_anti_join")]
     Anti,
 }
 
+impl JoinType {
+    /// Whether the right key columns are kept in the output, instead of being merged
+    /// with the left key columns.
+    pub fn keeps_right_keys(&self) -> bool {
+        #[cfg(feature = "join_where")]
+        if let JoinType::Inequality(_) = self {
+            return true;
+        }
+        false
+    }
+}
+
 pub(crate) unsafe fn get_hash_tbl_threaded_join_partitioned<Item>(
     h: u64,
     hash_tables: &[Item],
@@ -341,6 +365,17 @@ impl DataFrame {
         if let JoinType::Cross = how {
             return self.cross_join(other, suffix, slice);
         }
+        #[cfg(feature = "join_where")]
+        if let JoinType::Inequality(ops) = &how {
+            return self._join_where_impl(
+                other,
+                &selected_left,
+                ops,
+                &selected_right,
+                suffix,
+                slice,
+            );
+        }
 
         #[cfg(feature = "chunked_ids")]
         {
@@ -444,6 +479,10 @@ impl DataFrame {
                 JoinType::Cross => {
                     unreachable!()
                 }
+                #[cfg(feature = "join_where")]
+                JoinType::Inequality(_) => {
+                    unreachable!()
+                }
             };
         }
 
@@ -564,6 +603,10 @@ impl DataFrame {
             JoinType::Cross => {
                 unreachable!()
             }
+            #[cfg(feature = "join_where")]
+            JoinType::Inequality(_) => {
+                unreachable!()
+            }
         }
     }
 
@@ -608,26 +651,300 @@ impl DataFrame {
         how: JoinType,
         suffix: Option<String>,
     ) -> Result<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.join_with_validation(
+            other,
+            left_on,
+            right_on,
+            how,
+            suffix,
+            JoinValidation::ManyToMany,
+        )
+    }
+
+    /// Generic join method, that first checks if the join keys fulfil the expected
+    /// [`JoinValidation`]. The join fails if there are duplicate keys on a side that must be unique.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let left = df!("a" => [1, 2, 2]).unwrap();
+    /// let right = df!("a" => [1, 2, 3]).unwrap();
+    /// let join = |validate| left.join_with_validation(&right, ["a"], ["a"], JoinType::Left, None, validate);
+    /// assert!(join(JoinValidation::ManyToOne).is_ok());
+    /// assert!(join(JoinValidation::OneToOne).is_err());
+    /// ```
+    pub fn join_with_validation<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        right_on: I,
+        how: JoinType,
+        suffix: Option<String>,
+        validate: JoinValidation,
+    ) -> Result<DataFrame>
     where
         I: IntoIterator<Item = S>,
         S: AsRef<str>,
     {
         #[cfg(feature = "cross_join")]
         if let JoinType::Cross = how {
+            validate.validate(&how, &[], &[])?;
             return self.cross_join(other, suffix, None);
         }
         let selected_left = self.select_series(left_on)?;
         let selected_right = other.select_series(right_on)?;
-        self._join_impl(
-            other,
-            selected_left,
-            selected_right,
-            how,
-            suffix,
+        validate.validate(&how, &selected_left, &selected_right)?;
+        let ordered = crate::config::deterministic();
+        self._join_ordered(other, &how, None, ordered, |left, right, slice| {
+            left._join_impl(
+                right,
+                selected_left,
+                selected_right,
+                how.clone(),
+                suffix,
+                slice,
+                true,
+                false,
+            )
+        })
+    }
+
+    /// Join on keys that are not columns of the `DataFrame`s, for instance keys that are computed
+    /// from the columns. All columns of both `DataFrame`s are kept, the keys are not part of the output.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// fn join_on_seconds(left: &DataFrame, right: &DataFrame) -> Result<DataFrame> {
+    ///     let left_on = left.column("milliseconds")? / 1000;
+    ///     let right_on = right.column("seconds")?.clone();
+    ///     left.join_with_series(right, vec![left_on], vec![right_on], JoinType::Inner, None)
+    /// }
+    /// ```
+    pub fn join_with_series(
+        &self,
+        other: &DataFrame,
+        mut left_on: Vec<Series>,
+        mut right_on: Vec<Series>,
+        how: JoinType,
+        suffix: Option<String>,
+    ) -> Result<DataFrame> {
+        for (i, s) in left_on.iter_mut().enumerate() {
+            s.rename(&format!("{}LEFT_{}", _TEMPORARY_JOIN_KEY, i));
+        }
+        for (i, s) in right_on.iter_mut().enumerate() {
+            s.rename(&format!("{}RIGHT_{}", _TEMPORARY_JOIN_KEY, i));
+        }
+        let ordered = crate::config::deterministic();
+        self._join_ordered(other, &how, None, ordered, |left, right, slice| {
+            left._join_with_keys(
+                right,
+                left_on,
+                right_on,
+                how.clone(),
+                suffix,
+                slice,
+                false,
+                true,
+            )
+        })
+    }
+
+    /// Generic join method, where `nulls_equal` determines whether null keys match each other,
+    /// as with [`DataFrame::join`], or don't match any key, as in SQL. With `nulls_equal` set to
+    /// `false`, an outer join keeps the rows with a null key of both tables unmatched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let left = df!("a" => [Some(1), None])?;
+    /// let right = df!("a" => [Some(1), None], "b" => [1, 2])?;
+    /// let join = |nulls_equal| left.join_with_nulls(&right, ["a"], ["a"], JoinType::Inner, None, nulls_equal);
+    /// assert_eq!(join(true)?.height(), 2);
+    /// assert_eq!(join(false)?.height(), 1);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn join_with_nulls<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        right_on: I,
+        how: JoinType,
+        suffix: Option<String>,
+        nulls_equal: bool,
+    ) -> Result<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let selected_left = self.select_series(left_on)?;
+        let selected_right = other.select_series(right_on)?;
+        let ordered = crate::config::deterministic();
+        self._join_ordered(other, &how, None, ordered, |left, right, slice| {
+            left._join_with_keys(
+                right,
+                selected_left,
+                selected_right,
+                how.clone(),
+                suffix,
+                slice,
+                false,
+                nulls_equal,
+            )
+        })
+    }
+
+    /// Apply `join` to this and the `other` table with the rows of the output ordered by the rows
+    /// of the left and then the right table if `ordered` is set, as in the
+    /// [deterministic mode](crate::config::deterministic). Otherwise the order of the matches
+    /// depends on the hash tables and the number of threads. `join` gets the `slice` to apply.
+    #[doc(hidden)]
+    pub fn _join_ordered<F>(
+        &self,
+        other: &DataFrame,
+        how: &JoinType,
+        slice: Option<(i64, usize)>,
+        ordered: bool,
+        join: F,
+    ) -> Result<DataFrame>
+    where
+        F: FnOnce(&DataFrame, &DataFrame, Option<(i64, usize)>) -> Result<DataFrame>,
+    {
+        if !(ordered && matches!(how, JoinType::Inner | JoinType::Left | JoinType::Outer)) {
+            return join(self, other, slice);
+        }
+        let left = self.with_row_count(ROW_LEFT, None)?;
+        let right = other.with_row_count(ROW_RIGHT, None)?;
+        // the slice must be taken from the ordered rows
+        let df = join(&left, &right, None)?
+            .sort([ROW_LEFT, ROW_RIGHT], vec![false, false])?
+            .drop(ROW_LEFT)?
+            .drop(ROW_RIGHT)?;
+        Ok(match slice {
+            Some((offset, len)) => df.slice(offset, len),
+            None => df,
+        })
+    }
+
+    /// Join where null keys don't match any key, by removing the rows with a null key from the
+    /// right table. An outer join appends them to the output, unmatched.
+    #[allow(clippy::too_many_arguments)]
+    fn join_nulls_not_equal(
+        &self,
+        other: &DataFrame,
+        left_on: Vec<Series>,
+        right_on: Vec<Series>,
+        how: JoinType,
+        suffix: Option<String>,
+        slice: Option<(i64, usize)>,
+        verbose: bool,
+    ) -> Result<DataFrame> {
+        let null_rows = match right_on
+            .iter()
+            .filter(|s| s.null_count() > 0)
+            .map(|s| s.is_null())
+            .reduce(|acc, is_null| &acc | &is_null)
+        {
+            Some(null_rows) => null_rows,
+            None => {
+                return self._join_impl(other, left_on, right_on, how, suffix, slice, true, verbose)
+            }
+        };
+        let filter = |mask: &BooleanChunked| -> Result<(DataFrame, Vec<Series>)> {
+            let keys = right_on
+                .iter()
+                .map(|s| s.filter(mask))
+                .collect::<Result<Vec<_>>>()?;
+            Ok((other.filter(mask)?, keys))
+        };
+        let (right, right_keys) = filter(&!&null_rows)?;
+        if !matches!(how, JoinType::Outer) {
+            return self._join_impl(
+                &right, left_on, right_keys, how, suffix, slice, true, verbose,
+            );
+        }
+
+        let out = self._join_impl(
+            &right,
+            left_on.clone(),
+            right_keys,
+            how.clone(),
+            suffix.clone(),
             None,
             true,
-            false,
-        )
+            verbose,
+        )?;
+        // an outer join with an empty left table keeps the null rows unmatched
+        let (right, right_keys) = filter(&null_rows)?;
+        let left_keys = left_on.iter().map(|s| s.slice(0, 0)).collect();
+        let unmatched = self.slice(0, 0)._join_impl(
+            &right, left_keys, right_keys, how, suffix, None, true, verbose,
+        )?;
+        let out = out.vstack(&unmatched)?;
+        Ok(match slice {
+            Some((offset, len)) => out.slice(offset, len),
+            None => out,
+        })
+    }
+
+    /// Join on keys of which the ones that are not a column (by name) of their `DataFrame`
+    /// are added as temporary columns. The temporary columns are removed from the output.
+    /// Null keys only match each other if `nulls_equal` is set.
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn _join_with_keys(
+        &self,
+        other: &DataFrame,
+        left_on: Vec<Series>,
+        right_on: Vec<Series>,
+        how: JoinType,
+        suffix: Option<String>,
+        slice: Option<(i64, usize)>,
+        verbose: bool,
+        nulls_equal: bool,
+    ) -> Result<DataFrame> {
+        let mut temporary = PlHashSet::new();
+        let mut add_keys = |df: &DataFrame, keys: &[Series]| -> Result<Option<DataFrame>> {
+            let new = keys
+                .iter()
+                .filter(|s| df.find_idx_by_name(s.name()).is_none())
+                .cloned()
+                .collect::<Vec<_>>();
+            if new.is_empty() {
+                return Ok(None);
+            }
+            temporary.extend(new.iter().map(|s| s.name().to_string()));
+            df.hstack(&new).map(Some)
+        };
+        let left = add_keys(self, &left_on)?;
+        let right = add_keys(other, &right_on)?;
+
+        let (left, right) = (
+            left.as_ref().unwrap_or(self),
+            right.as_ref().unwrap_or(other),
+        );
+        let out = if nulls_equal {
+            left._join_impl(right, left_on, right_on, how, suffix, slice, true, verbose)?
+        } else {
+            left.join_nulls_not_equal(right, left_on, right_on, how, suffix, slice, verbose)?
+        };
+        if temporary.is_empty() {
+            return Ok(out);
+        }
+        Ok(DataFrame::new_no_checks(
+            out.get_columns()
+                .iter()
+                .filter(|s| !temporary.contains(s.name()))
+                .cloned()
+                .collect(),
+        ))
     }
 
     /// Perform an inner join on two DataFrames.
@@ -975,6 +1292,7 @@ impl DataFrame {
 mod test {
     use crate::df;
     use crate::prelude::*;
+    use crate::series::IsSorted;
 
     fn create_frames() -> (DataFrame, DataFrame) {
         let s0 = Series::new("days", &[0, 1, 2]);
@@ -1559,4 +1877,127 @@ mod test {
         assert_eq!(out.shape(), (1, 2));
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_sorted_merge_join() -> Result<()> {
+        let sorted = |values: &[i64], sorted: IsSorted| {
+            // multiple chunks
+            let mut s = Series::new("key", &values[..2]);
+            s.append(&Series::new("key", &values[2..]))?;
+            s.set_sorted(sorted);
+            Result::Ok(s)
+        };
+        let left_keys = [1i64, 2, 2, 4, 6, 7, 9];
+        let right_keys = [2i64, 2, 4, 5, 7, 7];
+
+        for (is_sorted, reverse) in [(IsSorted::Ascending, false), (IsSorted::Descending, true)] {
+            let mut left_keys = left_keys.to_vec();
+            let mut right_keys = right_keys.to_vec();
+            if reverse {
+                left_keys.reverse();
+                right_keys.reverse();
+            }
+            let left_vals = Series::new("left", (0..left_keys.len() as i32).collect::<Vec<_>>());
+            let right_vals = Series::new("right", (0..right_keys.len() as i32).collect::<Vec<_>>());
+            let left = DataFrame::new(vec![sorted(&left_keys, is_sorted)?, left_vals.clone()])?;
+            let right = DataFrame::new(vec![sorted(&right_keys, is_sorted)?, right_vals.clone()])?;
+            assert!(super::sort_merge::use_sort_merge(
+                left.column("key")?,
+                right.column("key")?
+            ));
+
+            // same frames without the sorted flag use the hash join
+            let left_hash = DataFrame::new(vec![Series::new("key", &left_keys), left_vals])?;
+            let right_hash = DataFrame::new(vec![Series::new("key", &right_keys), right_vals])?;
+
+            for how in [JoinType::Inner, JoinType::Left] {
+                let out = left.join(&right, ["key"], ["key"], how.clone(), None)?;
+                let expected = left_hash.join(&right_hash, ["key"], ["key"], how.clone(), None)?;
+                assert!(out.frame_equal_missing(&expected));
+            }
+        }
+
+        // floats use the hash join
+        let mut s = Series::new("key", &[1.0, 2.0, f64::NAN]);
+        s.set_sorted(IsSorted::Ascending);
+        assert!(!super::sort_merge::use_sort_merge(&s, &s));
+        let df = DataFrame::new(vec![s])?;
+        assert_eq!(df.inner_join(&df, ["key"], ["key"])?.height(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_validation() -> Result<()> {
+        let left = df![
+            "a" => [Some(1), None, Some(2)],
+            "b" => ["x", "y", "x"]
+        ]?;
+        let right = df![
+            "a" => [Some(1), Some(1), None],
+            "b" => ["x", "y", "y"]
+        ]?;
+        let join = |on: &[&str], how: JoinType, validate| {
+            left.join_with_validation(&right, on, on, how, None, validate)
+        };
+
+        for how in [JoinType::Inner, JoinType::Left, JoinType::Outer] {
+            assert!(join(&["a"], how.clone(), JoinValidation::OneToMany).is_ok());
+            assert!(join(&["a"], how.clone(), JoinValidation::ManyToOne).is_err());
+            assert!(join(&["a"], how.clone(), JoinValidation::OneToOne).is_err());
+            // the combined keys are unique, nulls are equal to each other
+            assert!(join(&["a", "b"], how.clone(), JoinValidation::OneToOne).is_ok());
+        }
+        assert!(join(&["a"], JoinType::Inner, JoinValidation::ManyToMany)?
+            .frame_equal_missing(&left.inner_join(&right, ["a"], ["a"])?));
+        // duplicate nulls
+        let left = df!["a" => [Some(1), None, None]]?;
+        let err = left
+            .join_with_validation(
+                &left,
+                ["a"],
+                ["a"],
+                JoinType::Left,
+                None,
+                JoinValidation::OneToMany,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("left keys contain duplicates"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_with_series() -> Result<()> {
+        let left = df![
+            "a" => [1, 2, 3],
+            "b" => ["x", "y", "z"]
+        ]?;
+        let right = df![
+            "a" => [10, 30, 40],
+            "c" => [1, 2, 3]
+        ]?;
+        let left_on = left.column("a")? * 10;
+        let right_on = right.column("a")?.clone();
+
+        let out = left.join_with_series(
+            &right,
+            vec![left_on.clone()],
+            vec![right_on.clone()],
+            JoinType::Inner,
+            None,
+        )?;
+        assert_eq!(out.get_column_names(), &["a", "b", "a_right", "c"]);
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(3)]);
+
+        // the keys must have the same length as the tables
+        let out = left.join_with_series(
+            &right,
+            vec![Series::new("key", &[1, 2])],
+            vec![right_on],
+            JoinType::Inner,
+            None,
+        );
+        assert!(out.is_err());
+        Ok(())
+    }
 }
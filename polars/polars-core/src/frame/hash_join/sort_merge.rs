@@ -5,15 +5,23 @@ use crate::utils::split_offsets;
 use polars_arrow::kernels::sorted_join;
 #[cfg(feature = "performant")]
 use polars_utils::flatten;
+#[cfg(feature = "performant")]
+use std::borrow::Cow;
+#[cfg(feature = "performant")]
+use std::ops::Not;
 
 pub(super) fn use_sort_merge(s_left: &Series, s_right: &Series) -> bool {
-    // only use for numeric data for now
+    // only use for integer data for now, floats need a total order
     use IsSorted::*;
     let out = match (s_left.is_sorted(), s_right.is_sorted()) {
-        (Ascending, Ascending) => {
+        (Ascending, Ascending) | (Descending, Descending) => {
             s_left.null_count() == 0
                 && s_right.null_count() == 0
                 && s_left.dtype().to_physical().is_numeric()
+                && !matches!(
+                    s_left.dtype().to_physical(),
+                    DataType::Float32 | DataType::Float64
+                )
         }
         _ => false,
     };
@@ -23,27 +31,65 @@ pub(super) fn use_sort_merge(s_left: &Series, s_right: &Series) -> bool {
     out
 }
 
+/// Get the values of sorted keys in ascending order.
+/// Descending integers are inverted bitwise, this reverses the order and maintains equality.
+#[cfg(feature = "performant")]
+fn ascending_values<T>(ca: &ChunkedArray<T>, descending: bool) -> Cow<'_, [T::Native]>
+where
+    T: PolarsIntegerType,
+    T::Native: Not<Output = T::Native>,
+{
+    // we can unwrap because we should not have nulls
+    let values = ca.cont_slice().unwrap();
+    if descending {
+        Cow::Owned(values.iter().map(|v| !*v).collect_trusted())
+    } else {
+        Cow::Borrowed(values)
+    }
+}
+
+/// The values of `right` that can match a partition of `left` and the offset of those values.
+#[cfg(feature = "performant")]
+fn matching_right<'a, T: PartialOrd>(left: &[T], right: &'a [T]) -> (&'a [T], IdxSize) {
+    match left.first() {
+        Some(first) => {
+            let offset = right.partition_point(|v| v < first);
+            (&right[offset..], offset as IdxSize)
+        }
+        None => (right, 0),
+    }
+}
+
 #[cfg(feature = "performant")]
 fn par_sorted_merge_left_impl<T>(
     s_left: &ChunkedArray<T>,
     s_right: &ChunkedArray<T>,
+    descending: bool,
 ) -> (Vec<IdxSize>, Vec<Option<IdxSize>>)
 where
-    T: PolarsNumericType,
+    T: PolarsIntegerType,
+    T::Native: Not<Output = T::Native>,
 {
     let offsets = split_offsets(s_left.len(), POOL.current_num_threads());
     let s_left = s_left.rechunk();
     let s_right = s_right.rechunk();
 
-    // we can unwrap because we should not have nulls
-    let slice_left = s_left.cont_slice().unwrap();
-    let slice_right = s_right.cont_slice().unwrap();
+    let slice_left = ascending_values(&s_left, descending);
+    let slice_right = ascending_values(&s_right, descending);
 
     let indexes = offsets
         .into_par_iter()
         .map(|(offset, len)| {
             let slice_left = &slice_left[offset..offset + len];
-            sorted_join::left::join(slice_left, slice_right, offset as IdxSize)
+            let (slice_right, right_offset) = matching_right(slice_left, &slice_right);
+            let (lhs, mut rhs) =
+                sorted_join::left::join(slice_left, slice_right, offset as IdxSize);
+            if right_offset > 0 {
+                rhs.iter_mut()
+                    .flatten()
+                    .for_each(|idx| *idx += right_offset);
+            }
+            (lhs, rhs)
         })
         .collect::<Vec<_>>();
     let lefts = indexes.iter().map(|t| &t.0).collect::<Vec<_>>();
@@ -59,33 +105,38 @@ pub(super) fn par_sorted_merge_left(
 ) -> (Vec<IdxSize>, Vec<Option<IdxSize>>) {
     // Don't use bit_repr here. It messes up sortedness.
     debug_assert_eq!(s_left.dtype(), s_right.dtype());
+    let descending = s_left.is_sorted() == IsSorted::Descending;
     let s_left = s_left.to_physical_repr();
     let s_right = s_right.to_physical_repr();
 
     match s_left.dtype() {
         #[cfg(feature = "dtype-i8")]
-        DataType::Int8 => par_sorted_merge_left_impl(s_left.i8().unwrap(), s_right.i8().unwrap()),
+        DataType::Int8 => {
+            par_sorted_merge_left_impl(s_left.i8().unwrap(), s_right.i8().unwrap(), descending)
+        }
         #[cfg(feature = "dtype-u8")]
-        DataType::UInt8 => par_sorted_merge_left_impl(s_left.u8().unwrap(), s_right.u8().unwrap()),
+        DataType::UInt8 => {
+            par_sorted_merge_left_impl(s_left.u8().unwrap(), s_right.u8().unwrap(), descending)
+        }
         #[cfg(feature = "dtype-u16")]
         DataType::UInt16 => {
-            par_sorted_merge_left_impl(s_left.u16().unwrap(), s_right.u16().unwrap())
+            par_sorted_merge_left_impl(s_left.u16().unwrap(), s_right.u16().unwrap(), descending)
         }
         #[cfg(feature = "dtype-i16")]
         DataType::Int16 => {
-            par_sorted_merge_left_impl(s_left.i16().unwrap(), s_right.i16().unwrap())
+            par_sorted_merge_left_impl(s_left.i16().unwrap(), s_right.i16().unwrap(), descending)
         }
         DataType::UInt32 => {
-            par_sorted_merge_left_impl(s_left.u32().unwrap(), s_right.u32().unwrap())
+            par_sorted_merge_left_impl(s_left.u32().unwrap(), s_right.u32().unwrap(), descending)
         }
         DataType::Int32 => {
-            par_sorted_merge_left_impl(s_left.i32().unwrap(), s_right.i32().unwrap())
+            par_sorted_merge_left_impl(s_left.i32().unwrap(), s_right.i32().unwrap(), descending)
         }
         DataType::UInt64 => {
-            par_sorted_merge_left_impl(s_left.u64().unwrap(), s_right.u64().unwrap())
+            par_sorted_merge_left_impl(s_left.u64().unwrap(), s_right.u64().unwrap(), descending)
         }
         DataType::Int64 => {
-            par_sorted_merge_left_impl(s_left.i64().unwrap(), s_right.i64().unwrap())
+            par_sorted_merge_left_impl(s_left.i64().unwrap(), s_right.i64().unwrap(), descending)
         }
         _ => unreachable!(),
     }
@@ -94,23 +145,30 @@ pub(super) fn par_sorted_merge_left(
 fn par_sorted_merge_inner_impl<T>(
     s_left: &ChunkedArray<T>,
     s_right: &ChunkedArray<T>,
+    descending: bool,
 ) -> (Vec<IdxSize>, Vec<IdxSize>)
 where
-    T: PolarsNumericType,
+    T: PolarsIntegerType,
+    T::Native: Not<Output = T::Native>,
 {
     let offsets = split_offsets(s_left.len(), POOL.current_num_threads());
     let s_left = s_left.rechunk();
     let s_right = s_right.rechunk();
 
-    // we can unwrap because we should not have nulls
-    let slice_left = s_left.cont_slice().unwrap();
-    let slice_right = s_right.cont_slice().unwrap();
+    let slice_left = ascending_values(&s_left, descending);
+    let slice_right = ascending_values(&s_right, descending);
 
     let indexes = offsets
         .into_par_iter()
         .map(|(offset, len)| {
             let slice_left = &slice_left[offset..offset + len];
-            sorted_join::inner::join(slice_left, slice_right, offset as IdxSize)
+            let (slice_right, right_offset) = matching_right(slice_left, &slice_right);
+            let (lhs, mut rhs) =
+                sorted_join::inner::join(slice_left, slice_right, offset as IdxSize);
+            if right_offset > 0 {
+                rhs.iter_mut().for_each(|idx| *idx += right_offset);
+            }
+            (lhs, rhs)
         })
         .collect::<Vec<_>>();
     let lefts = indexes.iter().map(|t| &t.0).collect::<Vec<_>>();
@@ -126,33 +184,38 @@ pub(super) fn par_sorted_merge_inner(
 ) -> (Vec<IdxSize>, Vec<IdxSize>) {
     // Don't use bit_repr here. It messes up sortedness.
     debug_assert_eq!(s_left.dtype(), s_right.dtype());
+    let descending = s_left.is_sorted() == IsSorted::Descending;
     let s_left = s_left.to_physical_repr();
     let s_right = s_right.to_physical_repr();
 
     match s_left.dtype() {
         #[cfg(feature = "dtype-i8")]
-        DataType::Int8 => par_sorted_merge_inner_impl(s_left.i8().unwrap(), s_right.i8().unwrap()),
+        DataType::Int8 => {
+            par_sorted_merge_inner_impl(s_left.i8().unwrap(), s_right.i8().unwrap(), descending)
+        }
         #[cfg(feature = "dtype-u8")]
-        DataType::UInt8 => par_sorted_merge_inner_impl(s_left.u8().unwrap(), s_right.u8().unwrap()),
+        DataType::UInt8 => {
+            par_sorted_merge_inner_impl(s_left.u8().unwrap(), s_right.u8().unwrap(), descending)
+        }
         #[cfg(feature = "dtype-u16")]
         DataType::UInt16 => {
-            par_sorted_merge_inner_impl(s_left.u16().unwrap(), s_right.u16().unwrap())
+            par_sorted_merge_inner_impl(s_left.u16().unwrap(), s_right.u16().unwrap(), descending)
         }
         #[cfg(feature = "dtype-i16")]
         DataType::Int16 => {
-            par_sorted_merge_inner_impl(s_left.i16().unwrap(), s_right.i16().unwrap())
+            par_sorted_merge_inner_impl(s_left.i16().unwrap(), s_right.i16().unwrap(), descending)
         }
         DataType::UInt32 => {
-            par_sorted_merge_inner_impl(s_left.u32().unwrap(), s_right.u32().unwrap())
+            par_sorted_merge_inner_impl(s_left.u32().unwrap(), s_right.u32().unwrap(), descending)
         }
         DataType::Int32 => {
-            par_sorted_merge_inner_impl(s_left.i32().unwrap(), s_right.i32().unwrap())
+            par_sorted_merge_inner_impl(s_left.i32().unwrap(), s_right.i32().unwrap(), descending)
         }
         DataType::UInt64 => {
-            par_sorted_merge_inner_impl(s_left.u64().unwrap(), s_right.u64().unwrap())
+            par_sorted_merge_inner_impl(s_left.u64().unwrap(), s_right.u64().unwrap(), descending)
         }
         DataType::Int64 => {
-            par_sorted_merge_inner_impl(s_left.i64().unwrap(), s_right.i64().unwrap())
+            par_sorted_merge_inner_impl(s_left.i64().unwrap(), s_right.i64().unwrap(), descending)
         }
         _ => unreachable!(),
     }
@@ -0,0 +1,82 @@
+use super::*;
+
+/// The expected relation between the keys of the left and the right table of a join.
+///
+/// A join errors if a side that must be unique contains duplicate keys.
+/// Null keys are considered equal to each other.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinValidation {
+    /// No checks are done.
+    #[default]
+    ManyToMany,
+    /// The keys of the right table must be unique.
+    ManyToOne,
+    /// The keys of the left table must be unique.
+    OneToMany,
+    /// The keys of both tables must be unique.
+    OneToOne,
+}
+
+impl JoinValidation {
+    fn as_str(&self) -> &'static str {
+        use JoinValidation::*;
+        match self {
+            ManyToMany => "m:m",
+            ManyToOne => "m:1",
+            OneToMany => "1:m",
+            OneToOne => "1:1",
+        }
+    }
+
+    /// Check the join keys, this is a no-op for [`JoinValidation::ManyToMany`].
+    pub fn validate(&self, how: &JoinType, left_on: &[Series], right_on: &[Series]) -> Result<()> {
+        use JoinValidation::*;
+        if let ManyToMany = self {
+            return Ok(());
+        }
+        if !matches!(how, JoinType::Inner | JoinType::Left | JoinType::Outer) {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "{} validation is only supported for inner, left and outer joins, got: {:?}",
+                    self.as_str(),
+                    how
+                )
+                .into(),
+            ));
+        }
+
+        let check = |keys: &[Series], side: &str| {
+            if keys_are_unique(keys)? {
+                Ok(())
+            } else {
+                Err(PolarsError::ComputeError(
+                    format!(
+                        "join keys did not fulfil {} validation: the {} keys contain duplicates",
+                        self.as_str(),
+                        side
+                    )
+                    .into(),
+                ))
+            }
+        };
+        if matches!(self, OneToMany | OneToOne) {
+            check(left_on, "left")?;
+        }
+        if matches!(self, ManyToOne | OneToOne) {
+            check(right_on, "right")?;
+        }
+        Ok(())
+    }
+}
+
+fn keys_are_unique(keys: &[Series]) -> Result<bool> {
+    let height = keys.first().map(|s| s.len()).unwrap_or(0);
+    if height < 2 {
+        return Ok(true);
+    }
+    // the empty DataFrame is a dummy, we only need the groups of the keys
+    let df = DataFrame::new_no_checks(vec![]);
+    let gb = df.groupby_with_series(keys.to_vec(), true, false)?;
+    Ok(gb.get_groups().len() == height)
+}
@@ -0,0 +1,334 @@
+//! Joins on inequality predicates, e.g. `left.start <= right.ts && left.end > right.ts`.
+//!
+//! The right table is sorted by the key of the first predicate. Every predicate on that same key
+//! narrows down a contiguous range of the sorted rows, which is found by binary search. The
+//! predicates on the other keys filter the rows in that range.
+use crate::prelude::*;
+use crate::series::IsSorted;
+use crate::utils::{get_supertype, slice_slice, split_offsets, CustomIterTools, NoNull};
+use crate::POOL;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The comparison of a left and a right join key in a [`JoinType::Inequality`] join.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InequalityOperator {
+    /// `left < right`
+    Lt,
+    /// `left <= right`
+    LtEq,
+    /// `left > right`
+    Gt,
+    /// `left >= right`
+    GtEq,
+}
+
+impl InequalityOperator {
+    fn compare(&self, left: &Series, right: &Series) -> Result<BooleanChunked> {
+        use InequalityOperator::*;
+        match self {
+            Lt => left.lt(right),
+            LtEq => left.lt_eq(right),
+            Gt => left.gt(right),
+            GtEq => left.gt_eq(right),
+        }
+    }
+
+    /// Narrow `start..end` to the sorted right values that fulfil `left <op> right`.
+    fn narrow_range<T: PartialOrd>(
+        &self,
+        sorted: &[T],
+        left: T,
+        start: &mut usize,
+        end: &mut usize,
+    ) {
+        use InequalityOperator::*;
+        match self {
+            Lt => *start = std::cmp::max(*start, sorted.partition_point(|r| *r <= left)),
+            LtEq => *start = std::cmp::max(*start, sorted.partition_point(|r| *r < left)),
+            Gt => *end = std::cmp::min(*end, sorted.partition_point(|r| *r < left)),
+            GtEq => *end = std::cmp::min(*end, sorted.partition_point(|r| *r <= left)),
+        }
+    }
+}
+
+// null and NaN never match
+fn is_valid<T: PartialOrd>(v: &T) -> bool {
+    v.partial_cmp(v).is_some()
+}
+
+fn sorted_range_matches<T>(
+    left: &[&ChunkedArray<T>],
+    ops: &[InequalityOperator],
+    right: &ChunkedArray<T>,
+) -> (Vec<IdxSize>, Vec<IdxSize>)
+where
+    T: PolarsNumericType,
+{
+    let mut sorted = right
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, v)| v.filter(is_valid).map(|v| (v, idx as IdxSize)))
+        .collect::<Vec<_>>();
+    // stable, so that equal values keep the order of the right table
+    POOL.install(|| sorted.par_sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap()));
+    let (values, right_idx): (Vec<_>, Vec<_>) = sorted.into_iter().unzip();
+
+    let n_threads = POOL.current_num_threads();
+    let offsets = split_offsets(left[0].len(), n_threads);
+    let matches = POOL.install(|| {
+        offsets
+            .into_par_iter()
+            .map(|(offset, len)| {
+                let mut left_out = vec![];
+                let mut right_out = vec![];
+                'rows: for i in offset..offset + len {
+                    let mut start = 0;
+                    let mut end = values.len();
+                    for (ca, op) in left.iter().zip(ops) {
+                        match ca.get(i) {
+                            Some(v) if is_valid(&v) => {
+                                op.narrow_range(&values, v, &mut start, &mut end)
+                            }
+                            _ => continue 'rows,
+                        }
+                    }
+                    if start < end {
+                        left_out.resize(left_out.len() + end - start, i as IdxSize);
+                        right_out.extend_from_slice(&right_idx[start..end]);
+                    }
+                }
+                (left_out, right_out)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let len = matches.iter().map(|(l, _)| l.len()).sum();
+    let mut left_out = Vec::with_capacity(len);
+    let mut right_out = Vec::with_capacity(len);
+    for (l, r) in matches {
+        left_out.extend_from_slice(&l);
+        right_out.extend_from_slice(&r);
+    }
+    (left_out, right_out)
+}
+
+/// Indices of the rows that fulfil all `left[i] <ops[i]> right`.
+fn range_matches(
+    left: &[Series],
+    ops: &[InequalityOperator],
+    right: &Series,
+) -> Result<(Vec<IdxSize>, Vec<IdxSize>)> {
+    let mut supertype = right.dtype().clone();
+    for s in left {
+        supertype = get_supertype(&supertype, s.dtype())?;
+    }
+    let physical = match supertype.to_physical() {
+        DataType::UInt64 => DataType::UInt64,
+        DataType::Float32 | DataType::Float64 => DataType::Float64,
+        dt if dt.is_numeric() => DataType::Int64,
+        _ => {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "inequality joins are only supported on numeric and temporal keys, got: {}",
+                    supertype
+                )
+                .into(),
+            ))
+        }
+    };
+    let cast = |s: &Series| -> Result<Series> {
+        let s = s.cast(&supertype)?.to_physical_repr().cast(&physical)?;
+        Ok(s.rechunk())
+    };
+    let right = cast(right)?;
+    let left = left.iter().map(cast).collect::<Result<Vec<_>>>()?;
+
+    macro_rules! dispatch {
+        ($method:ident) => {{
+            let left = left
+                .iter()
+                .map(|s| s.$method().unwrap())
+                .collect::<Vec<_>>();
+            sorted_range_matches(&left, ops, right.$method().unwrap())
+        }};
+    }
+    Ok(match physical {
+        DataType::UInt64 => dispatch!(u64),
+        DataType::Float64 => dispatch!(f64),
+        _ => dispatch!(i64),
+    })
+}
+
+impl DataFrame {
+    /// Inner join on the inequality predicates `left_on[i] <ops[i]> right_on[i]`.
+    /// All columns of both tables are kept.
+    #[doc(hidden)]
+    pub fn _join_where_impl(
+        &self,
+        other: &DataFrame,
+        left_on: &[Series],
+        ops: &[InequalityOperator],
+        right_on: &[Series],
+        suffix: Option<String>,
+        slice: Option<(i64, usize)>,
+    ) -> Result<DataFrame> {
+        if ops.is_empty() || left_on.len() != ops.len() || right_on.len() != ops.len() {
+            return Err(PolarsError::ComputeError(
+                "an inequality join needs an operator for every pair of join keys".into(),
+            ));
+        }
+
+        let sort_key = right_on[0].name();
+        let (primary, secondary): (Vec<_>, Vec<_>) =
+            (0..ops.len()).partition(|i| right_on[*i].name() == sort_key);
+        let primary_left = primary
+            .iter()
+            .map(|i| left_on[*i].clone())
+            .collect::<Vec<_>>();
+        let primary_ops = primary.iter().map(|i| ops[*i]).collect::<Vec<_>>();
+        let (mut left_idx, mut right_idx) =
+            range_matches(&primary_left, &primary_ops, &right_on[0])?;
+
+        for i in secondary {
+            let left_take = IdxCa::from_vec("", left_idx);
+            let right_take = IdxCa::from_vec("", right_idx);
+            let mask = ops[i].compare(
+                &left_on[i].take(&left_take)?,
+                &right_on[i].take(&right_take)?,
+            )?;
+            let (l, r): (Vec<_>, Vec<_>) = mask
+                .into_iter()
+                .zip(
+                    left_take
+                        .into_no_null_iter()
+                        .zip(right_take.into_no_null_iter()),
+                )
+                .filter(|(keep, _)| *keep == Some(true))
+                .map(|(_, idx)| idx)
+                .unzip();
+            left_idx = l;
+            right_idx = r;
+        }
+
+        let (left_idx, right_idx) = match slice {
+            Some((offset, len)) => (
+                slice_slice(&left_idx, offset, len),
+                slice_slice(&right_idx, offset, len),
+            ),
+            None => (left_idx.as_slice(), right_idx.as_slice()),
+        };
+        let mut left_take: NoNull<IdxCa> = left_idx.iter().copied().collect_trusted();
+        left_take.set_sorted2(IsSorted::Ascending);
+        let left_take = left_take.into_inner();
+        let right_take: NoNull<IdxCa> = right_idx.iter().copied().collect_trusted();
+        let right_take = right_take.into_inner();
+
+        // Safety:
+        // the indices are in bounds
+        let (df_left, df_right) = POOL.install(|| {
+            rayon::join(
+                || unsafe { self.take_unchecked(&left_take) },
+                || unsafe { other.take_unchecked(&right_take) },
+            )
+        });
+        self.finish_join(df_left, df_right, suffix)
+    }
+
+    /// Inner join on the inequality predicates `left_on[i] <ops[i]> right_on[i]`, without
+    /// materializing the cartesian product. All columns of both tables are kept.
+    ///
+    /// The keys must be numeric or temporal. Null and NaN keys never match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// // match every event with the intervals `start <= ts < end` that contain it
+    /// fn events_in_intervals(intervals: &DataFrame, events: &DataFrame) -> Result<DataFrame> {
+    ///     use InequalityOperator::*;
+    ///     intervals.join_where(events, ["start", "end"], &[LtEq, Gt], ["ts", "ts"], None)
+    /// }
+    /// ```
+    pub fn join_where<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        ops: &[InequalityOperator],
+        right_on: I,
+        suffix: Option<String>,
+    ) -> Result<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let left_on = self.select_series(left_on)?;
+        let right_on = other.select_series(right_on)?;
+        self._join_where_impl(other, &left_on, ops, &right_on, suffix, None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_join_where() -> Result<()> {
+        use InequalityOperator::*;
+        let intervals = df![
+            "id" => [0, 1, 2, 3],
+            "start" => [Some(0), Some(5), None, Some(2)],
+            "end" => [10, 7, 5, 3]
+        ]?;
+        let events = df![
+            "ts" => [Some(5.0), Some(2.0), None, Some(f64::NAN), Some(9.5)],
+            "id" => [0, 1, 2, 3, 4]
+        ]?;
+
+        let out =
+            intervals.join_where(&events, ["start", "end"], &[LtEq, Gt], ["ts", "ts"], None)?;
+        let expected = df![
+            "id" => [0, 0, 0, 1, 3],
+            "start" => [0, 0, 0, 5, 2],
+            "end" => [10, 10, 10, 7, 3],
+            "ts" => [2.0, 5.0, 9.5, 5.0, 2.0],
+            "id_right" => [1, 0, 4, 0, 1]
+        ]?;
+        assert!(out.frame_equal(&expected));
+
+        // a predicate on another column filters the matches
+        let events = events.hstack(&[Series::new("limit", &[0, 0, 0, 0, 20])])?;
+        let out = intervals.join_where(
+            &events,
+            ["start", "end"],
+            &[LtEq, Lt],
+            ["ts", "limit"],
+            None,
+        )?;
+        assert_eq!(
+            Vec::from(out.column("id")?.i32()?),
+            &[Some(0), Some(1), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(out.column("id_right")?.i32()?),
+            &[Some(4), Some(4), Some(4)]
+        );
+
+        let out = intervals._join_where_impl(
+            &events,
+            &[intervals.column("end")?.clone()],
+            &[Gt],
+            &[events.column("ts")?.clone()],
+            None,
+            Some((1, 2)),
+        )?;
+        assert_eq!(out.shape(), (2, 6));
+
+        assert!(intervals
+            .join_where(&events, ["id", "id"], &[Lt], ["ts", "ts"], None)
+            .is_err());
+        Ok(())
+    }
+}
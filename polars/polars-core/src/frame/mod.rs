@@ -1,5 +1,6 @@
 //! DataFrame module.
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::iter::{FromIterator, Iterator};
 use std::mem;
 use std::ops;
@@ -24,10 +25,17 @@ mod chunks;
 pub(crate) mod cross_join;
 pub mod explode;
 mod from;
+#[cfg(feature = "fmt")]
+pub mod glimpse;
 pub mod groupby;
 pub mod hash_join;
+#[cfg(feature = "join_where")]
+pub(crate) mod join_where;
+#[cfg(feature = "fmt")]
+pub mod render;
 #[cfg(feature = "rows")]
 pub mod row;
+pub(crate) mod update;
 mod upstream_traits;
 
 #[cfg(feature = "sort_multiple")]
@@ -130,12 +138,40 @@ pub enum UniqueKeepStrategy {
 /// assert_eq!(df["Color"], Series::new("Color", &["Red", "Yellow", "Green"]));
 /// # Ok::<(), PolarsError>(())
 /// ```
+///
+/// # Copy-on-write
+///
+/// Cloning a `DataFrame` is cheap: it clones the `Vec` of `Series`, which are reference counted
+/// and share their chunks. A mutation of a shared `Series` (e.g. [`Series::rename`] or
+/// [`Series::append`]) first clones that `Series`, and a mutation of a shared buffer first copies
+/// that buffer. So the other clones never observe the mutation and only the mutated columns
+/// take extra memory. [`Series::n_references`] shows how many clones share a `Series`.
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// let df = df!("a" => [1, 2, 3], "b" => [4, 5, 6])?;
+/// let mut snapshot = df.clone();
+/// assert_eq!(df.column("a")?.n_references(), 2);
+///
+/// snapshot.apply("a", |s| s + 1)?;
+/// assert_eq!(Vec::from(df.column("a")?.i32()?), &[Some(1), Some(2), Some(3)]);
+/// // "b" is still shared
+/// assert_eq!(df.column("a")?.n_references(), 1);
+/// assert_eq!(df.column("b")?.n_references(), 2);
+/// # Ok::<(), PolarsError>(())
+/// ```
 #[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DataFrame {
     pub(crate) columns: Vec<Series>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    metadata: FrameMetadata,
 }
 
+/// Key/value metadata of a [`DataFrame`], e.g. the lineage of the data. See
+/// [`DataFrame::set_metadata`] for the operations that keep it.
+pub type FrameMetadata = BTreeMap<String, String>;
+
 fn duplicate_err(name: &str) -> Result<()> {
     Err(PolarsError::Duplicate(
         format!("Column with name: '{}' has more than one occurrences", name).into(),
@@ -289,9 +325,7 @@ impl DataFrame {
             series_cols
         };
 
-        Ok(DataFrame {
-            columns: series_cols,
-        })
+        Ok(DataFrame::new_no_checks(series_cols))
     }
 
     /// Creates an empty `DataFrame` usable in a compile time context (such as static initializers).
@@ -396,7 +430,10 @@ impl DataFrame {
     /// It is the callers responsibility to uphold the contract of all `Series`
     /// having an equal length, if not this may panic down the line.
     pub const fn new_no_checks(columns: Vec<Series>) -> DataFrame {
-        DataFrame { columns }
+        DataFrame {
+            columns,
+            metadata: BTreeMap::new(),
+        }
     }
 
     /// Aggregate all chunks to contiguous memory.
@@ -646,6 +683,40 @@ impl DataFrame {
             .collect()
     }
 
+    /// Get the arrow schema of the `DataFrame`, including the key/value metadata of the
+    /// `DataFrame` and of its columns.
+    pub fn arrow_schema(&self) -> ArrowSchema {
+        let fields: Vec<_> = self.columns.iter().map(|s| s.field().to_arrow()).collect();
+        ArrowSchema::from(fields).with_metadata(self.metadata.clone())
+    }
+
+    /// Get the key/value metadata of the `DataFrame`.
+    pub fn metadata(&self) -> &FrameMetadata {
+        &self.metadata
+    }
+
+    /// Set the key/value metadata of the `DataFrame`, e.g. the lineage of the data.
+    ///
+    /// The metadata is written to Parquet and IPC files and read back from them by the eager
+    /// readers. It is kept by the operations that modify the `DataFrame` in place, operations
+    /// that create a new `DataFrame`, like `select` or `filter`, don't keep it. The metadata
+    /// is eager-only: lazy queries, including the lazy scans of files, are not guaranteed to
+    /// keep it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut df = df!("a" => [1, 2])?;
+    /// df.set_metadata([("source".to_string(), "sensors.csv".to_string())].into());
+    ///
+    /// assert_eq!(df.metadata()["source"], "sensors.csv");
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn set_metadata(&mut self, metadata: FrameMetadata) {
+        self.metadata = metadata;
+    }
+
     /// Get (height, width) of the `DataFrame`.
     ///
     /// # Example
@@ -1745,7 +1816,9 @@ impl DataFrame {
         self.as_single_chunk_par();
         let by_column = self.select_series(by_column)?;
         let reverse = reverse.into_vec();
-        self.columns = self.sort_impl(by_column, reverse, false, None)?.columns;
+        self.columns = self
+            .sort_impl(by_column, reverse, false, NanPosition::default(), None)?
+            .columns;
         Ok(self)
     }
 
@@ -1756,6 +1829,7 @@ impl DataFrame {
         by_column: Vec<Series>,
         reverse: Vec<bool>,
         nulls_last: bool,
+        nan_position: NanPosition,
         slice: Option<(i64, usize)>,
     ) -> Result<Self> {
         // note that the by_column argument also contains evaluated expression from polars-lazy
@@ -1765,12 +1839,14 @@ impl DataFrame {
         // as expressions are not present (they are renamed to _POLARS_SORT_COLUMN_i.
         let first_reverse = reverse[0];
         let first_by_column = by_column[0].name().to_string();
+        let first_is_float = matches!(by_column[0].dtype(), DataType::Float32 | DataType::Float64);
         let mut take = match by_column.len() {
             1 => {
                 let s = &by_column[0];
                 let options = SortOptions {
                     descending: reverse[0],
                     nulls_last,
+                    nan_position,
                 };
                 // fast path for a frame with a single series
                 // no need to compute the sort indices and then take by these indices
@@ -1783,7 +1859,8 @@ impl DataFrame {
             _ => {
                 #[cfg(feature = "sort_multiple")]
                 {
-                    let (first, by_column, reverse) = prepare_argsort(by_column, reverse)?;
+                    let (first, by_column, reverse) =
+                        prepare_argsort(by_column, reverse, nan_position)?;
                     first.argsort_multiple(&by_column, &reverse)?
                 }
                 #[cfg(not(feature = "sort_multiple"))]
@@ -1807,6 +1884,10 @@ impl DataFrame {
         // Mark the first sort column as sorted
         // if the column did not exists it is ok, because we sorted by an expression
         // not present in the dataframe
+        // a float column with its `NaN` values moved is not sorted in the usual order
+        if nan_position != NanPosition::Largest && first_is_float {
+            return Ok(df);
+        }
         let _ = df.apply(&first_by_column, |s| {
             let mut s = s.clone();
             if first_reverse {
@@ -1851,7 +1932,13 @@ impl DataFrame {
         let by_column = vec![df.column(by_column)?.clone()];
         let reverse = vec![options.descending];
         df.columns = df
-            .sort_impl(by_column, reverse, options.nulls_last, None)?
+            .sort_impl(
+                by_column,
+                reverse,
+                options.nulls_last,
+                options.nan_position,
+                None,
+            )?
             .columns;
         Ok(df)
     }
@@ -2167,6 +2254,11 @@ impl DataFrame {
 
     /// Slice the `DataFrame` along the rows.
     ///
+    /// Slicing is zero-copy: the columns of the output point into the buffers of this
+    /// `DataFrame` and only the (cheap) array metadata is created. A negative `offset` is
+    /// counted from the end and an out of bounds `offset` or `length` is clipped to the
+    /// `DataFrame`.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -2207,6 +2299,7 @@ impl DataFrame {
         DataFrame::new_no_checks(col)
     }
 
+    /// Zero-copy [`DataFrame::slice`] that slices the columns in parallel.
     #[must_use]
     pub fn slice_par(&self, offset: i64, length: usize) -> Self {
         if offset == 0 && length == self.height() {
@@ -2215,6 +2308,72 @@ impl DataFrame {
         DataFrame::new_no_checks(self.apply_columns_par(&|s| s.slice(offset, length)))
     }
 
+    /// Split the `DataFrame` into zero-copy slices that consist of a single chunk per column.
+    ///
+    /// The `DataFrame` is split at every chunk boundary of any of its columns, so the columns
+    /// do not need to have the same chunk lengths. An empty `DataFrame` is returned as a single
+    /// slice.
+    pub fn split_chunks(&self) -> Vec<DataFrame> {
+        let mut ends = self
+            .columns
+            .iter()
+            .flat_map(|s| {
+                s.chunk_lengths().scan(0, |end, len| {
+                    *end += len;
+                    Some(*end)
+                })
+            })
+            .filter(|end| *end > 0)
+            .collect::<Vec<_>>();
+        if ends.is_empty() {
+            return vec![self.clone()];
+        }
+        ends.sort_unstable();
+        ends.dedup();
+
+        let mut offset = 0;
+        ends.into_iter()
+            .map(|end| {
+                let df = self.slice(offset as i64, end - offset);
+                offset = end;
+                df
+            })
+            .collect()
+    }
+
+    /// Split the `DataFrame` into `n` zero-copy slices of balanced heights; the heights differ
+    /// by at most one row. Useful to distribute the rows over `n` threads in custom parallel
+    /// pipelines. If `n` is larger than the height, the last slices are empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [1, 2, 3, 4, 5]).unwrap();
+    /// let parts = df.partition_into_n(2);
+    /// assert_eq!(parts[0].height(), 3);
+    /// assert_eq!(parts[1].height(), 2);
+    /// ```
+    pub fn partition_into_n(&self, n: usize) -> Vec<DataFrame> {
+        assert!(n > 0, "cannot partition a DataFrame into 0 slices");
+        let height = self.height();
+        let (size, remainder) = (height / n, height % n);
+
+        let mut offset = 0;
+        (0..n)
+            .map(|i| {
+                let len = size + (i < remainder) as usize;
+                let df = self.slice(offset as i64, len);
+                offset += len;
+                df
+            })
+            .collect()
+    }
+
     #[must_use]
     pub fn _slice_and_realloc(&self, offset: i64, length: usize) -> Self {
         if offset == 0 && length == self.height() {
@@ -2944,9 +3103,10 @@ impl DataFrame {
         self.distinct_impl(true, subset, keep)
     }
 
-    /// Unstable distinct. See [`DataFrame::distinct_stable`].
+    /// Unstable distinct, unless the [deterministic mode](crate::config::deterministic) is on.
+    /// See [`DataFrame::unique_stable`].
     pub fn unique(&self, subset: Option<&[String]>, keep: UniqueKeepStrategy) -> Result<DataFrame> {
-        self.distinct_impl(false, subset, keep)
+        self.distinct_impl(crate::config::deterministic(), subset, keep)
     }
 
     fn distinct_impl(
@@ -3474,4 +3634,83 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_slices_are_zero_copy() -> Result<()> {
+        let a = Int32Chunked::new("a", &[1, 2, 3, 4, 5, 6, 7]);
+        let mut b = Int32Chunked::new("b", &[1, 2, 3]);
+        b.append(&Int32Chunked::new("b", &[4, 5, 6, 7]));
+        let df = DataFrame::new(vec![a.clone().into_series(), b.into_series()])?;
+        let ptr = a.cont_slice()?.as_ptr();
+
+        let sliced = df.slice(2, 3);
+        assert_eq!(sliced.column("a")?.i32()?.cont_slice()?.as_ptr(), unsafe {
+            ptr.add(2)
+        });
+
+        let parts = df.partition_into_n(3);
+        assert_eq!(
+            parts.iter().map(|df| df.height()).collect::<Vec<_>>(),
+            &[3, 2, 2]
+        );
+        assert_eq!(
+            parts[2].column("a")?.i32()?.cont_slice()?.as_ptr(),
+            unsafe { ptr.add(5) }
+        );
+        assert_eq!(df.partition_into_n(10).len(), 10);
+
+        // split at the chunk boundary of "b"
+        let chunks = df.split_chunks();
+        assert_eq!(
+            chunks.iter().map(|df| df.height()).collect::<Vec<_>>(),
+            &[3, 4]
+        );
+        assert!(chunks.iter().all(|df| df.n_chunks().unwrap() == 1));
+        assert_eq!(
+            chunks[1].column("a")?.i32()?.cont_slice()?.as_ptr(),
+            unsafe { ptr.add(3) }
+        );
+        assert!(crate::utils::concat_df_unchecked(&chunks).frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_on_write() -> Result<()> {
+        let df = df!["a" => [1, 2, 3], "b" => ["x", "y", "z"]]?;
+        let mut snapshot = df.clone();
+        assert!(df.iter().all(|s| s.n_references() == 2));
+
+        let columns = snapshot.get_columns_mut();
+        columns[0].rename("c");
+        columns[1].append(&Series::new("b", &["w"]))?;
+        assert_eq!(df.get_column_names(), &["a", "b"]);
+        assert_eq!(df.column("b")?.len(), 3);
+        assert!(df.iter().all(|s| s.n_references() == 1));
+
+        // mutations of shared buffers copy the buffer
+        let ca = df.column("a")?.i32()?;
+        let mut cloned = ca.clone();
+        cloned.apply_mut(|v| v * 10);
+        assert_eq!(Vec::from(ca), &[Some(1), Some(2), Some(3)]);
+        assert_eq!(Vec::from(&cloned), &[Some(10), Some(20), Some(30)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rechunk_if_fragmented() -> Result<()> {
+        let mut df = df!("a" => &[1, 2], "b" => &["a", "b"])?;
+        for _ in 0..4 {
+            df.vstack_mut(&df.clone())?;
+        }
+        let stats = df.chunk_stats();
+        assert_eq!(stats.max_chunks, 16);
+        assert_eq!(stats.total_chunks, 32);
+        assert!(stats.aligned);
+        assert!(!df.rechunk_if_fragmented(16));
+        assert!(df.rechunk_if_fragmented(4));
+        assert_eq!(df.chunk_stats().total_chunks, 2);
+        assert_eq!(df.height(), 32);
+        assert_eq!(DataFrame::default().chunk_stats().max_chunks, 0);
+        Ok(())
+    }
 }
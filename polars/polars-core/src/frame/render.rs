@@ -0,0 +1,283 @@
+//! Render a [`DataFrame`] as a markdown, HTML or LaTeX table.
+use crate::fmt::make_str_val;
+use crate::prelude::*;
+use std::fmt::Write;
+
+/// Truncation options of [`DataFrame::to_markdown`], [`DataFrame::to_html`]
+/// and [`DataFrame::to_latex`]. By default nothing is truncated.
+#[derive(Clone, Debug, Default)]
+pub struct RenderOptions {
+    /// Maximum number of rows; the rows in the middle are replaced by `...`.
+    pub max_rows: Option<usize>,
+    /// Maximum number of columns; the columns in the middle are replaced by `...`.
+    pub max_cols: Option<usize>,
+    /// Maximum number of characters per value.
+    pub str_len: Option<usize>,
+    /// Add the data types of the columns to the header.
+    pub show_dtypes: bool,
+}
+
+const ELLIPSIS: &str = "...";
+
+/// The cells of the table, before escaping.
+struct Cells {
+    header: Vec<String>,
+    dtypes: Vec<String>,
+    // right align numeric columns
+    numeric: Vec<bool>,
+    rows: Vec<Vec<String>>,
+}
+
+/// Indices of the shown items, `None` is an ellipsis.
+fn shown_indices(
+    n: usize,
+    max: Option<usize>,
+    n_first: impl Fn(usize) -> usize,
+) -> Vec<Option<usize>> {
+    match max {
+        Some(max) if n > max => {
+            let first = n_first(max);
+            let last = max - first;
+            (0..first)
+                .map(Some)
+                .chain(std::iter::once(None))
+                .chain((n - last..n).map(Some))
+                .collect()
+        }
+        _ => (0..n).map(Some).collect(),
+    }
+}
+
+impl Cells {
+    fn new(df: &DataFrame, options: &RenderOptions) -> Self {
+        // same splits as the `Display` implementation
+        let cols = shown_indices(df.width(), options.max_cols, |max| max - max / 2);
+        let rows = shown_indices(
+            df.height(),
+            options.max_rows.map(|max| std::cmp::max(max, 2)),
+            |max| max / 2,
+        );
+        let truncate = |v: &str| match options.str_len {
+            Some(len) => make_str_val(v, len),
+            None => v.to_string(),
+        };
+        let columns = df.get_columns();
+
+        let map_cols = |f: &dyn Fn(&Series) -> String| {
+            cols.iter()
+                .map(|idx| match idx {
+                    Some(idx) => f(&columns[*idx]),
+                    None => ELLIPSIS.to_string(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let header = map_cols(&|s| truncate(s.name()));
+        let dtypes = map_cols(&|s| s.dtype().to_string());
+        let numeric = cols
+            .iter()
+            .map(|idx| matches!(idx, Some(idx) if columns[*idx].dtype().is_numeric()))
+            .collect();
+        let rows = rows
+            .iter()
+            .map(|row| match row {
+                Some(row) => map_cols(&|s| truncate(&s.str_value(*row))),
+                None => cols.iter().map(|_| ELLIPSIS.to_string()).collect(),
+            })
+            .collect();
+
+        Cells {
+            header,
+            dtypes,
+            numeric,
+            rows,
+        }
+    }
+}
+
+fn escape_markdown(v: &str) -> String {
+    v.replace('|', "\\|").replace('\n', "<br>")
+}
+
+fn escape_html(v: &str) -> String {
+    let mut out = String::with_capacity(v.len());
+    for c in v.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn escape_latex(v: &str) -> String {
+    let mut out = String::with_capacity(v.len());
+    for c in v.chars() {
+        match c {
+            '\\' => out.push_str("\\textbackslash{}"),
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c)
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+impl DataFrame {
+    /// Render the `DataFrame` as a GitHub flavored markdown table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => [1, 2], "b" => ["x", "y"]).unwrap();
+    /// assert_eq!(
+    ///     df.to_markdown(&Default::default()),
+    ///     "| a | b |\n| ---: | --- |\n| 1 | x |\n| 2 | y |\n"
+    /// );
+    /// ```
+    pub fn to_markdown(&self, options: &RenderOptions) -> String {
+        let cells = Cells::new(self, options);
+        let mut out = String::new();
+
+        let write_row = |out: &mut String, row: &[String]| {
+            out.push('|');
+            for v in row {
+                // writing to a string cannot fail
+                write!(out, " {} |", escape_markdown(v)).unwrap();
+            }
+            out.push('\n');
+        };
+
+        if options.show_dtypes {
+            let header = cells
+                .header
+                .iter()
+                .zip(&cells.dtypes)
+                .map(|(name, dtype)| format!("{} ({})", name, dtype))
+                .collect::<Vec<_>>();
+            write_row(&mut out, &header)
+        } else {
+            write_row(&mut out, &cells.header)
+        }
+        out.push('|');
+        for numeric in &cells.numeric {
+            out.push_str(if *numeric { " ---: |" } else { " --- |" });
+        }
+        out.push('\n');
+        for row in &cells.rows {
+            write_row(&mut out, row);
+        }
+        out
+    }
+
+    /// Render the `DataFrame` as an HTML `<table>`.
+    pub fn to_html(&self, options: &RenderOptions) -> String {
+        let cells = Cells::new(self, options);
+        let mut out = String::from("<table>\n<thead>\n<tr>");
+        for name in &cells.header {
+            write!(out, "<th>{}</th>", escape_html(name)).unwrap();
+        }
+        out.push_str("</tr>\n");
+        if options.show_dtypes {
+            out.push_str("<tr>");
+            for dtype in &cells.dtypes {
+                write!(out, "<td>{}</td>", escape_html(dtype)).unwrap();
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</thead>\n<tbody>\n");
+        for row in &cells.rows {
+            out.push_str("<tr>");
+            for v in row {
+                write!(out, "<td>{}</td>", escape_html(v)).unwrap();
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</tbody>\n</table>\n");
+        out
+    }
+
+    /// Render the `DataFrame` as a LaTeX `tabular`.
+    pub fn to_latex(&self, options: &RenderOptions) -> String {
+        let cells = Cells::new(self, options);
+        let spec = cells
+            .numeric
+            .iter()
+            .map(|numeric| if *numeric { 'r' } else { 'l' })
+            .collect::<String>();
+
+        let write_row = |out: &mut String, row: &[String]| {
+            let row = row.iter().map(|v| escape_latex(v)).collect::<Vec<_>>();
+            writeln!(out, "{} \\\\", row.join(" & ")).unwrap();
+        };
+
+        let mut out = String::new();
+        writeln!(out, "\\begin{{tabular}}{{{}}}", spec).unwrap();
+        out.push_str("\\hline\n");
+        write_row(&mut out, &cells.header);
+        if options.show_dtypes {
+            write_row(&mut out, &cells.dtypes);
+        }
+        out.push_str("\\hline\n");
+        for row in &cells.rows {
+            write_row(&mut out, row);
+        }
+        out.push_str("\\hline\n\\end{tabular}\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_render_truncated() {
+        let df = df!(
+            "a" => (0..10).collect::<Vec<i32>>(),
+            "b" => (0..10).map(|i| format!("v_{}|", i)).collect::<Vec<_>>(),
+            "c" => (0..10).map(|i| i as f64).collect::<Vec<_>>()
+        )
+        .unwrap();
+        let options = RenderOptions {
+            max_rows: Some(2),
+            max_cols: Some(2),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            df.to_markdown(&options),
+            "| a | ... | c |\n| ---: | --- | ---: |\n| 0 | ... | 0.0 |\n| ... | ... | ... |\n| 9 | ... | 9.0 |\n"
+        );
+
+        let options = RenderOptions {
+            max_rows: Some(1),
+            show_dtypes: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            df.to_latex(&options),
+            "\\begin{tabular}{rlr}\n\\hline\na & b & c \\\\\ni32 & str & f64 \\\\\n\\hline\n\
+             0 & v\\_0| & 0.0 \\\\\n... & ... & ... \\\\\n9 & v\\_9| & 9.0 \\\\\n\\hline\n\\end{tabular}\n"
+        );
+    }
+
+    #[test]
+    fn test_render_html_escape() {
+        let df = df!("<a>" => ["x & y"]).unwrap();
+        assert_eq!(
+            df.to_html(&Default::default()),
+            "<table>\n<thead>\n<tr><th>&lt;a&gt;</th></tr>\n</thead>\n<tbody>\n\
+             <tr><td>x &amp; y</td></tr>\n</tbody>\n</table>\n"
+        );
+    }
+}
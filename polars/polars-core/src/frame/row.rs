@@ -53,6 +53,35 @@ impl DataFrame {
             });
     }
 
+    /// Overwrite the row at `idx` with the values of `row`, which are cast to the data types of
+    /// the columns. The columns are copied (see [`Series::set_at_idx`]), so other `DataFrame`s
+    /// sharing them are not affected; this `DataFrame` is left unchanged if a value cannot be set.
+    #[cfg_attr(docsrs, doc(cfg(feature = "rows")))]
+    pub fn set_row_at_idx(&mut self, idx: usize, row: &Row) -> Result<&mut Self> {
+        if row.0.len() != self.width() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "the row has {} values, but the DataFrame has {} columns",
+                    row.0.len(),
+                    self.width()
+                )
+                .into(),
+            ));
+        }
+        let take = IdxCa::new("", &[idx as IdxSize]);
+        let columns = self
+            .columns
+            .iter()
+            .zip(&row.0)
+            .map(|(s, av)| {
+                let value = Series::from_any_values("", std::slice::from_ref(av))?;
+                s.set_at_idx(&take, &value)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.columns = columns;
+        Ok(self)
+    }
+
     /// Create a new DataFrame from rows. This should only be used when you have row wise data,
     /// as this is a lot slower than creating the `Series` in a columnar fashion
     #[cfg_attr(docsrs, doc(cfg(feature = "rows")))]
@@ -561,4 +590,33 @@ mod test {
         assert!(out.frame_equal_missing(&expected));
         Ok(())
     }
+
+    #[test]
+    fn test_set_row_at_idx() -> Result<()> {
+        let df = df![
+            "a" => [1, 2, 3],
+            "b" => [Some("x"), Some("y"), None],
+        ]?;
+        let mut out = df.clone();
+        out.set_row_at_idx(1, &Row::new(vec![AnyValue::Int64(20), AnyValue::Null]))?;
+        let expected = df![
+            "a" => [1, 20, 3],
+            "b" => [Some("x"), None, None],
+        ]?;
+        assert!(out.frame_equal_missing(&expected));
+        // the original is not changed
+        assert_eq!(
+            Vec::from(df.column("a")?.i32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+
+        assert!(out
+            .set_row_at_idx(3, &Row::new(vec![AnyValue::Null; 2]))
+            .is_err());
+        assert!(out
+            .set_row_at_idx(0, &Row::new(vec![AnyValue::Null]))
+            .is_err());
+        assert!(out.frame_equal_missing(&expected));
+        Ok(())
+    }
 }
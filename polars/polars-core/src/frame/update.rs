@@ -0,0 +1,143 @@
+use crate::prelude::*;
+use crate::utils::NoNull;
+
+/// How [`DataFrame::update`] merges the values of the other `DataFrame`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum UpdateStrategy {
+    /// Overwrite the values of the matching rows with the non-null values of the other `DataFrame`.
+    #[default]
+    Overwrite,
+    /// Only fill the null values of the matching rows.
+    FillNull,
+}
+
+const UPDATE_IDX: &str = "__POLARS_UPDATE_IDX";
+
+impl DataFrame {
+    /// Update the values of this `DataFrame` with the values of `other`, matching the rows on
+    /// the key columns `on`.
+    ///
+    /// The columns of `other` that are not keys and have the same name as a column of this
+    /// `DataFrame` are updated; the other columns of `other` are ignored. Rows without a match
+    /// are not changed. The updated values are cast to the data type of this `DataFrame`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the keys of `other` are not unique.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("id" => [1, 2, 3], "value" => [Some(1.0), None, Some(3.0)]).unwrap();
+    /// let corrections = df!("id" => [2, 3], "value" => [Some(20.0), None]).unwrap();
+    ///
+    /// let out = df.update(&corrections, ["id"], UpdateStrategy::Overwrite).unwrap();
+    /// assert_eq!(
+    ///     Vec::from(out.column("value").unwrap().f64().unwrap()),
+    ///     &[Some(1.0), Some(20.0), Some(3.0)]
+    /// );
+    /// ```
+    pub fn update<I, S>(&self, other: &DataFrame, on: I, how: UpdateStrategy) -> Result<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let on = on
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect::<Vec<_>>();
+        let keys_left = self.select(&on)?;
+        let keys_right = other.select(&on)?.with_row_count(UPDATE_IDX, None)?;
+
+        // the position of the matching row of `other` for every row
+        let joined = keys_left.join_with_validation(
+            &keys_right,
+            &on,
+            &on,
+            JoinType::Left,
+            None,
+            JoinValidation::ManyToOne,
+        )?;
+        let right_idx = joined.column(UPDATE_IDX)?.idx()?;
+
+        let height = self.height() as IdxSize;
+        let mut columns = self.columns.clone();
+        for s in columns.iter_mut() {
+            if on.iter().any(|name| name == s.name()) {
+                continue;
+            }
+            let new = match other.column(s.name()) {
+                Ok(new) => new.cast(s.dtype())?,
+                Err(_) => continue,
+            };
+            let new_is_valid = new.is_not_null().rechunk();
+            let is_null = s.is_null();
+            let mut updated = false;
+
+            // take from the concatenation of the original and the new values,
+            // the new values start at `height`
+            let take: NoNull<IdxCa> = right_idx
+                .into_iter()
+                .zip(is_null.into_no_null_iter())
+                .enumerate()
+                .map(|(i, (right_idx, is_null))| match right_idx {
+                    Some(j)
+                        if new_is_valid.get(j as usize) == Some(true)
+                            && (how == UpdateStrategy::Overwrite || is_null) =>
+                    {
+                        updated = true;
+                        height + j
+                    }
+                    _ => i as IdxSize,
+                })
+                .collect_trusted();
+            if !updated {
+                continue;
+            }
+            let mut values = s.clone();
+            values.append(&new)?;
+            *s = values.take(&take.into_inner())?;
+        }
+        Ok(DataFrame::new_no_checks(columns))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_update() -> Result<()> {
+        let df = df![
+            "a" => [1, 1, 2, 3],
+            "b" => ["x", "y", "x", "x"],
+            "c" => [Some(1), None, None, Some(4)],
+            "d" => [1.0, 2.0, 3.0, 4.0]
+        ]?;
+        let other = df![
+            "b" => ["x", "y", "x"],
+            "a" => [1, 1, 3],
+            "c" => [Some(10), Some(20), None],
+            "e" => [0, 0, 0]
+        ]?;
+
+        let out = df.update(&other, ["a", "b"], UpdateStrategy::Overwrite)?;
+        assert_eq!(
+            Vec::from(out.column("c")?.i32()?),
+            &[Some(10), Some(20), None, Some(4)]
+        );
+        assert_eq!(out.get_column_names(), df.get_column_names());
+        assert!(out.column("d")?.series_equal(df.column("d")?));
+
+        let out = df.update(&other, ["a", "b"], UpdateStrategy::FillNull)?;
+        assert_eq!(
+            Vec::from(out.column("c")?.i32()?),
+            &[Some(1), Some(20), None, Some(4)]
+        );
+
+        // the keys of other must be unique
+        assert!(df.update(&df, ["a"], UpdateStrategy::Overwrite).is_err());
+        Ok(())
+    }
+}
@@ -108,7 +108,8 @@ pub fn argsort_by(by: &[Series], reverse: &[bool]) -> Result<IdxCa> {
             .into(),
         ));
     }
-    let (first, by, reverse) = prepare_argsort(by.to_vec(), reverse.to_vec()).unwrap();
+    let (first, by, reverse) =
+        prepare_argsort(by.to_vec(), reverse.to_vec(), NanPosition::default())?;
     first.argsort_multiple(&by, &reverse)
 }
 
@@ -2,8 +2,9 @@
 extern crate core;
 #[macro_use]
 pub mod utils;
+pub mod cancel;
 pub mod chunked_array;
-pub(crate) mod config;
+pub mod config;
 pub mod datatypes;
 #[cfg(feature = "docs")]
 pub mod doc;
@@ -12,6 +13,7 @@ pub mod export;
 mod fmt;
 pub mod frame;
 pub mod functions;
+pub mod memory;
 mod named_from;
 pub mod prelude;
 pub mod schema;
@@ -26,24 +28,48 @@ pub(crate) mod vector_hasher;
 
 use once_cell::sync::Lazy;
 
-#[cfg(feature = "object")]
+#[cfg(all(
+    not(target_family = "wasm"),
+    any(feature = "object", feature = "dtype-categorical")
+))]
 use std::time::{SystemTime, UNIX_EPOCH};
 
+#[cfg(not(target_family = "wasm"))]
 use rayon::{ThreadPool, ThreadPoolBuilder};
 use std::sync::Mutex;
 
+pub use crate::chunked_array::kernels;
 #[cfg(feature = "dtype-categorical")]
 pub use crate::chunked_array::logical::categorical::stringcache::*;
 
 #[cfg(feature = "object")]
-pub(crate) static PROCESS_ID: Lazy<u128> = Lazy::new(|| {
+pub(crate) static PROCESS_ID: Lazy<u128> = Lazy::new(unique_id);
+
+/// An id that is unique for the lifetime of the process.
+#[cfg(all(
+    not(target_family = "wasm"),
+    any(feature = "object", feature = "dtype-categorical")
+))]
+pub(crate) fn unique_id() -> u128 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap()
         .as_nanos()
-});
+}
+
+/// There is no system clock on `wasm32-unknown-unknown`, so the ids are counted instead.
+#[cfg(all(
+    target_family = "wasm",
+    any(feature = "object", feature = "dtype-categorical")
+))]
+pub(crate) fn unique_id() -> u128 {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static COUNTER: AtomicU64 = AtomicU64::new(1);
+    COUNTER.fetch_add(1, Ordering::Relaxed) as u128
+}
 
 // this is re-exported in utils for polars child crates
+#[cfg(not(target_family = "wasm"))]
 pub static POOL: Lazy<ThreadPool> = Lazy::new(|| {
     ThreadPoolBuilder::new()
         .num_threads(
@@ -59,5 +85,9 @@ pub static POOL: Lazy<ThreadPool> = Lazy::new(|| {
         .expect("could not spawn threads")
 });
 
+// wasm can't spawn threads, everything runs on the calling thread
+#[cfg(target_family = "wasm")]
+pub static POOL: Lazy<polars_utils::wasm::Pool> = Lazy::new(|| polars_utils::wasm::Pool);
+
 // utility for the tests to ensure a single thread can execute
 pub static SINGLE_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
@@ -0,0 +1,148 @@
+//! Memory usage introspection.
+//!
+//! The size of the data of a `Series` or `DataFrame` is estimated with
+//! [`Series::estimated_size`](crate::series::Series::estimated_size) and
+//! [`DataFrame::estimated_size`](crate::frame::DataFrame::estimated_size).
+//!
+//! The memory of the whole process is tracked by installing the [`TrackingAllocator`] as the
+//! global allocator.
+//!
+//! ```rust
+//! use polars_core::memory::TrackingAllocator;
+//!
+//! #[global_allocator]
+//! static ALLOC: TrackingAllocator = TrackingAllocator::new();
+//! ```
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// A global allocator that keeps track of the allocated and the peak allocated bytes and
+/// delegates the allocations to an inner allocator.
+///
+/// The counters are global, so all `TrackingAllocator`s share them.
+#[derive(Debug, Default)]
+pub struct TrackingAllocator<A = System> {
+    inner: A,
+}
+
+impl TrackingAllocator<System> {
+    /// Track the allocations of the [`System`] allocator.
+    pub const fn new() -> Self {
+        TrackingAllocator { inner: System }
+    }
+}
+
+impl<A> TrackingAllocator<A> {
+    /// Track the allocations of another allocator, e.g. jemalloc or mimalloc.
+    pub const fn with_allocator(inner: A) -> Self {
+        TrackingAllocator { inner }
+    }
+}
+
+#[inline]
+fn add(size: usize) {
+    let allocated = ALLOCATED.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(allocated, Ordering::Relaxed);
+}
+
+#[inline]
+fn sub(size: usize) {
+    ALLOCATED.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc(layout);
+        if !ptr.is_null() {
+            add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout);
+        sub(layout.size());
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.inner.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            sub(layout.size());
+            add(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Check if the [`TrackingAllocator`] is used, i.e. if it has allocated any memory.
+pub fn using_tracking_allocator() -> bool {
+    PEAK.load(Ordering::Relaxed) > 0
+}
+
+/// The number of bytes that are currently allocated by the [`TrackingAllocator`].
+pub fn allocated_bytes() -> usize {
+    ALLOCATED.load(Ordering::Relaxed)
+}
+
+/// The maximum number of bytes that was allocated by the [`TrackingAllocator`]
+/// since the start of the process or the last [`reset_peak_allocated_bytes`].
+pub fn peak_allocated_bytes() -> usize {
+    PEAK.load(Ordering::Relaxed)
+}
+
+/// Reset the peak to the currently allocated bytes and return the previous peak.
+///
+/// Measure the peak memory of a computation by resetting the peak before it starts and reading
+/// [`peak_allocated_bytes`] after it finishes. Note that the peak includes the allocations of
+/// all threads, including those that are not part of the computation.
+pub fn reset_peak_allocated_bytes() -> usize {
+    PEAK.swap(ALLOCATED.load(Ordering::Relaxed), Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_tracking_allocator() {
+        let alloc = TrackingAllocator::new();
+        let before = allocated_bytes();
+        unsafe {
+            let layout = Layout::from_size_align(1024, 8).unwrap();
+            let ptr = alloc.alloc(layout);
+            assert_eq!(allocated_bytes(), before + 1024);
+            let ptr = alloc.realloc(ptr, layout, 4096);
+            assert_eq!(allocated_bytes(), before + 4096);
+            assert!(peak_allocated_bytes() >= before + 4096);
+            alloc.dealloc(ptr, Layout::from_size_align(4096, 8).unwrap());
+        }
+        assert_eq!(allocated_bytes(), before);
+        assert!(using_tracking_allocator());
+        reset_peak_allocated_bytes();
+        assert_eq!(peak_allocated_bytes(), before);
+    }
+
+    #[test]
+    fn test_estimated_size() {
+        // the validity and the string data are included
+        let s = Series::new("a", &["a", "bb", "ccc"]);
+        let with_nulls = Series::new("a", &[Some("a"), None, Some("ccc")]);
+        assert!(s.estimated_size() >= 3 * std::mem::size_of::<i64>() + 6);
+        assert!(with_nulls.estimated_size() > Series::new("a", &["a", "", "ccc"]).estimated_size());
+
+        let df = DataFrame::new(vec![s.clone(), Series::new("b", &[1i64, 2, 3])]).unwrap();
+        assert_eq!(df.estimated_size(), s.estimated_size() + 3 * 8);
+    }
+}
@@ -16,17 +16,18 @@ pub use crate::{
     datatypes,
     datatypes::*,
     df,
-    error::{PolarsError, Result},
+    error::{ErrorCode, ErrorContext, PolarsError, Result},
     frame::{
-        explode::MeltArgs,
+        explode::{ExplodeOptions, MeltArgs},
         groupby::{GroupsIdx, GroupsProxy, GroupsSlice},
-        hash_join::JoinType,
+        hash_join::{JoinType, JoinValidation},
         *,
     },
     named_from::{NamedFrom, NamedFromOwned},
     schema::*,
     series::{
         arithmetic::{LhsNumOps, NumOpsDispatch},
+        ops::CastOptions,
         IntoSeries, Series, SeriesTrait,
     },
     testing::*,
@@ -44,6 +45,10 @@ pub use crate::chunked_array::object::PolarsObject;
 pub use crate::chunked_array::temporal::conversion::*;
 #[cfg(feature = "checked_arithmetic")]
 pub use crate::series::arithmetic::checked::NumOpsDispatchChecked;
+#[cfg(feature = "checked_arithmetic")]
+pub use crate::series::arithmetic::{ArithmeticOp, OverflowPolicy};
+#[cfg(feature = "round_series")]
+pub use crate::series::ops::RoundMode;
 
 #[cfg(feature = "rank")]
 pub use crate::chunked_array::ops::unique::rank::{RankMethod, RankOptions};
@@ -51,6 +56,9 @@ pub use crate::chunked_array::ops::unique::rank::{RankMethod, RankOptions};
 #[cfg(feature = "rolling_window")]
 pub use crate::chunked_array::ops::rolling_window::RollingOptionsFixedWindow;
 
+#[cfg(feature = "string_encoding")]
+pub use crate::chunked_array::strings::{DecodeStrategy, StringEncoding};
+
 #[cfg(feature = "ewma")]
 pub use polars_arrow::kernels::ewm::EWMOptions;
 
@@ -62,3 +70,9 @@ pub use crate::chunked_array::logical::categorical::*;
 
 #[cfg(feature = "asof_join")]
 pub use crate::frame::asof_join::*;
+#[cfg(feature = "join_where")]
+pub use crate::frame::join_where::InequalityOperator;
+pub use crate::frame::update::UpdateStrategy;
+
+#[cfg(feature = "fmt")]
+pub use crate::frame::{glimpse::GlimpseOptions, render::RenderOptions};
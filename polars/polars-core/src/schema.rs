@@ -113,6 +113,10 @@ impl Schema {
         self.inner.get(name)
     }
 
+    pub fn contains(&self, name: &str) -> bool {
+        self.inner.contains_key(name)
+    }
+
     pub fn get_full(&self, name: &str) -> Option<(usize, &String, &DataType)> {
         self.inner.get_full(name)
     }
@@ -170,6 +174,47 @@ impl Schema {
     pub fn iter(&self) -> impl Iterator<Item = (&String, &DataType)> + '_ {
         self.inner.iter()
     }
+
+    /// Get the columns that `other` adds, removes or changes the dtype of, in the order of
+    /// the schema they are in. Schemas that only differ in column order have an empty diff,
+    /// just like they compare equal.
+    pub fn diff(&self, other: &Schema) -> SchemaDiff {
+        let mut diff = SchemaDiff::default();
+        for (name, dtype) in self.iter() {
+            match other.get(name) {
+                None => diff.removed.with_column(name.clone(), dtype.clone()),
+                Some(other_dtype) if other_dtype != dtype => {
+                    diff.changed
+                        .push((name.clone(), dtype.clone(), other_dtype.clone()))
+                }
+                _ => {}
+            }
+        }
+        for (name, dtype) in other.iter() {
+            if !self.contains(name) {
+                diff.added.with_column(name.clone(), dtype.clone())
+            }
+        }
+        diff
+    }
+}
+
+/// The differences between two schemas, see [`Schema::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub struct SchemaDiff {
+    /// The columns that are only in the other schema.
+    pub added: Schema,
+    /// The columns that are only in this schema.
+    pub removed: Schema,
+    /// The columns of which the dtype changed, as `(name, dtype, other dtype)`.
+    pub changed: Vec<(String, DataType, DataType)>,
+}
+
+impl SchemaDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
 }
 
 pub type SchemaRef = Arc<Schema>;
@@ -204,3 +249,39 @@ impl IndexOfSchema for ArrowSchema {
         self.fields.iter().position(|f| f.name == name)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_schema_diff() {
+        let a = Schema::from([
+            Field::new("a", DataType::Int32),
+            Field::new("b", DataType::Utf8),
+            Field::new("c", DataType::Boolean),
+        ]);
+        let b = Schema::from([
+            Field::new("d", DataType::Float64),
+            Field::new("b", DataType::Int64),
+            Field::new("a", DataType::Int32),
+        ]);
+        let diff = a.diff(&b);
+        assert_eq!(diff.added.iter_names().collect::<Vec<_>>(), &["d"]);
+        assert_eq!(diff.removed.get("c"), Some(&DataType::Boolean));
+        assert_eq!(
+            diff.changed,
+            &[("b".to_string(), DataType::Utf8, DataType::Int64)]
+        );
+        assert!(!diff.is_empty());
+
+        // the order of the columns doesn't matter
+        let c = Schema::from([
+            Field::new("c", DataType::Boolean),
+            Field::new("a", DataType::Int32),
+            Field::new("b", DataType::Utf8),
+        ]);
+        assert!(a.diff(&c).is_empty());
+        assert_eq!(a, c);
+    }
+}
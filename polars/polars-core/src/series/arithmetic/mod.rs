@@ -1,4 +1,6 @@
 mod borrowed;
+#[cfg(feature = "checked_arithmetic")]
+mod overflow;
 mod owned;
 
 use crate::prelude::*;
@@ -9,3 +11,5 @@ use std::fmt::Debug;
 use std::ops::{self, Add, Div, Mul, Sub};
 
 pub use borrowed::*;
+#[cfg(feature = "checked_arithmetic")]
+pub use overflow::{ArithmeticOp, OverflowPolicy};
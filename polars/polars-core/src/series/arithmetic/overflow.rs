@@ -0,0 +1,248 @@
+//! Integer arithmetic with an explicit policy for values that overflow.
+use crate::prelude::*;
+use crate::utils::get_supertype;
+use num::traits::{WrappingAdd, WrappingMul, WrappingSub};
+use num::{Bounded, CheckedAdd, CheckedMul, CheckedSub, Zero};
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
+
+/// The arithmetic operations of [`Series::arithmetic_with_overflow`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum ArithmeticOp {
+    Add,
+    Sub,
+    Mul,
+}
+
+/// What to do with the values of an integer operation that don't fit in the integer type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum OverflowPolicy {
+    /// Wrap around at the bounds of the type.
+    Wrap,
+    /// Set the values that overflow to null.
+    Null,
+    /// Raise an error if any value overflows.
+    Error,
+    /// Clamp the values that overflow to the bounds of the type.
+    Saturate,
+    /// Compute in the 64 bit integer type of the same signedness, in which the operations on
+    /// smaller integers cannot overflow. 64 bit integers raise an error if a value overflows.
+    Promote,
+}
+
+impl OverflowPolicy {
+    /// The output dtype of an operation on integers of (super)type `dtype`.
+    pub fn output_dtype(&self, dtype: &DataType) -> DataType {
+        use DataType::*;
+        match (self, dtype) {
+            (OverflowPolicy::Promote, Int8 | Int16 | Int32) => Int64,
+            (OverflowPolicy::Promote, UInt8 | UInt16 | UInt32) => UInt64,
+            _ => dtype.clone(),
+        }
+    }
+}
+
+fn apply_with_overflow<T>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<T>,
+    op: ArithmeticOp,
+    policy: OverflowPolicy,
+) -> Result<ChunkedArray<T>>
+where
+    T: PolarsIntegerType,
+    T::Native: CheckedAdd + CheckedSub + CheckedMul + WrappingAdd + WrappingSub + WrappingMul,
+{
+    let zero = T::Native::zero();
+    let saturated = |l: T::Native, r: T::Native| match op {
+        ArithmeticOp::Add if r > zero => T::Native::max_value(),
+        ArithmeticOp::Sub if r < zero => T::Native::max_value(),
+        ArithmeticOp::Mul if (l < zero) == (r < zero) => T::Native::max_value(),
+        _ => T::Native::min_value(),
+    };
+    let mut overflowed = false;
+
+    let mut out: ChunkedArray<T> = lhs
+        .into_iter()
+        .zip(rhs)
+        .map(|(l, r)| {
+            let (l, r) = (l?, r?);
+            if let OverflowPolicy::Wrap = policy {
+                return Some(match op {
+                    ArithmeticOp::Add => l.wrapping_add(&r),
+                    ArithmeticOp::Sub => l.wrapping_sub(&r),
+                    ArithmeticOp::Mul => l.wrapping_mul(&r),
+                });
+            }
+            let out = match op {
+                ArithmeticOp::Add => l.checked_add(&r),
+                ArithmeticOp::Sub => l.checked_sub(&r),
+                ArithmeticOp::Mul => l.checked_mul(&r),
+            };
+            match (out, policy) {
+                (Some(v), _) => Some(v),
+                (None, OverflowPolicy::Saturate) => Some(saturated(l, r)),
+                (None, OverflowPolicy::Null) => None,
+                (None, _) => {
+                    overflowed = true;
+                    None
+                }
+            }
+        })
+        .collect_trusted();
+
+    if overflowed {
+        return Err(PolarsError::ComputeError(
+            format!(
+                "integer overflow in {:?} of '{}' and '{}' with dtype {:?}",
+                op,
+                lhs.name(),
+                rhs.name(),
+                T::get_dtype()
+            )
+            .into(),
+        ));
+    }
+    out.rename(lhs.name());
+    Ok(out)
+}
+
+impl Series {
+    /// Apply an arithmetic operation to integer Series where `policy` determines what happens to
+    /// the values that overflow. The operators `+`, `-` and `*` don't check for overflow.
+    ///
+    /// Non-integer types are computed as with the operators, as they cannot overflow.
+    /// A Series of length 1 is broadcasted.
+    #[cfg_attr(docsrs, doc(cfg(feature = "checked_arithmetic")))]
+    pub fn arithmetic_with_overflow(
+        &self,
+        rhs: &Series,
+        op: ArithmeticOp,
+        policy: OverflowPolicy,
+    ) -> Result<Series> {
+        let dtype = get_supertype(self.dtype(), rhs.dtype())?;
+        if !dtype.is_numeric() || matches!(dtype, DataType::Float32 | DataType::Float64) {
+            return Ok(match op {
+                ArithmeticOp::Add => self + rhs,
+                ArithmeticOp::Sub => self - rhs,
+                ArithmeticOp::Mul => self * rhs,
+            });
+        }
+        let len = match (self.len(), rhs.len()) {
+            (l, r) if l == r || r == 1 => l,
+            (1, r) => r,
+            (l, r) => {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!("cannot apply {:?} to Series of length {} and {}", op, l, r).into(),
+                ))
+            }
+        };
+        let dtype = policy.output_dtype(&dtype);
+        let broadcast = |s: &Series| -> Result<Series> {
+            let s = s.cast(&dtype)?;
+            Ok(if s.len() == len {
+                s
+            } else {
+                s.expand_at_index(0, len)
+            })
+        };
+        let (l, r) = (broadcast(self)?, broadcast(rhs)?);
+        let policy = match policy {
+            OverflowPolicy::Promote => OverflowPolicy::Error,
+            policy => policy,
+        };
+
+        use DataType::*;
+        let out = match dtype {
+            #[cfg(feature = "dtype-i8")]
+            Int8 => apply_with_overflow(l.i8()?, r.i8()?, op, policy)?.into_series(),
+            #[cfg(feature = "dtype-i16")]
+            Int16 => apply_with_overflow(l.i16()?, r.i16()?, op, policy)?.into_series(),
+            Int32 => apply_with_overflow(l.i32()?, r.i32()?, op, policy)?.into_series(),
+            Int64 => apply_with_overflow(l.i64()?, r.i64()?, op, policy)?.into_series(),
+            #[cfg(feature = "dtype-u8")]
+            UInt8 => apply_with_overflow(l.u8()?, r.u8()?, op, policy)?.into_series(),
+            #[cfg(feature = "dtype-u16")]
+            UInt16 => apply_with_overflow(l.u16()?, r.u16()?, op, policy)?.into_series(),
+            UInt32 => apply_with_overflow(l.u32()?, r.u32()?, op, policy)?.into_series(),
+            UInt64 => apply_with_overflow(l.u64()?, r.u64()?, op, policy)?.into_series(),
+            dt => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("cannot apply {:?} with overflow policy to {:?}", op, dt).into(),
+                ))
+            }
+        };
+        Ok(out)
+    }
+
+    /// Add `rhs` where `policy` determines what happens to values that overflow.
+    #[cfg_attr(docsrs, doc(cfg(feature = "checked_arithmetic")))]
+    pub fn checked_add(&self, rhs: &Series, policy: OverflowPolicy) -> Result<Series> {
+        self.arithmetic_with_overflow(rhs, ArithmeticOp::Add, policy)
+    }
+
+    /// Subtract `rhs` where `policy` determines what happens to values that overflow.
+    #[cfg_attr(docsrs, doc(cfg(feature = "checked_arithmetic")))]
+    pub fn checked_sub(&self, rhs: &Series, policy: OverflowPolicy) -> Result<Series> {
+        self.arithmetic_with_overflow(rhs, ArithmeticOp::Sub, policy)
+    }
+
+    /// Multiply with `rhs` where `policy` determines what happens to values that overflow.
+    #[cfg_attr(docsrs, doc(cfg(feature = "checked_arithmetic")))]
+    pub fn checked_mul(&self, rhs: &Series, policy: OverflowPolicy) -> Result<Series> {
+        self.arithmetic_with_overflow(rhs, ArithmeticOp::Mul, policy)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_overflow_policies() -> Result<()> {
+        let a = Series::new("a", [Some(i32::MAX), Some(-2), None, Some(i32::MIN)]);
+        let b = Series::new("b", [1i32]);
+
+        let out = a.checked_add(&b, OverflowPolicy::Wrap)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(i32::MIN), Some(-1), None, Some(i32::MIN + 1)]
+        );
+        let out = a.checked_add(&b, OverflowPolicy::Null)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[None, Some(-1), None, Some(i32::MIN + 1)]
+        );
+        let out = a.checked_add(&b, OverflowPolicy::Saturate)?;
+        assert_eq!(out.i32()?.get(0), Some(i32::MAX));
+        let out = a.checked_sub(&b, OverflowPolicy::Saturate)?;
+        assert_eq!(out.i32()?.get(3), Some(i32::MIN));
+        let out = a.checked_mul(&Series::new("", [-1i32]), OverflowPolicy::Saturate)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(-i32::MAX), Some(2), None, Some(i32::MAX)]
+        );
+        assert!(a.checked_add(&b, OverflowPolicy::Error).is_err());
+        assert!(a.slice(0, 3).checked_sub(&b, OverflowPolicy::Error).is_ok());
+
+        let out = a.checked_mul(&a, OverflowPolicy::Promote)?;
+        assert_eq!(out.dtype(), &DataType::Int64);
+        assert_eq!(out.i64()?.get(0), Some(i32::MAX as i64 * i32::MAX as i64));
+
+        // the supertype of unsigned and signed integers can hold negative values
+        let u = Series::new("u", [0u32]);
+        let out = u.checked_sub(&b, OverflowPolicy::Error)?;
+        assert_eq!(out.dtype(), &DataType::Int64);
+        assert_eq!(out.i64()?.get(0), Some(-1));
+        let u = Series::new("u", [0u32, 1]);
+        let out = u.checked_sub(&Series::new("", [1u32]), OverflowPolicy::Saturate)?;
+        assert_eq!(Vec::from(out.u32()?), &[Some(0), Some(0)]);
+
+        // floats don't overflow
+        let f = Series::new("f", [f64::MAX]);
+        assert!(f.checked_add(&f, OverflowPolicy::Error).is_ok());
+        Ok(())
+    }
+}
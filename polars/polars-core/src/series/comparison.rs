@@ -162,6 +162,13 @@ impl ChunkCompare<&Series> for Series {
                     return Err(PolarsError::ComputeError("Cannot compare categoricals originating from different sources. Consider setting a global string cache.".into()));
                 }
             }
+            // broadcast, the kernels zip the values of both sides
+            (_, _, 1, len) if len != 1 => {
+                return self.expand_at_index(0, len).eq_missing(rhs);
+            }
+            (_, _, len, 1) if len != 1 => {
+                return self.eq_missing(&rhs.expand_at_index(0, len));
+            }
             _ => {
                 impl_compare!(self, rhs, eq_missing)
             }
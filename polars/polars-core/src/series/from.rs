@@ -461,6 +461,29 @@ impl TryFrom<(&str, ArrayRef)> for Series {
     }
 }
 
+impl TryFrom<(&ArrowField, Vec<ArrayRef>)> for Series {
+    type Error = PolarsError;
+
+    /// Create a Series with the name and the key/value metadata of the arrow field.
+    fn try_from(field_arr: (&ArrowField, Vec<ArrayRef>)) -> Result<Self> {
+        let (field, chunks) = field_arr;
+        let mut s = Series::try_from((field.name.as_str(), chunks))?;
+        if !field.metadata.is_empty() {
+            s.set_metadata(field.metadata.clone());
+        }
+        Ok(s)
+    }
+}
+
+impl TryFrom<(&ArrowField, ArrayRef)> for Series {
+    type Error = PolarsError;
+
+    fn try_from(field_arr: (&ArrowField, ArrayRef)) -> Result<Self> {
+        let (field, arr) = field_arr;
+        Series::try_from((field, vec![arr]))
+    }
+}
+
 pub trait IntoSeries {
     fn is_series() -> bool {
         false
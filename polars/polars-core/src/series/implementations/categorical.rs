@@ -166,6 +166,10 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         self.0.logical_mut().rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.0.logical_mut().set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkIdIter {
         self.0.logical().chunk_id()
     }
@@ -201,6 +205,10 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
     fn extend(&mut self, other: &Series) -> Result<()> {
         if self.0.dtype() == other.dtype() {
             let other = other.categorical()?;
+            if !self.0.get_rev_map().same_src(other.get_rev_map()) {
+                // the categories are re-encoded, so the chunks can't be extended in place
+                return self.0.append(other);
+            }
             self.0.logical_mut().extend(other.logical());
             let new_rev_map = self.0.merge_categorical_map(other)?;
             // safety:
@@ -104,12 +104,21 @@ macro_rules! impl_dyn_series {
             ) -> Series {
                 self.0
                     .agg_quantile(groups, quantile, interpol)
-                    .$into_logical()
-                    .into_series()
+                    // cast f64 back to physical type
+                    .cast(&self.dtype().to_physical())
+                    .unwrap()
+                    .cast(self.dtype())
+                    .unwrap()
             }
 
             unsafe fn agg_median(&self, groups: &GroupsProxy) -> Series {
-                self.0.agg_median(groups).$into_logical().into_series()
+                self.0
+                    .agg_median(groups)
+                    // cast f64 back to physical type
+                    .cast(&self.dtype().to_physical())
+                    .unwrap()
+                    .cast(self.dtype())
+                    .unwrap()
             }
 
             fn zip_outer_join_column(
@@ -196,6 +205,10 @@ macro_rules! impl_dyn_series {
                 self.0.rename(name);
             }
 
+            fn set_metadata(&mut self, metadata: FieldMetadata) {
+                self.0.set_metadata(metadata);
+            }
+
             fn chunk_lengths(&self) -> ChunkIdIter {
                 self.0.chunk_id()
             }
@@ -440,10 +453,12 @@ macro_rules! impl_dyn_series {
                 self.0.min_as_series().$into_logical()
             }
             fn median_as_series(&self) -> Series {
-                Int32Chunked::full_null(self.name(), 1)
+                self.0
+                    .median_as_series()
+                    .cast(&self.dtype().to_physical())
+                    .unwrap()
                     .cast(self.dtype())
                     .unwrap()
-                    .into()
             }
             fn var_as_series(&self, _ddof: u8) -> Series {
                 Int32Chunked::full_null(self.name(), 1)
@@ -459,13 +474,13 @@ macro_rules! impl_dyn_series {
             }
             fn quantile_as_series(
                 &self,
-                _quantile: f64,
-                _interpol: QuantileInterpolOptions,
+                quantile: f64,
+                interpol: QuantileInterpolOptions,
             ) -> Result<Series> {
-                Ok(Int32Chunked::full_null(self.name(), 1)
+                self.0
+                    .quantile_as_series(quantile, interpol)?
+                    .cast(&self.dtype().to_physical())?
                     .cast(self.dtype())
-                    .unwrap()
-                    .into())
             }
 
             fn fmt_list(&self) -> String {
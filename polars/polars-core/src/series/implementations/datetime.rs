@@ -110,6 +110,9 @@ impl private::PrivateSeries for SeriesWrap<DatetimeChunked> {
     ) -> Series {
         self.0
             .agg_quantile(groups, quantile, interpol)
+            // cast f64 back to physical type
+            .cast(&DataType::Int64)
+            .unwrap()
             .into_datetime(self.0.time_unit(), self.0.time_zone().clone())
             .into_series()
     }
@@ -117,6 +120,9 @@ impl private::PrivateSeries for SeriesWrap<DatetimeChunked> {
     unsafe fn agg_median(&self, groups: &GroupsProxy) -> Series {
         self.0
             .agg_median(groups)
+            // cast f64 back to physical type
+            .cast(&DataType::Int64)
+            .unwrap()
             .into_datetime(self.0.time_unit(), self.0.time_zone().clone())
             .into_series()
     }
@@ -225,6 +231,10 @@ impl SeriesTrait for SeriesWrap<DatetimeChunked> {
         self.0.rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.0.set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkIdIter {
         self.0.chunk_id()
     }
@@ -493,7 +503,10 @@ impl SeriesTrait for SeriesWrap<DatetimeChunked> {
             .into_datetime(self.0.time_unit(), self.0.time_zone().clone())
     }
     fn median_as_series(&self) -> Series {
-        Int32Chunked::full_null(self.name(), 1)
+        self.0
+            .median_as_series()
+            .cast(&DataType::Int64)
+            .unwrap()
             .cast(self.dtype())
             .unwrap()
     }
@@ -509,12 +522,13 @@ impl SeriesTrait for SeriesWrap<DatetimeChunked> {
     }
     fn quantile_as_series(
         &self,
-        _quantile: f64,
-        _interpol: QuantileInterpolOptions,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
     ) -> Result<Series> {
-        Ok(Int32Chunked::full_null(self.name(), 1)
+        self.0
+            .quantile_as_series(quantile, interpol)?
+            .cast(&DataType::Int64)?
             .cast(self.dtype())
-            .unwrap())
     }
 
     fn fmt_list(&self) -> String {
@@ -259,6 +259,10 @@ impl SeriesTrait for SeriesWrap<DurationChunked> {
         self.0.rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.0.set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkIdIter {
         self.0.chunk_id()
     }
@@ -506,7 +510,10 @@ impl SeriesTrait for SeriesWrap<DurationChunked> {
         self.0.min_as_series().into_duration(self.0.time_unit())
     }
     fn median_as_series(&self) -> Series {
-        Int32Chunked::full_null(self.name(), 1)
+        self.0
+            .median_as_series()
+            .cast(&DataType::Int64)
+            .unwrap()
             .cast(self.dtype())
             .unwrap()
     }
@@ -522,12 +529,13 @@ impl SeriesTrait for SeriesWrap<DurationChunked> {
     }
     fn quantile_as_series(
         &self,
-        _quantile: f64,
-        _interpol: QuantileInterpolOptions,
+        quantile: f64,
+        interpol: QuantileInterpolOptions,
     ) -> Result<Series> {
-        Ok(Int32Chunked::full_null(self.name(), 1)
+        self.0
+            .quantile_as_series(quantile, interpol)?
+            .cast(&DataType::Int64)?
             .cast(self.dtype())
-            .unwrap())
     }
 
     fn fmt_list(&self) -> String {
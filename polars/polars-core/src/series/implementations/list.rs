@@ -58,6 +58,10 @@ impl SeriesTrait for SeriesWrap<ListChunked> {
         self.0.rename(name);
     }
 
+    fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.0.set_metadata(metadata);
+    }
+
     fn chunk_lengths(&self) -> ChunkIdIter {
         self.0.chunk_id()
     }
@@ -188,6 +192,27 @@ impl SeriesTrait for SeriesWrap<ListChunked> {
         self.0.has_validity()
     }
 
+    #[cfg(feature = "groupby_list")]
+    fn unique(&self) -> Result<Series> {
+        let groups = self.group_tuples(true, false);
+        // safety:
+        // groups are in bounds
+        Ok(unsafe { self.0.clone().into_series().agg_first(&groups) })
+    }
+
+    #[cfg(feature = "groupby_list")]
+    fn n_unique(&self) -> Result<usize> {
+        Ok(self.group_tuples(true, false).len())
+    }
+
+    #[cfg(feature = "groupby_list")]
+    fn arg_unique(&self) -> Result<IdxCa> {
+        // sorted groups are in the order of their first occurrence
+        let groups = self.group_tuples(true, true);
+        let first = groups.take_group_firsts();
+        Ok(IdxCa::from_vec(self.name(), first))
+    }
+
     fn is_null(&self) -> BooleanChunked {
         self.0.is_null()
     }
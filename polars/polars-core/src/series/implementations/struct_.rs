@@ -74,6 +74,10 @@ impl SeriesTrait for SeriesWrap<StructChunked> {
         self.0.rename(name)
     }
 
+    fn set_metadata(&mut self, metadata: FieldMetadata) {
+        self.0.set_metadata(metadata)
+    }
+
     fn take_every(&self, n: usize) -> Series {
         self.0.apply_fields(|s| s.take_every(n)).into_series()
     }
@@ -303,7 +307,8 @@ impl SeriesTrait for SeriesWrap<StructChunked> {
 
     /// Get first indexes of unique values.
     fn arg_unique(&self) -> Result<IdxCa> {
-        let groups = self.group_tuples(true, false);
+        // sorted groups are in the order of their first occurrence
+        let groups = self.group_tuples(true, true);
         let first = groups.take_group_firsts();
         Ok(IdxCa::from_vec(self.name(), first))
     }
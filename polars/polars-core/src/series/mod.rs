@@ -166,6 +166,14 @@ impl Series {
         Arc::get_mut(&mut self.0).expect("implementation error")
     }
 
+    /// The number of `Series` (e.g. in cloned `DataFrame`s) that share the data of this `Series`.
+    ///
+    /// A mutation of a `Series` that is shared first clones it, so that the other `Series`
+    /// are not affected.
+    pub fn n_references(&self) -> usize {
+        Arc::strong_count(&self.0)
+    }
+
     /// # Safety
     /// The caller must ensure the length and the data types of `ArrayRef` does not change.
     pub(crate) unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
@@ -192,6 +200,19 @@ impl Series {
         self
     }
 
+    /// Get the key/value metadata of the field of the series.
+    pub fn metadata(&self) -> FieldMetadata {
+        self.field().metadata().clone()
+    }
+
+    /// Set the key/value metadata of the field of the series, e.g. the unit or a description of
+    /// the values. The metadata is kept when the series is renamed, sliced or filtered, and written
+    /// to Parquet and IPC files.
+    pub fn set_metadata(&mut self, metadata: FieldMetadata) -> &mut Series {
+        self._get_inner_mut().set_metadata(metadata);
+        self
+    }
+
     /// Shrink the capacity of this array to fit it's length.
     pub fn shrink_to_fit(&mut self) {
         self._get_inner_mut().shrink_to_fit()
@@ -324,6 +345,16 @@ impl Series {
         }
     }
 
+    /// Replace every NaN value by the same NaN, so that all NaN values hash equal. Series of
+    /// other dtypes are returned as is.
+    pub fn canonicalize_nans(&self) -> Series {
+        match self.dtype() {
+            DataType::Float32 => self.f32().unwrap().canonicalize_nans().into_series(),
+            DataType::Float64 => self.f64().unwrap().canonicalize_nans().into_series(),
+            _ => self.clone(),
+        }
+    }
+
     /// Check if float value is finite
     pub fn is_finite(&self) -> Result<BooleanChunked> {
         match self.dtype() {
@@ -670,22 +701,19 @@ impl Series {
         rank(self, options.method, options.descending)
     }
 
-    /// Cast throws an error if conversion had overflows
+    #[cfg(feature = "is_last")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "is_last")))]
+    /// Get a mask of the last unique values.
+    pub fn is_last(&self) -> Result<BooleanChunked> {
+        let mut mask = self.reverse().is_first()?.reverse();
+        mask.rename(self.name());
+        Ok(mask)
+    }
+
+    /// Cast throws an error if conversion had overflows or values could not be parsed.
+    /// The error names the first value that could not be cast.
     pub fn strict_cast(&self, data_type: &DataType) -> Result<Series> {
-        let s = self.cast(data_type)?;
-        if self.null_count() != s.null_count() {
-            Err(PolarsError::ComputeError(
-                format!(
-                    "strict conversion of cast from {:?} to {:?} failed. consider non-strict cast.\n
-                    If you were trying to cast Utf8 to Date,Time,Datetime, consider using `strptime`",
-                    self.dtype(),
-                    data_type
-                )
-                    .into(),
-            ))
-        } else {
-            Ok(s)
-        }
+        self.cast_with_options(data_type, CastOptions::Strict)
     }
 
     #[cfg(feature = "dtype-time")]
@@ -846,6 +874,26 @@ impl Series {
         self.slice(-(len as i64), len)
     }
 
+    /// The standard deviation of the values, with `ddof` Delta Degrees of Freedom; the divisor
+    /// is `N - ddof`. The null values are skipped; `None` if there are no valid values or the
+    /// data type is not numeric.
+    pub fn std(&self, ddof: u8) -> Option<f64> {
+        self.std_as_series(ddof)
+            .cast(&DataType::Float64)
+            .ok()
+            .and_then(|s| s.f64().unwrap().get(0))
+    }
+
+    /// The variance of the values, with `ddof` Delta Degrees of Freedom; the divisor is
+    /// `N - ddof`. The null values are skipped; `None` if there are no valid values or the
+    /// data type is not numeric.
+    pub fn var(&self, ddof: u8) -> Option<f64> {
+        self.var_as_series(ddof)
+            .cast(&DataType::Float64)
+            .ok()
+            .and_then(|s| s.f64().unwrap().get(0))
+    }
+
     pub fn mean_as_series(&self) -> Series {
         let val = [self.mean()];
         let s = Series::new(self.name(), val);
@@ -858,6 +906,8 @@ impl Series {
 
     /// Compute the unique elements, but maintain order. This requires more work
     /// than a naive [`Series::unique`](SeriesTrait::unique).
+    ///
+    /// The unique elements are guaranteed to be in the order of their first occurrence.
     pub fn unique_stable(&self) -> Result<Series> {
         let idx = self.arg_unique()?;
         // Safety:
@@ -996,6 +1046,31 @@ mod test {
         let _ = ca.into_series();
     }
 
+    #[test]
+    fn series_metadata() -> Result<()> {
+        let metadata: FieldMetadata = [("unit".to_string(), "m".to_string())].into();
+        let mut s = Series::new("a", &[1, 2, 3]);
+        s.set_metadata(metadata.clone());
+        assert_eq!(s.metadata(), metadata);
+
+        s.rename("b");
+        assert_eq!(s.metadata(), metadata);
+        assert_eq!(s.slice(1, 2).metadata(), metadata);
+        let df = DataFrame::new(vec![s.clone(), Series::new("c", &[1, 2, 3])])?;
+        assert_eq!(df.select(["b"])?.column("b")?.metadata(), metadata);
+        assert_eq!(df.arrow_schema().fields[0].metadata, metadata);
+        assert!(df.arrow_schema().fields[1].metadata.is_empty());
+
+        // logical types and arrow fields
+        let mut s = s.cast(&DataType::Date)?;
+        assert!(s.metadata().is_empty());
+        s.set_metadata(metadata.clone());
+        assert_eq!(s.field().metadata(), &metadata);
+        let s = Series::try_from((&s.field().to_arrow(), s.to_arrow(0)))?;
+        assert_eq!(s.metadata(), metadata);
+        Ok(())
+    }
+
     #[test]
     fn new_series_from_arrow_primitive_array() {
         let array = UInt32Array::from_slice(&[1, 2, 3, 4, 5]);
@@ -1046,4 +1121,19 @@ mod test {
         let ca = out.f64().unwrap();
         assert_eq!(ca.get(0), Some(1.0));
     }
+
+    #[test]
+    fn test_std_var_ddof() {
+        let s = Series::new("a", &[Some(1i32), None, Some(2), Some(3)]);
+        assert_eq!(s.var(1), Some(1.0));
+        assert_eq!(s.var(0), Some(2.0 / 3.0));
+        assert_eq!(s.std(1), Some(1.0));
+        // a single valid value has no sample variance
+        assert!(Series::new("a", &[Some(1.0), None])
+            .var(1)
+            .unwrap()
+            .is_nan());
+        assert_eq!(Series::new("a", &[None::<f64>]).var(1), None);
+        assert_eq!(Series::new("a", &["a"]).std(1), None);
+    }
 }
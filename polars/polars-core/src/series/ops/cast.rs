@@ -0,0 +1,158 @@
+use crate::prelude::*;
+use std::convert::TryFrom;
+
+/// How [`Series::cast_with_options`] handles values that cannot be represented in the
+/// target type.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CastOptions {
+    /// Raise an error that names the first value that could not be cast and its index.
+    Strict,
+    /// Set the values that could not be cast to null.
+    #[default]
+    NonStrict,
+}
+
+/// Mask of the rows of `input` that were valid but could not be cast to `output`.
+/// For lists and structs a row has failed if any of its nested values failed.
+fn failed_casts(input: &Series, output: &Series) -> BooleanChunked {
+    let mut mask = &input.is_not_null() & &output.is_null();
+    match (input.dtype(), output.dtype()) {
+        (DataType::List(_), DataType::List(_)) => {
+            if let Some(inner) = failed_list_casts(input, output) {
+                mask = &mask | &inner;
+            }
+        }
+        #[cfg(feature = "dtype-struct")]
+        (DataType::Struct(_), DataType::Struct(_)) => {
+            let (a, b) = (input.struct_().unwrap(), output.struct_().unwrap());
+            if a.fields().len() == b.fields().len() {
+                for (a, b) in a.fields().iter().zip(b.fields()) {
+                    mask = &mask | &failed_casts(a, b);
+                }
+            }
+        }
+        _ => {}
+    }
+    mask
+}
+
+/// The values of casts that change the nesting level are not in lockstep, so they only report
+/// the rows that became null.
+fn failed_list_casts(input: &Series, output: &Series) -> Option<BooleanChunked> {
+    let (input, output) = (input.list().ok()?.rechunk(), output.list().ok()?.rechunk());
+    let (a, b) = (
+        input.downcast_iter().next()?,
+        output.downcast_iter().next()?,
+    );
+    if a.values().len() != b.values().len() {
+        return None;
+    }
+    let inner_in = Series::try_from(("", a.values().clone())).ok()?;
+    let inner_out = Series::try_from(("", b.values().clone())).ok()?;
+    let inner = failed_casts(&inner_in, &inner_out).rechunk();
+    let inner = inner.downcast_iter().next()?;
+    Some(
+        a.offsets()
+            .windows(2)
+            .map(|w| (w[0] as usize..w[1] as usize).any(|i| inner.value(i)))
+            .collect(),
+    )
+}
+
+fn first_failed_cast(input: &Series, output: &Series) -> Option<usize> {
+    let nested = matches!(input.dtype(), DataType::List(_));
+    #[cfg(feature = "dtype-struct")]
+    let nested = nested || matches!(input.dtype(), DataType::Struct(_));
+    if input.is_empty() || (!nested && input.null_count() == output.null_count()) {
+        return None;
+    }
+    failed_casts(input, output)
+        .into_iter()
+        .position(|failed| failed == Some(true))
+}
+
+impl Series {
+    /// Cast to another [`DataType`], where `options` determines what happens to the values that
+    /// cannot be cast, e.g. strings that are no valid numbers or integers that overflow.
+    pub fn cast_with_options(&self, dtype: &DataType, options: CastOptions) -> Result<Series> {
+        let out = self.cast(dtype)?;
+        if let CastOptions::NonStrict = options {
+            return Ok(out);
+        }
+        match first_failed_cast(self, &out) {
+            None => Ok(out),
+            Some(idx) => {
+                let hint = match (self.dtype(), dtype) {
+                    (DataType::Utf8, DataType::Date | DataType::Datetime(_, _) | DataType::Time) => {
+                        "\nIf you were trying to cast Utf8 to Date, Time or Datetime, consider using `strptime`"
+                    }
+                    _ => "",
+                };
+                Err(PolarsError::ComputeError(
+                    format!(
+                        "strict conversion of '{}' from {:?} to {:?} failed for value {} at index {}, consider a non-strict cast.{}",
+                        self.name(),
+                        self.dtype(),
+                        dtype,
+                        self.get(idx),
+                        idx,
+                        hint
+                    )
+                    .into(),
+                )
+                .with_column(self.name())
+                .with_dtypes(self.dtype(), dtype))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cast_with_options() -> Result<()> {
+        let s = Series::new("a", [Some("1"), None, Some("x"), Some("3")]);
+        let out = s.cast_with_options(&DataType::Int32, CastOptions::NonStrict)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), None, None, Some(3)]);
+        let err = s
+            .cast_with_options(&DataType::Int32, CastOptions::Strict)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("\"x\" at index 2"));
+        assert!(s
+            .slice(0, 2)
+            .cast_with_options(&DataType::Int32, CastOptions::Strict)
+            .is_ok());
+
+        let s = Series::new("a", [1i64, i64::MAX]);
+        let err = s.strict_cast(&DataType::Int32).unwrap_err().to_string();
+        assert!(err.contains(&format!("{} at index 1", i64::MAX)));
+        #[cfg(feature = "dtype-date")]
+        assert!(s.strict_cast(&DataType::Date).is_err());
+
+        // the rows stay valid if a nested value fails
+        let s = Series::new(
+            "a",
+            [
+                Series::new("", ["1"]),
+                Series::new("", ["2", "y"]),
+                Series::new("", ["3"]),
+            ],
+        );
+        let dtype = DataType::List(Box::new(DataType::Int32));
+        assert_eq!(
+            s.cast_with_options(&dtype, CastOptions::NonStrict)?
+                .null_count(),
+            0
+        );
+        let err = s
+            .cast_with_options(&dtype, CastOptions::Strict)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("at index 1"));
+        assert!(s.slice(2, 1).strict_cast(&dtype).is_ok());
+        Ok(())
+    }
+}
@@ -1,15 +1,168 @@
 use crate::prelude::*;
 use crate::series::ops::NullBehavior;
+use arrow::bitmap::MutableBitmap;
+
+/// The dtype of [`Series::diff`] on data of the given `dtype`. Unsigned integers become signed
+/// integers, so that negative differences can be represented, and temporal types become a
+/// `Duration`.
+pub fn diff_dtype(dtype: &DataType) -> DataType {
+    use DataType::*;
+    match dtype {
+        #[cfg(feature = "dtype-u8")]
+        UInt8 => Int32,
+        #[cfg(feature = "dtype-u16")]
+        UInt16 => Int32,
+        UInt32 | UInt64 => Int64,
+        #[cfg(all(feature = "dtype-date", feature = "dtype-duration"))]
+        Date => Duration(TimeUnit::Milliseconds),
+        #[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+        Datetime(tu, _) => Duration(*tu),
+        #[cfg(all(feature = "dtype-time", feature = "dtype-duration"))]
+        Time => Duration(TimeUnit::Nanoseconds),
+        dt => dt.clone(),
+    }
+}
+
+/// Subtract the values `n` slots before from the values in a single pass over the buffers.
+fn diff_numeric<T>(ca: &ChunkedArray<T>, n: usize, null_behavior: NullBehavior) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    let len = ca.len();
+    let n = std::cmp::min(n, len);
+    let n_leading = match null_behavior {
+        NullBehavior::Ignore => n,
+        NullBehavior::Drop => 0,
+    };
+    if len == 0 {
+        return ca.clone();
+    }
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let values = arr.values().as_slice();
+
+    let mut out = Vec::with_capacity(n_leading + len - n);
+    out.resize(n_leading, T::Native::default());
+    out.extend(
+        values[n..]
+            .iter()
+            .zip(&values[..len - n])
+            .map(|(a, b)| *a - *b),
+    );
+
+    let validity = if n_leading > 0 || arr.null_count() > 0 {
+        let mut bits = MutableBitmap::with_capacity(out.len());
+        bits.extend_constant(n_leading, false);
+        match arr.validity() {
+            // Safety:
+            // the range has a trusted length
+            Some(validity) => unsafe {
+                bits.extend_from_trusted_len_iter_unchecked(
+                    (n..len).map(|i| validity.get_bit(i) && validity.get_bit(i - n)),
+                )
+            },
+            None => bits.extend_constant(len - n, true),
+        }
+        Some(bits.into())
+    } else {
+        None
+    };
+    ChunkedArray::new_from_owned_with_null_bitmap(ca.name(), out, validity)
+}
 
 impl Series {
+    /// Compute the difference between every value and the value `n` slots before it.
+    ///
+    /// With [`NullBehavior::Ignore`] the first `n` values are null, with [`NullBehavior::Drop`]
+    /// they are removed. The output dtype is given by [`diff_dtype`].
     #[cfg_attr(docsrs, doc(cfg(feature = "diff")))]
     pub fn diff(&self, n: usize, null_behavior: NullBehavior) -> Series {
-        match null_behavior {
-            NullBehavior::Ignore => self - &self.shift(n as i64),
-            NullBehavior::Drop => {
-                let len = self.len() - n;
-                &self.slice(n as i64, len) - &self.slice(0, len)
+        use DataType::*;
+        let out_dtype = diff_dtype(self.dtype());
+        match self.dtype() {
+            #[cfg(feature = "dtype-u8")]
+            UInt8 => self.cast(&out_dtype).unwrap().diff(n, null_behavior),
+            #[cfg(feature = "dtype-u16")]
+            UInt16 => self.cast(&out_dtype).unwrap().diff(n, null_behavior),
+            UInt32 | UInt64 => self.cast(&out_dtype).unwrap().diff(n, null_behavior),
+            #[cfg(feature = "dtype-i8")]
+            Int8 => diff_numeric(self.i8().unwrap(), n, null_behavior).into_series(),
+            #[cfg(feature = "dtype-i16")]
+            Int16 => diff_numeric(self.i16().unwrap(), n, null_behavior).into_series(),
+            Int32 => diff_numeric(self.i32().unwrap(), n, null_behavior).into_series(),
+            Int64 => diff_numeric(self.i64().unwrap(), n, null_behavior).into_series(),
+            Float32 => diff_numeric(self.f32().unwrap(), n, null_behavior).into_series(),
+            Float64 => diff_numeric(self.f64().unwrap(), n, null_behavior).into_series(),
+            #[cfg(all(feature = "dtype-date", feature = "dtype-duration"))]
+            Date => {
+                let days = self.cast(&Int32).unwrap().diff(n, null_behavior);
+                let ms = days.cast(&Int64).unwrap() * 86_400_000i64;
+                ms.cast(&out_dtype).unwrap()
             }
+            #[cfg(feature = "dtype-duration")]
+            dt if matches!(out_dtype, Duration(_)) && dt.to_physical() == Int64 => self
+                .to_physical_repr()
+                .diff(n, null_behavior)
+                .cast(&out_dtype)
+                .unwrap(),
+            _ => match null_behavior {
+                NullBehavior::Ignore => self - &self.shift(n as i64),
+                NullBehavior::Drop => {
+                    let len = self.len().saturating_sub(n);
+                    &self.slice(n as i64, len) - &self.slice(0, len)
+                }
+            },
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let s = Series::new("a", [Some(1), Some(4), None, Some(10), Some(11)]);
+        let out = s.diff(1, NullBehavior::Ignore);
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[None, Some(3), None, None, Some(1)]
+        );
+        let out = s.diff(2, NullBehavior::Drop);
+        assert_eq!(Vec::from(out.i32().unwrap()), &[None, Some(6), None]);
+        assert_eq!(s.diff(10, NullBehavior::Drop).len(), 0);
+        assert_eq!(s.diff(10, NullBehavior::Ignore).null_count(), 5);
+
+        // unsigned integers can have negative differences
+        let s = Series::new("a", [3u32, 1]);
+        let out = s.diff(1, NullBehavior::Drop);
+        assert_eq!(Vec::from(out.i64().unwrap()), &[Some(-2)]);
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-date",
+        feature = "dtype-datetime",
+        feature = "dtype-duration"
+    ))]
+    fn test_diff_temporal() {
+        let s = Series::new("a", [1i32, 3]).cast(&DataType::Date).unwrap();
+        let out = s.diff(1, NullBehavior::Drop);
+        assert_eq!(out.dtype(), &DataType::Duration(TimeUnit::Milliseconds));
+        assert_eq!(
+            Vec::from(out.to_physical_repr().i64().unwrap()),
+            &[Some(2 * 86_400_000)]
+        );
+
+        let s = Series::new("a", [10i64, 25])
+            .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .unwrap();
+        let out = s.diff(1, NullBehavior::Ignore);
+        assert_eq!(out.dtype(), &DataType::Duration(TimeUnit::Microseconds));
+        assert_eq!(
+            Vec::from(out.to_physical_repr().i64().unwrap()),
+            &[None, Some(15)]
+        );
+    }
+}
@@ -1,26 +1,52 @@
 use crate::prelude::*;
 
 impl Series {
+    /// Create a new Series of length `n` in which every value is `value`.
+    /// A [`AnyValue::Null`] creates a Series of nulls of dtype `Int32`.
+    pub fn new_repeated(name: &str, value: AnyValue, n: usize) -> Result<Self> {
+        let s = Series::from_any_values(name, &[value])?;
+        Ok(s.expand_at_index(0, n))
+    }
+
     /// Extend with a constant value.
     pub fn extend_constant(&self, value: AnyValue, n: usize) -> Result<Self> {
-        use AnyValue::*;
-        let s = match value {
-            Float32(v) => Series::new("", vec![v]),
-            Float64(v) => Series::new("", vec![v]),
-            UInt32(v) => Series::new("", vec![v]),
-            UInt64(v) => Series::new("", vec![v]),
-            Int32(v) => Series::new("", vec![v]),
-            Int64(v) => Series::new("", vec![v]),
-            Utf8(v) => Series::new("", vec![v]),
-            Boolean(v) => Series::new("", vec![v]),
-            Null => BooleanChunked::full_null("", 1).into_series(),
-            dt => panic!("{:?} not supported", dt),
+        let to_append = match value {
+            AnyValue::Null => Series::full_null(self.name(), n, self.dtype()),
+            // cast the single value instead of the repeated values
+            value => Series::from_any_values(self.name(), &[value])?
+                .cast(self.dtype())?
+                .expand_at_index(0, n),
         };
-        let s = s.cast(self.dtype())?;
-        let to_append = s.expand_at_index(0, n);
 
         let mut out = self.clone();
         out.append(&to_append)?;
         Ok(out)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_extend_constant() -> Result<()> {
+        let s = Series::new_repeated("a", AnyValue::Utf8("ab"), 3)?;
+        assert_eq!(s.name(), "a");
+        assert_eq!(Vec::from(s.utf8()?), &[Some("ab"); 3]);
+
+        let s = Series::new("a", [1i32, 2]);
+        let out = s.extend_constant(AnyValue::Int64(3), 2)?;
+        assert_eq!(out.dtype(), &DataType::Int32);
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(2), Some(3), Some(3)]);
+        let out = s.extend_constant(AnyValue::Null, 1)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(2), None]);
+
+        let s = Series::new("a", [true]);
+        let out = s.extend_constant(AnyValue::Boolean(false), 2)?;
+        assert_eq!(
+            Vec::from(out.bool()?),
+            &[Some(true), Some(false), Some(false)]
+        );
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+mod cast;
 #[cfg(feature = "diff")]
 #[cfg_attr(docsrs, doc(cfg(feature = "diff")))]
 pub mod diff;
@@ -16,9 +17,14 @@ pub mod pct_change;
 #[cfg(feature = "round_series")]
 #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
 mod round;
+mod set;
 mod to_list;
 mod unique;
 
+pub use cast::CastOptions;
+#[cfg(feature = "round_series")]
+pub use round::RoundMode;
+
 #[derive(Copy, Clone)]
 pub enum NullBehavior {
     /// drop nulls
@@ -1,15 +1,62 @@
 use crate::prelude::*;
-use crate::series::ops::NullBehavior;
+use arrow::bitmap::MutableBitmap;
+use num::Zero;
+
+/// Divide the difference with the value `n` slots before by that value in a single pass over
+/// the buffers. The change from a zero or null value is null.
+fn pct_change_float<T>(ca: &ChunkedArray<T>, n: usize) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+{
+    let len = ca.len();
+    let n = std::cmp::min(n, len);
+    if len == 0 {
+        return ca.clone();
+    }
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let values = arr.values().as_slice();
+
+    let mut out = Vec::with_capacity(len);
+    out.resize(n, T::Native::zero());
+    out.extend(values[n..].iter().zip(&values[..len - n]).map(|(a, b)| {
+        if b.is_zero() {
+            T::Native::zero()
+        } else {
+            (*a - *b) / *b
+        }
+    }));
+
+    let mut validity = MutableBitmap::with_capacity(len);
+    validity.extend_constant(n, false);
+    let is_valid = |i: usize| arr.validity().map_or(true, |v| v.get_bit(i));
+    // Safety:
+    // the range has a trusted length
+    unsafe {
+        validity.extend_from_trusted_len_iter_unchecked(
+            (n..len).map(|i| is_valid(i) && is_valid(i - n) && !values[i - n].is_zero()),
+        )
+    };
+    ChunkedArray::new_from_owned_with_null_bitmap(ca.name(), out, Some(validity.into()))
+}
 
 impl Series {
+    /// Compute the relative change between every value and the value `n` slots before it.
+    ///
+    /// Nulls are forward filled first. The first `n` values and the changes from a value of zero
+    /// are null.
     #[cfg_attr(docsrs, doc(cfg(feature = "pct_change")))]
     pub fn pct_change(&self, n: usize) -> Result<Series> {
-        match self.dtype() {
-            DataType::Float64 | DataType::Float32 => {}
+        let filled = match self.dtype() {
+            DataType::Float64 | DataType::Float32 => {
+                self.fill_null(FillNullStrategy::Forward(None))?
+            }
             _ => return self.cast(&DataType::Float64)?.pct_change(n),
+        };
+        match filled.dtype() {
+            DataType::Float32 => Ok(pct_change_float(filled.f32().unwrap(), n).into_series()),
+            _ => Ok(pct_change_float(filled.f64().unwrap(), n).into_series()),
         }
-        let nn = self.fill_null(FillNullStrategy::Forward(None))?;
-        nn.diff(n, NullBehavior::Ignore).divide(&nn.shift(n as i64))
     }
 }
 
@@ -37,6 +84,27 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_zero() -> Result<()> {
+        // the change from zero is null instead of infinite
+        let s = Series::new("", &[Some(0.0f64), Some(1.0), Some(0.0), Some(0.0)]);
+        assert_eq!(
+            Vec::from(s.pct_change(1)?.f64()?),
+            &[None, None, Some(-1.0), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_leading_nulls() -> Result<()> {
+        let s = Series::new("", &[None, None, Some(2i32), None, Some(4), Some(6)]);
+        assert_eq!(
+            Vec::from(s.pct_change(2)?.f64()?),
+            &[None, None, None, None, Some(1.0), Some(2.0)]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_two_periods() -> Result<()> {
         let s = Series::new("", &[Some(1), Some(2), Some(4), Some(8), Some(16)]);
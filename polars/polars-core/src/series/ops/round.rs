@@ -1,24 +1,105 @@
 use crate::prelude::*;
+use num::NumCast;
+
+/// How [`Series::round_with_mode`] rounds values to the given decimals.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Round half-way values away from zero, e.g. `2.5 -> 3` and `-2.5 -> -3`.
+    #[default]
+    HalfAwayFromZero,
+    /// Round half-way values to the nearest even value, e.g. `2.5 -> 2` and `3.5 -> 4`.
+    HalfEven,
+    /// Round half-way values up, e.g. `2.5 -> 3` and `-2.5 -> -2`.
+    HalfUp,
+    /// Round down.
+    Floor,
+    /// Round up.
+    Ceil,
+    /// Round towards zero, i.e. truncate.
+    TowardZero,
+}
+
+impl RoundMode {
+    fn round(self, v: f64) -> f64 {
+        match self {
+            RoundMode::HalfAwayFromZero => v.round(),
+            RoundMode::HalfEven => v.round_ties_even(),
+            RoundMode::HalfUp => {
+                let floor = v.floor();
+                if v - floor >= 0.5 {
+                    floor + 1.0
+                } else {
+                    floor
+                }
+            }
+            RoundMode::Floor => v.floor(),
+            RoundMode::Ceil => v.ceil(),
+            RoundMode::TowardZero => v.trunc(),
+        }
+    }
+}
+
+/// Round `v` to `decimals`, which may be negative to round to tens, hundreds, etc.
+fn round_to(v: f64, decimals: i32, mode: RoundMode) -> f64 {
+    if !v.is_finite() {
+        return v;
+    }
+    let multiplier = 10f64.powi(decimals);
+    let scaled = v * multiplier;
+    // values that are that large no longer have decimals
+    if !scaled.is_finite() {
+        return v;
+    }
+    mode.round(scaled) / multiplier
+}
 
 impl Series {
     /// Round underlying floating point array to given decimal.
     #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
     pub fn round(&self, decimals: u32) -> Result<Self> {
-        use num::traits::Pow;
+        self.round_with_mode(decimals, RoundMode::HalfAwayFromZero)
+    }
+
+    /// Round underlying floating point array to given decimal, where `mode` determines
+    /// the direction of the rounding.
+    #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
+    pub fn round_with_mode(&self, decimals: u32, mode: RoundMode) -> Result<Self> {
+        let decimals = decimals.min(i32::MAX as u32) as i32;
+        self.apply_rounding(|v| round_to(v, decimals, mode))
+    }
+
+    /// Round underlying floating point array to `significant_figures` significant figures,
+    /// e.g. `1234.5 -> 1200` and `0.012345 -> 0.012` for 2 significant figures.
+    #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
+    pub fn round_sig_figs(&self, significant_figures: u32) -> Result<Self> {
+        if significant_figures == 0 {
+            return Err(PolarsError::ComputeError(
+                "round_sig_figs needs at least 1 significant figure".into(),
+            ));
+        }
+        let significant_figures = significant_figures.min(i32::MAX as u32) as i32;
+        self.apply_rounding(|v| {
+            if v == 0.0 || !v.is_finite() {
+                return v;
+            }
+            let magnitude = v.abs().log10().floor() as i32;
+            round_to(
+                v,
+                significant_figures.saturating_sub(magnitude + 1),
+                RoundMode::HalfAwayFromZero,
+            )
+        })
+    }
+
+    fn apply_rounding<F: Fn(f64) -> f64 + Copy>(&self, f: F) -> Result<Self> {
         if let Ok(ca) = self.f32() {
             // Note we do the computation on f64 floats to not loose precision
             // when the computation is done, we cast to f32
-            let multiplier = 10.0.pow(decimals as f64);
-            let s = ca
-                .apply(|val| ((val as f64 * multiplier).round() / multiplier) as f32)
-                .into_series();
+            let s = ca.apply(|val| f(val as f64) as f32).into_series();
             return Ok(s);
         }
         if let Ok(ca) = self.f64() {
-            let multiplier = 10.0.pow(decimals as f64);
-            let s = ca
-                .apply(|val| (val * multiplier).round() / multiplier)
-                .into_series();
+            let s = ca.apply(f).into_series();
             return Ok(s);
         }
         Err(PolarsError::SchemaMisMatch(
@@ -59,38 +140,124 @@ impl Series {
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
-    /// Ceil underlying floating point array to the highest integers smaller or equal to the float value.
+    /// Clip the values to the boundaries `[min, max]`. Null values stay null.
     pub fn clip(&self, min: f64, max: f64) -> Result<Self> {
-        if let Ok(ca) = self.f32() {
-            let min = min as f32;
-            let max = max as f32;
-            let s = ca.apply(|val| val.clamp(min, max)).into_series();
-            return Ok(s);
-        }
-        if let Ok(ca) = self.f64() {
-            let s = ca.apply(|val| val.clamp(min, max)).into_series();
-            return Ok(s);
-        }
-        if let Ok(ca) = self.i64() {
-            let min = min as i64;
-            let max = max as i64;
-            let s = ca.apply(|val| val.clamp(min, max)).into_series();
-            return Ok(s);
-        }
-        if let Ok(ca) = self.i32() {
-            let min = min as i32;
-            let max = max as i32;
-            let s = ca.apply(|val| val.clamp(min, max)).into_series();
-            return Ok(s);
+        self.clip_impl(Some(min), Some(max))
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
+    /// Clip the values that are smaller than `min` to `min`.
+    pub fn clip_min(&self, min: f64) -> Result<Self> {
+        self.clip_impl(Some(min), None)
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
+    /// Clip the values that are larger than `max` to `max`.
+    pub fn clip_max(&self, max: f64) -> Result<Self> {
+        self.clip_impl(None, Some(max))
+    }
+
+    fn clip_impl(&self, min: Option<f64>, max: Option<f64>) -> Result<Self> {
+        if [min, max].iter().flatten().any(|v| v.is_nan()) {
+            return Err(PolarsError::ComputeError(
+                "the boundaries of clip cannot be NaN".into(),
+            ));
         }
-        if let Ok(ca) = self.u32() {
-            let min = min as u32;
-            let max = max as u32;
-            let s = ca.apply(|val| val.clamp(min, max)).into_series();
-            return Ok(s);
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "the lower boundary {} of clip is larger than the upper boundary {}",
+                        min, max
+                    )
+                    .into(),
+                ));
+            }
         }
-        Err(PolarsError::SchemaMisMatch(
-            format!("{:?} is not one of {{Float32, Float64, Int32, Int64, UInt32}} consider using a when -> then -> otherwise", self.dtype()).into(),
-        ))
+        use DataType::*;
+        let s = match self.dtype() {
+            Float32 => clip(self.f32().unwrap(), min, max),
+            Float64 => clip(self.f64().unwrap(), min, max),
+            #[cfg(feature = "dtype-u8")]
+            UInt8 => clip(self.u8().unwrap(), min, max),
+            #[cfg(feature = "dtype-u16")]
+            UInt16 => clip(self.u16().unwrap(), min, max),
+            UInt32 => clip(self.u32().unwrap(), min, max),
+            UInt64 => clip(self.u64().unwrap(), min, max),
+            #[cfg(feature = "dtype-i8")]
+            Int8 => clip(self.i8().unwrap(), min, max),
+            #[cfg(feature = "dtype-i16")]
+            Int16 => clip(self.i16().unwrap(), min, max),
+            Int32 => clip(self.i32().unwrap(), min, max),
+            Int64 => clip(self.i64().unwrap(), min, max),
+            dt => {
+                return Err(PolarsError::SchemaMisMatch(
+                    format!("cannot clip a Series of dtype {:?}, consider using a when -> then -> otherwise", dt).into(),
+                ))
+            }
+        };
+        Ok(s)
+    }
+}
+
+fn clip<T>(ca: &ChunkedArray<T>, min: Option<f64>, max: Option<f64>) -> Series
+where
+    T: PolarsNumericType,
+    ChunkedArray<T>: IntoSeries,
+{
+    let min = min.map(to_bound::<T::Native>);
+    let max = max.map(to_bound::<T::Native>);
+    ca.apply(|v| match (min, max) {
+        (Some(min), _) if v < min => min,
+        (_, Some(max)) if v > max => max,
+        _ => v,
+    })
+    .into_series()
+}
+
+/// Convert a boundary to the native type, saturating at the bounds of integer types.
+fn to_bound<T: NumericNative>(v: f64) -> T {
+    if T::is_float() {
+        NumCast::from(v).unwrap()
+    } else if v <= T::min_value().to_f64().unwrap() {
+        T::min_value()
+    } else if v >= T::max_value().to_f64().unwrap() {
+        T::max_value()
+    } else {
+        NumCast::from(v).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_modes() -> Result<()> {
+        let s = Series::new("a", [2.5f64, -2.5, 3.5, 1.25, -1.7]);
+        let round = |decimals, mode| -> Result<Vec<Option<f64>>> {
+            Ok(Vec::from(s.round_with_mode(decimals, mode)?.f64()?))
+        };
+        use RoundMode::*;
+        let v = |v: [f64; 5]| v.into_iter().map(Some).collect::<Vec<_>>();
+        assert_eq!(round(0, HalfAwayFromZero)?, v([3.0, -3.0, 4.0, 1.0, -2.0]));
+        assert_eq!(round(0, HalfEven)?, v([2.0, -2.0, 4.0, 1.0, -2.0]));
+        assert_eq!(round(0, HalfUp)?, v([3.0, -2.0, 4.0, 1.0, -2.0]));
+        assert_eq!(round(0, Floor)?, v([2.0, -3.0, 3.0, 1.0, -2.0]));
+        assert_eq!(round(0, Ceil)?, v([3.0, -2.0, 4.0, 2.0, -1.0]));
+        assert_eq!(round(0, TowardZero)?, v([2.0, -2.0, 3.0, 1.0, -1.0]));
+        assert_eq!(round(1, HalfEven)?[3], Some(1.2));
+        assert_eq!(s.round(1)?.f64()?.get(3), Some(1.3));
+
+        let s = Series::new("a", [Some(1234.5f32), Some(-0.012345), Some(0.0), None]);
+        let out = s.round_sig_figs(2)?;
+        assert_eq!(out.dtype(), &DataType::Float32);
+        assert_eq!(
+            Vec::from(out.f32()?),
+            &[Some(1200.0), Some(-0.012), Some(0.0), None]
+        );
+        assert!(s.round_sig_figs(0).is_err());
+        assert!(Series::new("a", [1i32]).round(0).is_err());
+        Ok(())
     }
 }
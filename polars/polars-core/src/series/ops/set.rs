@@ -0,0 +1,155 @@
+use crate::prelude::*;
+
+impl Series {
+    /// Take from the concatenation of `self` and `values`; the values start at `self.len()`.
+    fn take_with_values(&self, values: &Series, idx: Vec<IdxSize>) -> Result<Series> {
+        let mut out = self.clone();
+        out.append(&values.cast(self.dtype())?)?;
+        out.take(&IdxCa::from_vec("", idx))
+    }
+
+    fn check_values_len(&self, values: &Series, len: usize) -> Result<()> {
+        if values.len() != 1 && values.len() != len {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot set {} values with a Series of length {}",
+                    len,
+                    values.len()
+                )
+                .into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Set the values at the indices `idx` to `values`. The `values` are cast to the data type
+    /// of this `Series` and are either a single value that is set at all indices, or a value
+    /// for every index. If an index occurs multiple times, the last value wins. Null indices are
+    /// ignored.
+    ///
+    /// This returns a new `Series`; the buffers of this `Series` (which may be shared with other
+    /// `Series` and `DataFrame`s) are never mutated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[1, 2, 3, 4]);
+    /// let idx = IdxCa::new("idx", &[0, 2]);
+    /// let out = s.set_at_idx(&idx, &Series::new("", &[10, 30])).unwrap();
+    /// assert_eq!(Vec::from(out.i32().unwrap()), &[Some(10), Some(2), Some(30), Some(4)]);
+    /// // `s` is not modified
+    /// assert_eq!(Vec::from(s.i32().unwrap()), &[Some(1), Some(2), Some(3), Some(4)]);
+    /// ```
+    pub fn set_at_idx(&self, idx: &IdxCa, values: &Series) -> Result<Series> {
+        self.check_values_len(values, idx.len())?;
+        let len = self.len() as IdxSize;
+        let broadcast = values.len() == 1;
+
+        let mut take = (0..len).collect::<Vec<_>>();
+        for (i, opt_idx) in idx.into_iter().enumerate() {
+            if let Some(idx) = opt_idx {
+                let v = take.get_mut(idx as usize).ok_or_else(|| {
+                    PolarsError::ComputeError(
+                        format!(
+                            "index {} is out of bounds for a Series of length {}",
+                            idx, len
+                        )
+                        .into(),
+                    )
+                })?;
+                *v = if broadcast { len } else { len + i as IdxSize };
+            }
+        }
+        self.take_with_values(values, take)
+    }
+
+    /// Set the values where `mask` is `true` to `values`. The `values` are cast to the data
+    /// type of this `Series` and are either a single value, or a `Series` of the same length
+    /// as this `Series` of which the values at the `true` positions are taken. Null values in
+    /// the mask are treated as `false`.
+    ///
+    /// This returns a new `Series`; the buffers of this `Series` (which may be shared with other
+    /// `Series` and `DataFrame`s) are never mutated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let s = Series::new("a", &[Some(1), None, Some(3)]);
+    /// let out = s.set(&s.is_null(), &Series::new("", &[0])).unwrap();
+    /// assert_eq!(Vec::from(out.i32().unwrap()), &[Some(1), Some(0), Some(3)]);
+    /// ```
+    pub fn set(&self, mask: &BooleanChunked, values: &Series) -> Result<Series> {
+        if mask.len() != self.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "the mask has length {}, but the Series has length {}",
+                    mask.len(),
+                    self.len()
+                )
+                .into(),
+            ));
+        }
+        self.check_values_len(values, self.len())?;
+        let len = self.len() as IdxSize;
+        let broadcast = values.len() == 1;
+
+        let take = mask
+            .into_iter()
+            .zip(0..len)
+            .map(|(set, i)| match set {
+                Some(true) if broadcast => len,
+                Some(true) => len + i,
+                _ => i,
+            })
+            .collect();
+        self.take_with_values(values, take)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_series_set() -> Result<()> {
+        let s = Series::new("a", &["a", "b", "c", "d"]);
+        let shared = s.clone();
+
+        let idx = IdxCa::new("", &[Some(3), None, Some(0), Some(3)]);
+        let values = Series::new("", &[Some("x"), Some("y"), None, Some("z")]);
+        let out = s.set_at_idx(&idx, &values)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[None, Some("b"), Some("c"), Some("z")]
+        );
+        assert!(shared.series_equal(&Series::new("a", &["a", "b", "c", "d"])));
+
+        let out = s.set_at_idx(&IdxCa::new("", &[1]), &Series::new("", &["e"]))?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("a"), Some("e"), Some("c"), Some("d")]
+        );
+        // out of bounds
+        assert!(s
+            .set_at_idx(&IdxCa::new("", &[4]), &Series::new("", &["x"]))
+            .is_err());
+        assert!(s
+            .set_at_idx(&IdxCa::new("", &[0, 1]), &Series::new("", &["x", "y", "z"]))
+            .is_err());
+
+        // values are cast to the dtype of the Series
+        let s = Series::new("a", &[1.0, 2.0, 3.0]);
+        let mask = BooleanChunked::new("", &[Some(true), None, Some(false)]);
+        let out = s.set(&mask, &Series::new("", &[10]))?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(10.0), Some(2.0), Some(3.0)]);
+
+        let other = Series::new("", &[4, 5, 6]);
+        let out = s.set(&!&mask, &other)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(1.0), Some(2.0), Some(6.0)]);
+        assert!(s.set(&mask.slice(0, 2), &other).is_err());
+        Ok(())
+    }
+}
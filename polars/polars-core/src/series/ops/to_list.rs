@@ -1,3 +1,4 @@
+#[cfg(any(feature = "dtype-categorical", feature = "dtype-struct"))]
 use crate::chunked_array::builder::get_list_builder;
 use crate::prelude::*;
 use polars_arrow::kernels::list::array_to_unit_list;
@@ -49,78 +50,101 @@ impl Series {
         Ok(ca)
     }
 
+    /// Reshape the values of this Series to the given dimensions.
+    ///
+    /// * `[rows, cols]` returns a List column of `rows` lists with `cols` values each.
+    /// * `[len]` returns the flat values, so a List column of equally sized lists is reshaped back.
+    ///
+    /// One of the dimensions may be `-1`, it is then inferred from the length of the values.
     pub fn reshape(&self, dims: &[i64]) -> Result<Series> {
-        if dims.is_empty() {
-            panic!("dimensions cannot be empty")
-        }
         let s = if let DataType::List(_) = self.dtype() {
             Cow::Owned(self.explode()?)
         } else {
             Cow::Borrowed(self)
         };
-
-        // no rows
-        if dims[0] == 0 {
-            let s = reshape_fast_path(self.name(), &s);
-            return Ok(s);
-        }
-
         let s_ref = s.as_ref();
-
-        let mut dims = dims.to_vec();
-        if let Some(idx) = dims.iter().position(|i| *i == -1) {
-            let mut product = 1;
-
-            for (cnt, dim) in dims.iter().enumerate() {
-                if cnt != idx {
-                    product *= *dim
+        let dims = infer_dims(dims, s_ref.len())?;
+
+        match dims.as_slice() {
+            [len] => Ok(s_ref.slice(0, *len)),
+            [rows, cols] => {
+                match s_ref.dtype() {
+                    // fast path, we can create a unit list so we only allocate offsets
+                    dt if *rows == s_ref.len() && !dt.is_logical() => {
+                        return Ok(reshape_fast_path(self.name(), s_ref))
+                    }
+                    // the builder keeps the categories and struct fields
+                    #[cfg(feature = "dtype-categorical")]
+                    DataType::Categorical(_) => return reshape_with_builder(s_ref, *rows, *cols),
+                    #[cfg(feature = "dtype-struct")]
+                    DataType::Struct(_) => return reshape_with_builder(s_ref, *rows, *cols),
+                    _ => {}
+                }
+                let s_ref = s_ref.rechunk();
+                let values = s_ref.to_physical_repr().array_ref(0).clone();
+                let offsets = (0..=*rows)
+                    .map(|row| (row * cols) as i64)
+                    .collect::<Vec<_>>();
+                let data_type =
+                    ListArray::<i64>::default_datatype(s_ref.dtype().to_physical().to_arrow());
+                // Safety:
+                // the offsets are monotonically increasing and end at the length of the values
+                let arr =
+                    unsafe { ListArray::new_unchecked(data_type, offsets.into(), values, None) };
+
+                let mut ca = ListChunked::from_chunks(self.name(), vec![Box::new(arr)]);
+                if s_ref.dtype().is_logical() {
+                    ca.to_logical(s_ref.dtype().clone())
                 }
+                ca.set_fast_explode();
+                Ok(ca.into_series())
             }
-            dims[idx] = s_ref.len() as i64 / product;
+            _ => Err(PolarsError::InvalidOperation(
+                format!(
+                    "cannot reshape into {} dimensions, only 1 or 2 are supported",
+                    dims.len()
+                )
+                .into(),
+            )),
         }
+    }
+}
 
-        let prod = dims.iter().product::<i64>() as usize;
-        if prod != s_ref.len() {
-            return Err(PolarsError::ComputeError(
-                format!("cannot reshape len {} into shape {:?}", s_ref.len(), dims).into(),
-            ));
-        }
-
-        match dims.len() {
-            1 => Ok(s_ref.slice(0, dims[0] as usize)),
-            2 => {
-                let mut rows = dims[0];
-                let mut cols = dims[1];
-
-                // infer dimension
-                if rows == -1 {
-                    rows = cols / s_ref.len() as i64
-                }
-                if cols == -1 {
-                    cols = rows / s_ref.len() as i64
-                }
-
-                // fast path, we can create a unit list so we only allocate offsets
-                if rows as usize == s_ref.len() && cols == 1 {
-                    let s = reshape_fast_path(self.name(), s_ref);
-                    return Ok(s);
-                }
-
-                let mut builder =
-                    get_list_builder(s_ref.dtype(), s_ref.len(), rows as usize, self.name())?;
+#[cfg(any(feature = "dtype-categorical", feature = "dtype-struct"))]
+fn reshape_with_builder(s: &Series, rows: usize, cols: usize) -> Result<Series> {
+    let mut builder = get_list_builder(s.dtype(), s.len(), rows, s.name())?;
+    for row in 0..rows {
+        builder.append_series(&s.slice((row * cols) as i64, cols));
+    }
+    Ok(builder.finish().into_series())
+}
 
-                let mut offset = 0i64;
-                for _ in 0..rows {
-                    let row = s_ref.slice(offset, cols as usize);
-                    builder.append_series(&row);
-                    offset += cols;
-                }
-                Ok(builder.finish().into_series())
-            }
-            _ => {
-                panic!("more than two dimensions not yet supported");
-            }
-        }
+/// Replace a `-1` in `dims` by the dimension needed to fit `len` values and check that the
+/// dimensions fit exactly.
+fn infer_dims(dims: &[i64], len: usize) -> Result<Vec<usize>> {
+    let err = || {
+        Err(PolarsError::ComputeError(
+            format!("cannot reshape len {} into shape {:?}", len, dims).into(),
+        ))
+    };
+    if dims.is_empty()
+        || dims.iter().any(|d| *d < -1)
+        || dims.iter().filter(|d| **d == -1).count() > 1
+    {
+        return err();
+    }
+    let known = dims.iter().filter(|d| **d != -1).product::<i64>() as usize;
+    let dims = dims
+        .iter()
+        .map(|d| match *d {
+            -1 if known == 0 => None,
+            -1 => Some(len / known),
+            d => Some(d as usize),
+        })
+        .collect::<Option<Vec<_>>>();
+    match dims {
+        Some(dims) if dims.iter().product::<usize>() == len => Ok(dims),
+        _ => err(),
     }
 }
 
@@ -160,6 +184,29 @@ mod test {
             assert_eq!(out.explode()?.len(), 4);
         }
 
+        let out = s.reshape(&[2, 2])?;
+        assert_eq!(
+            Vec::from(out.list()?.get(1).unwrap().i32()?),
+            &[Some(3), Some(4)]
+        );
+        // and back
+        let back = out.reshape(&[-1])?;
+        assert!(back.series_equal(&s));
+
+        for dims in [
+            &[][..],
+            &[3, -1],
+            &[-1, -1],
+            &[-2, -2],
+            &[0, -1],
+            &[2, 2, 1],
+        ] {
+            assert!(s.reshape(dims).is_err());
+        }
+        let empty = s.slice(0, 0).reshape(&[0, 2])?;
+        assert_eq!(empty.len(), 0);
+        assert!(matches!(empty.dtype(), DataType::List(_)));
+
         Ok(())
     }
 }
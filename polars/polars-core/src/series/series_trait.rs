@@ -205,6 +205,9 @@ pub trait SeriesTrait:
     /// Rename the Series.
     fn rename(&mut self, name: &str);
 
+    /// Set the key/value metadata of the field of the Series.
+    fn set_metadata(&mut self, metadata: FieldMetadata);
+
     fn bitand(&self, _other: &Series) -> Result<Series> {
         Err(PolarsError::InvalidOperation(
             format!(
@@ -436,7 +439,8 @@ pub trait SeriesTrait:
     /// no bitmap means no null values.
     fn has_validity(&self) -> bool;
 
-    /// Get unique values in the Series.
+    /// Get unique values in the Series. The order of the values is unspecified,
+    /// use [`Series::unique_stable`] to get them in the order of first occurrence.
     fn unique(&self) -> Result<Series> {
         invalid_operation!(self)
     }
@@ -447,6 +451,7 @@ pub trait SeriesTrait:
     }
 
     /// Get first indexes of unique values.
+    /// The indexes are sorted, so they are in the order in which the values first occur.
     fn arg_unique(&self) -> Result<IdxCa> {
         invalid_operation_panic!(self)
     }
@@ -55,9 +55,19 @@ impl<'a> UnstableSeries<'a> {
     }
 
     pub fn deep_clone(&self) -> Series {
-        let array_ref = unsafe { (*self.container).chunks() }[0].clone();
-        let name = unsafe { (*self.container).name() };
-        Series::try_from((name, array_ref)).unwrap()
+        let container = unsafe { &*self.container };
+        let array_ref = container.chunks()[0].clone();
+        let dtype = container.dtype();
+        if !dtype.is_logical() {
+            Series::try_from((container.name(), array_ref)).unwrap()
+        } else {
+            // the array only holds the physical values, so restore the logical type
+            // Safety:
+            // the array is taken from the container that has this dtype
+            unsafe {
+                Series::from_chunks_and_dtype_unchecked(container.name(), vec![array_ref], dtype)
+            }
+        }
     }
 
     #[inline]
@@ -143,8 +143,271 @@ impl PartialEq for DataFrame {
     }
 }
 
+/// Options that determine how [`assert_series_equal`] and [`assert_frame_equal`]
+/// compare their inputs.
+#[derive(Clone, Debug)]
+pub struct AssertEqualOptions {
+    /// Require the data types to be equal. If `false`, the right hand side is cast
+    /// to the data type of the left hand side before comparison.
+    pub check_dtype: bool,
+    /// Require the names of the `Series` to be equal.
+    pub check_names: bool,
+    /// Require the columns of the `DataFrames` to be in the same order.
+    pub check_column_order: bool,
+    /// Consider `None == None` to be `true`.
+    pub null_equal: bool,
+    /// Consider `NaN == NaN` to be `true`.
+    pub nan_equal: bool,
+    /// Relative tolerance for float comparisons.
+    pub rtol: f64,
+    /// Absolute tolerance for float comparisons.
+    pub atol: f64,
+    /// Maximum number of differing rows that are reported per column.
+    pub max_reported_rows: usize,
+}
+
+impl Default for AssertEqualOptions {
+    fn default() -> Self {
+        Self {
+            check_dtype: true,
+            check_names: true,
+            check_column_order: true,
+            null_equal: true,
+            nan_equal: true,
+            rtol: 1e-5,
+            atol: 1e-8,
+            max_reported_rows: 10,
+        }
+    }
+}
+
+impl AssertEqualOptions {
+    /// Compare floats exactly.
+    pub fn exact(mut self) -> Self {
+        self.rtol = 0.0;
+        self.atol = 0.0;
+        self
+    }
+}
+
+fn float_equal(l: Option<f64>, r: Option<f64>, options: &AssertEqualOptions) -> bool {
+    match (l, r) {
+        (Some(l), Some(r)) => {
+            if l.is_nan() || r.is_nan() {
+                options.nan_equal && l.is_nan() && r.is_nan()
+            } else {
+                // equal infinities have a `NaN` difference
+                l == r || (l - r).abs() <= options.atol + options.rtol * r.abs()
+            }
+        }
+        (None, None) => options.null_equal,
+        _ => false,
+    }
+}
+
+/// Get the indices of the values that differ.
+fn differing_rows(
+    left: &Series,
+    right: &Series,
+    options: &AssertEqualOptions,
+) -> Result<Vec<usize>> {
+    let is_float = |s: &Series| matches!(s.dtype(), DataType::Float32 | DataType::Float64);
+    let idx = if is_float(left) || is_float(right) {
+        let l = left.cast(&DataType::Float64)?;
+        let r = right.cast(&DataType::Float64)?;
+        l.f64()?
+            .into_iter()
+            .zip(r.f64()?)
+            .enumerate()
+            .filter(|(_, (l, r))| !float_equal(*l, *r, options))
+            .map(|(i, _)| i)
+            .collect()
+    } else {
+        match left.eq_missing(right) {
+            Ok(mask) => mask
+                .into_iter()
+                .enumerate()
+                .filter(|(_, eq)| !eq.unwrap_or(false))
+                .map(|(i, _)| i)
+                .collect(),
+            // not all types implement the comparison kernels, fall back to AnyValue
+            Err(_) => (0..left.len())
+                .filter(|&i| left.get(i) != right.get(i))
+                .collect(),
+        }
+    };
+    if options.null_equal {
+        Ok(idx)
+    } else {
+        let mut idx = idx;
+        let nulls = left.is_null() | right.is_null();
+        idx.extend(
+            nulls
+                .into_iter()
+                .enumerate()
+                .filter(|(_, is_null)| is_null.unwrap_or(false))
+                .map(|(i, _)| i),
+        );
+        idx.sort_unstable();
+        idx.dedup();
+        Ok(idx)
+    }
+}
+
+/// Compare two `Series` and describe how they differ.
+fn series_difference(
+    left: &Series,
+    right: &Series,
+    options: &AssertEqualOptions,
+) -> std::result::Result<(), String> {
+    if options.check_names && left.name() != right.name() {
+        return Err(format!(
+            "names differ: left: {:?}, right: {:?}",
+            left.name(),
+            right.name()
+        ));
+    }
+    if left.len() != right.len() {
+        return Err(format!(
+            "lengths differ: left: {}, right: {}",
+            left.len(),
+            right.len()
+        ));
+    }
+    let right = if left.dtype() != right.dtype() {
+        if options.check_dtype {
+            return Err(format!(
+                "dtypes differ: left: {:?}, right: {:?}",
+                left.dtype(),
+                right.dtype()
+            ));
+        }
+        right.cast(left.dtype()).map_err(|e| {
+            format!(
+                "could not cast right: {:?} to left: {:?}: {}",
+                right.dtype(),
+                left.dtype(),
+                e
+            )
+        })?
+    } else {
+        right.clone()
+    };
+
+    let idx = differing_rows(left, &right, options).map_err(|e| e.to_string())?;
+    if idx.is_empty() {
+        return Ok(());
+    }
+    let mut msg = format!("{} of {} values differ:", idx.len(), left.len());
+    for &i in idx.iter().take(options.max_reported_rows) {
+        msg.push_str(&format!(
+            "\n\trow {}: left: {}, right: {}",
+            i,
+            left.get(i),
+            right.get(i)
+        ));
+    }
+    if idx.len() > options.max_reported_rows {
+        msg.push_str("\n\t...");
+    }
+    Err(msg)
+}
+
+fn frame_difference(
+    left: &DataFrame,
+    right: &DataFrame,
+    options: &AssertEqualOptions,
+) -> std::result::Result<(), String> {
+    let left_names = left.get_column_names();
+    let right_names = right.get_column_names();
+    let missing = left_names
+        .iter()
+        .filter(|name| !right_names.contains(name))
+        .collect::<Vec<_>>();
+    let extra = right_names
+        .iter()
+        .filter(|name| !left_names.contains(name))
+        .collect::<Vec<_>>();
+    if !missing.is_empty() || !extra.is_empty() {
+        return Err(format!(
+            "columns differ: only in left: {:?}, only in right: {:?}",
+            missing, extra
+        ));
+    }
+    if options.check_column_order && left_names != right_names {
+        return Err(format!(
+            "column order differs: left: {:?}, right: {:?}",
+            left_names, right_names
+        ));
+    }
+    if left.height() != right.height() {
+        return Err(format!(
+            "heights differ: left: {}, right: {}",
+            left.height(),
+            right.height()
+        ));
+    }
+
+    let msgs = left
+        .get_columns()
+        .iter()
+        .filter_map(|l| {
+            // we checked that the names are present
+            let r = right.column(l.name()).unwrap();
+            series_difference(l, r, options)
+                .err()
+                .map(|msg| format!("column {:?}: {}", l.name(), msg))
+        })
+        .collect::<Vec<_>>();
+    if msgs.is_empty() {
+        Ok(())
+    } else {
+        Err(msgs.join("\n"))
+    }
+}
+
+/// Assert that two `Series` are equal, panicking with a description of the differences if they are not.
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// let a = Series::new("a", &[1.0, 2.0, 3.0]);
+/// let b = Series::new("a", &[1.0, 2.0, 3.0 + 1e-9]);
+///
+/// assert_series_equal(&a, &b, Default::default());
+/// ```
+#[track_caller]
+pub fn assert_series_equal(left: &Series, right: &Series, options: AssertEqualOptions) {
+    if let Err(msg) = series_difference(left, right, &options) {
+        panic!("Series are different: {}", msg)
+    }
+}
+
+/// Assert that two `DataFrames` are equal, panicking with a description of the differing
+/// columns and rows if they are not.
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// let df1: DataFrame = df!("Atomic number" => &[1, 51, 300],
+///                         "Element" => &[Some("Hydrogen"), Some("Antimony"), None])?;
+/// let df2 = df1.clone();
+///
+/// assert_frame_equal(&df1, &df2, Default::default());
+/// # Ok::<(), PolarsError>(())
+/// ```
+#[track_caller]
+pub fn assert_frame_equal(left: &DataFrame, right: &DataFrame, options: AssertEqualOptions) {
+    if let Err(msg) = frame_difference(left, right, &options) {
+        panic!("DataFrames are different:\n{}", msg)
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use super::*;
     use crate::prelude::*;
 
     #[test]
@@ -209,4 +472,53 @@ mod test {
         assert_eq!(df3, df3);
         assert_ne!(df4, df4);
     }
+
+    #[test]
+    fn test_assert_series_equal() {
+        let a = Series::new("a", &[Some(1.0), None, Some(3.0)]);
+        let b = Series::new("a", &[Some(1.0 + 1e-10), None, Some(3.0)]);
+        assert_series_equal(&a, &b, Default::default());
+
+        let opts = AssertEqualOptions::default().exact();
+        assert!(series_difference(&a, &b, &opts).is_err());
+
+        let opts = AssertEqualOptions {
+            null_equal: false,
+            ..Default::default()
+        };
+        assert!(series_difference(&a, &b, &opts).is_err());
+
+        let a = Series::new("a", &[1i32, 2, 3]);
+        let b = Series::new("a", &[1i64, 2, 3]);
+        assert!(series_difference(&a, &b, &Default::default()).is_err());
+        let opts = AssertEqualOptions {
+            check_dtype: false,
+            ..Default::default()
+        };
+        assert_series_equal(&a, &b, opts);
+    }
+
+    #[test]
+    fn test_assert_frame_equal() {
+        let df1 = df!("a" => &[1, 2, 3],
+                      "b" => &["foo", "bar", "ham"])
+        .unwrap();
+        let df2 = df!("a" => &[1, 2, 4],
+                      "b" => &["foo", "spam", "ham"])
+        .unwrap();
+        assert_frame_equal(&df1, &df1, Default::default());
+
+        let msg = frame_difference(&df1, &df2, &Default::default()).unwrap_err();
+        assert!(msg.contains("column \"a\": 1 of 3 values differ"));
+        assert!(msg.contains("row 2: left: 3, right: 4"));
+        assert!(msg.contains("column \"b\""));
+
+        let df3 = df1.select(["b", "a"]).unwrap();
+        assert!(frame_difference(&df1, &df3, &Default::default()).is_err());
+        let opts = AssertEqualOptions {
+            check_column_order: false,
+            ..Default::default()
+        };
+        assert_frame_equal(&df1, &df3, opts);
+    }
 }
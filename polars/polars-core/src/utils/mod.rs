@@ -139,11 +139,13 @@ pub fn split_series(s: &Series, n: usize) -> Result<Vec<Series>> {
 }
 
 fn flatten_df(df: &DataFrame) -> impl Iterator<Item = DataFrame> + '_ {
-    df.iter_chunks().map(|chunk| {
+    // we use the physical chunks and not the arrow arrays of `iter_chunks`
+    // as the latter are dictionary arrays for categoricals
+    (0..df.n_chunks().unwrap_or(0)).map(|chunk_idx| {
         DataFrame::new_no_checks(
             df.iter()
-                .zip(chunk.into_arrays())
-                .map(|(s, arr)| {
+                .map(|s| {
+                    let arr = s.chunks()[chunk_idx].clone();
                     // Safety:
                     // datatypes are correct
                     unsafe {
@@ -394,7 +396,7 @@ macro_rules! df {
     }
 }
 
-#[cfg(feature = "private")]
+/// Get the coarsest of both time units.
 pub fn get_time_units(tu_l: &TimeUnit, tu_r: &TimeUnit) -> TimeUnit {
     use TimeUnit::*;
     match (tu_l, tu_r) {
@@ -404,8 +406,34 @@ pub fn get_time_units(tu_l: &TimeUnit, tu_r: &TimeUnit) -> TimeUnit {
     }
 }
 
-/// Given two datatypes, determine the supertype that both types can safely be cast to
-#[cfg(feature = "private")]
+/// Given two datatypes, determine the supertype that both types can safely be cast to.
+///
+/// The rules are symmetric. Equal types are their own supertype, `Null` and `Unknown`
+/// coerce to the other type and a `List` coerces its inner type. Other combinations are:
+///
+/// | left                      | right                     | supertype                  |
+/// |---------------------------|---------------------------|----------------------------|
+/// | `Boolean`                 | any number                | that number                |
+/// | integer                   | integer of the same sign  | the wider integer          |
+/// | signed integer            | unsigned integer up to 32 bits | smallest signed integer that holds both |
+/// | signed integer            | `UInt64`                  | `Float64` (lossy), `Int64` with `bigidx` |
+/// | integer                   | `Float32`                 | `Float32` up to 16 bits, `Float64` otherwise |
+/// | `Float32`                 | `Float64`                 | `Float64`                  |
+/// | `Date`                    | integer up to 32 bits     | `Int32`                    |
+/// | `Date`                    | `UInt32`, 64 bits integer | `Int64`                    |
+/// | `Datetime`, `Duration`    | integer                   | `Int64`                    |
+/// | `Date`, `Datetime`, `Duration`, `Time` | float        | `Float64` (`Float32` for `Date`) |
+/// | `Date`                    | `Datetime(tu, tz)`        | `Datetime(tu, tz)`         |
+/// | `Datetime(tu_l, _)`       | `Datetime(tu_r, _)`       | coarsest time unit (lossy), time zone if only one is set |
+/// | `Duration(tu_l)`          | `Duration(tu_r)`          | coarsest time unit (lossy) |
+/// | `Duration(tu_l)`          | `Datetime(tu_r, tz)`      | `Datetime` with the coarsest time unit |
+/// | `Duration`                | `Date`                    | `Datetime(ms)`             |
+/// | `Time`                    | `Int32`, `Int64`, `Date`, `Datetime`, `Duration` | `Int64` |
+/// | `Categorical`             | `Utf8`                    | `Utf8`                     |
+/// | any                       | `Utf8`                    | `Utf8`                     |
+///
+/// A coercion is lossy if not every value of a type can be represented in the supertype.
+/// Use [`get_supertype_strict`] to refuse those.
 pub fn get_supertype(l: &DataType, r: &DataType) -> Result<DataType> {
     match _get_supertype(l, r) {
         Some(dt) => Ok(dt),
@@ -413,10 +441,106 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Result<DataType> {
             PolarsError::ComputeError(
                 format!("Failed to determine supertype of {:?} and {:?}", l, r).into(),
             )
+            .with_dtypes(l, r)
         }),
     }
 }
 
+/// Like [`get_supertype`], but returns an error if casting either type to the supertype
+/// can lose information. See [`is_lossless_cast`].
+pub fn get_supertype_strict(l: &DataType, r: &DataType) -> Result<DataType> {
+    let st = get_supertype(l, r)?;
+    for dt in [l, r] {
+        if !is_lossless_cast(dt, &st) {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "implicit cast of {:?} to the supertype {:?} of {:?} and {:?} may lose information, cast explicitly instead",
+                    dt, st, l, r
+                )
+                .into(),
+            ));
+        }
+    }
+    Ok(st)
+}
+
+fn integer_bits(dt: &DataType) -> Option<(bool, u8)> {
+    use DataType::*;
+    match dt {
+        #[cfg(feature = "dtype-u8")]
+        UInt8 => Some((false, 8)),
+        #[cfg(feature = "dtype-u16")]
+        UInt16 => Some((false, 16)),
+        UInt32 => Some((false, 32)),
+        UInt64 => Some((false, 64)),
+        #[cfg(feature = "dtype-i8")]
+        Int8 => Some((true, 8)),
+        #[cfg(feature = "dtype-i16")]
+        Int16 => Some((true, 16)),
+        Int32 => Some((true, 32)),
+        Int64 => Some((true, 64)),
+        _ => None,
+    }
+}
+
+/// Orders time units from coarse to fine.
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-duration"))]
+fn time_unit_rank(tu: &TimeUnit) -> u8 {
+    match tu {
+        TimeUnit::Milliseconds => 0,
+        TimeUnit::Microseconds => 1,
+        TimeUnit::Nanoseconds => 2,
+    }
+}
+
+/// Check if every value of `from` can be cast to `to` without losing information.
+///
+/// Integers are lossless in a wider integer of the same sign, a signed integer that is at
+/// least twice as wide, or a float whose mantissa holds all their values. Temporal types are
+/// lossless in a type with an equal or finer time unit and in a numeric type that their
+/// physical type is lossless in. Every type is lossless in `Utf8`.
+pub fn is_lossless_cast(from: &DataType, to: &DataType) -> bool {
+    use DataType::*;
+    if from == to {
+        return true;
+    }
+    match (from, to) {
+        (Null | Unknown, _) | (_, Utf8) => true,
+        (List(from), List(to)) => is_lossless_cast(from, to),
+        (from, List(to)) => is_lossless_cast(from, to),
+        (Boolean, to) => integer_bits(to).is_some() || matches!(to, Float32 | Float64),
+        (Float32, Float64) => true,
+        #[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+        (Date, Datetime(_, _)) => true,
+        #[cfg(feature = "dtype-datetime")]
+        (Datetime(tu_l, tz_l), Datetime(tu_r, tz_r)) => {
+            let tz_l = tz_l.as_deref().filter(|tz| !tz.is_empty());
+            let tz_r = tz_r.as_deref().filter(|tz| !tz.is_empty());
+            tz_l == tz_r && time_unit_rank(tu_l) <= time_unit_rank(tu_r)
+        }
+        #[cfg(feature = "dtype-duration")]
+        (Duration(tu_l), Duration(tu_r)) => time_unit_rank(tu_l) <= time_unit_rank(tu_r),
+        (from, to) if from.is_logical() && to.is_numeric() => {
+            is_lossless_cast(&from.to_physical(), to)
+        }
+        (from, Float32 | Float64) => {
+            let mantissa_bits = if matches!(to, Float32) { 24 } else { 53 };
+            match integer_bits(from) {
+                Some((signed, bits)) => bits - signed as u8 <= mantissa_bits,
+                None => false,
+            }
+        }
+        (from, to) => match (integer_bits(from), integer_bits(to)) {
+            (Some((signed_l, bits_l)), Some((signed_r, bits_r))) => match (signed_l, signed_r) {
+                (false, true) => bits_l < bits_r,
+                (true, false) => false,
+                _ => bits_l <= bits_r,
+            },
+            _ => false,
+        },
+    }
+}
+
 /// Given two datatypes, determine the supertype that both types can safely be cast to
 fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
     fn inner(l: &DataType, r: &DataType) -> Option<DataType> {
@@ -546,6 +670,14 @@ fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
             (Float64, Float32) => Some(Float64),
 
             // Time related dtypes
+            #[cfg(all(feature = "dtype-date", feature = "dtype-i8"))]
+            (Date, Int8) => Some(Int32),
+            #[cfg(all(feature = "dtype-date", feature = "dtype-i16"))]
+            (Date, Int16) => Some(Int32),
+            #[cfg(all(feature = "dtype-date", feature = "dtype-u8"))]
+            (Date, UInt8) => Some(Int32),
+            #[cfg(all(feature = "dtype-date", feature = "dtype-u16"))]
+            (Date, UInt16) => Some(Int32),
             #[cfg(feature = "dtype-date")]
             (Date, UInt32) => Some(Int64),
             #[cfg(feature = "dtype-date")]
@@ -573,12 +705,20 @@ fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
             (Datetime(_, _), Float32) => Some(Float64),
             #[cfg(feature = "dtype-datetime")]
             (Datetime(_, _), Float64) => Some(Float64),
-            #[cfg(all(feature = "dtype-datetime", feature = "dtype=date"))]
+            #[cfg(all(feature = "dtype-datetime", feature = "dtype-date"))]
             (Datetime(tu, tz), Date) => Some(Datetime(*tu, tz.clone())),
 
             (Boolean, Float32) => Some(Float32),
             (Boolean, Float64) => Some(Float64),
 
+            #[cfg(all(feature = "dtype-duration", feature = "dtype-i8"))]
+            (Duration(_), Int8) => Some(Int64),
+            #[cfg(all(feature = "dtype-duration", feature = "dtype-i16"))]
+            (Duration(_), Int16) => Some(Int64),
+            #[cfg(all(feature = "dtype-duration", feature = "dtype-u8"))]
+            (Duration(_), UInt8) => Some(Int64),
+            #[cfg(all(feature = "dtype-duration", feature = "dtype-u16"))]
+            (Duration(_), UInt16) => Some(Int64),
             #[cfg(feature = "dtype-duration")]
             (Duration(_), UInt32) => Some(Int64),
             #[cfg(feature = "dtype-duration")]
@@ -605,6 +745,8 @@ fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
             (Time, Datetime(_, _)) => Some(Int64),
             #[cfg(all(feature = "dtype-datetime", feature = "dtype-time"))]
             (Datetime(_, _), Time) => Some(Int64),
+            #[cfg(all(feature = "dtype-time", feature = "dtype-duration"))]
+            (Time, Duration(_)) => Some(Int64),
             #[cfg(all(feature = "dtype-time", feature = "dtype-date"))]
             (Time, Date) => Some(Int64),
             #[cfg(all(feature = "dtype-date", feature = "dtype-time"))]
@@ -1079,4 +1221,20 @@ mod test {
             b.chunk_id().collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn test_supertype_strict() -> Result<()> {
+        use DataType::*;
+        assert_eq!(get_supertype_strict(&Int32, &Int64)?, Int64);
+        assert_eq!(get_supertype_strict(&UInt32, &Int64)?, Int64);
+        assert_eq!(get_supertype_strict(&Int32, &Float64)?, Float64);
+        assert_eq!(get_supertype_strict(&Boolean, &UInt32)?, UInt32);
+        // u64 and i64 both lose information in f64
+        assert!(get_supertype_strict(&UInt64, &Int64).is_err());
+        assert!(get_supertype_strict(&Int64, &Float32).is_err());
+        assert!(is_lossless_cast(&UInt32, &Int64));
+        assert!(!is_lossless_cast(&Int32, &UInt64));
+        assert!(!is_lossless_cast(&Float64, &Float32));
+        Ok(())
+    }
 }
@@ -126,6 +126,8 @@ where
             None,
             &projected_schema,
             None,
+            None,
+            None,
         )
     }
 }
@@ -57,8 +57,10 @@ use crate::csv::read_impl::{cast_columns, CoreReader};
 use crate::csv::utils::get_reader_bytes;
 use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
+use crate::progress::ReadProgressRef;
 use crate::utils::resolve_homedir;
 use crate::{RowCount, SerReader, SerWriter};
+use polars_core::cancel::CancellationToken;
 
 use polars_core::prelude::*;
 #[cfg(feature = "temporal")]
@@ -72,5 +74,7 @@ use std::fs::File;
 use std::io::Write;
 use std::path::PathBuf;
 
-pub use read::{CsvEncoding, CsvReader, NullValues};
+pub use read::{CsvEncoding, CsvReader, NullValues, RaggedLines};
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+pub use write::CsvCompression;
 pub use write::CsvWriter;
@@ -1,5 +1,6 @@
 use super::buffer::*;
-use crate::csv::read::NullValuesCompiled;
+use crate::csv::read::{NullValuesCompiled, RaggedLines};
+use arrow::bitmap::Bitmap;
 use num::traits::Pow;
 use polars_core::prelude::*;
 
@@ -414,11 +415,16 @@ fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
 /// * `offset` - offset in bytes in total input. This is 0 if single threaded. If multi-threaded every
 ///              thread has a different offset.
 /// * `projection` - Indices of the columns to project.
+/// * `selected` - If given, only the lines with a set bit are parsed. The other lines are skipped
+///                without parsing any field. The bits index the lines that are not rejected.
 /// * `buffers` - Parsed output will be written to these buffers. Except for UTF8 data. The offsets of the
 ///               fields are written to the buffers. The UTF8 data will be parsed later.
+/// * `ragged_lines` - What to do with lines that don't have `schema_len` fields.
+/// * `rejected` - The lines rejected by [`RaggedLines::Reject`] are pushed here. They are not
+///                counted in `n_lines`.
 #[allow(clippy::too_many_arguments)]
-pub(super) fn parse_lines(
-    mut bytes: &[u8],
+pub(super) fn parse_lines<'a>(
+    mut bytes: &'a [u8],
     offset: usize,
     delimiter: u8,
     comment_char: Option<u8>,
@@ -426,11 +432,14 @@ pub(super) fn parse_lines(
     eol_char: u8,
     null_values: Option<&NullValuesCompiled>,
     projection: &[usize],
+    selected: Option<&Bitmap>,
     buffers: &mut [Buffer],
     ignore_parser_errors: bool,
     n_lines: usize,
     // length or original schema
     schema_len: usize,
+    ragged_lines: RaggedLines,
+    rejected: &mut Vec<&'a [u8]>,
 ) -> Result<usize> {
     assert!(
         !projection.is_empty(),
@@ -470,6 +479,45 @@ pub(super) fn parse_lines(
             }
         }
 
+        if ragged_lines != RaggedLines::PadWithNulls {
+            let n_fields = SplitFields::new(bytes, delimiter, quote_char, eol_char).count();
+            if n_fields != schema_len {
+                match ragged_lines {
+                    RaggedLines::Truncate if n_fields > schema_len => {}
+                    RaggedLines::Reject => {
+                        let bytes_rem = skip_this_line(bytes, quote_char, eol_char);
+                        rejected.push(&bytes[..bytes.len() - bytes_rem.len()]);
+                        bytes = bytes_rem;
+                        continue;
+                    }
+                    _ => {
+                        return Err(PolarsError::ComputeError(
+                            format!(
+                                "Found a line with {} fields, but the schema has {} columns.\n\
+                                The current offset in the file is {} bytes.\n\
+                                \n\
+                                Consider choosing another policy for ragged lines.",
+                                n_fields,
+                                schema_len,
+                                offset + bytes.as_ptr() as usize - start,
+                            )
+                            .into(),
+                        ))
+                    }
+                }
+            }
+        }
+
+        // the lines are selected by their index among the lines that are not rejected
+        if let Some(selected) = selected {
+            let i = line_count as usize;
+            if i >= selected.len() || !selected.get_bit(i) {
+                bytes = skip_this_line(bytes, quote_char, eol_char);
+                line_count += 1;
+                continue;
+            }
+        }
+
         // Every line we only need to parse the columns that are projected.
         // Therefore we check if the idx of the field is in our projected columns.
         // If it is not, we skip the field.
@@ -7,6 +7,28 @@ pub enum CsvEncoding {
     Utf8,
     /// Utf8 encoding and unknown bytes are replaced with �
     LossyUtf8,
+    /// Latin-1 (ISO-8859-1) encoding, transcoded to utf8 when the file is read
+    Latin1,
+    /// Little endian UTF-16 encoding, transcoded to utf8 when the file is read
+    Utf16Le,
+    /// Big endian UTF-16 encoding, transcoded to utf8 when the file is read
+    Utf16Be,
+}
+
+/// What to do with lines that have fewer or more fields than the schema has columns.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RaggedLines {
+    /// Missing fields are read as null and extra fields are ignored.
+    #[default]
+    PadWithNulls,
+    /// Extra fields are ignored, missing fields raise an error.
+    Truncate,
+    /// Raise an error.
+    Error,
+    /// Don't read the line, but collect it in the rejected lines.
+    /// See [`CsvReader::finish_with_rejected`].
+    Reject,
 }
 
 #[derive(Clone, Debug)]
@@ -41,6 +63,16 @@ impl NullValuesCompiled {
         }
     }
 
+    /// The null values of the columns at the given `positions` of the projection.
+    pub(super) fn select(&self, positions: &[usize]) -> Self {
+        use NullValuesCompiled::*;
+        match self {
+            AllColumnsSingle(v) => AllColumnsSingle(v.clone()),
+            AllColumns(v) => AllColumns(v.clone()),
+            Columns(v) => Columns(positions.iter().map(|i| v[*i].clone()).collect()),
+        }
+    }
+
     /// Safety
     /// The caller must ensure that `index` is in bounds
     pub(super) unsafe fn is_null(&self, field: &[u8], index: usize) -> bool {
@@ -127,6 +159,9 @@ where
     skip_rows_after_header: usize,
     parse_dates: bool,
     row_count: Option<RowCount>,
+    ragged_lines: RaggedLines,
+    progress: Option<ReadProgressRef>,
+    cancel_token: Option<CancellationToken>,
 }
 
 impl<'a, R> CsvReader<'a, R>
@@ -145,6 +180,13 @@ where
         self
     }
 
+    /// Set what to do with lines that have fewer or more fields than the schema.
+    /// Defaults to [`RaggedLines::PadWithNulls`].
+    pub fn with_ragged_lines(mut self, ragged_lines: RaggedLines) -> Self {
+        self.ragged_lines = ragged_lines;
+        self
+    }
+
     /// Sets the chunk size used by the parser. This influences performance
     pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
         self.chunk_size = chunk_size;
@@ -312,6 +354,19 @@ where
         self.aggregate = aggregate;
         self
     }
+
+    /// Report the lines and bytes of every chunk that is parsed.
+    pub fn with_progress(mut self, progress: Option<ReadProgressRef>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Abort the read with an error once `cancel_token` is cancelled. The token is checked
+    /// before every chunk that is parsed.
+    pub fn with_cancellation(mut self, cancel_token: Option<CancellationToken>) -> Self {
+        self.cancel_token = cancel_token;
+        self
+    }
 }
 
 impl<'a> CsvReader<'a, File> {
@@ -358,17 +413,45 @@ where
             skip_rows_after_header: 0,
             parse_dates: false,
             row_count: None,
+            ragged_lines: RaggedLines::default(),
+            progress: None,
+            cancel_token: None,
         }
     }
 
     /// Read the file and create the DataFrame.
-    fn finish(mut self) -> Result<DataFrame> {
+    fn finish(self) -> Result<DataFrame> {
+        self.finish_impl().map(|(df, _)| df)
+    }
+}
+
+impl<'a, R> CsvReader<'a, R>
+where
+    R: MmapBytesReader,
+{
+    /// Read the file and create the DataFrame. Also returns a DataFrame with a Utf8 column `line`
+    /// that has the lines that were rejected by [`RaggedLines::Reject`].
+    pub fn finish_with_rejected(self) -> Result<(DataFrame, DataFrame)> {
+        let (df, rejected) = self.finish_impl()?;
+        let rejected = DataFrame::new_no_checks(vec![Utf8Chunked::from_iter_values(
+            "line",
+            rejected.iter().map(|line| line.as_str()),
+        )
+        .into_series()]);
+        Ok((df, rejected))
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "read_csv", skip_all, fields(rows = tracing::field::Empty))
+    )]
+    fn finish_impl(mut self) -> Result<(DataFrame, Vec<String>)> {
         let rechunk = self.rechunk;
         // we cannot append categorical under local string cache, so we cast them later.
         #[allow(unused_mut)]
         let mut to_cast_local = vec![];
 
-        let mut df = if let Some(schema) = self.schema_overwrite {
+        let (mut df, rejected) = if let Some(schema) = self.schema_overwrite {
             // This branch we check if there are dtypes we cannot parse.
             // We only support a few dtypes in the parser and later cast to the required dtype
             let mut to_cast = Vec::with_capacity(schema.len());
@@ -435,8 +518,12 @@ where
                 self.skip_rows_after_header,
                 self.row_count,
                 self.parse_dates,
+                self.ragged_lines,
+                self.progress.clone(),
+                self.cancel_token.clone(),
             )?;
-            csv_reader.as_df()?
+            let df = csv_reader.as_df()?;
+            (df, csv_reader.take_rejected_lines())
         } else {
             let reader_bytes = get_reader_bytes(&mut self.reader)?;
             let mut csv_reader = CoreReader::new(
@@ -467,8 +554,12 @@ where
                 self.skip_rows_after_header,
                 self.row_count,
                 self.parse_dates,
+                self.ragged_lines,
+                self.progress.clone(),
+                self.cancel_token.clone(),
             )?;
-            csv_reader.as_df()?
+            let df = csv_reader.as_df()?;
+            (df, csv_reader.take_rejected_lines())
         };
 
         // Important that this rechunk is never done in parallel.
@@ -502,7 +593,9 @@ where
         }
 
         cast_columns(&mut df, &to_cast_local, true)?;
-        Ok(df)
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("rows", df.height());
+        Ok((df, rejected))
     }
 }
 
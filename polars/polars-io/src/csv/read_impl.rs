@@ -1,19 +1,23 @@
 use crate::aggregations::ScanAggregation;
-use crate::csv::read::NullValuesCompiled;
+use crate::csv::read::{NullValuesCompiled, RaggedLines};
 use crate::csv::utils::*;
 use crate::csv::{buffer::*, parser::*};
 use crate::csv::{CsvEncoding, NullValues};
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
+use crate::progress::ReadProgressRef;
 use crate::utils::update_row_counts;
 use crate::RowCount;
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_arrow::array::*;
+use polars_core::cancel::CancellationToken;
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::{prelude::*, POOL};
 #[cfg(feature = "polars-time")]
 use polars_time::prelude::*;
 use polars_utils::flatten;
 use rayon::prelude::*;
+#[cfg(not(target_family = "wasm"))]
 use rayon::ThreadPoolBuilder;
 use std::borrow::Cow;
 use std::fmt;
@@ -57,6 +61,9 @@ pub(crate) fn cast_columns(df: &mut DataFrame, to_cast: &[Field], parallel: bool
     Ok(())
 }
 
+/// Upper bound of the chunk size in low memory mode.
+const LOW_MEMORY_CHUNK_SIZE: usize = 1 << 14;
+
 /// CSV file reader
 pub(crate) struct CoreReader<'a> {
     reader_bytes: Option<ReaderBytes<'a>>,
@@ -86,6 +93,11 @@ pub(crate) struct CoreReader<'a> {
     aggregate: Option<&'a [ScanAggregation]>,
     to_cast: &'a [Field],
     row_count: Option<RowCount>,
+    ragged_lines: RaggedLines,
+    /// The lines that were rejected by [`RaggedLines::Reject`]
+    rejected_lines: Vec<String>,
+    progress: Option<ReadProgressRef>,
+    cancel_token: Option<CancellationToken>,
 }
 
 impl<'a> fmt::Debug for CoreReader<'a> {
@@ -146,6 +158,41 @@ impl RunningSize {
     }
 }
 
+/// The columns that are parsed in one pass over a chunk, together with their null values and
+/// the running statistics of their str columns.
+struct PassProjection {
+    columns: Vec<usize>,
+    null_values: Option<NullValuesCompiled>,
+    str_columns: Vec<String>,
+    str_capacities: Vec<RunningSize>,
+}
+
+/// If a predicate only needs some of the projected columns, every chunk is parsed in two
+/// passes. The first pass parses the columns of the predicate, the second pass only parses the
+/// remaining columns for the lines that pass the predicate.
+struct PredicateProjection {
+    predicate: PassProjection,
+    rest: PassProjection,
+}
+
+/// The lines that pass a predicate. A null is not selected, like in a filter.
+fn selected_lines(mask: &BooleanChunked, n_lines: usize) -> Bitmap {
+    if mask.len() == 1 && n_lines != 1 {
+        let mut bits = MutableBitmap::with_capacity(n_lines);
+        bits.extend_constant(n_lines, mask.get(0).unwrap_or(false));
+        return bits.into();
+    }
+    let mask = mask.rechunk();
+    let selected = match mask.downcast_iter().next() {
+        Some(arr) => match arr.validity() {
+            Some(validity) => arr.values() & validity,
+            None => arr.values().clone(),
+        },
+        None => MutableBitmap::new().into(),
+    };
+    selected
+}
+
 impl<'a> CoreReader<'a> {
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
@@ -176,10 +223,10 @@ impl<'a> CoreReader<'a> {
         skip_rows_after_header: usize,
         row_count: Option<RowCount>,
         parse_dates: bool,
+        ragged_lines: RaggedLines,
+        progress: Option<ReadProgressRef>,
+        cancel_token: Option<CancellationToken>,
     ) -> Result<CoreReader<'a>> {
-        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
-        let mut reader_bytes = reader_bytes;
-
         #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
         if is_compressed(&reader_bytes) {
             return Err(PolarsError::ComputeError("cannot read compressed csv file; compile with feature 'decompress' or 'decompress-fast'".into()));
@@ -188,52 +235,46 @@ impl<'a> CoreReader<'a> {
         // check if schema should be inferred
         let delimiter = delimiter.unwrap_or(b',');
 
+        // we only decompress the lines we need, the header and the skipped lines included.
+        // Which lines are rejected depends on the schema, so a rejected line could take the
+        // place of a row that is needed.
+        let n_lines = match ragged_lines {
+            RaggedLines::Reject => None,
+            _ => n_rows.map(|n| n + skip_rows + skip_rows_after_header + has_header as usize),
+        };
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        let reader_bytes =
+            match decompress(&reader_bytes, n_lines, delimiter, quote_char, eol_char)? {
+                Some(b) => ReaderBytes::Owned(b),
+                None => reader_bytes,
+            };
+        // like the decompression, only the lines that are read are transcoded
+        let reader_bytes = transcode_to_utf8(
+            reader_bytes,
+            encoding,
+            n_lines,
+            quote_char,
+            comment_char,
+            eol_char,
+        )?;
+
         let mut schema = match schema {
             Some(schema) => Cow::Borrowed(schema),
             None => {
-                #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
-                {
-                    // We keep track of the inferred schema bool
-                    // In case the file is compressed this schema inference is wrong and has to be done
-                    // again after decompression.
-                    if let Some(b) =
-                        decompress(&reader_bytes, n_rows, delimiter, quote_char, eol_char)
-                    {
-                        reader_bytes = ReaderBytes::Owned(b);
-                    }
-
-                    let (inferred_schema, _) = infer_file_schema(
-                        &reader_bytes,
-                        delimiter,
-                        max_records,
-                        has_header,
-                        schema_overwrite,
-                        &mut skip_rows,
-                        comment_char,
-                        quote_char,
-                        eol_char,
-                        null_values.as_ref(),
-                        parse_dates,
-                    )?;
-                    Cow::Owned(inferred_schema)
-                }
-                #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
-                {
-                    let (inferred_schema, _) = infer_file_schema(
-                        &reader_bytes,
-                        delimiter,
-                        max_records,
-                        has_header,
-                        schema_overwrite,
-                        &mut skip_rows,
-                        comment_char,
-                        quote_char,
-                        eol_char,
-                        null_values.as_ref(),
-                        parse_dates,
-                    )?;
-                    Cow::Owned(inferred_schema)
-                }
+                let (inferred_schema, _) = infer_file_schema(
+                    &reader_bytes,
+                    delimiter,
+                    max_records,
+                    has_header,
+                    schema_overwrite,
+                    &mut skip_rows,
+                    comment_char,
+                    quote_char,
+                    eol_char,
+                    null_values.as_ref(),
+                    parse_dates,
+                )?;
+                Cow::Owned(inferred_schema)
             }
         };
         if let Some(dtypes) = dtype_overwrite {
@@ -271,12 +312,21 @@ impl<'a> CoreReader<'a> {
             skip_rows_before_header: skip_rows,
             skip_rows_after_header,
             n_rows,
-            encoding,
+            // the other encodings are transcoded to utf8 already
+            encoding: match encoding {
+                CsvEncoding::LossyUtf8 => CsvEncoding::LossyUtf8,
+                _ => CsvEncoding::Utf8,
+            },
             n_threads,
             has_header,
             delimiter,
             sample_size,
-            chunk_size,
+            // smaller chunks lead to smaller buffers that are alive at the same time
+            chunk_size: if low_memory {
+                std::cmp::min(chunk_size, LOW_MEMORY_CHUNK_SIZE)
+            } else {
+                chunk_size
+            },
             low_memory,
             comment_char,
             quote_char,
@@ -286,6 +336,10 @@ impl<'a> CoreReader<'a> {
             aggregate,
             to_cast,
             row_count,
+            ragged_lines,
+            rejected_lines: vec![],
+            progress,
+            cancel_token,
         })
     }
 
@@ -319,18 +373,22 @@ impl<'a> CoreReader<'a> {
 
         if self.skip_rows_after_header > 0 {
             for _ in 0..self.skip_rows_after_header {
-                let pos = match bytes.first() {
-                    Some(first) if Some(*first) == self.comment_char => {
-                        next_line_position_naive(bytes, eol_char)
+                // comment lines don't count as rows
+                if let Some(c) = self.comment_char {
+                    while bytes.first() == Some(&c) {
+                        let pos = next_line_position_naive(bytes, eol_char).ok_or_else(|| {
+                            PolarsError::NoData("not enough lines to skip".into())
+                        })?;
+                        bytes = &bytes[pos..];
                     }
-                    _ => next_line_position(
-                        bytes,
-                        self.schema.len(),
-                        self.delimiter,
-                        self.quote_char,
-                        eol_char,
-                    ),
                 }
+                let pos = next_line_position(
+                    bytes,
+                    self.schema.len(),
+                    self.delimiter,
+                    self.quote_char,
+                    eol_char,
+                )
                 .ok_or_else(|| PolarsError::NoData("not enough lines to skip".into()))?;
 
                 bytes = &bytes[pos..];
@@ -346,6 +404,198 @@ impl<'a> CoreReader<'a> {
         Ok((bytes, starting_point_offset))
     }
 
+    /// Determine if the `predicate` can be evaluated on a subset of the `projection`, see
+    /// [`PredicateProjection`].
+    fn predicate_projection(
+        &self,
+        projection: &[usize],
+        predicate: &dyn PhysicalIoExpr,
+        init_str_bytes: usize,
+    ) -> Option<PredicateProjection> {
+        let mut predicate_columns = predicate
+            .live_columns()?
+            .iter()
+            .map(|name| self.schema.get_full(name).map(|(i, _, _)| i))
+            .collect::<Option<Vec<_>>>()?;
+        predicate_columns.sort_unstable();
+        predicate_columns.dedup();
+        if predicate_columns.is_empty()
+            || predicate_columns.len() >= projection.len()
+            || predicate_columns
+                .iter()
+                .any(|i| projection.binary_search(i).is_err())
+        {
+            return None;
+        }
+
+        // positions in the projection
+        let (predicate_positions, rest_positions): (Vec<_>, Vec<_>) = (0..projection.len())
+            .partition(|pos| predicate_columns.binary_search(&projection[*pos]).is_ok());
+
+        let pass = |positions: Vec<usize>| {
+            let columns = positions
+                .iter()
+                .map(|pos| projection[*pos])
+                .collect::<Vec<_>>();
+            let str_columns = columns
+                .iter()
+                .filter_map(|i| match self.schema.get_index(*i) {
+                    Some((name, DataType::Utf8)) => Some(name.clone()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            PassProjection {
+                null_values: self.null_values.as_ref().map(|nv| nv.select(&positions)),
+                str_capacities: str_columns
+                    .iter()
+                    .map(|_| RunningSize::new(init_str_bytes))
+                    .collect(),
+                columns,
+                str_columns,
+            }
+        };
+        Some(PredicateProjection {
+            predicate: pass(predicate_positions),
+            rest: pass(rest_positions),
+        })
+    }
+
+    /// Parse at most `n_lines + 1` lines of `bytes` into a `DataFrame` with the projected
+    /// `columns`. Returns the `DataFrame` and the number of bytes read.
+    #[allow(clippy::too_many_arguments)]
+    fn parse_chunk<'b>(
+        &self,
+        bytes: &'b [u8],
+        offset: usize,
+        columns: &[usize],
+        null_values: Option<&NullValuesCompiled>,
+        str_capacities: &[RunningSize],
+        selected: Option<&Bitmap>,
+        capacity: usize,
+        n_lines: usize,
+        rejected: &mut Vec<&'b [u8]>,
+    ) -> Result<(DataFrame, usize)> {
+        let mut buffers = init_buffers(
+            columns,
+            capacity,
+            &self.schema,
+            str_capacities,
+            self.quote_char,
+            self.encoding,
+            self.ignore_parser_errors,
+        )?;
+        let read = parse_lines(
+            bytes,
+            offset,
+            self.delimiter,
+            self.comment_char,
+            self.quote_char,
+            self.eol_char,
+            null_values,
+            columns,
+            selected,
+            &mut buffers,
+            self.ignore_parser_errors,
+            n_lines,
+            self.schema.len(),
+            self.ragged_lines,
+            rejected,
+        )?;
+        let df = DataFrame::new_no_checks(
+            buffers
+                .into_iter()
+                .map(|buf| buf.into_series())
+                .collect::<Result<_>>()?,
+        );
+        Ok((df, read))
+    }
+
+    /// Parse a chunk in two passes, see [`PredicateProjection`].
+    ///
+    /// Returns the filtered `DataFrame`, the number of lines before filtering and the number of
+    /// bytes read. Returns `None` if both passes don't agree on the lines of the chunk, which can
+    /// happen with malformed quoting.
+    #[allow(clippy::too_many_arguments)]
+    fn parse_chunk_with_predicate<'b>(
+        &self,
+        bytes: &'b [u8],
+        offset: usize,
+        projection: &[usize],
+        predicate_projection: &PredicateProjection,
+        predicate: &dyn PhysicalIoExpr,
+        chunk_size: usize,
+        rejected: &mut Vec<&'b [u8]>,
+    ) -> Result<Option<(DataFrame, IdxSize, usize)>> {
+        let PredicateProjection {
+            predicate: first,
+            rest: second,
+        } = predicate_projection;
+
+        let (mut predicate_df, read) = self.parse_chunk(
+            bytes,
+            offset,
+            &first.columns,
+            first.null_values.as_ref(),
+            &first.str_capacities,
+            None,
+            chunk_size,
+            chunk_size,
+            rejected,
+        )?;
+        let n_lines = predicate_df.height();
+        if let Some(rc) = &self.row_count {
+            predicate_df.with_row_count_mut(&rc.name, Some(rc.offset));
+        };
+        let s = predicate.evaluate(&predicate_df)?;
+        let mask = s.bool().expect("filter predicates was not of type boolean");
+        let selected = selected_lines(mask, n_lines);
+        let n_selected = n_lines - selected.unset_bits();
+
+        // if no line is selected, there is nothing to parse in the second pass
+        let bytes = if n_selected == 0 {
+            &bytes[..0]
+        } else {
+            &bytes[..read]
+        };
+        let (rest_df, _) = self.parse_chunk(
+            bytes,
+            offset,
+            &second.columns,
+            second.null_values.as_ref(),
+            &second.str_capacities,
+            Some(&selected),
+            n_selected,
+            chunk_size,
+            // the rejected lines are already collected in the first pass
+            &mut vec![],
+        )?;
+        if rest_df.height() != n_selected {
+            return Ok(None);
+        }
+        let predicate_df = predicate_df.filter(mask)?;
+
+        // restore the order of the projection
+        let mut predicate_columns = predicate_df.get_columns().iter();
+        let mut rest_columns = rest_df.get_columns().iter();
+        let mut columns = Vec::with_capacity(predicate_df.width() + rest_df.width());
+        if self.row_count.is_some() {
+            columns.extend(predicate_columns.next().cloned());
+        }
+        for i in projection {
+            let s = if first.columns.binary_search(i).is_ok() {
+                predicate_columns.next()
+            } else {
+                rest_columns.next()
+            };
+            columns.push(s.unwrap().clone());
+        }
+        Ok(Some((
+            DataFrame::new_no_checks(columns),
+            n_lines as IdxSize,
+            read,
+        )))
+    }
+
     fn parse_csv(
         &mut self,
         mut n_threads: usize,
@@ -451,7 +701,9 @@ impl<'a> CoreReader<'a> {
 
         // If the number of threads given by the user is lower than our global thread pool we create
         // new one.
+        #[cfg(not(target_family = "wasm"))]
         let owned_pool;
+        #[cfg(not(target_family = "wasm"))]
         let pool = if POOL.current_num_threads() != n_threads {
             owned_pool = Some(
                 ThreadPoolBuilder::new()
@@ -463,11 +715,14 @@ impl<'a> CoreReader<'a> {
         } else {
             &POOL
         };
+        // wasm has no threads to spawn, the global pool runs on the calling thread
+        #[cfg(target_family = "wasm")]
+        let pool = &*POOL;
 
         // all the buffers returned from the threads
         // Structure:
         //      the inner vec has got buffers from all the columns.
-        if predicate.is_some() {
+        if let Some(predicate) = predicate {
             // assume 10 chars per str
             // this is not updated in low memory mode
             let init_str_bytes = chunk_size * 10;
@@ -496,73 +751,102 @@ impl<'a> CoreReader<'a> {
                 return Ok(df);
             }
 
+            let predicate_projection =
+                self.predicate_projection(&projection, &**predicate, init_str_bytes);
+            if logging && predicate_projection.is_some() {
+                eprintln!("parsing the columns of the predicate before the other columns");
+            }
+            let str_stats: Vec<(&str, &RunningSize)> = match &predicate_projection {
+                Some(pp) => [&pp.predicate, &pp.rest]
+                    .into_iter()
+                    .flat_map(|pass| {
+                        pass.str_columns
+                            .iter()
+                            .map(|name| name.as_str())
+                            .zip(&pass.str_capacities)
+                    })
+                    .collect(),
+                None => str_columns
+                    .iter()
+                    .map(|name| name.as_str())
+                    .zip(&str_capacities)
+                    .collect(),
+            };
+
             let dfs = pool.install(|| {
                 file_chunks
                     .into_par_iter()
                     .map(|(bytes_offset_thread, stop_at_nbytes)| {
-                        let delimiter = self.delimiter;
-                        let schema = self.schema.clone();
-                        let ignore_parser_errors = self.ignore_parser_errors;
                         let projection = &projection;
 
                         let mut read = bytes_offset_thread;
                         let mut dfs = Vec::with_capacity(256);
+                        let mut rejected = vec![];
 
                         let mut last_read = usize::MAX;
                         loop {
                             if read >= stop_at_nbytes || read == last_read {
                                 break;
                             }
-
-                            let mut buffers = init_buffers(
-                                projection,
-                                chunk_size,
-                                &schema,
-                                &str_capacities,
-                                self.quote_char,
-                                self.encoding,
-                                self.ignore_parser_errors,
-                            )?;
+                            self.check_cancelled()?;
 
                             let local_bytes = &bytes[read..stop_at_nbytes];
 
                             last_read = read;
                             let offset = read + starting_point_offset.unwrap();
-                            read += parse_lines(
-                                local_bytes,
-                                offset,
-                                delimiter,
-                                self.comment_char,
-                                self.quote_char,
-                                self.eol_char,
-                                self.null_values.as_ref(),
-                                projection,
-                                &mut buffers,
-                                ignore_parser_errors,
-                                chunk_size,
-                                self.schema.len(),
-                            )?;
 
-                            let mut local_df = DataFrame::new_no_checks(
-                                buffers
-                                    .into_iter()
-                                    .map(|buf| buf.into_series())
-                                    .collect::<Result<_>>()?,
-                            );
-                            let current_row_count = local_df.height() as IdxSize;
-                            if let Some(rc) = &self.row_count {
-                                local_df.with_row_count_mut(&rc.name, Some(rc.offset));
+                            // the lines rejected by a two pass parse are only kept if that
+                            // parse is not discarded, otherwise the fallback rejects them again
+                            let mut pass_rejected = vec![];
+                            let parsed = match &predicate_projection {
+                                Some(pp) => self.parse_chunk_with_predicate(
+                                    local_bytes,
+                                    offset,
+                                    projection,
+                                    pp,
+                                    &**predicate,
+                                    chunk_size,
+                                    &mut pass_rejected,
+                                )?,
+                                None => None,
                             };
-
-                            if let Some(predicate) = predicate {
-                                let s = predicate.evaluate(&local_df)?;
-                                let mask =
-                                    s.bool().expect("filter predicates was not of type boolean");
-                                local_df = local_df.filter(mask)?;
+                            let (mut local_df, current_row_count, n_read) = match parsed {
+                                Some(parsed) => {
+                                    rejected.append(&mut pass_rejected);
+                                    parsed
+                                }
+                                None => {
+                                    let (mut local_df, n_read) = self.parse_chunk(
+                                        local_bytes,
+                                        offset,
+                                        projection,
+                                        self.null_values.as_ref(),
+                                        &str_capacities,
+                                        None,
+                                        chunk_size,
+                                        chunk_size,
+                                        &mut rejected,
+                                    )?;
+
+                                    let current_row_count = local_df.height() as IdxSize;
+                                    if let Some(rc) = &self.row_count {
+                                        local_df.with_row_count_mut(&rc.name, Some(rc.offset));
+                                    };
+
+                                    let s = predicate.evaluate(&local_df)?;
+                                    let mask = s
+                                        .bool()
+                                        .expect("filter predicates was not of type boolean");
+                                    (local_df.filter(mask)?, current_row_count, n_read)
+                                }
+                            };
+                            read += n_read;
+                            if let Some(progress) = &self.progress {
+                                progress.rows_read(current_row_count as usize, n_read as u64);
                             }
 
                             // update the running str bytes statistics
-                            for (str_index, name) in str_columns.iter().enumerate() {
+                            for (name, str_capacity) in &str_stats {
                                 let ca = local_df.column(name)?.utf8()?;
                                 let str_bytes_len = ca.get_values_size();
 
@@ -570,7 +854,7 @@ impl<'a> CoreReader<'a> {
                                 if self.low_memory {
                                     local_df.shrink_to_fit();
                                     let (max, avg, last, size_hint) =
-                                        str_capacities[str_index].update(str_bytes_len);
+                                        str_capacity.update(str_bytes_len);
                                     if logging {
                                         if size_hint < str_bytes_len {
                                             eprintln!(
@@ -590,10 +874,12 @@ impl<'a> CoreReader<'a> {
                             cast_columns(&mut local_df, self.to_cast, false)?;
                             dfs.push((local_df, current_row_count));
                         }
-                        Ok(dfs)
+                        Ok((dfs, rejected))
                     })
                     .collect::<Result<Vec<_>>>()
             })?;
+            let (dfs, rejected): (Vec<_>, Vec<_>) = dfs.into_iter().unzip();
+            self.set_rejected_lines(&rejected);
             let mut dfs = flatten(&dfs, None);
             if self.row_count.is_some() {
                 update_row_counts(&mut dfs)
@@ -618,7 +904,7 @@ impl<'a> CoreReader<'a> {
                 .map(|_| RunningSize::new(init_str_bytes))
                 .collect();
 
-            let mut dfs = pool.install(|| {
+            let dfs = pool.install(|| {
                 file_chunks
                     .into_par_iter()
                     .map(|(bytes_offset_thread, stop_at_nbytes)| {
@@ -628,6 +914,7 @@ impl<'a> CoreReader<'a> {
                         let projection = &projection;
 
                         let mut read = bytes_offset_thread;
+                        let mut rejected = vec![];
                         let mut buffers = init_buffers(
                             projection,
                             capacity,
@@ -643,6 +930,7 @@ impl<'a> CoreReader<'a> {
                             if read >= stop_at_nbytes || read == last_read {
                                 break;
                             }
+                            self.check_cancelled()?;
                             let local_bytes = &bytes[read..stop_at_nbytes];
 
                             last_read = read;
@@ -656,12 +944,15 @@ impl<'a> CoreReader<'a> {
                                 self.eol_char,
                                 self.null_values.as_ref(),
                                 projection,
+                                None,
                                 &mut buffers,
                                 ignore_parser_errors,
                                 // chunk size doesn't really matter anymore,
                                 // less calls if we increase the size
                                 usize::MAX,
                                 self.schema.len(),
+                                self.ragged_lines,
+                                &mut rejected,
                             )?;
                         }
 
@@ -672,15 +963,21 @@ impl<'a> CoreReader<'a> {
                                 .collect::<Result<_>>()?,
                         );
 
+                        if let Some(progress) = &self.progress {
+                            progress.rows_read(df.height(), (read - bytes_offset_thread) as u64);
+                        }
+
                         cast_columns(&mut df, self.to_cast, false)?;
                         if let Some(rc) = &self.row_count {
                             df.with_row_count_mut(&rc.name, Some(rc.offset));
                         }
                         let n_read = df.height() as IdxSize;
-                        Ok((df, n_read))
+                        Ok(((df, n_read), rejected))
                     })
                     .collect::<Result<Vec<_>>>()
             })?;
+            let (mut dfs, rejected): (Vec<_>, Vec<_>) = dfs.into_iter().unzip();
+            self.set_rejected_lines(&rejected);
             if self.row_count.is_some() {
                 update_row_counts(&mut dfs)
             }
@@ -688,6 +985,33 @@ impl<'a> CoreReader<'a> {
         }
     }
 
+    /// Error if the read is cancelled, checked before every chunk that is parsed.
+    fn check_cancelled(&self) -> Result<()> {
+        match &self.cancel_token {
+            Some(token) => token.check(),
+            None => Ok(()),
+        }
+    }
+
+    /// Store the lines rejected by [`RaggedLines::Reject`] by all threads, in file order.
+    fn set_rejected_lines(&mut self, rejected: &[Vec<&[u8]>]) {
+        let eol_char = self.eol_char;
+        self.rejected_lines = rejected
+            .iter()
+            .flatten()
+            .map(|line| {
+                let line = line.strip_suffix(&[eol_char]).unwrap_or(line);
+                let line = line.strip_suffix(b"\r").unwrap_or(line);
+                String::from_utf8_lossy(line).into_owned()
+            })
+            .collect();
+    }
+
+    /// Take the lines that were rejected by [`RaggedLines::Reject`] in [`CoreReader::as_df`].
+    pub(crate) fn take_rejected_lines(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.rejected_lines)
+    }
+
     /// Read the csv into a DataFrame. The predicate can come from a lazy physical plan.
     pub fn as_df(&mut self) -> Result<DataFrame> {
         let predicate = self.predicate.take();
@@ -56,25 +56,25 @@ pub(crate) fn get_file_chunks(
 
 pub fn get_reader_bytes<R: Read + MmapBytesReader>(reader: &mut R) -> Result<ReaderBytes<'_>> {
     // we have a file so we can mmap
+    // on wasm there is no mmap, the file is read to an owned buffer instead
+    #[cfg(not(target_family = "wasm"))]
     if let Some(file) = reader.to_file() {
         let mmap = unsafe { memmap::Mmap::map(file)? };
-        Ok(ReaderBytes::Mapped(mmap))
+        return Ok(ReaderBytes::Mapped(mmap));
+    }
+    // we can get the bytes for free
+    if reader.to_bytes().is_some() {
+        // duplicate .to_bytes() is necessary to satisfy the borrow checker
+        Ok(ReaderBytes::Borrowed((*reader).to_bytes().unwrap()))
     } else {
-        // we can get the bytes for free
-        if reader.to_bytes().is_some() {
-            // duplicate .to_bytes() is necessary to satisfy the borrow checker
-            Ok(ReaderBytes::Borrowed((*reader).to_bytes().unwrap()))
-        } else {
-            // we have to read to an owned buffer to get the bytes.
-            let mut bytes = Vec::with_capacity(1024 * 128);
-            reader.read_to_end(&mut bytes)?;
-            if !bytes.is_empty()
-                && (bytes[bytes.len() - 1] != b'\n' || bytes[bytes.len() - 1] != b'\r')
-            {
-                bytes.push(b'\n')
-            }
-            Ok(ReaderBytes::Owned(bytes))
+        // we have to read to an owned buffer to get the bytes.
+        let mut bytes = Vec::with_capacity(1024 * 128);
+        reader.read_to_end(&mut bytes)?;
+        if !bytes.is_empty() && (bytes[bytes.len() - 1] != b'\n' || bytes[bytes.len() - 1] != b'\r')
+        {
+            bytes.push(b'\n')
         }
+        Ok(ReaderBytes::Owned(bytes))
     }
 }
 
@@ -145,14 +145,118 @@ fn infer_field_schema(string: &str, parse_dates: bool) -> DataType {
 #[inline]
 pub(crate) fn parse_bytes_with_encoding(bytes: &[u8], encoding: CsvEncoding) -> Result<Cow<str>> {
     let s = match encoding {
-        CsvEncoding::Utf8 => simdutf8::basic::from_utf8(bytes)
+        CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
+        // other encodings are transcoded to utf8 when the file is read
+        _ => simdutf8::basic::from_utf8(bytes)
             .map_err(anyhow::Error::from)?
             .into(),
-        CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
     };
     Ok(s)
 }
 
+/// Counts the lines of the transcoded chars, so that the transcoding stops after the lines
+/// that are needed. A line ending in a quoted field does not end a line, and empty lines and
+/// comments are not counted, as the parser doesn't emit a row for them.
+struct LineCounter {
+    remaining: Option<usize>,
+    in_quotes: bool,
+    quote_char: Option<char>,
+    comment_char: Option<char>,
+    eol_char: char,
+    /// Whether the current line has a char that is not whitespace.
+    has_content: bool,
+    is_comment: bool,
+}
+
+impl LineCounter {
+    /// Whether `c` ends the last line that is needed.
+    #[inline]
+    fn is_done(&mut self, c: char) -> bool {
+        if c == self.eol_char && !self.in_quotes {
+            let is_row = self.has_content && !self.is_comment;
+            self.has_content = false;
+            self.is_comment = false;
+            if let (true, Some(remaining)) = (is_row, self.remaining.as_mut()) {
+                *remaining = remaining.saturating_sub(1);
+                return *remaining == 0;
+            }
+            return false;
+        }
+        if !self.has_content && !matches!(c, ' ' | '\t' | '\r') {
+            self.has_content = true;
+            self.is_comment = Some(c) == self.comment_char;
+        }
+        if Some(c) == self.quote_char {
+            self.in_quotes = !self.in_quotes;
+        }
+        false
+    }
+}
+
+/// Transcode the bytes of a file with the given `encoding` to utf8, so that the parser only
+/// has to deal with utf8. Bytes that are utf8 already are returned as is.
+///
+/// If `n_lines` is given, the transcoding stops after the first `n_lines` lines that are not
+/// empty or comments, so that e.g. the inference of the schema does not transcode the whole
+/// file.
+pub fn transcode_to_utf8(
+    bytes: ReaderBytes<'_>,
+    encoding: CsvEncoding,
+    n_lines: Option<usize>,
+    quote_char: Option<u8>,
+    comment_char: Option<u8>,
+    eol_char: u8,
+) -> Result<ReaderBytes<'_>> {
+    let mut lines = LineCounter {
+        remaining: n_lines,
+        in_quotes: false,
+        quote_char: quote_char.map(char::from),
+        comment_char: comment_char.map(char::from),
+        eol_char: char::from(eol_char),
+        has_content: false,
+        is_comment: false,
+    };
+    let utf16_unit = match encoding {
+        CsvEncoding::Utf8 | CsvEncoding::LossyUtf8 => return Ok(bytes),
+        CsvEncoding::Latin1 => {
+            if bytes.is_ascii() {
+                return Ok(bytes);
+            }
+            // every byte is the code point of a char
+            let mut s = String::with_capacity(bytes.len());
+            for c in bytes.iter().map(|b| char::from(*b)) {
+                s.push(c);
+                if lines.is_done(c) {
+                    break;
+                }
+            }
+            return Ok(ReaderBytes::Owned(s.into_bytes()));
+        }
+        CsvEncoding::Utf16Le => u16::from_le_bytes,
+        CsvEncoding::Utf16Be => u16::from_be_bytes,
+    };
+    if bytes.len() % 2 != 0 {
+        return Err(PolarsError::ComputeError(
+            "utf-16 encoded csv has an odd number of bytes".into(),
+        ));
+    }
+    let units = bytes.chunks_exact(2).map(|b| utf16_unit([b[0], b[1]]));
+    let mut s = String::with_capacity(match n_lines {
+        Some(_) => 4096,
+        None => bytes.len() / 2,
+    });
+    for c in char::decode_utf16(units) {
+        let c = c.map_err(|e| {
+            PolarsError::ComputeError(format!("invalid utf-16 in csv: {}", e).into())
+        })?;
+        s.push(c);
+        if lines.is_done(c) {
+            break;
+        }
+    }
+    Ok(ReaderBytes::Owned(s.into_bytes()))
+}
+
 /// Infer the schema of a CSV file by reading through the first n records of the file,
 /// with `max_read_records` controlling the maximum number of records to read.
 ///
@@ -431,19 +535,7 @@ pub fn infer_file_schema(
     Ok((Schema::from(fields), rows_count))
 }
 
-// magic numbers
-const GZIP: [u8; 2] = [31, 139];
-const ZLIB0: [u8; 2] = [0x78, 0x01];
-const ZLIB1: [u8; 2] = [0x78, 0x9C];
-const ZLIB2: [u8; 2] = [0x78, 0xDA];
-
-/// check if csv file is compressed
-pub fn is_compressed(bytes: &[u8]) -> bool {
-    bytes.starts_with(&ZLIB0)
-        || bytes.starts_with(&ZLIB1)
-        || bytes.starts_with(&ZLIB2)
-        || bytes.starts_with(&GZIP)
-}
+pub use crate::utils::is_compressed;
 
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 fn decompress_impl<R: Read>(
@@ -519,22 +611,25 @@ fn decompress_impl<R: Read>(
     })
 }
 
+/// Decompress a gzip, zlib, zstd or bzip2 compressed csv file. Returns `None` if the file is not
+/// compressed.
+///
+/// If `n_rows` is given, decompression stops after the first `n_rows` lines.
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
-pub(crate) fn decompress(
+pub fn decompress(
     bytes: &[u8],
     n_rows: Option<usize>,
     delimiter: u8,
     quote_char: Option<u8>,
     eol_char: u8,
-) -> Option<Vec<u8>> {
-    if bytes.starts_with(&GZIP) {
-        let mut decoder = flate2::read::MultiGzDecoder::new(bytes);
-        decompress_impl(&mut decoder, bytes, n_rows, delimiter, quote_char, eol_char)
-    } else if bytes.starts_with(&ZLIB0) || bytes.starts_with(&ZLIB1) || bytes.starts_with(&ZLIB2) {
-        let mut decoder = flate2::read::ZlibDecoder::new(bytes);
-        decompress_impl(&mut decoder, bytes, n_rows, delimiter, quote_char, eol_char)
-    } else {
-        None
+) -> Result<Option<Vec<u8>>> {
+    match crate::utils::decoder(bytes)? {
+        Some(mut decoder) => {
+            decompress_impl(&mut decoder, bytes, n_rows, delimiter, quote_char, eol_char)
+                .map(Some)
+                .ok_or_else(|| PolarsError::ComputeError("could not decompress csv file".into()))
+        }
+        None => Ok(None),
     }
 }
 
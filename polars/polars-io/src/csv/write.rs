@@ -1,5 +1,15 @@
 use super::*;
 
+/// The compression of the output of a [`CsvWriter`].
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CsvCompression {
+    Gzip,
+    Zlib,
+    Zstd,
+    Bzip2,
+}
+
 /// Write a DataFrame to csv.
 ///
 /// Don't use a `Buffered` writer, the `CsvWriter` internally already buffers writes.
@@ -10,6 +20,8 @@ pub struct CsvWriter<W: Write> {
     options: write_impl::SerializeOptions,
     header: bool,
     batch_size: usize,
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    compression: Option<CsvCompression>,
 }
 
 impl<W> SerWriter<W> for CsvWriter<W>
@@ -29,17 +41,92 @@ where
             options,
             header: true,
             batch_size: 1024,
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            compression: None,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> Result<()> {
         df.as_single_chunk_par();
-        let names = df.get_column_names();
-        if self.header {
-            write_impl::write_header(&mut self.buffer, &names, &self.options)?;
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        match self.compression {
+            Some(CsvCompression::Gzip) => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(&mut self.buffer, flate2::Compression::default());
+                write_csv(
+                    &mut encoder,
+                    df,
+                    self.header,
+                    self.batch_size,
+                    &self.options,
+                )?;
+                encoder.finish()?;
+                return Ok(());
+            }
+            Some(CsvCompression::Zlib) => {
+                let mut encoder = flate2::write::ZlibEncoder::new(
+                    &mut self.buffer,
+                    flate2::Compression::default(),
+                );
+                write_csv(
+                    &mut encoder,
+                    df,
+                    self.header,
+                    self.batch_size,
+                    &self.options,
+                )?;
+                encoder.finish()?;
+                return Ok(());
+            }
+            Some(CsvCompression::Zstd) => {
+                let mut encoder = zstd::stream::write::Encoder::new(&mut self.buffer, 0)?;
+                write_csv(
+                    &mut encoder,
+                    df,
+                    self.header,
+                    self.batch_size,
+                    &self.options,
+                )?;
+                encoder.finish()?;
+                return Ok(());
+            }
+            Some(CsvCompression::Bzip2) => {
+                let mut encoder =
+                    bzip2::write::BzEncoder::new(&mut self.buffer, bzip2::Compression::default());
+                write_csv(
+                    &mut encoder,
+                    df,
+                    self.header,
+                    self.batch_size,
+                    &self.options,
+                )?;
+                encoder.finish()?;
+                return Ok(());
+            }
+            None => {}
         }
-        write_impl::write(&mut self.buffer, df, self.batch_size, &self.options)
+        write_csv(
+            &mut self.buffer,
+            df,
+            self.header,
+            self.batch_size,
+            &self.options,
+        )
+    }
+}
+
+fn write_csv<W: Write>(
+    writer: &mut W,
+    df: &DataFrame,
+    header: bool,
+    batch_size: usize,
+    options: &write_impl::SerializeOptions,
+) -> Result<()> {
+    if header {
+        let names = df.get_column_names();
+        write_impl::write_header(writer, &names, options)?;
     }
+    write_impl::write(writer, df, batch_size, options)
 }
 
 impl<W> CsvWriter<W>
@@ -86,4 +173,11 @@ where
         self.options.quote = char;
         self
     }
+
+    /// Compress the output. The [`CsvReader`] decompresses these files transparently.
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    pub fn with_compression(mut self, compression: Option<CsvCompression>) -> Self {
+        self.compression = compression;
+        self
+    }
 }
@@ -0,0 +1,391 @@
+//! # Read fixed-width text files
+//!
+//! In a fixed-width file every field starts and ends at the same byte offset on every line.
+//! The columns are not inferred, they are given by their offsets (or widths) and dtypes.
+//!
+//! ## Example
+//!
+//! ```
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use std::fs::File;
+//!
+//! fn example() -> Result<DataFrame> {
+//!     FixedWidthReader::from_path("accounts.txt")?
+//!         .with_widths(&[
+//!             ("id", 6, DataType::Int64),
+//!             ("name", 20, DataType::Utf8),
+//!             ("balance", 12, DataType::Float64),
+//!         ])
+//!         .with_skip_rows(1)
+//!         .finish()
+//! }
+//! ```
+use crate::csv::parser::{next_line_position_naive, skip_bom};
+use crate::csv::read_impl::cast_columns;
+use crate::csv::utils::get_reader_bytes;
+use crate::mmap::MmapBytesReader;
+use crate::utils::resolve_homedir;
+use crate::SerReader;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_core::{prelude::*, POOL};
+use rayon::prelude::*;
+use std::fs::File;
+use std::ops::Range;
+use std::path::PathBuf;
+
+/// A column of a fixed-width file.
+#[derive(Clone, Debug)]
+pub struct FixedWidthColumn {
+    name: String,
+    /// Byte offsets of the field in a line.
+    range: Range<usize>,
+    dtype: DataType,
+}
+
+impl FixedWidthColumn {
+    /// Create a column that spans the bytes `range` of every line.
+    pub fn new(name: &str, range: Range<usize>, dtype: DataType) -> Self {
+        FixedWidthColumn {
+            name: name.to_string(),
+            range,
+            dtype,
+        }
+    }
+}
+
+/// Read a fixed-width text file into a DataFrame.
+///
+/// Lines that are shorter than a column have nulls in that column, as have fields
+/// that are empty after trimming. Empty lines are skipped.
+#[must_use]
+pub struct FixedWidthReader<R>
+where
+    R: MmapBytesReader,
+{
+    reader: R,
+    columns: Vec<FixedWidthColumn>,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    skip_rows: usize,
+    n_threads: Option<usize>,
+    trim: bool,
+    eol_char: u8,
+    ignore_parser_errors: bool,
+}
+
+impl FixedWidthReader<File> {
+    pub fn from_path<P: Into<PathBuf>>(path: P) -> Result<Self> {
+        let path = resolve_homedir(&path.into());
+        let f = std::fs::File::open(&path)?;
+        Ok(Self::new(f))
+    }
+}
+
+impl<R> FixedWidthReader<R>
+where
+    R: MmapBytesReader,
+{
+    /// Set the columns by their byte offsets in a line.
+    pub fn with_columns(mut self, columns: Vec<FixedWidthColumn>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the columns by their widths in bytes. The columns are consecutive and the first
+    /// one starts at the beginning of the line.
+    pub fn with_widths(mut self, widths: &[(&str, usize, DataType)]) -> Self {
+        let mut start = 0;
+        self.columns = widths
+            .iter()
+            .map(|(name, width, dtype)| {
+                let col = FixedWidthColumn::new(name, start..start + width, dtype.clone());
+                start += width;
+                col
+            })
+            .collect();
+        self
+    }
+
+    /// Stop reading after `n_rows` rows. Empty lines don't count as rows.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    /// Skip the first `n` lines, e.g. a header.
+    pub fn with_skip_rows(mut self, n: usize) -> Self {
+        self.skip_rows = n;
+        self
+    }
+
+    /// Set the number of threads used to parse the file.
+    pub fn with_n_threads(mut self, n: Option<usize>) -> Self {
+        self.n_threads = n;
+        self
+    }
+
+    /// Trim leading and trailing spaces of every field. Defaults to `true`.
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Set the line ending character. Defaults to `\n`, a trailing `\r` is always removed.
+    pub fn with_end_of_line_char(mut self, eol_char: u8) -> Self {
+        self.eol_char = eol_char;
+        self
+    }
+
+    /// Set a field to null if it can't be parsed as the dtype of its column, instead of
+    /// returning an error. Defaults to `false`.
+    pub fn with_ignore_parser_errors(mut self, ignore: bool) -> Self {
+        self.ignore_parser_errors = ignore;
+        self
+    }
+}
+
+impl<R> SerReader<R> for FixedWidthReader<R>
+where
+    R: MmapBytesReader,
+{
+    fn new(reader: R) -> Self {
+        FixedWidthReader {
+            reader,
+            columns: vec![],
+            rechunk: true,
+            n_rows: None,
+            skip_rows: 0,
+            n_threads: None,
+            trim: true,
+            eol_char: b'\n',
+            ignore_parser_errors: false,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> Result<DataFrame> {
+        if self.columns.is_empty() {
+            return Err(PolarsError::ComputeError(
+                "no columns given to read from the fixed-width file".into(),
+            ));
+        }
+        let fields = self
+            .columns
+            .iter()
+            .map(|col| Field::new(&col.name, col.dtype.clone()))
+            .collect::<Vec<_>>();
+
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let mut bytes = skip_bom(&reader_bytes);
+        for _ in 0..self.skip_rows {
+            bytes = match next_line_position_naive(bytes, self.eol_char) {
+                Some(pos) => &bytes[pos..],
+                None => &[],
+            };
+        }
+        if let Some(n_rows) = self.n_rows {
+            bytes = &bytes[..line_offset(bytes, n_rows, self.eol_char)];
+        }
+
+        let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
+        let chunks = get_line_chunks(bytes, n_threads, self.eol_char);
+        let dfs = POOL.install(|| {
+            chunks
+                .into_par_iter()
+                .map(|(start, end)| {
+                    let mut df =
+                        parse_lines(&bytes[start..end], &self.columns, self.eol_char, self.trim)?;
+                    let null_counts = df
+                        .get_columns()
+                        .iter()
+                        .map(|s| s.null_count())
+                        .collect::<Vec<_>>();
+                    cast_columns(&mut df, &fields, false)?;
+                    if !self.ignore_parser_errors {
+                        // a field that could not be parsed is cast to null
+                        let mut columns = df.get_columns().iter().zip(null_counts);
+                        if let Some((s, _)) = columns.find(|(s, n)| s.null_count() != *n) {
+                            return Err(PolarsError::ComputeError(
+                                format!(
+                                    "could not parse a field of column '{}' as {:?}",
+                                    s.name(),
+                                    s.dtype()
+                                )
+                                .into(),
+                            ));
+                        }
+                    }
+                    Ok(df)
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+        if self.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+/// Byte offset of the end of the first `n` lines that are not empty.
+fn line_offset(bytes: &[u8], n: usize, eol_char: u8) -> usize {
+    if n == 0 {
+        return 0;
+    }
+    let mut remaining = n;
+    let mut line_start = 0;
+    for pos in memchr::memchr_iter(eol_char, bytes) {
+        let line = &bytes[line_start..pos];
+        line_start = pos + 1;
+        if !matches!(line, [] | [b'\r']) {
+            remaining -= 1;
+            if remaining == 0 {
+                return pos + 1;
+            }
+        }
+    }
+    bytes.len()
+}
+
+/// Split `bytes` in at most `n_threads` chunks of whole lines.
+fn get_line_chunks(bytes: &[u8], n_threads: usize, eol_char: u8) -> Vec<(usize, usize)> {
+    let chunk_size = std::cmp::max(bytes.len() / std::cmp::max(n_threads, 1), 1);
+    let mut offsets = Vec::with_capacity(n_threads);
+    let mut last_pos = 0;
+    while last_pos + chunk_size < bytes.len() {
+        let search_pos = last_pos + chunk_size;
+        match next_line_position_naive(&bytes[search_pos..], eol_char) {
+            Some(pos) => {
+                offsets.push((last_pos, search_pos + pos));
+                last_pos = search_pos + pos;
+            }
+            None => break,
+        }
+    }
+    offsets.push((last_pos, bytes.len()));
+    offsets
+}
+
+fn trim_spaces(mut field: &[u8]) -> &[u8] {
+    while let [b' ', rest @ ..] = field {
+        field = rest
+    }
+    while let [rest @ .., b' '] = field {
+        field = rest
+    }
+    field
+}
+
+/// Parse the lines in `bytes` to `Utf8` columns.
+fn parse_lines(
+    bytes: &[u8],
+    columns: &[FixedWidthColumn],
+    eol_char: u8,
+    trim: bool,
+) -> Result<DataFrame> {
+    let n_lines = memchr::memchr_iter(eol_char, bytes).count() + 1;
+    let mut builders = columns
+        .iter()
+        .map(|col| Utf8ChunkedBuilder::new(&col.name, n_lines, n_lines * col.range.len()))
+        .collect::<Vec<_>>();
+
+    for line in bytes.split(|b| *b == eol_char) {
+        let line = match line {
+            [rest @ .., b'\r'] => rest,
+            _ => line,
+        };
+        if line.is_empty() {
+            continue;
+        }
+        for (col, builder) in columns.iter().zip(builders.iter_mut()) {
+            let start = std::cmp::min(col.range.start, line.len());
+            let end = std::cmp::min(col.range.end, line.len());
+            let field = &line[start..end];
+            let field = if trim { trim_spaces(field) } else { field };
+            if field.is_empty() {
+                builder.append_null();
+            } else {
+                let s = simdutf8::basic::from_utf8(field).map_err(|_| {
+                    PolarsError::ComputeError(
+                        format!("invalid utf-8 in column '{}'", col.name).into(),
+                    )
+                })?;
+                builder.append_value(s);
+            }
+        }
+    }
+    let columns = builders
+        .into_iter()
+        .map(|builder| builder.finish().into_series())
+        .collect();
+    Ok(DataFrame::new_no_checks(columns))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use polars_core::df;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_read_fixed_width() -> Result<()> {
+        let data = "ID    NAME      AMOUNT\r\n\
+                    000001alice       12.5\r\n\
+                    \r\n\
+                    000002bob         -3.0\r\n\
+                    000003              \r\n\
+                    000004carol\r\n";
+        let reader = || {
+            FixedWidthReader::new(Cursor::new(data))
+                .with_widths(&[
+                    ("id", 6, DataType::Int64),
+                    ("name", 10, DataType::Utf8),
+                    ("amount", 6, DataType::Float64),
+                ])
+                .with_skip_rows(1)
+        };
+
+        let expected = df![
+            "id" => [1i64, 2, 3, 4],
+            "name" => [Some("alice"), Some("bob"), None, Some("carol")],
+            "amount" => [Some(12.5), Some(-3.0), None, None]
+        ]?;
+        for n_threads in [1, 3] {
+            let df = reader().with_n_threads(Some(n_threads)).finish()?;
+            assert!(df.frame_equal_missing(&expected));
+        }
+
+        let df = reader().with_n_rows(Some(2)).finish()?;
+        assert!(df.frame_equal_missing(&expected.head(Some(2))));
+
+        let df = FixedWidthReader::new(Cursor::new(data))
+            .with_columns(vec![FixedWidthColumn::new("name", 6..16, DataType::Utf8)])
+            .with_skip_rows(1)
+            .with_trim(false)
+            .finish()?;
+        assert_eq!(
+            Vec::from(df.column("name")?.utf8()?),
+            &[
+                Some("alice     "),
+                Some("bob       "),
+                Some("          "),
+                Some("carol")
+            ]
+        );
+
+        // a field that can't be parsed is an error, unless parser errors are ignored
+        let data = "000001\n00000x\n";
+        let reader =
+            || FixedWidthReader::new(Cursor::new(data)).with_widths(&[("id", 6, DataType::Int64)]);
+        assert!(reader().finish().is_err());
+        let df = reader().with_ignore_parser_errors(true).finish()?;
+        assert_eq!(Vec::from(df.column("id")?.i64()?), &[Some(1), None]);
+        Ok(())
+    }
+}
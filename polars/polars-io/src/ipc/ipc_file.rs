@@ -34,9 +34,11 @@
 //! ```
 use super::{finish_reader, ArrowReader, ArrowResult};
 use crate::predicates::PhysicalIoExpr;
+use crate::progress::ReadProgressRef;
 use crate::{prelude::*, WriterFactory};
 use arrow::io::ipc::write::WriteOptions;
 use arrow::io::ipc::{read, write};
+use polars_core::cancel::CancellationToken;
 use polars_core::prelude::*;
 
 use std::io::{Read, Seek, Write};
@@ -71,6 +73,8 @@ pub struct IpcReader<R: MmapBytesReader> {
     columns: Option<Vec<String>>,
     pub(super) row_count: Option<RowCount>,
     memmap: bool,
+    pub(super) progress: Option<ReadProgressRef>,
+    pub(super) cancel_token: Option<CancellationToken>,
 }
 
 impl<R: MmapBytesReader> IpcReader<R> {
@@ -85,6 +89,28 @@ impl<R: MmapBytesReader> IpcReader<R> {
         let metadata = read::read_file_metadata(&mut self.reader)?;
         Ok(metadata.schema)
     }
+
+    /// Get the number of record batches of the Ipc File. Only the footer of the file is read.
+    pub fn num_batches(&mut self) -> Result<usize> {
+        let metadata = read::read_file_metadata(&mut self.reader)?;
+        Ok(metadata.blocks.len())
+    }
+
+    /// Get the number of rows of the Ipc File.
+    ///
+    /// The footer doesn't store the number of rows, so only the first column is read
+    /// to count them.
+    pub fn num_rows(&mut self) -> Result<usize> {
+        let metadata = read::read_file_metadata(&mut self.reader)?;
+        if metadata.schema.fields.is_empty() {
+            return Ok(0);
+        }
+        let reader = read::FileReader::new(&mut self.reader, metadata, Some(vec![0]), None);
+        let n_rows = reader
+            .map(|chunk| chunk.map(|chunk| chunk.len()))
+            .sum::<ArrowResult<usize>>()?;
+        Ok(n_rows)
+    }
     /// Stop reading when `n` rows are read.
     pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
         self.n_rows = num_rows;
@@ -117,6 +143,19 @@ impl<R: MmapBytesReader> IpcReader<R> {
         self
     }
 
+    /// Report the rows and bytes of every record batch that is read.
+    pub fn with_progress(mut self, progress: Option<ReadProgressRef>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Abort the read with an error once `cancel_token` is cancelled. The token is checked
+    /// before every record batch that is read.
+    pub fn with_cancellation(mut self, cancel_token: Option<CancellationToken>) -> Self {
+        self.cancel_token = cancel_token;
+        self
+    }
+
     // todo! hoist to lazy crate
     #[cfg(feature = "lazy")]
     pub fn finish_with_scan_ops(
@@ -125,23 +164,19 @@ impl<R: MmapBytesReader> IpcReader<R> {
         aggregate: Option<&[ScanAggregation]>,
         verbose: bool,
     ) -> Result<DataFrame> {
+        // wasm has no mmap, so the file is always read
+        #[cfg(not(target_family = "wasm"))]
         if self.memmap && self.reader.to_file().is_some() {
             if verbose {
                 eprintln!("memory map ipc file")
             }
             match self.finish_memmapped(predicate.clone(), aggregate) {
-                Ok(df) => return Ok(df),
-                Err(err) => match err {
-                    PolarsError::ArrowError(e) => match e.as_ref() {
-                        arrow::error::Error::NotYetImplemented(s)
-                            if s == "mmap can only be done on uncompressed IPC files" =>
-                        {
-                            eprint!("could not mmap compressed IPC file, defaulting to normal read")
-                        }
-                        _ => return Err(PolarsError::ArrowError(e)),
-                    },
-                    err => return Err(err),
-                },
+                Err(err) if is_compressed_mmap_error(&err) => {
+                    if verbose {
+                        eprintln!("could not mmap compressed IPC file, defaulting to normal read")
+                    }
+                }
+                out => return out,
             }
         }
         let rechunk = self.rechunk;
@@ -163,10 +198,26 @@ impl<R: MmapBytesReader> IpcReader<R> {
             aggregate,
             &schema,
             self.row_count,
+            self.progress.as_deref(),
+            self.cancel_token.as_ref(),
         )
     }
 }
 
+/// Only uncompressed files can be memory mapped, the buffers of compressed
+/// files must be decompressed by a normal read.
+#[cfg(not(target_family = "wasm"))]
+fn is_compressed_mmap_error(err: &PolarsError) -> bool {
+    match err.root() {
+        PolarsError::ArrowError(e) => matches!(
+            e.as_ref(),
+            arrow::error::Error::NotYetImplemented(s)
+                if s == "mmap can only be done on uncompressed IPC files"
+        ),
+        _ => false,
+    }
+}
+
 impl<R: MmapBytesReader> ArrowReader for read::FileReader<R>
 where
     R: Read + Seek,
@@ -186,6 +237,8 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             projection: None,
             row_count: None,
             memmap: true,
+            progress: None,
+            cancel_token: None,
         }
     }
 
@@ -195,20 +248,16 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
     }
 
     fn finish(mut self) -> Result<DataFrame> {
+        // wasm has no mmap, so the file is always read
+        #[cfg(not(target_family = "wasm"))]
         if self.memmap && self.reader.to_file().is_some() {
             match self.finish_memmapped(None, None) {
-                Ok(df) => return Ok(df),
-                Err(err) => match err {
-                    PolarsError::ArrowError(e) => match e.as_ref() {
-                        arrow::error::Error::NotYetImplemented(s)
-                            if s == "mmap can only be done on uncompressed IPC files" =>
-                        {
-                            eprint!("could not mmap compressed IPC file, defaulting to normal read")
-                        }
-                        _ => return Err(PolarsError::ArrowError(e)),
-                    },
-                    err => return Err(err),
-                },
+                Err(err) if is_compressed_mmap_error(&err) => {
+                    if std::env::var("POLARS_VERBOSE").is_ok() {
+                        eprintln!("could not mmap compressed IPC file, defaulting to normal read")
+                    }
+                }
+                out => return out,
             }
         }
         let rechunk = self.rechunk;
@@ -236,6 +285,8 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             None,
             &schema,
             self.row_count,
+            self.progress.as_deref(),
+            self.cancel_token.as_ref(),
         )
     }
 }
@@ -292,7 +343,7 @@ where
     fn finish(&mut self, df: &mut DataFrame) -> Result<()> {
         let mut ipc_writer = write::FileWriter::try_new(
             &mut self.writer,
-            &df.schema().to_arrow(),
+            &df.arrow_schema(),
             None,
             WriteOptions {
                 compression: self.compression,
@@ -395,6 +446,48 @@ mod test {
         df_read.frame_equal(&expected);
     }
 
+    #[test]
+    fn test_ipc_field_metadata() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let metadata: FieldMetadata = [("description".to_string(), "b values".to_string())].into();
+        let mut b = Series::new("b", [2, 3, 4]);
+        b.set_metadata(metadata.clone());
+        let mut df = DataFrame::new(vec![Series::new("a", [1, 2, 3]), b])?;
+
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf)
+            .with_projection(Some(vec![1]))
+            .finish()?;
+        assert_eq!(df_read.column("b")?.metadata(), metadata);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ipc_frame_metadata() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3], "b" => [2, 3, 4])?;
+        let metadata: FrameMetadata = [("source".to_string(), "sensors.csv".to_string())].into();
+        df.set_metadata(metadata.clone());
+
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        let df_read = IpcReader::new(buf.clone())
+            .with_projection(Some(vec![1]))
+            .finish()?;
+        assert_eq!(df_read.metadata(), &metadata);
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        IpcStreamWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+        let df_read = IpcStreamReader::new(buf)
+            .with_columns(Some(vec!["b".to_string(), "a".to_string()]))
+            .finish()?;
+        assert_eq!(df_read.metadata(), &metadata);
+        Ok(())
+    }
+
     #[test]
     fn test_read_ipc_with_columns() {
         let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -468,6 +561,43 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_read_compressed_file() -> Result<()> {
+        use tempdir::TempDir;
+
+        let mut df = create_df();
+        let dir = TempDir::new("ipc-compression")?;
+        for compression in [IpcCompression::LZ4, IpcCompression::ZSTD] {
+            let path = dir.path().join(format!("{:?}.ipc", compression));
+            IpcWriter::new(std::fs::File::create(&path)?)
+                .with_compression(Some(compression))
+                .finish(&mut df)?;
+
+            // compressed files cannot be memory mapped, so this falls back to a normal read
+            let df_read = IpcReader::new(std::fs::File::open(&path)?)
+                .memory_mapped(true)
+                .finish()?;
+            assert!(df.frame_equal(&df_read));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ipc_metadata() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let df = create_df();
+        let mut df = df.clone().vstack(&df)?;
+
+        IpcWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let mut reader = IpcReader::new(buf);
+        assert_eq!(reader.schema()?, df.schema());
+        assert_eq!(reader.num_rows()?, 10);
+        assert_eq!(reader.num_batches()?, 1);
+        Ok(())
+    }
+
     #[test]
     fn write_and_read_ipc_empty_series() {
         let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -186,6 +186,8 @@ where
             None,
             &schema,
             self.row_count,
+            None,
+            None,
         )
         .map(|df| fix_column_order(df, self.projection, include_row_count))
     }
@@ -209,7 +211,9 @@ fn fix_column_order(df: DataFrame, projection: Option<Vec<usize>>, row_count: bo
             iter.collect()
         };
 
-        DataFrame::new_no_checks(cols)
+        let mut out = DataFrame::new_no_checks(cols);
+        out.set_metadata(df.metadata().clone());
+        out
     } else {
         df
     }
@@ -270,7 +274,7 @@ where
             },
         );
 
-        ipc_stream_writer.start(&df.schema().to_arrow(), None)?;
+        ipc_stream_writer.start(&df.arrow_schema(), None)?;
 
         df.rechunk();
         let iter = df.iter_chunks();
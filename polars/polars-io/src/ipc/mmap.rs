@@ -90,6 +90,8 @@ impl<R: MmapBytesReader> IpcReader<R> {
                     aggregate,
                     &schema,
                     self.row_count.clone(),
+                    self.progress.as_deref(),
+                    self.cancel_token.as_ref(),
                 )
             }
             None => Err(PolarsError::ComputeError(
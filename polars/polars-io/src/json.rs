@@ -72,8 +72,13 @@ use std::io::Write;
 use std::ops::Deref;
 
 pub enum JsonFormat {
+    /// A JSON array of records: `[{"a": 1, "b": "x"}, ...]`.
     Json,
+    /// A record per line (NDJSON).
     JsonLines,
+    /// A single object with an array of values per column: `{"a": [1, ...], "b": ["x", ...]}`.
+    /// Can only be written.
+    JsonColumns,
 }
 
 // Write a DataFrame to JSON
@@ -82,6 +87,9 @@ pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    date_format: Option<String>,
+    datetime_format: Option<String>,
+    skip_nulls: bool,
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -89,6 +97,128 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Write `Date` columns as strings in this format, e.g. `"%Y-%m-%d"`.
+    pub fn with_date_format(mut self, format: Option<String>) -> Self {
+        self.date_format = format;
+        self
+    }
+
+    /// Write `Datetime` columns as strings in this format, e.g. `"%Y-%m-%dT%H:%M:%S"`.
+    pub fn with_datetime_format(mut self, format: Option<String>) -> Self {
+        self.datetime_format = format;
+        self
+    }
+
+    /// Leave out the null values of a record instead of writing `null`.
+    /// Has no effect on [`JsonFormat::JsonColumns`], where the values are positional.
+    pub fn with_skip_nulls(mut self, skip_nulls: bool) -> Self {
+        self.skip_nulls = skip_nulls;
+        self
+    }
+
+    /// Format the temporal columns with the configured formats.
+    fn format_temporal(&self, df: &DataFrame) -> Result<DataFrame> {
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(
+                |s| match (s.dtype(), &self.date_format, &self.datetime_format) {
+                    #[cfg(feature = "dtype-date")]
+                    (DataType::Date, Some(fmt), _) => Ok(s.date()?.strftime(fmt).into_series()),
+                    #[cfg(feature = "dtype-datetime")]
+                    (DataType::Datetime(_, _), _, Some(fmt)) => {
+                        Ok(s.datetime()?.strftime(fmt).into_series())
+                    }
+                    _ => Ok(s.clone()),
+                },
+            )
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataFrame::new_no_checks(columns))
+    }
+
+    fn write_records(&mut self, df: &mut DataFrame, json_format: &JsonFormat) -> Result<()> {
+        df.rechunk();
+        let fields = df.iter().map(|s| s.field().to_arrow()).collect::<Vec<_>>();
+        let batches = df
+            .iter_chunks()
+            .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
+
+        match json_format {
+            JsonFormat::Json => {
+                let serializer = json::write::Serializer::new(batches, vec![]);
+                json::write::write(&mut self.buffer, serializer)?;
+            }
+            _ => {
+                let serializer = ndjson::write::Serializer::new(batches, vec![]);
+                let writer = ndjson::write::FileWriter::new(&mut self.buffer, serializer);
+                writer.collect::<ArrowResult<()>>()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the records without their null values. Every column is serialized on its own
+    /// to a `"name":value` pair per row, and the records are assembled from the pairs of the
+    /// values that are not null.
+    fn write_records_skip_nulls(&mut self, df: &mut DataFrame) -> Result<()> {
+        df.rechunk();
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(serialize_pairs)
+            .collect::<Result<Vec<_>>>()?;
+
+        let is_json = matches!(self.json_format, JsonFormat::Json);
+        if is_json {
+            self.buffer.write_all(b"[")?;
+        }
+        for row in 0..df.height() {
+            if is_json && row > 0 {
+                self.buffer.write_all(b",")?;
+            }
+            self.buffer.write_all(b"{")?;
+            let mut first = true;
+            for pair in columns.iter().filter_map(|pairs| pairs[row].as_ref()) {
+                if !first {
+                    self.buffer.write_all(b",")?;
+                }
+                self.buffer.write_all(pair)?;
+                first = false;
+            }
+            self.buffer.write_all(b"}")?;
+            if !is_json {
+                self.buffer.write_all(b"\n")?;
+            }
+        }
+        if is_json {
+            self.buffer.write_all(b"]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Serialize every value of `s` to a `"name":value` pair, or `None` if it is null.
+fn serialize_pairs(s: &Series) -> Result<Vec<Option<Vec<u8>>>> {
+    let mut ndjson = Vec::new();
+    JsonWriter::new(&mut ndjson)
+        .with_json_format(JsonFormat::JsonLines)
+        .finish(&mut DataFrame::new_no_checks(vec![s.clone()]))?;
+    // every line is a record `{"name":value}` of a single value, a string value can't contain
+    // a newline as it is escaped
+    let is_null = s.is_null();
+    Ok(ndjson
+        .split(|b| *b == b'\n')
+        .filter(|line| !line.is_empty())
+        .zip(is_null.into_iter())
+        .map(|(line, is_null)| {
+            if is_null.unwrap_or(false) {
+                None
+            } else {
+                Some(line[1..line.len() - 1].to_vec())
+            }
+        })
+        .collect())
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -99,29 +229,30 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            date_format: None,
+            datetime_format: None,
+            skip_nulls: false,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> Result<()> {
-        df.rechunk();
-        let fields = df.iter().map(|s| s.field().to_arrow()).collect::<Vec<_>>();
-        let batches = df
-            .iter_chunks()
-            .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
+        let mut df = self.format_temporal(df)?;
 
         match self.json_format {
-            JsonFormat::JsonLines => {
-                let serializer = ndjson::write::Serializer::new(batches, vec![]);
-                let writer = ndjson::write::FileWriter::new(&mut self.buffer, serializer);
-                writer.collect::<ArrowResult<()>>()?;
-            }
-            JsonFormat::Json => {
-                let serializer = json::write::Serializer::new(batches, vec![]);
-                json::write::write(&mut self.buffer, serializer)?;
+            JsonFormat::JsonColumns => {
+                // a single record with a list of all values per column
+                let columns = df
+                    .get_columns()
+                    .iter()
+                    .map(|s| s.to_list().map(|ca| ca.into_series()))
+                    .collect::<Result<Vec<_>>>()?;
+                let mut df = DataFrame::new_no_checks(columns);
+                self.write_records(&mut df, &JsonFormat::JsonLines)
             }
+            _ if self.skip_nulls => self.write_records_skip_nulls(&mut df),
+            JsonFormat::Json => self.write_records(&mut df, &JsonFormat::Json),
+            JsonFormat::JsonLines => self.write_records(&mut df, &JsonFormat::JsonLines),
         }
-
-        Ok(())
     }
 }
 
@@ -162,6 +293,11 @@ where
 
     fn finish(self) -> Result<DataFrame> {
         let rb: ReaderBytes = (&self.reader).into();
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        let rb = match crate::utils::decompress_all(&rb)? {
+            Some(b) => ReaderBytes::Owned(b),
+            None => rb,
+        };
 
         let out = match self.json_format {
             JsonFormat::Json => {
@@ -193,6 +329,9 @@ where
                 }
                 Ok(df)
             }
+            JsonFormat::JsonColumns => Err(PolarsError::ComputeError(
+                "reading column oriented json is not supported".into(),
+            )),
         }?;
 
         if let Some(proj) = &self.projection {
@@ -241,6 +380,8 @@ where
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
+    use polars_core::df;
+    use polars_core::prelude::*;
     use std::io::Cursor;
 
     #[test]
@@ -269,4 +410,85 @@ mod test {
         assert_eq!("d", df.get_columns()[3].name());
         assert_eq!((12, 4), df.shape());
     }
+
+    #[test]
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    fn read_compressed_json() {
+        use std::io::Write;
+        let json = br#"{"a":1, "b":"x"}
+{"a":2, "b":"y"}"#;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(json).unwrap();
+        let gzipped = encoder.finish().unwrap();
+        let zstd = zstd::stream::encode_all(&json[..], 0).unwrap();
+
+        for bytes in [gzipped, zstd] {
+            let df = JsonReader::new(Cursor::new(bytes))
+                .with_json_format(JsonFormat::JsonLines)
+                .finish()
+                .unwrap();
+            assert_eq!((2, 2), df.shape());
+        }
+    }
+
+    #[test]
+    fn write_json() -> Result<()> {
+        let mut df = df![
+            "b" => [Some("x"), None],
+            "a" => [1, 2]
+        ]?;
+        let write = |df: &mut DataFrame, format: JsonFormat, skip_nulls: bool| -> Result<String> {
+            let mut buf = Vec::new();
+            JsonWriter::new(&mut buf)
+                .with_json_format(format)
+                .with_skip_nulls(skip_nulls)
+                .finish(df)?;
+            Ok(String::from_utf8(buf).unwrap())
+        };
+
+        assert_eq!(
+            write(&mut df, JsonFormat::JsonColumns, true)?,
+            "{\"b\":[\"x\",null],\"a\":[1,2]}\n"
+        );
+        assert_eq!(
+            write(&mut df, JsonFormat::Json, false)?,
+            r#"[{"b":"x","a":1},{"b":null,"a":2}]"#
+        );
+        assert_eq!(
+            write(&mut df, JsonFormat::Json, true)?,
+            r#"[{"b":"x","a":1},{"a":2}]"#
+        );
+        assert_eq!(
+            write(&mut df, JsonFormat::JsonLines, true)?,
+            "{\"b\":\"x\",\"a\":1}\n{\"a\":2}\n"
+        );
+
+        // a record of only nulls is empty, escaped values are kept
+        let mut df = df![
+            "b" => [Some("{\n}"), None],
+            "a" => [Some(1), None]
+        ]?;
+        assert_eq!(
+            write(&mut df, JsonFormat::Json, true)?,
+            r#"[{"b":"{\n}","a":1},{}]"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-date")]
+    fn write_json_date_format() -> Result<()> {
+        let mut df = df!["date" => [18628i32]]?;
+        df.try_apply("date", |s| s.cast(&DataType::Date))?;
+        let mut buf = Vec::new();
+        JsonWriter::new(&mut buf)
+            .with_json_format(JsonFormat::Json)
+            .with_date_format(Some("%d/%m/%Y".to_string()))
+            .finish(&mut df)?;
+        assert_eq!(
+            std::str::from_utf8(&buf).unwrap(),
+            r#"[{"date":"01/01/2021"}]"#
+        );
+        Ok(())
+    }
 }
@@ -14,6 +14,9 @@ pub mod csv;
 #[cfg(feature = "parquet")]
 #[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
 pub mod export;
+#[cfg(feature = "fwf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fwf")))]
+pub mod fwf;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 #[cfg_attr(docsrs, doc(cfg(any(feature = "ipc", feature = "ipc_streaming"))))]
 pub mod ipc;
@@ -35,6 +38,8 @@ pub mod predicates;
 #[cfg(not(feature = "private"))]
 pub(crate) mod predicates;
 pub mod prelude;
+pub mod progress;
+pub mod retry;
 #[cfg(all(test, feature = "csv-file"))]
 mod tests;
 pub(crate) mod utils;
@@ -58,9 +63,23 @@ use crate::aggregations::{apply_aggregations, ScanAggregation};
     feature = "ipc_streaming"
 ))]
 use crate::predicates::PhysicalIoExpr;
+#[cfg(any(
+    feature = "ipc",
+    feature = "json",
+    feature = "avro",
+    feature = "ipc_streaming"
+))]
+use crate::progress::ReadProgress;
 #[allow(unused)] // remove when updating to rust nightly >= 1.61
 use arrow::array::new_empty_array;
 use arrow::error::Result as ArrowResult;
+#[cfg(any(
+    feature = "ipc",
+    feature = "json",
+    feature = "avro",
+    feature = "ipc_streaming"
+))]
+use polars_core::cancel::CancellationToken;
 use polars_core::frame::ArrowChunk;
 use polars_core::prelude::*;
 use std::io::{Read, Seek, Write};
@@ -110,6 +129,10 @@ pub trait ArrowReader {
     feature = "avro",
     feature = "ipc_streaming"
 ))]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "read_arrow", skip_all, fields(rows = tracing::field::Empty))
+)]
 pub(crate) fn finish_reader<R: ArrowReader>(
     mut reader: R,
     rechunk: bool,
@@ -118,6 +141,8 @@ pub(crate) fn finish_reader<R: ArrowReader>(
     aggregate: Option<&[ScanAggregation]>,
     arrow_schema: &ArrowSchema,
     row_count: Option<RowCount>,
+    progress: Option<&dyn ReadProgress>,
+    cancel_token: Option<&CancellationToken>,
 ) -> Result<DataFrame> {
     use polars_core::utils::accumulate_dataframes_vertical;
 
@@ -125,9 +150,15 @@ pub(crate) fn finish_reader<R: ArrowReader>(
     let mut parsed_dfs = Vec::with_capacity(1024);
 
     while let Some(batch) = reader.next_record_batch()? {
+        if let Some(token) = cancel_token {
+            token.check()?;
+        }
         let current_num_rows = num_rows as IdxSize;
         num_rows += batch.len();
         let mut df = DataFrame::try_from((batch, arrow_schema.fields.as_slice()))?;
+        if let Some(progress) = progress {
+            progress.rows_read(df.height(), df.estimated_size() as u64);
+        }
 
         if let Some(rc) = &row_count {
             df.with_row_count_mut(&rc.name, Some(current_num_rows + rc.offset));
@@ -163,9 +194,7 @@ pub(crate) fn finish_reader<R: ArrowReader>(
             let empty_cols = arrow_schema
                 .fields
                 .iter()
-                .map(|fld| {
-                    Series::try_from((fld.name.as_str(), new_empty_array(fld.data_type.clone())))
-                })
+                .map(|fld| Series::try_from((fld, new_empty_array(fld.data_type.clone()))))
                 .collect::<Result<_>>()?;
             DataFrame::new(empty_cols)?
         } else {
@@ -177,8 +206,12 @@ pub(crate) fn finish_reader<R: ArrowReader>(
         }
     };
 
-    match rechunk {
-        true => Ok(df.agg_chunks()),
-        false => Ok(df),
-    }
+    let mut df = match rechunk {
+        true => df.agg_chunks(),
+        false => df,
+    };
+    df.set_metadata(arrow_schema.metadata.clone());
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("rows", df.height());
+    Ok(df)
 }
@@ -58,6 +58,7 @@ impl<T: MmapBytesReader> MmapBytesReader for &mut T {
 pub enum ReaderBytes<'a> {
     Borrowed(&'a [u8]),
     Owned(Vec<u8>),
+    #[cfg(not(target_family = "wasm"))]
     Mapped(memmap::Mmap),
 }
 
@@ -67,6 +68,7 @@ impl std::ops::Deref for ReaderBytes<'_> {
         match self {
             Self::Borrowed(ref_bytes) => ref_bytes,
             Self::Owned(vec) => vec,
+            #[cfg(not(target_family = "wasm"))]
             Self::Mapped(mmap) => mmap,
         }
     }
@@ -78,8 +80,20 @@ impl<'a, T: 'a + MmapBytesReader> From<&'a T> for ReaderBytes<'a> {
             Some(s) => ReaderBytes::Borrowed(s),
             None => {
                 let f = m.to_file().unwrap();
-                let mmap = unsafe { memmap::Mmap::map(f).unwrap() };
-                ReaderBytes::Mapped(mmap)
+                #[cfg(not(target_family = "wasm"))]
+                {
+                    let mmap = unsafe { memmap::Mmap::map(f).unwrap() };
+                    ReaderBytes::Mapped(mmap)
+                }
+                // there is no mmap on wasm, read the whole file like a mmap would see it
+                #[cfg(target_family = "wasm")]
+                {
+                    let mut f = f;
+                    let mut bytes = Vec::new();
+                    f.seek(std::io::SeekFrom::Start(0)).unwrap();
+                    f.read_to_end(&mut bytes).unwrap();
+                    ReaderBytes::Owned(bytes)
+                }
             }
         }
     }
@@ -103,4 +103,67 @@ mod test {
         assert_eq!(df_read.shape(), (3, 2));
         df_read.frame_equal(&expected);
     }
+
+    #[test]
+    fn test_read_parquet_metadata() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("a" => [1, 2, 3, 4, 5], "b" => ["x", "y", "z", "u", "v"])?;
+
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let mut reader = ParquetReader::new(buf);
+        assert_eq!(reader.schema()?, df.schema());
+        assert_eq!(reader.num_rows()?, 5);
+        assert_eq!(reader.row_group_num_rows()?, [2, 2, 1]);
+
+        let stats = reader.row_group_statistics()?;
+        assert_eq!(stats.len(), 3);
+        let a = stats[1].as_ref().unwrap().get_stats("a")?;
+        assert_eq!(a.null_count(), Some(0));
+        let min_max = a.to_min_max().unwrap();
+        assert_eq!(Vec::from(min_max.i32()?), &[Some(3), Some(4)]);
+
+        // the metadata is reused by the read
+        assert!(reader.finish()?.frame_equal(&df));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_read_parquet_dictionary_as_categorical() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "dict" => ["x", "y", "x", "x", "z"],
+            "plain" => [Some("a"), None, Some("b"), Some("a"), Some("a")],
+        )?;
+        df.try_apply("dict", |s| s.cast(&DataType::Categorical(None)))?;
+
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(2))
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let mut reader = ParquetReader::new(buf).with_dictionary_as_categorical(true);
+        let schema = reader.schema()?;
+        assert_eq!(schema.get("plain"), Some(&DataType::Categorical(None)));
+
+        let read = reader.finish()?;
+        for name in ["dict", "plain"] {
+            let s = read.column(name)?;
+            assert_eq!(s.dtype(), &DataType::Categorical(None));
+            let expected = df.column(name)?.cast(&DataType::Utf8)?;
+            assert!(s.cast(&DataType::Utf8)?.series_equal_missing(&expected));
+        }
+        // the dictionaries of the row groups are merged into one rev map
+        let dict = read.column("dict")?.categorical()?;
+        assert_eq!(
+            Vec::from(&dict.get_categories()),
+            &[Some("x"), Some("y"), Some("z")]
+        );
+        Ok(())
+    }
 }
@@ -100,7 +100,7 @@ pub(super) fn read_this_row_group(
                 // a parquet file may not have statistics of all columns
                 if matches!(should_read, Ok(false)) {
                     return Ok(false);
-                } else if !matches!(should_read, Err(PolarsError::NotFound(_))) {
+                } else if !matches!(should_read, Err(ref e) if e.code() == ErrorCode::NotFound) {
                     let _ = should_read?;
                 }
             }
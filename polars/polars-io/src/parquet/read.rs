@@ -1,10 +1,14 @@
 use crate::aggregations::ScanAggregation;
 use crate::mmap::MmapBytesReader;
+use crate::parquet::predicates::{collect_statistics, BatchStats};
 use crate::parquet::read_impl::read_parquet;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
+use crate::progress::ReadProgressRef;
 use crate::RowCount;
 use arrow::io::parquet::read;
+use arrow::io::parquet::read::FileMetaData;
+use polars_core::cancel::CancellationToken;
 use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -20,8 +24,13 @@ pub enum ParallelStrategy {
     Columns,
     /// Parallelize over the columns
     RowGroups,
+    /// Parallelize over the row groups and the columns within them. The row groups are decoded
+    /// in windows of as many row groups as there are threads, which bounds the memory held by
+    /// decoded row groups.
+    RowGroupsAndColumns,
     /// Automatically determine over which unit to parallelize
-    /// This will choose the most occurring unit.
+    /// This will choose the most occurring unit, or both the row groups and the columns if
+    /// neither of them has enough units to occupy all threads.
     Auto,
 }
 
@@ -42,6 +51,10 @@ pub struct ParquetReader<R: Read + Seek> {
     parallel: ParallelStrategy,
     row_count: Option<RowCount>,
     low_memory: bool,
+    dictionary_as_categorical: bool,
+    metadata: Option<FileMetaData>,
+    progress: Option<ReadProgressRef>,
+    cancel_token: Option<CancellationToken>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -54,7 +67,7 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         projection: Option<&[usize]>,
     ) -> Result<DataFrame> {
         // this path takes predicates and parallelism into account
-        let metadata = read::read_metadata(&mut self.reader)?;
+        let metadata = self.take_metadata()?;
         let schema = read::schema::infer_schema(&metadata)?;
 
         let rechunk = self.rechunk;
@@ -69,6 +82,9 @@ impl<R: MmapBytesReader> ParquetReader<R> {
             self.parallel,
             self.row_count,
             self.low_memory,
+            self.dictionary_as_categorical,
+            self.progress.as_deref(),
+            self.cancel_token.as_ref(),
         )
         .map(|mut df| {
             if rechunk {
@@ -85,6 +101,16 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Read the string columns as `Categorical`. Dictionary encoded columns are decoded
+    /// directly into the categories, without materializing a string per row. This cuts the
+    /// memory of string columns with few distinct values.
+    ///
+    /// This has no effect unless the `dtype-categorical` feature is activated.
+    pub fn with_dictionary_as_categorical(mut self, toggle: bool) -> Self {
+        self.dictionary_as_categorical = toggle;
+        self
+    }
+
     /// Read the parquet file in parallel (default). The single threaded reader consumes less memory.
     pub fn read_parallel(mut self, parallel: ParallelStrategy) -> Self {
         self.parallel = parallel;
@@ -117,12 +143,80 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
-    pub fn schema(mut self) -> Result<Schema> {
-        let metadata = read::read_metadata(&mut self.reader)?;
+    /// Report the rows and the compressed bytes of every row group that is read.
+    pub fn with_progress(mut self, progress: Option<ReadProgressRef>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Abort the read with an error once `cancel_token` is cancelled. The token is checked
+    /// before every row group that is decoded.
+    pub fn with_cancellation(mut self, cancel_token: Option<CancellationToken>) -> Self {
+        self.cancel_token = cancel_token;
+        self
+    }
+
+    /// Read the footer of the file, or take it if it was already read by a metadata accessor.
+    fn take_metadata(&mut self) -> Result<FileMetaData> {
+        match self.metadata.take() {
+            Some(metadata) => Ok(metadata),
+            None => Ok(read::read_metadata(&mut self.reader)?),
+        }
+    }
 
-        let schema = read::infer_schema(&metadata)?;
+    fn metadata(&mut self) -> Result<&FileMetaData> {
+        if self.metadata.is_none() {
+            self.metadata = Some(read::read_metadata(&mut self.reader)?);
+        }
+        Ok(self.metadata.as_ref().unwrap())
+    }
+
+    /// Get the schema of the `DataFrame` that is read from the file. Only the footer of the
+    /// file is read.
+    pub fn schema(&mut self) -> Result<Schema> {
+        let schema = read::infer_schema(self.metadata()?)?;
+        #[cfg(feature = "dtype-categorical")]
+        if self.dictionary_as_categorical {
+            return Ok(schema
+                .fields
+                .iter()
+                .map(|fld| match Field::from(fld) {
+                    fld if fld.data_type() == &DataType::Utf8 => {
+                        Field::new(fld.name(), DataType::Categorical(None))
+                    }
+                    fld => fld,
+                })
+                .collect());
+        }
         Ok((&schema.fields).into())
     }
+
+    /// Get the number of rows of the file. Only the footer of the file is read.
+    pub fn num_rows(&mut self) -> Result<usize> {
+        Ok(self.metadata()?.num_rows)
+    }
+
+    /// Get the number of rows of every row group. Only the footer of the file is read.
+    pub fn row_group_num_rows(&mut self) -> Result<Vec<usize>> {
+        Ok(self
+            .metadata()?
+            .row_groups
+            .iter()
+            .map(|rg| rg.num_rows())
+            .collect())
+    }
+
+    /// Get the min/max and null count statistics of the columns of every row group.
+    /// A row group without statistics yields `None`. Only the footer of the file is read.
+    pub fn row_group_statistics(&mut self) -> Result<Vec<Option<BatchStats>>> {
+        let metadata = self.metadata()?;
+        let schema = read::infer_schema(metadata)?;
+        metadata
+            .row_groups
+            .iter()
+            .map(|rg| Ok(collect_statistics(std::slice::from_ref(rg), &schema)?))
+            .collect()
+    }
 }
 
 impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
@@ -137,6 +231,10 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             parallel: Default::default(),
             row_count: None,
             low_memory: false,
+            dictionary_as_categorical: false,
+            metadata: None,
+            progress: None,
+            cancel_token: None,
         }
     }
 
@@ -146,7 +244,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
     }
 
     fn finish(mut self) -> Result<DataFrame> {
-        let metadata = read::read_metadata(&mut self.reader)?;
+        let metadata = self.take_metadata()?;
         let schema = read::schema::infer_schema(&metadata)?;
 
         if let Some(cols) = self.columns {
@@ -164,6 +262,9 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.parallel,
             self.row_count,
             self.low_memory,
+            self.dictionary_as_categorical,
+            self.progress.as_deref(),
+            self.cancel_token.as_ref(),
         )
         .map(|mut df| {
             if self.rechunk {
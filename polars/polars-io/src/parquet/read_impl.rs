@@ -4,11 +4,15 @@ use crate::parquet::mmap::mmap_columns;
 use crate::parquet::predicates::read_this_row_group;
 use crate::parquet::{mmap, ParallelStrategy};
 use crate::predicates::{apply_predicate, arrow_schema_to_empty_df, PhysicalIoExpr};
+use crate::progress::ReadProgress;
 use crate::utils::apply_projection;
 use crate::RowCount;
 use arrow::array::new_empty_array;
+#[cfg(feature = "dtype-categorical")]
+use arrow::datatypes::IntegerType;
 use arrow::io::parquet::read;
-use arrow::io::parquet::read::{ArrayIter, FileMetaData, RowGroupMetaData};
+use arrow::io::parquet::read::{get_field_columns, ArrayIter, FileMetaData, RowGroupMetaData};
+use polars_core::cancel::CancellationToken;
 use polars_core::prelude::*;
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::POOL;
@@ -25,8 +29,30 @@ fn column_idx_to_series(
     schema: &ArrowSchema,
     bytes: &[u8],
     chunk_size: usize,
+    dictionary_as_categorical: bool,
 ) -> Result<Series> {
     let field = &schema.fields[column_i];
+    #[cfg(feature = "dtype-categorical")]
+    if dictionary_as_categorical
+        && matches!(
+            field.data_type,
+            ArrowDataType::Utf8 | ArrowDataType::LargeUtf8
+        )
+    {
+        return utf8_column_to_categorical(field, md, remaining_rows, bytes, chunk_size);
+    }
+    #[cfg(not(feature = "dtype-categorical"))]
+    let _ = dictionary_as_categorical;
+    read_column(field, md, remaining_rows, bytes, chunk_size)
+}
+
+fn read_column(
+    field: &ArrowField,
+    md: &RowGroupMetaData,
+    remaining_rows: usize,
+    bytes: &[u8],
+    chunk_size: usize,
+) -> Result<Series> {
     let columns = mmap_columns(bytes, md.columns(), &field.name);
     let iter = mmap::to_deserializer(columns, field.clone(), remaining_rows, Some(chunk_size))?;
 
@@ -37,6 +63,66 @@ fn column_idx_to_series(
     }
 }
 
+/// Read a string column as a `Categorical`. A dictionary encoded column is decoded into the
+/// keys and the dictionary, so the strings are never materialized per row.
+#[cfg(feature = "dtype-categorical")]
+fn utf8_column_to_categorical(
+    field: &ArrowField,
+    md: &RowGroupMetaData,
+    remaining_rows: usize,
+    bytes: &[u8],
+    chunk_size: usize,
+) -> Result<Series> {
+    let has_dictionary = get_field_columns(md.columns(), &field.name)
+        .iter()
+        .all(|meta| meta.dictionary_page_offset().is_some());
+    if has_dictionary {
+        let dict_field = ArrowField::new(
+            &field.name,
+            ArrowDataType::Dictionary(
+                IntegerType::UInt32,
+                Box::new(field.data_type.clone()),
+                false,
+            ),
+            field.is_nullable,
+        );
+        // writers fall back to another encoding if the dictionary grows too large, those
+        // pages can't be decoded as a dictionary
+        if let Ok(s) = read_column(&dict_field, md, remaining_rows, bytes, chunk_size) {
+            return Ok(s);
+        }
+    }
+    read_column(field, md, remaining_rows, bytes, chunk_size)?.cast(&DataType::Categorical(None))
+}
+
+/// Stack the `DataFrame`s of the row groups. The categorical columns get a single [`RevMapping`]
+/// that merges the dictionaries of all row groups.
+#[cfg(feature = "dtype-categorical")]
+fn accumulate_row_groups_categorical(dfs: Vec<DataFrame>) -> Result<DataFrame> {
+    let columns = dfs[0]
+        .get_columns()
+        .iter()
+        .enumerate()
+        .map(|(i, s)| match s.dtype() {
+            DataType::Categorical(_) => {
+                let cas = dfs
+                    .iter()
+                    .map(|df| df.get_columns()[i].categorical())
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(CategoricalChunked::concat_local(&cas)?.into_series())
+            }
+            _ => {
+                let mut out = s.clone();
+                for df in &dfs[1..] {
+                    out.append(&df.get_columns()[i])?;
+                }
+                Ok(out)
+            }
+        })
+        .collect::<Result<_>>()?;
+    Ok(DataFrame::new_no_checks(columns))
+}
+
 fn array_iter_to_series(
     iter: ArrayIter,
     field: &ArrowField,
@@ -63,9 +149,27 @@ fn array_iter_to_series(
     };
     if chunks.is_empty() {
         let arr = new_empty_array(field.data_type.clone());
-        Series::try_from((field.name.as_str(), arr))
+        Series::try_from((field, arr))
     } else {
-        Series::try_from((field.name.as_str(), chunks))
+        Series::try_from((field, chunks))
+    }
+}
+
+/// Report a decoded row group: its rows and the compressed size of its projected columns.
+fn report_progress(
+    progress: Option<&dyn ReadProgress>,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    projection: &[usize],
+    rows: usize,
+) {
+    if let Some(progress) = progress {
+        let bytes = projection
+            .iter()
+            .flat_map(|i| get_field_columns(md.columns(), &schema.fields[*i].name))
+            .map(|column| column.compressed_size() as u64)
+            .sum();
+        progress.rows_read(rows, bytes);
     }
 }
 
@@ -82,6 +186,9 @@ fn rg_to_dfs(
     row_count: Option<RowCount>,
     parallel: ParallelStrategy,
     projection: &[usize],
+    dictionary_as_categorical: bool,
+    progress: Option<&dyn ReadProgress>,
+    cancel_token: Option<&CancellationToken>,
 ) -> Result<Vec<DataFrame>> {
     let mut dfs = Vec::with_capacity(n_row_groups);
 
@@ -89,6 +196,9 @@ fn rg_to_dfs(
 
     let mut previous_row_count = 0;
     for rg in 0..n_row_groups {
+        if let Some(token) = cancel_token {
+            token.check()?;
+        }
         let md = &file_metadata.row_groups[rg];
         let current_row_count = md.num_rows() as IdxSize;
 
@@ -115,6 +225,7 @@ fn rg_to_dfs(
                             schema,
                             bytes,
                             chunk_size,
+                            dictionary_as_categorical,
                         )
                     })
                     .collect::<Result<Vec<_>>>()
@@ -123,7 +234,15 @@ fn rg_to_dfs(
             projection
                 .iter()
                 .map(|column_i| {
-                    column_idx_to_series(*column_i, md, remaining_rows, schema, bytes, chunk_size)
+                    column_idx_to_series(
+                        *column_i,
+                        md,
+                        remaining_rows,
+                        schema,
+                        bytes,
+                        chunk_size,
+                        dictionary_as_categorical,
+                    )
                 })
                 .collect::<Result<Vec<_>>>()?
         };
@@ -132,6 +251,7 @@ fn rg_to_dfs(
             remaining_rows.saturating_sub(file_metadata.row_groups[rg].num_rows() as usize);
 
         let mut df = DataFrame::new_no_checks(columns);
+        report_progress(progress, md, schema, projection, df.height());
         if let Some(rc) = &row_count {
             df.with_row_count_mut(&rc.name, Some(previous_row_count + rc.offset));
         }
@@ -160,6 +280,9 @@ fn rg_to_dfs_par(
     aggregate: Option<&[ScanAggregation]>,
     row_count: Option<RowCount>,
     projection: &[usize],
+    dictionary_as_categorical: bool,
+    progress: Option<&dyn ReadProgress>,
+    cancel_token: Option<&CancellationToken>,
 ) -> Result<Vec<DataFrame>> {
     let mut remaining_rows = limit;
     let mut previous_row_count = 0;
@@ -182,6 +305,9 @@ fn rg_to_dfs_par(
     let dfs = row_groups
         .into_par_iter()
         .map(|(md, local_limit, row_count_start)| {
+            if let Some(token) = cancel_token {
+                token.check()?;
+            }
             if local_limit == 0 || !read_this_row_group(predicate.as_ref(), file_metadata, schema)?
             {
                 return Ok(None);
@@ -196,11 +322,20 @@ fn rg_to_dfs_par(
             let columns = projection
                 .iter()
                 .map(|column_i| {
-                    column_idx_to_series(*column_i, md, local_limit, schema, bytes, chunk_size)
+                    column_idx_to_series(
+                        *column_i,
+                        md,
+                        local_limit,
+                        schema,
+                        bytes,
+                        chunk_size,
+                        dictionary_as_categorical,
+                    )
                 })
                 .collect::<Result<Vec<_>>>()?;
 
             let mut df = DataFrame::new_no_checks(columns);
+            report_progress(progress, md, schema, projection, df.height());
 
             if let Some(rc) = &row_count {
                 df.with_row_count_mut(&rc.name, Some(row_count_start as IdxSize + rc.offset));
@@ -216,6 +351,98 @@ fn rg_to_dfs_par(
 }
 
 #[allow(clippy::too_many_arguments)]
+// parallelizes over row groups and the columns within them
+fn rg_to_dfs_par_columns(
+    bytes: &[u8],
+    limit: usize,
+    file_metadata: &FileMetaData,
+    schema: &ArrowSchema,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    aggregate: Option<&[ScanAggregation]>,
+    row_count: Option<RowCount>,
+    projection: &[usize],
+    dictionary_as_categorical: bool,
+    progress: Option<&dyn ReadProgress>,
+    cancel_token: Option<&CancellationToken>,
+) -> Result<Vec<DataFrame>> {
+    let mut dfs = Vec::with_capacity(file_metadata.row_groups.len());
+    let mut remaining_rows = limit;
+    let mut previous_row_count = 0;
+
+    // We decode a window of row groups at a time, so that we don't decode the remaining
+    // row groups if the limit is reached and only hold the decoded columns of a single window
+    // in memory next to the finished `DataFrame`s.
+    for window in file_metadata.row_groups.chunks(POOL.current_num_threads()) {
+        if let Some(token) = cancel_token {
+            token.check()?;
+        }
+        let mut row_groups = Vec::with_capacity(window.len());
+        for md in window {
+            let num_rows = md.num_rows();
+            if remaining_rows > 0 && read_this_row_group(predicate.as_ref(), file_metadata, schema)?
+            {
+                row_groups.push((md, remaining_rows, previous_row_count));
+                remaining_rows = remaining_rows.saturating_sub(num_rows);
+            }
+            previous_row_count += num_rows;
+        }
+        if row_groups.is_empty() {
+            if remaining_rows == 0 {
+                break;
+            }
+            continue;
+        }
+        // test we don't read the parquet file if this env var is set
+        #[cfg(debug_assertions)]
+        {
+            assert!(std::env::var("POLARS_PANIC_IF_PARQUET_PARSED").is_err())
+        }
+
+        let columns = POOL.install(|| {
+            row_groups
+                .par_iter()
+                .flat_map(|(md, local_limit, _)| {
+                    projection.par_iter().map(move |column_i| {
+                        let chunk_size = md.num_rows() as usize;
+                        column_idx_to_series(
+                            *column_i,
+                            md,
+                            *local_limit,
+                            schema,
+                            bytes,
+                            chunk_size,
+                            dictionary_as_categorical,
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        let mut columns = columns.into_iter();
+        for (md, _, row_count_start) in row_groups {
+            let mut df =
+                DataFrame::new_no_checks(columns.by_ref().take(projection.len()).collect());
+            report_progress(progress, md, schema, projection, df.height());
+            if let Some(rc) = &row_count {
+                df.with_row_count_mut(&rc.name, Some(row_count_start as IdxSize + rc.offset));
+            }
+
+            apply_predicate(&mut df, predicate.as_deref(), true)?;
+            apply_aggregations(&mut df, aggregate)?;
+            dfs.push(df);
+        }
+        if remaining_rows == 0 {
+            break;
+        }
+    }
+    Ok(dfs)
+}
+
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "read_parquet", skip_all, fields(rows = tracing::field::Empty))
+)]
 pub fn read_parquet<R: MmapBytesReader>(
     mut reader: R,
     limit: usize,
@@ -227,6 +454,9 @@ pub fn read_parquet<R: MmapBytesReader>(
     mut parallel: ParallelStrategy,
     row_count: Option<RowCount>,
     low_memory: bool,
+    dictionary_as_categorical: bool,
+    progress: Option<&dyn ReadProgress>,
+    cancel_token: Option<&CancellationToken>,
 ) -> Result<DataFrame> {
     let file_metadata = metadata
         .map(Ok)
@@ -237,8 +467,17 @@ pub fn read_parquet<R: MmapBytesReader>(
         .map(Cow::Borrowed)
         .unwrap_or_else(|| Cow::Owned((0usize..schema.fields.len()).collect::<Vec<_>>()));
 
+    // decoding multiple row groups at the same time multiplies the peak memory
+    if let (ParallelStrategy::Auto, true) = (parallel, low_memory) {
+        parallel = ParallelStrategy::Columns;
+    }
     if let ParallelStrategy::Auto = parallel {
-        if row_group_len > projection.len() || row_group_len > POOL.current_num_threads() {
+        let n_threads = POOL.current_num_threads();
+        if row_group_len > n_threads {
+            parallel = ParallelStrategy::RowGroups;
+        } else if row_group_len > 1 && projection.len() > 1 && projection.len() < n_threads {
+            parallel = ParallelStrategy::RowGroupsAndColumns;
+        } else if row_group_len > projection.len() {
             parallel = ParallelStrategy::RowGroups;
         } else {
             parallel = ParallelStrategy::Columns;
@@ -248,6 +487,9 @@ pub fn read_parquet<R: MmapBytesReader>(
     if let (ParallelStrategy::Columns, true) = (parallel, projection.len() == 1) {
         parallel = ParallelStrategy::None;
     }
+    if let (ParallelStrategy::RowGroupsAndColumns, true) = (parallel, projection.len() <= 1) {
+        parallel = ParallelStrategy::RowGroups;
+    }
 
     let reader = ReaderBytes::from(&reader);
     let bytes = reader.deref();
@@ -263,6 +505,9 @@ pub fn read_parquet<R: MmapBytesReader>(
             row_count,
             parallel,
             &projection,
+            dictionary_as_categorical,
+            progress,
+            cancel_token,
         )?,
         ParallelStrategy::RowGroups => rg_to_dfs_par(
             bytes,
@@ -273,25 +518,67 @@ pub fn read_parquet<R: MmapBytesReader>(
             aggregate,
             row_count,
             &projection,
+            dictionary_as_categorical,
+            progress,
+            cancel_token,
+        )?,
+        ParallelStrategy::RowGroupsAndColumns => rg_to_dfs_par_columns(
+            bytes,
+            limit,
+            &file_metadata,
+            schema,
+            predicate,
+            aggregate,
+            row_count,
+            &projection,
+            dictionary_as_categorical,
+            progress,
+            cancel_token,
         )?,
         // auto should already be replaced by Columns or RowGroups
         ParallelStrategy::Auto => unimplemented!(),
     };
 
-    if dfs.is_empty() {
+    let mut df = if dfs.is_empty() {
         let schema = if let Cow::Borrowed(_) = projection {
             Cow::Owned(apply_projection(schema, &projection))
         } else {
             Cow::Borrowed(schema)
         };
-        Ok(arrow_schema_to_empty_df(&schema))
+        let df = arrow_schema_to_empty_df(&schema);
+        #[cfg(feature = "dtype-categorical")]
+        let df = if dictionary_as_categorical {
+            let columns = df
+                .get_columns()
+                .iter()
+                .map(|s| match s.dtype() {
+                    DataType::Utf8 => s.cast(&DataType::Categorical(None)),
+                    _ => Ok(s.clone()),
+                })
+                .collect::<Result<_>>()?;
+            DataFrame::new_no_checks(columns)
+        } else {
+            df
+        };
+        df
     } else {
+        #[cfg(feature = "dtype-categorical")]
+        let mut df = if dictionary_as_categorical {
+            accumulate_row_groups_categorical(dfs)?
+        } else {
+            accumulate_dataframes_vertical(dfs.into_iter())?
+        };
+        #[cfg(not(feature = "dtype-categorical"))]
         let mut df = accumulate_dataframes_vertical(dfs.into_iter())?;
         apply_aggregations(&mut df, aggregate)?;
-        Ok(if low_memory {
+        if low_memory {
             df._slice_and_realloc(0, limit)
         } else {
             df.slice_par(0, limit)
-        })
-    }
+        }
+    };
+    df.set_metadata(schema.metadata.clone());
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("rows", df.height());
+    Ok(df)
 }
@@ -1,7 +1,9 @@
 use arrow::array::Array;
 use arrow::chunk::Chunk;
+use arrow::compute::aggregate::estimated_bytes_size;
+use arrow::compute::cast::cast;
 use arrow::datatypes::DataType as ArrowDataType;
-use arrow::datatypes::PhysicalType;
+use arrow::datatypes::{IntegerType, PhysicalType};
 use arrow::error::Error as ArrowError;
 use arrow::io::parquet::read::ParquetError;
 use arrow::io::parquet::write::{self, FileWriter, *};
@@ -21,6 +23,8 @@ pub struct ParquetWriter<W> {
     compression: write::CompressionOptions,
     statistics: bool,
     row_group_size: Option<usize>,
+    data_page_size: Option<usize>,
+    dictionary: PlHashMap<String, bool>,
 }
 
 impl<W> ParquetWriter<W>
@@ -37,6 +41,8 @@ where
             compression: write::CompressionOptions::Lz4Raw,
             statistics: false,
             row_group_size: None,
+            data_page_size: None,
+            dictionary: PlHashMap::new(),
         }
     }
 
@@ -44,6 +50,9 @@ where
     ///
     /// The default compression `Lz4Raw` has very good performance, but may not yet been supported
     /// by older readers. If you want more compatability guarantees, consider using `Snappy`.
+    ///
+    /// The level of the `Gzip`, `Brotli` and `Zstd` codecs can be set with [`GzipLevel`],
+    /// [`BrotliLevel`] and [`ZstdLevel`], e.g. `ParquetCompression::Zstd(Some(ZstdLevel::try_new(10)?))`.
     pub fn with_compression(mut self, compression: write::CompressionOptions) -> Self {
         self.compression = compression;
         self
@@ -55,31 +64,82 @@ where
         self
     }
 
-    /// Set the row group size during writing. This can reduce memory pressure and improve
+    /// Set the maximum number of rows of a row group. This can reduce memory pressure and improve
     /// writing performance.
     pub fn with_row_group_size(mut self, size: Option<usize>) -> Self {
         self.row_group_size = size;
         self
     }
 
+    /// Set the (estimated) maximum size in bytes of a data page. Columns are written as a single
+    /// data page per row group by default.
+    ///
+    /// Dictionary encoded columns are always written as a single data page per row group.
+    pub fn with_data_page_size(mut self, size: Option<usize>) -> Self {
+        self.data_page_size = size;
+        self
+    }
+
+    /// Turn dictionary encoding of a column on or off.
+    ///
+    /// By default only `Categorical` columns are dictionary encoded. A `Categorical` column that
+    /// is written without dictionary encoding is read back as `Utf8`.
+    pub fn with_dictionary_encoding(mut self, column: &str, dictionary: bool) -> Self {
+        self.dictionary.insert(column.to_string(), dictionary);
+        self
+    }
+
     /// Write the given DataFrame in the the writer `W`.
     pub fn finish(mut self, df: &mut DataFrame) -> Result<()> {
         // ensures all chunks are aligned.
         df.rechunk();
 
+        let mut schema = df.arrow_schema();
+        for (name, dictionary) in &self.dictionary {
+            // a column that is not dictionary encoded is written with the dtype of its values
+            let idx = df
+                .find_idx_by_name(name)
+                .ok_or_else(|| PolarsError::NotFound(name.clone()))?;
+            if let (false, ArrowDataType::Dictionary(_, values, _)) =
+                (*dictionary, &schema.fields[idx].data_type)
+            {
+                schema.fields[idx].data_type = (**values).clone();
+            }
+        }
+
         if let Some(n) = self.row_group_size {
-            *df = accumulate_dataframes_vertical_unchecked(split_df(df, df.height() / n)?);
+            if n == 0 {
+                return Err(PolarsError::ComputeError(
+                    "the row group size must be larger than 0".into(),
+                ));
+            }
+            let n_row_groups = std::cmp::max((df.height() + n - 1) / n, 1);
+            *df = accumulate_dataframes_vertical_unchecked(split_df(df, n_row_groups)?);
+            df.set_metadata(schema.metadata.clone());
         };
 
-        let fields = df.schema().to_arrow().fields;
-        let rb_iter = df.iter_chunks();
+        let fields = schema
+            .fields
+            .iter()
+            .map(|f| (f.clone(), self.dictionary.get(&f.name).copied()))
+            .collect::<Vec<_>>();
+        let rb_iter = df.iter_chunks().map(|batch| {
+            let arrays = batch
+                .into_arrays()
+                .into_iter()
+                .zip(&fields)
+                .map(|(array, (field, dictionary))| {
+                    prepare_array(array, &field.name, &field.data_type, *dictionary)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Chunk::new(arrays))
+        });
 
         let options = write::WriteOptions {
             write_statistics: self.statistics,
             compression: self.compression,
             version: write::Version::V2,
         };
-        let schema = ArrowSchema::from(fields);
         let parquet_schema = write::to_parquet_schema(&schema)?;
         // declare encodings
         let encoding_map = |data_type: &ArrowDataType| {
@@ -90,37 +150,110 @@ where
             }
         };
 
-        let encodings = schema
-            .fields
-            .iter()
-            .map(|f| transverse(&f.data_type, encoding_map))
-            .collect::<Vec<_>>();
-
-        let row_group_iter = rb_iter.filter_map(|batch| match batch.len() {
-            0 => None,
-            _ => {
-                let row_group =
-                    create_serializer(batch, parquet_schema.fields().to_vec(), &encodings, options);
+        let data_page_size = self.data_page_size;
+        let row_group_iter = rb_iter.filter_map(|batch: Result<Chunk<ArrayRef>>| match batch {
+            Ok(batch) if batch.is_empty() => None,
+            Ok(batch) => {
+                // the encodings follow the arrays, as the dictionary encoding can be set per column
+                let encodings = batch
+                    .arrays()
+                    .iter()
+                    .map(|arr| transverse(arr.data_type(), encoding_map))
+                    .collect::<Vec<_>>();
+                let row_group = create_serializer(
+                    batch,
+                    parquet_schema.fields().to_vec(),
+                    &encodings,
+                    options,
+                    data_page_size,
+                )
+                .map_err(PolarsError::from);
 
                 Some(row_group)
             }
+            Err(e) => Some(Err(e)),
         });
 
+        // the metadata is also in the serialized arrow schema, but this makes it
+        // available to readers that don't use arrow
+        let key_value_metadata = schema
+            .metadata
+            .iter()
+            .map(|(key, value)| KeyValue {
+                key: key.clone(),
+                value: Some(value.clone()),
+            })
+            .collect::<Vec<_>>();
         let mut writer = FileWriter::try_new(&mut self.writer, schema, options)?;
         for group in row_group_iter {
             writer.write(group?)?;
         }
-        let _ = writer.end(None)?;
+        let _ = writer.end(Some(key_value_metadata).filter(|kv| !kv.is_empty()))?;
 
         Ok(())
     }
 }
 
+/// Cast the array to a dictionary array or to the dtype of its values if its dictionary encoding
+/// is set explicitly.
+fn prepare_array(
+    array: ArrayRef,
+    name: &str,
+    data_type: &ArrowDataType,
+    dictionary: Option<bool>,
+) -> Result<ArrayRef> {
+    let is_dictionary = matches!(array.data_type(), ArrowDataType::Dictionary(..));
+    match dictionary {
+        Some(true) if !is_dictionary => {
+            let dtype = ArrowDataType::Dictionary(
+                IntegerType::UInt32,
+                Box::new(array.data_type().clone()),
+                false,
+            );
+            cast(array.as_ref(), &dtype, Default::default()).map_err(|_| {
+                PolarsError::InvalidOperation(
+                    format!(
+                        "cannot dictionary encode column '{}' of arrow dtype {:?}",
+                        name,
+                        array.data_type()
+                    )
+                    .into(),
+                )
+            })
+        }
+        Some(false) if is_dictionary => Ok(cast(array.as_ref(), data_type, Default::default())?),
+        _ => Ok(array),
+    }
+}
+
+/// Split the array in slices of (estimated) `data_page_size` bytes, that are each written as a
+/// data page.
+fn split_in_pages(array: &ArrayRef, data_page_size: Option<usize>) -> Vec<ArrayRef> {
+    match data_page_size {
+        // all pages of a column chunk share a single dictionary page
+        Some(page_size)
+            if array.len() > 1 && !matches!(array.data_type(), ArrowDataType::Dictionary(..)) =>
+        {
+            let len = array.len();
+            let page_size = std::cmp::max(page_size, 1);
+            let n_pages = (estimated_bytes_size(array.as_ref()) + page_size - 1) / page_size;
+            let n_pages = n_pages.clamp(1, len);
+            let page_len = (len + n_pages - 1) / n_pages;
+            (0..len)
+                .step_by(page_len)
+                .map(|offset| array.slice(offset, std::cmp::min(page_len, len - offset)))
+                .collect()
+        }
+        _ => vec![array.clone()],
+    }
+}
+
 fn create_serializer(
     batch: Chunk<Box<dyn Array>>,
     fields: Vec<ParquetType>,
     encodings: &[Vec<Encoding>],
     options: WriteOptions,
+    data_page_size: Option<usize>,
 ) -> std::result::Result<RowGroupIter<'static, ArrowError>, ArrowError> {
     let columns = batch
         .columns()
@@ -128,10 +261,21 @@ fn create_serializer(
         .zip(fields)
         .zip(encodings)
         .map(move |((array, type_), encoding)| {
-            let encoded_columns = array_to_columns(array, type_, options, encoding).unwrap();
+            let mut encoded_columns: Vec<Vec<_>> = vec![];
+            for page in split_in_pages(array, data_page_size) {
+                let pages = array_to_columns(page, type_.clone(), options, encoding).unwrap();
+                if encoded_columns.is_empty() {
+                    encoded_columns = pages.into_iter().map(|leaf| vec![leaf]).collect();
+                } else {
+                    for (leaf, pages) in encoded_columns.iter_mut().zip(pages) {
+                        leaf.push(pages)
+                    }
+                }
+            }
 
             encoded_columns
                 .into_iter()
+                .map(|pages| DynIter::new(pages.into_iter().flatten()))
                 .map(|encoded_pages| {
                     // iterator over pages
                     let pages = DynStreamingIterator::new(
@@ -5,6 +5,12 @@ pub trait PhysicalIoExpr: Send + Sync {
     /// as a predicate mask
     fn evaluate(&self, df: &DataFrame) -> Result<Series>;
 
+    /// The names of the columns that are needed to evaluate this predicate, if known.
+    /// Readers can use this to only materialize the other columns for the rows that pass.
+    fn live_columns(&self) -> Option<Vec<Arc<str>>> {
+        None
+    }
+
     /// Can take &dyn Statistics and determine of a file should be
     /// read -> `true`
     /// or not -> `false`
@@ -2,6 +2,8 @@ pub use crate::{SerReader, SerWriter};
 
 #[cfg(feature = "csv-file")]
 pub use crate::csv::*;
+#[cfg(feature = "fwf")]
+pub use crate::fwf::*;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub use crate::ipc::*;
 #[cfg(feature = "json")]
@@ -11,6 +13,8 @@ pub use crate::ndjson_core::ndjson::*;
 #[cfg(feature = "parquet")]
 pub use crate::parquet::*;
 
+pub use crate::progress::{ReadProgress, ReadProgressRef};
+pub use crate::retry::RetryPolicy;
 pub use crate::utils::*;
 
 #[cfg(test)]
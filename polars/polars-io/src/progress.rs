@@ -0,0 +1,13 @@
+//! Report the progress of a reader while it reads, e.g. to show the progress of a long scan.
+use polars_core::prelude::*;
+
+/// Receives the rows and bytes a reader read, every time it read a part of a file.
+pub trait ReadProgress: Send + Sync {
+    /// Called with the number of rows and bytes of the part of the file that was just read.
+    /// The rows are counted before a predicate filters them, the bytes are the bytes of the
+    /// file that were decoded or, if the reader can't tell, the size of the decoded data.
+    fn rows_read(&self, rows: usize, bytes: u64);
+}
+
+/// A [`ReadProgress`] that is shared with the caller of a reader.
+pub type ReadProgressRef = Arc<dyn ReadProgress>;
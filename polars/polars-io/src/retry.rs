@@ -0,0 +1,177 @@
+//! Retry reads that fail on a transient io error, e.g. of files on a network file system or an
+//! object store mount, so that a flaky read does not fail a long-running query.
+use arrow::error::Error as ArrowError;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::io::ErrorKind;
+use std::time::{Duration, Instant};
+
+/// How often and how long a read that fails on a transient io error is retried.
+///
+/// The default policy doesn't retry, retries are opted into with
+/// [`with_max_retries`](RetryPolicy::with_max_retries).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt.
+    pub max_retries: usize,
+    /// Wait time before the first retry. It doubles for every next retry.
+    pub backoff: Duration,
+    /// Upper bound of the wait time between two attempts.
+    pub max_backoff: Duration,
+    /// Time budget of the retries, counted from the start of the first attempt. No retry is
+    /// started that would wait beyond it. A single attempt is not interrupted, so this does
+    /// not bound a read that hangs.
+    pub retry_budget: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            retry_budget: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    #[must_use]
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    #[must_use]
+    pub fn with_backoff(mut self, backoff: Duration, max_backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    #[must_use]
+    pub fn with_retry_budget(mut self, retry_budget: Option<Duration>) -> Self {
+        self.retry_budget = retry_budget;
+        self
+    }
+
+    /// Run `read` until it succeeds, fails on an error that is not transient, or the retries
+    /// or the retry budget are exhausted. In the latter case the last error is returned.
+    pub fn retry<T, F>(&self, mut read: F) -> Result<T>
+    where
+        F: FnMut() -> Result<T>,
+    {
+        let start = Instant::now();
+        let mut backoff = self.backoff;
+        let mut retries = 0;
+        loop {
+            let err = match read() {
+                Ok(out) => return Ok(out),
+                Err(err) => err,
+            };
+            if retries == self.max_retries || !is_transient(&err) {
+                return Err(err);
+            }
+            if let Some(retry_budget) = self.retry_budget {
+                if start.elapsed() + backoff > retry_budget {
+                    return Err(err);
+                }
+            }
+            std::thread::sleep(backoff);
+            backoff = std::cmp::min(backoff * 2, self.max_backoff);
+            retries += 1;
+        }
+    }
+}
+
+fn is_transient_kind(kind: ErrorKind) -> bool {
+    matches!(
+        kind,
+        ErrorKind::Interrupted
+            | ErrorKind::TimedOut
+            | ErrorKind::WouldBlock
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::NotConnected
+            | ErrorKind::BrokenPipe
+    )
+}
+
+/// Whether `err` is an io error that may not happen again on a next attempt.
+pub fn is_transient(err: &PolarsError) -> bool {
+    match err.root() {
+        PolarsError::Io(err) => is_transient_kind(err.kind()),
+        PolarsError::ArrowError(err) => match err.as_ref() {
+            ArrowError::Io(err) => is_transient_kind(err.kind()),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn test_retry() {
+        let policy = RetryPolicy::default()
+            .with_max_retries(3)
+            .with_backoff(Duration::ZERO, Duration::ZERO);
+
+        // transient errors are retried
+        let mut attempts = 0;
+        let out = policy.retry(|| {
+            attempts += 1;
+            if attempts < 3 {
+                Err(io::Error::from(ErrorKind::TimedOut).into())
+            } else {
+                Ok(attempts)
+            }
+        });
+        assert_eq!(out.unwrap(), 3);
+
+        // up to the maximum number of retries
+        let mut attempts = 0;
+        let out: Result<()> = policy.retry(|| {
+            attempts += 1;
+            Err(io::Error::from(ErrorKind::Interrupted).into())
+        });
+        assert!(out.is_err());
+        assert_eq!(attempts, 4);
+
+        // other errors are not
+        let mut attempts = 0;
+        let out: Result<()> = policy.retry(|| {
+            attempts += 1;
+            Err(io::Error::from(ErrorKind::NotFound).into())
+        });
+        assert!(out.is_err());
+        assert_eq!(attempts, 1);
+
+        // no retry waits beyond the retry budget
+        let policy = RetryPolicy::default()
+            .with_max_retries(3)
+            .with_backoff(Duration::from_secs(10), Duration::from_secs(10))
+            .with_retry_budget(Some(Duration::from_secs(1)));
+        let mut attempts = 0;
+        let out: Result<()> = policy.retry(|| {
+            attempts += 1;
+            Err(io::Error::from(ErrorKind::TimedOut).into())
+        });
+        assert!(out.is_err());
+        assert_eq!(attempts, 1);
+
+        // the default policy doesn't retry
+        let mut attempts = 0;
+        let out: Result<()> = RetryPolicy::default().retry(|| {
+            attempts += 1;
+            Err(io::Error::from(ErrorKind::TimedOut).into())
+        });
+        assert!(out.is_err());
+        assert_eq!(attempts, 1);
+    }
+}
@@ -2,8 +2,16 @@
 //!
 
 use crate::csv::CsvReader;
+#[cfg(feature = "private")]
+use crate::csv::RaggedLines;
+#[cfg(feature = "private")]
+use crate::predicates::PhysicalIoExpr;
 use crate::SerReader;
 use polars_core::prelude::*;
+#[cfg(feature = "private")]
+use std::io::Cursor;
+#[cfg(feature = "private")]
+use std::sync::Arc;
 
 #[test]
 fn test_filter() -> Result<()> {
@@ -17,3 +25,50 @@ fn test_filter() -> Result<()> {
 
     Ok(())
 }
+
+/// Select the lines where the column `a` is not equal to a value.
+#[cfg(feature = "private")]
+struct NotEqual(i64);
+
+#[cfg(feature = "private")]
+impl PhysicalIoExpr for NotEqual {
+    fn evaluate(&self, df: &DataFrame) -> Result<Series> {
+        Ok(df.column("a")?.not_equal(self.0)?.into_series())
+    }
+
+    fn live_columns(&self) -> Option<Vec<Arc<str>>> {
+        Some(vec![Arc::from("a")])
+    }
+}
+
+#[test]
+#[cfg(feature = "private")]
+fn test_predicate_with_rejected_lines() -> Result<()> {
+    let mut csv = "a,b,c\n".to_string();
+    for i in 0..100 {
+        // every third line is ragged
+        if i % 3 == 1 {
+            csv.push_str(&format!("{},{}\n", i, i));
+        } else {
+            csv.push_str(&format!("{},b{},{}\n", i, i, i * 10));
+        }
+    }
+    let predicate: Arc<dyn PhysicalIoExpr> = Arc::new(NotEqual(3));
+
+    let (df, rejected) = CsvReader::new(Cursor::new(csv))
+        .with_ragged_lines(RaggedLines::Reject)
+        .with_predicate(Some(predicate))
+        .with_chunk_size(10)
+        .finish_with_rejected()?;
+    let expected = (0..100).filter(|i| i % 3 != 1 && *i != 3);
+    assert_eq!(df.height(), expected.clone().count());
+    // the fields of the second pass belong to the lines that are selected in the first pass
+    assert_eq!(
+        Vec::from(df.column("c")?.i64()?),
+        expected.map(|i| Some(i * 10)).collect::<Vec<_>>()
+    );
+    // the rejected lines are collected once
+    assert_eq!(rejected.height(), 33);
+    assert_eq!(rejected.column("line")?.utf8()?.get(0), Some("1,1"));
+    Ok(())
+}
@@ -34,7 +34,7 @@ pub(crate) fn apply_projection(schema: &ArrowSchema, projection: &[usize]) -> Ar
         .iter()
         .map(|idx| fields[*idx].clone())
         .collect::<Vec<_>>();
-    ArrowSchema::from(fields)
+    ArrowSchema::from(fields).with_metadata(schema.metadata.clone())
 }
 
 #[cfg(any(
@@ -81,6 +81,71 @@ pub(crate) fn columns_to_projection(
     Ok(prj)
 }
 
+// magic numbers
+const GZIP: [u8; 2] = [31, 139];
+const ZLIB0: [u8; 2] = [0x78, 0x01];
+const ZLIB1: [u8; 2] = [0x78, 0x9C];
+const ZLIB2: [u8; 2] = [0x78, 0xDA];
+const ZSTD: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+const BZIP2: [u8; 3] = *b"BZh";
+// the magic of the first block, or the end of the stream if it has no blocks
+const BZIP2_BLOCK: [u8; 6] = *b"1AY&SY";
+const BZIP2_END: [u8; 6] = [0x17, 0x72, 0x45, 0x38, 0x50, 0x90];
+
+/// The header of a bzip2 stream is followed by the block size digit and the magic of a block.
+fn is_bzip2(bytes: &[u8]) -> bool {
+    bytes.len() >= 10
+        && bytes.starts_with(&BZIP2)
+        && (b'1'..=b'9').contains(&bytes[3])
+        && (bytes[4..10] == BZIP2_BLOCK || bytes[4..10] == BZIP2_END)
+}
+
+/// check if csv file is compressed
+pub fn is_compressed(bytes: &[u8]) -> bool {
+    bytes.starts_with(&ZLIB0)
+        || bytes.starts_with(&ZLIB1)
+        || bytes.starts_with(&ZLIB2)
+        || bytes.starts_with(&GZIP)
+        || bytes.starts_with(&ZSTD)
+        || is_bzip2(bytes)
+}
+
+/// Get a decoder for the compression of `bytes`, determined by the magic number.
+/// Returns `None` if the bytes are not compressed.
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+pub(crate) fn decoder(bytes: &[u8]) -> Result<Option<Box<dyn std::io::Read + '_>>> {
+    let decoder: Box<dyn std::io::Read + '_> = if bytes.starts_with(&GZIP) {
+        Box::new(flate2::read::MultiGzDecoder::new(bytes))
+    } else if bytes.starts_with(&ZLIB0) || bytes.starts_with(&ZLIB1) || bytes.starts_with(&ZLIB2) {
+        Box::new(flate2::read::ZlibDecoder::new(bytes))
+    } else if bytes.starts_with(&ZSTD) {
+        Box::new(zstd::stream::read::Decoder::new(bytes)?)
+    } else if is_bzip2(bytes) {
+        Box::new(bzip2::read::MultiBzDecoder::new(bytes))
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(decoder))
+}
+
+/// Decompress `bytes` if they are compressed.
+#[cfg(all(
+    feature = "json",
+    any(feature = "decompress", feature = "decompress-fast")
+))]
+pub(crate) fn decompress_all(bytes: &[u8]) -> Result<Option<Vec<u8>>> {
+    use std::io::Read;
+    match decoder(bytes)? {
+        Some(mut decoder) => {
+            // decompression will likely be an order of magnitude larger
+            let mut out = Vec::with_capacity(bytes.len() * 10);
+            decoder.read_to_end(&mut out)?;
+            Ok(Some(out))
+        }
+        None => Ok(None),
+    }
+}
+
 /// Because of threading every row starts from `0` or from `offset`.
 /// We must correct that so that they are monotonically increasing.
 pub(crate) fn update_row_counts(dfs: &mut [(DataFrame, IdxSize)]) {
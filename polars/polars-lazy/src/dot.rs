@@ -423,6 +423,10 @@ impl LogicalPlan {
                 let current_node = format!("{:?}", &**err);
                 self.write_dot(acc_str, prev_node, &current_node, id)
             }
+            TableScan { name, .. } => {
+                let current_node = format!("TABLE {} [{:?}]", name, (branch, id));
+                self.write_dot(acc_str, prev_node, &current_node, id)
+            }
         }
     }
 }
@@ -28,4 +28,26 @@ impl CategoricalNameSpace {
             )
             .with_fmt("set_ordering")
     }
+
+    /// Get the categories of the categorical, including the categories that don't occur in
+    /// the column.
+    pub fn get_categories(self) -> Expr {
+        self.0
+            .map(
+                |s| Ok(s.categorical()?.get_categories().into_series()),
+                GetOutput::from_type(DataType::Utf8),
+            )
+            .with_fmt("get_categories")
+    }
+
+    /// Re-encode the categorical under a local mapping that only holds the categories that
+    /// occur in the column.
+    pub fn to_local(self) -> Expr {
+        self.0
+            .map(
+                |s| Ok(s.categorical()?.to_local().into_series()),
+                GetOutput::same_type(),
+            )
+            .with_fmt("to_local")
+    }
 }
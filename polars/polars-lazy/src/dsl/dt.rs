@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "dtype-struct")]
+use polars_time::prelude::temporal_components_dtype;
 use polars_time::prelude::TemporalMethods;
 
 /// Specialized expressions for [`Series`] with dates/datetimes.
@@ -63,6 +65,19 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Get the year, month, day and for a Datetime also the hour, minute, second and nanosecond
+    /// of a Date/Datetime as a [`DataType::Struct`].
+    ///
+    /// This extracts all components in a single pass over the data.
+    #[cfg(feature = "dtype-struct")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "dtype-struct")))]
+    pub fn components(self) -> Expr {
+        let function = move |s: Series| s.components().map(|ca| ca.into_series());
+        self.0
+            .map(function, GetOutput::map_dtype(temporal_components_dtype))
+            .with_fmt("dt.components")
+    }
+
     /// Get the year of a Date/Datetime
     pub fn year(self) -> Expr {
         let function = move |s: Series| s.year().map(|ca| ca.into_series());
@@ -171,4 +186,20 @@ impl DateLikeNameSpace {
         self.0
             .map_private(FunctionExpr::DateOffset(by), "dt.offset_by")
     }
+
+    /// Assign an id to the session of every value: a new session starts at a value that is
+    /// more than `gap` later than the previous value. The values must be sorted in ascending
+    /// order and the unit of `gap` must match the dtype, see [`polars_time::session_id`].
+    ///
+    /// Use a window expression to assign the sessions per partition, e.g.
+    /// `col("time").dt().session_id(Duration::parse("30m")).over([col("user")])`.
+    pub fn session_id(self, gap: Duration) -> Expr {
+        // applied per group, so that a window expression restarts the sessions per partition
+        self.0
+            .apply(
+                move |s| polars_time::session_id(&s, gap).map(|ca| ca.into_series()),
+                GetOutput::from_type(IDX_DTYPE),
+            )
+            .with_fmt("dt.session_id")
+    }
 }
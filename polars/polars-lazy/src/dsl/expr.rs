@@ -173,6 +173,20 @@ impl GetOutput {
         }))
     }
 
+    /// Integers and booleans become `Float64`, other types stay the same.
+    pub fn float_type() -> Self {
+        Self::map_dtype(|dt| match dt {
+            DataType::Float32 => DataType::Float32,
+            dt if dt.is_numeric() || matches!(dt, DataType::Boolean) => DataType::Float64,
+            dt => dt.clone(),
+        })
+    }
+
+    /// The output is a list of the input type.
+    pub fn list_type() -> Self {
+        Self::map_dtype(|dt| DataType::List(Box::new(dt.clone())))
+    }
+
     pub fn super_type() -> Self {
         Self::map_dtypes(|dtypes| {
             let mut st = dtypes[0].clone();
@@ -355,6 +369,11 @@ pub enum Expr {
     Count,
     /// Take the nth column in the `DataFrame`
     Nth(i64),
+    /// Evaluated once per operation, all uses with the same `id` share the result.
+    Cache {
+        input: Box<Expr>,
+        id: usize,
+    },
 }
 
 // TODO! derive. This is only a temporary fix
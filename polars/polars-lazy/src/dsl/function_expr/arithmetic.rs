@@ -0,0 +1,9 @@
+use super::*;
+
+pub(super) fn arithmetic_with_overflow(
+    s: &mut [Series],
+    op: ArithmeticOp,
+    policy: OverflowPolicy,
+) -> Result<Series> {
+    s[0].arithmetic_with_overflow(&s[1], op, policy)
+}
@@ -0,0 +1,20 @@
+use super::*;
+use polars_ops::prelude::{Polygon, EARTH_RADIUS_KM};
+
+pub(super) fn haversine_distance(s: &mut [Series]) -> Result<Series> {
+    let mut out = polars_ops::prelude::haversine_distance_series(
+        &s[0],
+        &s[1],
+        &s[2],
+        &s[3],
+        EARTH_RADIUS_KM,
+    )?;
+    out.rename(s[0].name());
+    Ok(out)
+}
+
+pub(super) fn point_in_polygon(s: &mut [Series], polygons: &[Polygon]) -> Result<Series> {
+    let mut out = polars_ops::prelude::point_in_polygon_series(&s[0], &s[1], polygons)?;
+    out.rename(s[0].name());
+    Ok(out.into_series())
+}
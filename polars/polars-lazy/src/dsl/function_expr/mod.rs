@@ -1,9 +1,14 @@
 #[cfg(feature = "arg_where")]
 mod arg_where;
+#[cfg(feature = "checked_arithmetic")]
+mod arithmetic;
 mod fill_null;
+#[cfg(feature = "geo")]
+mod geo;
 #[cfg(feature = "is_in")]
 mod is_in;
 mod list;
+mod plugin;
 mod pow;
 #[cfg(all(feature = "rolling_window", feature = "moment"))]
 mod rolling;
@@ -12,6 +17,8 @@ mod row_hash;
 mod shift_and_fill;
 #[cfg(feature = "sign")]
 mod sign;
+#[cfg(feature = "special_functions")]
+mod special;
 #[cfg(feature = "strings")]
 mod strings;
 #[cfg(any(feature = "temporal", feature = "date_offset"))]
@@ -20,7 +27,10 @@ mod temporal;
 mod trigonometry;
 
 use super::*;
+pub use plugin::{register_plugin, ExprPlugin, PluginFunction};
 use polars_core::prelude::*;
+#[cfg(feature = "geo")]
+use polars_ops::prelude::Polygon;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -29,6 +39,11 @@ use serde::{Deserialize, Serialize};
 pub enum FunctionExpr {
     NullCount,
     Pow,
+    #[cfg(feature = "checked_arithmetic")]
+    ArithmeticWithOverflow {
+        op: ArithmeticOp,
+        policy: OverflowPolicy,
+    },
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
     #[cfg(feature = "is_in")]
@@ -50,6 +65,8 @@ pub enum FunctionExpr {
     Trigonometry(TrigonometricFunction),
     #[cfg(feature = "sign")]
     Sign,
+    #[cfg(feature = "special_functions")]
+    SpecialFunction(SpecialFunction),
     FillNull {
         super_type: DataType,
     },
@@ -61,9 +78,14 @@ pub enum FunctionExpr {
         window_size: usize,
         bias: bool,
     },
-    ShiftAndFill {
-        periods: i64,
-    },
+    Shift,
+    ShiftAndFill,
+    #[cfg(feature = "geo")]
+    HaversineDistance,
+    #[cfg(feature = "geo")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    PointInPolygon(Arc<[Polygon]>),
+    Plugin(PluginFunction),
 }
 
 #[cfg(feature = "trigonometry")]
@@ -84,10 +106,18 @@ pub enum TrigonometricFunction {
     ArcTanh,
 }
 
+#[cfg(feature = "special_functions")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum SpecialFunction {
+    Erf,
+    LGamma,
+}
+
 impl FunctionExpr {
     pub(crate) fn get_field(
         &self,
-        _input_schema: &Schema,
+        input_schema: &Schema,
         _cntxt: Context,
         fields: &[Field],
     ) -> Result<Field> {
@@ -119,6 +149,12 @@ impl FunctionExpr {
         match self {
             NullCount => with_dtype(IDX_DTYPE),
             Pow => super_type(),
+            #[cfg(feature = "checked_arithmetic")]
+            ArithmeticWithOverflow { policy, .. } => {
+                let mut field = super_type()?;
+                field.coerce(policy.output_dtype(field.data_type()));
+                Ok(field)
+            }
             #[cfg(feature = "row_hash")]
             Hash(..) => with_dtype(DataType::UInt64),
             #[cfg(feature = "is_in")]
@@ -135,12 +171,19 @@ impl FunctionExpr {
             Trigonometry(_) => float_dtype(),
             #[cfg(feature = "sign")]
             Sign => with_dtype(DataType::Int64),
+            #[cfg(feature = "special_functions")]
+            SpecialFunction(_) => float_dtype(),
             FillNull { super_type, .. } => with_dtype(super_type.clone()),
             #[cfg(feature = "is_in")]
             ListContains => with_dtype(DataType::Boolean),
             #[cfg(all(feature = "rolling_window", feature = "moment"))]
             RollingSkew { .. } => float_dtype(),
-            ShiftAndFill { .. } => same_type(),
+            Shift | ShiftAndFill => same_type(),
+            #[cfg(feature = "geo")]
+            HaversineDistance => with_dtype(DataType::Float64),
+            #[cfg(feature = "geo")]
+            PointInPolygon(_) => with_dtype(IDX_DTYPE),
+            Plugin(function) => function.get_field(input_schema, fields),
         }
     }
 }
@@ -214,6 +257,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Pow => {
                 wrap!(pow::pow)
             }
+            #[cfg(feature = "checked_arithmetic")]
+            ArithmeticWithOverflow { op, policy } => {
+                map_as_slice!(arithmetic::arithmetic_with_overflow, op, policy)
+            }
             #[cfg(feature = "row_hash")]
             Hash(k0, k1, k2, k3) => {
                 map_with_args!(row_hash::row_hash, k0, k1, k2, k3)
@@ -250,6 +297,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Sign => {
                 map_without_args!(sign::sign)
             }
+            #[cfg(feature = "special_functions")]
+            SpecialFunction(function) => {
+                map_with_args!(special::apply_special_function, function)
+            }
             FillNull { super_type } => {
                 map_as_slice!(fill_null::fill_null, &super_type)
             }
@@ -261,8 +312,23 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             RollingSkew { window_size, bias } => {
                 map_with_args!(rolling::rolling_skew, window_size, bias)
             }
-            ShiftAndFill { periods } => {
-                map_as_slice!(shift_and_fill::shift_and_fill, periods)
+            Shift => {
+                wrap!(shift_and_fill::shift)
+            }
+            ShiftAndFill => {
+                wrap!(shift_and_fill::shift_and_fill)
+            }
+            #[cfg(feature = "geo")]
+            HaversineDistance => {
+                wrap!(geo::haversine_distance)
+            }
+            #[cfg(feature = "geo")]
+            PointInPolygon(polygons) => {
+                map_as_slice!(geo::point_in_polygon, &polygons)
+            }
+            Plugin(function) => {
+                let f = move |s: &mut [Series]| function.call(s);
+                wrap!(f)
             }
         }
     }
@@ -0,0 +1,142 @@
+use super::*;
+use parking_lot::RwLock;
+use polars_core::export::once_cell::sync::Lazy;
+
+/// A function of an expression namespace that is defined outside of this crate.
+///
+/// Plugins are registered with [`register_plugin`] and called with [`Expr::plugin`].
+/// Unlike the closures of [`Expr::map`], a plugin call is a native function node: the
+/// optimizer knows its name and output type, and the call can be compared and serialized.
+pub trait ExprPlugin: Send + Sync {
+    /// Determine the output field from the fields of the arguments.
+    /// The first field is that of the expression the plugin is called on.
+    fn get_field(&self, input_schema: &Schema, fields: &[Field]) -> Result<Field>;
+
+    /// Apply the function. The first series is that of the expression the plugin is called on.
+    fn call(&self, s: &mut [Series]) -> Result<Series>;
+
+    /// Whether the function is applied to every element independently. Such a function is
+    /// applied once on the whole column in a groupby context, otherwise it is applied per group.
+    fn is_elementwise(&self) -> bool {
+        true
+    }
+}
+
+struct RegisteredPlugin {
+    plugin: Arc<dyn ExprPlugin>,
+    // plugins are registered once, so this leaks a bounded amount of memory
+    fmt_str: &'static str,
+}
+
+static PLUGINS: Lazy<RwLock<PlHashMap<PluginFunction, RegisteredPlugin>>> =
+    Lazy::new(Default::default);
+
+/// The name of a plugin function, e.g. `area` in the namespace `geo`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct PluginFunction {
+    pub namespace: String,
+    pub name: String,
+}
+
+impl PluginFunction {
+    fn get(&self) -> Result<Arc<dyn ExprPlugin>> {
+        PLUGINS
+            .read()
+            .get(self)
+            .map(|registered| registered.plugin.clone())
+            .ok_or_else(|| {
+                PolarsError::NotFound(format!(
+                    "no plugin '{}.{}' is registered",
+                    self.namespace, self.name
+                ))
+            })
+    }
+
+    pub(super) fn get_field(&self, input_schema: &Schema, fields: &[Field]) -> Result<Field> {
+        self.get()?.get_field(input_schema, fields)
+    }
+
+    pub(super) fn call(&self, s: &mut [Series]) -> Result<Series> {
+        self.get()?.call(s)
+    }
+
+    /// The options of a call of this plugin. They are looked up again when the query is
+    /// planned, as the plugin may be registered after the call is built.
+    pub(crate) fn function_options(&self) -> FunctionOptions {
+        let (elementwise, fmt_str) = match PLUGINS.read().get(self) {
+            Some(registered) => (registered.plugin.is_elementwise(), registered.fmt_str),
+            None => (true, "plugin"),
+        };
+        FunctionOptions {
+            collect_groups: if elementwise {
+                ApplyOptions::ApplyFlat
+            } else {
+                ApplyOptions::ApplyGroups
+            },
+            input_wildcard_expansion: false,
+            auto_explode: elementwise,
+            fmt_str,
+        }
+    }
+}
+
+/// Register `plugin` as the function `name` of the expression namespace `namespace`.
+/// A plugin that was registered under the same name is replaced.
+pub fn register_plugin(namespace: &str, name: &str, plugin: Arc<dyn ExprPlugin>) {
+    let function = PluginFunction {
+        namespace: namespace.to_string(),
+        name: name.to_string(),
+    };
+    let mut plugins = PLUGINS.write();
+    let fmt_str = match plugins.get(&function) {
+        Some(registered) => registered.fmt_str,
+        None => Box::leak(format!("{}.{}", namespace, name).into_boxed_str()),
+    };
+    plugins.insert(function, RegisteredPlugin { plugin, fmt_str });
+}
+
+impl Expr {
+    /// Call the plugin function `name` of the expression namespace `namespace` on this
+    /// expression and `arguments`. The plugin must be registered with [`register_plugin`]
+    /// before the call is used in a query, as its output type is needed to build the query.
+    ///
+    /// Crates that define a namespace can expose its functions with an extension trait:
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// pub struct GeoNameSpace(Expr);
+    ///
+    /// impl GeoNameSpace {
+    ///     pub fn distance(self, other: Expr) -> Expr {
+    ///         self.0.plugin("geo", "distance", &[other])
+    ///     }
+    /// }
+    ///
+    /// pub trait GeoExt {
+    ///     fn geo(self) -> GeoNameSpace;
+    /// }
+    ///
+    /// impl GeoExt for Expr {
+    ///     fn geo(self) -> GeoNameSpace {
+    ///         GeoNameSpace(self)
+    ///     }
+    /// }
+    /// ```
+    pub fn plugin(self, namespace: &str, name: &str, arguments: &[Expr]) -> Expr {
+        let function = PluginFunction {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        };
+        let mut input = Vec::with_capacity(arguments.len() + 1);
+        input.push(self);
+        input.extend_from_slice(arguments);
+        Expr::Function {
+            input,
+            options: function.function_options(),
+            function: FunctionExpr::Plugin(function),
+        }
+    }
+}
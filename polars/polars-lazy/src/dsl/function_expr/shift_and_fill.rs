@@ -1,26 +1,43 @@
 use super::*;
 
-pub(super) fn shift_and_fill(args: &mut [Series], periods: i64) -> Result<Series> {
+fn get_periods(periods: &Series) -> Result<i64> {
+    if periods.len() != 1 {
+        return Err(PolarsError::ComputeError(
+            format!(
+                "the periods of a shift must be a single value, got {} values",
+                periods.len()
+            )
+            .into(),
+        ));
+    }
+    periods
+        .cast(&DataType::Int64)?
+        .i64()?
+        .get(0)
+        .ok_or_else(|| PolarsError::ComputeError("the periods of a shift cannot be null".into()))
+}
+
+pub(super) fn shift(args: &mut [Series]) -> Result<Series> {
+    let periods = get_periods(&args[1])?;
+    Ok(args[0].shift(periods))
+}
+
+pub(super) fn shift_and_fill(args: &mut [Series]) -> Result<Series> {
     let s = &args[0];
     let fill_value = &args[1];
+    let periods = get_periods(&args[2])?;
 
-    let mask: BooleanChunked = if periods > 0 {
-        let len = s.len();
-        let mut bits = MutableBitmap::with_capacity(s.len());
-        bits.extend_constant(periods as usize, false);
-        bits.extend_constant(len.saturating_sub(periods as usize), true);
-        let mask = BooleanArray::from_data_default(bits.into(), None);
-        mask.into()
+    let len = s.len();
+    let n_shifted = std::cmp::min(periods.unsigned_abs() as usize, len);
+    let mut bits = MutableBitmap::with_capacity(len);
+    if periods > 0 {
+        bits.extend_constant(n_shifted, false);
+        bits.extend_constant(len - n_shifted, true);
     } else {
-        let length = s.len() as i64;
-        // periods is negative, so subtraction.
-        let tipping_point = std::cmp::max(length + periods, 0);
-        let mut bits = MutableBitmap::with_capacity(s.len());
-        bits.extend_constant(tipping_point as usize, true);
-        bits.extend_constant(-periods as usize, false);
-        let mask = BooleanArray::from_data_default(bits.into(), None);
-        mask.into()
-    };
+        bits.extend_constant(len - n_shifted, true);
+        bits.extend_constant(n_shifted, false);
+    }
+    let mask: BooleanChunked = BooleanArray::from_data_default(bits.into(), None).into();
 
     s.shift(periods).zip_with_same_type(&mask, fill_value)
 }
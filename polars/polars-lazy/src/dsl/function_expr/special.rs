@@ -0,0 +1,21 @@
+use super::*;
+
+pub(super) fn apply_special_function(s: &Series, function: SpecialFunction) -> Result<Series> {
+    use DataType::*;
+    let s = match (s.dtype(), function) {
+        (Float32, SpecialFunction::Erf) => s.f32().unwrap().apply(libm::erff).into_series(),
+        (Float32, SpecialFunction::LGamma) => s.f32().unwrap().apply(libm::lgammaf).into_series(),
+        (Float64, SpecialFunction::Erf) => s.f64().unwrap().apply(libm::erf).into_series(),
+        (Float64, SpecialFunction::LGamma) => s.f64().unwrap().apply(libm::lgamma).into_series(),
+        (dt, _) if dt.is_numeric() => {
+            let s = s.cast(&Float64)?;
+            return apply_special_function(&s, function);
+        }
+        (dt, _) => {
+            return Err(PolarsError::ComputeError(
+                format!("cannot use {:?} on Series of dtype: {:?}", function, dt).into(),
+            ))
+        }
+    };
+    Ok(s)
+}
@@ -2,7 +2,7 @@
 //!
 //! Functions on expressions that might be useful.
 //!
-#[cfg(feature = "arg_where")]
+#[cfg(any(feature = "arg_where", feature = "geo"))]
 use crate::dsl::function_expr::FunctionExpr;
 use crate::prelude::*;
 use crate::utils::has_wildcard;
@@ -554,7 +554,11 @@ pub fn duration(args: DurationArgs) -> Expr {
     .alias("duration")
 }
 
-/// Concat multiple
+/// Concat multiple `LazyFrame`s vertically.
+///
+/// The inputs are executed in parallel. If only a slice of the result is needed, the inputs
+/// are executed one after another and execution stops once enough rows are gathered.
+/// The result is rechunked to a single chunk if `rechunk` is set.
 pub fn concat<L: AsRef<[LazyFrame]>>(inputs: L, rechunk: bool) -> Result<LazyFrame> {
     let mut inputs = inputs.as_ref().to_vec();
     let lf = std::mem::take(
@@ -569,30 +573,21 @@ pub fn concat<L: AsRef<[LazyFrame]>>(inputs: L, rechunk: bool) -> Result<LazyFra
     for lf in &mut inputs[1..] {
         // ensure we enable file caching if any lf has it enabled
         opt_state.file_caching |= lf.opt_state.file_caching;
+        opt_state.low_memory |= lf.opt_state.low_memory;
         let lp = std::mem::take(&mut lf.logical_plan);
         lps.push(lp)
     }
 
     let lp = LogicalPlan::Union {
         inputs: lps,
-        options: Default::default(),
+        options: UnionOptions {
+            rechunk,
+            ..Default::default()
+        },
     };
     let mut lf = LazyFrame::from(lp);
     lf.opt_state = opt_state;
-
-    if rechunk {
-        Ok(lf.map(
-            |mut df: DataFrame| {
-                df.as_single_chunk_par();
-                Ok(df)
-            },
-            Some(AllowedOptimizations::default()),
-            None,
-            Some("RECHUNK"),
-        ))
-    } else {
-        Ok(lf)
-    }
+    Ok(lf)
 }
 
 /// Collect all `LazyFrame` computations.
@@ -927,3 +922,162 @@ pub fn arg_where<E: Into<Expr>>(condition: E) -> Expr {
         },
     }
 }
+
+#[cfg(feature = "geo")]
+#[cfg_attr(docsrs, doc(cfg(feature = "geo")))]
+/// Compute the great-circle distance in kilometers between the points `(lat1, lon1)` and
+/// `(lat2, lon2)`, given in degrees, with the haversine formula.
+pub fn haversine_distance(lat1: Expr, lon1: Expr, lat2: Expr, lon2: Expr) -> Expr {
+    lat1.map_many_private(
+        FunctionExpr::HaversineDistance,
+        &[lon1, lat2, lon2],
+        "haversine_distance",
+    )
+}
+
+#[cfg(feature = "geo")]
+#[cfg_attr(docsrs, doc(cfg(feature = "geo")))]
+/// Get the index of the first of `polygons` that contains the point `(x, y)`, e.g.
+/// `(longitude, latitude)`, or null if no polygon contains the point.
+pub fn point_in_polygon(x: Expr, y: Expr, polygons: Vec<Polygon>) -> Expr {
+    x.map_many_private(
+        FunctionExpr::PointInPolygon(polygons.into()),
+        &[y],
+        "point_in_polygon",
+    )
+}
+
+/// Build a column of random values of `length`, or of the height of the frame.
+#[cfg(feature = "random")]
+fn random_column<F>(length: Option<usize>, name: &'static str, dtype: DataType, sample: F) -> Expr
+where
+    F: Fn(usize) -> Result<Series> + Send + Sync + 'static,
+{
+    let length = match length {
+        Some(length) => lit(length as u64),
+        None => count(),
+    };
+    length
+        .map(
+            move |s| {
+                let length = s.get(0).extract::<usize>().ok_or_else(|| {
+                    PolarsError::ComputeError("could not determine the length".into())
+                })?;
+                sample(length)
+            },
+            GetOutput::from_type(dtype),
+        )
+        .with_fmt(name)
+        .alias(name)
+}
+
+#[cfg(feature = "random")]
+#[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+/// Sample `Float64` values uniformly from `[low, high)`.
+///
+/// The column has `length` values, or as many values as the frame has rows if `length` is
+/// `None`. The same `seed` gives the same values.
+pub fn rand_uniform(low: f64, high: f64, length: Option<usize>, seed: Option<u64>) -> Expr {
+    random_column(length, "rand_uniform", DataType::Float64, move |n| {
+        Float64Chunked::rand_uniform_seeded("rand_uniform", n, low, high, seed)
+            .map(|ca| ca.into_series())
+    })
+}
+
+#[cfg(feature = "random")]
+#[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+/// Sample `Float64` values from a normal distribution.
+///
+/// See [`rand_uniform`] for the length and the seed of the column.
+pub fn rand_normal(mean: f64, std_dev: f64, length: Option<usize>, seed: Option<u64>) -> Expr {
+    random_column(length, "rand_normal", DataType::Float64, move |n| {
+        Float64Chunked::rand_normal_seeded("rand_normal", n, mean, std_dev, seed)
+            .map(|ca| ca.into_series())
+    })
+}
+
+#[cfg(feature = "random")]
+#[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+/// Sample `Int64` values uniformly from `[low, high)`.
+///
+/// See [`rand_uniform`] for the length and the seed of the column.
+pub fn rand_int(low: i64, high: i64, length: Option<usize>, seed: Option<u64>) -> Expr {
+    random_column(length, "rand_int", DataType::Int64, move |n| {
+        Int64Chunked::rand_int("rand_int", n, low, high, seed).map(|ca| ca.into_series())
+    })
+}
+
+#[cfg(feature = "least_squares")]
+fn least_squares_expr<F>(
+    y: Expr,
+    x: Vec<Expr>,
+    fit_intercept: bool,
+    fmt_str: &'static str,
+    auto_explode: bool,
+    fit: F,
+) -> Expr
+where
+    F: Fn(&Series, &[Series]) -> Result<StructChunked> + Send + Sync + 'static,
+{
+    let mut input = Vec::with_capacity(x.len() + 1);
+    input.push(y);
+    input.extend(x);
+
+    let function = SpecialEq::new(Arc::new(move |s: &mut [Series]| {
+        fit(&s[0], &s[1..]).map(|ca| ca.into_series())
+    }) as Arc<dyn SeriesUdf>);
+
+    Expr::AnonymousFunction {
+        input,
+        function,
+        output_type: GetOutput::map_fields(move |fields| {
+            let mut coefficients = fields[1..]
+                .iter()
+                .map(|field| Field::new(field.name(), DataType::Float64))
+                .collect::<Vec<_>>();
+            if fit_intercept {
+                coefficients.push(Field::new("intercept", DataType::Float64));
+            }
+            Field::new(fields[0].name(), DataType::Struct(coefficients))
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ApplyGroups,
+            input_wildcard_expansion: false,
+            auto_explode,
+            fmt_str,
+        },
+    }
+}
+
+#[cfg(feature = "least_squares")]
+#[cfg_attr(docsrs, doc(cfg(feature = "least_squares")))]
+/// Fit `y` on the regressors `x` with ordinary least squares, with an intercept if
+/// `fit_intercept` is set. Rows with a null value are ignored.
+///
+/// The coefficients are returned as a struct with a field per regressor, named after the
+/// regressor, and an `intercept` field. In a groupby context the fit is done per group.
+pub fn least_squares(y: Expr, x: Vec<Expr>, fit_intercept: bool) -> Expr {
+    least_squares_expr(y, x, fit_intercept, "least_squares", true, move |y, x| {
+        y.least_squares(x, fit_intercept)
+    })
+}
+
+#[cfg(feature = "least_squares")]
+#[cfg_attr(docsrs, doc(cfg(feature = "least_squares")))]
+/// Fit `y` on the regressors `x` like [`least_squares`] on every window of `window_size`
+/// rows. Every row gets the coefficients of the window that ends at that row.
+pub fn rolling_least_squares(
+    y: Expr,
+    x: Vec<Expr>,
+    window_size: usize,
+    fit_intercept: bool,
+) -> Expr {
+    least_squares_expr(
+        y,
+        x,
+        fit_intercept,
+        "rolling_least_squares",
+        false,
+        move |y, x| y.rolling_least_squares(x, window_size, fit_intercept),
+    )
+}
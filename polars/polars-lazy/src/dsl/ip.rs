@@ -0,0 +1,55 @@
+use super::*;
+use polars_ops::prelude::{ipv4_from_int, Utf8NameSpaceImpl};
+
+/// Specialized expressions for IP addresses stored as strings.
+pub struct IpNameSpace(pub(crate) Expr);
+
+impl IpNameSpace {
+    /// Parse IPv4 addresses to their `UInt32` representation. Values that are not IPv4
+    /// addresses become null, including IPv6 addresses, which don't fit in a `UInt32`.
+    pub fn to_int(self) -> Expr {
+        let function = |s: Series| {
+            let ca = s.utf8()?;
+            Ok(ca.ip_to_int().into_series())
+        };
+        self.0
+            .map(function, GetOutput::from_type(DataType::UInt32))
+            .with_fmt("ip.to_int")
+    }
+
+    /// Format the `UInt32` representation of IPv4 addresses in dotted decimal notation.
+    pub fn from_int(self) -> Expr {
+        let function = |s: Series| {
+            let s = s.cast(&DataType::UInt32)?;
+            Ok(ipv4_from_int(s.u32()?).into_series())
+        };
+        self.0
+            .map(function, GetOutput::from_type(DataType::Utf8))
+            .with_fmt("ip.from_int")
+    }
+
+    /// Check if the IPv4 or IPv6 addresses lie in the network `cidr`, e.g. `"10.0.0.0/8"`.
+    /// Values that are not IP addresses become null.
+    pub fn is_in_subnet(self, cidr: &str) -> Expr {
+        let cidr = cidr.to_string();
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            ca.ip_is_in_subnet(&cidr).map(|ca| ca.into_series())
+        };
+        self.0
+            .map(function, GetOutput::from_type(DataType::Boolean))
+            .with_fmt("ip.is_in_subnet")
+    }
+
+    /// Format the IPv4 or IPv6 addresses in their canonical form. Values that are not IP
+    /// addresses become null.
+    pub fn normalize(self) -> Expr {
+        let function = |s: Series| {
+            let ca = s.utf8()?;
+            Ok(ca.ip_normalize().into_series())
+        };
+        self.0
+            .map(function, GetOutput::from_type(DataType::Utf8))
+            .with_fmt("ip.normalize")
+    }
+}
@@ -115,6 +115,19 @@ impl ListNameSpace {
             .with_fmt("arr.unique")
     }
 
+    /// Concatenate the sublists of every list, turning `List(List(T))` into `List(T)`.
+    pub fn flatten(self) -> Expr {
+        self.0
+            .map(
+                move |s| Ok(s.list()?.lst_flatten()?.into_series()),
+                GetOutput::map_dtype(|dt| match dt {
+                    DataType::List(inner) => *inner.clone(),
+                    dt => dt.clone(),
+                }),
+            )
+            .with_fmt("arr.flatten")
+    }
+
     /// Get items in every sublist by index.
     pub fn get(self, index: i64) -> Expr {
         self.0.map(
@@ -176,7 +189,12 @@ impl ListNameSpace {
         self.0
             .map(
                 move |s| Ok(s.list()?.lst_diff(n, null_behavior).into_series()),
-                GetOutput::same_type(),
+                GetOutput::map_dtype(|dt| match dt {
+                    DataType::List(inner) => {
+                        DataType::List(Box::new(polars_core::series::ops::diff::diff_dtype(inner)))
+                    }
+                    dt => dt.clone(),
+                }),
             )
             .with_fmt("arr.diff")
     }
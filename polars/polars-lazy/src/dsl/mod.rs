@@ -11,6 +11,8 @@ mod from;
 pub(crate) mod function_expr;
 #[cfg(feature = "compile")]
 mod functions;
+#[cfg(feature = "ip")]
+pub mod ip;
 #[cfg(feature = "list")]
 mod list;
 mod options;
@@ -32,22 +34,26 @@ use polars_core::prelude::*;
 use std::fmt::Debug;
 use std::{
     ops::{Add, Div, Mul, Rem, Sub},
+    sync::atomic::{AtomicUsize, Ordering},
     sync::Arc,
 };
 // reexport the lazy method
 pub use crate::frame::IntoLazy;
 pub use crate::logical_plan::lit;
 pub use expr::*;
+pub use function_expr::{register_plugin, ExprPlugin, PluginFunction};
 pub use functions::*;
 pub use options::*;
 
 use crate::dsl::function_expr::FunctionExpr;
 
+#[cfg(feature = "special_functions")]
+use crate::dsl::function_expr::SpecialFunction;
 #[cfg(feature = "trigonometry")]
 use crate::dsl::function_expr::TrigonometricFunction;
 
 #[cfg(feature = "diff")]
-use polars_core::series::ops::NullBehavior;
+use polars_core::series::ops::{diff::diff_dtype, NullBehavior};
 use polars_core::series::IsSorted;
 use polars_core::utils::{get_supertype, NoNull};
 use polars_ops::prelude::SeriesOps;
@@ -191,6 +197,39 @@ pub fn ternary_expr(predicate: Expr, truthy: Expr, falsy: Expr) -> Expr {
     }
 }
 
+/// The periods of a shift if they are given by an integer literal, so that the shift doesn't
+/// have to evaluate them.
+fn literal_periods(periods: &Expr) -> Option<i64> {
+    match periods {
+        Expr::Literal(LiteralValue::Int32(v)) => Some(*v as i64),
+        Expr::Literal(LiteralValue::Int64(v)) => Some(*v),
+        Expr::Literal(LiteralValue::UInt32(v)) => Some(*v as i64),
+        Expr::Literal(LiteralValue::UInt64(v)) => i64::try_from(*v).ok(),
+        _ => None,
+    }
+}
+
+/// The peak kernels panic on non-numeric data, so check the dtype before calling them.
+fn check_peaks_dtype(s: &Series) -> Result<()> {
+    if s.to_physical_repr().dtype().is_numeric() {
+        Ok(())
+    } else {
+        Err(PolarsError::InvalidOperation(
+            format!("cannot detect peaks in values of dtype {:?}", s.dtype()).into(),
+        ))
+    }
+}
+
+/// Reduce `s` with `agg`, unless `s` has a `NaN` value, which is then the result.
+fn nan_propagating(s: Series, agg: fn(&Series) -> Series) -> Result<Series> {
+    let is_nan = s.is_nan()?;
+    if is_nan.any() {
+        Ok(s.filter(&is_nan)?.head(Some(1)))
+    } else {
+        Ok(agg(&s))
+    }
+}
+
 impl Expr {
     /// Modify the Options passed to the `Function` node.
     pub(crate) fn with_function_options<F>(self, func: F) -> Expr
@@ -251,6 +290,23 @@ impl Expr {
         binary_expr(self, Operator::NotEq, other.into())
     }
 
+    /// Compare `Expr` with other `Expr` on equality, where two nulls are equal and a null is
+    /// not equal to any value.
+    pub fn eq_missing<E: Into<Expr>>(self, other: E) -> Expr {
+        self.map_many(
+            |s| s[0].eq_missing(&s[1]).map(|ca| ca.into_series()),
+            &[other.into()],
+            GetOutput::from_type(DataType::Boolean),
+        )
+        .with_fmt("eq_missing")
+    }
+
+    /// Compare `Expr` with other `Expr` on non-equality, where two nulls are equal and a null is
+    /// not equal to any value.
+    pub fn ne_missing<E: Into<Expr>>(self, other: E) -> Expr {
+        self.eq_missing(other).not()
+    }
+
     /// Check if `Expr` < `Expr`
     pub fn lt<E: Into<Expr>>(self, other: E) -> Expr {
         binary_expr(self, Operator::Lt, other.into())
@@ -329,6 +385,36 @@ impl Expr {
         AggExpr::Max(Box::new(self)).into()
     }
 
+    /// Get the minimal value, like [`min`](Expr::min), but propagate `NaN`: the result is
+    /// `NaN` if the values have a `NaN`. This is a function that is applied per group, not an
+    /// aggregation kernel, so in a groupby it is slower than [`min`](Expr::min).
+    pub fn nan_min(self) -> Self {
+        self.apply(
+            |s| nan_propagating(s, |s| s.min_as_series()),
+            GetOutput::same_type(),
+        )
+        .with_function_options(|mut opt| {
+            opt.fmt_str = "nan_min";
+            opt.auto_explode = true;
+            opt
+        })
+    }
+
+    /// Get the maximum value, like [`max`](Expr::max), but propagate `NaN`: the result is
+    /// `NaN` if the values have a `NaN`. This is a function that is applied per group, not an
+    /// aggregation kernel, so in a groupby it is slower than [`max`](Expr::max).
+    pub fn nan_max(self) -> Self {
+        self.apply(
+            |s| nan_propagating(s, |s| s.max_as_series()),
+            GetOutput::same_type(),
+        )
+        .with_function_options(|mut opt| {
+            opt.fmt_str = "nan_max";
+            opt.auto_explode = true;
+            opt
+        })
+    }
+
     /// Reduce groups to the mean value.
     pub fn mean(self) -> Self {
         AggExpr::Mean(Box::new(self)).into()
@@ -379,12 +465,15 @@ impl Expr {
         AggExpr::AggGroups(Box::new(self)).into()
     }
 
-    /// Alias for explode
+    /// Alias for explode. On a column of nested lists this removes one level of nesting, use
+    /// [`list::ListNameSpace::flatten`] to flatten the nested lists of every row instead.
     pub fn flatten(self) -> Self {
         self.explode()
     }
 
     /// Explode the utf8/ list column
+    ///
+    /// In a groupby context all the lists of a group are exploded into the values of that group.
     pub fn explode(self) -> Self {
         let has_filter = has_expr(&self, |e| matches!(e, Expr::Filter { .. }));
 
@@ -461,20 +550,33 @@ impl Expr {
         self.slice(lit(-(len as i64)), lit(len as u64))
     }
 
-    /// Get unique values of this expression.
+    /// Get unique values of this expression. The order of the values is unspecified, unless
+    /// the [deterministic mode](polars_core::config::deterministic) is on, see [`Expr::unique_stable`].
     pub fn unique(self) -> Self {
-        self.apply(|s: Series| s.unique(), GetOutput::same_type())
-            .with_fmt("unique")
+        self.apply(
+            |s: Series| {
+                if polars_core::config::deterministic() {
+                    s.unique_stable()
+                } else {
+                    s.unique()
+                }
+            },
+            GetOutput::same_type(),
+        )
+        .with_fmt("unique")
     }
 
     /// Get unique values of this expression, while maintaining order.
     /// This requires more work than [`Expr::unique`].
+    ///
+    /// The values are guaranteed to be in the order of their first occurrence.
     pub fn unique_stable(self) -> Self {
         self.apply(|s: Series| s.unique_stable(), GetOutput::same_type())
             .with_fmt("unique_stable")
     }
 
     /// Get the first index of unique values of this expression.
+    /// The indices are sorted, so they are in the order in which the values first occur.
     pub fn arg_unique(self) -> Self {
         self.apply(
             |s: Series| s.arg_unique().map(|ca| ca.into_series()),
@@ -542,7 +644,7 @@ impl Expr {
     }
 
     /// Cast expression to another data type.
-    /// Throws an error if conversion had overflows
+    /// Throws an error that names the first value that overflows or could not be parsed.
     pub fn strict_cast(self, data_type: DataType) -> Self {
         Expr::Cast {
             expr: Box::new(self),
@@ -560,6 +662,16 @@ impl Expr {
         }
     }
 
+    /// Cast expression to another data type, where `options` determines what happens to the
+    /// values that cannot be cast.
+    pub fn cast_with_options(self, data_type: DataType, options: CastOptions) -> Self {
+        Expr::Cast {
+            expr: Box::new(self),
+            data_type,
+            strict: matches!(options, CastOptions::Strict),
+        }
+    }
+
     /// Take the values by idx.
     pub fn take<E: Into<Expr>>(self, idx: E) -> Self {
         Expr::Take {
@@ -711,6 +823,9 @@ impl Expr {
     ///
     /// * `map` should be used for operations that are independent of groups, e.g. `multiply * 2`, or `raise to the power`
     /// * `apply` should be used for operations that work on a group of data. e.g. `sum`, `count`, etc.
+    ///
+    /// Errors of the function are propagated, except for [`PolarsError::NoData`], which
+    /// turns the output of that group into a null value.
     pub fn apply<F>(self, function: F, output_type: GetOutput) -> Self
     where
         F: Fn(Series) -> Result<Series> + 'static + Send + Sync,
@@ -852,18 +967,27 @@ impl Expr {
     }
 
     /// Shift the values in the array by some period. See [the eager implementation](polars_core::series::SeriesTrait::shift).
-    pub fn shift(self, periods: i64) -> Self {
-        Expr::Shift {
-            input: Box::new(self),
-            periods,
+    ///
+    /// The `periods` can be an expression that yields a single value, e.g. `col("n").first()`,
+    /// which is evaluated per group in a groupby or window context.
+    pub fn shift<E: Into<Expr>>(self, periods: E) -> Self {
+        let periods = periods.into();
+        match literal_periods(&periods) {
+            Some(periods) => Expr::Shift {
+                input: Box::new(self),
+                periods,
+            },
+            None => self.apply_many_private(FunctionExpr::Shift, &[periods], "shift", false),
         }
     }
 
     /// Shift the values in the array by some period and fill the resulting empty values.
-    pub fn shift_and_fill<E: Into<Expr>>(self, periods: i64, fill_value: E) -> Self {
+    ///
+    /// The `periods` can be an expression that yields a single value, see [`Expr::shift`].
+    pub fn shift_and_fill<E: Into<Expr>, IE: Into<Expr>>(self, periods: E, fill_value: IE) -> Self {
         self.apply_many_private(
-            FunctionExpr::ShiftAndFill { periods },
-            &[fill_value.into()],
+            FunctionExpr::ShiftAndFill,
+            &[fill_value.into(), periods.into()],
             "shift_and_fill",
             false,
         )
@@ -973,6 +1097,29 @@ impl Expr {
             .with_fmt("round")
     }
 
+    /// Round underlying floating point array to given decimal numbers, where `mode` determines
+    /// the direction of the rounding.
+    #[cfg(feature = "round_series")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
+    pub fn round_with_mode(self, decimals: u32, mode: RoundMode) -> Self {
+        self.map(
+            move |s: Series| s.round_with_mode(decimals, mode),
+            GetOutput::same_type(),
+        )
+        .with_fmt("round")
+    }
+
+    /// Round underlying floating point array to the given number of significant figures.
+    #[cfg(feature = "round_series")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
+    pub fn round_sig_figs(self, significant_figures: u32) -> Self {
+        self.map(
+            move |s: Series| s.round_sig_figs(significant_figures),
+            GetOutput::same_type(),
+        )
+        .with_fmt("round_sig_figs")
+    }
+
     /// Floor underlying floating point array to the lowest integers smaller or equal to the float value.
     #[cfg(feature = "round_series")]
     #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
@@ -997,6 +1144,22 @@ impl Expr {
             .with_fmt("clip")
     }
 
+    /// Clip underlying values to a lower boundary.
+    #[cfg(feature = "round_series")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
+    pub fn clip_min(self, min: f64) -> Self {
+        self.map(move |s: Series| s.clip_min(min), GetOutput::same_type())
+            .with_fmt("clip_min")
+    }
+
+    /// Clip underlying values to an upper boundary.
+    #[cfg(feature = "round_series")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "round_series")))]
+    pub fn clip_max(self, max: f64) -> Self {
+        self.map(move |s: Series| s.clip_max(max), GetOutput::same_type())
+            .with_fmt("clip_max")
+    }
+
     /// Convert all values to their absolute/positive value.
     #[cfg(feature = "abs")]
     #[cfg_attr(docsrs, doc(cfg(feature = "abs")))]
@@ -1009,6 +1172,11 @@ impl Expr {
     /// This is similar to a groupby + aggregation + self join.
     /// Or similar to [window functions in Postgres](https://www.postgresql.org/docs/9.1/tutorial-window.html).
     ///
+    /// The partitions are determined by one or more expressions, which may compute the keys,
+    /// e.g. `over([col("date").dt().year(), col("store")])`; no temporary columns are needed.
+    /// The keys are evaluated once for all window functions in a projection that use the
+    /// same partition expressions.
+    ///
     /// # Example
     ///
     /// ``` rust
@@ -1127,6 +1295,59 @@ impl Expr {
         AggExpr::Var(Box::new(self), ddof).into()
     }
 
+    /// Let an aggregation return null if any of its input values (in a group) is null.
+    ///
+    /// By default the aggregations skip the null values, e.g. `col("a").mean()` is the mean of
+    /// the valid values of `a`. An aggregation wrapped in an alias, rename or cast is handled
+    /// as well. Expressions that are not an aggregation propagate the nulls already and are
+    /// returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// let df = df!("g" => [1, 1, 2], "a" => [Some(1.0), None, Some(3.0)])?;
+    /// let out = df
+    ///     .lazy()
+    ///     .groupby_stable([col("g")])
+    ///     .agg([
+    ///         col("a").mean().alias("skip"),
+    ///         col("a").mean().null_on_null().alias("propagate"),
+    ///     ])
+    ///     .collect()?;
+    /// assert_eq!(Vec::from(out.column("skip")?.f64()?), &[Some(1.0), Some(3.0)]);
+    /// assert_eq!(Vec::from(out.column("propagate")?.f64()?), &[None, Some(3.0)]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn null_on_null(self) -> Self {
+        match self {
+            Expr::Agg(ref agg) => {
+                let input = agg.as_ref().clone();
+                // the aggregation is the `then` branch, so that it determines the output name
+                when(input.null_count().eq(lit(0)))
+                    .then(self)
+                    .otherwise(Null {}.lit())
+            }
+            Expr::Alias(expr, name) => Expr::Alias(Box::new(expr.null_on_null()), name),
+            Expr::KeepName(expr) => Expr::KeepName(Box::new(expr.null_on_null())),
+            Expr::RenameAlias { function, expr } => Expr::RenameAlias {
+                function,
+                expr: Box::new(expr.null_on_null()),
+            },
+            Expr::Cast {
+                expr,
+                data_type,
+                strict,
+            } => Expr::Cast {
+                expr: Box::new(expr.null_on_null()),
+                data_type,
+                strict,
+            },
+            _ => self,
+        }
+    }
+
     /// Get a mask of duplicated values
     #[allow(clippy::wrong_self_convention)]
     pub fn is_duplicated(self) -> Self {
@@ -1168,6 +1389,49 @@ impl Expr {
         }
     }
 
+    #[cfg(feature = "checked_arithmetic")]
+    fn arithmetic_with_overflow(
+        self,
+        other: Expr,
+        op: ArithmeticOp,
+        policy: OverflowPolicy,
+    ) -> Self {
+        Expr::Function {
+            input: vec![self, other],
+            function: FunctionExpr::ArithmeticWithOverflow { op, policy },
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::ApplyFlat,
+                input_wildcard_expansion: false,
+                auto_explode: false,
+                fmt_str: "arithmetic_with_overflow",
+            },
+        }
+    }
+
+    /// Add `other`, where `policy` determines what happens to integer values that overflow.
+    /// The `+` operator wraps around.
+    #[cfg(feature = "checked_arithmetic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "checked_arithmetic")))]
+    pub fn checked_add<E: Into<Expr>>(self, other: E, policy: OverflowPolicy) -> Self {
+        self.arithmetic_with_overflow(other.into(), ArithmeticOp::Add, policy)
+    }
+
+    /// Subtract `other`, where `policy` determines what happens to integer values that
+    /// overflow. The `-` operator wraps around.
+    #[cfg(feature = "checked_arithmetic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "checked_arithmetic")))]
+    pub fn checked_sub<E: Into<Expr>>(self, other: E, policy: OverflowPolicy) -> Self {
+        self.arithmetic_with_overflow(other.into(), ArithmeticOp::Sub, policy)
+    }
+
+    /// Multiply with `other`, where `policy` determines what happens to integer values that
+    /// overflow. The `*` operator wraps around.
+    #[cfg(feature = "checked_arithmetic")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "checked_arithmetic")))]
+    pub fn checked_mul<E: Into<Expr>>(self, other: E, policy: OverflowPolicy) -> Self {
+        self.arithmetic_with_overflow(other.into(), ArithmeticOp::Mul, policy)
+    }
+
     /// Compute the sine of the given expression
     #[cfg(feature = "trigonometry")]
     pub fn sin(self) -> Self {
@@ -1363,6 +1627,37 @@ impl Expr {
         }
     }
 
+    /// Compute the error function of the given expression
+    #[cfg(feature = "special_functions")]
+    pub fn erf(self) -> Self {
+        Expr::Function {
+            input: vec![self],
+            function: FunctionExpr::SpecialFunction(SpecialFunction::Erf),
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::ApplyFlat,
+                input_wildcard_expansion: false,
+                auto_explode: false,
+                fmt_str: "erf",
+            },
+        }
+    }
+
+    /// Compute the natural logarithm of the absolute value of the gamma function of the given
+    /// expression
+    #[cfg(feature = "special_functions")]
+    pub fn lgamma(self) -> Self {
+        Expr::Function {
+            input: vec![self],
+            function: FunctionExpr::SpecialFunction(SpecialFunction::LGamma),
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::ApplyFlat,
+                input_wildcard_expansion: false,
+                auto_explode: false,
+                fmt_str: "lgamma",
+            },
+        }
+    }
+
     /// Filter a single column
     /// Should be used in aggregation context. If you want to filter on a DataFrame level, use
     /// [LazyFrame::filter](LazyFrame::filter)
@@ -1377,6 +1672,10 @@ impl Expr {
     }
 
     /// Check if the values of the left expression are in the lists of the right expr.
+    ///
+    /// The right expression can be a (literal) `Series` of values, a `List` column that is
+    /// checked row-wise, or a single list that is checked for every row. The dtypes are
+    /// promoted to their supertype.
     #[allow(clippy::wrong_self_convention)]
     #[cfg(feature = "is_in")]
     #[cfg_attr(docsrs, doc(cfg(feature = "is_in")))]
@@ -1436,7 +1735,7 @@ impl Expr {
     #[cfg(feature = "repeat_by")]
     #[cfg_attr(docsrs, doc(cfg(feature = "repeat_by")))]
     /// Repeat the column `n` times, where `n` is determined by the values in `by`.
-    /// This yields an `Expr` of dtype `List`
+    /// This yields an `Expr` of dtype `List`. A `by` of length 1, e.g. a literal, is broadcasted.
     pub fn repeat_by<E: Into<Expr>>(self, by: E) -> Expr {
         self.repeat_by_impl(by.into())
     }
@@ -1453,6 +1752,50 @@ impl Expr {
         .with_fmt("is_first")
     }
 
+    #[cfg(feature = "is_first")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "is_first")))]
+    #[allow(clippy::wrong_self_convention)]
+    /// Get a mask of the first occurrence of every distinct value. Alias for [`Expr::is_first`].
+    pub fn is_first_distinct(self) -> Expr {
+        self.is_first()
+    }
+
+    #[cfg(feature = "is_last")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "is_last")))]
+    #[allow(clippy::wrong_self_convention)]
+    /// Get a mask of the last occurrence of every distinct value.
+    pub fn is_last_distinct(self) -> Expr {
+        self.apply(
+            |s| s.is_last().map(|ca| ca.into_series()),
+            GetOutput::from_type(DataType::Boolean),
+        )
+        .with_fmt("is_last_distinct")
+    }
+
+    /// Get a mask of the local maxima: the values that are larger than both of their neighbors.
+    pub fn peak_max(self) -> Expr {
+        self.apply(
+            |s| {
+                check_peaks_dtype(&s)?;
+                Ok(s.peak_max().into_series())
+            },
+            GetOutput::from_type(DataType::Boolean),
+        )
+        .with_fmt("peak_max")
+    }
+
+    /// Get a mask of the local minima: the values that are smaller than both of their neighbors.
+    pub fn peak_min(self) -> Expr {
+        self.apply(
+            |s| {
+                check_peaks_dtype(&s)?;
+                Ok(s.peak_min().into_series())
+            },
+            GetOutput::from_type(DataType::Boolean),
+        )
+        .with_fmt("peak_min")
+    }
+
     #[cfg(feature = "dot_product")]
     fn dot_impl(self, other: Expr) -> Expr {
         let function = |s: &mut [Series]| Ok((&s[0] * &s[1]).sum_as_series());
@@ -1478,6 +1821,39 @@ impl Expr {
         .with_fmt("mode")
     }
 
+    /// Evaluate this expression once per operation and share the result between all its uses,
+    /// e.g. in multiple expressions of a `select` or `with_columns`, or in multiple branches of
+    /// a `when/then/otherwise`. Use it for an expensive expression, like a regex extraction or
+    /// a user defined function, that is used more than once.
+    ///
+    /// Only the clones of the returned expression share its result. In an aggregation the
+    /// expression is evaluated on every use.
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: LazyFrame) -> LazyFrame {
+    ///     // a user defined function that is expensive to evaluate
+    ///     let udf = col("a")
+    ///         .map(|s| s.cast(&DataType::Float64), GetOutput::from_type(DataType::Float64))
+    ///         .cache();
+    ///     df.select([
+    ///         when(udf.clone().gt(lit(10.0)))
+    ///             .then(udf.clone())
+    ///             .otherwise(lit(0.0)),
+    ///         (udf + lit(1.0)).alias("b"),
+    ///     ])
+    /// }
+    /// ```
+    pub fn cache(self) -> Expr {
+        static CACHE_ID: AtomicUsize = AtomicUsize::new(0);
+        Expr::Cache {
+            input: Box::new(self),
+            id: CACHE_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
     /// Keep the original root name
     ///
     /// ```
@@ -1814,16 +2190,21 @@ impl Expr {
 
     #[cfg(feature = "diff")]
     #[cfg_attr(docsrs, doc(cfg(feature = "diff")))]
+    /// Compute the difference between every value and the value `n` slots before it, see
+    /// [`Series::diff`]. In a groupby or window context the differences are computed per group.
     pub fn diff(self, n: usize, null_behavior: NullBehavior) -> Expr {
         self.apply(
             move |s| Ok(s.diff(n, null_behavior)),
-            GetOutput::same_type(),
+            GetOutput::map_dtype(diff_dtype),
         )
         .with_fmt("diff")
     }
 
     #[cfg(feature = "pct_change")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pct_change")))]
+    /// Compute the relative change between every value and the value `n` slots before it. Nulls
+    /// are forward filled first. In a groupby or window context the changes are computed per
+    /// group.
     pub fn pct_change(self, n: usize) -> Expr {
         use DataType::*;
         self.apply(
@@ -1944,6 +2325,8 @@ impl Expr {
         .with_fmt("lower_bound")
     }
 
+    /// Reshape the values to a List column of `[rows, cols]`, or back to the flat values with
+    /// `[len]`. See [`Series::reshape`].
     pub fn reshape(self, dims: &[i64]) -> Self {
         let dims = dims.to_vec();
         let output_type = if dims.len() == 1 {
@@ -2143,6 +2526,235 @@ impl Expr {
         .with_fmt("unique_counts")
     }
 
+    #[cfg(feature = "to_dummies")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "to_dummies")))]
+    #[allow(clippy::wrong_self_convention)]
+    /// One-hot encode the values in a `Struct` with a `UInt8` dummy field for every category in
+    /// `categories`, in that order. The categories are the values formatted as strings and
+    /// `"null"` for the null values; a value of another category is zero in all fields. The
+    /// categories determine the fields, so the schema is known before the data is read.
+    /// [`DummiesOptions::drop_first`] drops the field of the first category.
+    ///
+    /// Use [`LazyFrame::unnest`] to get the dummies as columns.
+    pub fn to_dummies(self, categories: &[&str], options: DummiesOptions) -> Self {
+        let mut categories = categories.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        if options.drop_first && !categories.is_empty() {
+            categories.remove(0);
+        }
+        let all_dummies = DummiesOptions {
+            drop_first: false,
+            ..options.clone()
+        };
+        let field_names = Arc::new(move |name: &str| {
+            let prefix = options.prefix.as_deref().unwrap_or(name);
+            categories
+                .iter()
+                .map(|c| format!("{}{}{}", prefix, options.separator, c))
+                .collect::<Vec<_>>()
+        });
+        let output_names = field_names.clone();
+        self.map(
+            move |s| {
+                let df = s.to_ops().to_dummies_with_options(&all_dummies)?;
+                let fields = field_names(s.name())
+                    .iter()
+                    .map(|name| match df.column(name) {
+                        Ok(dummy) => dummy.clone(),
+                        Err(_) => UInt8Chunked::full(name, 0, s.len()).into_series(),
+                    })
+                    .collect::<Vec<_>>();
+                StructChunked::new(s.name(), &fields).map(|ca| ca.into_series())
+            },
+            GetOutput::map_field(move |fld| {
+                let fields = output_names(fld.name())
+                    .iter()
+                    .map(|name| Field::new(name, DataType::UInt8))
+                    .collect();
+                Field::new(fld.name(), DataType::Struct(fields))
+            }),
+        )
+        .with_fmt("to_dummies")
+    }
+
+    #[cfg(feature = "map_dict")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "map_dict")))]
+    /// Remap the values with `mapping`, a `DataFrame` with a column of unique keys and a column of
+    /// values. See [`mapping_from_pairs`] to create it from a `HashMap`. The mapping is hash joined
+    /// on the column, `default` determines what happens to values without a key in the mapping.
+    pub fn map_dict(self, mapping: DataFrame, default: MapDictDefault) -> Self {
+        let values_dtype = mapping
+            .get_columns()
+            .get(1)
+            .map(|s| s.dtype().clone())
+            .unwrap_or(DataType::Null);
+        self.map(
+            move |s| s.map_dict(&mapping, default),
+            GetOutput::map_dtype(move |dt| match default {
+                MapDictDefault::KeepOriginal => {
+                    get_supertype(dt, &values_dtype).unwrap_or_else(|_| values_dtype.clone())
+                }
+                _ => values_dtype.clone(),
+            }),
+        )
+        .with_fmt("map_dict")
+    }
+
+    #[cfg(feature = "replace")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "replace")))]
+    /// Replace the values that are in `old` by the value at the same index of `new`, other values
+    /// are kept. `new` may have a different dtype, the output has the supertype of both.
+    pub fn replace<E: Into<Expr>>(self, old: E, new: E) -> Self {
+        self.map_many(
+            |s| s[0].replace(&s[1], &s[2]),
+            &[old.into(), new.into()],
+            GetOutput::map_dtypes(|dtypes| {
+                get_supertype(dtypes[0], dtypes[2]).unwrap_or_else(|_| dtypes[2].clone())
+            }),
+        )
+        .with_fmt("replace")
+    }
+
+    #[cfg(feature = "replace")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "replace")))]
+    /// Replace every value by the value of `new` at its index in `old`, and raise an error if a
+    /// value is not in `old`. The output has the dtype of `new`, or `return_dtype` if given.
+    pub fn replace_strict<E: Into<Expr>>(
+        self,
+        old: E,
+        new: E,
+        return_dtype: Option<DataType>,
+    ) -> Self {
+        let output_type = match &return_dtype {
+            Some(dtype) => GetOutput::from_type(dtype.clone()),
+            None => GetOutput::map_dtypes(|dtypes| dtypes[2].clone()),
+        };
+        self.map_many(
+            move |s| s[0].replace_strict(&s[1], &s[2], return_dtype.as_ref()),
+            &[old.into(), new.into()],
+            output_type,
+        )
+        .with_fmt("replace_strict")
+    }
+
+    #[cfg(feature = "anonymize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "anonymize")))]
+    /// Replace the values by a hash keyed by `salt`. The same value and salt give the same
+    /// `UInt64` token in every run, so tokenized columns can still be joined.
+    pub fn hash_salted(self, salt: &str) -> Self {
+        let salt = salt.to_string();
+        self.map(
+            move |s| s.hash_salted(&salt).map(|ca| ca.into_series()),
+            GetOutput::from_type(DataType::UInt64),
+        )
+        .with_fmt("hash_salted")
+    }
+
+    #[cfg(feature = "anonymize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "anonymize")))]
+    /// Replace the characters of the values by `*`, except for the first `keep_first` and
+    /// last `keep_last` characters. Shorter values are masked completely.
+    pub fn mask_chars(self, keep_first: usize, keep_last: usize) -> Self {
+        self.map(
+            move |s| {
+                s.mask_chars(keep_first, keep_last, '*')
+                    .map(|ca| ca.into_series())
+            },
+            GetOutput::from_type(DataType::Utf8),
+        )
+        .with_fmt("mask_chars")
+    }
+
+    #[cfg(feature = "anonymize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "anonymize")))]
+    /// Generalize numeric values to the lower bound of their bucket of size `width`,
+    /// e.g. to report ages in buckets of 10 years.
+    pub fn generalize(self, width: f64) -> Self {
+        self.map(move |s| s.generalize(width), GetOutput::same_type())
+            .with_fmt("generalize")
+    }
+
+    #[cfg(feature = "rle")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rle")))]
+    /// Encode the runs of equal consecutive values as a struct with the fields `value` and
+    /// `length`. The output has one row per run. In a groupby context the runs of every group
+    /// are encoded separately.
+    pub fn rle(self) -> Self {
+        self.apply(
+            |s| s.rle().map(|ca| ca.into_series()),
+            GetOutput::map_dtype(|dt| {
+                DataType::Struct(vec![
+                    Field::new("value", dt.clone()),
+                    Field::new("length", IDX_DTYPE),
+                ])
+            }),
+        )
+        .with_fmt("rle")
+    }
+
+    #[cfg(feature = "rle")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rle")))]
+    /// Assign an id to every run of equal consecutive values, e.g. to detect where a value
+    /// changes. The ids count up from `0`.
+    pub fn rle_id(self) -> Self {
+        self.map(
+            |s| s.rle_id().map(|ca| ca.into_series()),
+            GetOutput::from_type(IDX_DTYPE),
+        )
+        .with_fmt("rle_id")
+    }
+
+    #[cfg(feature = "weighted")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "weighted")))]
+    /// Compute the mean weighted by `weights`. Rows where the value or the weight is null
+    /// are ignored. In a groupby context the mean is computed per group.
+    pub fn weighted_mean<E: Into<Expr>>(self, weights: E) -> Self {
+        self.apply_many(
+            |s| {
+                let mean = polars_ops::prelude::weighted_mean(&s[0], &s[1])?;
+                Ok(Series::new(s[0].name(), &[mean]))
+            },
+            &[weights.into()],
+            GetOutput::from_type(DataType::Float64),
+        )
+        .with_fmt("weighted_mean")
+    }
+
+    #[cfg(feature = "weighted")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "weighted")))]
+    /// Compute the `quantile` weighted by `weights`. Rows where the value or the weight is
+    /// null are ignored. In a groupby context the quantile is computed per group.
+    pub fn weighted_quantile<E: Into<Expr>>(self, weights: E, quantile: f64) -> Self {
+        self.apply_many(
+            move |s| {
+                let q = polars_ops::prelude::weighted_quantile(&s[0], &s[1], quantile)?;
+                Ok(Series::new(s[0].name(), &[q]))
+            },
+            &[weights.into()],
+            GetOutput::from_type(DataType::Float64),
+        )
+        .with_fmt("weighted_quantile")
+    }
+
+    #[cfg(feature = "hist")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hist")))]
+    /// Count the values per bin, given by the `bins` edges or by a `bin_count` of bins of
+    /// equal width. The output is a struct with the fields `lower`, `upper` and `count`, and
+    /// has one row per bin. In a groupby context the histogram is computed per group.
+    pub fn hist(self, bins: Option<Vec<f64>>, bin_count: Option<usize>) -> Self {
+        self.apply(
+            move |s| {
+                s.hist(bins.as_deref(), bin_count)
+                    .map(|ca| ca.into_series())
+            },
+            GetOutput::from_type(DataType::Struct(vec![
+                Field::new("lower", DataType::Float64),
+                Field::new("upper", DataType::Float64),
+                Field::new("count", IDX_DTYPE),
+            ])),
+        )
+        .with_fmt("hist")
+    }
+
     #[cfg(feature = "log")]
     #[cfg_attr(docsrs, doc(cfg(feature = "log")))]
     /// Compute the logarithm to a given base
@@ -2174,7 +2786,41 @@ impl Expr {
                 }
             }),
         )
-        .with_fmt("log")
+        .with_fmt("exp")
+    }
+
+    #[cfg(feature = "log")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+    /// Compute the natural logarithm of one plus the values, accurate for values close to zero
+    pub fn log1p(self) -> Self {
+        self.map(
+            move |s| Ok(s.log1p()),
+            GetOutput::map_dtype(|dt| {
+                if matches!(dt, DataType::Float32) {
+                    DataType::Float32
+                } else {
+                    DataType::Float64
+                }
+            }),
+        )
+        .with_fmt("log1p")
+    }
+
+    #[cfg(feature = "log")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+    /// Calculate `exp(x) - 1` of all elements, accurate for values close to zero
+    pub fn expm1(self) -> Self {
+        self.map(
+            move |s| Ok(s.expm1()),
+            GetOutput::map_dtype(|dt| {
+                if matches!(dt, DataType::Float32) {
+                    DataType::Float32
+                } else {
+                    DataType::Float64
+                }
+            }),
+        )
+        .with_fmt("expm1")
     }
 
     #[cfg(feature = "log")]
@@ -2233,6 +2879,13 @@ impl Expr {
         string::StringNameSpace(self)
     }
 
+    /// Get the [`ip::IpNameSpace`].
+    #[cfg(feature = "ip")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ip")))]
+    pub fn ip(self) -> ip::IpNameSpace {
+        ip::IpNameSpace(self)
+    }
+
     #[cfg(feature = "temporal")]
     pub fn dt(self) -> dt::DateLikeNameSpace {
         dt::DateLikeNameSpace(self)
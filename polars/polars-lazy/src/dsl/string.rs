@@ -1,7 +1,7 @@
 use super::*;
 use polars_arrow::array::ValueSize;
 use polars_arrow::export::arrow::array::{MutableArray, MutableUtf8Array};
-use polars_ops::prelude::Utf8NameSpaceImpl;
+use polars_ops::prelude::{ReplaceOptions, Utf8NameSpaceImpl};
 use polars_time::prelude::*;
 
 /// Specialized expressions for [`Series`] of [`DataType::Utf8`].
@@ -55,6 +55,54 @@ impl StringNameSpace {
             .with_fmt("str.extract")
     }
 
+    /// Replace the leftmost match of the regex pattern `pat` with `val`, or of the literal
+    /// string `pat` if `literal` is set.
+    /// The replacement may refer to the capture groups of a regex with `$1` or `${name}`.
+    pub fn replace(self, pat: &str, val: &str, literal: bool) -> Expr {
+        self.replace_with_options(
+            pat,
+            val,
+            ReplaceOptions {
+                literal,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Replace all matches of the regex pattern `pat` with `val`, or of the literal
+    /// string `pat` if `literal` is set.
+    /// The replacement may refer to the capture groups of a regex with `$1` or `${name}`.
+    pub fn replace_all(self, pat: &str, val: &str, literal: bool) -> Expr {
+        self.replace_with_options(
+            pat,
+            val,
+            ReplaceOptions {
+                literal,
+                all: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Replace the matches of `pat` with `val`, see [`ReplaceOptions`].
+    pub fn replace_with_options(self, pat: &str, val: &str, options: ReplaceOptions) -> Expr {
+        let pat = pat.to_string();
+        let val = val.to_string();
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            ca.replace_with_options(&pat, &val, options)
+                .map(|ca| ca.into_series())
+        };
+        let fmt = if options.all {
+            "str.replace_all"
+        } else {
+            "str.replace"
+        };
+        self.0
+            .map(function, GetOutput::from_type(DataType::Utf8))
+            .with_fmt(fmt)
+    }
+
     /// Return a copy of the string left filled with ASCII '0' digits to make a string of length width.
     /// A leading sign prefix ('+'/'-') is handled by inserting the padding after the sign character
     /// rather than before.
@@ -101,6 +149,31 @@ impl StringNameSpace {
             .with_fmt("str.rjust")
     }
 
+    /// Encode the string values with the given encoding.
+    #[cfg(feature = "string_encoding")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "string_encoding")))]
+    pub fn encode(self, encoding: StringEncoding) -> Expr {
+        let function = move |s: Series| Ok(s.utf8()?.encode(encoding).into_series());
+        self.0
+            .map(function, GetOutput::from_type(DataType::Utf8))
+            .with_fmt("str.encode")
+    }
+
+    /// Decode the string values with the given encoding. `strategy` determines what happens
+    /// to values that cannot be decoded to valid utf8.
+    #[cfg(feature = "string_encoding")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "string_encoding")))]
+    pub fn decode(self, encoding: StringEncoding, strategy: DecodeStrategy) -> Expr {
+        let function = move |s: Series| {
+            s.utf8()?
+                .decode(encoding, strategy)
+                .map(|ca| ca.into_series())
+        };
+        self.0
+            .map(function, GetOutput::from_type(DataType::Utf8))
+            .with_fmt("str.decode")
+    }
+
     /// Extract each successive non-overlapping match in an individual string as an array
     pub fn extract_all(self, pat: &str) -> Expr {
         let pat = pat.to_string();
@@ -360,4 +433,115 @@ impl StringNameSpace {
             )
             .with_fmt("str.split_inclusive")
     }
+
+    /// The levenshtein distance between the strings and the strings of `other`.
+    #[cfg(feature = "string_similarity")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "string_similarity")))]
+    pub fn levenshtein_distance(self, other: Expr) -> Expr {
+        let function = |s: &mut [Series]| {
+            let ca = s[0].utf8()?;
+            ca.levenshtein_distance(s[1].utf8()?)
+                .map(|ca| ca.into_series())
+        };
+        self.0
+            .map_many(function, &[other], GetOutput::from_type(DataType::UInt32))
+            .with_fmt("str.levenshtein_distance")
+    }
+
+    /// The levenshtein distance normalized to a similarity in `[0, 1]`.
+    #[cfg(feature = "string_similarity")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "string_similarity")))]
+    pub fn levenshtein_ratio(self, other: Expr) -> Expr {
+        let function = |s: &mut [Series]| {
+            let ca = s[0].utf8()?;
+            ca.levenshtein_ratio(s[1].utf8()?)
+                .map(|ca| ca.into_series())
+        };
+        self.0
+            .map_many(function, &[other], GetOutput::from_type(DataType::Float64))
+            .with_fmt("str.levenshtein_ratio")
+    }
+
+    /// The jaro-winkler similarity between the strings and the strings of `other`.
+    #[cfg(feature = "string_similarity")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "string_similarity")))]
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        let function = |s: &mut [Series]| {
+            let ca = s[0].utf8()?;
+            ca.jaro_winkler(s[1].utf8()?).map(|ca| ca.into_series())
+        };
+        self.0
+            .map_many(function, &[other], GetOutput::from_type(DataType::Float64))
+            .with_fmt("str.jaro_winkler")
+    }
+
+    /// The jaccard similarity of the sets of `n` char n-grams of the strings and the strings
+    /// of `other`.
+    #[cfg(feature = "string_similarity")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "string_similarity")))]
+    pub fn jaccard(self, other: Expr, n: usize) -> Expr {
+        let function = move |s: &mut [Series]| {
+            let ca = s[0].utf8()?;
+            ca.jaccard(s[1].utf8()?, n).map(|ca| ca.into_series())
+        };
+        self.0
+            .map_many(function, &[other], GetOutput::from_type(DataType::Float64))
+            .with_fmt("str.jaccard")
+    }
+
+    /// Decode the `%XX` escapes and the `+` (as a space) of URL components. Values that don't
+    /// decode to valid utf8 become null.
+    #[cfg(feature = "url")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+    pub fn url_decode(self) -> Expr {
+        let function = |s: Series| {
+            let ca = s.utf8()?;
+            Ok(ca.url_decode().into_series())
+        };
+        self.0
+            .map(function, GetOutput::from_type(DataType::Utf8))
+            .with_fmt("str.url_decode")
+    }
+
+    /// Percent encode all bytes except the unreserved characters `A-Z a-z 0-9 - _ . ~`.
+    #[cfg(feature = "url")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+    pub fn url_encode(self) -> Expr {
+        let function = |s: Series| {
+            let ca = s.utf8()?;
+            Ok(ca.url_encode().into_series())
+        };
+        self.0
+            .map(function, GetOutput::from_type(DataType::Utf8))
+            .with_fmt("str.url_encode")
+    }
+
+    /// Get the host of URLs, without user info and port, e.g. `"example.com"` for
+    /// `"https://user@example.com:8080/path"`.
+    #[cfg(feature = "url")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+    pub fn extract_domain(self) -> Expr {
+        let function = |s: Series| {
+            let ca = s.utf8()?;
+            Ok(ca.extract_domain().into_series())
+        };
+        self.0
+            .map(function, GetOutput::from_type(DataType::Utf8))
+            .with_fmt("str.extract_domain")
+    }
+
+    /// Get the decoded value of the first query parameter `name` of URLs, or null if the
+    /// parameter is missing.
+    #[cfg(feature = "url")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+    pub fn extract_query_param(self, name: &str) -> Expr {
+        let name = name.to_string();
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            Ok(ca.extract_query_param(&name).into_series())
+        };
+        self.0
+            .map(function, GetOutput::from_type(DataType::Utf8))
+            .with_fmt("str.extract_query_param")
+    }
 }
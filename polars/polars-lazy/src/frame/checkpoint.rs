@@ -0,0 +1,132 @@
+use crate::logical_plan::FETCH_ROWS;
+use crate::prelude::*;
+use polars_core::prelude::*;
+use polars_io::prelude::*;
+use std::path::{Path, PathBuf};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum CheckpointFormat {
+    #[cfg(feature = "ipc")]
+    Ipc,
+    #[cfg(feature = "parquet")]
+    Parquet,
+}
+
+impl CheckpointFormat {
+    /// Files ending on `.parquet` are written as parquet, all others as ipc.
+    fn from_path(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "parquet")]
+            Some("parquet") => Ok(CheckpointFormat::Parquet),
+            #[cfg(feature = "ipc")]
+            _ => Ok(CheckpointFormat::Ipc),
+            #[cfg(not(feature = "ipc"))]
+            _ => Err(PolarsError::ComputeError(
+                format!(
+                    "cannot checkpoint to {:?}, activate the 'ipc' feature or use a '.parquet' extension",
+                    path
+                )
+                .into(),
+            )),
+        }
+    }
+}
+
+/// Scan that reads a checkpoint file if it exists, or otherwise
+/// computes the upstream query and writes the checkpoint file.
+struct CheckpointScan {
+    input: LazyFrame,
+    path: PathBuf,
+    format: CheckpointFormat,
+}
+
+impl CheckpointScan {
+    fn read(&self) -> Result<DataFrame> {
+        let file = std::fs::File::open(&self.path)?;
+        match self.format {
+            #[cfg(feature = "ipc")]
+            CheckpointFormat::Ipc => IpcReader::new(file).finish(),
+            #[cfg(feature = "parquet")]
+            CheckpointFormat::Parquet => ParquetReader::new(file).finish(),
+        }
+    }
+
+    fn write(&self, df: &mut DataFrame) -> Result<()> {
+        // write to a temporary file first, so that we never scan a partially written checkpoint.
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        let file = std::fs::File::create(&tmp_path)?;
+        match self.format {
+            #[cfg(feature = "ipc")]
+            CheckpointFormat::Ipc => IpcWriter::new(file).finish(df)?,
+            #[cfg(feature = "parquet")]
+            CheckpointFormat::Parquet => ParquetWriter::new(file).finish(df)?,
+        }
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+impl AnonymousScan for CheckpointScan {
+    fn scan(&self, scan_opts: AnonymousScanOptions) -> Result<DataFrame> {
+        let df = if self.path.exists() {
+            self.read()?
+        } else {
+            // a fetch must not truncate the upstream query, the checkpoint holds its full result
+            let fetch_rows = FETCH_ROWS.with(|fr| fr.take());
+            let out = self.input.clone().collect();
+            FETCH_ROWS.with(|fr| fr.set(fetch_rows));
+            let mut df = out?;
+            self.write(&mut df)?;
+            df
+        };
+        Ok(match scan_opts.n_rows {
+            Some(n_rows) => df.head(Some(n_rows)),
+            None => df,
+        })
+    }
+}
+
+impl LazyFrame {
+    /// Checkpoint the result of this query to `path`.
+    ///
+    /// The first time the query is collected, the result of this `LazyFrame` is written to `path`.
+    /// Subsequent collections (and new queries that checkpoint to the same `path`) scan
+    /// the checkpoint file instead of recomputing the upstream query.
+    ///
+    /// Paths with a `.parquet` extension are written as parquet, all others as ipc.
+    /// Remove the file to invalidate the checkpoint.
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "ipc", feature = "parquet"))))]
+    pub fn checkpoint<P: AsRef<Path>>(self, path: P) -> Result<LazyFrame> {
+        let path = path.as_ref().to_path_buf();
+        let format = CheckpointFormat::from_path(&path)?;
+
+        // the checkpoint already exists, so we can use a normal scan and its optimizations.
+        if path.exists() {
+            let path_str = path.to_string_lossy().into_owned();
+            return match format {
+                #[cfg(feature = "ipc")]
+                CheckpointFormat::Ipc => LazyFrame::scan_ipc(path_str, Default::default()),
+                #[cfg(feature = "parquet")]
+                CheckpointFormat::Parquet => LazyFrame::scan_parquet(path_str, Default::default()),
+            };
+        }
+
+        let schema = self.schema()?;
+        let scan = CheckpointScan {
+            input: self,
+            path,
+            format,
+        };
+        LazyFrame::anonymous_scan(
+            Arc::new(scan),
+            ScanArgsAnonymous {
+                schema: Some(schema.as_ref().clone()),
+                name: "CHECKPOINT",
+                ..Default::default()
+            },
+        )
+    }
+}
@@ -1,14 +1,19 @@
+use super::directory::with_path_column;
 use crate::prelude::*;
 use polars_core::prelude::*;
 use polars_io::csv::utils::get_reader_bytes;
-use polars_io::csv::utils::infer_file_schema;
-use polars_io::csv::{CsvEncoding, NullValues};
+use polars_io::csv::utils::{infer_file_schema, transcode_to_utf8};
+use polars_io::csv::{CsvEncoding, NullValues, RaggedLines};
 use polars_io::RowCount;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use polars_io::{csv::utils::decompress, mmap::ReaderBytes};
+use std::path::{Path, PathBuf};
 
 #[derive(Clone)]
 #[cfg(feature = "csv-file")]
 pub struct LazyCsvReader<'a> {
     path: String,
+    paths: Vec<PathBuf>,
     delimiter: u8,
     has_header: bool,
     ignore_errors: bool,
@@ -28,6 +33,8 @@ pub struct LazyCsvReader<'a> {
     encoding: CsvEncoding,
     row_count: Option<RowCount>,
     parse_dates: bool,
+    ragged_lines: RaggedLines,
+    path_column: Option<String>,
 }
 
 #[cfg(feature = "csv-file")]
@@ -35,6 +42,7 @@ impl<'a> LazyCsvReader<'a> {
     pub fn new(path: String) -> Self {
         LazyCsvReader {
             path,
+            paths: vec![],
             delimiter: b',',
             has_header: true,
             ignore_errors: false,
@@ -54,9 +62,40 @@ impl<'a> LazyCsvReader<'a> {
             encoding: CsvEncoding::Utf8,
             row_count: None,
             parse_dates: false,
+            ragged_lines: RaggedLines::default(),
+            path_column: None,
         }
     }
 
+    /// Create a reader that scans the given files and concatenates them in the given order.
+    ///
+    /// Unlike a glob pattern in [`LazyCsvReader::new`], the paths are taken literally.
+    /// The schema is inferred from the first file.
+    pub fn new_paths<I, P>(paths: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let paths = paths
+            .into_iter()
+            .map(|p| p.as_ref().to_path_buf())
+            .collect::<Vec<PathBuf>>();
+        let path = paths
+            .first()
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let mut reader = Self::new(path);
+        reader.paths = paths;
+        reader
+    }
+
+    /// Create a reader that scans the files of a directory tree selected by `dir`.
+    pub fn new_dir(mut dir: DirectoryScan) -> Result<Self> {
+        let mut reader = Self::new_paths(dir.collect_paths()?);
+        reader.path_column = dir.path_column().map(|s| s.to_string());
+        Ok(reader)
+    }
+
     /// Skip this number of rows after the header location.
     #[must_use]
     pub fn with_skip_rows_after_header(mut self, offset: usize) -> Self {
@@ -71,6 +110,16 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Set what to do with lines that have fewer or more fields than the schema.
+    /// [`RaggedLines::Reject`] is not supported, as a scan cannot return the rejected lines. Use
+    /// [`CsvReader::finish_with_rejected`](polars_io::csv::CsvReader::finish_with_rejected)
+    /// to read them.
+    #[must_use]
+    pub fn with_ragged_lines(mut self, ragged_lines: RaggedLines) -> Self {
+        self.ragged_lines = ragged_lines;
+        self
+    }
+
     /// Try to stop parsing when `n` rows are parsed. During multithreaded parsing the upper bound `n` cannot
     /// be guaranteed.
     #[must_use]
@@ -203,6 +252,27 @@ impl<'a> LazyCsvReader<'a> {
     {
         let mut file = std::fs::File::open(&self.path)?;
         let reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        let reader_bytes = match decompress(
+            &reader_bytes,
+            self.infer_schema_length
+                .map(|n| n + self.skip_rows + self.has_header as usize),
+            self.delimiter,
+            self.quote_char,
+            self.eol_char,
+        )? {
+            Some(b) => ReaderBytes::Owned(b),
+            None => reader_bytes,
+        };
+        let reader_bytes = transcode_to_utf8(
+            reader_bytes,
+            self.encoding,
+            self.infer_schema_length
+                .map(|n| n + self.skip_rows + self.has_header as usize),
+            self.quote_char,
+            self.comment_char,
+            self.eol_char,
+        )?;
         let mut skip_rows = self.skip_rows;
 
         let (schema, _) = infer_file_schema(
@@ -232,6 +302,13 @@ impl<'a> LazyCsvReader<'a> {
     }
 
     pub fn finish_impl(self) -> Result<LazyFrame> {
+        if self.ragged_lines == RaggedLines::Reject {
+            return Err(PolarsError::InvalidOperation(
+                "rejecting ragged lines is not supported in a lazy scan, \
+                use 'CsvReader::finish_with_rejected' to read the rejected lines"
+                    .into(),
+            ));
+        }
         let mut lf: LazyFrame = LogicalPlanBuilder::scan_csv(
             self.path,
             self.delimiter,
@@ -253,6 +330,7 @@ impl<'a> LazyCsvReader<'a> {
             self.encoding,
             self.row_count,
             self.parse_dates,
+            self.ragged_lines,
         )?
         .build()
         .into();
@@ -260,35 +338,45 @@ impl<'a> LazyCsvReader<'a> {
         Ok(lf)
     }
 
-    pub fn finish(self) -> Result<LazyFrame> {
-        if self.path.contains('*') {
-            let paths = glob::glob(&self.path)
-                .map_err(|_| PolarsError::ComputeError("invalid glob pattern given".into()))?;
+    fn finish_files(self, paths: Vec<PathBuf>) -> Result<LazyFrame> {
+        let lfs = paths
+            .into_iter()
+            .map(|path| {
+                let mut builder = self.clone();
+                builder.path = path.to_string_lossy().into_owned();
+                builder.paths = vec![];
+                builder.path_column = None;
+                if builder.skip_rows > 0 {
+                    builder.skip_rows = 0;
+                    builder.n_rows = None;
+                }
+                // do no rechunk yet.
+                builder.rechunk = false;
+                let lf = builder.finish_impl()?;
+                Ok(with_path_column(lf, &path, self.path_column.as_deref()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        concat(&lfs, self.rechunk)
+            .map_err(|_| PolarsError::ComputeError("no matching files found".into()))
+            .map(|lf| {
+                if self.skip_rows != 0 || self.n_rows.is_some() {
+                    lf.slice(self.skip_rows as i64, self.n_rows.unwrap() as IdxSize)
+                } else {
+                    lf
+                }
+            })
+    }
 
-            let lfs = paths
-                .map(|r| {
-                    let path = r.map_err(|e| PolarsError::ComputeError(format!("{}", e).into()))?;
-                    let path_string = path.to_string_lossy().into_owned();
-                    let mut builder = self.clone();
-                    builder.path = path_string;
-                    if builder.skip_rows > 0 {
-                        builder.skip_rows = 0;
-                        builder.n_rows = None;
-                    }
-                    // do no rechunk yet.
-                    builder.rechunk = false;
-                    builder.finish_impl()
-                })
+    pub fn finish(mut self) -> Result<LazyFrame> {
+        if !self.paths.is_empty() {
+            let paths = std::mem::take(&mut self.paths);
+            self.finish_files(paths)
+        } else if self.path.contains('*') {
+            let paths = glob::glob(&self.path)
+                .map_err(|_| PolarsError::ComputeError("invalid glob pattern given".into()))?
+                .map(|r| r.map_err(|e| PolarsError::ComputeError(format!("{}", e).into())))
                 .collect::<Result<Vec<_>>>()?;
-            concat(&lfs, self.rechunk)
-                .map_err(|_| PolarsError::ComputeError("no matching files found".into()))
-                .map(|lf| {
-                    if self.skip_rows != 0 || self.n_rows.is_some() {
-                        lf.slice(self.skip_rows as i64, self.n_rows.unwrap() as IdxSize)
-                    } else {
-                        lf
-                    }
-                })
+            self.finish_files(paths)
         } else {
             self.finish_impl()
         }
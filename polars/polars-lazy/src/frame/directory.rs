@@ -0,0 +1,136 @@
+//! Scans of the files in a directory tree.
+use crate::prelude::*;
+use glob::Pattern;
+use polars_core::prelude::*;
+use std::path::{Path, PathBuf};
+
+type FileFilter = Box<dyn FnMut(&Path) -> bool>;
+
+/// Select the files in a directory tree that are scanned.
+///
+/// The include and exclude glob patterns are matched against the path of a file relative
+/// to the root directory, e.g. `"2022/**/*.parquet"`. A file is scanned if it matches any
+/// include pattern (or none are given), matches no exclude pattern and passes the filter.
+/// The files are scanned in the lexical order of their paths.
+#[must_use]
+pub struct DirectoryScan {
+    root: PathBuf,
+    recursive: bool,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    filter: Option<FileFilter>,
+    path_column: Option<String>,
+}
+
+impl DirectoryScan {
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        DirectoryScan {
+            root: root.into(),
+            recursive: true,
+            include: vec![],
+            exclude: vec![],
+            filter: None,
+            path_column: None,
+        }
+    }
+
+    /// Walk the subdirectories of the root. Defaults to `true`.
+    pub fn recursive(mut self, toggle: bool) -> Self {
+        self.recursive = toggle;
+        self
+    }
+
+    /// Only scan the files that match this glob pattern.
+    pub fn with_include(mut self, pattern: &str) -> Self {
+        self.include.push(pattern.to_string());
+        self
+    }
+
+    /// Don't scan the files that match this glob pattern.
+    pub fn with_exclude(mut self, pattern: &str) -> Self {
+        self.exclude.push(pattern.to_string());
+        self
+    }
+
+    /// Only scan the files for which `filter` returns `true`.
+    pub fn with_filter<F>(mut self, filter: F) -> Self
+    where
+        F: FnMut(&Path) -> bool + 'static,
+    {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Add a column with this name that holds the path of the file a row was read from.
+    pub fn with_path_column(mut self, name: Option<&str>) -> Self {
+        self.path_column = name.map(|s| s.to_string());
+        self
+    }
+
+    pub(crate) fn path_column(&self) -> Option<&str> {
+        self.path_column.as_deref()
+    }
+
+    /// Get the paths of the files that are scanned.
+    pub fn collect_paths(&mut self) -> Result<Vec<PathBuf>> {
+        let compile = |patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|p| {
+                    Pattern::new(p)
+                        .map_err(|_| PolarsError::ComputeError("invalid glob pattern given".into()))
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+        let include = compile(&self.include)?;
+        let exclude = compile(&self.exclude)?;
+
+        let mut files = vec![];
+        walk_dir(&self.root, self.recursive, &mut files)?;
+        files.sort();
+
+        let mut paths = Vec::with_capacity(files.len());
+        for path in files {
+            let relative = path.strip_prefix(&self.root).unwrap_or(&path);
+            let included = include.is_empty() || include.iter().any(|p| p.matches_path(relative));
+            if !included || exclude.iter().any(|p| p.matches_path(relative)) {
+                continue;
+            }
+            if let Some(filter) = &mut self.filter {
+                if !filter(&path) {
+                    continue;
+                }
+            }
+            paths.push(path)
+        }
+        if paths.is_empty() {
+            return Err(PolarsError::ComputeError("no matching files found".into()));
+        }
+        Ok(paths)
+    }
+}
+
+/// Collect the files of `dir`. Symbolic links to files are collected, but symbolic links to
+/// directories are not followed, so that a link cycle can't recurse forever.
+fn walk_dir(dir: &Path, recursive: bool, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            if recursive {
+                walk_dir(&path, recursive, files)?;
+            }
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Add a literal column with the path of the scanned file.
+pub(super) fn with_path_column(lf: LazyFrame, path: &Path, name: Option<&str>) -> LazyFrame {
+    match name {
+        Some(name) => lf.with_column(lit(path.to_string_lossy().as_ref()).alias(name)),
+        None => lf,
+    }
+}
@@ -0,0 +1,101 @@
+//! Scans of an explicit list of files.
+use crate::prelude::*;
+use parking_lot::Mutex;
+use polars_core::export::once_cell::sync::Lazy;
+use polars_core::prelude::*;
+use polars_io::RowCount;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// A file is identified by its path, size and modification time, so that a rewritten
+/// file gets a new entry.
+#[derive(Hash, PartialEq, Eq)]
+struct FileKey {
+    path: PathBuf,
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+/// The number of files of which the row count is cached. A full cache is emptied, so that
+/// a long running process that scans ever new files doesn't grow it without bound.
+const MAX_CACHED_ROW_COUNTS: usize = 4096;
+
+static ROW_COUNTS: Lazy<Mutex<PlHashMap<FileKey, usize>>> = Lazy::new(Default::default);
+
+/// Get the number of rows of a file with `read_num_rows`, which should only read the
+/// metadata of the file. The row counts of up to [`MAX_CACHED_ROW_COUNTS`] files are cached,
+/// so scanning the same files again doesn't read their metadata again.
+pub(super) fn cached_num_rows<F>(path: &Path, read_num_rows: F) -> Result<usize>
+where
+    F: FnOnce(File) -> Result<usize>,
+{
+    let metadata = std::fs::metadata(path)?;
+    let key = FileKey {
+        path: path.to_path_buf(),
+        len: metadata.len(),
+        modified: metadata.modified().ok(),
+    };
+    if let Some(n) = ROW_COUNTS.lock().get(&key) {
+        return Ok(*n);
+    }
+    let n = read_num_rows(File::open(path)?)?;
+    let mut row_counts = ROW_COUNTS.lock();
+    if row_counts.len() >= MAX_CACHED_ROW_COUNTS {
+        row_counts.clear();
+    }
+    row_counts.insert(key, n);
+    Ok(n)
+}
+
+/// Free the cached row counts of scanned files.
+pub fn clear_row_count_cache() {
+    ROW_COUNTS.lock().clear()
+}
+
+impl LazyFrame {
+    /// Concatenate the scans of `paths` in the given order.
+    ///
+    /// If `n_rows` or `row_count` are set, the number of rows of the files is read from their
+    /// metadata, so that every scan gets its own limit and row count offset and files after
+    /// the limit are not scanned at all.
+    pub(super) fn scan_file_list<S, N>(
+        paths: &[PathBuf],
+        n_rows: Option<usize>,
+        row_count: Option<RowCount>,
+        rechunk: bool,
+        scan: S,
+        read_num_rows: N,
+    ) -> Result<Self>
+    where
+        S: Fn(&Path, Option<usize>, Option<RowCount>) -> Result<LazyFrame>,
+        N: Fn(File) -> Result<usize>,
+    {
+        if paths.is_empty() {
+            return Err(PolarsError::NoData("no files given to scan".into()));
+        }
+        let needs_row_counts = n_rows.is_some() || row_count.is_some();
+        let mut offset: IdxSize = 0;
+        let mut remaining = n_rows;
+
+        let mut lfs = Vec::with_capacity(paths.len());
+        for path in paths {
+            // the first file is always scanned for the schema
+            if remaining == Some(0) && !lfs.is_empty() {
+                break;
+            }
+            let rc = row_count.as_ref().map(|rc| RowCount {
+                name: rc.name.clone(),
+                offset: rc.offset + offset,
+            });
+            lfs.push(scan(path, remaining, rc)?);
+
+            if needs_row_counts {
+                let n = cached_num_rows(path, &read_num_rows)?;
+                offset += n as IdxSize;
+                remaining = remaining.map(|remaining| remaining.saturating_sub(n));
+            }
+        }
+        concat(&lfs, rechunk)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::prelude::*;
+use parking_lot::Mutex;
+use polars_core::prelude::*;
+use polars_core::POOL;
+use std::future::Future;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+
+#[derive(Default)]
+struct Shared {
+    result: Option<Result<DataFrame>>,
+    waker: Option<Waker>,
+}
+
+/// A [`Future`] that resolves to the output of a query running on the polars thread pool.
+/// Created by [`LazyFrame::collect_async`].
+pub struct CollectFuture {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl Future for CollectFuture {
+    type Output = Result<DataFrame>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock();
+        match shared.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Execute the query like [`collect`](LazyFrame::collect), but on the polars thread pool,
+    /// and return a [`Future`] that resolves to its output. Awaiting the future doesn't block
+    /// the thread of the executor, so queries can be run from async code such as a web service.
+    ///
+    /// The future doesn't depend on a specific executor. The query starts right away, not on the
+    /// first poll, and runs to completion even if the future is dropped.
+    pub fn collect_async(self) -> CollectFuture {
+        self.collect_async_with_hooks(ExecutionHooks::default())
+    }
+
+    /// Like [`collect_async`](LazyFrame::collect_async), but the query is aborted with an error
+    /// once `token` is cancelled, see [`collect_with_cancellation`](LazyFrame::collect_with_cancellation).
+    /// Dropping the future doesn't cancel the query, cancel the token instead.
+    pub fn collect_async_with_cancellation(self, token: &CancellationToken) -> CollectFuture {
+        self.collect_async_with_hooks(ExecutionHooks::new().with_cancellation(token))
+    }
+
+    /// Like [`collect_async`](LazyFrame::collect_async), with the given `hooks`, see
+    /// [`collect_with_hooks`](LazyFrame::collect_with_hooks).
+    pub fn collect_async_with_hooks(self, hooks: ExecutionHooks) -> CollectFuture {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let task_shared = shared.clone();
+        POOL.spawn(move || {
+            // a panic in a spawned task would abort the process
+            let result = catch_unwind(AssertUnwindSafe(|| self.collect_with_hooks(hooks)))
+                .unwrap_or_else(|_| {
+                    Err(PolarsError::ComputeError(
+                        "the query panicked while it was collected".into(),
+                    ))
+                });
+            let mut shared = task_shared.lock();
+            shared.result = Some(result);
+            if let Some(waker) = shared.waker.take() {
+                waker.wake()
+            }
+        });
+        CollectFuture { shared }
+    }
+}
@@ -1,6 +1,8 @@
+use super::directory::with_path_column;
 use crate::prelude::*;
 use polars_core::prelude::*;
 use polars_io::RowCount;
+use std::path::{Path, PathBuf};
 
 #[derive(Clone)]
 pub struct ScanArgsIpc {
@@ -45,35 +47,71 @@ impl LazyFrame {
         Ok(lf)
     }
 
+    /// Create a LazyFrame from the ipc scans of the given files, in the given order.
+    ///
+    /// Unlike a glob pattern in [`LazyFrame::scan_ipc`], the paths are taken literally.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
+    pub fn scan_ipc_files<I, P>(paths: I, args: ScanArgsIpc) -> Result<Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let paths = paths
+            .into_iter()
+            .map(|p| p.as_ref().to_path_buf())
+            .collect::<Vec<_>>();
+        Self::scan_ipc_list(&paths, args, None)
+    }
+
+    /// Create a LazyFrame from the ipc scans of the files of a directory tree selected by `dir`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
+    pub fn scan_ipc_dir(mut dir: DirectoryScan, args: ScanArgsIpc) -> Result<Self> {
+        let paths = dir.collect_paths()?;
+        Self::scan_ipc_list(&paths, args, dir.path_column())
+    }
+
+    fn scan_ipc_list(
+        paths: &[PathBuf],
+        args: ScanArgsIpc,
+        path_column: Option<&str>,
+    ) -> Result<Self> {
+        let lfs = paths
+            .iter()
+            .map(|path| {
+                let mut args = args.clone();
+                args.row_count = None;
+                Self::scan_ipc_impl(path.to_string_lossy().into_owned(), args)
+                    .map(|lf| with_path_column(lf, path, path_column))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        concat(&lfs, args.rechunk)
+            .map_err(|_| PolarsError::ComputeError("no files given to scan".into()))
+            .map(|mut lf| {
+                if let Some(n_rows) = args.n_rows {
+                    lf = lf.slice(0, n_rows as IdxSize);
+                };
+
+                if let Some(rc) = args.row_count {
+                    lf = lf.with_row_count(&rc.name, Some(rc.offset))
+                }
+
+                lf
+            })
+    }
+
     /// Create a LazyFrame directly from a ipc scan.
     #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
     pub fn scan_ipc(path: String, args: ScanArgsIpc) -> Result<Self> {
         if path.contains('*') {
             let paths = glob::glob(&path)
-                .map_err(|_| PolarsError::ComputeError("invalid glob pattern given".into()))?;
-            let lfs = paths
-                .map(|r| {
-                    let path = r.map_err(|e| PolarsError::ComputeError(format!("{}", e).into()))?;
-                    let path_string = path.to_string_lossy().into_owned();
-                    let mut args = args.clone();
-                    args.row_count = None;
-                    Self::scan_ipc_impl(path_string, args)
-                })
+                .map_err(|_| PolarsError::ComputeError("invalid glob pattern given".into()))?
+                .map(|r| r.map_err(|e| PolarsError::ComputeError(format!("{}", e).into())))
                 .collect::<Result<Vec<_>>>()?;
-
-            concat(&lfs, args.rechunk)
-                .map_err(|_| PolarsError::ComputeError("no matching files found".into()))
-                .map(|mut lf| {
-                    if let Some(n_rows) = args.n_rows {
-                        lf = lf.slice(0, n_rows as IdxSize);
-                    };
-
-                    if let Some(rc) = args.row_count {
-                        lf = lf.with_row_count(&rc.name, Some(rc.offset))
-                    }
-
-                    lf
-                })
+            if paths.is_empty() {
+                return Err(PolarsError::ComputeError("no matching files found".into()));
+            }
+            Self::scan_ipc_files(paths, args)
         } else {
             Self::scan_ipc_impl(path, args)
         }
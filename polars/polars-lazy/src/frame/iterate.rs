@@ -0,0 +1,151 @@
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use parking_lot::Mutex;
+use polars_core::prelude::*;
+
+/// The input of an iteration. It is swapped by every iteration, so the plan of
+/// an iteration only has to be built and optimized once.
+struct IterationInput {
+    df: Mutex<DataFrame>,
+    schema: Schema,
+}
+
+impl AnonymousScan for IterationInput {
+    fn scan(&self, scan_opts: AnonymousScanOptions) -> Result<DataFrame> {
+        let df = self.df.lock().clone();
+        Ok(match scan_opts.n_rows {
+            Some(n_rows) => df.head(Some(n_rows)),
+            None => df,
+        })
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> Result<Schema> {
+        Ok(self.schema.clone())
+    }
+}
+
+impl LazyFrame {
+    /// Run the query built by `step` repeatedly, feeding the output of an iteration back as
+    /// the input of the next one, until the output equals the input (a fixed point) or
+    /// `max_iterations` iterations have run.
+    ///
+    /// `step` is called once to build the query of an iteration, which is optimized once.
+    /// The intermediate results stay in memory and only the input of that query is swapped
+    /// between iterations.
+    /// The output of `step` must have the same schema as its input.
+    ///
+    /// This can be used for recursive queries like graph reachability:
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn reachable(edges: LazyFrame, start: LazyFrame) -> Result<LazyFrame> {
+    ///     // `start` has a single column "node"
+    ///     start.iterate(
+    ///         move |nodes| {
+    ///             let next = nodes
+    ///                 .clone()
+    ///                 .join(edges, [col("node")], [col("from")], JoinType::Inner)
+    ///                 .select([col("to").alias("node")]);
+    ///             concat(&[nodes, next], false)
+    ///                 .unwrap()
+    ///                 .unique_stable(None, UniqueKeepStrategy::First)
+    ///         },
+    ///         100,
+    ///     )
+    /// }
+    /// ```
+    pub fn iterate<F>(self, step: F, max_iterations: usize) -> Result<LazyFrame>
+    where
+        F: FnOnce(LazyFrame) -> LazyFrame,
+    {
+        self.iterate_impl(step, max_iterations, None)
+    }
+
+    /// Run the iterations like [`iterate`](LazyFrame::iterate), but abort them with an error
+    /// once `token` is cancelled. The token is checked by every iteration and by the operations
+    /// within it.
+    ///
+    /// The iterations run as a single operation of the returned query, so cancelling the token
+    /// passed to [`collect_with_cancellation`](LazyFrame::collect_with_cancellation) alone
+    /// only stops the query after all iterations are done.
+    pub fn iterate_with_cancellation<F>(
+        self,
+        step: F,
+        max_iterations: usize,
+        token: &CancellationToken,
+    ) -> Result<LazyFrame>
+    where
+        F: FnOnce(LazyFrame) -> LazyFrame,
+    {
+        self.iterate_impl(step, max_iterations, Some(token.clone()))
+    }
+
+    fn iterate_impl<F>(
+        self,
+        step: F,
+        max_iterations: usize,
+        cancel_token: Option<CancellationToken>,
+    ) -> Result<LazyFrame>
+    where
+        F: FnOnce(LazyFrame) -> LazyFrame,
+    {
+        let schema = self.schema()?;
+        let input = Arc::new(IterationInput {
+            df: Mutex::new(DataFrame::default()),
+            schema: (*schema).clone(),
+        });
+        let args = ScanArgsAnonymous {
+            schema: Some((*schema).clone()),
+            name: "ITERATION INPUT",
+            ..Default::default()
+        };
+        let mut step_lf = step(LazyFrame::anonymous_scan(input.clone(), args)?);
+        if step_lf.schema()? != schema {
+            return Err(PolarsError::SchemaMisMatch(
+                "the output schema of an iteration must equal its input schema".into(),
+            ));
+        }
+        // the file cache only shares the reads of a single execution
+        step_lf.opt_state.file_caching = false;
+        let opt_state = step_lf.opt_state;
+        let mut lp_arena = Arena::with_capacity(32);
+        let mut expr_arena = Arena::with_capacity(64);
+        let root = step_lf.optimize(&mut lp_arena, &mut expr_arena)?;
+        let planner = DefaultPlanner::default();
+        let physical_plan = planner.create_physical_plan(root, &mut lp_arena, &mut expr_arena)?;
+        // a run holds the plan and its input until it is done
+        let physical_plan = Mutex::new(physical_plan);
+
+        // the iterations depend on all rows and all columns, so nothing may be pushed down
+        let optimizations = AllowedOptimizations {
+            predicate_pushdown: false,
+            projection_pushdown: false,
+            slice_pushdown: false,
+            ..Default::default()
+        };
+        Ok(self.map(
+            move |mut df: DataFrame| {
+                let mut physical_plan = physical_plan.lock();
+                for _ in 0..max_iterations {
+                    *input.df.lock() = df.clone();
+                    let mut state = ExecutionState::new();
+                    state.set_options(&opt_state);
+                    state.cancel_token = cancel_token.clone();
+                    state.check_cancelled()?;
+                    let out = physical_plan.execute(&mut state)?;
+                    if out.frame_equal_missing(&df) {
+                        break;
+                    }
+                    df = out;
+                }
+                *input.df.lock() = DataFrame::default();
+                Ok(df)
+            },
+            Some(optimizations),
+            None,
+            Some("ITERATE"),
+        ))
+    }
+}
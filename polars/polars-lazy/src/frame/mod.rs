@@ -1,12 +1,22 @@
 //! Lazy variant of a [DataFrame](polars_core::frame::DataFrame).
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+mod checkpoint;
 #[cfg(feature = "csv-file")]
 mod csv;
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
+mod directory;
+#[cfg(feature = "parquet")]
+mod file_list;
+mod future;
 #[cfg(feature = "ipc")]
 mod ipc;
+mod iterate;
 #[cfg(feature = "parquet")]
 mod parquet;
+mod prepared;
 #[cfg(feature = "python")]
 mod python;
+mod registry;
 
 mod anonymous_scan;
 
@@ -14,15 +24,24 @@ pub use anonymous_scan::*;
 
 #[cfg(feature = "csv-file")]
 pub use csv::*;
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
+pub use directory::DirectoryScan;
+#[cfg(feature = "parquet")]
+pub use file_list::clear_row_count_cache;
+pub use future::CollectFuture;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
+pub use prepared::PreparedLazyFrame;
+pub use registry::TableRegistry;
 use std::borrow::Cow;
 
 #[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
 use polars_core::datatypes::PlHashMap;
+use polars_core::frame::groupby::{_null_group_key, _NULL_GROUP_KEY};
 use polars_core::frame::hash_join::JoinType;
+use polars_core::memory;
 use polars_core::prelude::*;
 #[cfg(feature = "dtype-categorical")]
 use polars_core::toggle_string_cache;
@@ -36,6 +55,7 @@ use crate::logical_plan::optimizer::stack_opt::{OptimizationRule, StackOptimizer
 use crate::logical_plan::optimizer::{
     predicate_pushdown::PredicatePushDown, projection_pushdown::ProjectionPushDown,
 };
+use crate::physical_plan::progress::ProgressTracker;
 use crate::physical_plan::state::ExecutionState;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -48,12 +68,14 @@ use crate::prelude::{
 };
 
 use crate::logical_plan::FETCH_ROWS;
+use crate::prelude::combine_filters::CombineFilters;
 use crate::prelude::delay_rechunk::DelayRechunk;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
 use crate::prelude::file_caching::collect_fingerprints;
 use crate::utils::{combine_predicates_expr, expr_to_root_column_names};
 use polars_arrow::prelude::QuantileInterpolOptions;
 use polars_core::frame::explode::MeltArgs;
+use polars_io::retry::RetryPolicy;
 use polars_io::RowCount;
 
 #[derive(Clone, Debug)]
@@ -64,6 +86,9 @@ pub struct JoinOptions {
     pub how: JoinType,
     pub suffix: Cow<'static, str>,
     pub slice: Option<(i64, usize)>,
+    pub validate: JoinValidation,
+    /// Whether null keys match each other.
+    pub nulls_equal: bool,
 }
 
 impl Default for JoinOptions {
@@ -74,6 +99,8 @@ impl Default for JoinOptions {
             how: JoinType::Left,
             suffix: "_right".into(),
             slice: None,
+            validate: JoinValidation::ManyToMany,
+            nulls_equal: true,
         }
     }
 }
@@ -93,6 +120,46 @@ impl IntoLazy for DataFrame {
     }
 }
 
+/// Evaluate expressions on an eager `DataFrame`.
+pub trait DataFrameExprs {
+    /// Add or replace columns with the output of the expressions.
+    ///
+    /// Just like [`LazyFrame::with_columns`], all expressions are evaluated on the original
+    /// `DataFrame` (an expression does not see the columns added by the other expressions)
+    /// and the `DataFrame` is only modified if all of them succeed.
+    ///
+    /// # Errors
+    ///
+    /// Fails if an expression produces a `Series` whose length is neither 1 nor the height
+    /// of the `DataFrame`; the error names that expression.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// let mut df = df!("a" => [1, 2, 3]).unwrap();
+    /// // "b" is computed from the original "a", not from the new one
+    /// df.with_columns([col("a") * lit(10), (col("a") + lit(1)).alias("b")])
+    ///     .unwrap();
+    /// assert_eq!(Vec::from(df.column("b").unwrap().i32().unwrap()), &[Some(2), Some(3), Some(4)]);
+    /// ```
+    fn with_columns<E: AsRef<[Expr]>>(&mut self, exprs: E) -> Result<&mut Self>;
+}
+
+impl DataFrameExprs for DataFrame {
+    fn with_columns<E: AsRef<[Expr]>>(&mut self, exprs: E) -> Result<&mut Self> {
+        *self = self
+            .clone()
+            .lazy()
+            .without_optimizations()
+            .with_columns(exprs)
+            .collect()?;
+        Ok(self)
+    }
+}
+
 /// Lazy abstraction over an eager `DataFrame`.
 /// It really is an abstraction over a logical plan. The methods of this struct will incrementally
 /// modify a logical plan until output is requested (via [collect](crate::frame::LazyFrame::collect))
@@ -103,6 +170,21 @@ pub struct LazyFrame {
     pub(crate) opt_state: OptState,
 }
 
+/// Make the groupbys and distincts of the plan maintain the order of their groups.
+fn maintain_order(root: Node, lp_arena: &mut Arena<ALogicalPlan>) {
+    let nodes = (&*lp_arena)
+        .iter(root)
+        .map(|(node, _)| node)
+        .collect::<Vec<_>>();
+    for node in nodes {
+        match lp_arena.get_mut(node) {
+            ALogicalPlan::Aggregate { maintain_order, .. } => *maintain_order = true,
+            ALogicalPlan::Distinct { options, .. } => options.maintain_order = true,
+            _ => {}
+        }
+    }
+}
+
 impl From<LogicalPlan> for LazyFrame {
     fn from(plan: LogicalPlan) -> Self {
         Self {
@@ -121,11 +203,30 @@ pub struct OptState {
     pub projection_pushdown: bool,
     pub predicate_pushdown: bool,
     pub type_coercion: bool,
+    /// Make type coercion error on implicit casts that may lose information, such as
+    /// `UInt64` and `Int64` to their `Float64` supertype, instead of applying them.
+    pub strict_type_coercion: bool,
     pub simplify_expr: bool,
     pub file_caching: bool,
     pub aggregate_pushdown: bool,
     pub global_string_cache: bool,
     pub slice_pushdown: bool,
+    pub combine_filters: bool,
+    /// Rechunk the input of pipeline breaking operations (joins, groupbys, sorts and
+    /// distincts) if a column consists of more chunks than this threshold.
+    pub rechunk_threshold: Option<usize>,
+    /// Use smaller batches in the readers and prefer join and groupby algorithms that keep
+    /// the peak memory low, at the expense of performance.
+    pub low_memory: bool,
+    /// Retry the reads of the scans that fail on a transient io error with this policy.
+    /// `None` doesn't retry, see [`RetryPolicy::default`].
+    pub retry_policy: Option<RetryPolicy>,
+    /// Use order-stable groupbys, distincts and joins. `None` uses the global
+    /// [deterministic mode](polars_core::config::deterministic) at the time the query is collected.
+    pub deterministic: Option<bool>,
+    /// Sort on disk if a sort needs more memory than this budget in bytes. Overrides the
+    /// budget of the global external sort.
+    pub external_sort_budget: Option<usize>,
 }
 
 impl Default for OptState {
@@ -134,12 +235,19 @@ impl Default for OptState {
             projection_pushdown: true,
             predicate_pushdown: true,
             type_coercion: true,
+            strict_type_coercion: false,
             simplify_expr: true,
             global_string_cache: false,
             slice_pushdown: true,
+            combine_filters: true,
             // will be toggled by a scan operation such as csv scan or parquet scan
             file_caching: false,
             aggregate_pushdown: false,
+            rechunk_threshold: None,
+            low_memory: false,
+            retry_policy: None,
+            deterministic: None,
+            external_sort_budget: None,
         }
     }
 }
@@ -149,6 +257,8 @@ pub type AllowedOptimizations = OptState;
 
 impl LazyFrame {
     /// Get a hold on the schema of the current LazyFrame computation.
+    ///
+    /// The schema is resolved from the logical plan, the query is not executed.
     pub fn schema(&self) -> Result<SchemaRef> {
         let logical_plan = self.clone().get_plan_builder().build();
         logical_plan.schema().map(|schema| schema.into_owned())
@@ -185,16 +295,28 @@ impl LazyFrame {
 
     /// Turn off all optimizations
     pub fn without_optimizations(self) -> Self {
+        // strictness, the retries, determinism and the external sort are not optimizations
+        let strict_type_coercion = self.opt_state.strict_type_coercion;
+        let retry_policy = self.opt_state.retry_policy;
+        let deterministic = self.opt_state.deterministic;
+        let external_sort_budget = self.opt_state.external_sort_budget;
         self.with_optimizations(OptState {
             projection_pushdown: false,
             predicate_pushdown: false,
             type_coercion: true,
+            strict_type_coercion,
             simplify_expr: false,
             global_string_cache: false,
             slice_pushdown: false,
+            combine_filters: false,
             // will be toggled by a scan operation such as csv scan or parquet scan
             file_caching: false,
             aggregate_pushdown: false,
+            rechunk_threshold: None,
+            low_memory: false,
+            retry_policy,
+            deterministic,
+            external_sort_budget,
         })
     }
 
@@ -216,6 +338,24 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle strict type coercion. If set, implicit casts to a supertype that may lose
+    /// information error instead. See [`get_supertype`](polars_core::utils::get_supertype)
+    /// for the coercion rules.
+    pub fn with_strict_type_coercion(mut self, toggle: bool) -> Self {
+        self.opt_state.strict_type_coercion = toggle;
+        self
+    }
+
+    /// Toggle the deterministic execution mode of this query. If set, groupbys and distincts
+    /// maintain the order of the groups and the rows of joins are ordered by the rows of the
+    /// left and then the right table, at the expense of performance. This overrides the global
+    /// mode, except for the expressions `unique` and `value_counts`, which follow the global
+    /// mode, see [`polars_core::config::deterministic`].
+    pub fn with_deterministic(mut self, toggle: bool) -> Self {
+        self.opt_state.deterministic = Some(toggle);
+        self
+    }
+
     /// Toggle expression simplification optimization on or off
     pub fn with_simplify_expr(mut self, toggle: bool) -> Self {
         self.opt_state.simplify_expr = toggle;
@@ -240,6 +380,47 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle combining consecutive filters and ordering their predicates by estimated cost.
+    pub fn with_combine_filters(mut self, toggle: bool) -> Self {
+        self.opt_state.combine_filters = toggle;
+        self
+    }
+
+    /// Rechunk the input of pipeline breaking operations if a column consists of more than
+    /// `threshold` chunks, see [`DataFrame::rechunk_if_fragmented`]. `None` never rechunks,
+    /// which is the default.
+    pub fn with_rechunk_threshold(mut self, threshold: Option<usize>) -> Self {
+        self.opt_state.rechunk_threshold = threshold;
+        self
+    }
+
+    /// Reduce the memory consumption of the query at the expense of performance.
+    ///
+    /// The csv and parquet scans read in low memory mode, the inputs of a join are
+    /// executed one after the other and the groupby prefers the hash partitioned algorithm,
+    /// which aggregates one partition at a time.
+    pub fn with_low_memory(mut self, toggle: bool) -> Self {
+        self.opt_state.low_memory = toggle;
+        self
+    }
+
+    /// Retry the reads of the scans of this query that fail on a transient io error, e.g. of
+    /// files on a network file system, with `policy`. `None` doesn't retry.
+    pub fn with_retry_policy(mut self, policy: Option<RetryPolicy>) -> Self {
+        self.opt_state.retry_policy = policy;
+        self
+    }
+
+    /// Sort on disk if a sort of this query needs more memory than `memory_budget` bytes,
+    /// see [`enable_external_sort`](crate::prelude::enable_external_sort). `None` falls back
+    /// to the global setting.
+    #[cfg(feature = "ipc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
+    pub fn with_external_sort_budget(mut self, memory_budget: Option<usize>) -> Self {
+        self.opt_state.external_sort_budget = memory_budget;
+        self
+    }
+
     /// Describe the logical plan.
     pub fn describe_plan(&self) -> String {
         self.logical_plan.describe()
@@ -271,11 +452,17 @@ impl LazyFrame {
     pub fn sort(self, by_column: &str, options: SortOptions) -> Self {
         let reverse = options.descending;
         let nulls_last = options.nulls_last;
+        let nan_position = options.nan_position;
 
         let opt_state = self.get_opt_state();
         let lp = self
             .get_plan_builder()
-            .sort(vec![col(by_column)], vec![reverse], nulls_last)
+            .sort(
+                vec![col(by_column)],
+                vec![reverse],
+                nulls_last,
+                nan_position,
+            )
             .build();
         Self::from_logical_plan(lp, opt_state)
     }
@@ -291,7 +478,7 @@ impl LazyFrame {
     /// /// Sort DataFrame by 'sepal.width' column
     /// fn example(df: DataFrame) -> LazyFrame {
     ///       df.lazy()
-    ///         .sort_by_exprs(vec![col("sepal.width")], vec![false], false)
+    ///         .sort_by_exprs(vec![col("sepal.width")], vec![false], false, Default::default())
     /// }
     /// ```
     pub fn sort_by_exprs<E: AsRef<[Expr]>, B: AsRef<[bool]>>(
@@ -299,6 +486,7 @@ impl LazyFrame {
         by_exprs: E,
         reverse: B,
         nulls_last: bool,
+        nan_position: NanPosition,
     ) -> Self {
         let by_exprs = by_exprs.as_ref().to_vec();
         let reverse = reverse.as_ref().to_vec();
@@ -308,7 +496,7 @@ impl LazyFrame {
             let opt_state = self.get_opt_state();
             let lp = self
                 .get_plan_builder()
-                .sort(by_exprs, reverse, nulls_last)
+                .sort(by_exprs, reverse, nulls_last, nan_position)
                 .build();
             Self::from_logical_plan(lp, opt_state)
         }
@@ -462,10 +650,12 @@ impl LazyFrame {
             .into_iter()
             .map(|a| a.as_ref().to_string())
             .collect::<Vec<_>>();
-        // todo! make delayed
-        let schema = &*self.schema().unwrap();
-        // a column gets swapped
-        if new.iter().any(|name| schema.get(name).is_some()) {
+        // a column gets swapped, the error of an invalid plan is raised when it is executed
+        let swapping = match self.schema() {
+            Ok(schema) => new.iter().any(|name| schema.get(name).is_some()),
+            Err(_) => false,
+        };
+        if swapping {
             self.rename_impl_swapping(existing, new)
         } else {
             self.rename_impl(existing, new)
@@ -530,8 +720,13 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
-    /// Fetch is like a collect operation, but it overwrites the number of rows read by every scan
-    /// operation. This is a utility that helps debug a query on a smaller number of rows.
+    /// Fetch is like a collect operation, but it limits the number of rows read by every scan
+    /// operation to `n_rows`. This is a utility that helps debug a query on a smaller number of rows.
+    ///
+    /// The fetched rows are deterministic: every scan reads its first `n_rows` rows (or fewer if
+    /// the scan already has a smaller limit) and a scan of multiple files, or any other
+    /// concatenation, takes the first `n_rows` rows of its inputs in order, so the later files
+    /// are not read once enough rows are gathered.
     ///
     /// Note that the fetch does not guarantee the final number of rows in the DataFrame.
     /// Filter, join operations and a lower number of rows available in the scanned file influence
@@ -548,12 +743,19 @@ impl LazyFrame {
         lp_arena: &mut Arena<ALogicalPlan>,
         expr_arena: &mut Arena<AExpr>,
     ) -> Result<Node> {
+        trace_span!("optimize");
         // get toggle values
         let predicate_pushdown = self.opt_state.predicate_pushdown;
         let projection_pushdown = self.opt_state.projection_pushdown;
         let type_coercion = self.opt_state.type_coercion;
+        let strict_type_coercion = self.opt_state.strict_type_coercion;
         let simplify_expr = self.opt_state.simplify_expr;
         let slice_pushdown = self.opt_state.slice_pushdown;
+        let combine_filters = self.opt_state.combine_filters;
+        let deterministic = self
+            .opt_state
+            .deterministic
+            .unwrap_or_else(polars_core::config::deterministic);
 
         #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
         let agg_scan_projection = self.opt_state.file_caching;
@@ -577,6 +779,7 @@ impl LazyFrame {
         }
 
         if projection_pushdown {
+            trace_span!("projection_pushdown");
             let projection_pushdown_opt = ProjectionPushDown {};
             let alp = lp_arena.take(lp_top);
             let alp = projection_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
@@ -584,6 +787,7 @@ impl LazyFrame {
         }
 
         if predicate_pushdown {
+            trace_span!("predicate_pushdown");
             let predicate_pushdown_opt = PredicatePushDown::default();
             let alp = lp_arena.take(lp_top);
             let alp = predicate_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
@@ -592,6 +796,7 @@ impl LazyFrame {
 
         #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
         if agg_scan_projection {
+            trace_span!("file_caching");
             // we do this so that expressions are simplified created by the pushdown optimizations
             // we must clean up the predicates, because the agg_scan_projection
             // uses them in the hashtable to determine duplicates.
@@ -625,6 +830,7 @@ impl LazyFrame {
         rules.push(Box::new(DelayRechunk {}));
 
         if slice_pushdown {
+            trace_span!("slice_pushdown");
             let slice_pushdown_opt = SlicePushDown {};
             let alp = lp_arena.take(lp_top);
             let alp = slice_pushdown_opt.optimize(alp, lp_arena, expr_arena)?;
@@ -635,8 +841,11 @@ impl LazyFrame {
             rules.push(Box::new(slice_pushdown_opt));
         }
 
+        let mut coercion_error = None;
         if type_coercion {
-            rules.push(Box::new(TypeCoercionRule {}))
+            let rule = TypeCoercionRule::new(strict_type_coercion);
+            coercion_error = Some(rule.error_handle());
+            rules.push(Box::new(rule))
         }
         // this optimization removes branches, so we must do it when type coercion
         // is completed
@@ -648,9 +857,23 @@ impl LazyFrame {
             rules.push(Box::new(AggregatePushdown::new()))
         }
 
+        if combine_filters {
+            rules.push(Box::new(CombineFilters {}))
+        }
+
         rules.push(Box::new(ReplaceDropNulls {}));
 
-        lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top);
+        {
+            trace_span!("optimize_loop", rules = rules.len());
+            lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top);
+        }
+        if let Some(err) = coercion_error.and_then(|err| err.take()) {
+            return Err(err);
+        }
+
+        if deterministic {
+            maintain_order(lp_top, lp_arena);
+        }
 
         // during debug we check if the optimizations have not modified the final schema
         #[cfg(debug_assertions)]
@@ -686,16 +909,99 @@ impl LazyFrame {
     /// }
     /// ```
     pub fn collect(self) -> Result<DataFrame> {
-        let file_caching = self.opt_state.file_caching;
-        #[cfg(feature = "dtype-categorical")]
-        let use_string_cache = self.opt_state.global_string_cache;
+        self.collect_with_hooks(ExecutionHooks::default())
+    }
+
+    /// Execute the query like [`collect`](LazyFrame::collect), but abort it with an error once
+    /// `token` is cancelled. The token is checked between the operations of the query, so a
+    /// cancelled query stops after the running operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(lf: LazyFrame) -> Result<DataFrame> {
+    ///     let token = CancellationToken::new();
+    ///     // move a clone to the thread that may cancel the query
+    ///     let handle = token.clone();
+    ///     std::thread::spawn(move || {
+    ///         std::thread::sleep(std::time::Duration::from_secs(10));
+    ///         handle.cancel();
+    ///     });
+    ///     lf.collect_with_cancellation(&token)
+    /// }
+    /// ```
+    pub fn collect_with_cancellation(self, token: &CancellationToken) -> Result<DataFrame> {
+        self.collect_with_hooks(ExecutionHooks::new().with_cancellation(token))
+    }
+
+    /// Execute the query like [`collect`](LazyFrame::collect) and call `callback` with the
+    /// [`Progress`] of the query every time one of its operations is done, e.g. to draw a
+    /// progress bar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(lf: LazyFrame) -> Result<DataFrame> {
+    ///     lf.collect_with_progress(|progress| {
+    ///         eprintln!(
+    ///             "{} operations done, {} rows scanned",
+    ///             progress.nodes_completed, progress.rows_scanned
+    ///         )
+    ///     })
+    /// }
+    /// ```
+    pub fn collect_with_progress<F>(self, callback: F) -> Result<DataFrame>
+    where
+        F: Fn(&Progress) + Send + Sync + 'static,
+    {
+        self.collect_with_hooks(ExecutionHooks::new().with_progress(callback))
+    }
+
+    /// Execute the query like [`collect`](LazyFrame::collect) with the given `hooks`, to combine
+    /// the hooks of [`collect_with_cancellation`](LazyFrame::collect_with_cancellation) and
+    /// [`collect_with_progress`](LazyFrame::collect_with_progress).
+    pub fn collect_with_hooks(mut self, hooks: ExecutionHooks) -> Result<DataFrame> {
+        // the optimizer and the execution read the global mode once
+        self.opt_state
+            .deterministic
+            .get_or_insert_with(polars_core::config::deterministic);
+        let opt_state = self.opt_state;
         #[cfg(feature = "dtype-categorical")]
-        if use_string_cache {
+        if opt_state.global_string_cache {
             eprint!("global string cache in combination with LazyFrames is deprecated; please set the global string cache globally.")
         }
         let mut expr_arena = Arena::with_capacity(256);
         let mut lp_arena = Arena::with_capacity(128);
         let lp_top = self.optimize(&mut lp_arena, &mut expr_arena)?;
+        Self::execute_optimized(opt_state, lp_top, &mut lp_arena, &mut expr_arena, hooks)
+    }
+
+    /// Execute an optimized logical plan.
+    pub(crate) fn execute_optimized(
+        opt_state: OptState,
+        lp_top: Node,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        hooks: ExecutionHooks,
+    ) -> Result<DataFrame> {
+        trace_span!("execute");
+        if let Some(token) = &hooks.cancel_token {
+            token.check()?;
+        }
+        let file_caching = opt_state.file_caching;
+        #[cfg(feature = "dtype-categorical")]
+        let use_string_cache = opt_state.global_string_cache;
+
+        let (result_cache_key, cached) = get_cached_result(lp_top, lp_arena, expr_arena);
+        if let Some(df) = cached {
+            return Ok(df);
+        }
 
         // if string cache was already set, we skip this and global settings are respected
         #[cfg(feature = "dtype-categorical")]
@@ -707,7 +1013,7 @@ impl LazyFrame {
             #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
             {
                 let mut fps = Vec::with_capacity(8);
-                collect_fingerprints(lp_top, &mut fps, &lp_arena, &expr_arena);
+                collect_fingerprints(lp_top, &mut fps, lp_arena, expr_arena);
                 Some(fps)
             }
             #[cfg(not(any(feature = "ipc", feature = "parquet", feature = "csv-file")))]
@@ -719,15 +1025,32 @@ impl LazyFrame {
         };
 
         let planner = DefaultPlanner::default();
-        let mut physical_plan =
-            planner.create_physical_plan(lp_top, &mut lp_arena, &mut expr_arena)?;
+        let mut physical_plan = planner.create_physical_plan(lp_top, lp_arena, expr_arena)?;
 
         let mut state = ExecutionState::with_finger_prints(finger_prints);
+        state.set_options(&opt_state);
+        state.cancel_token = hooks.cancel_token;
+        state.progress = hooks
+            .progress
+            .map(|callback| Arc::new(ProgressTracker::new(callback)));
+        let track_memory = state.verbose() && memory::using_tracking_allocator();
+        if track_memory {
+            memory::reset_peak_allocated_bytes();
+        }
         let out = physical_plan.execute(&mut state);
+        if track_memory {
+            eprintln!(
+                "peak memory of the query: {} bytes",
+                memory::peak_allocated_bytes()
+            );
+        }
         #[cfg(feature = "dtype-categorical")]
         if use_string_cache {
             toggle_string_cache(!use_string_cache);
         }
+        if let (Some(key), Ok(df)) = (result_cache_key, &out) {
+            insert_result(key, df)
+        }
         out
     }
 
@@ -826,6 +1149,8 @@ impl LazyFrame {
             maintain_order: false,
             dynamic_options: None,
             rolling_options: None,
+            nulls_equal: true,
+            nans_equal: false,
         }
     }
 
@@ -842,6 +1167,8 @@ impl LazyFrame {
             maintain_order: true,
             dynamic_options: None,
             rolling_options: Some(options),
+            nulls_equal: true,
+            nans_equal: false,
         }
     }
 
@@ -858,10 +1185,14 @@ impl LazyFrame {
             maintain_order: true,
             dynamic_options: Some(options),
             rolling_options: None,
+            nulls_equal: true,
+            nans_equal: false,
         }
     }
 
     /// Similar to groupby, but order of the DataFrame is maintained.
+    ///
+    /// The groups are guaranteed to be in the order in which their keys first occur.
     pub fn groupby_stable<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, by: E) -> LazyGroupBy {
         let keys = by
             .as_ref()
@@ -876,6 +1207,8 @@ impl LazyFrame {
             maintain_order: true,
             dynamic_options: None,
             rolling_options: None,
+            nulls_equal: true,
+            nans_equal: false,
         }
     }
 
@@ -933,6 +1266,74 @@ impl LazyFrame {
         self.join(other, vec![], vec![], JoinType::Cross)
     }
 
+    /// Inner join on inequality predicates, without materializing the cartesian product.
+    ///
+    /// Every predicate compares an expression on the left table with an expression on the right
+    /// table: `col("start").lt_eq(col("ts"))` means `left.start <= right.ts`. Predicates can also
+    /// be combined with `and`. All columns of both tables are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// /// Match every event with the intervals `start <= ts < end` that contain it.
+    /// fn events_in_intervals(intervals: LazyFrame, events: LazyFrame) -> Result<LazyFrame> {
+    ///     intervals.join_where(events, [col("start").lt_eq(col("ts")), col("end").gt(col("ts"))])
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails if a predicate is not a comparison with `<`, `<=`, `>` or `>=`.
+    #[cfg(feature = "join_where")]
+    pub fn join_where<E: AsRef<[Expr]>>(
+        self,
+        other: LazyFrame,
+        predicates: E,
+    ) -> Result<LazyFrame> {
+        fn split(e: &Expr, out: &mut Vec<(Expr, InequalityOperator, Expr)>) -> Result<()> {
+            use InequalityOperator::*;
+            let op = match e {
+                Expr::BinaryExpr { left, op, right } => match op {
+                    Operator::And => {
+                        split(left, out)?;
+                        return split(right, out);
+                    }
+                    Operator::Lt => Some((left, Lt, right)),
+                    Operator::LtEq => Some((left, LtEq, right)),
+                    Operator::Gt => Some((left, Gt, right)),
+                    Operator::GtEq => Some((left, GtEq, right)),
+                    _ => None,
+                },
+                _ => None,
+            };
+            match op {
+                Some((left, op, right)) => {
+                    out.push((left.as_ref().clone(), op, right.as_ref().clone()));
+                    Ok(())
+                }
+                None => Err(PolarsError::ComputeError(
+                    format!("join_where expects inequality predicates, got: {:?}", e).into(),
+                )),
+            }
+        }
+        let mut split_predicates = vec![];
+        for e in predicates.as_ref() {
+            split(e, &mut split_predicates)?;
+        }
+        let mut left_on = Vec::with_capacity(split_predicates.len());
+        let mut ops = Vec::with_capacity(split_predicates.len());
+        let mut right_on = Vec::with_capacity(split_predicates.len());
+        for (left, op, right) in split_predicates {
+            left_on.push(left);
+            ops.push(op);
+            right_on.push(right);
+        }
+        Ok(self.join(other, left_on, right_on, JoinType::Inequality(ops)))
+    }
+
     /// Generic join function that can join on multiple columns.
     ///
     /// # Example
@@ -955,6 +1356,12 @@ impl LazyFrame {
     ) -> LazyFrame {
         // if any of the nodes reads from files we must activate this this plan as well.
         self.opt_state.file_caching |= other.opt_state.file_caching;
+        self.opt_state.low_memory |= other.opt_state.low_memory;
+        self.opt_state.retry_policy = self.opt_state.retry_policy.or(other.opt_state.retry_policy);
+        self.opt_state.external_sort_budget = self
+            .opt_state
+            .external_sort_budget
+            .or(other.opt_state.external_sort_budget);
 
         let left_on = left_on.as_ref().to_vec();
         let right_on = right_on.as_ref().to_vec();
@@ -1068,17 +1475,29 @@ impl LazyFrame {
 
     /// Apply explode operation. [See eager explode](polars_core::frame::DataFrame::explode).
     pub fn explode<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, columns: E) -> LazyFrame {
+        self.explode_with_options(columns, ExplodeOptions::default())
+    }
+
+    /// Apply explode operation with options that determine what empty lists and nulls explode to.
+    /// [See eager explode](polars_core::frame::DataFrame::explode_with_options).
+    pub fn explode_with_options<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        columns: E,
+        options: ExplodeOptions,
+    ) -> LazyFrame {
         let columns = columns
             .as_ref()
             .iter()
             .map(|e| e.clone().into())
             .collect::<Vec<_>>();
         let opt_state = self.get_opt_state();
-        let lp = self.get_plan_builder().explode(columns).build();
+        let lp = self.get_plan_builder().explode(columns, options).build();
         Self::from_logical_plan(lp, opt_state)
     }
 
-    /// Keep unique rows and maintain order
+    /// Keep unique rows and maintain order.
+    ///
+    /// The rows are guaranteed to be in the order of their first occurrence.
     pub fn unique_stable(
         self,
         subset: Option<Vec<String>>,
@@ -1124,6 +1543,20 @@ impl LazyFrame {
         }
     }
 
+    /// Drop rows with a `NaN` value. Columns that are not of a float dtype have no `NaN` values.
+    ///
+    /// Equal to `LazyFrame::filter(col("*").is_not_nan())`
+    pub fn drop_nans(self, subset: Option<Vec<Expr>>) -> LazyFrame {
+        match subset {
+            None => self.filter(col("*").is_not_nan()),
+            Some(subset) => {
+                let it = subset.into_iter().map(|e| e.is_not_nan());
+                let predicate = combine_predicates_expr(it);
+                self.filter(predicate)
+            }
+        }
+    }
+
     /// Slice the DataFrame.
     pub fn slice(self, offset: i64, len: IdxSize) -> LazyFrame {
         let opt_state = self.get_opt_state();
@@ -1295,6 +1728,30 @@ impl LazyFrame {
             Some("unnest"),
         )
     }
+
+    /// Flatten nested `Struct` columns and lists of records into a flat `LazyFrame`,
+    /// see [`DataFrameOps::json_normalize`].
+    #[cfg(feature = "json_normalize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json_normalize")))]
+    pub fn json_normalize(self, options: NormalizeOptions) -> Self {
+        let schema_options = options.clone();
+        let udf_schema =
+            move |schema: &Schema| Ok(Arc::new(json_normalize_schema(schema, &schema_options)));
+        // the lists of records are exploded into rows and the struct fields are renamed, so
+        // neither rows nor columns may be pushed down
+        let optimizations = AllowedOptimizations {
+            predicate_pushdown: false,
+            projection_pushdown: false,
+            slice_pushdown: false,
+            ..Default::default()
+        };
+        self.map(
+            move |df| df.json_normalize(&options),
+            Some(optimizations),
+            Some(Arc::new(udf_schema)),
+            Some("json_normalize"),
+        )
+    }
 }
 
 /// Utility struct for lazy groupby operation.
@@ -1306,9 +1763,26 @@ pub struct LazyGroupBy {
     maintain_order: bool,
     dynamic_options: Option<DynamicGroupOptions>,
     rolling_options: Option<RollingGroupOptions>,
+    nulls_equal: bool,
+    nans_equal: bool,
 }
 
 impl LazyGroupBy {
+    /// Whether the rows of which a key is null form one group, or a group each. Only applies
+    /// to [`LazyGroupBy::agg`]. Defaults to `true`.
+    pub fn nulls_equal(mut self, nulls_equal: bool) -> Self {
+        self.nulls_equal = nulls_equal;
+        self
+    }
+
+    /// Whether all `NaN` keys form one group. Otherwise `NaN` keys are grouped by their bit
+    /// representation, so `NaN` values with a different sign or payload form different groups.
+    /// Only applies to [`LazyGroupBy::agg`]. Defaults to `false`.
+    pub fn nans_equal(mut self, nans_equal: bool) -> Self {
+        self.nans_equal = nans_equal;
+        self
+    }
+
     /// Group by and aggregate.
     ///
     /// Select a column with [col](crate::dsl::col) and choose an aggregation.
@@ -1331,7 +1805,27 @@ impl LazyGroupBy {
     ///        ])
     /// }
     /// ```
-    pub fn agg<E: AsRef<[Expr]>>(self, aggs: E) -> LazyFrame {
+    pub fn agg<E: AsRef<[Expr]>>(mut self, aggs: E) -> LazyFrame {
+        if self.nans_equal {
+            self.keys = self
+                .keys
+                .into_iter()
+                .map(|key| key.map(|s| Ok(s.canonicalize_nans()), GetOutput::same_type()))
+                .collect();
+        }
+        let nulls_equal = self.nulls_equal || self.keys.is_empty();
+        if !nulls_equal {
+            // split the null groups with an extra key, that is dropped after the aggregation
+            let null_group_key = self.keys[0]
+                .clone()
+                .map_many(
+                    |keys| Ok(_null_group_key(keys)),
+                    &self.keys[1..],
+                    GetOutput::from_type(IDX_DTYPE),
+                )
+                .alias(_NULL_GROUP_KEY);
+            self.keys.push(null_group_key);
+        }
         let lp = LogicalPlanBuilder::from(self.logical_plan)
             .groupby(
                 Arc::new(self.keys),
@@ -1342,35 +1836,102 @@ impl LazyGroupBy {
                 self.rolling_options,
             )
             .build();
-        LazyFrame::from_logical_plan(lp, self.opt_state)
+        let lf = LazyFrame::from_logical_plan(lp, self.opt_state);
+        if nulls_equal {
+            lf
+        } else {
+            lf.drop_columns([_NULL_GROUP_KEY])
+        }
     }
 
-    /// Return first n rows of each group
-    pub fn head(self, n: Option<usize>) -> LazyFrame {
-        let keys = self
+    fn slice_groups(self, n: Option<usize>, tail: bool) -> LazyFrame {
+        let key_names = self
             .keys
             .iter()
-            .flat_map(|k| expr_to_root_column_names(k).into_iter())
-            .collect::<Vec<_>>();
+            .map(|e| match e {
+                Expr::Column(name) => Some(name.to_string()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+
+        match key_names {
+            // slice the groups at once by taking their first or last indices
+            Some(key_names) if self.dynamic_options.is_none() && self.rolling_options.is_none() => {
+                let maintain_order = self.maintain_order;
+                let lf = LazyFrame::from_logical_plan(self.logical_plan, self.opt_state);
+                let names = key_names.clone();
+                let schema = move |input_schema: &Schema| -> Result<SchemaRef> {
+                    let mut schema = Schema::with_capacity(input_schema.len());
+                    for name in &names {
+                        let dtype = input_schema
+                            .get(name)
+                            .ok_or_else(|| PolarsError::NotFound(name.to_string()))?;
+                        schema.with_column(name.clone(), dtype.clone());
+                    }
+                    for (name, dtype) in input_schema.iter() {
+                        if !names.contains(name) {
+                            schema.with_column(name.clone(), dtype.clone());
+                        }
+                    }
+                    Ok(Arc::new(schema))
+                };
+                // the slices depend on all rows and all columns, so nothing may be pushed down
+                let optimizations = AllowedOptimizations {
+                    predicate_pushdown: false,
+                    projection_pushdown: false,
+                    slice_pushdown: false,
+                    ..Default::default()
+                };
+                lf.map(
+                    move |df: DataFrame| {
+                        let gb = if maintain_order {
+                            df.groupby_stable(&key_names)?
+                        } else {
+                            df.groupby(&key_names)?
+                        };
+                        if tail {
+                            gb.tail(n)
+                        } else {
+                            gb.head(n)
+                        }
+                    },
+                    Some(optimizations),
+                    Some(Arc::new(schema)),
+                    Some(if tail { "GROUPBY_TAIL" } else { "GROUPBY_HEAD" }),
+                )
+            }
+            _ => {
+                let keys = self
+                    .keys
+                    .iter()
+                    .flat_map(|k| expr_to_root_column_names(k).into_iter())
+                    .collect::<Vec<_>>();
+                let slice = if tail {
+                    col("*").exclude(&keys).tail(n)
+                } else {
+                    col("*").exclude(&keys).head(n)
+                };
+                self.agg([slice.list().keep_name()])
+                    .explode([col("*").exclude(&keys)])
+            }
+        }
+    }
 
-        self.agg([col("*").exclude(&keys).head(n).list().keep_name()])
-            .explode([col("*").exclude(&keys)])
+    /// Return first n rows of each group. The rows of a group keep their order.
+    pub fn head(self, n: Option<usize>) -> LazyFrame {
+        self.slice_groups(n, false)
     }
 
-    /// Return last n rows of each group
+    /// Return last n rows of each group. The rows of a group keep their order.
     pub fn tail(self, n: Option<usize>) -> LazyFrame {
-        let keys = self
-            .keys
-            .iter()
-            .flat_map(|k| expr_to_root_column_names(k).into_iter())
-            .collect::<Vec<_>>();
-
-        self.agg([col("*").exclude(&keys).tail(n).keep_name()])
-            .explode([col("*").exclude(&keys)])
+        self.slice_groups(n, true)
     }
 
     /// Apply a function over the groups as a new `DataFrame`. It is not recommended that you use
     /// this as materializing the `DataFrame` is quite expensive.
+    ///
+    /// The function is called for the groups in parallel, the results are concatenated in the
+    /// order of the groups and must have the same schema.
     pub fn apply<F>(self, f: F) -> LazyFrame
     where
         F: 'static + Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
@@ -1399,6 +1960,8 @@ pub struct JoinBuilder {
     allow_parallel: bool,
     force_parallel: bool,
     suffix: Option<String>,
+    validate: JoinValidation,
+    nulls_equal: bool,
 }
 impl JoinBuilder {
     pub fn new(lf: LazyFrame) -> Self {
@@ -1411,6 +1974,8 @@ impl JoinBuilder {
             allow_parallel: true,
             force_parallel: false,
             suffix: None,
+            validate: JoinValidation::ManyToMany,
+            nulls_equal: true,
         }
     }
 
@@ -1456,6 +2021,21 @@ impl JoinBuilder {
         self
     }
 
+    /// Check that the join keys fulfil the expected relation, e.g. that the keys of the
+    /// right table are unique for [`JoinValidation::ManyToOne`].
+    /// Defaults to [`JoinValidation::ManyToMany`], which does no checks.
+    pub fn validate(mut self, validate: JoinValidation) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Whether null keys match each other. If `false`, a null key doesn't match any key,
+    /// as in SQL. Defaults to `true`.
+    pub fn nulls_equal(mut self, nulls_equal: bool) -> Self {
+        self.nulls_equal = nulls_equal;
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let mut opt_state = self.lf.opt_state;
@@ -1463,6 +2043,11 @@ impl JoinBuilder {
 
         // if any of the nodes reads from files we must activate this this plan as well.
         opt_state.file_caching |= other.opt_state.file_caching;
+        opt_state.low_memory |= other.opt_state.low_memory;
+        opt_state.retry_policy = opt_state.retry_policy.or(other.opt_state.retry_policy);
+        opt_state.external_sort_budget = opt_state
+            .external_sort_budget
+            .or(other.opt_state.external_sort_budget);
 
         let suffix = match self.suffix {
             None => Cow::Borrowed("_right"),
@@ -1482,6 +2067,8 @@ impl JoinBuilder {
                     how: self.how,
                     suffix,
                     slice: None,
+                    validate: self.validate,
+                    nulls_equal: self.nulls_equal,
                 },
             )
             .build();
@@ -1,7 +1,9 @@
+use super::directory::with_path_column;
 use crate::prelude::*;
 use polars_core::prelude::*;
-use polars_io::parquet::ParallelStrategy;
-use polars_io::RowCount;
+use polars_io::parquet::{ParallelStrategy, ParquetReader};
+use polars_io::{RowCount, SerReader};
+use std::path::{Path, PathBuf};
 
 #[derive(Clone)]
 pub struct ScanArgsParquet {
@@ -11,6 +13,9 @@ pub struct ScanArgsParquet {
     pub rechunk: bool,
     pub row_count: Option<RowCount>,
     pub low_memory: bool,
+    /// Read the string columns as `Categorical`, see
+    /// [`ParquetReader::with_dictionary_as_categorical`].
+    pub dictionary_as_categorical: bool,
 }
 
 impl Default for ScanArgsParquet {
@@ -22,11 +27,13 @@ impl Default for ScanArgsParquet {
             rechunk: true,
             row_count: None,
             low_memory: false,
+            dictionary_as_categorical: false,
         }
     }
 }
 
 impl LazyFrame {
+    #[allow(clippy::too_many_arguments)]
     fn scan_parquet_impl(
         path: String,
         n_rows: Option<usize>,
@@ -35,9 +42,17 @@ impl LazyFrame {
         row_count: Option<RowCount>,
         rechunk: bool,
         low_memory: bool,
+        dictionary_as_categorical: bool,
     ) -> Result<Self> {
         let mut lf: LazyFrame = LogicalPlanBuilder::scan_parquet(
-            path, n_rows, cache, parallel, None, rechunk, low_memory,
+            path,
+            n_rows,
+            cache,
+            parallel,
+            None,
+            rechunk,
+            low_memory,
+            dictionary_as_categorical,
         )?
         .build()
         .into();
@@ -51,38 +66,59 @@ impl LazyFrame {
         Ok(lf)
     }
 
-    fn concat_impl(lfs: Vec<LazyFrame>, args: ScanArgsParquet) -> Result<LazyFrame> {
-        concat(&lfs, args.rechunk).map(|mut lf| {
-            if let Some(n_rows) = args.n_rows {
-                lf = lf.slice(0, n_rows as IdxSize)
-            };
-            if let Some(rc) = args.row_count {
-                lf = lf.with_row_count(&rc.name, Some(rc.offset))
-            };
-            lf
-        })
-    }
-
-    /// Create a LazyFrame directly from a parquet scan.
-    #[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
-    #[deprecated(note = "please use `concat_lf` instead")]
-    pub fn scan_parquet_files(paths: Vec<String>, args: ScanArgsParquet) -> Result<Self> {
-        let lfs = paths
-            .iter()
-            .map(|p| {
+    fn scan_parquet_list(
+        paths: &[PathBuf],
+        args: ScanArgsParquet,
+        parallel: ParallelStrategy,
+        path_column: Option<&str>,
+    ) -> Result<Self> {
+        Self::scan_file_list(
+            paths,
+            args.n_rows,
+            args.row_count,
+            args.rechunk,
+            |path, n_rows, row_count| {
                 Self::scan_parquet_impl(
-                    p.to_string(),
-                    args.n_rows,
+                    path.to_string_lossy().into_owned(),
+                    n_rows,
                     args.cache,
-                    args.parallel,
-                    None,
-                    args.rechunk,
+                    parallel,
+                    row_count,
+                    false,
                     args.low_memory,
+                    args.dictionary_as_categorical,
                 )
-            })
-            .collect::<Result<Vec<_>>>()?;
+                .map(|lf| with_path_column(lf, path, path_column))
+            },
+            |file| ParquetReader::new(file).num_rows(),
+        )
+    }
+
+    /// Create a LazyFrame from the parquet scans of the given files, in the given order.
+    ///
+    /// Unlike a glob pattern in [`LazyFrame::scan_parquet`], the paths are taken literally.
+    /// If `n_rows` or a `row_count` is set, the number of rows of the files is read from their
+    /// metadata, which is cached for the next scan of the same files.
+    #[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
+    pub fn scan_parquet_files<I, P>(paths: I, args: ScanArgsParquet) -> Result<Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let paths = paths
+            .into_iter()
+            .map(|p| p.as_ref().to_path_buf())
+            .collect::<Vec<_>>();
+        let parallel = args.parallel;
+        Self::scan_parquet_list(&paths, args, parallel, None)
+    }
 
-        Self::concat_impl(lfs, args)
+    /// Create a LazyFrame from the parquet scans of the files of a directory tree selected by `dir`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
+    pub fn scan_parquet_dir(mut dir: DirectoryScan, args: ScanArgsParquet) -> Result<Self> {
+        let paths = dir.collect_paths()?;
+        let parallel = args.parallel;
+        Self::scan_parquet_list(&paths, args, parallel, dir.path_column())
     }
 
     /// Create a LazyFrame directly from a parquet scan.
@@ -90,24 +126,13 @@ impl LazyFrame {
     pub fn scan_parquet(path: String, args: ScanArgsParquet) -> Result<Self> {
         if path.contains('*') {
             let paths = glob::glob(&path)
-                .map_err(|_| PolarsError::ComputeError("invalid glob pattern given".into()))?;
-            let lfs = paths
-                .map(|r| {
-                    let path = r.map_err(|e| PolarsError::ComputeError(format!("{}", e).into()))?;
-                    let path_string = path.to_string_lossy().into_owned();
-                    Self::scan_parquet_impl(
-                        path_string,
-                        args.n_rows,
-                        args.cache,
-                        ParallelStrategy::None,
-                        None,
-                        args.rechunk,
-                        args.low_memory,
-                    )
-                })
+                .map_err(|_| PolarsError::ComputeError("invalid glob pattern given".into()))?
+                .map(|r| r.map_err(|e| PolarsError::ComputeError(format!("{}", e).into())))
                 .collect::<Result<Vec<_>>>()?;
-
-            Self::concat_impl(lfs, args)
+            if paths.is_empty() {
+                return Err(PolarsError::ComputeError("no matching files found".into()));
+            }
+            Self::scan_parquet_list(&paths, args, ParallelStrategy::None, None)
         } else {
             Self::scan_parquet_impl(
                 path,
@@ -117,6 +142,7 @@ impl LazyFrame {
                 args.row_count,
                 args.rechunk,
                 args.low_memory,
+                args.dictionary_as_categorical,
             )
         }
     }
@@ -0,0 +1,277 @@
+use crate::prelude::*;
+use parking_lot::Mutex;
+use polars_core::prelude::*;
+
+/// Collect the values of the parameters. Every value must be a literal.
+fn parameter_values<I, S>(params: I) -> Result<PlHashMap<String, LiteralValue>>
+where
+    I: IntoIterator<Item = (S, Expr)>,
+    S: AsRef<str>,
+{
+    params
+        .into_iter()
+        .map(|(name, value)| match value {
+            Expr::Literal(LiteralValue::Parameter { .. })
+            | Expr::Literal(LiteralValue::Range { .. }) => Err(PolarsError::ComputeError(
+                format!("the value of parameter '{}' is not valid", name.as_ref()).into(),
+            )),
+            Expr::Literal(lv) => Ok((name.as_ref().to_string(), lv)),
+            _ => Err(PolarsError::ComputeError(
+                format!(
+                    "the value of parameter '{}' must be a literal",
+                    name.as_ref()
+                )
+                .into(),
+            )),
+        })
+        .collect()
+}
+
+/// Replace the [`lit_param`] placeholders in `expr_arena` by the result of `f` on their values.
+fn replace_parameters<F>(
+    expr_arena: &mut Arena<AExpr>,
+    params: &PlHashMap<String, LiteralValue>,
+    f: F,
+) -> Result<()>
+where
+    F: Fn(&str, &LiteralValue) -> LiteralValue,
+{
+    for idx in 0..expr_arena.len() {
+        let node = Node(idx);
+        if let AExpr::Literal(LiteralValue::Parameter { name, .. }) = expr_arena.get(node) {
+            let value = params.get(name).ok_or_else(|| {
+                PolarsError::NotFound(format!("no value is bound to parameter '{}'", name))
+            })?;
+            let lv = f(name, value);
+            expr_arena.replace(node, AExpr::Literal(lv));
+        }
+    }
+    Ok(())
+}
+
+/// Replace the [`lit_param`] placeholders in `expr_arena` by their values.
+fn bind_parameters(
+    expr_arena: &mut Arena<AExpr>,
+    params: &PlHashMap<String, LiteralValue>,
+) -> Result<()> {
+    replace_parameters(expr_arena, params, |_, value| value.clone())
+}
+
+/// Give the [`lit_param`] placeholders in `expr_arena` the types of their values.
+fn type_parameters(
+    expr_arena: &mut Arena<AExpr>,
+    params: &PlHashMap<String, LiteralValue>,
+) -> Result<()> {
+    replace_parameters(expr_arena, params, |name, value| LiteralValue::Parameter {
+        name: name.to_string(),
+        dtype: value.get_datatype(),
+    })
+}
+
+/// Recompute the schemas of the plan that depend on the types of its expressions, as the
+/// types of the parameters are only known once they are bound.
+fn update_schemas(node: Node, lp_arena: &mut Arena<ALogicalPlan>, expr_arena: &mut Arena<AExpr>) {
+    for input in lp_arena.get(node).get_inputs() {
+        update_schemas(input, lp_arena, expr_arena);
+    }
+    use ALogicalPlan::*;
+    let lp = match lp_arena.get(node).clone() {
+        Projection { input, expr, .. } if !expr.is_empty() => {
+            ALogicalPlanBuilder::new(input, expr_arena, lp_arena)
+                .project(expr)
+                .build()
+        }
+        LocalProjection { input, expr, .. } => {
+            ALogicalPlanBuilder::new(input, expr_arena, lp_arena)
+                .project_local(expr)
+                .build()
+        }
+        HStack { input, exprs, .. } => ALogicalPlanBuilder::new(input, expr_arena, lp_arena)
+            .with_columns(exprs)
+            .build(),
+        Aggregate {
+            input,
+            keys,
+            aggs,
+            apply,
+            maintain_order,
+            options,
+            ..
+        } => ALogicalPlanBuilder::new(input, expr_arena, lp_arena)
+            .groupby(keys, aggs, apply, maintain_order, options)
+            .build(),
+        Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } => ALogicalPlanBuilder::new(input_left, expr_arena, lp_arena)
+            .join(input_right, left_on, right_on, options)
+            .build(),
+        Melt { input, args, .. } => ALogicalPlanBuilder::new(input, expr_arena, lp_arena)
+            .melt(args)
+            .build(),
+        Explode {
+            input,
+            columns,
+            options,
+            ..
+        } => {
+            let mut schema = (**lp_arena.get(input).schema(lp_arena)).clone();
+            for name in &columns {
+                if let Some(DataType::List(inner)) = schema.get(name) {
+                    let inner = *inner.clone();
+                    schema.with_column(name.clone(), inner)
+                }
+            }
+            Explode {
+                input,
+                columns,
+                options,
+                schema: Arc::new(schema),
+            }
+        }
+        _ => return,
+    };
+    lp_arena.replace(node, lp);
+}
+
+/// Replace the [`lit_param`] placeholders of `lp` with `replace` and update the schemas.
+fn replace_in_plan<F>(
+    lp: LogicalPlan,
+    params: &PlHashMap<String, LiteralValue>,
+    replace: F,
+) -> Result<LogicalPlan>
+where
+    F: Fn(&mut Arena<AExpr>, &PlHashMap<String, LiteralValue>) -> Result<()>,
+{
+    let mut expr_arena = Arena::with_capacity(64);
+    let mut lp_arena = Arena::with_capacity(32);
+    let root = to_alp(lp, &mut expr_arena, &mut lp_arena)?;
+    replace(&mut expr_arena, params)?;
+    update_schemas(root, &mut lp_arena, &mut expr_arena);
+    Ok(node_to_lp(root, &mut expr_arena, &mut lp_arena))
+}
+
+impl LazyFrame {
+    /// Bind values to the [`lit_param`] placeholders of this query.
+    ///
+    /// To execute a query many times with different values without optimizing it
+    /// every time, use [`LazyFrame::prepare`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(lf: LazyFrame) -> Result<DataFrame> {
+    ///     lf.filter(col("sepal.width").gt(lit_param("min_width")))
+    ///         .with_parameters([("min_width", lit(3.0))])?
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn with_parameters<I, S>(self, params: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (S, Expr)>,
+        S: AsRef<str>,
+    {
+        let params = parameter_values(params)?;
+        let opt_state = self.opt_state;
+        let lp = replace_in_plan(self.logical_plan, &params, bind_parameters)?;
+        Ok(Self::from_logical_plan(lp, opt_state))
+    }
+
+    /// Prepare this query to be executed many times with different values for its
+    /// [`lit_param`] placeholders. The query is optimized once for every combination of
+    /// the types of the values, as the types decide how the values are coerced.
+    pub fn prepare(self) -> Result<PreparedLazyFrame> {
+        // fail early on an invalid query
+        self.schema()?;
+        Ok(PreparedLazyFrame {
+            logical_plan: self.logical_plan,
+            opt_state: self.opt_state,
+            optimized: Default::default(),
+        })
+    }
+}
+
+/// The names and types of the values of the parameters.
+type ParameterTypes = Vec<(String, DataType)>;
+
+/// An optimized plan, with the parameters still to be bound.
+#[derive(Clone)]
+struct OptimizedPlan {
+    root: Node,
+    lp_arena: Arena<ALogicalPlan>,
+    expr_arena: Arena<AExpr>,
+}
+
+/// A query with [`lit_param`] placeholders, created by [`LazyFrame::prepare`].
+#[derive(Clone)]
+pub struct PreparedLazyFrame {
+    logical_plan: LogicalPlan,
+    opt_state: OptState,
+    optimized: Arc<Mutex<Vec<(ParameterTypes, OptimizedPlan)>>>,
+}
+
+impl PreparedLazyFrame {
+    /// Get the plan optimized for the types of `params`.
+    fn optimized_plan(&self, params: &PlHashMap<String, LiteralValue>) -> Result<OptimizedPlan> {
+        let mut types = params
+            .iter()
+            .map(|(name, value)| (name.clone(), value.get_datatype()))
+            .collect::<ParameterTypes>();
+        types.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        let mut optimized = self.optimized.lock();
+        if let Some((_, plan)) = optimized.iter().find(|(t, _)| *t == types) {
+            return Ok(plan.clone());
+        }
+        let lp = replace_in_plan(self.logical_plan.clone(), params, type_parameters)?;
+        let mut lp_arena = Arena::with_capacity(128);
+        let mut expr_arena = Arena::with_capacity(256);
+        let root = LazyFrame::from_logical_plan(lp, self.opt_state)
+            .optimize(&mut lp_arena, &mut expr_arena)?;
+        let plan = OptimizedPlan {
+            root,
+            lp_arena,
+            expr_arena,
+        };
+        optimized.push((types, plan.clone()));
+        Ok(plan)
+    }
+
+    /// Execute the query with these values bound to its parameters.
+    pub fn collect_with_parameters<I, S>(&self, params: I) -> Result<DataFrame>
+    where
+        I: IntoIterator<Item = (S, Expr)>,
+        S: AsRef<str>,
+    {
+        self.collect_with_parameters_and_hooks(params, ExecutionHooks::default())
+    }
+
+    /// Execute the query like
+    /// [`collect_with_parameters`](PreparedLazyFrame::collect_with_parameters) with the given
+    /// `hooks`, e.g. to cancel it or to report its progress.
+    pub fn collect_with_parameters_and_hooks<I, S>(
+        &self,
+        params: I,
+        hooks: ExecutionHooks,
+    ) -> Result<DataFrame>
+    where
+        I: IntoIterator<Item = (S, Expr)>,
+        S: AsRef<str>,
+    {
+        let params = parameter_values(params)?;
+        let OptimizedPlan {
+            root,
+            mut lp_arena,
+            mut expr_arena,
+        } = self.optimized_plan(&params)?;
+        bind_parameters(&mut expr_arena, &params)?;
+        LazyFrame::execute_optimized(self.opt_state, root, &mut lp_arena, &mut expr_arena, hooks)
+    }
+}
@@ -0,0 +1,133 @@
+//! A catalog of queries that are registered by name.
+use crate::prelude::*;
+use parking_lot::RwLock;
+use polars_core::export::once_cell::sync::Lazy;
+use polars_core::prelude::*;
+use std::cell::RefCell;
+
+static GLOBAL_REGISTRY: Lazy<TableRegistry> = Lazy::new(TableRegistry::new);
+
+thread_local! {
+    /// The tables that are being resolved, by registry and name, to detect a table that
+    /// scans itself.
+    static RESOLVING: RefCell<Vec<(usize, String)>> = RefCell::new(vec![]);
+}
+
+/// Maps table names to the queries that produce them.
+///
+/// A [`LazyFrame`] that scans a table by name, created with [`TableRegistry::scan_table`]
+/// or [`LazyFrame::scan_table`], looks up the table when its plan is optimized. A table must
+/// be registered before a query scans it, but it may be replaced by a table with the same
+/// schema afterwards. A table must not scan itself.
+///
+/// Cloning a registry is cheap and the clones share their tables.
+#[derive(Clone, Default)]
+pub struct TableRegistry {
+    tables: Arc<RwLock<PlHashMap<String, LazyFrame>>>,
+}
+
+impl TableRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The registry used by [`LazyFrame::scan_table`].
+    pub fn global() -> &'static TableRegistry {
+        &GLOBAL_REGISTRY
+    }
+
+    /// Register `lf` under `name`. Returns the query previously registered under `name`.
+    pub fn register(&self, name: &str, lf: LazyFrame) -> Option<LazyFrame> {
+        self.tables.write().insert(name.to_string(), lf)
+    }
+
+    /// Remove the table `name` from the registry.
+    pub fn deregister(&self, name: &str) -> Option<LazyFrame> {
+        self.tables.write().remove(name)
+    }
+
+    /// Get the query registered under `name`.
+    pub fn get(&self, name: &str) -> Result<LazyFrame> {
+        self.tables.read().get(name).cloned().ok_or_else(|| {
+            PolarsError::NotFound(format!("no table with name '{}' is registered", name))
+        })
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.tables.read().contains_key(name)
+    }
+
+    /// The names of the registered tables in sorted order.
+    pub fn table_names(&self) -> Vec<String> {
+        let mut names = self.tables.read().keys().cloned().collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    /// Scan the table `name` of this registry.
+    pub fn scan_table(&self, name: &str) -> Result<LazyFrame> {
+        let schema = self.get(name)?.schema()?;
+        Ok(LogicalPlan::TableScan {
+            name: name.to_string(),
+            registry: self.clone(),
+            schema,
+        }
+        .into())
+    }
+
+    /// Pass the plan of the table `name` to `f`, which converts it. The table must have the
+    /// `schema` of the scan and may not be converted within its own conversion.
+    pub(crate) fn resolve<F, T>(&self, name: &str, schema: &Schema, f: F) -> Result<T>
+    where
+        F: FnOnce(LogicalPlan) -> Result<T>,
+    {
+        let lf = self.get(name)?;
+        if *lf.schema()? != *schema {
+            return Err(PolarsError::SchemaMisMatch(
+                format!(
+                    "the schema of table '{}' changed since the query was built",
+                    name
+                )
+                .into(),
+            ));
+        }
+        let key = (Arc::as_ptr(&self.tables) as usize, name.to_string());
+        let is_cycle = RESOLVING.with(|resolving| {
+            let mut resolving = resolving.borrow_mut();
+            let is_cycle = resolving.contains(&key);
+            if !is_cycle {
+                resolving.push(key);
+            }
+            is_cycle
+        });
+        if is_cycle {
+            return Err(PolarsError::ComputeError(
+                format!("table '{}' scans itself", name).into(),
+            ));
+        }
+        let out = f(lf.logical_plan);
+        RESOLVING.with(|resolving| resolving.borrow_mut().pop());
+        out
+    }
+}
+
+impl LazyFrame {
+    /// Scan the table `name` of the [global registry](TableRegistry::global).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     TableRegistry::global().register("iris", df.lazy());
+    ///     LazyFrame::scan_table("iris")?
+    ///         .filter(col("sepal.width").gt(lit(3.0)))
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn scan_table(name: &str) -> Result<Self> {
+        TableRegistry::global().scan_table(name)
+    }
+}
@@ -106,6 +106,10 @@ pub enum AExpr {
     },
     Count,
     Nth(i64),
+    Cache {
+        input: Node,
+        id: usize,
+    },
 }
 
 impl Default for AExpr {
@@ -178,6 +182,7 @@ impl AExpr {
                 Ok(Field::new(field.name(), DataType::Boolean))
             }
             Reverse(expr) => arena.get(*expr).to_field(schema, ctxt, arena),
+            Cache { input, .. } => arena.get(*input).to_field(schema, ctxt, arena),
             Explode(expr) => {
                 let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
 
@@ -4,7 +4,7 @@ use crate::logical_plan::IpcScanOptionsInner;
 use crate::logical_plan::ParquetOptions;
 use crate::logical_plan::{det_melt_schema, Context, CsvParserOptions};
 use crate::prelude::*;
-use crate::utils::{aexprs_to_schema, PushNode};
+use crate::utils::{aexpr_join_key_column, aexprs_to_schema, PushNode};
 use polars_core::frame::explode::MeltArgs;
 use polars_core::prelude::*;
 use polars_utils::arena::{Arena, Node};
@@ -98,6 +98,7 @@ pub enum ALogicalPlan {
     Explode {
         input: Node,
         columns: Vec<String>,
+        options: ExplodeOptions,
         schema: SchemaRef,
     },
     Cache {
@@ -176,6 +177,55 @@ impl ALogicalPlan {
         }
     }
 
+    /// Describe this node without its inputs, to point at the failing node in error messages.
+    pub(crate) fn describe_node(&self, expr_arena: &Arena<AExpr>) -> String {
+        use ALogicalPlan::*;
+        let to_exprs = |nodes: &[Node]| {
+            nodes
+                .iter()
+                .map(|node| node_to_expr(*node, expr_arena))
+                .collect::<Vec<_>>()
+        };
+        match self {
+            AnonymousScan { options, .. } => options.fmt_str.to_string(),
+            #[cfg(feature = "python")]
+            PythonScan { .. } => "PYTHON SCAN".to_string(),
+            Melt { .. } => "MELT".to_string(),
+            Slice { offset, len, .. } => format!("SLICE[offset: {}, len: {}]", offset, len),
+            Selection { predicate, .. } => {
+                format!("FILTER {:?}", node_to_expr(*predicate, expr_arena))
+            }
+            #[cfg(feature = "csv-file")]
+            CsvScan { path, .. } => format!("CSV SCAN {}", path.to_string_lossy()),
+            #[cfg(feature = "ipc")]
+            IpcScan { path, .. } => format!("IPC SCAN {}", path.to_string_lossy()),
+            #[cfg(feature = "parquet")]
+            ParquetScan { path, .. } => format!("PARQUET SCAN {}", path.to_string_lossy()),
+            DataFrameScan { .. } => "DATAFRAME SCAN".to_string(),
+            Projection { expr, .. } | LocalProjection { expr, .. } => {
+                format!("SELECT {:?}", to_exprs(expr))
+            }
+            Sort { by_column, .. } => format!("SORT BY {:?}", to_exprs(by_column)),
+            Explode { columns, .. } => format!("EXPLODE COLUMN(S) {:?}", columns),
+            Cache { .. } => "CACHE".to_string(),
+            Aggregate { keys, aggs, .. } => {
+                format!("AGGREGATE {:?} BY {:?}", to_exprs(aggs), to_exprs(keys))
+            }
+            Join {
+                left_on, right_on, ..
+            } => format!(
+                "JOIN LEFT ON {:?} RIGHT ON {:?}",
+                to_exprs(left_on),
+                to_exprs(right_on)
+            ),
+            HStack { exprs, .. } => format!("WITH COLUMNS {:?}", to_exprs(exprs)),
+            Distinct { .. } => "DISTINCT".to_string(),
+            Udf { options, .. } => options.fmt_str.to_string(),
+            Union { .. } => "UNION".to_string(),
+            ExtContext { .. } => "EXTERNAL CONTEXT".to_string(),
+        }
+    }
+
     /// Get the schema of the logical plan node.
     pub(crate) fn schema<'a>(&'a self, arena: &'a Arena<ALogicalPlan>) -> Cow<'a, SchemaRef> {
         use ALogicalPlan::*;
@@ -311,10 +361,14 @@ impl ALogicalPlan {
                 args: args.clone(),
             },
             Explode {
-                columns, schema, ..
+                columns,
+                options,
+                schema,
+                ..
             } => Explode {
                 input: inputs[0],
                 columns: columns.clone(),
+                options: *options,
                 schema: schema.clone(),
             },
             Cache { .. } => Cache { input: inputs[0] },
@@ -797,13 +851,16 @@ impl<'a> ALogicalPlanBuilder<'a> {
             new_schema.with_column(name.to_string(), dtype.clone())
         }
 
-        let right_names: PlHashSet<_> = right_on
-            .iter()
-            .map(|e| {
-                aexpr_to_root_column_name(*e, self.expr_arena)
-                    .expect("could not determine join column names")
-            })
-            .collect();
+        // only the right key columns are removed by the join,
+        // keys that are computed by an expression are temporary
+        let right_names: PlHashSet<_> = if options.how.keeps_right_keys() {
+            PlHashSet::default()
+        } else {
+            right_on
+                .iter()
+                .filter_map(|e| aexpr_join_key_column(*e, self.expr_arena))
+                .collect()
+        };
 
         for (name, dtype) in schema_right.iter() {
             if !right_names.contains(name.as_str()) {
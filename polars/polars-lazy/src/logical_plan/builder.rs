@@ -6,19 +6,26 @@ use parking_lot::Mutex;
 use polars_core::frame::explode::MeltArgs;
 use polars_core::prelude::*;
 use polars_core::utils::get_supertype;
+#[cfg(all(
+    feature = "csv-file",
+    not(any(feature = "decompress", feature = "decompress-fast"))
+))]
+use polars_io::csv::utils::is_compressed;
 #[cfg(feature = "csv-file")]
-use polars_io::csv::utils::infer_file_schema;
-use polars_io::csv::CsvEncoding;
+use polars_io::csv::utils::{infer_file_schema, transcode_to_utf8};
+use polars_io::csv::{CsvEncoding, RaggedLines};
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcReader;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::ParquetReader;
 use polars_io::RowCount;
+#[cfg(all(
+    feature = "csv-file",
+    any(feature = "decompress", feature = "decompress-fast")
+))]
+use polars_io::{csv::utils::decompress, mmap::ReaderBytes};
 #[cfg(feature = "csv-file")]
-use polars_io::{
-    csv::utils::{get_reader_bytes, is_compressed},
-    csv::NullValues,
-};
+use polars_io::{csv::utils::get_reader_bytes, csv::NullValues};
 use std::io::{Read, Seek, SeekFrom};
 use std::path::PathBuf;
 
@@ -84,6 +91,7 @@ impl LogicalPlanBuilder {
 
     #[cfg(feature = "parquet")]
     #[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
+    #[allow(clippy::too_many_arguments)]
     pub fn scan_parquet<P: Into<PathBuf>>(
         path: P,
         n_rows: Option<usize>,
@@ -92,12 +100,17 @@ impl LogicalPlanBuilder {
         row_count: Option<RowCount>,
         rechunk: bool,
         low_memory: bool,
+        dictionary_as_categorical: bool,
     ) -> Result<Self> {
         use polars_io::SerReader as _;
 
         let path = path.into();
         let file = std::fs::File::open(&path)?;
-        let schema = Arc::new(ParquetReader::new(file).schema()?);
+        let schema = Arc::new(
+            ParquetReader::new(file)
+                .with_dictionary_as_categorical(dictionary_as_categorical)
+                .schema()?,
+        );
 
         Ok(LogicalPlan::ParquetScan {
             path,
@@ -113,6 +126,7 @@ impl LogicalPlanBuilder {
                 rechunk,
                 file_counter: Default::default(),
                 low_memory,
+                dictionary_as_categorical,
             },
         }
         .into())
@@ -160,38 +174,64 @@ impl LogicalPlanBuilder {
         encoding: CsvEncoding,
         row_count: Option<RowCount>,
         parse_dates: bool,
+        ragged_lines: RaggedLines,
     ) -> Result<Self> {
         let path = path.into();
         let mut file = std::fs::File::open(&path)?;
         let mut magic_nr = [0u8; 2];
         file.read_exact(&mut magic_nr)
             .map_err(|_| PolarsError::NoData("empty csv".into()))?;
+        file.seek(SeekFrom::Start(0))?;
+        let reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
 
-        if is_compressed(&magic_nr) {
+        #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+        if is_compressed(&reader_bytes) {
             return Err(PolarsError::ComputeError(
-                "cannot scan compressed csv; use read_csv for compressed data".into(),
+                "cannot scan compressed csv; compile with feature 'decompress' or 'decompress-fast'"
+                    .into(),
             ));
         }
-        file.seek(SeekFrom::Start(0))?;
-        let reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
 
-        let schema = schema.unwrap_or_else(|| {
-            let (schema, _) = infer_file_schema(
-                &reader_bytes,
-                delimiter,
-                infer_schema_length,
-                has_header,
-                schema_overwrite,
-                &mut skip_rows,
-                comment_char,
-                quote_char,
-                eol_char,
-                null_values.as_ref(),
-                parse_dates,
-            )
-            .expect("could not read schema");
-            Arc::new(schema)
-        });
+        let schema = match schema {
+            Some(schema) => schema,
+            None => {
+                // only the lines needed for the inference are decompressed, the reader
+                // decompresses the whole file again when the scan is executed
+                #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+                let reader_bytes = match decompress(
+                    &reader_bytes,
+                    infer_schema_length.map(|n| n + skip_rows + has_header as usize),
+                    delimiter,
+                    quote_char,
+                    eol_char,
+                )? {
+                    Some(b) => ReaderBytes::Owned(b),
+                    None => reader_bytes,
+                };
+                let reader_bytes = transcode_to_utf8(
+                    reader_bytes,
+                    encoding,
+                    infer_schema_length.map(|n| n + skip_rows + has_header as usize),
+                    quote_char,
+                    comment_char,
+                    eol_char,
+                )?;
+                let (schema, _) = infer_file_schema(
+                    &reader_bytes,
+                    delimiter,
+                    infer_schema_length,
+                    has_header,
+                    schema_overwrite,
+                    &mut skip_rows,
+                    comment_char,
+                    quote_char,
+                    eol_char,
+                    null_values.as_ref(),
+                    parse_dates,
+                )?;
+                Arc::new(schema)
+            }
+        };
         skip_rows += skip_rows_after_header;
         Ok(LogicalPlan::CsvScan {
             path,
@@ -213,6 +253,7 @@ impl LogicalPlanBuilder {
                 encoding,
                 row_count,
                 parse_dates,
+                ragged_lines,
                 file_counter: Default::default(),
             },
             predicate: None,
@@ -426,7 +467,13 @@ impl LogicalPlanBuilder {
         .into()
     }
 
-    pub fn sort(self, by_column: Vec<Expr>, reverse: Vec<bool>, null_last: bool) -> Self {
+    pub fn sort(
+        self,
+        by_column: Vec<Expr>,
+        reverse: Vec<bool>,
+        null_last: bool,
+        nan_position: NanPosition,
+    ) -> Self {
         let schema = try_delayed!(self.0.schema(), &self.0, into);
         let by_column = rewrite_projections(by_column, &schema, &[]);
         LogicalPlan::Sort {
@@ -435,13 +482,14 @@ impl LogicalPlanBuilder {
             args: SortArguments {
                 reverse,
                 nulls_last: null_last,
+                nan_position,
                 slice: None,
             },
         }
         .into()
     }
 
-    pub fn explode(self, columns: Vec<Expr>) -> Self {
+    pub fn explode(self, columns: Vec<Expr>, options: ExplodeOptions) -> Self {
         let schema = try_delayed!(self.0.schema(), &self.0, into);
         let columns = rewrite_projections(columns, &schema, &[]);
 
@@ -466,6 +514,7 @@ impl LogicalPlanBuilder {
         LogicalPlan::Explode {
             input: Box::new(self.0),
             columns,
+            options,
             schema: Arc::new(schema),
         }
         .into()
@@ -518,10 +567,16 @@ impl LogicalPlanBuilder {
             new_schema.with_column(name.to_string(), dtype.clone())
         }
 
-        let right_names: PlHashSet<_> = right_on
-            .iter()
-            .map(|e| utils::expr_output_name(e).expect("could not find name"))
-            .collect();
+        // only the right key columns are removed by the join,
+        // keys that are computed by an expression are temporary
+        let right_names: PlHashSet<_> = if options.how.keeps_right_keys() {
+            PlHashSet::default()
+        } else {
+            right_on
+                .iter()
+                .filter_map(|e| utils::join_key_column(e).cloned())
+                .collect()
+        };
 
         for (name, dtype) in schema_right.iter() {
             if !right_names.iter().any(|s| s.as_ref() == name) {
@@ -110,11 +110,18 @@ pub(crate) fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
             input,
             function,
             options,
-        } => AExpr::Function {
-            input: to_aexprs(input, arena),
-            function,
-            options,
-        },
+        } => {
+            // a plugin may be registered after the expression is built
+            let options = match &function {
+                FunctionExpr::Plugin(plugin) => plugin.function_options(),
+                _ => options,
+            };
+            AExpr::Function {
+                input: to_aexprs(input, arena),
+                function,
+                options,
+            }
+        }
         Expr::Shift { input, periods } => AExpr::Shift {
             input: to_aexpr(*input, arena),
             periods,
@@ -142,6 +149,10 @@ pub(crate) fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
         Expr::Wildcard => AExpr::Wildcard,
         Expr::Count => AExpr::Count,
         Expr::Nth(i) => AExpr::Nth(i),
+        Expr::Cache { input, id } => AExpr::Cache {
+            input: to_aexpr(*input, arena),
+            id,
+        },
         Expr::KeepName(_) => panic!("no keep_name expected at this point"),
         Expr::Exclude(_, _) => panic!("no exclude expected at this point"),
         Expr::RenameAlias { .. } => panic!("no `rename_alias` expected at this point"),
@@ -321,12 +332,14 @@ pub(crate) fn to_alp(
         LogicalPlan::Explode {
             input,
             columns,
+            options,
             schema,
         } => {
             let input = to_alp(*input, expr_arena, lp_arena)?;
             ALogicalPlan::Explode {
                 input,
                 columns,
+                options,
                 schema,
             }
         }
@@ -421,10 +434,15 @@ pub(crate) fn to_alp(
             }
         }
         LogicalPlan::Error { err, .. } => {
-            // We just take the error. The LogicalPlan should not be used anymore once this
-            // is taken.
-            let mut err = err.lock();
-            return Err(err.take().unwrap());
+            // the error is copied, so that every execution of the plan raises it
+            return Err(copy_error(err.lock().as_ref().unwrap()));
+        }
+        LogicalPlan::TableScan {
+            name,
+            registry,
+            schema,
+        } => {
+            return registry.resolve(&name, &schema, |lp| to_alp(lp, expr_arena, lp_arena));
         }
         LogicalPlan::ExtContext {
             input,
@@ -666,6 +684,10 @@ pub(crate) fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
         },
         AExpr::Count => Expr::Count,
         AExpr::Nth(i) => Expr::Nth(i),
+        AExpr::Cache { input, id } => Expr::Cache {
+            input: Box::new(node_to_expr(input, expr_arena)),
+            id,
+        },
         AExpr::Wildcard => Expr::Wildcard,
     }
 }
@@ -822,12 +844,14 @@ pub(crate) fn node_to_lp(
         ALogicalPlan::Explode {
             input,
             columns,
+            options,
             schema,
         } => {
             let input = Box::new(node_to_lp(input, expr_arena, lp_arena));
             LogicalPlan::Explode {
                 input,
                 columns,
+                options,
                 schema,
             }
         }
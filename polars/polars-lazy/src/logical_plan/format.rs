@@ -178,6 +178,7 @@ FROM
             ExtContext { input, .. } => {
                 write!(f, "{:?}\nExtContext", input)
             }
+            TableScan { name, .. } => write!(f, "TABLE {}", name),
         }
     }
 }
@@ -203,6 +204,7 @@ impl fmt::Debug for Expr {
             Explode(expr) => write!(f, "{:?}.explode()", expr),
             Duplicated(expr) => write!(f, "{:?}.is_duplicate()", expr),
             Reverse(expr) => write!(f, "{:?}.reverse()", expr),
+            Cache { input, .. } => write!(f, "{:?}.cache()", input),
             Alias(expr, name) => write!(f, "{:?}.alias(\"{}\")", expr, name),
             Column(name) => write!(f, "col(\"{}\")", name),
             Literal(v) => {
@@ -366,6 +368,7 @@ impl Debug for LiteralValue {
                     write!(f, "Series[{}]", name)
                 }
             }
+            Parameter { name, .. } => write!(f, "param({})", name),
         }
     }
 }
@@ -61,6 +61,7 @@ macro_rules! push_expr {
             Function { input, .. } => input.$iter().for_each(|e| $push(e)),
             Shift { input, .. } => $push(input),
             Reverse(e) => $push(e),
+            Cache { input, .. } => $push(input),
             Duplicated(e) => $push(e),
             IsUnique(e) => $push(e),
             Explode(e) => $push(e),
@@ -220,6 +221,7 @@ impl AExpr {
             AnonymousFunction { input, .. } | Function { input, .. } => input.iter().for_each(push),
             Shift { input, .. } => push(input),
             Reverse(e) => push(e),
+            Cache { input, .. } => push(input),
             Duplicated(e) => push(e),
             IsUnique(e) => push(e),
             Explode(e) => push(e),
@@ -51,6 +51,12 @@ pub enum LiteralValue {
     Duration(ChronoDuration, TimeUnit),
     #[cfg_attr(feature = "serde", serde(skip))]
     Series(SpecialEq<Series>),
+    /// A named placeholder that is bound to a value before execution, see [`lit_param`].
+    /// `dtype` is the type of the value, `Null` until the type is known.
+    Parameter {
+        name: String,
+        dtype: DataType,
+    },
 }
 
 impl LiteralValue {
@@ -104,6 +110,7 @@ impl LiteralValue {
             LiteralValue::Duration(_, tu) => DataType::Duration(*tu),
             LiteralValue::Series(s) => s.dtype().clone(),
             LiteralValue::Null => DataType::Null,
+            LiteralValue::Parameter { dtype, .. } => dtype.clone(),
         }
     }
 }
@@ -258,3 +265,12 @@ impl Literal for Series {
 pub fn lit<L: Literal>(t: L) -> Expr {
     t.lit()
 }
+
+/// Create a named placeholder for a literal value. The value is bound with
+/// [`LazyFrame::with_parameters`] or when executing a [`PreparedLazyFrame`].
+pub fn lit_param(name: &str) -> Expr {
+    Expr::Literal(LiteralValue::Parameter {
+        name: name.to_string(),
+        dtype: DataType::Null,
+    })
+}
@@ -157,6 +157,7 @@ pub enum LogicalPlan {
     Explode {
         input: Box<LogicalPlan>,
         columns: Vec<String>,
+        options: ExplodeOptions,
         schema: SchemaRef,
     },
     /// Slice the table
@@ -195,6 +196,14 @@ pub enum LogicalPlan {
         contexts: Vec<LogicalPlan>,
         schema: SchemaRef,
     },
+    /// Scan a table of a [`TableRegistry`]. The table is looked up when the plan is optimized,
+    /// it must still have the `schema` it had when the scan was built.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    TableScan {
+        name: String,
+        registry: TableRegistry,
+        schema: SchemaRef,
+    },
 }
 
 impl Default for LogicalPlan {
@@ -220,6 +229,26 @@ impl LogicalPlan {
     }
 }
 
+/// Copy an error that is raised by every use of the plan that stores it.
+pub(crate) fn copy_error(err: &PolarsError) -> PolarsError {
+    match err {
+        PolarsError::ArrowError(err) => PolarsError::ComputeError(format!("{}", err).into()),
+        PolarsError::InvalidOperation(msg) => PolarsError::InvalidOperation(msg.clone()),
+        PolarsError::SchemaMisMatch(msg) => PolarsError::SchemaMisMatch(msg.clone()),
+        PolarsError::NotFound(msg) => PolarsError::NotFound(msg.clone()),
+        PolarsError::ShapeMisMatch(msg) => PolarsError::ShapeMisMatch(msg.clone()),
+        PolarsError::ComputeError(msg) => PolarsError::ComputeError(msg.clone()),
+        PolarsError::NoData(msg) => PolarsError::NoData(msg.clone()),
+        PolarsError::Io(err) => PolarsError::Io(std::io::Error::new(err.kind(), err.to_string())),
+        PolarsError::Duplicate(msg) => PolarsError::Duplicate(msg.clone()),
+        PolarsError::Cancelled(msg) => PolarsError::Cancelled(msg.clone()),
+        PolarsError::Context { error, context } => PolarsError::Context {
+            error: Box::new(copy_error(error)),
+            context: context.clone(),
+        },
+    }
+}
+
 impl LogicalPlan {
     pub(crate) fn schema(&self) -> Result<Cow<'_, SchemaRef>> {
         use LogicalPlan::*;
@@ -254,8 +283,9 @@ impl LogicalPlan {
                     None => Ok(input_schema),
                 }
             }
-            Error { input, .. } => input.schema(),
+            Error { err, .. } => Err(copy_error(err.lock().as_ref().unwrap())),
             ExtContext { schema, .. } => Ok(Cow::Borrowed(schema)),
+            TableScan { schema, .. } => Ok(Cow::Borrowed(schema)),
         }
     }
     pub fn describe(&self) -> String {
@@ -0,0 +1,272 @@
+#[cfg(any(feature = "strings", feature = "is_in"))]
+use crate::dsl::function_expr::FunctionExpr;
+use crate::logical_plan::iterator::*;
+use crate::prelude::optimizer::predicate_pushdown::predicate_is_pushdown_boundary;
+use crate::prelude::stack_opt::OptimizationRule;
+use crate::prelude::*;
+
+/// Merges consecutive filters into a single filter and orders the
+/// conjuncts of a filter by their estimated cost, so that cheap
+/// comparisons come before regex matches, udfs and windows.
+///
+/// `.filter(a).filter(b)` becomes `.filter(a & b)` if `b` does not depend on
+/// the rows removed by `a` (e.g. `b` is not an aggregation/window/shift).
+pub(crate) struct CombineFilters {}
+
+/// Split a predicate `a & (b & c)` into its conjuncts `[a, b, c]`.
+fn split_conjunction(node: Node, expr_arena: &Arena<AExpr>, out: &mut Vec<Node>) {
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr {
+            left,
+            op: Operator::And,
+            right,
+        } => {
+            split_conjunction(*left, expr_arena, out);
+            split_conjunction(*right, expr_arena, out);
+        }
+        _ => out.push(node),
+    }
+}
+
+/// A rough estimate of the cost of evaluating an expression.
+/// The absolute numbers don't mean anything, only their ordering.
+fn estimated_cost(node: Node, expr_arena: &Arena<AExpr>) -> usize {
+    expr_arena
+        .iter(node)
+        .map(|(_, e)| match e {
+            AExpr::Column(_) | AExpr::Literal(_) | AExpr::Alias(_, _) | AExpr::Count => 0,
+            AExpr::BinaryExpr { .. } | AExpr::Not(_) | AExpr::IsNull(_) | AExpr::IsNotNull(_) => 1,
+            AExpr::Cast { .. } | AExpr::Ternary { .. } => 2,
+            AExpr::Function { function, .. } => match function {
+                #[cfg(feature = "strings")]
+                FunctionExpr::StringContains { literal: false, .. } => 20,
+                #[cfg(feature = "strings")]
+                FunctionExpr::StringContains { .. }
+                | FunctionExpr::StringStartsWith(_)
+                | FunctionExpr::StringEndsWith(_) => 5,
+                #[cfg(feature = "is_in")]
+                FunctionExpr::IsIn => 5,
+                _ => 3,
+            },
+            // we don't know what a udf does, assume the worst
+            AExpr::AnonymousFunction { .. } => 50,
+            AExpr::Agg(_) | AExpr::IsUnique(_) | AExpr::Duplicated(_) => 10,
+            AExpr::Sort { .. } | AExpr::SortBy { .. } => 30,
+            AExpr::Window { .. } => 100,
+            _ => 3,
+        })
+        .sum()
+}
+
+/// Build the conjunction of `conjuncts`, cheapest first.
+/// Returns `None` if the conjuncts are already ordered by cost.
+fn order_by_cost(conjuncts: &[Node], expr_arena: &mut Arena<AExpr>) -> Option<Node> {
+    let mut costs = conjuncts
+        .iter()
+        .map(|node| (estimated_cost(*node, expr_arena), *node))
+        .collect::<Vec<_>>();
+    if costs.windows(2).all(|w| w[0].0 <= w[1].0) {
+        return None;
+    }
+    // stable, so that conjuncts of equal cost keep their order
+    costs.sort_by_key(|t| t.0);
+    costs.into_iter().map(|t| t.1).reduce(|left, right| {
+        expr_arena.add(AExpr::BinaryExpr {
+            left,
+            op: Operator::And,
+            right,
+        })
+    })
+}
+
+/// The predicate that predicate pushdown pushed into a scan.
+fn scan_predicate(lp: &ALogicalPlan) -> Option<Node> {
+    use ALogicalPlan::*;
+    match lp {
+        #[cfg(feature = "csv-file")]
+        CsvScan { predicate, .. } => *predicate,
+        #[cfg(feature = "ipc")]
+        IpcScan { predicate, .. } => *predicate,
+        #[cfg(feature = "parquet")]
+        ParquetScan { predicate, .. } => *predicate,
+        AnonymousScan { predicate, .. } => *predicate,
+        DataFrameScan { selection, .. } => *selection,
+        _ => None,
+    }
+}
+
+impl OptimizationRule for CombineFilters {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        let lp = lp_arena.get(node);
+
+        use ALogicalPlan::*;
+        match lp {
+            Selection { input, predicate } => {
+                let mut input = *input;
+                let predicate = *predicate;
+                let mut conjuncts = Vec::with_capacity(4);
+
+                // the first filter must be evaluated on the output of the second filter
+                // if it depends on all rows we cannot combine them.
+                let mut merged = false;
+                if let Selection {
+                    input: inner_input,
+                    predicate: inner_predicate,
+                } = lp_arena.get(input)
+                {
+                    if !predicate_is_pushdown_boundary(predicate, expr_arena) {
+                        split_conjunction(*inner_predicate, expr_arena, &mut conjuncts);
+                        input = *inner_input;
+                        merged = true;
+                    }
+                }
+                split_conjunction(predicate, expr_arena, &mut conjuncts);
+
+                let predicate = match order_by_cost(&conjuncts, expr_arena) {
+                    Some(predicate) => predicate,
+                    None if merged => conjuncts
+                        .into_iter()
+                        .reduce(|left, right| {
+                            expr_arena.add(AExpr::BinaryExpr {
+                                left,
+                                op: Operator::And,
+                                right,
+                            })
+                        })
+                        .unwrap(),
+                    None => return None,
+                };
+                Some(Selection { input, predicate })
+            }
+            // predicate pushdown combines the predicates of a scan in arbitrary order
+            lp => {
+                let predicate = scan_predicate(lp)?;
+                let mut conjuncts = Vec::with_capacity(4);
+                split_conjunction(predicate, expr_arena, &mut conjuncts);
+                let predicate = order_by_cost(&conjuncts, expr_arena)?;
+
+                let mut exprs = lp.get_exprs();
+                // the predicate of a scan is its last expression
+                *exprs.last_mut().unwrap() = predicate;
+                Some(lp.with_exprs_and_input(exprs, vec![]))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::fruits_cars;
+    use crate::utils::test::optimize_lp;
+    use polars_core::prelude::*;
+
+    fn count_selections(lp: &LogicalPlan) -> usize {
+        match lp {
+            LogicalPlan::Selection { input, .. } => 1 + count_selections(input),
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn test_combine_filters() -> Result<()> {
+        let mut rules: Vec<Box<dyn OptimizationRule>> = vec![Box::new(CombineFilters {})];
+        let df = fruits_cars();
+
+        let lp = df
+            .clone()
+            .lazy()
+            .filter(col("A").gt(lit(1)))
+            .filter(col("B").lt(lit(5)))
+            .filter(col("cars").eq(lit("beetle")))
+            .logical_plan;
+        let out = optimize_lp(lp, &mut rules);
+        assert_eq!(count_selections(&out), 1);
+
+        // the last filter depends on the rows filtered by the first
+        let lp = df
+            .lazy()
+            .filter(col("A").gt(lit(1)))
+            .filter(col("B").eq(col("B").max()))
+            .logical_plan;
+        let out = optimize_lp(lp, &mut rules);
+        assert_eq!(count_selections(&out), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reorder_conjuncts() -> Result<()> {
+        let mut rules: Vec<Box<dyn OptimizationRule>> = vec![Box::new(CombineFilters {})];
+        let df = fruits_cars();
+
+        let lp = df
+            .lazy()
+            .filter(
+                col("B")
+                    .eq(col("B").max())
+                    .over([col("fruits")])
+                    .and(col("A").gt(lit(1))),
+            )
+            .logical_plan;
+        let out = optimize_lp(lp, &mut rules);
+        match out {
+            LogicalPlan::Selection {
+                predicate: Expr::BinaryExpr { left, .. },
+                ..
+            } => {
+                assert!(matches!(
+                    *left,
+                    Expr::BinaryExpr {
+                        op: Operator::Gt,
+                        ..
+                    }
+                ))
+            }
+            _ => panic!(),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_reorder_pushed_down_predicate() -> Result<()> {
+        // predicate pushdown moves both conjuncts into the scan; the cheap one must come first
+        let mut lp_arena = Arena::with_capacity(8);
+        let mut expr_arena = Arena::with_capacity(8);
+        let node = fruits_cars()
+            .lazy()
+            .filter(
+                col("A")
+                    .cast(DataType::Float64)
+                    .gt(lit(1.0))
+                    .and(col("B").lt(lit(5))),
+            )
+            .optimize(&mut lp_arena, &mut expr_arena)?;
+        let predicate = match lp_arena.get(node) {
+            ALogicalPlan::DataFrameScan {
+                selection: Some(selection),
+                ..
+            } => node_to_expr(*selection, &expr_arena),
+            lp => panic!("predicate not pushed down: {:?}", lp),
+        };
+        match predicate {
+            Expr::BinaryExpr {
+                left,
+                op: Operator::And,
+                ..
+            } => assert!(matches!(
+                *left,
+                Expr::BinaryExpr {
+                    op: Operator::Lt,
+                    ..
+                }
+            )),
+            e => panic!("unexpected predicate: {:?}", e),
+        }
+        Ok(())
+    }
+}
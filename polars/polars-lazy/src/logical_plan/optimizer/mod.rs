@@ -2,6 +2,7 @@ use crate::prelude::*;
 use polars_core::{datatypes::PlHashMap, prelude::*};
 
 pub(crate) mod aggregate_pushdown;
+pub(crate) mod combine_filters;
 pub(crate) mod delay_rechunk;
 pub(crate) mod drop_nulls;
 pub(crate) mod fast_projection;
@@ -7,6 +7,8 @@ use polars_core::datatypes::PlHashMap;
 use polars_core::prelude::*;
 use utils::*;
 
+pub(crate) use utils::predicate_is_pushdown_boundary;
+
 #[derive(Default)]
 pub(crate) struct PredicatePushDown {}
 
@@ -343,14 +345,14 @@ impl PredicatePushDown {
                 }
             }
 
-            Explode { input, columns, schema } => {
+            Explode { input, columns, options, schema } => {
                 let condition = |name: Arc<str>| columns.iter().any(|s| s.as_str() == &*name);
                 let mut local_predicates =
                     transfer_to_local_by_name(expr_arena, &mut acc_predicates, condition);
                 local_predicates.extend_from_slice(&transfer_to_local_by_node(&mut acc_predicates, |node| predicate_is_pushdown_boundary(node, expr_arena)));
 
                 self.pushdown_and_assign(input, acc_predicates, lp_arena, expr_arena)?;
-                let lp = Explode { input, columns, schema };
+                let lp = Explode { input, columns, options, schema };
                 Ok(self.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
             }
             Distinct {
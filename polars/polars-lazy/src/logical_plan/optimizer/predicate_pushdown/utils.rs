@@ -109,7 +109,7 @@ pub(super) fn get_insertion_name(
 ///.filter(a == min(a))     # filter 1
 ///
 /// the min(a) is influenced by filter 2 so min(a) should not pass
-pub(super) fn predicate_is_pushdown_boundary(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+pub(crate) fn predicate_is_pushdown_boundary(node: Node, expr_arena: &Arena<AExpr>) -> bool {
     let matches = |e: &AExpr| {
         matches!(
             e,
@@ -2,8 +2,8 @@ use crate::logical_plan::Context;
 use crate::prelude::iterator::ArenaExprIter;
 use crate::prelude::*;
 use crate::utils::{
-    aexpr_assign_renamed_root, aexpr_to_root_names, aexpr_to_root_nodes, check_input_node,
-    has_aexpr,
+    aexpr_assign_renamed_root, aexpr_join_key_column, aexpr_to_root_names, aexpr_to_root_nodes,
+    check_input_node, has_aexpr,
 };
 use polars_core::{datatypes::PlHashSet, prelude::*};
 
@@ -559,6 +559,7 @@ impl ProjectionPushDown {
             Explode {
                 input,
                 columns,
+                options,
                 schema,
             } => {
                 columns.iter().for_each(|name| {
@@ -580,6 +581,7 @@ impl ProjectionPushDown {
                 Ok(Explode {
                     input,
                     columns,
+                    options,
                     schema,
                 })
             }
@@ -776,8 +778,21 @@ impl ProjectionPushDown {
                             &mut names_left,
                             expr_arena,
                         );
-                        if !local_projection.contains(e) {
-                            local_projection.push(*e)
+                        if aexpr_join_key_column(*e, expr_arena).is_some() {
+                            if !local_projection.contains(e) {
+                                local_projection.push(*e)
+                            }
+                        } else {
+                            // keys computed by an expression are not in the output of the join,
+                            // but the columns they are computed from are
+                            for name in aexpr_to_root_names(*e, expr_arena) {
+                                let already_projected = local_projection.iter().any(|node| {
+                                    matches!(expr_arena.get(*node), AExpr::Column(n) if *n == name)
+                                });
+                                if !already_projected {
+                                    local_projection.push(expr_arena.add(AExpr::Column(name)))
+                                }
+                            }
                         }
                     }
                     for e in &right_on {
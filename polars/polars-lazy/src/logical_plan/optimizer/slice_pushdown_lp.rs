@@ -192,12 +192,33 @@ impl SlicePushDown {
                 Ok(lp)
             }
 
-            (Union {inputs, .. }, Some(state)) => {
-                let options = UnionOptions {
-                    slice: true,
-                    slice_offset: state.offset,
-                    slice_len: state.len,
+            (Union {inputs, mut options }, Some(state)) => {
+                // with a positive offset, no input has to produce more than `offset + len` rows
+                let input_state = if state.offset >= 0 {
+                    let offset = IdxSize::try_from(state.offset).map_err(|_| {
+                        PolarsError::ComputeError(
+                            format!(
+                                "slice offset {} doesn't fit in the index type",
+                                state.offset
+                            )
+                            .into(),
+                        )
+                    })?;
+                    Some(State {
+                        offset: 0,
+                        len: offset.saturating_add(state.len)
+                    })
+                } else {
+                    None
                 };
+                for &input in &inputs {
+                    let alp = lp_arena.take(input);
+                    let alp = self.pushdown(alp, input_state, lp_arena, expr_arena)?;
+                    lp_arena.replace(input, alp);
+                }
+                options.slice = true;
+                options.slice_offset = state.offset;
+                options.slice_len = state.len;
                 Ok(Union {inputs, options})
             },
             (Join {
@@ -315,7 +336,8 @@ impl SlicePushDown {
             // these nodes will be pushed down.
              m @(Udf{options: LogicalPlanUdfOptions{ predicate_pd: true, ..}, .. }, _) |
              // State is None, we can continue
-             m @(Projection{..}, None)
+             m @(Projection{..}, None) |
+             m @(Union{..}, None)
             => {
                 let (lp, state) = m;
                 self.pushdown_and_continue(lp, state, lp_arena, expr_arena)
@@ -1,14 +1,82 @@
 use crate::dsl::function_expr::FunctionExpr;
 use polars_core::prelude::*;
-use polars_core::utils::get_supertype;
+use polars_core::utils::{get_supertype, is_lossless_cast};
 use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 use crate::logical_plan::optimizer::stack_opt::OptimizationRule;
 use crate::logical_plan::Context;
 use crate::prelude::*;
 use crate::utils::is_scan;
 
-pub struct TypeCoercionRule {}
+#[derive(Default)]
+pub struct TypeCoercionRule {
+    /// Refuse implicit casts that may lose information, see [`is_lossless_cast`].
+    strict: bool,
+    /// The first lossy cast that was refused in strict mode.
+    error: Rc<RefCell<Option<PolarsError>>>,
+}
+
+impl TypeCoercionRule {
+    pub fn new(strict: bool) -> Self {
+        TypeCoercionRule {
+            strict,
+            ..Default::default()
+        }
+    }
+
+    /// Get a handle to the error of the first lossy cast that was refused in strict mode.
+    /// The rule itself will be boxed by the optimizer, so this is how the error is surfaced.
+    pub fn error_handle(&self) -> Rc<RefCell<Option<PolarsError>>> {
+        self.error.clone()
+    }
+
+    /// In strict mode, check that the operands can be cast to the supertype without losing
+    /// information. Scalar literals are checked by value: they must round-trip through the
+    /// supertype unchanged. Returns `None` and records the error if they can't.
+    fn check_lossless(&self, operands: &[(&AExpr, &DataType)], st: &DataType) -> Option<()> {
+        if !self.strict {
+            return Some(());
+        }
+        for (ae, dt) in operands {
+            let lossless = match ae {
+                AExpr::Literal(lv) => match lv.to_anyvalue() {
+                    Some(av) => literal_round_trips(av, dt, st),
+                    None => is_lossless_cast(dt, st),
+                },
+                _ => is_lossless_cast(dt, st),
+            };
+            if !lossless {
+                let mut error = self.error.borrow_mut();
+                if error.is_none() {
+                    *error = Some(PolarsError::ComputeError(
+                        format!(
+                            "implicit cast of {:?} to {:?} may lose information, cast explicitly instead",
+                            dt, st
+                        )
+                        .into(),
+                    ));
+                }
+                return None;
+            }
+        }
+        Some(())
+    }
+}
+
+/// Check if a scalar literal keeps its value when cast from `dt` to `st` and back.
+fn literal_round_trips(av: AnyValue, dt: &DataType, st: &DataType) -> bool {
+    if matches!(av, AnyValue::Null) || dt == st {
+        return true;
+    }
+    Series::from_any_values("", &[av])
+        .and_then(|s| {
+            let back = s.strict_cast(st)?.strict_cast(s.dtype())?;
+            Ok(back.series_equal_missing(&s))
+        })
+        .unwrap_or(false)
+}
 
 /// determine if we use the supertype or not. For instance when we have a column Int64 and we compare with literal UInt32
 /// it would be wasteful to cast the column instead of the literal.
@@ -146,6 +214,7 @@ impl OptimizationRule for TypeCoercionRule {
                 early_escape(&type_true, &type_false)?;
                 let st = get_supertype(&type_true, &type_false).expect("supertype");
                 let st = modify_supertype(st, truthy, falsy, &type_true, &type_false);
+                self.check_lossless(&[(truthy, &type_true), (falsy, &type_false)], &st)?;
 
                 // only cast if the type is not already the super type.
                 // this can prevent an expensive flattening and subsequent aggregation
@@ -318,6 +387,7 @@ impl OptimizationRule for TypeCoercionRule {
                     if cat_str_arithmetic {
                         st = DataType::Utf8
                     }
+                    self.check_lossless(&[(left, &type_left), (right, &type_right)], &st)?;
 
                     // only cast if the type is not already the super type.
                     // this can prevent an expensive flattening and subsequent aggregation
@@ -405,6 +475,10 @@ impl OptimizationRule for TypeCoercionRule {
                 let super_type = get_supertype(&type_left, &type_fill_value).ok()?;
                 let super_type =
                     modify_supertype(super_type, left, fill_value, &type_left, &type_fill_value);
+                self.check_lossless(
+                    &[(left, &type_left), (fill_value, &type_fill_value)],
+                    &super_type,
+                )?;
                 Some(AExpr::Function {
                     function: FunctionExpr::FillNull { super_type },
                     input: input.clone(),
@@ -413,13 +487,14 @@ impl OptimizationRule for TypeCoercionRule {
             }
             AExpr::Function {
                 // only for `DataType::Unknown` as it still has to be set.
-                function: FunctionExpr::ShiftAndFill { periods },
+                function: FunctionExpr::ShiftAndFill,
                 ref input,
                 options,
             } => {
                 let input_schema = get_schema(lp_arena, lp_node);
                 let self_node = input[0];
                 let other_node = input[1];
+                let periods_node = input[2];
                 let (left, type_self) = get_aexpr_and_type(expr_arena, self_node, &input_schema)?;
                 let (fill_value, type_other) =
                     get_aexpr_and_type(expr_arena, other_node, &input_schema)?;
@@ -429,6 +504,10 @@ impl OptimizationRule for TypeCoercionRule {
                 let super_type = get_supertype(&type_self, &type_other).ok()?;
                 let super_type =
                     modify_supertype(super_type, left, fill_value, &type_self, &type_other);
+                self.check_lossless(
+                    &[(left, &type_self), (fill_value, &type_other)],
+                    &super_type,
+                )?;
 
                 // only cast if the type is not already the super type.
                 // this can prevent an expensive flattening and subsequent aggregation
@@ -454,8 +533,8 @@ impl OptimizationRule for TypeCoercionRule {
                 };
 
                 Some(AExpr::Function {
-                    function: FunctionExpr::ShiftAndFill { periods },
-                    input: vec![new_node_self, new_node_other],
+                    function: FunctionExpr::ShiftAndFill,
+                    input: vec![new_node_self, new_node_other, periods_node],
                     options,
                 })
             }
@@ -486,7 +565,7 @@ mod test {
     #[test]
     #[cfg(feature = "dtype-categorical")]
     fn test_categorical_utf8() {
-        let mut rules: Vec<Box<dyn OptimizationRule>> = vec![Box::new(TypeCoercionRule {})];
+        let mut rules: Vec<Box<dyn OptimizationRule>> = vec![Box::new(TypeCoercionRule::default())];
         let schema = Schema::from(vec![Field::new("fruits", DataType::Categorical(None))]);
 
         let expr = col("fruits").eq(lit("somestr"));
@@ -499,4 +578,28 @@ mod test {
         let expected = col("fruits").cast(DataType::Utf8) + lit("somestr");
         assert_eq!(out, expected);
     }
+
+    #[test]
+    fn test_strict_coercion() {
+        let schema = Schema::from(vec![
+            Field::new("a", DataType::Int32),
+            Field::new("b", DataType::UInt64),
+            Field::new("c", DataType::Int64),
+        ]);
+
+        let rule = TypeCoercionRule::new(true);
+        let error = rule.error_handle();
+        let mut rules: Vec<Box<dyn OptimizationRule>> = vec![Box::new(rule)];
+
+        // i32 fits in i64 and a fitting literal is cast to the column
+        let expr = col("a") + col("c") + lit(1i64);
+        optimize_expr(expr, schema.clone(), &mut rules);
+        assert!(error.borrow().is_none());
+
+        // u64 and i64 have a f64 supertype
+        let expr = col("b") + col("c");
+        let out = optimize_expr(expr.clone(), schema, &mut rules);
+        assert_eq!(out, expr);
+        assert!(error.take().is_some());
+    }
 }
@@ -1,6 +1,6 @@
 use crate::prelude::*;
 use polars_core::prelude::*;
-use polars_io::csv::{CsvEncoding, NullValues};
+use polars_io::csv::{CsvEncoding, NullValues, RaggedLines};
 use polars_io::RowCount;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -26,6 +26,7 @@ pub struct CsvParserOptions {
     pub(crate) encoding: CsvEncoding,
     pub(crate) row_count: Option<RowCount>,
     pub(crate) parse_dates: bool,
+    pub(crate) ragged_lines: RaggedLines,
     pub(crate) file_counter: FileCount,
 }
 #[cfg(feature = "parquet")]
@@ -40,6 +41,7 @@ pub struct ParquetOptions {
     pub(crate) row_count: Option<RowCount>,
     pub(crate) file_counter: FileCount,
     pub(crate) low_memory: bool,
+    pub(crate) dictionary_as_categorical: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -85,6 +87,7 @@ pub struct UnionOptions {
     pub(crate) slice: bool,
     pub(crate) slice_offset: i64,
     pub(crate) slice_len: IdxSize,
+    pub(crate) rechunk: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -179,6 +182,7 @@ pub struct SortArguments {
     pub(crate) reverse: Vec<bool>,
     // Can only be true in case of a single column.
     pub(crate) nulls_last: bool,
+    pub(crate) nan_position: NanPosition,
     pub(crate) slice: Option<(i64, usize)>,
 }
 
@@ -0,0 +1 @@
+pub use polars_core::cancel::CancellationToken;
@@ -21,7 +21,7 @@ impl Executor for CacheExec {
 
         // cache miss
         let df = self.input.execute(state)?;
-        state.store_cache(std::mem::take(&mut self.key), df.clone());
+        state.store_cache(self.key.clone(), df.clone());
         if state.verbose() {
             println!("cache set {:?}", self.key);
         }
@@ -15,7 +15,8 @@ impl Executor for DropDuplicatesExec {
                 println!("run DropDuplicatesExec")
             }
         }
-        let df = self.input.execute(state)?;
+        let mut df = self.input.execute(state)?;
+        state.rechunk_fragmented(&mut df);
         let subset = self.options.subset.as_ref().map(|v| &***v);
         let keep = self.options.keep_strategy;
 
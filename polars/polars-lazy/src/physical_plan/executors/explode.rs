@@ -5,6 +5,7 @@ use polars_core::prelude::*;
 pub(crate) struct ExplodeExec {
     pub(crate) input: Box<dyn Executor>,
     pub(crate) columns: Vec<String>,
+    pub(crate) options: ExplodeOptions,
 }
 
 impl Executor for ExplodeExec {
@@ -16,6 +17,6 @@ impl Executor for ExplodeExec {
             }
         }
         let df = self.input.execute(state)?;
-        df.explode(&self.columns)
+        df.explode_with_options(&self.columns, self.options)
     }
 }
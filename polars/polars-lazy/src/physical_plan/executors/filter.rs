@@ -23,6 +23,7 @@ impl Executor for FilterExec {
         }
         let df = self.input.execute(state)?;
         let s = self.predicate.evaluate(&df, state)?;
+        state.clear_cached_exprs();
         let mask = s.bool().expect("filter predicate wasn't of type boolean");
         let df = df.filter(mask)?;
         if state.verbose() {
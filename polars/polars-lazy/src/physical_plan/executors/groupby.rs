@@ -49,7 +49,7 @@ pub(super) fn groupby_helper(
 
     if let Some(f) = apply {
         state.clear_schema_cache();
-        return gb.apply(|df| f.call_udf(df));
+        return gb.par_apply(|df| f.call_udf(df));
     }
 
     let mut groups = gb.get_groups();
@@ -101,13 +101,28 @@ impl Executor for GroupByExec {
         if state.verbose() {
             eprintln!("keys/aggregates are not partitionable: running default HASH AGGREGATION")
         }
-        let df = self.input.execute(state)?;
+        let mut df = self.input.execute(state)?;
+        state.rechunk_fragmented(&mut df);
         state.set_schema(self.input_schema.clone());
-        let keys = self
+        let keys: Vec<_> = self
             .keys
             .iter()
             .map(|e| e.evaluate(&df, state))
             .collect::<Result<_>>()?;
+        state.clear_cached_exprs();
+        if self.apply.is_none() && !self.maintain_order {
+            if let Some(n_partitions) = hash_partition_count(&keys, &df, state)? {
+                return groupby_hash_partitioned(
+                    df,
+                    keys,
+                    &self.aggs,
+                    state,
+                    &self.input_schema,
+                    n_partitions,
+                    self.slice,
+                );
+            }
+        }
         groupby_helper(
             df,
             keys,
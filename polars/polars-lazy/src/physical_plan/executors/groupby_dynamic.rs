@@ -34,6 +34,7 @@ impl Executor for GroupByDynamicExec {
             .iter()
             .map(|e| e.evaluate(&df, state))
             .collect::<Result<Vec<_>>>()?;
+        state.clear_cached_exprs();
 
         let (time_key, keys, groups) = df.groupby_dynamic(keys, &self.options)?;
 
@@ -0,0 +1,236 @@
+use super::*;
+use polars_core::frame::groupby::_hash_partition_indices;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_core::POOL;
+#[cfg(feature = "ipc")]
+use polars_io::prelude::*;
+use rayon::prelude::*;
+#[cfg(feature = "ipc")]
+use std::path::Path;
+#[cfg(feature = "ipc")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Parse a positive count from the environment variable `key`.
+fn env_count(key: &str) -> Result<Option<usize>> {
+    match std::env::var(key) {
+        Ok(v) => match v.parse::<usize>() {
+            Ok(n) if n > 0 => Ok(Some(n)),
+            _ => Err(PolarsError::ComputeError(
+                format!("{} must be a positive integer, got: '{}'", key, v).into(),
+            )),
+        },
+        Err(_) => Ok(None),
+    }
+}
+
+/// Get the number of partitions of a hash partitioned groupby,
+/// or `None` if the cardinality of the keys is low enough to run the default groupby.
+pub(super) fn hash_partition_count(
+    keys: &[Series],
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> Result<Option<usize>> {
+    if std::env::var("POLARS_NO_PARTITION").is_ok() {
+        return Ok(None);
+    }
+    // nested keys cannot be hashed
+    if !keys.iter().all(|s| {
+        let dtype = s.dtype().to_physical();
+        dtype.is_numeric() || matches!(dtype, DataType::Utf8 | DataType::Boolean)
+    }) {
+        return Ok(None);
+    }
+
+    // in low memory mode we already partition at a lower cardinality, as that bounds the
+    // size of the hash tables and the groups that are alive at the same time.
+    let default_boundary = if state.low_memory { 100_000 } else { 1_000_000 };
+    let unique_count_boundary =
+        env_count("POLARS_HASH_PARTITION_UNIQUE_COUNT")?.unwrap_or(default_boundary);
+    // we cannot have more groups than rows
+    if df.height() <= unique_count_boundary {
+        return Ok(None);
+    }
+    let unique_estimate = estimate_unique_count(keys, 1_000);
+    if unique_estimate <= unique_count_boundary {
+        return Ok(None);
+    }
+
+    let n_partitions = env_count("POLARS_HASH_PARTITION_COUNT")?
+        .unwrap_or(unique_estimate / unique_count_boundary + 1);
+    if state.verbose() {
+        eprintln!(
+            "estimated unique count: {} exceeded the boundary: {}, running HASH PARTITIONED AGGREGATION with {} partitions",
+            unique_estimate, unique_count_boundary, n_partitions
+        )
+    }
+    Ok(Some(n_partitions))
+}
+
+fn take_partition(
+    df: &DataFrame,
+    keys: &[Series],
+    idx: &IdxCa,
+) -> Result<(DataFrame, Vec<Series>)> {
+    let df = df.take(idx)?;
+    let keys = keys.iter().map(|s| s.take(idx)).collect::<Result<_>>()?;
+    Ok((df, keys))
+}
+
+#[cfg(feature = "ipc")]
+static SPILL_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A partition that is written to disk. The files are removed on drop.
+#[cfg(feature = "ipc")]
+struct SpilledPartition {
+    df_path: PathBuf,
+    keys_path: PathBuf,
+}
+
+#[cfg(feature = "ipc")]
+impl SpilledPartition {
+    fn write(dir: &Path, mut df: DataFrame, keys: Vec<Series>) -> Result<Self> {
+        let id = SPILL_ID.fetch_add(1, Ordering::Relaxed);
+        let name = format!("polars_groupby_{}_{}", std::process::id(), id);
+        let spilled = SpilledPartition {
+            df_path: dir.join(format!("{}.ipc", name)),
+            keys_path: dir.join(format!("{}_keys.ipc", name)),
+        };
+        IpcWriter::new(std::fs::File::create(&spilled.df_path)?).finish(&mut df)?;
+        let mut keys = DataFrame::new_no_checks(keys);
+        IpcWriter::new(std::fs::File::create(&spilled.keys_path)?).finish(&mut keys)?;
+        Ok(spilled)
+    }
+
+    fn read(&self) -> Result<(DataFrame, Vec<Series>)> {
+        let df = IpcReader::new(std::fs::File::open(&self.df_path)?).finish()?;
+        let keys = IpcReader::new(std::fs::File::open(&self.keys_path)?).finish()?;
+        Ok((df, keys.get_columns().to_vec()))
+    }
+}
+
+#[cfg(feature = "ipc")]
+impl Drop for SpilledPartition {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.df_path);
+        let _ = std::fs::remove_file(&self.keys_path);
+    }
+}
+
+/// Groupby on keys with a high cardinality.
+///
+/// The rows are partitioned by the hash of the keys, so that all rows of a group are in the same
+/// partition. The partitions are then aggregated in parallel with the default groupby, which
+/// bounds the size of the hash tables. In low memory mode the partitions are aggregated one
+/// after the other, so that only the groups of a single partition are alive at the same time.
+///
+/// If `POLARS_GROUPBY_SPILL_DIR` is set, the partitions are first written to that directory
+/// so that the input can be freed before the aggregation.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn groupby_hash_partitioned(
+    df: DataFrame,
+    keys: Vec<Series>,
+    aggs: &[Arc<dyn PhysicalExpr>],
+    state: &ExecutionState,
+    input_schema: &SchemaRef,
+    n_partitions: usize,
+    slice: Option<(i64, usize)>,
+) -> Result<DataFrame> {
+    if n_partitions == 0 {
+        return Err(PolarsError::ComputeError(
+            "the number of hash partitions must be positive".into(),
+        ));
+    }
+    let partitions = _hash_partition_indices(&keys, n_partitions);
+
+    let aggregate = |(df, keys): (DataFrame, Vec<Series>)| {
+        state.check_cancelled()?;
+        let mut state = state.split_branch();
+        state.set_schema(input_schema.clone());
+        groupby_helper(df, keys, aggs, None, &mut state, false, None)
+    };
+    let aggregate_all = |n: usize, f: &(dyn Fn(usize) -> Result<DataFrame> + Send + Sync)| {
+        if state.low_memory {
+            (0..n).map(f).collect::<Result<Vec<_>>>()
+        } else {
+            POOL.install(|| (0..n).into_par_iter().map(f).collect::<Result<Vec<_>>>())
+        }
+    };
+
+    #[cfg(feature = "ipc")]
+    if let Ok(dir) = std::env::var("POLARS_GROUPBY_SPILL_DIR") {
+        let dir = PathBuf::from(dir);
+        std::fs::create_dir_all(&dir)?;
+        let spilled = partitions
+            .iter()
+            .map(|idx| {
+                state.check_cancelled()?;
+                let (df, keys) = take_partition(&df, &keys, idx)?;
+                SpilledPartition::write(&dir, df, keys)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        drop(df);
+        drop(keys);
+
+        let dfs = aggregate_all(spilled.len(), &|i| aggregate(spilled[i].read()?))?;
+        return finish_partitions(dfs, slice);
+    }
+
+    let dfs = aggregate_all(partitions.len(), &|i| {
+        aggregate(take_partition(&df, &keys, &partitions[i])?)
+    })?;
+    finish_partitions(dfs, slice)
+}
+
+fn finish_partitions(dfs: Vec<DataFrame>, slice: Option<(i64, usize)>) -> Result<DataFrame> {
+    let df = accumulate_dataframes_vertical(dfs)?;
+    Ok(match slice {
+        Some((offset, len)) => df.slice(offset, len),
+        None => df,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_groupby_hash_partitioned() -> Result<()> {
+        let df = df![
+            "a" => (0..100).map(|i| i % 7).collect::<Vec<i32>>(),
+            "b" => (0..100).map(|i| (i % 3).to_string()).collect::<Vec<_>>(),
+            "c" => (0..100).collect::<Vec<i32>>()
+        ]?;
+        let aggs = [col("c").sum(), col("c").list().alias("c_list")];
+        let expected = df
+            .clone()
+            .lazy()
+            .groupby([col("a"), col("b")])
+            .agg(aggs.clone())
+            .sort_by_exprs(
+                [col("a"), col("b")],
+                [false, false],
+                false,
+                Default::default(),
+            )
+            .collect()?;
+
+        let mut expr_arena = Arena::with_capacity(8);
+        let aggs = aggs
+            .into_iter()
+            .map(|e| to_aexpr(e, &mut expr_arena))
+            .collect::<Vec<_>>();
+        let aggs = DefaultPlanner::default().create_physical_expressions(
+            &aggs,
+            Context::Aggregation,
+            &mut expr_arena,
+        )?;
+        let keys = vec![df.column("a")?.clone(), df.column("b")?.clone()];
+        let schema = Arc::new(df.schema());
+        let state = ExecutionState::new();
+
+        let out = groupby_hash_partitioned(df, keys, &aggs, &state, &schema, 3, None)?
+            .sort(["a", "b"], false)?;
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
+}
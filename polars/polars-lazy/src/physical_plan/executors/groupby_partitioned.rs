@@ -33,7 +33,9 @@ impl PartitionGroupByExec {
     }
 
     fn keys(&self, df: &DataFrame, state: &ExecutionState) -> Result<Vec<Series>> {
-        self.keys.iter().map(|s| s.evaluate(df, state)).collect()
+        let keys = self.keys.iter().map(|s| s.evaluate(df, state)).collect();
+        state.clear_cached_exprs();
+        keys
     }
 }
 
@@ -52,6 +54,7 @@ fn run_partitions(
     POOL.install(|| {
         dfs.into_par_iter()
             .map(|df| {
+                state.check_cancelled()?;
                 let keys = exec.keys(&df, state)?;
                 let phys_aggs = &exec.phys_aggs;
                 let gb = df.groupby_with_series(keys, false, maintain_order)?;
@@ -92,7 +95,7 @@ fn run_partitions(
     }).collect()
 }
 
-fn estimate_unique_count(keys: &[Series], mut sample_size: usize) -> usize {
+pub(super) fn estimate_unique_count(keys: &[Series], mut sample_size: usize) -> usize {
     // https://stats.stackexchange.com/a/19090/147321
     // estimated unique size
     // u + ui / m (s - m)
@@ -144,6 +147,11 @@ fn can_run_partitioned(keys: &[Series], original_df: &DataFrame, state: &Executi
             eprintln!("POLARS_NO_PARTITION set: running default HASH AGGREGATION")
         }
         false
+    } else if state.low_memory {
+        if state.verbose() {
+            eprintln!("low memory mode: running default HASH AGGREGATION")
+        }
+        false
     } else if std::env::var("POLARS_FORCE_PARTITION").is_ok() {
         if state.verbose() {
             eprintln!("POLARS_FORCE_PARTITION set: running partitioned HASH AGGREGATION")
@@ -200,7 +208,8 @@ impl Executor for PartitionGroupByExec {
             }
         }
         let dfs = {
-            let original_df = self.input.execute(state)?;
+            let mut original_df = self.input.execute(state)?;
+            state.rechunk_fragmented(&mut original_df);
 
             // already get the keys. This is the very last minute decision which groupby method we choose.
             // If the column is a categorical, we know the number of groups we have and can decide to continue
@@ -209,6 +218,19 @@ impl Executor for PartitionGroupByExec {
             let keys = self.keys(&original_df, state)?;
 
             if !can_run_partitioned(&keys, &original_df, state) {
+                if !self.maintain_order {
+                    if let Some(n_partitions) = hash_partition_count(&keys, &original_df, state)? {
+                        return groupby_hash_partitioned(
+                            original_df,
+                            keys,
+                            &self.phys_aggs,
+                            state,
+                            &self.input_schema,
+                            n_partitions,
+                            self.slice,
+                        );
+                    }
+                }
                 return groupby_helper(
                     original_df,
                     keys,
@@ -231,6 +253,7 @@ impl Executor for PartitionGroupByExec {
             run_partitions(&original_df, self, state, n_threads, self.maintain_order)?
         };
         state.clear_schema_cache();
+        state.check_cancelled()?;
 
         // MERGE phase
         // merge and hash aggregate again
@@ -33,6 +33,7 @@ impl Executor for GroupByRollingExec {
             .iter()
             .map(|e| e.evaluate(&df, state))
             .collect::<Result<Vec<_>>>()?;
+        state.clear_cached_exprs();
 
         let (time_key, keys, groups) = df.groupby_rolling(keys, &self.options)?;
 
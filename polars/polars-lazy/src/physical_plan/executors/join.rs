@@ -1,6 +1,8 @@
 use crate::logical_plan::FETCH_ROWS;
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
+use crate::utils::join_key_column;
+use polars_core::frame::hash_join::_TEMPORARY_JOIN_KEY;
 use polars_core::prelude::*;
 use polars_core::POOL;
 use std::borrow::Cow;
@@ -14,6 +16,8 @@ pub struct JoinExec {
     parallel: bool,
     suffix: Cow<'static, str>,
     slice: Option<(i64, usize)>,
+    validate: JoinValidation,
+    nulls_equal: bool,
 }
 
 impl JoinExec {
@@ -27,6 +31,8 @@ impl JoinExec {
         parallel: bool,
         suffix: Cow<'static, str>,
         slice: Option<(i64, usize)>,
+        validate: JoinValidation,
+        nulls_equal: bool,
     ) -> Self {
         JoinExec {
             input_left: Some(input_left),
@@ -37,6 +43,8 @@ impl JoinExec {
             parallel,
             suffix,
             slice,
+            validate,
+            nulls_equal,
         }
     }
 }
@@ -49,15 +57,17 @@ impl Executor for JoinExec {
                 println!("run JoinExec")
             }
         }
+        // in low memory mode we don't materialize both inputs at the same time
+        let parallel = self.parallel && !state.low_memory;
         if state.verbose() {
-            eprintln!("join parallel: {}", self.parallel);
+            eprintln!("join parallel: {}", parallel);
         };
-        let mut input_left = self.input_left.take().unwrap();
-        let mut input_right = self.input_right.take().unwrap();
+        let input_left = self.input_left.as_mut().unwrap();
+        let input_right = self.input_right.as_mut().unwrap();
 
-        let (df_left, df_right) = if self.parallel {
-            let mut state_right = state.split();
-            let mut state_left = state.split();
+        let (df_left, df_right) = if parallel {
+            let mut state_right = state.split_branch();
+            let mut state_left = state.split_branch();
             state_right.branch_idx += 1;
             // propagate the fetch_rows static value to the spawning threads.
             let fetch_rows = FETCH_ROWS.with(|fetch_rows| fetch_rows.get());
@@ -73,31 +83,37 @@ impl Executor for JoinExec {
                 },
             )
         } else {
-            (input_left.execute(state), input_right.execute(state))
+            let df_left = input_left.execute(state)?;
+            (Ok(df_left), input_right.execute(state))
         };
 
         let mut df_left = df_left?;
         let mut df_right = df_right?;
+        state.rechunk_fragmented(&mut df_left);
+        state.rechunk_fragmented(&mut df_right);
 
-        let left_on_series = self
-            .left_on
-            .iter()
-            .map(|e| e.evaluate(&df_left, state))
-            .collect::<Result<Vec<_>>>()?;
-
-        let right_on_series = self
-            .right_on
-            .iter()
-            .map(|e| e.evaluate(&df_right, state))
-            .collect::<Result<Vec<_>>>()?;
+        // keys that are computed by an expression get a temporary name, so that they
+        // don't overwrite the columns of the tables
+        let evaluate_keys = |exprs: &[Arc<dyn PhysicalExpr>], df: &DataFrame, side: &str| {
+            exprs
+                .iter()
+                .enumerate()
+                .map(|(i, e)| {
+                    let mut s = e.evaluate(df, state)?;
+                    if e.as_expression().and_then(join_key_column).is_none() {
+                        s.rename(&format!("{}{}_{}", _TEMPORARY_JOIN_KEY, side, i));
+                    }
+                    Ok(s)
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+        let left_on_series = evaluate_keys(&self.left_on, &df_left, "LEFT")?;
+        let right_on_series = evaluate_keys(&self.right_on, &df_right, "RIGHT")?;
+        state.clear_cached_exprs();
 
-        // make sure that we can join on evaluated expressions
-        for s in &left_on_series {
-            df_left.with_column(s.clone())?;
-        }
-        for s in &right_on_series {
-            df_right.with_column(s.clone())?;
-        }
+        self.validate
+            .validate(&self.how, &left_on_series, &right_on_series)?;
+        state.check_cancelled()?;
 
         // prepare the tolerance
         // we must ensure that we use the right units
@@ -110,7 +126,7 @@ impl Executor for JoinExec {
                     if duration.months() != 0 {
                         return Err(PolarsError::ComputeError("Cannot use month offset in timedelta of an asof join. Consider using 4 weeks".into()));
                     }
-                    let left_asof = df_left.column(left_on_series[0].name())?;
+                    let left_asof = &left_on_series[0];
                     use DataType::*;
                     match left_asof.dtype() {
                         Datetime(tu, _) | Duration(tu) => {
@@ -137,15 +153,25 @@ impl Executor for JoinExec {
             }
         }
 
-        let df = df_left._join_impl(
+        // the order of the matches depends on the hash tables and the number of threads, so in
+        // the deterministic mode the rows are ordered by the rows of the left and right table
+        let df = df_left._join_ordered(
             &df_right,
-            left_on_series,
-            right_on_series,
-            self.how.clone(),
-            Some(self.suffix.clone().into_owned()),
+            &self.how,
             self.slice,
-            true,
-            state.verbose(),
+            state.deterministic,
+            |left, right, slice| {
+                left._join_with_keys(
+                    right,
+                    left_on_series,
+                    right_on_series,
+                    self.how.clone(),
+                    Some(self.suffix.clone().into_owned()),
+                    slice,
+                    state.verbose(),
+                    self.nulls_equal,
+                )
+            },
         );
 
         if state.verbose() {
@@ -17,7 +17,6 @@ impl Executor for MeltExec {
             }
         }
         let df = self.input.execute(state)?;
-        let args = std::mem::take(Arc::make_mut(&mut self.args));
-        df.melt2(args)
+        df.melt2((*self.args).clone())
     }
 }
@@ -5,10 +5,12 @@ mod ext_context;
 mod filter;
 mod groupby;
 mod groupby_dynamic;
+mod groupby_hash_partitioned;
 mod groupby_partitioned;
 mod groupby_rolling;
 mod join;
 mod melt;
+mod plan_node;
 mod projection;
 #[cfg(feature = "python")]
 mod python_scan;
@@ -24,8 +26,9 @@ pub(super) use self::python_scan::*;
 
 pub(super) use self::{
     cache::*, drop_duplicates::*, explode::*, ext_context::*, filter::*, groupby::*,
-    groupby_dynamic::*, groupby_partitioned::*, groupby_rolling::*, join::*, melt::*,
-    projection::*, scan::*, slice::*, sort::*, stack::*, udf::*, union::*,
+    groupby_dynamic::*, groupby_hash_partitioned::*, groupby_partitioned::*, groupby_rolling::*,
+    join::*, melt::*, plan_node::*, projection::*, scan::*, slice::*, sort::*, stack::*, udf::*,
+    union::*,
 };
 
 use super::*;
@@ -35,11 +38,13 @@ use polars_core::POOL;
 use rayon::prelude::*;
 use std::path::PathBuf;
 
+/// The number of rows a scan reads. A fetch limits every scan to the fetched number of rows.
 fn set_n_rows(n_rows: Option<usize>) -> Option<usize> {
     let fetch_rows = FETCH_ROWS.with(|fetch_rows| fetch_rows.get());
-    match fetch_rows {
-        None => n_rows,
-        Some(n) => Some(n),
+    match (fetch_rows, n_rows) {
+        (None, n_rows) => n_rows,
+        (Some(n), None) => Some(n),
+        (Some(n), Some(n_rows)) => Some(std::cmp::min(n, n_rows)),
     }
 }
 
@@ -144,6 +149,19 @@ fn execute_projection_cached_window_fns(
     Ok(selected_columns)
 }
 
+/// Evaluate the cached subexpressions of a projection one by one, so that the expressions that
+/// are evaluated in parallel afterwards find them in the cache instead of evaluating them
+/// concurrently. An error is ignored here, it is raised by the expression that uses it.
+pub(crate) fn evaluate_cached_expressions(
+    df: &DataFrame,
+    cached: &[Arc<dyn PhysicalExpr>],
+    state: &ExecutionState,
+) {
+    for expr in cached {
+        let _ = expr.evaluate(df, state);
+    }
+}
+
 pub(crate) fn evaluate_physical_expressions(
     df: &DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
@@ -0,0 +1,45 @@
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use parking_lot::RwLock;
+use polars_core::prelude::*;
+
+/// Points the errors of its input to the node of the logical plan the input executes,
+/// so that an error of a long query shows which operation failed. It also checks
+/// whether the query is cancelled before and after its input executes and reports
+/// the progress once it is done.
+pub struct PlanNodeExec {
+    pub input: Box<dyn Executor>,
+    pub node: ALogicalPlan,
+    /// The expressions of the plan, the node is only described once it fails.
+    pub exprs: Arc<RwLock<Arena<AExpr>>>,
+}
+
+impl PlanNodeExec {
+    fn describe(&self) -> String {
+        self.node.describe_node(&self.exprs.read())
+    }
+}
+
+impl Executor for PlanNodeExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> Result<DataFrame> {
+        state.check_cancelled()?;
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "operation",
+            node = %self.describe(),
+            rows = tracing::field::Empty
+        )
+        .entered();
+        let out = self
+            .input
+            .execute(state)
+            .map_err(|e| e.with_plan_node(&self.describe()))?;
+        #[cfg(feature = "tracing")]
+        span.record("rows", out.height());
+        state.check_cancelled()?;
+        if let Some(progress) = &state.progress {
+            progress.node_completed();
+        }
+        Ok(out)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::physical_plan::executors::evaluate_physical_expressions;
+use crate::physical_plan::executors::{evaluate_cached_expressions, evaluate_physical_expressions};
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_core::prelude::*;
@@ -8,6 +8,8 @@ use polars_core::prelude::*;
 pub struct ProjectionExec {
     pub(crate) input: Box<dyn Executor>,
     pub(crate) expr: Vec<Arc<dyn PhysicalExpr>>,
+    /// The cached subexpressions of `expr`.
+    pub(crate) cached: Vec<Arc<dyn PhysicalExpr>>,
     pub(crate) has_windows: bool,
     pub(crate) input_schema: SchemaRef,
     #[cfg(test)]
@@ -24,6 +26,7 @@ impl Executor for ProjectionExec {
         }
         let df = self.input.execute(state)?;
         state.set_schema(self.input_schema.clone());
+        evaluate_cached_expressions(&df, &self.cached, state);
 
         let df = evaluate_physical_expressions(&df, &self.expr, state, self.has_windows);
 
@@ -40,6 +43,7 @@ impl Executor for ProjectionExec {
         }
 
         state.clear_expr_cache();
+        state.clear_cached_exprs();
         df
     }
 }
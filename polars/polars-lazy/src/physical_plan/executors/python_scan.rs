@@ -8,23 +8,22 @@ pub(crate) struct PythonScanExec {
 }
 
 impl Executor for PythonScanExec {
-    fn execute(&mut self, _state: &mut ExecutionState) -> Result<DataFrame> {
+    fn execute(&mut self, state: &mut ExecutionState) -> Result<DataFrame> {
         #[cfg(debug_assertions)]
         {
-            if _state.verbose() {
+            if state.verbose() {
                 println!("run PythonScanExec")
             }
         }
-        let with_columns = self.options.with_columns.take();
-        Python::with_gil(|py| {
+        let with_columns = self.options.with_columns.clone();
+        let df = Python::with_gil(|py| {
             let pl = PyModule::import(py, "polars").unwrap();
             let pli = pl.getattr("internals").unwrap();
             let deser_and_exec = pli.getattr("_deser_and_exec").unwrap();
 
             let bytes = PyBytes::new(py, &self.options.scan_fn);
 
-            let with_columns =
-                with_columns.map(|mut cols| std::mem::take(Arc::make_mut(&mut cols)));
+            let with_columns = with_columns.map(|cols| cols.as_ref().clone());
 
             let out = deser_and_exec
                 .call1((bytes, with_columns))
@@ -41,6 +40,8 @@ impl Executor for PythonScanExec {
                     cap,
                 )))
             }
-        })
+        })?;
+        state.scanned(df.height());
+        Ok(df)
     }
 }
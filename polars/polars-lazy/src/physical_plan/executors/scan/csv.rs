@@ -10,8 +10,13 @@ pub struct CsvExec {
 }
 
 impl CsvExec {
-    fn read(&mut self) -> Result<DataFrame> {
-        let mut with_columns = mem::take(&mut self.options.with_columns);
+    fn read(
+        &self,
+        low_memory: bool,
+        progress: Option<ReadProgressRef>,
+        cancel_token: Option<CancellationToken>,
+    ) -> Result<DataFrame> {
+        let mut with_columns = self.options.with_columns.clone();
         let mut projected_len = 0;
         with_columns.as_ref().map(|columns| {
             projected_len = columns.len();
@@ -33,17 +38,16 @@ impl CsvExec {
             Some(self.aggregate.as_slice())
         };
 
-        CsvReader::from_path(&self.path)
-            .unwrap()
+        CsvReader::from_path(&self.path)?
             .has_header(self.options.has_header)
             .with_schema(&self.schema)
             .with_delimiter(self.options.delimiter)
             .with_ignore_parser_errors(self.options.ignore_errors)
             .with_skip_rows(self.options.skip_rows)
             .with_n_rows(n_rows)
-            .with_columns(with_columns.map(|mut cols| std::mem::take(Arc::make_mut(&mut cols))))
-            .low_memory(self.options.low_memory)
-            .with_null_values(std::mem::take(&mut self.options.null_values))
+            .with_columns(with_columns.map(|cols| cols.as_ref().clone()))
+            .low_memory(low_memory)
+            .with_null_values(self.options.null_values.clone())
             .with_predicate(predicate)
             .with_aggregate(aggregate)
             .with_encoding(CsvEncoding::LossyUtf8)
@@ -52,8 +56,11 @@ impl CsvExec {
             .with_end_of_line_char(self.options.eol_char)
             .with_encoding(self.options.encoding)
             .with_rechunk(self.options.rechunk)
-            .with_row_count(std::mem::take(&mut self.options.row_count))
+            .with_row_count(self.options.row_count.clone())
             .with_parse_dates(self.options.parse_dates)
+            .with_ragged_lines(self.options.ragged_lines)
+            .with_progress(progress)
+            .with_cancellation(cancel_token)
             .finish()
     }
 }
@@ -68,8 +75,18 @@ impl Executor for CsvExec {
                 .map(|ae| ae.as_expression().unwrap().clone()),
             slice: (self.options.skip_rows, self.options.n_rows),
         };
+        let low_memory = self.options.low_memory || state.low_memory;
+        let retry_policy = state.retry_policy;
+        let progress = state.progress.clone();
+        let cancel_token = state.cancel_token.clone();
         state
             .file_cache
-            .read(finger_print, self.options.file_counter, &mut || self.read())
+            .read(finger_print, self.options.file_counter, &mut || {
+                retry_policy.retry(|| {
+                    read_attempt(progress.as_ref(), |progress| {
+                        self.read(low_memory, progress, cancel_token.clone())
+                    })
+                })
+            })
     }
 }
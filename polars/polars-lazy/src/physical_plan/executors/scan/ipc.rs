@@ -10,21 +10,28 @@ pub struct IpcExec {
 }
 
 impl IpcExec {
-    fn read(&mut self, verbose: bool) -> Result<DataFrame> {
+    fn read(
+        &self,
+        verbose: bool,
+        progress: Option<ReadProgressRef>,
+        cancel_token: Option<CancellationToken>,
+    ) -> Result<DataFrame> {
         let (file, projection, n_rows, aggregate, predicate) = prepare_scan_args(
             &self.path,
             &self.predicate,
-            &mut self.options.with_columns,
-            &mut self.schema,
+            &self.options.with_columns,
+            &self.schema,
             self.options.n_rows,
             &self.aggregate,
-        );
+        )?;
         IpcReader::new(file)
             .with_n_rows(n_rows)
-            .with_row_count(std::mem::take(&mut self.options.row_count))
+            .with_row_count(self.options.row_count.clone())
             .set_rechunk(self.options.rechunk)
             .with_projection(projection)
             .memory_mapped(self.options.memmap)
+            .with_progress(progress)
+            .with_cancellation(cancel_token)
             .finish_with_scan_ops(predicate, aggregate, verbose)
     }
 }
@@ -39,10 +46,18 @@ impl Executor for IpcExec {
                 .map(|ae| ae.as_expression().unwrap().clone()),
             slice: (0, self.options.n_rows),
         };
+        let verbose = state.verbose();
+        let retry_policy = state.retry_policy;
+        let progress = state.progress.clone();
+        let cancel_token = state.cancel_token.clone();
         state
             .file_cache
             .read(finger_print, self.options.file_counter, &mut || {
-                self.read(state.verbose())
+                retry_policy.retry(|| {
+                    read_attempt(progress.as_ref(), |progress| {
+                        self.read(verbose, progress, cancel_token.clone())
+                    })
+                })
             })
     }
 }
@@ -6,12 +6,13 @@ mod ipc;
 mod parquet;
 
 use super::*;
+#[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
+use crate::physical_plan::progress::read_attempt;
 use crate::prelude::*;
 use polars_io::aggregations::ScanAggregation;
 use polars_io::csv::CsvEncoding;
 use polars_io::prelude::*;
 use std::fs::File;
-use std::mem;
 use std::path::Path;
 
 #[cfg(feature = "csv-file")]
@@ -34,17 +35,14 @@ type Predicate = Option<Arc<dyn PhysicalIoExpr>>;
 fn prepare_scan_args<'a>(
     path: &Path,
     predicate: &Option<Arc<dyn PhysicalExpr>>,
-    with_columns: &mut Option<Arc<Vec<String>>>,
-    schema: &mut SchemaRef,
+    with_columns: &Option<Arc<Vec<String>>>,
+    schema: &SchemaRef,
     n_rows: Option<usize>,
     aggregate: &'a [ScanAggregation],
-) -> (File, Projection, StopNRows, Aggregation<'a>, Predicate) {
-    let file = std::fs::File::open(&path).unwrap();
+) -> Result<(File, Projection, StopNRows, Aggregation<'a>, Predicate)> {
+    let file = std::fs::File::open(&path)?;
 
-    let with_columns = mem::take(with_columns);
-    let schema = mem::take(schema);
-
-    let projection: Option<Vec<_>> = with_columns.map(|with_columns| {
+    let projection: Option<Vec<_>> = with_columns.as_ref().map(|with_columns| {
         with_columns
             .iter()
             .map(|name| schema.index_of(name).unwrap())
@@ -61,7 +59,7 @@ fn prepare_scan_args<'a>(
         .clone()
         .map(|expr| Arc::new(PhysicalIoHelper { expr }) as Arc<dyn PhysicalIoExpr>);
 
-    (file, projection, n_rows, aggregate, predicate)
+    Ok((file, projection, n_rows, aggregate, predicate))
 }
 
 /// Producer of an in memory DataFrame
@@ -74,8 +72,9 @@ pub struct DataFrameExec {
 
 impl Executor for DataFrameExec {
     fn execute(&mut self, state: &mut ExecutionState) -> Result<DataFrame> {
-        let df = mem::take(&mut self.df);
-        let mut df = Arc::try_unwrap(df).unwrap_or_else(|df| (*df).clone());
+        // the plan may be executed more than once, so the frame is not taken
+        let mut df = (*self.df).clone();
+        state.scanned(df.height());
 
         // projection should be before selection as those are free
         // TODO: this is only the case if we don't create new columns
@@ -109,7 +108,10 @@ pub(crate) struct AnonymousScanExec {
 
 impl Executor for AnonymousScanExec {
     fn execute(&mut self, state: &mut ExecutionState) -> Result<DataFrame> {
-        let mut df = self.function.scan(self.options.clone())?;
+        let mut options = self.options.clone();
+        options.n_rows = set_n_rows(options.n_rows);
+        let mut df = self.function.scan(options)?;
+        state.scanned(df.height());
         if let Some(predicate) = &self.predicate {
             let s = predicate.evaluate(&df, state)?;
             let mask = s.bool().map_err(|_| {
@@ -26,22 +26,30 @@ impl ParquetExec {
         }
     }
 
-    fn read(&mut self) -> Result<DataFrame> {
+    fn read(
+        &self,
+        low_memory: bool,
+        progress: Option<ReadProgressRef>,
+        cancel_token: Option<CancellationToken>,
+    ) -> Result<DataFrame> {
         let (file, projection, n_rows, aggregate, predicate) = prepare_scan_args(
             &self.path,
             &self.predicate,
-            &mut self.options.with_columns,
-            &mut self.schema,
+            &self.options.with_columns,
+            &self.schema,
             self.options.n_rows,
             &self.aggregate,
-        );
+        )?;
 
         ParquetReader::new(file)
             .with_n_rows(n_rows)
+            .with_dictionary_as_categorical(self.options.dictionary_as_categorical)
             .read_parallel(self.options.parallel)
-            .with_row_count(std::mem::take(&mut self.options.row_count))
+            .with_row_count(self.options.row_count.clone())
             .set_rechunk(self.options.rechunk)
-            .set_low_memory(self.options.low_memory)
+            .set_low_memory(low_memory)
+            .with_progress(progress)
+            .with_cancellation(cancel_token)
             ._finish_with_scan_ops(
                 predicate,
                 aggregate,
@@ -60,8 +68,18 @@ impl Executor for ParquetExec {
                 .map(|ae| ae.as_expression().unwrap().clone()),
             slice: (0, self.options.n_rows),
         };
+        let low_memory = self.options.low_memory || state.low_memory;
+        let retry_policy = state.retry_policy;
+        let progress = state.progress.clone();
+        let cancel_token = state.cancel_token.clone();
         state
             .file_cache
-            .read(finger_print, self.options.file_counter, &mut || self.read())
+            .read(finger_print, self.options.file_counter, &mut || {
+                retry_policy.retry(|| {
+                    read_attempt(progress.as_ref(), |progress| {
+                        self.read(low_memory, progress, cancel_token.clone())
+                    })
+                })
+            })
     }
 }
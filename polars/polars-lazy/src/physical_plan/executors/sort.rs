@@ -1,3 +1,5 @@
+#[cfg(feature = "ipc")]
+use crate::physical_plan::external_sort::{external_sort_options, sort_external_impl};
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_core::prelude::*;
@@ -8,23 +10,14 @@ pub(crate) struct SortExec {
     pub(crate) args: SortArguments,
 }
 
-impl Executor for SortExec {
-    fn execute(&mut self, state: &mut ExecutionState) -> Result<DataFrame> {
-        #[cfg(debug_assertions)]
-        {
-            if state.verbose() {
-                println!("run SortExec")
-            }
-        }
-        let mut df = self.input.execute(state)?;
-        df.as_single_chunk_par();
-
+impl SortExec {
+    fn evaluate_by(&self, df: &DataFrame, state: &ExecutionState) -> Result<Vec<Series>> {
         let by_columns = self
             .by_column
             .iter()
             .enumerate()
             .map(|(i, e)| {
-                let mut s = e.evaluate(&df, state)?;
+                let mut s = e.evaluate(df, state)?;
                 // polars core will try to set the sorted columns as sorted
                 // this should only be done with simple col("foo") expressions
                 // therefore we rename more complex expressions so that
@@ -34,12 +27,48 @@ impl Executor for SortExec {
                 }
                 Ok(s)
             })
-            .collect::<Result<Vec<_>>>()?;
+            .collect();
+        state.clear_cached_exprs();
+        by_columns
+    }
+}
+
+impl Executor for SortExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> Result<DataFrame> {
+        #[cfg(debug_assertions)]
+        {
+            if state.verbose() {
+                println!("run SortExec")
+            }
+        }
+        let mut df = self.input.execute(state)?;
+        state.rechunk_fragmented(&mut df);
+
+        // a sliced sort does not materialize the sorted `DataFrame`
+        // the partitions of an external sort order `NaN` as the largest value
+        #[cfg(feature = "ipc")]
+        if self.args.slice.is_none() && self.args.nan_position == NanPosition::Largest {
+            if let Some(options) = external_sort_options(&df, state.external_sort_budget) {
+                let by_columns = self.evaluate_by(&df, state)?;
+                return sort_external_impl(
+                    df,
+                    by_columns,
+                    self.args.reverse.clone(),
+                    self.args.nulls_last,
+                    &options,
+                    state.verbose(),
+                );
+            }
+        }
+
+        df.as_single_chunk_par();
+        let by_columns = self.evaluate_by(&df, state)?;
 
         df.sort_impl(
             by_columns,
-            std::mem::take(&mut self.args.reverse),
+            self.args.reverse.clone(),
             self.args.nulls_last,
+            self.args.nan_position,
             self.args.slice,
         )
     }
@@ -1,4 +1,6 @@
-use crate::physical_plan::executors::execute_projection_cached_window_fns;
+use crate::physical_plan::executors::{
+    evaluate_cached_expressions, execute_projection_cached_window_fns,
+};
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_core::{prelude::*, POOL};
@@ -8,6 +10,8 @@ pub struct StackExec {
     pub(crate) input: Box<dyn Executor>,
     pub(crate) has_windows: bool,
     pub(crate) expr: Vec<Arc<dyn PhysicalExpr>>,
+    /// The cached subexpressions of `expr`.
+    pub(crate) cached: Vec<Arc<dyn PhysicalExpr>>,
     pub(crate) input_schema: SchemaRef,
 }
 
@@ -22,6 +26,7 @@ impl Executor for StackExec {
         let mut df = self.input.execute(state)?;
 
         state.set_schema(self.input_schema.clone());
+        evaluate_cached_expressions(&df, &self.cached, state);
         let res = if self.has_windows {
             // we have a different run here
             // to ensure the window functions run sequential and share caches
@@ -36,6 +41,31 @@ impl Executor for StackExec {
         };
         state.clear_schema_cache();
         state.clear_expr_cache();
+        state.clear_cached_exprs();
+
+        // all expressions are evaluated on the input, so check them all
+        // before we modify the `DataFrame`
+        let height = df.height();
+        if df.width() > 0 {
+            for (s, expr) in res.iter().zip(&self.expr) {
+                if s.len() != height && s.len() != 1 {
+                    let expr = expr
+                        .as_expression()
+                        .map(|e| format!("{:?}", e))
+                        .unwrap_or_else(|| s.name().to_string());
+                    return Err(PolarsError::ShapeMisMatch(
+                        format!(
+                            "the expression '{}' produced a Series of length {}, \
+                            which differs from the DataFrame height: {}",
+                            expr,
+                            s.len(),
+                            height
+                        )
+                        .into(),
+                    ));
+                }
+            }
+        }
 
         let schema = &*self.input_schema;
         for (i, s) in res.into_iter().enumerate() {
@@ -1,3 +1,4 @@
+use crate::logical_plan::FETCH_ROWS;
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_core::prelude::*;
@@ -18,41 +19,43 @@ impl Executor for UnionExec {
                 println!("run UnionExec")
             }
         }
-        let mut inputs = std::mem::take(&mut self.inputs);
+        let inputs = &mut self.inputs;
+        let fetch_rows = FETCH_ROWS.with(|fetch_rows| fetch_rows.get());
 
-        if self.options.slice && self.options.slice_offset >= 0 {
-            let mut offset = self.options.slice_offset as usize;
-            let mut len = self.options.slice_len as usize;
-            let dfs = inputs
-                .into_iter()
-                .enumerate()
-                .map(|(idx, mut input)| {
-                    let mut state = state.split();
-                    state.branch_idx += idx;
-                    let df = input.execute(&mut state)?;
+        // a fetch takes the first rows of the first inputs, like a slice
+        let slice = match (self.options.slice, fetch_rows) {
+            (true, _) => Some((self.options.slice_offset, self.options.slice_len as usize)),
+            (false, Some(n)) => Some((0, n)),
+            (false, None) => None,
+        };
 
-                    Ok(if offset > df.height() {
-                        offset -= df.height();
-                        None
-                    } else if offset + len > df.height() {
-                        len -= df.height() - offset;
-                        if offset == 0 {
-                            Some(df)
-                        } else {
-                            let out = Some(df.slice(offset as i64, usize::MAX));
-                            offset = 0;
-                            out
-                        }
-                    } else {
-                        let out = Some(df.slice(offset as i64, len));
-                        len = 0;
-                        offset = 0;
-                        out
-                    })
-                })
-                .collect::<Result<Vec<_>>>()?;
+        let mut df = if let Some((offset, len)) = slice.filter(|(offset, _)| *offset >= 0) {
+            let mut offset = offset as usize;
+            let mut len = len;
+            let mut dfs = Vec::with_capacity(inputs.len());
+            for (idx, input) in inputs.iter_mut().enumerate() {
+                // enough rows are gathered, the remaining inputs don't have to be executed
+                if len == 0 && !dfs.is_empty() {
+                    break;
+                }
+                let mut state = state.split_branch();
+                state.branch_idx += idx;
+                let df = input.execute(&mut state)?;
 
-            concat_df(dfs.iter().flatten())
+                if offset >= df.height() {
+                    offset -= df.height();
+                    // keep an empty frame so that the schema is known
+                    if dfs.is_empty() {
+                        dfs.push(df.slice(0, 0));
+                    }
+                } else {
+                    let out = df.slice(offset as i64, len);
+                    len -= out.height();
+                    offset = 0;
+                    dfs.push(out);
+                }
+            }
+            concat_df(&dfs)?
         } else {
             // we don't use par_iter directly because the LP may also start threads for every LP (for instance scan_csv)
             // this might then lead to a rayon SO. So we take a multitude of the threads to keep work stealing
@@ -65,17 +68,28 @@ impl Executor for UnionExec {
                             .into_par_iter()
                             .enumerate()
                             .map(|(idx, input)| {
-                                let mut input = std::mem::take(input);
-                                let mut state = state.split();
+                                // propagate the fetch_rows static value to the spawning threads.
+                                let prev = FETCH_ROWS.with(|fr| fr.replace(fetch_rows));
+                                let mut state = state.split_branch();
                                 state.branch_idx += idx;
-                                input.execute(&mut state)
+                                let out = input.execute(&mut state);
+                                FETCH_ROWS.with(|fr| fr.set(prev));
+                                out
                             })
                             .collect::<Result<Vec<_>>>()
                     })
                     .collect::<Result<Vec<_>>>()
             });
 
-            concat_df(out?.iter().flat_map(|dfs| dfs.iter()))
+            let df = concat_df(out?.iter().flat_map(|dfs| dfs.iter()))?;
+            match slice {
+                Some((offset, len)) => df.slice(offset, len),
+                None => df,
+            }
+        };
+        if self.options.rechunk {
+            df.as_single_chunk_par();
         }
+        Ok(df)
     }
 }
@@ -1,6 +1,5 @@
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
-use polars_arrow::utils::CustomIterTools;
 use polars_core::frame::groupby::GroupsProxy;
 use polars_core::prelude::*;
 use polars_core::POOL;
@@ -46,6 +45,18 @@ impl ApplyExpr {
         }
         ac
     }
+
+    /// Apply the function on a single group.
+    ///
+    /// A [`PolarsError::NoData`] means that the group has no output and becomes a null value,
+    /// all other errors are propagated.
+    fn call_on_group(&self, container: &mut [Series]) -> Result<Option<Series>> {
+        match self.function.call_udf(container) {
+            Ok(s) => Ok(Some(s)),
+            Err(e) if e.code() == ErrorCode::NoData => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 fn all_unit_length(ca: &ListChunked) -> bool {
@@ -118,13 +129,11 @@ impl PhysicalExpr for ApplyExpr {
                         .list()
                         .unwrap()
                         .par_iter()
-                        .map(|opt_s| {
-                            opt_s.and_then(|s| {
-                                let mut container = [s];
-                                self.function.call_udf(&mut container).ok()
-                            })
+                        .map(|opt_s| match opt_s {
+                            None => Ok(None),
+                            Some(s) => self.call_on_group(&mut [s]),
                         })
-                        .collect();
+                        .collect::<Result<_>>()?;
 
                     ca.rename(&name);
                     Ok(self.finish_apply_groups(ac, ca))
@@ -172,13 +181,13 @@ impl PhysicalExpr for ApplyExpr {
                             container.clear();
                             for iter in &mut iters {
                                 match iter.next().unwrap() {
-                                    None => return None,
+                                    None => return Ok(None),
                                     Some(s) => container.push(s.deep_clone()),
                                 }
                             }
-                            self.function.call_udf(&mut container).ok()
+                            self.call_on_group(&mut container)
                         })
-                        .collect_trusted();
+                        .collect::<Result<_>>()?;
                     ca.rename(&name);
                     drop(iters);
 
@@ -223,7 +232,7 @@ impl PhysicalExpr for ApplyExpr {
         }
     }
     fn to_field(&self, input_schema: &Schema) -> Result<Field> {
-        self.inputs[0].to_field(input_schema)
+        self.expr.to_field(input_schema, Context::Default)
     }
     fn is_valid_aggregation(&self) -> bool {
         matches!(self.collect_groups, ApplyOptions::ApplyGroups)
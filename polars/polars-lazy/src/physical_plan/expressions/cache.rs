@@ -0,0 +1,57 @@
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use polars_core::frame::groupby::GroupsProxy;
+use polars_core::prelude::*;
+use std::sync::Arc;
+
+/// Evaluates its input once per `DataFrame`: the result is stored in the
+/// [`ExecutionState`] and shared by all [`CacheExpr`]s with the same `key`.
+pub struct CacheExpr {
+    pub(crate) physical_expr: Arc<dyn PhysicalExpr>,
+    pub(crate) key: String,
+    expr: Expr,
+}
+
+impl CacheExpr {
+    pub fn new(physical_expr: Arc<dyn PhysicalExpr>, key: String, expr: Expr) -> Self {
+        Self {
+            physical_expr,
+            key,
+            expr,
+        }
+    }
+}
+
+impl PhysicalExpr for CacheExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        Some(&self.expr)
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> Result<Series> {
+        if let Some(s) = state.cached_expr(&self.key, df) {
+            return Ok(s);
+        }
+        let s = self.physical_expr.evaluate(df, state)?;
+        state.store_cached_expr(&self.key, df, s.clone());
+        Ok(s)
+    }
+
+    // the groups differ per use, so the result is not shared in an aggregation
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> Result<AggregationContext<'a>> {
+        self.physical_expr.evaluate_on_groups(df, groups, state)
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> Result<Field> {
+        self.physical_expr.to_field(input_schema)
+    }
+
+    fn is_valid_aggregation(&self) -> bool {
+        self.physical_expr.is_valid_aggregation()
+    }
+}
@@ -67,11 +67,15 @@ impl CastExpr {
             }
         }
 
-        if self.strict {
+        let out = if self.strict {
             input.strict_cast(&self.data_type)
         } else {
             input.cast(&self.data_type)
-        }
+        };
+        out.map_err(|err| {
+            err.with_column(input.name())
+                .with_dtypes(input.dtype(), &self.data_type)
+        })
     }
 }
 
@@ -1,7 +1,7 @@
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_arrow::is_valid::IsValid;
-use polars_core::frame::groupby::GroupsProxy;
+use polars_core::frame::groupby::{GroupsIndicator, GroupsProxy};
 use polars_core::{prelude::*, POOL};
 use rayon::prelude::*;
 use std::sync::Arc;
@@ -42,7 +42,23 @@ impl PhysicalExpr for FilterExpr {
         let ac_predicate_f = || self.by.evaluate_on_groups(df, groups, state);
 
         let (ac_s, ac_predicate) = POOL.install(|| rayon::join(ac_s_f, ac_predicate_f));
-        let (mut ac_s, ac_predicate) = (ac_s?, ac_predicate?);
+        let (mut ac_s, mut ac_predicate) = (ac_s?, ac_predicate?);
+
+        // the group indexes do not point into an aggregated input, so that is filtered per group
+        if !matches!(ac_s.agg_state(), AggState::NotAggregated(_)) {
+            let out = filter_aggregated(&mut ac_s, &mut ac_predicate)?;
+            ac_s.with_series(out, true)
+                .with_update_groups(UpdateGroups::WithSeriesLen)
+                .set_original_len(false);
+            return Ok(ac_s);
+        }
+        // only if both are still in the order of the rows the predicate can be indexed
+        // with the group indexes, otherwise we filter by the predicate of every group
+        if !matches!(ac_predicate.agg_state(), AggState::NotAggregated(_)) {
+            let groups = filter_by_group_predicates(ac_s.groups(), &mut ac_predicate)?;
+            ac_s.with_groups(groups).set_original_len(false);
+            return Ok(ac_s);
+        }
 
         let groups = ac_s.groups();
         let predicate_s = ac_predicate.flat_naive();
@@ -108,3 +124,94 @@ impl PhysicalExpr for FilterExpr {
         self.input.is_valid_aggregation()
     }
 }
+
+/// Filter every group of an aggregated input by the predicate values of that group, which gives
+/// a list per group. A predicate that is a single value per group (or literal) keeps or drops
+/// the whole group.
+fn filter_aggregated(
+    ac_s: &mut AggregationContext,
+    ac_predicate: &mut AggregationContext,
+) -> Result<Series> {
+    let name = ac_s.series().name().to_string();
+    let mut out = ac_s
+        .iter_groups()
+        .zip(ac_predicate.iter_groups())
+        .map(|(opt_s, opt_mask)| match (opt_s, opt_mask) {
+            (Some(s), Some(mask)) => s.as_ref().filter(mask.as_ref().bool()?).map(Some),
+            (Some(s), None) => Ok(Some(s.as_ref().slice(0, 0))),
+            (None, _) => Ok(None),
+        })
+        .collect::<Result<ListChunked>>()?;
+    out.rename(&name);
+    Ok(out.into_series())
+}
+
+/// Filter the groups by the predicate values of that group. A predicate that is a single value
+/// per group (or literal) keeps or drops the whole group.
+fn filter_by_group_predicates(
+    groups: &GroupsProxy,
+    ac_predicate: &mut AggregationContext,
+) -> Result<GroupsProxy> {
+    let filter_group = |g: GroupsIndicator, keep: &mut dyn FnMut(usize) -> bool| {
+        let (first, idx): (IdxSize, Vec<IdxSize>) = match g {
+            GroupsIndicator::Idx((first, idx)) => (
+                first,
+                idx.iter()
+                    .enumerate()
+                    .filter(|(i, _)| keep(*i))
+                    .map(|(_, idx)| *idx)
+                    .collect(),
+            ),
+            GroupsIndicator::Slice([first, len]) => (
+                first,
+                (first..first + len)
+                    .enumerate()
+                    .filter(|(i, _)| keep(*i))
+                    .map(|(_, idx)| idx)
+                    .collect(),
+            ),
+        };
+        (*idx.first().unwrap_or(&first), idx)
+    };
+
+    let groups = match ac_predicate.agg_state() {
+        AggState::Literal(s) | AggState::AggregatedFlat(s) => {
+            let predicate = s.bool()?;
+            let literal = ac_predicate.is_literal();
+            groups
+                .iter()
+                .enumerate()
+                .map(|(i, g)| {
+                    let keep = predicate.get(if literal { 0 } else { i }) == Some(true);
+                    filter_group(g, &mut |_| keep)
+                })
+                .collect()
+        }
+        _ => {
+            let predicate = ac_predicate.aggregated();
+            let predicate = predicate.list()?;
+            groups
+                .iter()
+                .zip(predicate.amortized_iter())
+                .map(|(g, opt_mask)| {
+                    let mask = match opt_mask {
+                        Some(mask) => mask.as_ref().bool()?.clone(),
+                        None => BooleanChunked::full("", false, g.len()),
+                    };
+                    if mask.len() != g.len() {
+                        return Err(PolarsError::ShapeMisMatch(
+                            format!(
+                                "the predicate of a filter produced {} values in a group of length {}",
+                                mask.len(),
+                                g.len()
+                            )
+                            .into(),
+                        ));
+                    }
+                    Ok(filter_group(g, &mut |i| mask.get(i) == Some(true)))
+                })
+                .collect::<Result<_>>()?
+        }
+    };
+    Ok(GroupsProxy::Idx(groups))
+}
@@ -10,12 +10,12 @@ impl<'a> AggregationContext<'a> {
             AggState::Literal(_) => {
                 self.groups();
                 let s = self.series();
-                Box::new(LitIter::new(s.array_ref(0).clone(), self.groups.len()))
+                Box::new(LitIter::new(s, self.groups.len()))
             }
             AggState::AggregatedFlat(_) => {
                 self.groups();
                 let s = self.series();
-                Box::new(FlatIter::new(s.array_ref(0).clone(), self.groups.len()))
+                Box::new(FlatIter::new(s, self.groups.len()))
             }
             AggState::AggregatedList(_) => {
                 let s = self.series();
@@ -43,8 +43,9 @@ struct LitIter<'a> {
 }
 
 impl<'a> LitIter<'a> {
-    fn new(array: ArrayRef, len: usize) -> Self {
-        let mut series_container = Box::pin(Series::try_from(("", array.clone())).unwrap());
+    fn new(s: &Series, len: usize) -> Self {
+        // the container keeps the dtype of `s`, so that logical types are preserved
+        let mut series_container = Box::pin(s.rechunk());
         let ref_s = &mut *series_container as *mut Series;
         Self {
             offset: 0,
@@ -84,8 +85,10 @@ struct FlatIter<'a> {
 }
 
 impl<'a> FlatIter<'a> {
-    fn new(array: ArrayRef, len: usize) -> Self {
-        let mut series_container = Box::pin(Series::try_from(("", array.clone())).unwrap());
+    fn new(s: &Series, len: usize) -> Self {
+        // the container keeps the dtype of `s`, so that logical types are preserved
+        let mut series_container = Box::pin(s.rechunk());
+        let array = series_container.array_ref(0).clone();
         let ref_s = &mut *series_container as *mut Series;
         Self {
             array,
@@ -114,6 +114,11 @@ impl PhysicalExpr for LiteralExpr {
                     .into_series()
             }
             Series(series) => series.deref().clone(),
+            Parameter { name, .. } => {
+                return Err(PolarsError::ComputeError(
+                    format!("no value is bound to parameter '{}'", name).into(),
+                ))
+            }
         };
         Ok(s)
     }
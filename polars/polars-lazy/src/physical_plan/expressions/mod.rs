@@ -2,6 +2,7 @@ mod aggregation;
 mod alias;
 mod apply;
 mod binary;
+mod cache;
 mod cast;
 mod column;
 mod count;
@@ -20,13 +21,14 @@ mod ternary;
 mod window;
 
 pub(crate) use {
-    aggregation::*, alias::*, apply::*, binary::*, cast::*, column::*, count::*, filter::*,
-    is_not_null::*, is_null::*, literal::*, not::*, shift::*, slice::*, sort::*, sortby::*,
-    take::*, ternary::*, window::*,
+    aggregation::*, alias::*, apply::*, binary::*, cache::*, cast::*, column::*, count::*,
+    filter::*, is_not_null::*, is_null::*, literal::*, not::*, shift::*, slice::*, sort::*,
+    sortby::*, take::*, ternary::*, window::*,
 };
 
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
+use crate::utils::expr_to_root_column_names;
 use polars_arrow::export::arrow::array::ListArray;
 use polars_arrow::trusted_len::PushUnchecked;
 use polars_arrow::utils::CustomIterTools;
@@ -588,6 +590,10 @@ impl PhysicalIoExpr for PhysicalIoHelper {
         self.expr.evaluate(df, &Default::default())
     }
 
+    fn live_columns(&self) -> Option<Vec<Arc<str>>> {
+        self.expr.as_expression().map(expr_to_root_column_names)
+    }
+
     #[cfg(feature = "parquet")]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         self.expr.as_stats_evaluator()
@@ -26,6 +26,33 @@ pub struct WindowExpr {
     pub(crate) expr: Expr,
 }
 
+/// Write the key of a partition expression. The debug representation omits the arguments of
+/// functions and aggregations and the values of `Series` literals, so these are written per node.
+/// The functions of `map` and `apply` and the `Series` literals are identified by their address.
+fn write_cache_key(cache_key: &mut String, e: &Expr) {
+    write!(cache_key, "{:?}", e).unwrap();
+    for e in e {
+        match e {
+            Expr::AnonymousFunction { function, .. } => {
+                write!(cache_key, "{:p}", Arc::as_ptr(function)).unwrap()
+            }
+            Expr::Function { function, .. } => write!(cache_key, "{:?}", function).unwrap(),
+            Expr::Agg(AggExpr::Std(_, ddof) | AggExpr::Var(_, ddof)) => {
+                write!(cache_key, "ddof={}", ddof).unwrap()
+            }
+            Expr::Agg(AggExpr::Quantile {
+                quantile, interpol, ..
+            }) => write!(cache_key, "q={:?},{:?}", quantile, interpol).unwrap(),
+            Expr::Literal(LiteralValue::Series(s)) => {
+                let s: &Series = s;
+                write!(cache_key, "{:p}", Arc::as_ptr(&s.0)).unwrap()
+            }
+            Expr::Sort { options, .. } => write!(cache_key, "{:?}", options).unwrap(),
+            _ => {}
+        }
+    }
+}
+
 #[derive(Debug)]
 enum MapStrategy {
     Join,
@@ -38,6 +65,33 @@ enum MapStrategy {
 }
 
 impl WindowExpr {
+    /// Evaluate the partition expressions, or get them from the cache.
+    fn evaluate_keys(
+        &self,
+        df: &DataFrame,
+        state: &ExecutionState,
+        cache_key: &str,
+    ) -> Result<Vec<Series>> {
+        if state.cache_window() {
+            let keys_map = state.window_keys.lock();
+            if let Some(keys) = keys_map.get(cache_key) {
+                if keys.iter().all(|s| s.len() == df.height()) {
+                    return Ok(keys.clone());
+                }
+            }
+        }
+        let keys = self
+            .group_by
+            .iter()
+            .map(|e| e.evaluate(df, state))
+            .collect::<Result<Vec<_>>>()?;
+        if state.cache_window() {
+            let mut keys_map = state.window_keys.lock();
+            keys_map.insert(cache_key.to_string(), keys.clone());
+        }
+        Ok(keys)
+    }
+
     fn run_aggregation<'a>(
         &self,
         df: &DataFrame,
@@ -232,11 +286,24 @@ impl PhysicalExpr for WindowExpr {
         //              `(col("x").sum() * col("y")).over("groups")`
 
         // 4. select the final column and return
-        let groupby_columns = self
-            .group_by
-            .iter()
-            .map(|e| e.evaluate(df, state))
-            .collect::<Result<Vec<_>>>()?;
+
+        // the window functions with the same partition expressions share the cached keys,
+        // groups and join tuples. The expressions are used, as computed keys may have the
+        // same names as other keys.
+        let cache_key = if state.cache_window() {
+            let mut cache_key = String::with_capacity(32 * self.group_by.len());
+            write!(&mut cache_key, "{}", state.branch_idx).unwrap();
+            for e in &self.group_by {
+                match e.as_expression() {
+                    Some(e) => write_cache_key(&mut cache_key, e),
+                    None => write!(&mut cache_key, "{:p}", Arc::as_ptr(e)).unwrap(),
+                }
+            }
+            cache_key
+        } else {
+            "".to_string()
+        };
+        let groupby_columns = self.evaluate_keys(df, state, &cache_key)?;
 
         // if the keys are sorted
         let sorted_keys = groupby_columns
@@ -263,13 +330,7 @@ impl PhysicalExpr for WindowExpr {
         };
 
         // Try to get cached grouptuples
-        let (groups, _, cache_key) = if state.cache_window() {
-            let mut cache_key = String::with_capacity(32 * groupby_columns.len());
-            write!(&mut cache_key, "{}", state.branch_idx).unwrap();
-            for s in &groupby_columns {
-                cache_key.push_str(s.name());
-            }
-
+        let (groups, _) = if state.cache_window() {
             let mut gt_map = state.group_tuples.lock();
             // we run sequential and partitioned
             // and every partition run the cache should be empty so we expect a max of 1.
@@ -285,12 +346,12 @@ impl PhysicalExpr for WindowExpr {
                 // We take now, but it is important that we set this before we return!
                 // a next windows function may get this cached key and get an empty if this
                 // does not happen
-                (std::mem::take(gt), true, cache_key)
+                (std::mem::take(gt), true)
             } else {
-                (create_groups()?, false, cache_key)
+                (create_groups()?, false)
             }
         } else {
-            (create_groups()?, false, "".to_string())
+            (create_groups()?, false)
         };
 
         // 2. create GroupBy object and apply aggregation
@@ -0,0 +1,308 @@
+//! Sorting of `DataFrame`s that do not fit in the memory budget.
+//!
+//! The rows are range partitioned on the first sort key. The splitters are taken from a sample
+//! of that key, so that every partition is expected to fit in the memory budget. The partitions
+//! are spilled to ipc files, after which the input is dropped. The partitions are then read
+//! back in key order, sorted in memory one by one and concatenated.
+use parking_lot::RwLock;
+use polars_core::export::once_cell::sync::Lazy;
+use polars_core::prelude::*;
+use polars_core::utils::NoNull;
+use polars_io::prelude::*;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static EXTERNAL_SORT: Lazy<RwLock<Option<ExternalSortOptions>>> = Lazy::new(Default::default);
+
+const SORT_KEY: &str = "__POLARS_SORT_KEY_";
+// number of samples of the first key per partition
+const SAMPLES_PER_PARTITION: usize = 64;
+
+/// Configuration of the disk backed sort.
+#[derive(Clone, Debug)]
+pub struct ExternalSortOptions {
+    /// Maximum (estimated) memory in bytes a sort may use before it spills to disk.
+    pub memory_budget: usize,
+    /// Directory in which the partitions are spilled. Defaults to [`std::env::temp_dir`].
+    pub temp_dir: Option<PathBuf>,
+}
+
+impl Default for ExternalSortOptions {
+    fn default() -> Self {
+        Self {
+            memory_budget: 1 << 32,
+            temp_dir: None,
+        }
+    }
+}
+
+/// Let the sorts of [`LazyFrame`](crate::frame::LazyFrame)s spill to disk
+/// if their estimated memory usage exceeds the budget.
+pub fn enable_external_sort(options: ExternalSortOptions) {
+    *EXTERNAL_SORT.write() = Some(options);
+}
+
+/// Always sort in memory.
+pub fn disable_external_sort() {
+    *EXTERNAL_SORT.write() = None;
+}
+
+/// Check if sorts may spill to disk.
+pub fn using_external_sort() -> bool {
+    EXTERNAL_SORT.read().is_some()
+}
+
+/// The options of the external sort, if the sort of `df` must spill to disk.
+/// The `memory_budget` of a query overrides the global budget.
+pub(crate) fn external_sort_options(
+    df: &DataFrame,
+    memory_budget: Option<usize>,
+) -> Option<ExternalSortOptions> {
+    let global = EXTERNAL_SORT.read().clone();
+    let options = match memory_budget {
+        Some(memory_budget) => Some(ExternalSortOptions {
+            memory_budget,
+            temp_dir: global.and_then(|options| options.temp_dir),
+        }),
+        None => global,
+    };
+    options.filter(|options| estimated_sort_size(df) > options.memory_budget)
+}
+
+// the sorted copy and the sort indices
+fn estimated_sort_size(df: &DataFrame) -> usize {
+    df.estimated_size() + df.height() * std::mem::size_of::<IdxSize>()
+}
+
+/// A directory with spilled partitions that is removed on drop.
+struct SpillDir(PathBuf);
+
+impl SpillDir {
+    fn new(parent: &Path) -> Result<Self> {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let path = parent.join(format!(
+            "polars-sort-{}-{}",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&path)?;
+        Ok(SpillDir(path))
+    }
+
+    fn write(&self, idx: usize, df: &mut DataFrame) -> Result<PathBuf> {
+        let path = self.0.join(format!("{}.ipc", idx));
+        let file = std::fs::File::create(&path)?;
+        IpcWriter::new(file).finish(df)?;
+        Ok(path)
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Sorted, unique values of the first key that split it into `n` parts.
+fn splitters(key: &Series, n: usize) -> Result<Series> {
+    let mut valid = key.drop_nulls();
+    if matches!(valid.dtype(), DataType::Float32 | DataType::Float64) {
+        valid = valid.filter(&valid.is_not_nan()?)?;
+    }
+    let len = valid.len();
+    if len == 0 {
+        return Ok(valid);
+    }
+    let n_samples = std::cmp::min(len, n * SAMPLES_PER_PARTITION);
+    let idx: NoNull<IdxCa> = (0..n_samples)
+        .map(|i| (i * len / n_samples) as IdxSize)
+        .collect();
+    let sample = valid.take(&idx.into_inner())?.sort(false);
+    let idx: NoNull<IdxCa> = (1..n).map(|i| (i * n_samples / n) as IdxSize).collect();
+    Ok(sample.take(&idx.into_inner())?.unique()?.sort(false))
+}
+
+/// The first sort key with its values widened to `Int64`, `UInt64`, `Float64` or `Utf8`,
+/// or `None` if the partitions cannot be ordered by the values of the key, e.g. categoricals.
+fn partition_key(s: &Series) -> Result<Option<Series>> {
+    use DataType::*;
+    if !(s.dtype().is_numeric() || matches!(s.dtype(), Utf8 | Date | Datetime(_, _) | Duration(_)))
+    {
+        return Ok(None);
+    }
+    let s = s.to_physical_repr();
+    let dtype = match s.dtype() {
+        Float32 | Float64 => Float64,
+        UInt64 | Utf8 => return Ok(Some(s.into_owned())),
+        _ => Int64,
+    };
+    s.cast(&dtype).map(Some)
+}
+
+/// Collect the row indices of every partition: nulls are in partition `0`, the keys in
+/// `(splitters[i - 1], splitters[i]]` in partition `i + 1` and the larger keys and NaN in the
+/// last partition.
+fn partition_rows<T: PartialOrd>(
+    keys: impl Iterator<Item = Option<T>>,
+    splitters: &[T],
+) -> Vec<Vec<IdxSize>> {
+    let mut partitions = vec![vec![]; splitters.len() + 2];
+    for (idx, key) in keys.enumerate() {
+        let partition = match key {
+            None => 0,
+            Some(key) if key.partial_cmp(&key).is_none() => splitters.len() + 1,
+            Some(key) => splitters.partition_point(|s| *s < key) + 1,
+        };
+        partitions[partition].push(idx as IdxSize);
+    }
+    partitions
+}
+
+fn partition_indices(key: &Series, splitters: &Series) -> Result<Vec<Vec<IdxSize>>> {
+    macro_rules! partition {
+        ($method:ident) => {{
+            let splitters = splitters.$method()?.into_no_null_iter().collect::<Vec<_>>();
+            partition_rows(key.$method()?.into_iter(), &splitters)
+        }};
+    }
+    Ok(match key.dtype() {
+        DataType::Int64 => partition!(i64),
+        DataType::UInt64 => partition!(u64),
+        DataType::Float64 => partition!(f64),
+        _ => partition!(utf8),
+    })
+}
+
+pub(crate) fn sort_external_impl(
+    df: DataFrame,
+    by_column: Vec<Series>,
+    reverse: Vec<bool>,
+    nulls_last: bool,
+    options: &ExternalSortOptions,
+    verbose: bool,
+) -> Result<DataFrame> {
+    let n_partitions = estimated_sort_size(&df) / std::cmp::max(options.memory_budget, 1) + 1;
+    let key = match partition_key(&by_column[0])? {
+        Some(key) if n_partitions > 1 && df.height() > 1 => key,
+        _ => return df.sort_impl(by_column, reverse, nulls_last, NanPosition::Largest, None),
+    };
+    let splitters = splitters(&key, n_partitions)?;
+    if splitters.is_empty() {
+        return df.sort_impl(by_column, reverse, nulls_last, NanPosition::Largest, None);
+    }
+
+    // the partitions carry the sort keys, as these may be computed expressions
+    let key_names = (0..by_column.len())
+        .map(|i| format!("{}{}", SORT_KEY, i))
+        .collect::<Vec<_>>();
+    let mut df = df;
+    for (s, name) in by_column.into_iter().zip(&key_names) {
+        let mut s = s;
+        s.rename(name);
+        df.with_column(s)?;
+    }
+
+    let dir = SpillDir::new(&options.temp_dir.clone().unwrap_or_else(std::env::temp_dir))?;
+    if verbose {
+        eprintln!(
+            "external sort: spilling {} partitions to {:?}",
+            splitters.len() + 2,
+            dir.0
+        );
+    }
+    let mut partitions = partition_indices(&key, &splitters)?
+        .into_iter()
+        .enumerate()
+        .map(|(i, idx)| {
+            if idx.is_empty() {
+                Ok(None)
+            } else {
+                let mut part = df.take(&IdxCa::from_vec("", idx))?;
+                dir.write(i, &mut part).map(Some)
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    drop(key);
+    drop(df);
+    let nulls = partitions.remove(0);
+
+    if reverse[0] {
+        partitions.reverse();
+    }
+    // a single key can put the nulls last, multiple keys order them as the smallest value
+    let nulls_first = if key_names.len() == 1 {
+        !nulls_last
+    } else {
+        !reverse[0]
+    };
+    if nulls_first {
+        partitions.insert(0, nulls);
+    } else {
+        partitions.push(nulls);
+    }
+
+    let mut out: Option<DataFrame> = None;
+    for path in partitions.into_iter().flatten() {
+        let file = std::fs::File::open(&path)?;
+        let part = IpcReader::new(file).memory_mapped(false).finish()?;
+        let by_column = part.select_series(&key_names)?;
+        let mut sorted = part.sort_impl(
+            by_column,
+            reverse.clone(),
+            nulls_last,
+            NanPosition::Largest,
+            None,
+        )?;
+        drop(part);
+        std::fs::remove_file(&path)?;
+        for name in &key_names {
+            let _ = sorted.drop_in_place(name)?;
+        }
+        match out.as_mut() {
+            Some(out) => {
+                out.vstack_mut(&sorted)?;
+            }
+            None => out = Some(sorted),
+        }
+    }
+    Ok(out.expect("at least one partition is not empty"))
+}
+
+/// Sort `df` by the columns `by_column`, spilling to disk if the estimated memory usage of the sort
+/// exceeds [`ExternalSortOptions::memory_budget`]. The input is dropped once it is partitioned,
+/// so that the sort needs about the memory of the output and a single partition.
+///
+/// `nulls_last` only applies to a sort by a single column, like in [`DataFrame::sort_with_options`].
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_lazy::prelude::*;
+///
+/// fn sort_large(df: DataFrame) -> Result<DataFrame> {
+///     let options = ExternalSortOptions {
+///         memory_budget: 1 << 30,
+///         temp_dir: Some("/mnt/scratch".into()),
+///     };
+///     sort_external(df, ["a", "b"], vec![false, true], false, &options)
+/// }
+/// ```
+pub fn sort_external(
+    mut df: DataFrame,
+    by_column: impl IntoVec<String>,
+    reverse: impl IntoVec<bool>,
+    nulls_last: bool,
+    options: &ExternalSortOptions,
+) -> Result<DataFrame> {
+    let reverse = reverse.into_vec();
+    if estimated_sort_size(&df) > options.memory_budget {
+        let by_column = df.select_series(by_column)?;
+        let verbose = std::env::var("POLARS_VERBOSE").is_ok();
+        sort_external_impl(df, by_column, reverse, nulls_last, options, verbose)
+    } else {
+        df.as_single_chunk_par();
+        let by_column = df.select_series(by_column)?;
+        df.sort_impl(by_column, reverse, nulls_last, NanPosition::Largest, None)
+    }
+}
@@ -0,0 +1,32 @@
+use super::cancel::CancellationToken;
+use super::progress::{Progress, ProgressCallback};
+use polars_core::prelude::*;
+
+/// Hooks into the execution of a query, passed to
+/// [`collect_with_hooks`](crate::frame::LazyFrame::collect_with_hooks).
+#[derive(Clone, Default)]
+pub struct ExecutionHooks {
+    pub(crate) cancel_token: Option<CancellationToken>,
+    pub(crate) progress: Option<ProgressCallback>,
+}
+
+impl ExecutionHooks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Abort the query with an error once `token` is cancelled.
+    pub fn with_cancellation(mut self, token: &CancellationToken) -> Self {
+        self.cancel_token = Some(token.clone());
+        self
+    }
+
+    /// Call `callback` with the [`Progress`] of the query every time an operation is done.
+    pub fn with_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&Progress) + Send + Sync + 'static,
+    {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+}
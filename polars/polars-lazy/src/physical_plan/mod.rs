@@ -1,8 +1,14 @@
+pub mod cancel;
 pub mod executors;
 pub mod expressions;
+pub mod hooks;
+#[cfg(feature = "ipc")]
+pub mod external_sort;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
 mod file_cache;
 pub mod planner;
+pub mod progress;
+pub mod result_cache;
 pub(crate) mod state;
 
 use crate::physical_plan::state::ExecutionState;
@@ -5,6 +5,12 @@ use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::parallel_op_series;
 
+/// The key under which the result of a cached expression is stored. The input is part of
+/// the key, because expanding a wildcard gives a cached expression per column with the same id.
+pub(super) fn cache_key(id: usize, input: Node, expr_arena: &Arena<AExpr>) -> String {
+    format!("{}_{:?}", id, node_to_expr(input, expr_arena))
+}
+
 impl DefaultPlanner {
     pub fn create_physical_expr(
         &self,
@@ -615,10 +621,20 @@ impl DefaultPlanner {
                     inputs: vec![input],
                     function,
                     expr: node_to_expr(expression, expr_arena),
-                    collect_groups: ApplyOptions::ApplyFlat,
+                    // the lists of a group explode to the values of that group
+                    collect_groups: ApplyOptions::ApplyGroups,
                     auto_explode: false,
                 }))
             }
+            Cache { input, id } => {
+                let key = cache_key(id, input, expr_arena);
+                let input = self.create_physical_expr(input, ctxt, expr_arena)?;
+                Ok(Arc::new(phys_expr::CacheExpr::new(
+                    input,
+                    key,
+                    node_to_expr(expression, expr_arena),
+                )))
+            }
             Wildcard => panic!("should be no wildcard at this point"),
             Nth(_) => panic!("should be no nth at this point"),
         }
@@ -1,5 +1,6 @@
 use super::super::executors;
 use crate::prelude::*;
+use parking_lot::RwLock;
 use polars_core::prelude::*;
 use polars_io::aggregations::ScanAggregation;
 
@@ -48,7 +49,10 @@ fn aggregate_expr_to_scan_agg(
 }
 
 #[derive(Default)]
-pub struct DefaultPlanner {}
+pub struct DefaultPlanner {
+    /// A snapshot of the expressions of the plan, to describe a failing node only once it fails.
+    exprs: Arc<RwLock<Arena<AExpr>>>,
+}
 
 impl DefaultPlanner {
     pub fn create_physical_expressions(
@@ -62,11 +66,68 @@ impl DefaultPlanner {
             .map(|e| self.create_physical_expr(*e, context, expr_arena))
             .collect()
     }
+
+    /// Create the cached subexpressions of `exprs`, once per cache key, so that a projection
+    /// can evaluate them before it evaluates `exprs` in parallel. The subexpressions of
+    /// aggregations and window functions are skipped, these are evaluated per group.
+    fn create_cached_expressions(
+        &self,
+        exprs: &[Node],
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<Vec<Arc<dyn PhysicalExpr>>> {
+        let mut keys = PlHashSet::new();
+        let mut cached = vec![];
+        let mut stack = exprs.to_vec();
+        while let Some(node) = stack.pop() {
+            let e = expr_arena.get(node);
+            match e {
+                AExpr::Agg(_) | AExpr::Window { .. } => continue,
+                AExpr::Cache { id, input } => {
+                    if keys.insert(super::expr::cache_key(*id, *input, expr_arena)) {
+                        cached.push(node)
+                    }
+                }
+                _ => {}
+            }
+            e.nodes(&mut stack);
+        }
+        self.create_physical_expressions(&cached, Context::Default, expr_arena)
+    }
+
+    /// Create the executor of the plan at `root`.
     pub fn create_physical_plan(
         &self,
         root: Node,
         lp_arena: &mut Arena<ALogicalPlan>,
         expr_arena: &mut Arena<AExpr>,
+    ) -> Result<Box<dyn Executor>> {
+        *self.exprs.write() = expr_arena.clone();
+        self.create_plan_node(root, lp_arena, expr_arena)
+    }
+
+    /// Create the executor of the `root` node. Errors of the executor point to the node.
+    fn create_plan_node(
+        &self,
+        root: Node,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Result<Box<dyn Executor>> {
+        let node = lp_arena.get(root).clone();
+        let input = self
+            .create_executor(root, lp_arena, expr_arena)
+            .map_err(|e| e.with_plan_node(&node.describe_node(expr_arena)))?;
+        Ok(Box::new(executors::PlanNodeExec {
+            input,
+            node,
+            exprs: self.exprs.clone(),
+        }))
+    }
+
+    fn create_executor(
+        &self,
+        root: Node,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
     ) -> Result<Box<dyn Executor>> {
         use ALogicalPlan::*;
         let logical_plan = lp_arena.take(root);
@@ -76,20 +137,20 @@ impl DefaultPlanner {
             Union { inputs, options } => {
                 let inputs = inputs
                     .into_iter()
-                    .map(|node| self.create_physical_plan(node, lp_arena, expr_arena))
+                    .map(|node| self.create_plan_node(node, lp_arena, expr_arena))
                     .collect::<Result<Vec<_>>>()?;
                 Ok(Box::new(executors::UnionExec { inputs, options }))
             }
             Melt { input, args, .. } => {
-                let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = self.create_plan_node(input, lp_arena, expr_arena)?;
                 Ok(Box::new(executors::MeltExec { input, args }))
             }
             Slice { input, offset, len } => {
-                let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = self.create_plan_node(input, lp_arena, expr_arena)?;
                 Ok(Box::new(executors::SliceExec { input, offset, len }))
             }
             Selection { input, predicate } => {
-                let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = self.create_plan_node(input, lp_arena, expr_arena)?;
                 let predicate =
                     self.create_physical_expr(predicate, Context::Default, expr_arena)?;
                 Ok(Box::new(executors::FilterExec::new(predicate, input)))
@@ -163,12 +224,14 @@ impl DefaultPlanner {
             } => {
                 let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
                 let has_windows = expr.iter().any(|node| has_window_aexpr(*node, expr_arena));
-                let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = self.create_plan_node(input, lp_arena, expr_arena)?;
                 let phys_expr =
                     self.create_physical_expressions(&expr, Context::Default, expr_arena)?;
+                let cached = self.create_cached_expressions(&expr, expr_arena)?;
                 Ok(Box::new(executors::ProjectionExec {
                     input,
                     expr: phys_expr,
+                    cached,
                     has_windows,
                     input_schema,
                     #[cfg(test)]
@@ -185,12 +248,14 @@ impl DefaultPlanner {
                 let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
 
                 let has_windows = expr.iter().any(|node| has_window_aexpr(*node, expr_arena));
-                let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = self.create_plan_node(input, lp_arena, expr_arena)?;
                 let phys_expr =
                     self.create_physical_expressions(&expr, Context::Default, expr_arena)?;
+                let cached = self.create_cached_expressions(&expr, expr_arena)?;
                 Ok(Box::new(executors::ProjectionExec {
                     input,
                     expr: phys_expr,
+                    cached,
                     has_windows,
                     input_schema,
                     #[cfg(test)]
@@ -246,7 +311,7 @@ impl DefaultPlanner {
                 by_column,
                 args,
             } => {
-                let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = self.create_plan_node(input, lp_arena, expr_arena)?;
                 let by_column =
                     self.create_physical_expressions(&by_column, Context::Default, expr_arena)?;
                 Ok(Box::new(executors::SortExec {
@@ -255,9 +320,18 @@ impl DefaultPlanner {
                     args,
                 }))
             }
-            Explode { input, columns, .. } => {
-                let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
-                Ok(Box::new(executors::ExplodeExec { input, columns }))
+            Explode {
+                input,
+                columns,
+                options,
+                ..
+            } => {
+                let input = self.create_plan_node(input, lp_arena, expr_arena)?;
+                Ok(Box::new(executors::ExplodeExec {
+                    input,
+                    columns,
+                    options,
+                }))
             }
             Cache { input } => {
                 let schema = lp_arena.get(input).schema(lp_arena);
@@ -272,11 +346,11 @@ impl DefaultPlanner {
                 }
                 // mutable borrow otherwise
                 drop(unique);
-                let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = self.create_plan_node(input, lp_arena, expr_arena)?;
                 Ok(Box::new(executors::CacheExec { key, input }))
             }
             Distinct { input, options } => {
-                let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = self.create_plan_node(input, lp_arena, expr_arena)?;
                 Ok(Box::new(executors::DropDuplicatesExec { input, options }))
             }
             Aggregate {
@@ -289,7 +363,7 @@ impl DefaultPlanner {
                 options,
             } => {
                 let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
-                let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = self.create_plan_node(input, lp_arena, expr_arena)?;
 
                 let phys_keys =
                     self.create_physical_expressions(&keys, Context::Default, expr_arena)?;
@@ -479,8 +553,8 @@ impl DefaultPlanner {
                     false
                 };
 
-                let input_left = self.create_physical_plan(input_left, lp_arena, expr_arena)?;
-                let input_right = self.create_physical_plan(input_right, lp_arena, expr_arena)?;
+                let input_left = self.create_plan_node(input_left, lp_arena, expr_arena)?;
+                let input_right = self.create_plan_node(input_right, lp_arena, expr_arena)?;
                 let left_on =
                     self.create_physical_expressions(&left_on, Context::Default, expr_arena)?;
                 let right_on =
@@ -494,34 +568,38 @@ impl DefaultPlanner {
                     parallel,
                     options.suffix,
                     options.slice,
+                    options.validate,
+                    options.nulls_equal,
                 )))
             }
             HStack { input, exprs, .. } => {
                 let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
                 let has_windows = exprs.iter().any(|node| has_window_aexpr(*node, expr_arena));
-                let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = self.create_plan_node(input, lp_arena, expr_arena)?;
                 let phys_expr =
                     self.create_physical_expressions(&exprs, Context::Default, expr_arena)?;
+                let cached = self.create_cached_expressions(&exprs, expr_arena)?;
                 Ok(Box::new(executors::StackExec {
                     input,
                     has_windows,
                     expr: phys_expr,
+                    cached,
                     input_schema,
                 }))
             }
             Udf {
                 input, function, ..
             } => {
-                let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = self.create_plan_node(input, lp_arena, expr_arena)?;
                 Ok(Box::new(executors::UdfExec { input, function }))
             }
             ExtContext {
                 input, contexts, ..
             } => {
-                let input = self.create_physical_plan(input, lp_arena, expr_arena)?;
+                let input = self.create_plan_node(input, lp_arena, expr_arena)?;
                 let contexts = contexts
                     .into_iter()
-                    .map(|node| self.create_physical_plan(node, lp_arena, expr_arena))
+                    .map(|node| self.create_plan_node(node, lp_arena, expr_arena))
                     .collect::<Result<_>>()?;
                 Ok(Box::new(executors::ExternalContext { input, contexts }))
             }
@@ -0,0 +1,100 @@
+use parking_lot::Mutex;
+use polars_core::prelude::*;
+#[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
+use polars_io::progress::{ReadProgress, ReadProgressRef};
+
+/// The progress of a running query, passed to the hook of
+/// [`collect_with_progress`](crate::frame::LazyFrame::collect_with_progress).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    /// The number of rows the scans read so far, before their pushed down filters.
+    pub rows_scanned: usize,
+    /// The number of operations of the query that are done.
+    pub nodes_completed: usize,
+    /// The number of bytes the scans of files read so far. A parquet scan counts the compressed
+    /// column chunks it decodes, a csv scan the parsed bytes and an ipc scan the size of the
+    /// decoded record batches.
+    pub bytes_read: u64,
+}
+
+/// A hook that is called with the [`Progress`] every time an operation of the query is done
+/// and every time a scan of a file is done. It is called on the threads that execute the query,
+/// so it should return quickly.
+pub type ProgressCallback = Arc<dyn Fn(&Progress) + Send + Sync>;
+
+/// Sums the progress of all branches of a query.
+pub(crate) struct ProgressTracker {
+    progress: Mutex<Progress>,
+    callback: ProgressCallback,
+}
+
+impl ProgressTracker {
+    pub(crate) fn new(callback: ProgressCallback) -> Self {
+        Self {
+            progress: Default::default(),
+            callback,
+        }
+    }
+
+    fn update(&self, f: impl FnOnce(&mut Progress)) {
+        let progress = {
+            let mut progress = self.progress.lock();
+            f(&mut progress);
+            progress.clone()
+        };
+        // call without the lock, so that a slow hook doesn't block the other threads and a
+        // hook that reads the progress can't deadlock. Concurrent updates may therefore
+        // reach the hook out of order.
+        (self.callback)(&progress)
+    }
+
+    /// Register a completed operation.
+    pub(crate) fn node_completed(&self) {
+        self.update(|progress| progress.nodes_completed += 1)
+    }
+
+    /// Register the rows and bytes a scan read.
+    pub(crate) fn scanned(&self, rows: usize, bytes: u64) {
+        self.update(|progress| {
+            progress.rows_scanned += rows;
+            progress.bytes_read += bytes;
+        })
+    }
+}
+
+/// The progress of a single attempt of a read, added to the tracker once the attempt succeeded.
+#[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
+struct ReadAttempt {
+    read: Mutex<(usize, u64)>,
+}
+
+#[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
+impl ReadProgress for ReadAttempt {
+    fn rows_read(&self, rows: usize, bytes: u64) {
+        let mut read = self.read.lock();
+        read.0 += rows;
+        read.1 += bytes;
+    }
+}
+
+/// Run an attempt of a read that reports to `tracker`. A read that is retried would count the
+/// rows of its failed attempts again, so the progress of an attempt is buffered and only
+/// registered when the attempt succeeds.
+#[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
+pub(crate) fn read_attempt<T>(
+    tracker: Option<&Arc<ProgressTracker>>,
+    read: impl FnOnce(Option<ReadProgressRef>) -> Result<T>,
+) -> Result<T> {
+    match tracker {
+        None => read(None),
+        Some(tracker) => {
+            let attempt = Arc::new(ReadAttempt {
+                read: Default::default(),
+            });
+            let out = read(Some(attempt.clone()))?;
+            let (rows, bytes) = *attempt.read.lock();
+            tracker.scanned(rows, bytes);
+            Ok(out)
+        }
+    }
+}
@@ -0,0 +1,417 @@
+//! An optional in-process cache of query results.
+//!
+//! If enabled, [`LazyFrame::collect`](crate::frame::LazyFrame::collect) computes a key from
+//! the optimized logical plan and the modification times of the scanned files, and returns
+//! the cached `DataFrame` if an identical query was collected before.
+use crate::dsl::function_expr::FunctionExpr;
+use crate::logical_plan::FETCH_ROWS;
+use crate::prelude::*;
+use parking_lot::Mutex;
+use polars_core::export::once_cell::sync::Lazy;
+use polars_core::prelude::*;
+use std::collections::VecDeque;
+use std::fmt::Write;
+#[cfg(any(feature = "csv-file", feature = "parquet", feature = "ipc"))]
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+static USE_RESULT_CACHE: AtomicBool = AtomicBool::new(false);
+static RESULT_CACHE: Lazy<Mutex<ResultCache>> = Lazy::new(Default::default);
+static HITS: AtomicUsize = AtomicUsize::new(0);
+static MISSES: AtomicUsize = AtomicUsize::new(0);
+
+/// Limits of the query result cache.
+#[derive(Clone, Debug)]
+pub struct ResultCacheOptions {
+    /// Maximum number of cached results.
+    pub max_entries: usize,
+    /// Maximum (estimated) size of all cached results in bytes.
+    pub max_size: Option<usize>,
+    /// Time after which a cached result is invalidated.
+    pub ttl: Option<Duration>,
+}
+
+impl Default for ResultCacheOptions {
+    fn default() -> Self {
+        Self {
+            max_entries: 32,
+            max_size: None,
+            ttl: None,
+        }
+    }
+}
+
+/// Enable the query result cache with the given limits.
+///
+/// Only queries that are fully defined by their plan are cached. Queries that contain
+/// user defined functions (`map`, `apply`, random sampling, plugins, anonymous scans) are
+/// never cached, as they may give a different result on every run.
+pub fn enable_result_cache(options: ResultCacheOptions) {
+    let mut cache = RESULT_CACHE.lock();
+    cache.options = options;
+    cache.evict(0);
+    USE_RESULT_CACHE.store(true, Ordering::Release);
+}
+
+/// Disable the query result cache and free all cached results.
+pub fn disable_result_cache() {
+    USE_RESULT_CACHE.store(false, Ordering::Release);
+    clear_result_cache();
+}
+
+/// Free all cached results, but keep the cache enabled.
+pub fn clear_result_cache() {
+    let mut cache = RESULT_CACHE.lock();
+    cache.entries.clear();
+    cache.order.clear();
+    cache.size = 0;
+}
+
+/// Check if the query result cache is enabled.
+pub fn using_result_cache() -> bool {
+    USE_RESULT_CACHE.load(Ordering::Acquire)
+}
+
+/// Get the number of `(hits, misses)` of the query result cache.
+pub fn result_cache_stats() -> (usize, usize) {
+    (HITS.load(Ordering::Relaxed), MISSES.load(Ordering::Relaxed))
+}
+
+struct CacheEntry {
+    df: DataFrame,
+    size: usize,
+    created: Instant,
+    // the key contains the addresses of these, so we keep them alive
+    // to ensure that the addresses are not reused by different data.
+    _pinned_dfs: Vec<Arc<DataFrame>>,
+    _pinned_series: Vec<Series>,
+}
+
+#[derive(Default)]
+struct ResultCache {
+    options: ResultCacheOptions,
+    entries: PlHashMap<String, CacheEntry>,
+    // insertion order, used for eviction
+    order: VecDeque<String>,
+    size: usize,
+}
+
+impl ResultCache {
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.size -= entry.size;
+            self.order.retain(|k| k != key);
+        }
+    }
+
+    /// Evict the oldest entries until `additional_size` and one more entry fit.
+    fn evict(&mut self, additional_size: usize) {
+        while !self.order.is_empty()
+            && (self.order.len() >= self.options.max_entries
+                || matches!(self.options.max_size, Some(max_size) if self.size + additional_size > max_size))
+        {
+            let key = self.order.pop_front().unwrap();
+            if let Some(entry) = self.entries.remove(&key) {
+                self.size -= entry.size;
+            }
+        }
+    }
+
+    fn get(&mut self, key: &str) -> Option<DataFrame> {
+        let entry = self.entries.get(key)?;
+        if matches!(self.options.ttl, Some(ttl) if entry.created.elapsed() > ttl) {
+            self.remove(key);
+            return None;
+        }
+        Some(entry.df.clone())
+    }
+
+    fn insert(&mut self, key: PlanKey, df: &DataFrame) {
+        if self.options.max_entries == 0 {
+            return;
+        }
+        let size = df.estimated_size();
+        if matches!(self.options.max_size, Some(max_size) if size > max_size) {
+            return;
+        }
+        self.remove(&key.key);
+        self.evict(size);
+
+        self.size += size;
+        self.order.push_back(key.key.clone());
+        self.entries.insert(
+            key.key,
+            CacheEntry {
+                df: df.clone(),
+                size,
+                created: Instant::now(),
+                _pinned_dfs: key.pinned_dfs,
+                _pinned_series: key.pinned_series,
+            },
+        );
+    }
+}
+
+pub(crate) struct PlanKey {
+    key: String,
+    pinned_dfs: Vec<Arc<DataFrame>>,
+    pinned_series: Vec<Series>,
+}
+
+#[cfg(any(feature = "csv-file", feature = "parquet", feature = "ipc"))]
+fn write_file_state(key: &mut String, path: &Path) -> Option<()> {
+    let metadata = std::fs::metadata(path).ok()?;
+    let modified = metadata
+        .modified()
+        .ok()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?
+        .as_nanos();
+    write!(key, "FILE {:?} {} {};", path, modified, metadata.len()).ok()
+}
+
+/// Write a literal to the key with its type and its exact value. The `Debug` output of a
+/// literal is meant for display: it doesn't quote strings and drops the type of a range.
+fn write_literal(
+    key: &mut String,
+    lv: &LiteralValue,
+    pinned_series: &mut Vec<Series>,
+) -> Option<()> {
+    use LiteralValue::*;
+    match lv {
+        Null => write!(key, "NULL;"),
+        Boolean(v) => write!(key, "BOOL {};", v),
+        Utf8(v) => write!(key, "UTF8 {:?};", v),
+        #[cfg(feature = "dtype-u8")]
+        UInt8(v) => write!(key, "U8 {};", v),
+        #[cfg(feature = "dtype-u16")]
+        UInt16(v) => write!(key, "U16 {};", v),
+        UInt32(v) => write!(key, "U32 {};", v),
+        UInt64(v) => write!(key, "U64 {};", v),
+        #[cfg(feature = "dtype-i8")]
+        Int8(v) => write!(key, "I8 {};", v),
+        #[cfg(feature = "dtype-i16")]
+        Int16(v) => write!(key, "I16 {};", v),
+        Int32(v) => write!(key, "I32 {};", v),
+        Int64(v) => write!(key, "I64 {};", v),
+        Float32(v) => write!(key, "F32 {:x};", v.to_bits()),
+        Float64(v) => write!(key, "F64 {:x};", v.to_bits()),
+        Range {
+            low,
+            high,
+            data_type,
+        } => write!(key, "RANGE {} {} {:?};", low, high, data_type),
+        #[cfg(all(feature = "temporal", feature = "dtype-datetime"))]
+        DateTime(v, tu) => write!(key, "DATETIME {:?} {:?};", v, tu),
+        #[cfg(all(feature = "temporal", feature = "dtype-duration"))]
+        Duration(v, tu) => write!(key, "DURATION {:?} {:?};", v, tu),
+        Series(s) => {
+            let s: &Series = s;
+            pinned_series.push(s.clone());
+            write!(key, "SERIES {:p};", Arc::as_ptr(&s.0))
+        }
+        // an unbound parameter has no value yet
+        Parameter { .. } => return None,
+    }
+    .ok()
+}
+
+/// Expressions whose result may differ between runs on the same data.
+fn is_deterministic(e: &AExpr) -> bool {
+    // we cannot know what a udf or a plugin does
+    !matches!(
+        e,
+        AExpr::AnonymousFunction { .. }
+            | AExpr::Function {
+                function: FunctionExpr::Plugin(_),
+                ..
+            }
+    )
+}
+
+/// Create the key of an optimized plan.
+/// Returns `None` if the plan cannot be cached.
+fn plan_key(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<PlanKey> {
+    let mut key = String::with_capacity(512);
+    let mut pinned_dfs = vec![];
+    let mut pinned_series = vec![];
+    let mut exprs = Vec::with_capacity(16);
+
+    for (node, lp) in lp_arena.iter(root) {
+        use ALogicalPlan::*;
+        write!(key, "{}:", node.0).ok()?;
+        match lp {
+            // opaque functions, we cannot know if they produce the same result
+            AnonymousScan { .. } | Udf { .. } | Aggregate { apply: Some(_), .. } => return None,
+            #[cfg(feature = "python")]
+            PythonScan { .. } => return None,
+            DataFrameScan {
+                df,
+                schema,
+                projection,
+                selection,
+            } => {
+                write!(
+                    key,
+                    "DF {:p} {:?} {:?} {:?};",
+                    Arc::as_ptr(df),
+                    schema,
+                    projection,
+                    selection
+                )
+                .ok()?;
+                pinned_dfs.push(df.clone());
+            }
+            #[cfg(feature = "csv-file")]
+            CsvScan { path, .. } => {
+                write_file_state(&mut key, path)?;
+                write!(key, "{:?};", lp).ok()?
+            }
+            #[cfg(feature = "parquet")]
+            ParquetScan { path, .. } => {
+                write_file_state(&mut key, path)?;
+                write!(key, "{:?};", lp).ok()?
+            }
+            #[cfg(feature = "ipc")]
+            IpcScan { path, .. } => {
+                write_file_state(&mut key, path)?;
+                write!(key, "{:?};", lp).ok()?
+            }
+            _ => write!(key, "{:?};", lp).ok()?,
+        }
+
+        lp.copy_exprs(&mut exprs);
+        while let Some(expr) = exprs.pop() {
+            for (node, e) in expr_arena.iter(expr) {
+                if !is_deterministic(e) {
+                    return None;
+                }
+                write!(key, "{}:", node.0).ok()?;
+                match e {
+                    AExpr::Literal(lv) => write_literal(&mut key, lv, &mut pinned_series)?,
+                    // the other expressions only hold nodes and options, which are
+                    // written in full by their derived `Debug`
+                    _ => write!(key, "{:?};", e).ok()?,
+                }
+            }
+        }
+    }
+    Some(PlanKey {
+        key,
+        pinned_dfs,
+        pinned_series,
+    })
+}
+
+/// Get the cache key of a plan and a cached result if it exists.
+pub(crate) fn get_cached_result(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> (Option<PlanKey>, Option<DataFrame>) {
+    // a fetch influences the number of rows read by the scans.
+    if !using_result_cache() || FETCH_ROWS.with(|fetch_rows| fetch_rows.get().is_some()) {
+        return (None, None);
+    }
+    match plan_key(root, lp_arena, expr_arena) {
+        Some(key) => {
+            let out = RESULT_CACHE.lock().get(&key.key);
+            if out.is_some() {
+                HITS.fetch_add(1, Ordering::Relaxed);
+            } else {
+                MISSES.fetch_add(1, Ordering::Relaxed);
+            }
+            (Some(key), out)
+        }
+        None => (None, None),
+    }
+}
+
+pub(crate) fn insert_result(key: PlanKey, df: &DataFrame) {
+    if using_result_cache() {
+        RESULT_CACHE.lock().insert(key, df)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::{fruits_cars, SINGLE_LOCK};
+
+    #[test]
+    fn test_result_cache() -> Result<()> {
+        // the cache is global, don't let it serve the queries of the other tests
+        let _guard = SINGLE_LOCK.lock().unwrap();
+        enable_result_cache(Default::default());
+
+        let df = fruits_cars();
+        let q = df
+            .lazy()
+            .filter(col("A").gt(lit(1)))
+            .select([col("B").sum()]);
+        let out = q.clone().collect()?;
+        let (hits, _) = result_cache_stats();
+        let cached = q.clone().collect()?;
+        assert!(result_cache_stats().0 > hits);
+        assert!(out.frame_equal(&cached));
+
+        // udfs are never cached
+        let mut expr_arena = Arena::with_capacity(16);
+        let mut lp_arena = Arena::with_capacity(16);
+        let lp_top = q
+            .map(Ok, None, None, None)
+            .optimize(&mut lp_arena, &mut expr_arena)?;
+        assert!(plan_key(lp_top, &lp_arena, &expr_arena).is_none());
+
+        // literals that display the same are different keys
+        let key = |e: Expr| {
+            let mut expr_arena = Arena::with_capacity(16);
+            let mut lp_arena = Arena::with_capacity(16);
+            let lp_top = df
+                .clone()
+                .lazy()
+                .select([e])
+                .optimize(&mut lp_arena, &mut expr_arena)
+                .unwrap();
+            plan_key(lp_top, &lp_arena, &expr_arena).unwrap().key
+        };
+        assert_ne!(key(lit(1i32).alias("a")), key(lit("1i32").alias("a")));
+
+        disable_result_cache();
+        Ok(())
+    }
+
+    #[test]
+    fn test_result_cache_eviction() {
+        let mut cache = ResultCache {
+            options: ResultCacheOptions {
+                max_entries: 2,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let df = fruits_cars();
+        for key in ["a", "b", "c"] {
+            let key = PlanKey {
+                key: key.to_string(),
+                pinned_dfs: vec![],
+                pinned_series: vec![],
+            };
+            cache.insert(key, &df);
+        }
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("b").is_some());
+        assert!(cache.get("c").is_some());
+        assert_eq!(cache.size, 2 * df.estimated_size());
+
+        cache.options.ttl = Some(Duration::from_secs(0));
+        std::thread::sleep(Duration::from_millis(1));
+        assert!(cache.get("c").is_none());
+        assert_eq!(cache.entries.len(), 1);
+    }
+}
@@ -1,15 +1,22 @@
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv-file"))]
 use super::file_cache::FileCache;
+use crate::physical_plan::cancel::CancellationToken;
+use crate::physical_plan::progress::ProgressTracker;
 #[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
 use crate::prelude::file_caching::FileFingerPrint;
+use crate::prelude::OptState;
 use bitflags::bitflags;
 use parking_lot::Mutex;
 use polars_core::frame::groupby::GroupsProxy;
 use polars_core::frame::hash_join::JoinOptIds;
 use polars_core::prelude::*;
+use polars_io::retry::RetryPolicy;
 
 pub type JoinTuplesCache = Arc<Mutex<PlHashMap<String, JoinOptIds>>>;
 pub type GroupsProxyCache = Arc<Mutex<PlHashMap<String, GroupsProxy>>>;
+pub type WindowKeysCache = Arc<Mutex<PlHashMap<String, Vec<Series>>>>;
+/// The results of cached expressions by their key, with the `DataFrame` they are evaluated on.
+pub type ExprResultsCache = Arc<Mutex<PlHashMap<String, (DataFrame, Series)>>>;
 
 bitflags! {
     pub(super) struct StateFlags: u8 {
@@ -48,10 +55,28 @@ pub struct ExecutionState {
     pub(super) group_tuples: GroupsProxyCache,
     /// Used by Window Expression to prevent redundant joins
     pub(super) join_tuples: JoinTuplesCache,
+    /// Used by Window Expression to evaluate the (computed) partition keys once
+    pub(super) window_keys: WindowKeysCache,
+    /// Used by cached expressions to evaluate them once per `DataFrame`
+    pub(super) expr_results: ExprResultsCache,
     // every join/union split gets an increment to distinguish between schema state
     pub(super) branch_idx: usize,
     pub(super) flags: StateFlags,
     pub(super) ext_contexts: Arc<Vec<DataFrame>>,
+    /// Rechunk the input of pipeline breaking nodes with more chunks than this.
+    pub(crate) rechunk_threshold: Option<usize>,
+    /// Prefer readers and algorithms that keep the peak memory low over raw speed.
+    pub(crate) low_memory: bool,
+    /// Retries the reads of the scans that fail on a transient io error.
+    pub(crate) retry_policy: RetryPolicy,
+    /// Use order-stable algorithms.
+    pub(crate) deterministic: bool,
+    /// Sort on disk above this memory budget in bytes.
+    pub(crate) external_sort_budget: Option<usize>,
+    /// Aborts the query once it is cancelled.
+    pub(crate) cancel_token: Option<CancellationToken>,
+    /// Reports the progress of the query.
+    pub(crate) progress: Option<Arc<ProgressTracker>>,
 }
 
 impl ExecutionState {
@@ -64,12 +89,30 @@ impl ExecutionState {
             schema_cache: Default::default(),
             group_tuples: Default::default(),
             join_tuples: Default::default(),
+            window_keys: Default::default(),
+            // the results are only shared when evaluated on the same `DataFrame`
+            expr_results: self.expr_results.clone(),
             branch_idx: self.branch_idx,
             flags: self.flags,
             ext_contexts: self.ext_contexts.clone(),
+            rechunk_threshold: self.rechunk_threshold,
+            low_memory: self.low_memory,
+            retry_policy: self.retry_policy,
+            deterministic: self.deterministic,
+            external_sort_budget: self.external_sort_budget,
+            cancel_token: self.cancel_token.clone(),
+            progress: self.progress.clone(),
         }
     }
 
+    /// Split the state for a branch that runs on another `DataFrame`, e.g. an input of a join or
+    /// a union. The branch gets its own results of the cached expressions.
+    pub(super) fn split_branch(&self) -> Self {
+        let mut state = self.split();
+        state.expr_results = Default::default();
+        state
+    }
+
     #[cfg(not(any(feature = "parquet", feature = "csv-file", feature = "ipc")))]
     pub(crate) fn with_finger_prints(finger_prints: Option<usize>) -> Self {
         Self::new()
@@ -83,9 +126,18 @@ impl ExecutionState {
             file_cache: FileCache::new(finger_prints),
             group_tuples: Arc::new(Mutex::new(PlHashMap::default())),
             join_tuples: Arc::new(Mutex::new(PlHashMap::default())),
+            window_keys: Arc::new(Mutex::new(PlHashMap::default())),
+            expr_results: Default::default(),
             branch_idx: 0,
             flags: StateFlags::init(),
             ext_contexts: Default::default(),
+            rechunk_threshold: None,
+            low_memory: false,
+            retry_policy: Default::default(),
+            deterministic: false,
+            external_sort_budget: None,
+            cancel_token: None,
+            progress: None,
         }
     }
 
@@ -102,11 +154,39 @@ impl ExecutionState {
             file_cache: FileCache::new(None),
             group_tuples: Arc::new(Mutex::new(PlHashMap::default())),
             join_tuples: Arc::new(Mutex::new(PlHashMap::default())),
+            window_keys: Arc::new(Mutex::new(PlHashMap::default())),
+            expr_results: Default::default(),
             branch_idx: 0,
             flags: StateFlags::init(),
             ext_contexts: Default::default(),
+            rechunk_threshold: None,
+            low_memory: false,
+            retry_policy: Default::default(),
+            deterministic: false,
+            external_sort_budget: None,
+            cancel_token: None,
+            progress: None,
         }
     }
+
+    /// Report the rows that a scan of an in-memory source read.
+    pub(crate) fn scanned(&self, rows: usize) {
+        if let Some(progress) = &self.progress {
+            progress.scanned(rows, 0)
+        }
+    }
+
+    /// Apply the settings of a query that are not optimizations.
+    pub(crate) fn set_options(&mut self, opt_state: &OptState) {
+        self.rechunk_threshold = opt_state.rechunk_threshold;
+        self.low_memory = opt_state.low_memory;
+        self.deterministic = opt_state
+            .deterministic
+            .unwrap_or_else(polars_core::config::deterministic);
+        self.external_sort_budget = opt_state.external_sort_budget;
+        self.retry_policy = opt_state.retry_policy.unwrap_or_default();
+    }
+
     pub(crate) fn set_schema(&mut self, schema: SchemaRef) {
         self.schema_cache = Some(schema);
     }
@@ -147,17 +227,62 @@ impl ExecutionState {
             let mut lock = self.group_tuples.lock();
             lock.clear();
         }
-        let mut lock = self.join_tuples.lock();
+        {
+            let mut lock = self.join_tuples.lock();
+            lock.clear();
+        }
+        let mut lock = self.window_keys.lock();
         lock.clear();
     }
 
+    /// Get the result of the cached expression `key` if it is evaluated on `df`.
+    pub(super) fn cached_expr(&self, key: &str, df: &DataFrame) -> Option<Series> {
+        let guard = self.expr_results.lock();
+        match guard.get(key) {
+            Some((cached_df, s)) if same_frame(cached_df, df) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    /// Store the result of the cached expression `key` evaluated on `df`.
+    pub(super) fn store_cached_expr(&self, key: &str, df: &DataFrame, s: Series) {
+        let mut guard = self.expr_results.lock();
+        guard.insert(key.to_string(), (df.clone(), s));
+    }
+
+    /// Clear the results of the cached expressions. Typically at the end of every executor that
+    /// evaluates expressions.
+    pub(crate) fn clear_cached_exprs(&self) {
+        self.expr_results.lock().clear();
+    }
+
     pub(super) fn cache_window(&self) -> bool {
         self.flags.contains(StateFlags::CACHE_WINDOW_EXPR)
     }
 
-    pub(super) fn verbose(&self) -> bool {
+    pub(crate) fn verbose(&self) -> bool {
         self.flags.contains(StateFlags::VERBOSE)
     }
+
+    /// Error if the query is cancelled.
+    pub(crate) fn check_cancelled(&self) -> Result<()> {
+        match &self.cancel_token {
+            Some(token) => token.check(),
+            None => Ok(()),
+        }
+    }
+
+    /// Rechunk the input of a pipeline breaking node if it is fragmented.
+    pub(crate) fn rechunk_fragmented(&self, df: &mut DataFrame) {
+        if let Some(threshold) = self.rechunk_threshold {
+            let max_chunks = self.verbose().then(|| df.chunk_stats().max_chunks);
+            if df.rechunk_if_fragmented(threshold) {
+                if let Some(max_chunks) = max_chunks {
+                    eprintln!("rechunked input of {} chunks", max_chunks)
+                }
+            }
+        }
+    }
 }
 
 impl Default for ExecutionState {
@@ -165,3 +290,14 @@ impl Default for ExecutionState {
         ExecutionState::new()
     }
 }
+
+/// Whether both `DataFrame`s hold the same columns. Cloned `DataFrame`s share their columns,
+/// so this is cheap and holds for the input of a single operation.
+fn same_frame(a: &DataFrame, b: &DataFrame) -> bool {
+    a.height() == b.height()
+        && a.width() == b.width()
+        && a.get_columns()
+            .iter()
+            .zip(b.get_columns())
+            .all(|(a, b)| Arc::as_ptr(&a.0) as *const u8 == Arc::as_ptr(&b.0) as *const u8)
+}
@@ -35,7 +35,21 @@ pub use crate::{
         options::*,
         *,
     },
-    physical_plan::{expressions::*, planner::DefaultPlanner, Executor, PhysicalPlanner},
+    physical_plan::{
+        cancel::CancellationToken,
+        expressions::*,
+        hooks::ExecutionHooks,
+        planner::DefaultPlanner,
+        progress::{Progress, ProgressCallback},
+        result_cache::*,
+        Executor, PhysicalPlanner,
+    },
+};
+
+#[cfg(feature = "ipc")]
+pub use crate::physical_plan::external_sort::{
+    disable_external_sort, enable_external_sort, sort_external, using_external_sort,
+    ExternalSortOptions,
 };
 
 pub(crate) use crate::{
@@ -558,3 +558,220 @@ fn test_take_in_groups() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_null_on_null() -> Result<()> {
+    let df = df![
+        "g" => [1, 1, 2, 2],
+        "a" => [Some(1.0), None, Some(3.0), Some(5.0)]
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("a").sum().alias("sum"),
+            col("a").sum().null_on_null().alias("null_sum"),
+            col("a").std(0).alias("std"),
+            col("a").max().null_on_null().over([col("g")]).alias("max"),
+            (col("a") * lit(2.0)).null_on_null().alias("elementwise"),
+        ])
+        .collect()?;
+    assert_eq!(out.column("sum")?.f64()?.get(0), Some(9.0));
+    assert_eq!(out.column("null_sum")?.f64()?.get(0), None);
+    assert_eq!(
+        out.column("std")?.f64()?.get(0),
+        Some((8.0f64 / 3.0).sqrt())
+    );
+    assert_eq!(
+        Vec::from(out.column("max")?.f64()?),
+        &[None, None, Some(5.0), Some(5.0)]
+    );
+    assert_eq!(out.column("elementwise")?.null_count(), 1);
+
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([
+            col("a").var(1).alias("var"),
+            col("a").var(1).null_on_null().alias("null_var"),
+            col("a")
+                .sum()
+                .alias("wrapped")
+                .cast(DataType::Int64)
+                .null_on_null(),
+        ])
+        .collect()?;
+    // the first group has a single valid value
+    assert!(out.column("var")?.f64()?.get(0).unwrap().is_nan());
+    assert_eq!(
+        Vec::from(out.column("null_var")?.f64()?),
+        &[None, Some(2.0)]
+    );
+    assert_eq!(Vec::from(out.column("wrapped")?.i64()?), &[None, Some(8)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "dtype-categorical"))]
+fn test_logical_aggregations() -> Result<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2],
+        "days" => [1, 2, 6, 10],
+        "s" => ["a", "b", "a", "c"],
+    ]?
+    .lazy()
+    .with_columns([
+        col("days").cast(DataType::Date).alias("date"),
+        col("days")
+            .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+            .alias("datetime"),
+        col("s").cast(DataType::Categorical(None)).alias("cat"),
+    ])
+    .collect()?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([
+            col("date").median().alias("date_median"),
+            col("date")
+                .quantile(1.0, QuantileInterpolOptions::Nearest)
+                .alias("date_quantile"),
+            col("datetime").median().alias("datetime_median"),
+            col("cat").n_unique().alias("cat_n_unique"),
+        ])
+        .collect()?;
+    assert_eq!(out.column("date_median")?.dtype(), &DataType::Date);
+    assert_eq!(
+        Vec::from(out.column("date_median")?.to_physical_repr().i32()?),
+        &[Some(2), Some(10)]
+    );
+    assert_eq!(
+        Vec::from(out.column("date_quantile")?.to_physical_repr().i32()?),
+        &[Some(6), Some(10)]
+    );
+    assert_eq!(
+        Vec::from(out.column("datetime_median")?.to_physical_repr().i64()?),
+        &[Some(2), Some(10)]
+    );
+    assert_eq!(
+        Vec::from(out.column("cat_n_unique")?.idx()?),
+        &[Some(2), Some(1)]
+    );
+
+    // these aggregations run on the partitioned groupby
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([
+            col("cat").first().alias("cat_first"),
+            col("cat").last().alias("cat_last"),
+        ])
+        .collect()?;
+    let cat = out.column("cat_first")?.cast(&DataType::Utf8)?;
+    assert_eq!(Vec::from(cat.utf8()?), &[Some("a"), Some("c")]);
+    let cat = out.column("cat_last")?.cast(&DataType::Utf8)?;
+    assert_eq!(Vec::from(cat.utf8()?), &[Some("a"), Some("c")]);
+
+    let out = df
+        .lazy()
+        .select([
+            col("date").median().alias("date_median"),
+            col("datetime")
+                .quantile(0.0, QuantileInterpolOptions::Nearest)
+                .alias("datetime_quantile"),
+        ])
+        .collect()?;
+    assert_eq!(out.column("date_median")?.date()?.get(0), Some(4));
+    assert_eq!(
+        out.column("datetime_quantile")?.dtype(),
+        &DataType::Datetime(TimeUnit::Milliseconds, None)
+    );
+    assert_eq!(out.column("datetime_quantile")?.datetime()?.get(0), Some(1));
+    Ok(())
+}
+
+#[test]
+fn test_masked_aggregations() -> Result<()> {
+    // the groups are not in the order of the rows
+    let df = df![
+        "g" => ["a", "a", "b", "b", "c", "a"],
+        "x" => [1, 2, 3, 4, 5, 6],
+        "y" => [Some(1), None, Some(-1), Some(1), Some(-1), Some(1)]
+    ]?;
+
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([
+            col("x").filter(col("y").gt(lit(0))).sum().alias("masked"),
+            col("x").filter(col("y").gt(lit(0))).count().alias("count"),
+            // predicates that depend on the group
+            col("x")
+                .filter(col("x").gt(col("x").mean()))
+                .sum()
+                .alias("above_mean"),
+            col("x")
+                .filter(col("x").sum().gt(lit(5)))
+                .list()
+                .alias("large_groups"),
+            col("x")
+                .sort(true)
+                .filter(col("y").gt(lit(0)))
+                .first()
+                .alias("sorted"),
+            // the input is aggregated already
+            col("x")
+                .sum()
+                .filter(col("x").sum().gt(lit(5)))
+                .first()
+                .alias("aggregated"),
+            col("x")
+                .unique_stable()
+                .filter(col("y").gt(lit(0)))
+                .list()
+                .alias("unique"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("masked")?.i32()?),
+        &[Some(7), Some(4), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("count")?.idx()?),
+        &[Some(2), Some(1), Some(0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("above_mean")?.i32()?),
+        &[Some(6), Some(4), None]
+    );
+    assert_eq!(
+        out.column("large_groups")?
+            .list()?
+            .lst_lengths()
+            .into_iter()
+            .collect::<Vec<_>>(),
+        &[Some(3), Some(2), Some(0)]
+    );
+    // the predicate is applied in the order of the sorted values
+    assert_eq!(
+        Vec::from(out.column("sorted")?.i32()?),
+        &[Some(6), Some(4), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("aggregated")?.i32()?),
+        &[Some(9), Some(7), None]
+    );
+    assert_eq!(
+        out.column("unique")?
+            .list()?
+            .lst_lengths()
+            .into_iter()
+            .collect::<Vec<_>>(),
+        &[Some(2), Some(1), Some(0)]
+    );
+    Ok(())
+}
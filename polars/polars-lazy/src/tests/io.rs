@@ -239,6 +239,43 @@ fn test_csv_globbing() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_fetch_multiple_files() -> Result<()> {
+    let glob = "../../examples/datasets/*.csv";
+    let full_df = LazyCsvReader::new(glob.into()).finish()?.collect()?;
+
+    // every file has 27 rows, the fetch takes the first rows of the first files
+    for n in [10, 40] {
+        let df = LazyCsvReader::new(glob.into()).finish()?.fetch(n)?;
+        assert!(df.frame_equal(&full_df.head(Some(n))));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_deterministic_scan_order() -> Result<()> {
+    // the files and the chunks of the parallel reader are concatenated in order
+    let mut expected = DataFrame::default();
+    for i in 1..=5 {
+        let path = format!("../../examples/datasets/foods{}.csv", i);
+        let df = CsvReader::from_path(path)?
+            .with_n_threads(Some(4))
+            .finish()?;
+        expected = if i == 1 { df } else { expected.vstack(&df)? };
+    }
+    let glob = "../../examples/datasets/foods*.csv";
+    for _ in 0..3 {
+        let df = LazyCsvReader::new(glob.into())
+            .finish()?
+            .with_deterministic(true)
+            .collect()?;
+        assert!(df.frame_equal(&expected));
+    }
+    Ok(())
+}
+
 #[test]
 pub fn test_simple_slice() -> Result<()> {
     let _guard = SINGLE_LOCK.lock().unwrap();
@@ -397,6 +434,97 @@ fn scan_predicate_on_set_null_values() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn scan_csv_predicate_on_subset_of_columns() -> Result<()> {
+    let lf = LazyCsvReader::new(FOODS_CSV.into())
+        .with_row_count(Some(RowCount {
+            name: "rc".into(),
+            offset: 0,
+        }))
+        .finish()?;
+    let predicate = col("category").eq(lit("fruit"));
+
+    let out = lf.clone().filter(predicate.clone()).collect()?;
+    let expected = lf.clone().collect()?.lazy().filter(predicate).collect()?;
+    assert!(out.frame_equal(&expected));
+    assert_eq!(out.shape(), (7, 5));
+
+    // no line passes the predicate
+    let out = lf
+        .select([col("fats_g"), col("category")])
+        .filter(col("fats_g").gt(lit(100)))
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["fats_g", "category"]);
+    assert_eq!(out.height(), 0);
+    Ok(())
+}
+
+#[test]
+fn scan_csv_reject_ragged_lines() -> Result<()> {
+    // the rejected lines cannot be returned from a scan
+    let out = LazyCsvReader::new(FOODS_CSV.to_string())
+        .with_ragged_lines(RaggedLines::Reject)
+        .finish();
+    assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+    Ok(())
+}
+
+#[test]
+fn scan_with_retry_policy() -> Result<()> {
+    let path = std::env::temp_dir().join("polars_test_retry_policy.csv");
+    std::fs::write(&path, "a,b\n1,x\n2,y\n")?;
+    let policy = RetryPolicy::default()
+        .with_max_retries(1)
+        .with_retry_budget(Some(std::time::Duration::from_secs(1)));
+    let q = LazyCsvReader::new(path.to_string_lossy().into_owned())
+        .finish()?
+        .with_retry_policy(Some(policy))
+        .without_optimizations();
+    // the policy is not an optimization
+    assert_eq!(q.opt_state.retry_policy, Some(policy));
+    let out = q.clone().collect()?;
+    assert_eq!(out.shape(), (2, 2));
+
+    // a read that fails on an error that is not transient is not retried
+    std::fs::remove_file(&path)?;
+    let err = q.collect().unwrap_err();
+    assert!(
+        matches!(err.root(), PolarsError::Io(err) if err.kind() == std::io::ErrorKind::NotFound)
+    );
+    Ok(())
+}
+
+#[test]
+fn scan_progress() -> Result<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    init_files();
+    for lf in [
+        scan_foods_csv(),
+        scan_foods_ipc(),
+        scan_foods_parquet(false),
+        scan_foods_parquet(true),
+    ] {
+        let reports = Arc::new(Mutex::new(vec![]));
+        let reports_hook = reports.clone();
+        let out = lf
+            .filter(col("calories").gt(lit(100)))
+            .collect_with_progress(move |progress| {
+                reports_hook.lock().unwrap().push(progress.clone())
+            })?;
+        assert_eq!(out.height(), 11);
+
+        let reports = reports.lock().unwrap();
+        // the readers report while they read, before the scan is done
+        assert!(reports[0].rows_scanned > 0);
+        assert_eq!(reports[0].nodes_completed, 0);
+        // and count the rows before the filter that is pushed down into them
+        let last = reports.last().unwrap();
+        assert_eq!(last.rows_scanned, 27);
+        assert!(last.bytes_read > 0);
+    }
+    Ok(())
+}
+
 #[test]
 fn scan_anonymous_fn() -> Result<()> {
     let function = Arc::new(|_scan_opts: AnonymousScanOptions| Ok(fruits_cars()));
@@ -411,3 +539,170 @@ fn scan_anonymous_fn() -> Result<()> {
     assert_eq!(df.shape(), (5, 4));
     Ok(())
 }
+
+#[test]
+fn test_checkpoint() -> Result<()> {
+    for ext in ["ipc", "parquet"] {
+        let path = std::env::temp_dir().join(format!("polars_test_checkpoint.{}", ext));
+        let _ = std::fs::remove_file(&path);
+
+        let q = fruits_cars()
+            .lazy()
+            .filter(col("A").gt(lit(2)))
+            .checkpoint(&path)?;
+        assert!(!path.exists());
+        let out = q.clone().select([col("A")]).collect()?;
+        assert_eq!(out.shape(), (3, 1));
+        assert!(path.exists());
+
+        // the checkpoint is used and the upstream query is not recomputed
+        let out = q.collect()?;
+        assert_eq!(out.shape(), (3, 4));
+        let q = fruits_cars()
+            .lazy()
+            .filter(col("A").gt(lit(4)))
+            .checkpoint(&path)?;
+        assert_eq!(q.collect()?.shape(), (3, 4));
+        std::fs::remove_file(&path)?;
+
+        // a fetch writes the full result to the checkpoint
+        let q = fruits_cars()
+            .lazy()
+            .filter(col("A").gt(lit(2)))
+            .checkpoint(&path)?;
+        assert_eq!(q.clone().fetch(1)?.shape(), (1, 4));
+        assert_eq!(q.collect()?.shape(), (3, 4));
+        std::fs::remove_file(&path)?;
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_external_sort() -> Result<()> {
+    let n = 1000;
+    let df = df![
+        "int" => (0..n).map(|i| if i % 7 == 0 { None } else { Some((i * 37) % 101) }).collect::<Vec<_>>(),
+        "flt" => (0..n).map(|i| if i % 11 == 0 { None } else { Some(((i * 13) % 17) as f64) }).collect::<Vec<_>>(),
+        "str" => (0..n).map(|i| format!("s{}", (i * 7) % 53)).collect::<Vec<_>>(),
+        "idx" => (0..n).collect::<Vec<_>>()
+    ]?;
+    let options = ExternalSortOptions {
+        memory_budget: df.estimated_size() / 4,
+        temp_dir: None,
+    };
+
+    for (by, reverse) in [
+        (vec!["int", "idx"], vec![false, false]),
+        (vec!["int", "idx"], vec![true, false]),
+        (vec!["flt", "idx"], vec![false, true]),
+        (vec!["str", "int", "idx"], vec![true, false, false]),
+    ] {
+        let expected = df.sort(by.clone(), reverse.clone())?;
+        let out = sort_external(df.clone(), by, reverse, false, &options)?;
+        assert!(out.column("idx")?.series_equal(expected.column("idx")?));
+    }
+    let expected = df.sort_with_options(
+        "flt",
+        SortOptions {
+            nulls_last: true,
+            ..Default::default()
+        },
+    )?;
+    let out = sort_external(df.clone(), ["flt"], false, true, &options)?;
+    assert!(out
+        .column("flt")?
+        .series_equal_missing(expected.column("flt")?));
+
+    // the sorts of a query spill to disk within its memory budget
+    let q = df
+        .clone()
+        .lazy()
+        .sort(
+            "int",
+            SortOptions {
+                descending: true,
+                nulls_last: true,
+                ..Default::default()
+            },
+        )
+        .select([col("int")]);
+    let expected = q.clone().collect()?;
+    let q = q.with_external_sort_budget(Some(options.memory_budget));
+    assert!(q.clone().collect()?.frame_equal_missing(&expected));
+    // the sort arguments survive an execution
+    assert!(q.collect()?.frame_equal_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_low_memory() -> Result<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let q = |low_memory: bool| {
+        scan_foods_parquet(true)
+            .with_low_memory(low_memory)
+            .groupby_stable([col("category")])
+            .agg([col("calories").sum()])
+            .join(
+                scan_foods_csv().select([col("category"), col("fats_g")]),
+                [col("category")],
+                [col("category")],
+                JoinType::Inner,
+            )
+            .collect()
+    };
+    let expected = q(false)?;
+    let out = q(true)?;
+    assert_eq!(out.shape(), (27, 3));
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_cancelled_readers() -> Result<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    init_files();
+    let token = CancellationToken::new();
+    let csv = CsvReader::from_path(FOODS_CSV)?.with_cancellation(Some(token.clone()));
+    let parquet = ParquetReader::new(std::fs::File::open(FOODS_PARQUET)?)
+        .with_cancellation(Some(token.clone()));
+    token.cancel();
+    assert_eq!(csv.finish().unwrap_err().code(), ErrorCode::Cancelled);
+    assert_eq!(parquet.finish().unwrap_err().code(), ErrorCode::Cancelled);
+    Ok(())
+}
+
+#[test]
+fn test_retried_read_progress() -> Result<()> {
+    use crate::physical_plan::progress::{read_attempt, ProgressTracker};
+    use std::time::Duration;
+
+    let reports = Arc::new(Mutex::new(vec![]));
+    let reports_hook = reports.clone();
+    let tracker = Arc::new(ProgressTracker::new(Arc::new(
+        move |progress: &Progress| reports_hook.lock().unwrap().push(progress.clone()),
+    )));
+    let mut attempts = 0;
+    let policy = RetryPolicy::default()
+        .with_max_retries(2)
+        .with_backoff(Duration::ZERO, Duration::ZERO);
+    let out = policy.retry(|| {
+        read_attempt(Some(&tracker), |progress| {
+            let progress = progress.unwrap();
+            progress.rows_read(10, 100);
+            attempts += 1;
+            if attempts == 1 {
+                Err(PolarsError::Io(std::io::ErrorKind::Interrupted.into()))
+            } else {
+                Ok(attempts)
+            }
+        })
+    })?;
+    assert_eq!(out, 2);
+    // only the successful attempt is counted
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].rows_scanned, 10);
+    assert_eq!(reports[0].bytes_read, 100);
+    Ok(())
+}
@@ -49,3 +49,42 @@ fn test_duration() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "dtype-struct", feature = "dtype-datetime"))]
+fn test_dt_components() -> Result<()> {
+    let df = df![
+        "date" => [1, 19_000]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("date")
+                .cast(DataType::Date)
+                .dt()
+                .components()
+                .alias("date"),
+            col("date")
+                .cast(DataType::Date)
+                .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+                .dt()
+                .components()
+                .alias("datetime"),
+        ])
+        .unnest(["date"])
+        .collect()?;
+
+    assert_eq!(
+        out.get_column_names(),
+        &["year", "month", "day", "datetime"]
+    );
+    assert_eq!(
+        Vec::from(out.column("year")?.i32()?),
+        &[Some(1970), Some(2022)]
+    );
+    assert_eq!(Vec::from(out.column("day")?.u32()?), &[Some(2), Some(8)]);
+    let datetime = out.column("datetime")?;
+    assert_eq!(datetime.struct_()?.fields().len(), 7);
+    Ok(())
+}
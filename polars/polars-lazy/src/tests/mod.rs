@@ -36,6 +36,7 @@ use crate::dsl::{argsort_by, pearson_corr};
 use crate::logical_plan::iterator::ArenaLpIter;
 use crate::logical_plan::optimizer::simplify_expr::SimplifyExprRule;
 use crate::logical_plan::optimizer::stack_opt::{OptimizationRule, StackOptimizer};
+use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_core::chunked_array::builder::get_list_builder;
 use polars_core::df;
@@ -425,3 +425,29 @@ fn test_groupby_ternary_literal_predicate() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_slice_pushdown_into_union_inputs() -> Result<()> {
+    let lfs = (1..=3)
+        .map(|i| LazyCsvReader::new(format!("../../examples/datasets/foods{}.csv", i)).finish())
+        .collect::<Result<Vec<_>>>()?;
+    let full = concat(&lfs, false)?.collect()?;
+
+    for rechunk in [false, true] {
+        let q = concat(&lfs, rechunk)?.slice(20, 10);
+
+        // every input has to produce at most `offset + len` rows
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        assert!((&lp_arena).iter(lp).all(|(_, lp)| match lp {
+            ALogicalPlan::CsvScan { options, .. } => options.n_rows == Some(30),
+            ALogicalPlan::Union { options, .. } => options.slice,
+            _ => true,
+        }));
+
+        let out = q.collect()?;
+        assert!(out.frame_equal(&full.slice(20, 10)));
+        assert_eq!(out.n_chunks()?, if rechunk { 1 } else { 2 });
+    }
+    Ok(())
+}
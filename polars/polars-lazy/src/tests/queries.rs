@@ -3,6 +3,7 @@ use crate::dsl::AggExpr::List;
 use polars_arrow::prelude::QuantileInterpolOptions;
 use polars_core::frame::explode::MeltArgs;
 use polars_core::series::ops::NullBehavior;
+use polars_core::series::IsSorted;
 use polars_time::prelude::DateMethods;
 
 #[test]
@@ -650,7 +651,7 @@ fn test_type_coercion() {
 
     let mut expr_arena = Arena::new();
     let mut lp_arena = Arena::new();
-    let rules: &mut [Box<dyn OptimizationRule>] = &mut [Box::new(TypeCoercionRule {})];
+    let rules: &mut [Box<dyn OptimizationRule>] = &mut [Box::new(TypeCoercionRule::default())];
 
     let optimizer = StackOptimizer {};
     let mut lp_top = to_alp(lp, &mut expr_arena, &mut lp_arena).unwrap();
@@ -758,6 +759,81 @@ fn test_lazy_shift_and_fill() {
     assert_eq!(out.column("A").unwrap().null_count(), 0);
 }
 
+#[test]
+#[cfg(feature = "dtype-datetime")]
+fn test_shift_by_expr_and_groups() -> Result<()> {
+    let df = df! {
+        "g" => ["a", "a", "a", "b", "b"],
+        "n" => [0, 0, 0, 1, 1],
+        "x" => [1, 2, 3, 4, 5],
+        "c" => ["p", "q", "r", "p", "q"]
+    }?
+    .lazy()
+    .with_columns([
+        col("c").cast(DataType::Categorical(None)),
+        col("x")
+            .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+            .alias("dt"),
+    ]);
+
+    let out = df
+        .clone()
+        .select([
+            col("x").shift(col("n").max()).alias("shift"),
+            col("x")
+                .shift(col("n").first())
+                .over([col("g")])
+                .alias("shift_over"),
+            col("x")
+                .shift_and_fill(-10, lit(0))
+                .over([col("g")])
+                .alias("fill_over"),
+            col("c").shift_and_fill(1, col("c").first()),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("shift")?.i32()?),
+        &[None, Some(1), Some(2), Some(3), Some(4)]
+    );
+    assert_eq!(
+        Vec::from(out.column("shift_over")?.i32()?),
+        &[Some(1), Some(2), Some(3), None, Some(4)]
+    );
+    assert_eq!(out.column("fill_over")?.sum::<i32>(), Some(0));
+    let c = out.column("c")?.cast(&DataType::Utf8)?;
+    assert_eq!(
+        Vec::from(c.utf8()?),
+        &[Some("p"), Some("p"), Some("q"), Some("r"), Some("p")]
+    );
+
+    // the logical types are preserved in the groups
+    let out = df
+        .groupby_stable([col("g")])
+        .agg([
+            col("dt").shift_and_fill(col("n").first(), col("dt").first()),
+            col("c").shift_and_fill(1, col("c").last()),
+        ])
+        .collect()?;
+    let dt = out.column("dt")?;
+    assert_eq!(
+        dt.dtype(),
+        &DataType::List(Box::new(DataType::Datetime(TimeUnit::Milliseconds, None)))
+    );
+    let dt = dt.explode()?.cast(&DataType::Int64)?;
+    assert_eq!(
+        Vec::from(dt.i64()?),
+        &[Some(1), Some(2), Some(3), Some(4), Some(4)]
+    );
+    let c = out.column("c")?.explode()?;
+    assert!(matches!(c.dtype(), DataType::Categorical(_)));
+    let c = c.cast(&DataType::Utf8)?;
+    assert_eq!(
+        Vec::from(c.utf8()?),
+        &[Some("r"), Some("p"), Some("q"), Some("q"), Some("p")]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_lazy_groupby() {
     let df = df! {
@@ -870,6 +946,58 @@ fn test_lazy_groupby_cast() {
         .unwrap();
 }
 
+#[test]
+fn test_lazy_cast_with_options() -> Result<()> {
+    let df = df! {
+        "a" => ["1", "2", "x"]
+    }?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").cast_with_options(DataType::Int64, CastOptions::NonStrict)])
+        .collect()?;
+    assert_eq!(out.column("a")?.null_count(), 1);
+
+    let err = df
+        .lazy()
+        .select([col("a").cast_with_options(DataType::Int64, CastOptions::Strict)])
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("\"x\" at index 2"));
+    Ok(())
+}
+
+#[test]
+fn test_lazy_schema_diff() -> Result<()> {
+    let df = df! {
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"]
+    }?;
+
+    // resolving the schema does not run the query
+    let q = df
+        .clone()
+        .lazy()
+        .map(
+            |_| panic!("the query should not be executed"),
+            None,
+            None,
+            None,
+        )
+        .with_column(col("a").cast(DataType::Float64))
+        .with_column(lit(true).alias("c"))
+        .drop_columns(["b"]);
+    let diff = df.schema().diff(&*q.schema()?);
+    assert_eq!(diff.added.get("c"), Some(&DataType::Boolean));
+    assert_eq!(diff.removed.get("b"), Some(&DataType::Utf8));
+    assert_eq!(
+        diff.changed,
+        &[("a".to_string(), DataType::Int32, DataType::Float64)]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_lazy_groupby_binary_expr() {
     let df = df! {
@@ -925,6 +1053,7 @@ fn test_lazy_groupby_filter() -> Result<()> {
             SortOptions {
                 descending: false,
                 nulls_last: false,
+                ..Default::default()
             },
         )
         .collect()?;
@@ -1098,6 +1227,59 @@ fn test_multiple_explode() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_explode_options_and_groups() -> Result<()> {
+    let df = df![
+        "g" => [1, 1, 2, 3],
+        "v" => [1, 2, 3, 4],
+    ]?
+    .lazy()
+    .groupby_stable([col("g")])
+    .agg([col("v").list().alias("l")])
+    .with_column(
+        when(col("g").eq(lit(3)))
+            .then(lit(Null {}).cast(DataType::List(Box::new(DataType::Int32))))
+            .otherwise(col("l"))
+            .alias("l"),
+    )
+    .collect()?;
+
+    let out = df.clone().lazy().explode([col("l")]).collect()?;
+    assert_eq!(
+        Vec::from(out.column("l")?.i32()?),
+        &[Some(1), Some(2), Some(3), None]
+    );
+    let out = df
+        .clone()
+        .lazy()
+        .explode_with_options(
+            [col("l")],
+            ExplodeOptions {
+                empty_as_null: false,
+            },
+        )
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("g")?.i32()?),
+        &[Some(1), Some(1), Some(2)]
+    );
+
+    // the lists of a group explode to the values of that group
+    let out = df
+        .lazy()
+        .with_column(lit(1).alias("key"))
+        .groupby([col("key")])
+        .agg([
+            col("l").explode().alias("values"),
+            col("l").explode().sum().alias("sum"),
+        ])
+        .collect()?;
+    let values = out.column("values")?.explode()?;
+    assert_eq!(Vec::from(values.i32()?), &[Some(1), Some(2), Some(3), None]);
+    assert_eq!(out.column("sum")?.i32()?.get(0), Some(6));
+    Ok(())
+}
+
 #[test]
 fn test_filter_and_alias() -> Result<()> {
     let df = df![
@@ -1193,6 +1375,211 @@ fn test_cross_join() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_join_on_expressions() -> Result<()> {
+    let left = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"]
+    ]?;
+    let right = df![
+        "a" => [10, 30, 40],
+        "c" => [1, 2, 3]
+    ]?;
+
+    // the computed key does not overwrite the column it is computed from
+    let q = left.clone().lazy().join(
+        right.clone().lazy(),
+        [col("a") * lit(10)],
+        [col("a")],
+        JoinType::Inner,
+    );
+    let schema = q.schema()?;
+    let out = q.clone().collect()?;
+    assert_eq!(out.schema(), *schema);
+    assert_eq!(out.get_column_names(), &["a", "b", "c"]);
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(3)]);
+    let out = q.select([col("b"), col("c")]).collect()?;
+    assert_eq!(out.get_column_names(), &["b", "c"]);
+    assert_eq!(out.height(), 2);
+
+    // the right column is kept if the right key is an expression
+    let q = left.lazy().join(
+        right.lazy(),
+        [col("a")],
+        [col("a") / lit(10)],
+        JoinType::Left,
+    );
+    let schema = q.schema()?;
+    let out = q.collect()?;
+    assert_eq!(out.schema(), *schema);
+    assert_eq!(out.get_column_names(), &["a", "b", "a_right", "c"]);
+    assert_eq!(
+        Vec::from(out.column("a_right")?.i32()?),
+        &[Some(10), None, Some(30)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_join_validation() -> Result<()> {
+    let left = df![
+        "a" => [1, 2, 3],
+        "b" => [1, 1, 2]
+    ]?;
+    let right = df![
+        "a" => [1, 1, 2],
+        "c" => ["x", "y", "z"]
+    ]?;
+
+    let join = |validate| {
+        left.clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            // join on an expression, so that the evaluated keys are validated
+            .left_on([col("b") * lit(1)])
+            .right_on([col("a")])
+            .how(JoinType::Left)
+            .validate(validate)
+            .finish()
+            .collect()
+    };
+    assert_eq!(join(JoinValidation::ManyToMany)?.height(), 5);
+    assert!(join(JoinValidation::ManyToOne).is_err());
+    assert!(join(JoinValidation::OneToMany).is_err());
+
+    let out = left
+        .lazy()
+        .join_builder()
+        .with(right.lazy())
+        .left_on([col("a")])
+        .right_on([col("a")])
+        .validate(JoinValidation::OneToMany)
+        .finish()
+        .collect()?;
+    assert_eq!(out.height(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_nulls_equal() -> Result<()> {
+    let left = df![
+        "k" => [Some(1), None, Some(2)],
+        "a" => [1, 2, 3]
+    ]?;
+    let right = df![
+        "k" => [Some(1), None, None],
+        "b" => [10, 20, 30]
+    ]?;
+
+    let join = |how, nulls_equal| {
+        left.clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            .left_on([col("k")])
+            .right_on([col("k")])
+            .how(how)
+            .nulls_equal(nulls_equal)
+            .finish()
+            .collect()
+    };
+    assert_eq!(join(JoinType::Inner, true)?.height(), 3);
+    let out = join(JoinType::Inner, false)?;
+    assert_eq!(Vec::from(out.column("b")?.i32()?), &[Some(10)]);
+    let out = join(JoinType::Left, false)?;
+    assert_eq!(out.height(), 3);
+    assert_eq!(out.column("b")?.null_count(), 2);
+    // every row with a null key is in the output once
+    let out = join(JoinType::Outer, false)?;
+    assert_eq!(out.height(), 5);
+    assert_eq!(out.column("k")?.null_count(), 3);
+
+    let groupby = |nulls_equal| {
+        right
+            .clone()
+            .lazy()
+            .groupby_stable([col("k")])
+            .nulls_equal(nulls_equal)
+            .agg([col("b").sum()])
+            .collect()
+    };
+    let out = groupby(true)?;
+    assert_eq!(Vec::from(out.column("b")?.i32()?), &[Some(10), Some(50)]);
+    let out = groupby(false)?;
+    assert_eq!(out.get_column_names(), &["k", "b"]);
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(10), Some(20), Some(30)]
+    );
+
+    let out = left
+        .lazy()
+        .select([
+            col("k").eq_missing(lit(Series::new("", [Some(1), None, None]))),
+            col("k").ne_missing(lit(NULL)).alias("ne"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("k")?.bool()?),
+        &[Some(true), Some(true), Some(false)]
+    );
+    assert_eq!(
+        Vec::from(out.column("ne")?.bool()?),
+        &[Some(true), Some(false), Some(true)]
+    );
+    Ok(())
+}
+
+#[cfg(feature = "join_where")]
+#[test]
+fn test_join_where() -> Result<()> {
+    let intervals = df![
+        "start" => [0, 5, 2],
+        "end" => [10, 7, 3],
+        "name" => ["a", "b", "c"]
+    ]?;
+    let events = df![
+        "ts" => [5, 2, 9, 11],
+        "name" => ["w", "x", "y", "z"]
+    ]?;
+
+    let q = intervals
+        .clone()
+        .lazy()
+        .join_where(
+            events.clone().lazy(),
+            [col("start")
+                .lt_eq(col("ts"))
+                .and((col("end") - lit(1)).gt_eq(col("ts")))],
+        )?
+        .filter(col("name").neq(lit("b")));
+    let schema = q.schema()?;
+    let out = q.clone().collect()?;
+    assert_eq!(out.schema(), *schema);
+    assert_eq!(
+        out.get_column_names(),
+        &["start", "end", "name", "ts", "name_right"]
+    );
+    assert_eq!(
+        Vec::from(out.column("ts")?.i32()?),
+        &[Some(2), Some(5), Some(9), Some(2)]
+    );
+
+    let out = q.select([col("name_right")]).collect()?;
+    assert_eq!(
+        Vec::from(out.column("name_right")?.utf8()?),
+        &[Some("x"), Some("w"), Some("y"), Some("x")]
+    );
+
+    // only inequalities can be used
+    assert!(intervals
+        .lazy()
+        .join_where(events.lazy(), [col("start").eq(col("ts"))])
+        .is_err());
+    Ok(())
+}
+
 #[test]
 fn test_fold_wildcard() -> Result<()> {
     let df1 = df![
@@ -1500,6 +1887,34 @@ fn test_groupby_small_ints() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_groupby_sorted_keys() -> Result<()> {
+    let df = df![
+        "date" => [1i32, 1, 2, 2, 2],
+        "sensor" => ["a", "b", "b", "b", "c"],
+        "value" => [1, 2, 3, 4, 5]
+    ]?;
+
+    let out = df
+        .lazy()
+        .groupby_stable([
+            col("date").set_sorted(IsSorted::Ascending),
+            col("sensor").set_sorted(IsSorted::Ascending),
+        ])
+        .agg([col("value").sum()])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("date")?.i32()?),
+        &[Some(1), Some(1), Some(2), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("value")?.i32()?),
+        &[Some(1), Some(2), Some(7), Some(5)]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_when_then_schema() -> Result<()> {
     let df = fruits_cars();
@@ -1568,6 +1983,79 @@ fn test_list_in_select_context() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "list_eval")]
+fn test_nested_lists() -> Result<()> {
+    let a = Series::new("", &[1i32, 2]);
+    let b = Series::new("", &[3i32]);
+    let nested = Series::new(
+        "n",
+        &[
+            Series::new("", &[a.clone(), b.clone()]),
+            Series::new("", &[b.clone()]),
+        ],
+    );
+    assert_eq!(
+        nested.dtype(),
+        &DataType::List(Box::new(DataType::List(Box::new(DataType::Int32))))
+    );
+    let df = DataFrame::new(vec![nested])?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("n").arr().flatten().alias("flat"),
+            col("n")
+                .arr()
+                .eval(col("").arr().sum(), false)
+                .alias("sums"),
+        ])
+        .collect()?;
+    let flat = out.column("flat")?;
+    assert_eq!(flat.dtype(), &DataType::List(Box::new(DataType::Int32)));
+    assert_eq!(
+        Vec::from(flat.list()?.get(0).unwrap().i32()?),
+        &[Some(1), Some(2), Some(3)]
+    );
+    let sums = out.column("sums")?.list()?.get(0).unwrap();
+    assert_eq!(Vec::from(sums.i32()?), &[Some(3), Some(3)]);
+
+    // `flatten` explodes one level of nesting
+    let out = df.lazy().select([col("n").flatten()]).collect()?;
+    assert_eq!(out.height(), 3);
+    assert_eq!(
+        out.column("n")?.dtype(),
+        &DataType::List(Box::new(DataType::Int32))
+    );
+
+    // `arr().flatten()` removes a single level of nesting
+    // [[[1], [2]], [[3]]] -> [[1], [2], [3]]
+    let s = |v: i32| Series::new("", &[v]);
+    let deep = Series::new(
+        "n",
+        &[Series::new(
+            "",
+            &[Series::new("", &[s(1), s(2)]), Series::new("", &[s(3)])],
+        )],
+    );
+    let out = DataFrame::new(vec![deep])?
+        .lazy()
+        .select([col("n").arr().flatten()])
+        .collect()?;
+    let flat = out.column("n")?;
+    assert_eq!(
+        flat.dtype(),
+        &DataType::List(Box::new(DataType::List(Box::new(DataType::Int32))))
+    );
+    let flat = flat.list()?.get(0).unwrap();
+    assert_eq!(flat.len(), 3);
+    for (i, v) in [1, 2, 3].into_iter().enumerate() {
+        assert_eq!(Vec::from(flat.list()?.get(i).unwrap().i32()?), &[Some(v)]);
+    }
+    Ok(())
+}
+
 #[test]
 fn test_round_after_agg() -> Result<()> {
     let df = fruits_cars();
@@ -1713,6 +2201,62 @@ fn test_apply_multiple_columns() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_apply_groups_error() -> Result<()> {
+    let df = fruits_cars();
+
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("cars")])
+        .agg([col("A").apply(
+            |s| {
+                if s.len() > 1 {
+                    Err(PolarsError::ComputeError("too many values".into()))
+                } else {
+                    Ok(s)
+                }
+            },
+            GetOutput::same_type(),
+        )])
+        .collect();
+    assert!(matches!(
+        out.unwrap_err().root(),
+        PolarsError::ComputeError(_)
+    ));
+
+    // a `NoData` error becomes a null value
+    let out = df
+        .lazy()
+        .groupby_stable([col("cars")])
+        .agg([col("A").apply(
+            |s| {
+                if s.len() > 1 {
+                    Err(PolarsError::NoData("".into()))
+                } else {
+                    Ok(s)
+                }
+            },
+            GetOutput::same_type(),
+        )])
+        .collect()?;
+    assert_eq!(out.column("A")?.null_count(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_map_output_type() -> Result<()> {
+    let df = fruits_cars();
+
+    let q = df
+        .lazy()
+        .select([col("A").map(|s| s.cast(&DataType::Float64), GetOutput::float_type())]);
+    assert_eq!(q.schema()?.get("A"), Some(&DataType::Float64));
+    let out = q.collect()?;
+    assert_eq!(out.column("A")?.dtype(), &DataType::Float64);
+    Ok(())
+}
+
 #[test]
 pub fn test_select_by_dtypes() -> Result<()> {
     let df = df![
@@ -2081,3 +2625,1223 @@ fn test_partitioned_gb_ternary() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_eager_with_columns() -> Result<()> {
+    let mut df = df![
+        "a" => [1, 2, 3],
+        "b" => [1, 1, 1]
+    ]?;
+
+    // all expressions see the original frame
+    df.with_columns([
+        col("a") + col("b"),
+        (col("a") * lit(10)).alias("b"),
+        (col("a") - col("b")).alias("c"),
+    ])?;
+    assert_eq!(
+        Vec::from(df.column("a")?.i32()?),
+        &[Some(2), Some(3), Some(4)]
+    );
+    assert_eq!(
+        Vec::from(df.column("b")?.i32()?),
+        &[Some(10), Some(20), Some(30)]
+    );
+    assert_eq!(
+        Vec::from(df.column("c")?.i32()?),
+        &[Some(0), Some(1), Some(2)]
+    );
+
+    // a length mismatch names the expression and leaves the frame unchanged
+    let expected = df.clone();
+    let err = df
+        .with_columns([lit(1).alias("d"), col("a").head(Some(2)).alias("e")])
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("col(\"a\")"), "{}", err);
+    assert!(df.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_rechunk_threshold() -> Result<()> {
+    let mut df = df![
+        "a" => [1, 2, 1, 3],
+        "b" => [1.0, 2.0, 3.0, 4.0]
+    ]?;
+    for _ in 0..3 {
+        df.vstack_mut(&df.clone())?;
+    }
+    assert_eq!(df.n_chunks()?, 8);
+
+    let q = |threshold| {
+        df.clone()
+            .lazy()
+            .with_rechunk_threshold(threshold)
+            .groupby_stable([col("a")])
+            .agg([col("b").sum()])
+            .join(
+                df.clone()
+                    .lazy()
+                    .unique_stable(None, UniqueKeepStrategy::First),
+                [col("a")],
+                [col("a")],
+                JoinType::Left,
+            )
+            .sort("a", Default::default())
+            .collect()
+    };
+    let expected = q(None)?;
+    let out = q(Some(2))?;
+    assert!(out.frame_equal(&expected));
+    assert_eq!(
+        Vec::from(out.column("b")?.f64()?),
+        &[Some(32.0), Some(32.0), Some(16.0), Some(32.0)]
+    );
+
+    // the input of a pipeline breaker is only rechunked above the threshold
+    let mut state = ExecutionState::new();
+    state.rechunk_threshold = Some(8);
+    let mut input = df.clone();
+    state.rechunk_fragmented(&mut input);
+    assert_eq!(input.n_chunks()?, 8);
+    state.rechunk_threshold = Some(2);
+    state.rechunk_fragmented(&mut input);
+    assert_eq!(input.n_chunks()?, 1);
+    Ok(())
+}
+
+#[test]
+fn test_groupby_head_tail() -> Result<()> {
+    let df = df![
+        "a" => [1, 2, 1, 1, 2],
+        "b" => [1, 2, 3, 4, 5],
+        "c" => ["a", "b", "c", "d", "e"]
+    ]?;
+
+    // the column keys slice the groups, other keys aggregate to lists and explode
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("a")])
+        .tail(Some(2))
+        .collect()?;
+    let expected = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("a").alias("a")])
+        .tail(Some(2))
+        .collect()?;
+    assert!(out.frame_equal(&expected));
+    assert_eq!(out.get_column_names(), &["a", "b", "c"]);
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(3), Some(4), Some(2), Some(5)]
+    );
+
+    // the filter is not pushed down below the slice
+    let out = df
+        .lazy()
+        .groupby_stable([col("a")])
+        .head(Some(1))
+        .filter(col("b").gt(lit(1)))
+        .collect()?;
+    assert_eq!(Vec::from(out.column("b")?.i32()?), &[Some(2)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_str_replace() -> Result<()> {
+    let df = df![
+        "a" => [Some("2021-03-04 Foo"), Some("$1 foo.foo"), None]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .str()
+                .replace(r"(\d+)-(\d+)-(\d+)", "$3/$2/$1", false)
+                .alias("groups"),
+            col("a")
+                .str()
+                .replace_all("foo.", "$1", true)
+                .alias("literal"),
+            col("a")
+                .str()
+                .replace_with_options(
+                    "foo",
+                    "bar",
+                    ReplaceOptions {
+                        case_insensitive: true,
+                        all: true,
+                        ..Default::default()
+                    },
+                )
+                .alias("case_insensitive"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("groups")?.utf8()?),
+        &[Some("04/03/2021 Foo"), Some("$1 foo.foo"), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("literal")?.utf8()?),
+        &[Some("2021-03-04 Foo"), Some("$1 $1foo"), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("case_insensitive")?.utf8()?),
+        &[Some("2021-03-04 bar"), Some("$1 bar.bar"), None]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_iterate() -> Result<()> {
+    let edges = df![
+        "from" => [1, 2, 3, 5],
+        "to" => [2, 3, 4, 6]
+    ]?
+    .lazy();
+    let start = df!["node" => [1]]?.lazy();
+
+    let reachable = |max_iterations| {
+        let edges = edges.clone();
+        start
+            .clone()
+            .iterate(
+                move |nodes| {
+                    let next = nodes
+                        .clone()
+                        .join(edges, [col("node")], [col("from")], JoinType::Inner)
+                        .select([col("to").alias("node")]);
+                    concat(&[nodes, next], false)
+                        .unwrap()
+                        .unique_stable(None, UniqueKeepStrategy::First)
+                },
+                max_iterations,
+            )?
+            .collect()
+    };
+
+    let out = reachable(100)?;
+    assert_eq!(
+        Vec::from(out.column("node")?.i32()?),
+        &[Some(1), Some(2), Some(3), Some(4)]
+    );
+    let out = reachable(1)?;
+    assert_eq!(Vec::from(out.column("node")?.i32()?), &[Some(1), Some(2)]);
+
+    // the schema may not change between iterations
+    assert!(start
+        .iterate(|nodes| nodes.with_column(lit(1).alias("x")), 10)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parameters() -> Result<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4],
+        "b" => ["x", "y", "x", "y"]
+    ]?;
+    let q = df.lazy().filter(
+        col("a")
+            .gt(lit_param("min"))
+            .and(col("b").eq(lit_param("b"))),
+    );
+
+    let out = q
+        .clone()
+        .with_parameters([("min", lit(1)), ("b", lit("x"))])?
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(3)]);
+
+    let prepared = q.clone().prepare()?;
+    for (min, expected) in [(0, vec![Some(1), Some(3)]), (2, vec![Some(3)])] {
+        let out = prepared.collect_with_parameters([("min", lit(min)), ("b", lit("x"))])?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), expected);
+    }
+
+    // all parameters must be bound
+    assert!(prepared.collect_with_parameters([("min", lit(0))]).is_err());
+    assert!(q.collect().is_err());
+
+    // a value is coerced by its own type, not cast to the type of the column
+    let df = df!["a" => [1, 2, 3, 4]]?;
+    let q = df.lazy().filter(col("a").lt(lit_param("max")));
+    let prepared = q.clone().prepare()?;
+    for (max, expected) in [(lit(2.5), vec![Some(1), Some(2)]), (lit(2), vec![Some(1)])] {
+        let out = prepared.collect_with_parameters([("max", max.clone())])?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), expected);
+        let out = q.clone().with_parameters([("max", max)])?.collect()?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), expected);
+    }
+
+    // the schema of a projected parameter has the type of its value
+    let q = df!["a" => [1, 2]]?
+        .lazy()
+        .with_column(lit_param("p").alias("p"))
+        .select([(col("a") + col("p")).alias("sum")]);
+    let out = q
+        .clone()
+        .prepare()?
+        .collect_with_parameters([("p", lit(0.5))])?;
+    assert_eq!(
+        Vec::from(out.column("sum")?.f64()?),
+        &[Some(1.5), Some(2.5)]
+    );
+    let q = q.with_parameters([("p", lit(0.5))])?;
+    assert_eq!(q.schema()?.get("sum"), Some(&DataType::Float64));
+    Ok(())
+}
+
+#[test]
+fn test_scan_table() -> Result<()> {
+    let registry = TableRegistry::new();
+    // a table must be registered before it is scanned
+    assert!(registry.scan_table("t").is_err());
+    registry.register("t", df!["a" => [1, 2, 3]]?.lazy());
+    let q = registry
+        .scan_table("t")?
+        .filter(col("a").gt(lit(1)))
+        .select([col("a").sum()]);
+    let out = q.clone().collect()?;
+    assert_eq!(out.column("a")?.get(0), AnyValue::Int32(5));
+
+    // the table is resolved when the query runs, so it can be replaced and refer to other tables
+    registry.register("base", df!["a" => [10, 20]]?.lazy());
+    registry.register("t", registry.scan_table("base")?);
+    let out = q.clone().collect()?;
+    assert_eq!(out.column("a")?.get(0), AnyValue::Int32(30));
+    assert_eq!(registry.table_names(), &["base", "t"]);
+
+    // but not by a table with another schema
+    registry.register("t", df!["b" => [1]]?.lazy());
+    assert!(q.clone().collect().is_err());
+
+    // a table may not scan itself
+    registry.register("t", df!["a" => [1]]?.lazy());
+    registry.register("t", registry.scan_table("t")?);
+    assert!(q.clone().collect().is_err());
+    registry.register("t", df!["a" => [4]]?.lazy());
+    let out = q.collect()?;
+    assert_eq!(out.column("a")?.get(0), AnyValue::Int32(4));
+    Ok(())
+}
+
+#[test]
+fn test_expr_plugin() -> Result<()> {
+    struct Norm;
+
+    impl ExprPlugin for Norm {
+        fn get_field(&self, _input_schema: &Schema, fields: &[Field]) -> Result<Field> {
+            Ok(Field::new(fields[0].name(), DataType::Float64))
+        }
+
+        fn call(&self, s: &mut [Series]) -> Result<Series> {
+            let x = s[0].cast(&DataType::Float64)?;
+            let y = s[1].cast(&DataType::Float64)?;
+            let out = (&(&x * &x) + &(&y * &y)).f64()?.apply(|v| v.sqrt());
+            Ok(out.into_series())
+        }
+    }
+
+    let df = df![
+        "x" => [3, 6, 0],
+        "y" => [4, 8, 1]
+    ]?;
+    let norm = || col("x").plugin("test_vec", "norm", &[col("y")]);
+
+    // the output type of an unregistered plugin is unknown, every use of the query fails
+    let q = df.clone().lazy().select([norm()]);
+    assert!(q.schema().is_err());
+    assert!(q.clone().collect().is_err());
+    assert!(q.collect().is_err());
+
+    register_plugin("test_vec", "norm", Arc::new(Norm));
+    let q = df.lazy().select([norm()]);
+    assert_eq!(q.schema()?.get("x"), Some(&DataType::Float64));
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("x")?.f64()?),
+        &[Some(5.0), Some(10.0), Some(1.0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "geo")]
+fn test_geo_kernels() -> Result<()> {
+    let df = df![
+        "lat" => [48.8566, 51.5074],
+        "lon" => [2.3522, -0.1278]
+    ]?;
+    let square = Polygon::new(vec![(-1.0, 50.0), (1.0, 50.0), (1.0, 52.0), (-1.0, 52.0)]);
+    let out = df
+        .lazy()
+        .select([
+            haversine_distance(col("lat"), col("lon"), lit(51.5074), lit(-0.1278))
+                .alias("distance"),
+            point_in_polygon(col("lon"), col("lat"), vec![square]).alias("polygon"),
+        ])
+        .collect()?;
+    let distance = out.column("distance")?.f64()?;
+    assert!((distance.get(0).unwrap() - 343.56).abs() < 0.1);
+    assert_eq!(distance.get(1), Some(0.0));
+    assert_eq!(
+        Vec::from(out.column("polygon")?.idx()?),
+        &[None, Some(0 as IdxSize)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ip")]
+fn test_ip_namespace() -> Result<()> {
+    let df = df![
+        "ip" => [Some("10.1.2.3"), Some("192.168.0.1"), Some("2001:0db8::0001"), Some("x"), None]
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("ip").ip().is_in_subnet("10.0.0.0/8").alias("private"),
+            col("ip").ip().to_int().alias("int"),
+            col("ip").ip().to_int().ip().from_int().alias("formatted"),
+            col("ip").ip().normalize().alias("normalized"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("private")?.bool()?),
+        &[Some(true), Some(false), Some(false), None, None]
+    );
+    // the ipv6 address doesn't fit in an UInt32 and becomes null
+    assert_eq!(
+        Vec::from(out.column("int")?.u32()?),
+        &[Some(167838211), Some(3232235521), None, None, None]
+    );
+    assert_eq!(
+        Vec::from(out.column("formatted")?.utf8()?),
+        &[Some("10.1.2.3"), Some("192.168.0.1"), None, None, None]
+    );
+    assert_eq!(
+        Vec::from(out.column("normalized")?.utf8()?),
+        &[
+            Some("10.1.2.3"),
+            Some("192.168.0.1"),
+            Some("2001:db8::1"),
+            None,
+            None
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "url")]
+fn test_url_expressions() -> Result<()> {
+    let df = df![
+        "url" => [
+            Some("https://www.example.com:443/search?q=rust+polars&lang=en"),
+            Some("http://example.org/?q=caf%C3%A9"),
+            Some("/index.html"),
+            None
+        ]
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("url").str().extract_domain().alias("domain"),
+            col("url").str().extract_query_param("q").alias("q"),
+            col("url")
+                .str()
+                .extract_query_param("q")
+                .str()
+                .url_encode()
+                .str()
+                .url_decode()
+                .alias("round_trip"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("domain")?.utf8()?),
+        &[Some("www.example.com"), Some("example.org"), None, None]
+    );
+    let expected = &[Some("rust polars"), Some("café"), None, None];
+    assert_eq!(Vec::from(out.column("q")?.utf8()?), expected);
+    assert_eq!(Vec::from(out.column("round_trip")?.utf8()?), expected);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "anonymize")]
+fn test_anonymize_expressions() -> Result<()> {
+    let df = df![
+        "id" => ["alice", "bob", "alice"],
+        "phone" => ["0612345678", "0687654321", "12"],
+        "age" => [31, 38, 45]
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("id").hash_salted("pepper"),
+            col("id").hash_salted("salt").alias("id_other_salt"),
+            col("phone").mask_chars(2, 2),
+            col("age").generalize(10.0),
+        ])
+        .collect()?;
+
+    let ids = out.column("id")?.u64()?;
+    assert_eq!(ids.get(0), ids.get(2));
+    assert_ne!(ids.get(0), ids.get(1));
+    assert_ne!(ids.get(0), out.column("id_other_salt")?.u64()?.get(0));
+    assert_eq!(
+        Vec::from(out.column("phone")?.utf8()?),
+        &[Some("06******78"), Some("06******21"), Some("**")]
+    );
+    assert_eq!(
+        Vec::from(out.column("age")?.i32()?),
+        &[Some(30), Some(30), Some(40)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "random")]
+fn test_random_expressions() -> Result<()> {
+    let df = df!["a" => [1, 2, 3, 4, 5]]?;
+    let q = df.lazy().with_columns([
+        rand_uniform(0.0, 1.0, None, Some(0)),
+        rand_normal(0.0, 1.0, None, Some(0)),
+        rand_int(10, 20, None, Some(0)),
+    ]);
+    let out = q.clone().collect()?;
+    assert_eq!(out.shape(), (5, 4));
+    assert!(out
+        .column("rand_uniform")?
+        .f64()?
+        .into_no_null_iter()
+        .all(|v| (0.0..1.0).contains(&v)));
+    assert!(out
+        .column("rand_int")?
+        .i64()?
+        .into_no_null_iter()
+        .all(|v| (10..20).contains(&v)));
+
+    // the same seed gives the same values
+    assert!(out.frame_equal(&q.collect()?));
+
+    let out = df!["a" => [1]]?
+        .lazy()
+        .select([rand_int(0, 2, Some(3), None)])
+        .collect()?;
+    assert_eq!(out.height(), 3);
+
+    // invalid bounds are an error instead of a panic
+    for (low, high) in [(1.0, 0.0), (0.0, f64::NAN), (f64::NEG_INFINITY, 0.0)] {
+        let out = df!["a" => [1]]?
+            .lazy()
+            .select([rand_uniform(low, high, None, None)])
+            .collect();
+        assert!(matches!(
+            out.unwrap_err().root(),
+            PolarsError::ComputeError(_)
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "temporal")]
+fn test_session_id() -> Result<()> {
+    let df = df![
+        "user" => ["a", "b", "a", "b", "a", "a"],
+        "t" => [0i64, 10, 20, 70, 120, 130]
+    ]?;
+    let out = df
+        .lazy()
+        .with_column(col("t").cast(DataType::Datetime(TimeUnit::Milliseconds, None)))
+        .select([
+            col("t")
+                .dt()
+                .session_id(Duration::parse("40ms"))
+                .alias("session"),
+            col("t")
+                .dt()
+                .session_id(Duration::parse("40ms"))
+                .over([col("user")])
+                .alias("user_session"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("session")?.idx()?),
+        &[0, 0, 0, 1, 2, 2].map(|v: IdxSize| Some(v))
+    );
+    assert_eq!(
+        Vec::from(out.column("user_session")?.idx()?),
+        &[0, 0, 0, 1, 1, 1].map(|v: IdxSize| Some(v))
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rle")]
+fn test_rle() -> Result<()> {
+    let df = df!["a" => ["x", "x", "y", "x", "x", "x"]]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").rle_id().alias("id")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("id")?.idx()?),
+        &[0, 0, 1, 2, 2, 2].map(|v: IdxSize| Some(v))
+    );
+
+    let out = df.lazy().select([col("a").rle()]).unnest(["a"]).collect()?;
+    assert_eq!(
+        Vec::from(out.column("value")?.utf8()?),
+        &[Some("x"), Some("y"), Some("x")]
+    );
+    assert_eq!(
+        Vec::from(out.column("length")?.idx()?),
+        &[2, 1, 3].map(|v: IdxSize| Some(v))
+    );
+
+    // the runs don't cross the groups
+    let df = df![
+        "g" => [1, 1, 1, 2, 2],
+        "a" => ["x", "x", "y", "y", "y"]
+    ]?;
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([col("a").rle()])
+        .collect()?;
+    assert_eq!(
+        Vec::from(&out.column("a")?.list()?.lst_lengths()),
+        &[Some(2), Some(1)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "is_last")]
+fn test_distinct_flags_and_peaks() -> Result<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "v" => [1, 3, 1, 2, 2]
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("v").is_first_distinct().over([col("g")]).alias("first"),
+            col("v").is_last_distinct().over([col("g")]).alias("last"),
+            col("v").peak_max().alias("peak_max"),
+            col("v").peak_min().alias("peak_min"),
+        ])
+        .collect()?;
+    let mask =
+        |name: &str| -> Result<Vec<Option<bool>>> { Ok(Vec::from(out.column(name)?.bool()?)) };
+    assert_eq!(mask("first")?, &[true, true, false, true, false].map(Some));
+    assert_eq!(mask("last")?, &[false, true, true, false, true].map(Some));
+    assert_eq!(
+        mask("peak_max")?,
+        &[false, true, false, false, false].map(Some)
+    );
+    assert_eq!(
+        mask("peak_min")?,
+        &[false, false, true, false, false].map(Some)
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "weighted")]
+fn test_weighted_aggregations() -> Result<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "v" => [Some(1.0), Some(3.0), None, Some(2.0), Some(10.0)],
+        "w" => [Some(3), Some(1), Some(5), Some(1), None]
+    ]?;
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([
+            col("v").weighted_mean(col("w")).alias("mean"),
+            col("v").weighted_quantile(col("w"), 0.5).alias("median"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("mean")?.f64()?),
+        &[Some(1.5), Some(2.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("median")?.f64()?),
+        &[Some(1.0), Some(2.0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "hist")]
+fn test_hist() -> Result<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "v" => [1.0, 2.0, 5.0, 3.0, 4.0]
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("v").hist(Some(vec![0.0, 2.5, 5.0]), None)])
+        .unnest(["v"])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("count")?.idx()?),
+        &[2, 3].map(|v: IdxSize| Some(v))
+    );
+
+    let out = df
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([col("v").hist(None, Some(2))])
+        .explode([col("v")])
+        .unnest(["v"])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("lower")?.f64()?),
+        &[Some(1.0), Some(3.0), Some(3.0), Some(3.5)]
+    );
+    assert_eq!(
+        Vec::from(out.column("count")?.idx()?),
+        &[2, 1, 1, 1].map(|v: IdxSize| Some(v))
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "least_squares")]
+fn test_least_squares() -> Result<()> {
+    // y = 2 * x + 1 in group "a" and y = -x in group "b"
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "b"],
+        "x" => [0.0, 1.0, 2.0, 1.0, 2.0, 3.0],
+        "y" => [1.0, 3.0, 5.0, -1.0, -2.0, -3.0]
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .groupby_stable([col("g")])
+        .agg([least_squares(col("y"), vec![col("x")], true).alias("fit")])
+        .unnest(["fit"])
+        .collect()?;
+    let round = |name: &str| -> Result<Vec<Option<f64>>> {
+        Ok(out
+            .column(name)?
+            .f64()?
+            .into_iter()
+            .map(|v| v.map(|v| (v * 1e6).round() / 1e6))
+            .collect())
+    };
+    assert_eq!(round("x")?, &[Some(2.0), Some(-1.0)]);
+    assert_eq!(round("intercept")?, &[Some(1.0), Some(0.0)]);
+
+    let out = df
+        .lazy()
+        .select([rolling_least_squares(col("y"), vec![col("x")], 3, false)
+            .over([col("g")])
+            .alias("fit")])
+        .unnest(["fit"])
+        .collect()?;
+    assert_eq!(out.column("x")?.null_count(), 4);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_categorical_get_categories() -> Result<()> {
+    let df = df!["a" => ["x", "y", "x", "z"]]?;
+    let lf = df
+        .lazy()
+        .select([col("a").cast(DataType::Categorical(None)).slice(2, 2)]);
+    let out = lf
+        .clone()
+        .select([col("a").cat().get_categories()])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.utf8()?),
+        &[Some("x"), Some("y"), Some("z")]
+    );
+    let out = lf
+        .select([col("a").cat().to_local().cat().get_categories()])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.utf8()?), &[Some("x"), Some("z")]);
+    Ok(())
+}
+
+#[test]
+fn test_strict_type_coercion() -> Result<()> {
+    let df = df![
+        "a" => [1i32, 2],
+        "b" => [1u64, 2],
+        "c" => [1i64, 2],
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .with_strict_type_coercion(true)
+        .select([col("a") + col("c")])
+        .collect()?;
+    assert_eq!(out.column("a")?.dtype(), &DataType::Int64);
+
+    let lf = df.lazy().select([col("b") + col("c")]);
+    let out = lf.clone().collect()?;
+    assert_eq!(out.column("b")?.dtype(), &DataType::Float64);
+    assert!(lf.with_strict_type_coercion(true).collect().is_err());
+
+    // literals are checked by value
+    let df = df![
+        "f" => [1.0f32, 2.0],
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .with_strict_type_coercion(true)
+        .select([col("f") * lit(0.5f64)])
+        .collect()?;
+    assert_eq!(out.column("f")?.dtype(), &DataType::Float32);
+    assert!(df
+        .lazy()
+        .with_strict_type_coercion(true)
+        .select([col("f") * lit(0.1f64)])
+        .collect()
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_error_context() -> Result<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => ["1", "2", "x"],
+    ]?;
+    let err = df
+        .lazy()
+        .filter(col("a").gt(lit(0)))
+        .select([col("a"), col("b").strict_cast(DataType::Int64)])
+        .with_column((col("a") * lit(2)).alias("c"))
+        .collect()
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::Compute);
+    assert!(matches!(err.root(), PolarsError::ComputeError(_)));
+
+    let context = err.context().unwrap();
+    assert_eq!(context.column.as_deref(), Some("b"));
+    assert_eq!(context.dtypes, Some((DataType::Utf8, DataType::Int64)));
+    assert!(context.plan_node.as_ref().unwrap().starts_with("SELECT"));
+    Ok(())
+}
+
+#[test]
+fn test_collect_async() -> Result<()> {
+    use std::future::Future;
+    use std::task::{Context, Poll, Wake};
+
+    struct ThreadWaker(std::thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark()
+        }
+    }
+
+    let mut fut = Box::pin(
+        fruits_cars()
+            .lazy()
+            .filter(col("A").gt(lit(2)))
+            .collect_async(),
+    );
+    let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let out = loop {
+        match fut.as_mut().poll(&mut cx) {
+            Poll::Ready(out) => break out?,
+            Poll::Pending => std::thread::park(),
+        }
+    };
+    assert_eq!(out.height(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_cancellation_token() -> Result<()> {
+    let token = CancellationToken::new();
+    let out = fruits_cars()
+        .lazy()
+        .filter(col("A").gt(lit(2)))
+        .collect_with_cancellation(&token)?;
+    assert_eq!(out.height(), 3);
+
+    // cancel while the query runs, the next operation is not executed
+    let handle = token.clone();
+    let err = fruits_cars()
+        .lazy()
+        .select([col("A").map(
+            move |s| {
+                handle.cancel();
+                Ok(s)
+            },
+            GetOutput::same_type(),
+        )])
+        .sort("A", false)
+        .collect_with_cancellation(&token)
+        .unwrap_err();
+    assert!(err.to_string().contains("cancelled"));
+    assert_eq!(err.code(), ErrorCode::Cancelled);
+    assert!(token.is_cancelled());
+
+    // a cancelled token aborts the query before it starts
+    assert!(fruits_cars()
+        .lazy()
+        .collect_with_cancellation(&token)
+        .is_err());
+
+    // prepared queries and iterations take the token too
+    let prepared = fruits_cars()
+        .lazy()
+        .filter(col("A").gt(lit_param("min")))
+        .prepare()?;
+    let err = prepared
+        .collect_with_parameters_and_hooks(
+            [("min", lit(2))],
+            ExecutionHooks::new().with_cancellation(&token),
+        )
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::Cancelled);
+    let err = df!["a" => [1]]?
+        .lazy()
+        .iterate_with_cancellation(|lf| lf, 10, &token)?
+        .collect()
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::Cancelled);
+    Ok(())
+}
+
+#[test]
+fn test_progress_callback() -> Result<()> {
+    let reports = Arc::new(Mutex::new(vec![]));
+    let reports_hook = reports.clone();
+    let out = fruits_cars()
+        .lazy()
+        .filter(col("A").gt(lit(2)))
+        .sort("B", false)
+        .collect_with_progress(move |progress| {
+            reports_hook.lock().unwrap().push(progress.clone())
+        })?;
+    assert_eq!(out.height(), 3);
+
+    let reports = reports.lock().unwrap();
+    // the scan reports its rows before the filter that is pushed down into it
+    assert_eq!(reports[0].rows_scanned, 5);
+    assert_eq!(reports[0].nodes_completed, 0);
+    let last = reports.last().unwrap();
+    assert_eq!(last.nodes_completed, reports.len() - 1);
+    assert_eq!(last.rows_scanned, 5);
+    assert_eq!(last.bytes_read, 0);
+    Ok(())
+}
+
+#[test]
+fn test_deterministic() -> Result<()> {
+    let df = df![
+        "key" => ["c", "a", "c", "b", "a"],
+        "value" => [1, 2, 3, 4, 5],
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .with_deterministic(true)
+        .groupby([col("key")])
+        .agg([col("value").sum()])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("key")?.utf8()?),
+        &[Some("c"), Some("a"), Some("b")]
+    );
+
+    let right = df![
+        "key" => ["a", "c", "a"],
+        "other" => [10, 20, 30],
+    ]?;
+    let out = df
+        .lazy()
+        .with_deterministic(true)
+        .inner_join(right.lazy(), col("key"), col("key"))
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("value")?.i32()?),
+        &[Some(1), Some(2), Some(2), Some(3), Some(5), Some(5)]
+    );
+    assert_eq!(
+        Vec::from(out.column("other")?.i32()?),
+        &[Some(20), Some(10), Some(30), Some(20), Some(10), Some(30)]
+    );
+    assert_eq!(out.width(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_global_deterministic() -> Result<()> {
+    let df = df![
+        "key" => ["c", "a", "c", "b", "a"],
+        "value" => [1, 2, 3, 4, 5],
+    ]?;
+    let right = df![
+        "key" => ["a", "c", "a"],
+        "other" => [10, 20, 30],
+    ]?;
+    // the query reads the global mode when it is collected
+    let lf = df.clone().lazy().select([col("key").unique()]);
+    polars_core::config::set_deterministic(true);
+    let unique = lf.collect();
+    let joined = df.join(&right, ["key"], ["key"], JoinType::Inner, None);
+    polars_core::config::set_deterministic(false);
+
+    assert_eq!(
+        Vec::from(unique?.column("key")?.utf8()?),
+        &[Some("c"), Some("a"), Some("b")]
+    );
+    let joined = joined?;
+    assert_eq!(
+        Vec::from(joined.column("value")?.i32()?),
+        &[Some(1), Some(2), Some(2), Some(3), Some(5), Some(5)]
+    );
+    assert_eq!(
+        Vec::from(joined.column("other")?.i32()?),
+        &[Some(20), Some(10), Some(30), Some(20), Some(10), Some(30)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_nan_policies() -> Result<()> {
+    let df = df![
+        "key" => [f64::NAN, -f64::NAN, 1.0],
+        "value" => [1.0, f64::NAN, 3.0],
+        "other" => [1, 2, 3],
+    ]?;
+
+    let groups = |nans_equal| {
+        df.clone()
+            .lazy()
+            .groupby_stable([col("key")])
+            .nans_equal(nans_equal)
+            .agg([col("other").sum()])
+            .collect()
+    };
+    assert_eq!(groups(false)?.height(), 3);
+    let out = groups(true)?;
+    assert_eq!(Vec::from(out.column("other")?.i32()?), &[Some(3), Some(3)]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("value").nan_max().alias("nan_max"),
+            col("value").nan_min().alias("nan_min"),
+        ])
+        .collect()?;
+    assert!(out.column("nan_max")?.f64()?.get(0).unwrap().is_nan());
+    assert!(out.column("nan_min")?.f64()?.get(0).unwrap().is_nan());
+
+    let out = df.clone().lazy().drop_nans(None).collect()?;
+    assert_eq!(Vec::from(out.column("other")?.i32()?), &[Some(3)]);
+    let out = df.lazy().drop_nans(Some(vec![col("value")])).collect()?;
+    assert_eq!(Vec::from(out.column("other")?.i32()?), &[Some(1), Some(3)]);
+    Ok(())
+}
+
+#[test]
+fn test_sort_nan_position() -> Result<()> {
+    let df = df![
+        "a" => [2.0, f64::NAN, 1.0],
+        "b" => [1, 2, 3],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .sort(
+            "a",
+            SortOptions {
+                nan_position: NanPosition::First,
+                ..Default::default()
+            },
+        )
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(2), Some(3), Some(1)]
+    );
+
+    let out = df
+        .lazy()
+        .sort_by_exprs(
+            [col("a"), col("b")],
+            [true, false],
+            false,
+            NanPosition::Last,
+        )
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(1), Some(3), Some(2)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_expr_cache() -> Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let df = df![
+        "a" => [1, 20, 3],
+        "b" => [4, 5, 6],
+    ]?;
+    let calls = Arc::new(AtomicUsize::new(0));
+    let udf = |calls: Arc<AtomicUsize>, name: &str| {
+        col(name)
+            .map(
+                move |s| {
+                    calls.fetch_add(1, Ordering::Relaxed);
+                    Ok(&s + &s)
+                },
+                GetOutput::same_type(),
+            )
+            .cache()
+    };
+
+    let doubled = udf(calls.clone(), "a");
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            when(doubled.clone().gt(lit(10)))
+                .then(doubled.clone())
+                .otherwise(lit(0))
+                .alias("big"),
+            (doubled.clone() + lit(1)).alias("plus_one"),
+            doubled,
+        ])
+        .collect()?;
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(
+        Vec::from(out.column("big")?.i32()?),
+        &[Some(0), Some(40), Some(0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("plus_one")?.i32()?),
+        &[Some(3), Some(41), Some(7)]
+    );
+
+    // a cached expression in a window function is only evaluated by the window function
+    calls.store(0, Ordering::Relaxed);
+    let out = df
+        .clone()
+        .lazy()
+        .select([udf(calls.clone(), "a").sum().over([col("b")])])
+        .collect()?;
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(2), Some(40), Some(6)]
+    );
+
+    // every column of a wildcard has its own result
+    calls.store(0, Ordering::Relaxed);
+    let out = df
+        .lazy()
+        .with_columns([udf(calls.clone(), "*")])
+        .collect()?;
+    assert_eq!(calls.load(Ordering::Relaxed), 2);
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(8), Some(10), Some(12)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json_normalize")]
+fn test_lazy_json_normalize() -> Result<()> {
+    let user = StructChunked::new("user", &[Series::new("name", ["a", "b"])])?;
+    let record =
+        |k: &[i32]| StructChunked::new("", &[Series::new("k", k)]).map(|ca| ca.into_series());
+    let records = Series::new("records", [record(&[1, 2])?, record(&[3, 4])?]);
+    let df = DataFrame::new(vec![Series::new("id", [1, 2]), user.into_series(), records])?;
+
+    // the filter, projection and slice on the flattened columns are not pushed down
+    let out = df
+        .lazy()
+        .json_normalize(Default::default())
+        .filter(col("records.k").gt(lit(1)))
+        .select([col("id"), col("records.k")])
+        .slice(0, 2)
+        .collect()?;
+    assert_eq!(Vec::from(out.column("id")?.i32()?), &[Some(1), Some(2)]);
+    assert_eq!(
+        Vec::from(out.column("records.k")?.i32()?),
+        &[Some(2), Some(3)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "string_encoding")]
+fn test_str_encode_decode() -> Result<()> {
+    let df = df![
+        "a" => [Some("foo"), None, Some("ü")],
+        "b" => ["66ff", "zz", "66"]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("a").str().encode(StringEncoding::Hex).alias("hex"),
+            col("a")
+                .str()
+                .encode(StringEncoding::Base64)
+                .str()
+                .decode(StringEncoding::Base64, DecodeStrategy::Error)
+                .alias("round_trip"),
+            col("b")
+                .str()
+                .decode(StringEncoding::Hex, DecodeStrategy::Null)
+                .alias("null"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("hex")?.utf8()?),
+        &[Some("666f6f"), None, Some("c3bc")]
+    );
+    assert_eq!(
+        Vec::from(out.column("round_trip")?.utf8()?),
+        &[Some("foo"), None, Some("ü")]
+    );
+    assert_eq!(
+        Vec::from(out.column("null")?.utf8()?),
+        &[None, None, Some("f")]
+    );
+
+    let out = df
+        .lazy()
+        .select([col("b")
+            .str()
+            .decode(StringEncoding::Hex, DecodeStrategy::Error)])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
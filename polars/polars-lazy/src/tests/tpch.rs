@@ -81,6 +81,7 @@ fn test_q2() -> Result<()> {
             [cols(["s_acctbal", "n_name", "s_name", "p_partkey"])],
             [true, false, false, false],
             false,
+            Default::default(),
         )
         .limit(100);
 
@@ -5,6 +5,16 @@ use polars_core::prelude::*;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+/// Enter a `tracing` span for the rest of the enclosing block.
+/// Without the `tracing` feature this does nothing.
+macro_rules! trace_span {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!($($arg)*).entered();
+    };
+}
+pub(crate) use trace_span;
+
 pub(crate) trait PushNode {
     fn push_node(&mut self, value: Node);
 }
@@ -62,6 +72,31 @@ pub(crate) fn aexpr_is_simple_projection(current_node: Node, arena: &Arena<AExpr
         .all(|(_node, e)| matches!(e, AExpr::Column(_) | AExpr::Alias(_, _)))
 }
 
+/// The column of a join key that selects a column: `col(name)` or `col(name).alias(name)`.
+/// Other keys are computed and are not part of the output of the join.
+pub(crate) fn join_key_column(e: &Expr) -> Option<&Arc<str>> {
+    match e {
+        Expr::Column(name) => Some(name),
+        Expr::Alias(e, alias) => match e.as_ref() {
+            Expr::Column(name) if name == alias => Some(name),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// See [`join_key_column`].
+pub(crate) fn aexpr_join_key_column(node: Node, arena: &Arena<AExpr>) -> Option<Arc<str>> {
+    match arena.get(node) {
+        AExpr::Column(name) => Some(name.clone()),
+        AExpr::Alias(node, alias) => match arena.get(*node) {
+            AExpr::Column(name) if name == alias => Some(name.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 pub(crate) fn has_aexpr<F>(current_node: Node, arena: &Arena<AExpr>, matches: F) -> bool
 where
     F: Fn(&AExpr) -> bool,
@@ -111,26 +146,6 @@ pub(crate) fn has_null(current_expr: &Expr) -> bool {
     })
 }
 
-/// output name of expr
-pub(crate) fn expr_output_name(expr: &Expr) -> Result<Arc<str>> {
-    for e in expr {
-        match e {
-            // don't follow the partition by branch
-            Expr::Window { function, .. } => return expr_output_name(function),
-            Expr::Column(name) => return Ok(name.clone()),
-            Expr::Alias(_, name) => return Ok(name.clone()),
-            _ => {}
-        }
-    }
-    Err(PolarsError::ComputeError(
-        format!(
-            "No root column name could be found for expr {:?} in output name utility",
-            expr
-        )
-        .into(),
-    ))
-}
-
 /// This function should be used to find the name of the start of an expression
 /// Normal iteration would just return the first root column it found
 pub(crate) fn get_single_root(expr: &Expr) -> Result<Arc<str>> {
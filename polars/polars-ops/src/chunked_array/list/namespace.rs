@@ -198,6 +198,17 @@ pub trait ListNameSpaceImpl: AsList {
         IdxCa::from_vec(ca.name(), lengths)
     }
 
+    /// Concatenate the sublists of every list, flattening one level of nesting.
+    fn lst_flatten(&self) -> Result<ListChunked> {
+        let ca = self.as_list();
+        match ca.inner_dtype() {
+            DataType::List(inner) => Ok(ca.cast(&DataType::List(inner))?.list()?.clone()),
+            dt => Err(PolarsError::InvalidOperation(
+                format!("cannot flatten a list of {:?}, expected nested lists", dt).into(),
+            )),
+        }
+    }
+
     /// Get the value by index in the sublists.
     /// So index `0` would return the first item of every sublist
     /// and index `-1` would return the last item of every sublist
@@ -0,0 +1,129 @@
+//! Parsing of IPv4 and IPv6 addresses and network containment.
+use polars_core::prelude::*;
+use polars_core::utils::CustomIterTools;
+use std::net::{IpAddr, Ipv4Addr};
+
+/// An IP network in CIDR notation, e.g. `"10.0.0.0/8"` or `"2001:db8::/32"`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IpNetwork {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpNetwork {
+    /// Parse a network in CIDR notation. An address without a prefix length is a network
+    /// with only that address.
+    pub fn parse(cidr: &str) -> Result<Self> {
+        let err = || PolarsError::ComputeError(format!("invalid ip network '{}'", cidr).into());
+        let (addr, prefix_len) = match cidr.split_once('/') {
+            Some((addr, prefix_len)) => (addr, Some(prefix_len)),
+            None => (cidr, None),
+        };
+        let addr: IpAddr = addr.trim().parse().map_err(|_| err())?;
+        let max_len = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix_len = match prefix_len {
+            Some(len) => len.trim().parse::<u8>().map_err(|_| err())?,
+            None => max_len,
+        };
+        if prefix_len > max_len {
+            return Err(err());
+        }
+        Ok(IpNetwork { addr, prefix_len })
+    }
+
+    /// Check if `addr` lies in this network. Addresses of the other IP version never do.
+    pub fn contains(&self, addr: IpAddr) -> bool {
+        match (self.addr, addr) {
+            (IpAddr::V4(net), IpAddr::V4(addr)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u32::from(net) & mask == u32::from(addr) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(addr)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u128::from(net) & mask == u128::from(addr) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+fn parse_ip(s: &str) -> Option<IpAddr> {
+    s.trim().parse().ok()
+}
+
+/// Parse IPv4 addresses to their integer representation. Values that are not IPv4
+/// addresses become null, including IPv6 addresses, which don't fit in an `u32`.
+pub(super) fn ipv4_to_int(ca: &Utf8Chunked) -> UInt32Chunked {
+    let mut out: UInt32Chunked = ca
+        .into_iter()
+        .map(|opt_s| match opt_s.and_then(parse_ip) {
+            Some(IpAddr::V4(addr)) => Some(u32::from(addr)),
+            _ => None,
+        })
+        .collect_trusted();
+    out.rename(ca.name());
+    out
+}
+
+/// Format the integer representation of IPv4 addresses in dotted decimal notation.
+pub fn ipv4_from_int(ca: &UInt32Chunked) -> Utf8Chunked {
+    let mut out: Utf8Chunked = ca
+        .into_iter()
+        .map(|opt_v| opt_v.map(|v| Ipv4Addr::from(v).to_string()))
+        .collect();
+    out.rename(ca.name());
+    out
+}
+
+pub(super) fn is_in_subnet(ca: &Utf8Chunked, network: &IpNetwork) -> BooleanChunked {
+    let mut out: BooleanChunked = ca
+        .into_iter()
+        .map(|opt_s| opt_s.and_then(parse_ip).map(|addr| network.contains(addr)))
+        .collect_trusted();
+    out.rename(ca.name());
+    out
+}
+
+pub(super) fn normalize(ca: &Utf8Chunked) -> Utf8Chunked {
+    let mut out: Utf8Chunked = ca
+        .into_iter()
+        .map(|opt_s| opt_s.and_then(parse_ip).map(|addr| addr.to_string()))
+        .collect();
+    out.rename(ca.name());
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ip_network() -> Result<()> {
+        let net = IpNetwork::parse("10.0.0.0/8")?;
+        assert!(net.contains("10.1.2.3".parse().unwrap()));
+        assert!(!net.contains("11.0.0.1".parse().unwrap()));
+        assert!(!net.contains("::1".parse().unwrap()));
+
+        let net = IpNetwork::parse("2001:db8::/32")?;
+        assert!(net.contains("2001:db8:0:1::1".parse().unwrap()));
+        assert!(!net.contains("2001:db9::1".parse().unwrap()));
+
+        assert!(IpNetwork::parse("0.0.0.0/0")?.contains("1.2.3.4".parse().unwrap()));
+        assert!(IpNetwork::parse("10.0.0.0/33").is_err());
+        assert!(IpNetwork::parse("10.0.0/8").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ipv4_int_round_trip() {
+        let ca = Utf8Chunked::new("ip", &[Some("192.168.0.1"), Some("::1"), Some("x"), None]);
+        let ints = ipv4_to_int(&ca);
+        assert_eq!(Vec::from(&ints), &[Some(3232235521), None, None, None]);
+        let out = ipv4_from_int(&ints);
+        assert_eq!(Vec::from(&out), &[Some("192.168.0.1"), None, None, None]);
+    }
+}
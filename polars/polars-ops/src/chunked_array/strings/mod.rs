@@ -2,8 +2,23 @@
 #[cfg_attr(docsrs, doc(cfg(feature = "strings")))]
 mod namespace;
 
+#[cfg(feature = "ip")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ip")))]
+pub mod ip;
+
+#[cfg(feature = "string_similarity")]
+#[cfg_attr(docsrs, doc(cfg(feature = "string_similarity")))]
+pub mod similarity;
+
+#[cfg(feature = "url")]
+mod url;
+
+#[cfg(feature = "ip")]
+pub use ip::{ipv4_from_int, IpNetwork};
 #[cfg(feature = "strings")]
 pub use namespace::*;
+#[cfg(feature = "url")]
+pub use url::{extract_domain, extract_query_param, url_decode, url_encode};
 
 use polars_core::prelude::*;
 
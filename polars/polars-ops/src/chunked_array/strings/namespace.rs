@@ -1,12 +1,45 @@
 use super::*;
 
+use memchr::memmem::Finder;
 use polars_arrow::{
     export::arrow::{self, compute::substring::substring},
     kernels::string::*,
 };
-use polars_core::export::regex::{escape, Regex};
+use polars_core::export::regex::{escape, NoExpand, Regex, RegexBuilder};
 use std::borrow::Cow;
 
+/// Options of [`Utf8NameSpaceImpl::replace_with_options`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ReplaceOptions {
+    /// Treat the pattern and the replacement as literal strings. Otherwise the pattern is a
+    /// regex, and the replacement may refer to its capture groups with `$1` or `${name}`.
+    /// Unlike in [`Utf8NameSpaceImpl::replace_literal`], a `$` in a literal replacement is
+    /// not expanded.
+    pub literal: bool,
+    /// Match the pattern case insensitively.
+    pub case_insensitive: bool,
+    /// Replace all matches instead of only the first (leftmost) match.
+    pub all: bool,
+}
+
+/// Replace the first `n` occurrences of the `finder`'s needle with `val`.
+fn replace_literal_n<'a>(s: &'a str, finder: &Finder, val: &str, n: usize) -> Cow<'a, str> {
+    let mut matches = finder.find_iter(s.as_bytes()).take(n).peekable();
+    if matches.peek().is_none() {
+        return Cow::Borrowed(s);
+    }
+    let mut out = String::with_capacity(s.len());
+    let mut last = 0;
+    for start in matches {
+        // a match of valid utf8 in valid utf8 starts and ends at char boundaries
+        out.push_str(&s[last..start]);
+        out.push_str(val);
+        last = start + finder.needle().len();
+    }
+    out.push_str(&s[last..]);
+    Cow::Owned(out)
+}
+
 fn f_regex_extract<'a>(reg: &Regex, input: &'a str, group_index: usize) -> Option<Cow<'a, str>> {
     reg.captures(input)
         .and_then(|cap| cap.get(group_index).map(|m| Cow::Borrowed(m.as_str())))
@@ -100,6 +133,110 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         ca.apply(f)
     }
 
+    /// Parse IPv4 addresses to their integer representation. Values that are not IPv4
+    /// addresses become null, including IPv6 addresses, which don't fit in an `u32`.
+    #[cfg(feature = "ip")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ip")))]
+    fn ip_to_int(&self) -> UInt32Chunked {
+        super::ip::ipv4_to_int(self.as_utf8())
+    }
+
+    /// Check if the IPv4 or IPv6 addresses lie in the network `cidr`, e.g. `"10.0.0.0/8"`.
+    /// Values that are not IP addresses become null.
+    #[cfg(feature = "ip")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ip")))]
+    fn ip_is_in_subnet(&self, cidr: &str) -> Result<BooleanChunked> {
+        let network = super::ip::IpNetwork::parse(cidr)?;
+        Ok(super::ip::is_in_subnet(self.as_utf8(), &network))
+    }
+
+    /// Format the IPv4 or IPv6 addresses in their canonical form, e.g. `"2001:db8::1"` for
+    /// `"2001:0db8:0:0::0001"`. Values that are not IP addresses become null.
+    #[cfg(feature = "ip")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ip")))]
+    fn ip_normalize(&self) -> Utf8Chunked {
+        super::ip::normalize(self.as_utf8())
+    }
+
+    /// Decode the `%XX` escapes and the `+` (as a space) of URL components. Values that don't
+    /// decode to valid utf8 become null.
+    #[cfg(feature = "url")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+    fn url_decode(&self) -> Utf8Chunked {
+        super::url::apply_opt(self.as_utf8(), super::url::url_decode)
+    }
+
+    /// Percent encode all bytes except the unreserved characters `A-Z a-z 0-9 - _ . ~`.
+    #[cfg(feature = "url")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+    fn url_encode(&self) -> Utf8Chunked {
+        super::url::apply_opt(self.as_utf8(), |s| Some(super::url::url_encode(s)))
+    }
+
+    /// Get the host of URLs, without user info and port.
+    #[cfg(feature = "url")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+    fn extract_domain(&self) -> Utf8Chunked {
+        super::url::apply_opt(self.as_utf8(), super::url::extract_domain)
+    }
+
+    /// Get the decoded value of the first query parameter `name` of URLs, or null if the
+    /// parameter is missing.
+    #[cfg(feature = "url")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+    fn extract_query_param(&self, name: &str) -> Utf8Chunked {
+        super::url::apply_opt(self.as_utf8(), |s| super::url::extract_query_param(s, name))
+    }
+
+    /// The levenshtein distance between these strings and the strings of `other`.
+    /// A unit length `other` is compared with every string.
+    #[cfg(feature = "string_similarity")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "string_similarity")))]
+    fn levenshtein_distance(&self, other: &Utf8Chunked) -> Result<UInt32Chunked> {
+        super::similarity::binary_str_elementwise(self.as_utf8(), other, |a, b| {
+            super::similarity::levenshtein(a, b) as u32
+        })
+    }
+
+    /// The levenshtein distance normalized to a similarity in `[0, 1]` by the length of the
+    /// longest string. A unit length `other` is compared with every string.
+    #[cfg(feature = "string_similarity")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "string_similarity")))]
+    fn levenshtein_ratio(&self, other: &Utf8Chunked) -> Result<Float64Chunked> {
+        super::similarity::binary_str_elementwise(
+            self.as_utf8(),
+            other,
+            super::similarity::levenshtein_ratio,
+        )
+    }
+
+    /// The jaro-winkler similarity between these strings and the strings of `other`.
+    /// A unit length `other` is compared with every string.
+    #[cfg(feature = "string_similarity")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "string_similarity")))]
+    fn jaro_winkler(&self, other: &Utf8Chunked) -> Result<Float64Chunked> {
+        super::similarity::binary_str_elementwise(
+            self.as_utf8(),
+            other,
+            super::similarity::jaro_winkler,
+        )
+    }
+
+    /// The jaccard similarity of the sets of `n` char n-grams of these strings and the strings
+    /// of `other`. A unit length `other` is compared with every string.
+    #[cfg(feature = "string_similarity")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "string_similarity")))]
+    fn jaccard(&self, other: &Utf8Chunked, n: usize) -> Result<Float64Chunked> {
+        if n == 0 {
+            return Err(PolarsError::ComputeError(
+                "the n-grams of 'jaccard' should have at least one char".into(),
+            ));
+        }
+        super::similarity::binary_str_elementwise(self.as_utf8(), other, |a, b| {
+            super::similarity::jaccard(a, b, n)
+        })
+    }
+
     /// Check if strings contain a regex pattern; take literal fast-path if
     /// no special chars and strlen <= 96 chars (otherwise regex faster).
     fn contains(&self, pat: &str) -> Result<BooleanChunked> {
@@ -147,8 +284,9 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
 
     /// Replace the leftmost regex-matched (sub)string with another string; take
     /// fast-path for small (<= 32 chars) strings (otherwise regex faster).
+    /// The replacement may refer to capture groups with `$1` or `${name}`.
     fn replace<'a>(&'a self, pat: &str, val: &str) -> Result<Utf8Chunked> {
-        let lit = pat.chars().all(|c| !c.is_ascii_punctuation());
+        let lit = pat.chars().all(|c| !c.is_ascii_punctuation()) && !val.contains('$');
         let ca = self.as_utf8();
         let reg = Regex::new(pat)?;
         let f = |s: &'a str| {
@@ -161,12 +299,20 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         Ok(ca.apply(f))
     }
 
-    /// Replace the leftmost literal (sub)string with another string
-    fn replace_literal(&self, pat: &str, val: &str) -> Result<Utf8Chunked> {
-        self.replace(escape(pat).as_str(), val)
+    /// Replace the leftmost literal (sub)string with another string.
+    /// Like in [`replace`](Utf8NameSpaceImpl::replace), the replacement may refer to the
+    /// match with `$0`, use `$$` for a literal `$`.
+    fn replace_literal<'a>(&'a self, pat: &str, val: &str) -> Result<Utf8Chunked> {
+        if pat.is_empty() || val.contains('$') {
+            return self.replace(escape(pat).as_str(), val);
+        }
+        let ca = self.as_utf8();
+        let finder = Finder::new(pat);
+        Ok(ca.apply(|s: &'a str| replace_literal_n(s, &finder, val, 1)))
     }
 
-    /// Replace all regex-matched (sub)strings with another string
+    /// Replace all regex-matched (sub)strings with another string.
+    /// The replacement may refer to capture groups with `$1` or `${name}`.
     fn replace_all(&self, pat: &str, val: &str) -> Result<Utf8Chunked> {
         let ca = self.as_utf8();
         let reg = Regex::new(pat)?;
@@ -174,9 +320,62 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         Ok(ca.apply(f))
     }
 
-    /// Replace all matching literal (sub)strings with another string
-    fn replace_literal_all(&self, pat: &str, val: &str) -> Result<Utf8Chunked> {
-        self.replace_all(escape(pat).as_str(), val)
+    /// Replace all matching literal (sub)strings with another string.
+    /// Like in [`replace_all`](Utf8NameSpaceImpl::replace_all), the replacement may refer to
+    /// the match with `$0`, use `$$` for a literal `$`.
+    fn replace_literal_all<'a>(&'a self, pat: &str, val: &str) -> Result<Utf8Chunked> {
+        // the empty pattern matches between all chars, not bytes
+        if pat.is_empty() || val.contains('$') {
+            return self.replace_all(escape(pat).as_str(), val);
+        }
+        let ca = self.as_utf8();
+        let finder = Finder::new(pat);
+        Ok(ca.apply(|s: &'a str| replace_literal_n(s, &finder, val, usize::MAX)))
+    }
+
+    /// Replace the matches of `pat` with `val`, see [`ReplaceOptions`].
+    fn replace_with_options<'a>(
+        &'a self,
+        pat: &str,
+        val: &str,
+        options: ReplaceOptions,
+    ) -> Result<Utf8Chunked> {
+        let ca = self.as_utf8();
+        match options {
+            // the empty pattern matches between all chars, not bytes
+            ReplaceOptions {
+                literal: true,
+                case_insensitive: false,
+                all,
+            } if !pat.is_empty() => {
+                let finder = Finder::new(pat);
+                let n = if all { usize::MAX } else { 1 };
+                Ok(ca.apply(|s: &'a str| replace_literal_n(s, &finder, val, n)))
+            }
+            ReplaceOptions {
+                literal,
+                case_insensitive,
+                all,
+            } => {
+                let pat = if literal {
+                    escape(pat)
+                } else {
+                    pat.to_string()
+                };
+                let reg = RegexBuilder::new(&pat)
+                    .case_insensitive(case_insensitive)
+                    .build()?;
+                let limit = if all { 0 } else { 1 };
+                let f = |s: &'a str| {
+                    if literal {
+                        reg.replacen(s, limit, NoExpand(val))
+                    } else {
+                        reg.replacen(s, limit, val)
+                    }
+                };
+                Ok(ca.apply(f))
+            }
+        }
     }
 
     /// Extract the nth capture group from pattern
@@ -258,3 +457,31 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
 }
 
 impl Utf8NameSpaceImpl for Utf8Chunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_replace_literal() -> Result<()> {
+        let ca = Utf8Chunked::new("a", &[Some("a.b.c"), Some("$0"), None]);
+        let out = ca.replace_literal(".", "-")?;
+        assert_eq!(Vec::from(&out), &[Some("a-b.c"), Some("$0"), None]);
+
+        // the replacement may refer to the match
+        let out = ca.replace_literal_all(".", "[$0]")?;
+        assert_eq!(Vec::from(&out), &[Some("a[.]b[.]c"), Some("$0"), None]);
+        let out = ca.replace_literal_all("$0", "$$")?;
+        assert_eq!(Vec::from(&out), &[Some("a.b.c"), Some("$"), None]);
+
+        // unless the replacement is literal as well
+        let options = ReplaceOptions {
+            literal: true,
+            all: true,
+            ..Default::default()
+        };
+        let out = ca.replace_with_options(".", "[$0]", options)?;
+        assert_eq!(Vec::from(&out), &[Some("a[$0]b[$0]c"), Some("$0"), None]);
+        Ok(())
+    }
+}
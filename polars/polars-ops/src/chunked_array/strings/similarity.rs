@@ -0,0 +1,188 @@
+//! Similarity and distance measures between strings, used for fuzzy matching.
+//!
+//! All measures operate on the `char`s of the strings, not on their bytes.
+use polars_core::prelude::*;
+use polars_core::utils::CustomIterTools;
+
+/// The minimal number of single `char` insertions, deletions and substitutions
+/// to change `a` into `b`.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    // the distances of the previous row of the dynamic programming matrix
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + (ca != *cb) as usize;
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// The levenshtein distance normalized to a similarity in `[0, 1]`, where `1` means equal.
+pub fn levenshtein_ratio(a: &str, b: &str) -> f64 {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(a, b) as f64 / max_len as f64
+}
+
+/// The jaro similarity in `[0, 1]`, where `1` means equal.
+pub fn jaro(a: &str, b: &str) -> f64 {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    // chars only match if they are not further apart than this
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::with_capacity(a.len());
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *ca {
+                b_matched[j] = true;
+                a_matches.push(*ca);
+                break;
+            }
+        }
+    }
+    let matches = a_matches.len();
+    if matches == 0 {
+        return 0.0;
+    }
+    let b_matches = b
+        .iter()
+        .zip(&b_matched)
+        .filter(|(_, m)| **m)
+        .map(|(c, _)| c);
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(a, b)| a != b)
+        .count()
+        / 2;
+
+    let m = matches as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+/// The jaro-winkler similarity in `[0, 1]`, where `1` means equal. This is the jaro similarity
+/// that is boosted for strings with a common prefix (of at most 4 chars).
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let sim = jaro(a, b);
+    let prefix = a
+        .chars()
+        .zip(b.chars())
+        .take(4)
+        .take_while(|(a, b)| a == b)
+        .count();
+    sim + prefix as f64 * 0.1 * (1.0 - sim)
+}
+
+fn ngrams(s: &str, n: usize) -> PlHashSet<&str> {
+    let bounds = s
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()))
+        .collect::<Vec<_>>();
+    if s.is_empty() {
+        return PlHashSet::default();
+    }
+    if bounds.len() <= n {
+        // a string shorter than `n` is a single n-gram.
+        return std::iter::once(s).collect();
+    }
+    bounds.windows(n + 1).map(|w| &s[w[0]..w[n]]).collect()
+}
+
+/// The jaccard similarity of the sets of `char` n-grams of two strings, in `[0, 1]`.
+///
+/// # Panics
+/// Panics if `n` is zero.
+pub fn jaccard(a: &str, b: &str, n: usize) -> f64 {
+    assert!(n > 0, "the n-grams should have at least one char");
+    let a = ngrams(a, n);
+    let b = ngrams(b, n);
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+/// Apply `op` to the pairs of strings of `lhs` and `rhs`. The output is null where either input
+/// is null and unit length arrays are broadcasted.
+pub(super) fn binary_str_elementwise<T, F>(
+    lhs: &Utf8Chunked,
+    rhs: &Utf8Chunked,
+    op: F,
+) -> Result<ChunkedArray<T>>
+where
+    T: PolarsNumericType,
+    F: Fn(&str, &str) -> T::Native,
+{
+    let mut out: ChunkedArray<T> = match (lhs.len(), rhs.len()) {
+        (l, r) if l == r => lhs
+            .into_iter()
+            .zip(rhs)
+            .map(|(l, r)| Some(op(l?, r?)))
+            .collect_trusted(),
+        (_, 1) => {
+            let r = rhs.get(0);
+            lhs.into_iter().map(|l| Some(op(l?, r?))).collect_trusted()
+        }
+        (1, _) => {
+            let l = lhs.get(0);
+            rhs.into_iter().map(|r| Some(op(l?, r?))).collect_trusted()
+        }
+        (l, r) => {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot compare strings of arrays with different lengths: {} and {}",
+                    l, r
+                )
+                .into(),
+            ))
+        }
+    };
+    out.rename(lhs.name());
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_string_similarity() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("über", "uber"), 1);
+        assert_eq!(levenshtein_ratio("", ""), 1.0);
+        assert_eq!(levenshtein_ratio("abcd", "abce"), 0.75);
+
+        assert!((jaro("martha", "marhta") - 0.944444).abs() < 1e-6);
+        assert!((jaro_winkler("martha", "marhta") - 0.961111).abs() < 1e-6);
+        assert!((jaro_winkler("dixon", "dicksonx") - 0.813333).abs() < 1e-6);
+        assert_eq!(jaro_winkler("abc", "xyz"), 0.0);
+        assert_eq!(jaro_winkler("same", "same"), 1.0);
+
+        // {ni, ig, gh, ht} and {na, ac, ch, ht}
+        assert_eq!(jaccard("night", "nacht", 2), 1.0 / 7.0);
+        assert_eq!(jaccard("a", "a", 3), 1.0);
+        assert_eq!(jaccard("", "", 2), 1.0);
+        assert_eq!(jaccard("ab", "ba", 1), 1.0);
+    }
+}
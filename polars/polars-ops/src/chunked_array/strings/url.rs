@@ -0,0 +1,143 @@
+//! Kernels to take apart URLs, e.g. of web server logs.
+use polars_core::prelude::*;
+use std::borrow::Cow;
+
+fn hex_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decode the `%XX` escapes and the `+` (as a space) of a URL component. Returns `None` if
+/// the decoded bytes are not valid utf8. Malformed escapes are kept as they are.
+pub fn url_decode(s: &str) -> Option<Cow<'_, str>> {
+    if !s.bytes().any(|b| b == b'%' || b == b'+') {
+        return Some(Cow::Borrowed(s));
+    }
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => match (
+                bytes.get(i + 1).copied().and_then(hex_value),
+                bytes.get(i + 2).copied().and_then(hex_value),
+            ) {
+                (Some(hi), Some(lo)) => {
+                    out.push(hi << 4 | lo);
+                    i += 3;
+                    continue;
+                }
+                _ => out.push(b'%'),
+            },
+            b'+' => out.push(b' '),
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8(out).ok().map(Cow::Owned)
+}
+
+/// Percent encode all bytes except the unreserved characters `A-Z a-z 0-9 - _ . ~`.
+pub fn url_encode(s: &str) -> Cow<'_, str> {
+    let unreserved = |b: u8| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b'~');
+    if s.bytes().all(unreserved) {
+        return Cow::Borrowed(s);
+    }
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    let mut out = String::with_capacity(s.len() * 3);
+    for b in s.bytes() {
+        if unreserved(b) {
+            out.push(b as char)
+        } else {
+            out.push('%');
+            out.push(HEX[(b >> 4) as usize] as char);
+            out.push(HEX[(b & 0xF) as usize] as char);
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// Get the host of a URL, without user info and port, e.g. `"example.com"` for
+/// `"https://user@example.com:8080/path"`. A URL without a scheme is parsed as if it starts
+/// with its host.
+pub fn extract_domain(url: &str) -> Option<&str> {
+    let rest = match url.find("://") {
+        Some(idx) => &url[idx + 3..],
+        None => url.strip_prefix("//").unwrap_or(url),
+    };
+    let authority = rest
+        .split(|c| c == '/' || c == '?' || c == '#')
+        .next()
+        .unwrap_or(rest);
+    let host = match authority.rfind('@') {
+        Some(idx) => &authority[idx + 1..],
+        None => authority,
+    };
+    let host = if let Some(stripped) = host.strip_prefix('[') {
+        // an IPv6 address
+        stripped.split(']').next().unwrap_or(stripped)
+    } else {
+        host.split(':').next().unwrap_or(host)
+    };
+    if host.is_empty() {
+        None
+    } else {
+        Some(host)
+    }
+}
+
+/// Get the decoded value of the first query parameter `name` of a URL.
+/// A parameter without a value has the empty string as value.
+pub fn extract_query_param<'a>(url: &'a str, name: &str) -> Option<Cow<'a, str>> {
+    let query = &url[url.find('?')? + 1..];
+    let query = query.split('#').next().unwrap_or(query);
+    query.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        if url_decode(key)? == name {
+            url_decode(value)
+        } else {
+            None
+        }
+    })
+}
+
+pub(super) fn apply_opt<'a, F, S>(ca: &'a Utf8Chunked, f: F) -> Utf8Chunked
+where
+    F: Fn(&'a str) -> Option<S>,
+    S: AsRef<str>,
+{
+    let mut out: Utf8Chunked = ca.into_iter().map(|opt_s| opt_s.and_then(&f)).collect();
+    out.rename(ca.name());
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_url_encoding() {
+        assert_eq!(url_decode("a%20b+c%2Fd").unwrap(), "a b c/d");
+        assert_eq!(url_decode("100%").unwrap(), "100%");
+        assert_eq!(url_decode("%E2%82%AC").unwrap(), "€");
+        assert!(url_decode("%FF").is_none());
+        assert_eq!(url_encode("a b/€"), "a%20b%2F%E2%82%AC");
+        assert_eq!(url_decode(&url_encode("x=1&y=ü")).unwrap(), "x=1&y=ü");
+    }
+
+    #[test]
+    fn test_extract_url_parts() {
+        let url = "https://user@Example.com:8080/path?q=polars+rs&page=2&flag#top";
+        assert_eq!(extract_domain(url), Some("Example.com"));
+        assert_eq!(extract_domain("example.com/a"), Some("example.com"));
+        assert_eq!(extract_domain("http://[::1]:80/"), Some("::1"));
+        assert_eq!(extract_domain("/relative"), None);
+        assert_eq!(extract_query_param(url, "q").unwrap(), "polars rs");
+        assert_eq!(extract_query_param(url, "flag").unwrap(), "");
+        assert_eq!(extract_query_param(url, "missing"), None);
+    }
+}
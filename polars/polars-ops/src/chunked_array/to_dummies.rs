@@ -1,30 +1,60 @@
 use super::*;
-use polars_core::frame::groupby::IntoGroupsProxy;
+use polars_core::frame::groupby::{GroupsIdx, IntoGroupsProxy};
 use polars_core::utils::Wrap;
 
+/// Options of [`DataFrameOps::to_dummies_with_options`] and
+/// [`SeriesOps::to_dummies_with_options`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DummiesOptions {
+    /// Prefix of the names of the dummy columns, defaults to the name of the encoded column.
+    pub prefix: Option<String>,
+    /// Separator between the prefix and the value in the names of the dummy columns.
+    pub separator: String,
+    /// Drop the dummy column of the first category of every encoded column. The dropped
+    /// category is implied by zeros in all other columns, so this avoids perfectly collinear
+    /// features. The dummy column of the nulls is kept.
+    pub drop_first: bool,
+}
+
+impl Default for DummiesOptions {
+    fn default() -> Self {
+        DummiesOptions {
+            prefix: None,
+            separator: "_".to_string(),
+            drop_first: false,
+        }
+    }
+}
+
+impl DummiesOptions {
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = Some(prefix.to_string());
+        self
+    }
+
+    pub fn with_separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    pub fn with_drop_first(mut self, drop_first: bool) -> Self {
+        self.drop_first = drop_first;
+        self
+    }
+}
+
 pub trait ToDummies<T> {
-    fn to_dummies(&self) -> Result<DataFrame> {
+    fn to_dummies(&self, _options: &DummiesOptions) -> Result<DataFrame> {
         Err(PolarsError::InvalidOperation(
             "to_dummies is not implemented for this dtype".into(),
         ))
     }
 }
 
-#[cfg(feature = "dtype-u8")]
-type DummyType = u8;
-#[cfg(feature = "dtype-u8")]
-type DummyCa = UInt8Chunked;
-
-#[cfg(not(feature = "dtype-u8"))]
-type DummyType = i32;
-#[cfg(not(feature = "dtype-u8"))]
-type DummyCa = Int32Chunked;
-
-fn dummies_helper(mut groups: Vec<IdxSize>, len: usize, name: &str) -> DummyCa {
+fn dummies_helper(mut groups: Vec<IdxSize>, len: usize, name: &str) -> UInt8Chunked {
     groups.sort_unstable();
 
-    // let mut group_member_iter = groups.into_iter();
-    let mut av = vec![0 as DummyType; len];
+    let mut av = vec![0u8; len];
 
     for idx in groups {
         let elem = unsafe { av.get_unchecked_mut(idx as usize) };
@@ -34,59 +64,74 @@ fn dummies_helper(mut groups: Vec<IdxSize>, len: usize, name: &str) -> DummyCa {
     ChunkedArray::from_vec(name, av)
 }
 
-fn sort_columns(mut columns: Vec<Series>) -> Vec<Series> {
-    columns.sort_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
-    columns
+/// Create a dummy column for every group, named by the value that `get_value` returns for the
+/// first index of the group.
+fn dummies_from_groups<F>(
+    groups: GroupsIdx,
+    len: usize,
+    col_name: &str,
+    options: &DummiesOptions,
+    get_value: F,
+) -> DataFrame
+where
+    F: Fn(IdxSize) -> Option<String> + Send + Sync,
+{
+    let prefix = options.prefix.as_deref().unwrap_or(col_name);
+    // the dummies with whether they are the dummy of the nulls
+    let mut columns = groups
+        .into_par_iter()
+        .map(|(first, groups)| {
+            let (name, is_null) = match get_value(first) {
+                Some(val) => (format!("{}{}{}", prefix, options.separator, val), false),
+                None => (format!("{}{}null", prefix, options.separator), true),
+            };
+            (dummies_helper(groups, len, &name).into_series(), is_null)
+        })
+        .collect::<Vec<_>>();
+
+    columns.sort_by(|a, b| a.0.name().partial_cmp(b.0.name()).unwrap());
+    if options.drop_first {
+        if let Some(first) = columns.iter().position(|(_, is_null)| !is_null) {
+            let _ = columns.remove(first);
+        }
+    }
+    DataFrame::new_no_checks(columns.into_iter().map(|(s, _)| s).collect())
 }
 
 impl ToDummies<Utf8Type> for Wrap<Utf8Chunked> {
-    fn to_dummies(&self) -> Result<DataFrame> {
+    fn to_dummies(&self, options: &DummiesOptions) -> Result<DataFrame> {
         let ca = &self.0;
         let groups = ca.group_tuples(true, false).into_idx();
-        let col_name = ca.name();
         let taker = ca.take_rand();
 
-        let columns = groups
-            .into_par_iter()
-            .map(|(first, groups)| {
-                let name = match unsafe { taker.get_unchecked(first as usize) } {
-                    Some(val) => format!("{}_{}", col_name, val),
-                    None => format!("{}_null", col_name),
-                };
-                let ca = dummies_helper(groups, self.len(), &name);
-                ca.into_series()
-            })
-            .collect();
-
-        Ok(DataFrame::new_no_checks(sort_columns(columns)))
+        Ok(dummies_from_groups(
+            groups,
+            ca.len(),
+            ca.name(),
+            options,
+            |first| unsafe { taker.get_unchecked(first as usize) }.map(|val| val.to_string()),
+        ))
     }
 }
 
 #[cfg(feature = "dtype-categorical")]
 impl ToDummies<Utf8Type> for Wrap<CategoricalChunked> {
-    fn to_dummies(&self) -> Result<DataFrame> {
+    fn to_dummies(&self, options: &DummiesOptions) -> Result<DataFrame> {
         let rev_map = self.get_rev_map();
 
         let groups = self.logical().group_tuples(true, false).into_idx();
-        let col_name = self.name();
         let taker = self.logical().take_rand();
 
-        let columns = groups
-            .into_par_iter()
-            .map(|(first, groups)| {
-                let name = match unsafe { taker.get_unchecked(first as usize) } {
-                    Some(val) => {
-                        let name = rev_map.get(val);
-                        format!("{}_{}", col_name, name)
-                    }
-                    None => format!("{}_null", col_name),
-                };
-                let ca = dummies_helper(groups, self.len(), &name);
-                ca.into_series()
-            })
-            .collect();
-
-        Ok(DataFrame::new_no_checks(sort_columns(columns)))
+        Ok(dummies_from_groups(
+            groups,
+            self.len(),
+            self.name(),
+            options,
+            |first| {
+                unsafe { taker.get_unchecked(first as usize) }
+                    .map(|val| rev_map.get(val).to_string())
+            },
+        ))
     }
 }
 
@@ -95,32 +140,57 @@ where
     T: PolarsIntegerType + Sync,
     T::Native: NumericNative,
 {
-    fn to_dummies(&self) -> Result<DataFrame> {
+    fn to_dummies(&self, options: &DummiesOptions) -> Result<DataFrame> {
         let groups = self.group_tuples(true, false).into_idx();
-        let col_name = self.name();
         let taker = self.take_rand();
 
-        let columns = groups
-            .into_par_iter()
-            .map(|(first, groups)| {
-                let name = match unsafe { taker.get_unchecked(first as usize) } {
-                    Some(val) => format!("{}_{}", col_name, val),
-                    None => format!("{}_null", col_name),
-                };
-
-                let ca = dummies_helper(groups, self.len(), &name);
-                ca.into_series()
-            })
-            .collect();
-
-        Ok(DataFrame::new_no_checks(sort_columns(columns)))
+        Ok(dummies_from_groups(
+            groups,
+            self.len(),
+            self.name(),
+            options,
+            |first| unsafe { taker.get_unchecked(first as usize) }.map(|val| val.to_string()),
+        ))
     }
 }
 
 impl<T: PolarsFloatType> ToDummies<Float32Type> for WrapFloat<ChunkedArray<T>> {}
 impl ToDummies<Wrap<BooleanType>> for Wrap<BooleanChunked> {
-    fn to_dummies(&self) -> Result<DataFrame> {
+    fn to_dummies(&self, options: &DummiesOptions) -> Result<DataFrame> {
         let ca = self.cast(&DataType::Int8)?;
-        ca.to_ops().to_dummies()
+        ca.to_ops().to_dummies_with_options(options)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_dummies_options() -> Result<()> {
+        let s = Series::new("a", [Some("x"), Some("y"), None, Some("x")]);
+        let out = s.to_ops().to_dummies()?;
+        assert_eq!(out.get_column_names(), &["a_null", "a_x", "a_y"]);
+        assert!(out.dtypes().iter().all(|dt| dt == &DataType::UInt8));
+        assert_eq!(
+            Vec::from(out.column("a_x")?.u8()?),
+            &[Some(1), Some(0), Some(0), Some(1)]
+        );
+
+        let options = DummiesOptions::default()
+            .with_prefix("b")
+            .with_separator(":")
+            .with_drop_first(true);
+        let out = s.to_ops().to_dummies_with_options(&options)?;
+        assert_eq!(out.get_column_names(), &["b:null", "b:y"]);
+
+        let s = Series::new("i", [3i32, 1, 3]);
+        let out = s.to_ops().to_dummies_with_options(&options)?;
+        assert_eq!(out.get_column_names(), &["b:3"]);
+        assert_eq!(
+            Vec::from(out.column("b:3")?.u8()?),
+            &[Some(1), Some(0), Some(1)]
+        );
+        Ok(())
     }
 }
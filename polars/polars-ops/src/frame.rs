@@ -1,3 +1,6 @@
+#[cfg(feature = "json_normalize")]
+mod json_normalize;
+
 #[cfg(feature = "to_dummies")]
 use polars_core::export::rayon::prelude::*;
 use polars_core::prelude::*;
@@ -8,6 +11,8 @@ use polars_core::POOL;
 
 #[allow(unused_imports)]
 use crate::prelude::*;
+#[cfg(feature = "json_normalize")]
+pub use json_normalize::{json_normalize_schema, NormalizeOptions};
 
 pub trait IntoDf {
     fn to_df(&self) -> &DataFrame;
@@ -69,15 +74,68 @@ pub trait DataFrameOps: IntoDf {
     /// ```
     #[cfg(feature = "to_dummies")]
     fn to_dummies(&self) -> Result<DataFrame> {
+        self.to_dummies_with_options(None, &DummiesOptions::default())
+    }
+
+    /// Create `UInt8` dummy variables of the columns in `subset`, or of all columns if `subset`
+    /// is `None`. Every encoded column is replaced by its dummy columns, the other columns are
+    /// kept as is.
+    ///
+    /// A custom [`DummiesOptions::prefix`] can only be used if a single column is encoded, as
+    /// the names of the dummy columns would clash otherwise.
+    #[cfg(feature = "to_dummies")]
+    fn to_dummies_with_options(
+        &self,
+        subset: Option<&[String]>,
+        options: &DummiesOptions,
+    ) -> Result<DataFrame> {
         let df = self.to_df();
+        if let Some(subset) = subset {
+            for name in subset {
+                df.column(name)?;
+            }
+        }
+        let encode = |name: &str| subset.map_or(true, |subset| subset.iter().any(|s| s == name));
+        let n_encoded = df
+            .get_column_names()
+            .into_iter()
+            .filter(|name| encode(name))
+            .count();
+        if let (Some(prefix), true) = (&options.prefix, n_encoded > 1) {
+            return Err(PolarsError::InvalidOperation(
+                format!(
+                    "cannot use the prefix {:?} for the dummies of {} columns",
+                    prefix, n_encoded
+                )
+                .into(),
+            ));
+        }
 
         let cols = POOL.install(|| {
             df.get_columns()
                 .par_iter()
-                .map(|s| s.to_ops().to_dummies())
+                .map(|s| {
+                    if encode(s.name()) {
+                        s.to_ops().to_dummies_with_options(options)
+                    } else {
+                        Ok(DataFrame::new_no_checks(vec![s.clone()]))
+                    }
+                })
                 .collect::<Result<Vec<_>>>()
         })?;
 
         accumulate_dataframes_horizontal(cols)
     }
+
+    /// Flatten nested `Struct` columns and lists of records (`List` columns of `Struct`s)
+    /// into a flat `DataFrame`, similar to pandas' `json_normalize`.
+    ///
+    /// The fields of a `Struct` column become columns named `{parent}{separator}{field}`.
+    /// A list of records is exploded to a row per record before its fields are flattened;
+    /// if a row has multiple lists of records, they must have the same length.
+    /// Flattening stops after [`NormalizeOptions::max_level`] levels of nesting.
+    #[cfg(feature = "json_normalize")]
+    fn json_normalize(&self, options: &NormalizeOptions) -> Result<DataFrame> {
+        json_normalize::json_normalize(self.to_df(), options)
+    }
 }
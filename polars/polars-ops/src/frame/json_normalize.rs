@@ -0,0 +1,169 @@
+use polars_core::prelude::*;
+
+/// Options of [`DataFrameOps::json_normalize`](crate::frame::DataFrameOps::json_normalize).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NormalizeOptions {
+    /// Separator between the names of the parent and the child in the flattened column names.
+    pub separator: String,
+    /// Maximum number of nesting levels that are flattened, `None` flattens all levels.
+    pub max_level: Option<usize>,
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        NormalizeOptions {
+            separator: ".".to_string(),
+            max_level: None,
+        }
+    }
+}
+
+impl NormalizeOptions {
+    pub fn with_separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    pub fn with_max_level(mut self, max_level: Option<usize>) -> Self {
+        self.max_level = max_level;
+        self
+    }
+
+    fn flatten_level(&self, level: usize) -> bool {
+        self.max_level.map_or(true, |max_level| level < max_level)
+    }
+
+    fn child_name(&self, parent: &str, child: &str) -> String {
+        format!("{}{}{}", parent, self.separator, child)
+    }
+}
+
+fn is_list_of_records(dtype: &DataType) -> bool {
+    matches!(dtype, DataType::List(inner) if matches!(inner.as_ref(), DataType::Struct(_)))
+}
+
+pub(super) fn json_normalize(df: &DataFrame, options: &NormalizeOptions) -> Result<DataFrame> {
+    let mut df = df.clone();
+    let mut level = 0;
+    while options.flatten_level(level) {
+        let records = df
+            .get_columns()
+            .iter()
+            .filter(|s| is_list_of_records(s.dtype()))
+            .map(|s| s.name().to_string())
+            .collect::<Vec<_>>();
+        if !records.is_empty() {
+            df = df.explode(records)?;
+        }
+
+        let mut flattened = false;
+        let mut columns = Vec::with_capacity(df.width());
+        for s in df.get_columns() {
+            if let DataType::Struct(_) = s.dtype() {
+                for field in s.struct_()?.fields() {
+                    let mut field = field.clone();
+                    field.rename(&options.child_name(s.name(), field.name()));
+                    columns.push(field);
+                }
+                flattened = true;
+            } else {
+                columns.push(s.clone())
+            }
+        }
+        if !flattened {
+            break;
+        }
+        df = DataFrame::new(columns)?;
+        level += 1;
+    }
+    Ok(df)
+}
+
+/// Get the schema of the output of
+/// [`DataFrameOps::json_normalize`](crate::frame::DataFrameOps::json_normalize).
+pub fn json_normalize_schema(schema: &Schema, options: &NormalizeOptions) -> Schema {
+    let mut fields = schema
+        .iter()
+        .map(|(name, dtype)| (name.clone(), dtype.clone()))
+        .collect::<Vec<_>>();
+    let mut level = 0;
+    while options.flatten_level(level) {
+        let mut flattened = false;
+        let mut new_fields = Vec::with_capacity(fields.len());
+        for (name, dtype) in fields {
+            let dtype = match dtype {
+                DataType::List(inner) if matches!(inner.as_ref(), DataType::Struct(_)) => *inner,
+                dtype => dtype,
+            };
+            if let DataType::Struct(children) = dtype {
+                for child in children {
+                    new_fields.push((
+                        options.child_name(&name, child.name()),
+                        child.data_type().clone(),
+                    ));
+                }
+                flattened = true;
+            } else {
+                new_fields.push((name, dtype))
+            }
+        }
+        fields = new_fields;
+        if !flattened {
+            break;
+        }
+        level += 1;
+    }
+    let mut schema = Schema::with_capacity(fields.len());
+    for (name, dtype) in fields {
+        schema.with_column(name, dtype)
+    }
+    schema
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use polars_core::df;
+
+    #[test]
+    fn test_json_normalize() -> Result<()> {
+        let address = StructChunked::new("address", &[Series::new("city", ["x", "y"])])?;
+        let user = StructChunked::new(
+            "user",
+            &[Series::new("name", ["a", "b"]), address.into_series()],
+        )?;
+        let record =
+            |k: &[i32]| StructChunked::new("", &[Series::new("k", k)]).map(|ca| ca.into_series());
+        let records = Series::new("records", [record(&[1, 2])?, record(&[3, 4])?]);
+        let df = DataFrame::new(vec![Series::new("id", [1, 2]), user.into_series(), records])?;
+
+        let options = NormalizeOptions::default();
+        let out = df.json_normalize(&options)?;
+        assert_eq!(
+            out.get_column_names(),
+            &["id", "user.name", "user.address.city", "records.k"]
+        );
+        let expected = df![
+            "id" => [1, 1, 2, 2],
+            "user.name" => ["a", "a", "b", "b"],
+            "user.address.city" => ["x", "x", "y", "y"],
+            "records.k" => [1, 2, 3, 4]
+        ]?;
+        assert!(out.frame_equal(&expected));
+        assert_eq!(json_normalize_schema(&df.schema(), &options), out.schema());
+
+        let options = options.with_separator("_").with_max_level(Some(1));
+        let out = df.json_normalize(&options)?;
+        assert_eq!(
+            out.get_column_names(),
+            &["id", "user_name", "user_address", "records_k"]
+        );
+        assert!(matches!(
+            out.column("user_address")?.dtype(),
+            DataType::Struct(_)
+        ));
+        assert_eq!(json_normalize_schema(&df.schema(), &options), out.schema());
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 #[allow(unused_imports)]
 pub(crate) use {crate::series::*, polars_core::export::rayon::prelude::*};
 
+#[cfg(feature = "json_normalize")]
+pub use crate::frame::{json_normalize_schema, NormalizeOptions};
 pub use crate::{chunked_array::*, frame::DataFrameOps, series::*};
@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "to_dummies")]
+use crate::chunked_array::DummiesOptions;
 use std::ops::Deref;
 
 #[cfg(feature = "to_dummies")]
@@ -17,8 +19,15 @@ macro_rules! invalid_operation {
 pub trait SeriesOps {
     fn dtype(&self) -> &DataType;
 
+    /// Create dummy variables with the default [`DummiesOptions`].
     #[cfg(feature = "to_dummies")]
     fn to_dummies(&self) -> Result<DataFrame> {
+        self.to_dummies_with_options(&DummiesOptions::default())
+    }
+
+    /// Create a `UInt8` dummy variable for every unique value.
+    #[cfg(feature = "to_dummies")]
+    fn to_dummies_with_options(&self, _options: &DummiesOptions) -> Result<DataFrame> {
         invalid_operation!(self)
     }
 }
@@ -28,7 +37,7 @@ impl SeriesOps for Series {
         self.deref().dtype()
     }
     #[cfg(feature = "to_dummies")]
-    fn to_dummies(&self) -> Result<DataFrame> {
-        self.to_ops().to_dummies()
+    fn to_dummies_with_options(&self, options: &DummiesOptions) -> Result<DataFrame> {
+        self.to_ops().to_dummies_with_options(options)
     }
 }
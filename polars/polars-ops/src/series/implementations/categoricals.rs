@@ -5,7 +5,7 @@ impl SeriesOps for Wrap<CategoricalChunked> {
         self.0.dtype()
     }
     #[cfg(feature = "to_dummies")]
-    fn to_dummies(&self) -> Result<DataFrame> {
-        ToDummies::to_dummies(self)
+    fn to_dummies_with_options(&self, options: &DummiesOptions) -> Result<DataFrame> {
+        ToDummies::to_dummies(self, options)
     }
 }
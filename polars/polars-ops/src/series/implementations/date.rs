@@ -6,7 +6,7 @@ impl SeriesOps for Wrap<DateChunked> {
         self.0.dtype()
     }
     #[cfg(feature = "to_dummies")]
-    fn to_dummies(&self) -> Result<DataFrame> {
-        ToDummies::to_dummies(self.0.deref())
+    fn to_dummies_with_options(&self, options: &DummiesOptions) -> Result<DataFrame> {
+        ToDummies::to_dummies(self.0.deref(), options)
     }
 }
@@ -7,7 +7,7 @@ impl SeriesOps for Wrap<DatetimeChunked> {
         self.0.dtype()
     }
     #[cfg(feature = "to_dummies")]
-    fn to_dummies(&self) -> Result<DataFrame> {
-        self.0.deref().to_ops().to_dummies()
+    fn to_dummies_with_options(&self, options: &DummiesOptions) -> Result<DataFrame> {
+        self.0.deref().to_ops().to_dummies_with_options(options)
     }
 }
@@ -0,0 +1,209 @@
+use crate::series::ops::SeriesSealed;
+use polars_core::prelude::*;
+
+#[inline]
+fn sip_round(v0: &mut u64, v1: &mut u64, v2: &mut u64, v3: &mut u64) {
+    *v0 = v0.wrapping_add(*v1);
+    *v1 = v1.rotate_left(13) ^ *v0;
+    *v0 = v0.rotate_left(32);
+    *v2 = v2.wrapping_add(*v3);
+    *v3 = v3.rotate_left(16) ^ *v2;
+    *v0 = v0.wrapping_add(*v3);
+    *v3 = v3.rotate_left(21) ^ *v0;
+    *v2 = v2.wrapping_add(*v1);
+    *v1 = v1.rotate_left(17) ^ *v2;
+    *v2 = v2.rotate_left(32);
+}
+
+/// SipHash-2-4 of `data` with the key `(k0, k1)`. Unlike the hashers of the std library
+/// and `ahash`, its output is specified, so the same value always gets the same hash.
+fn siphash24(k0: u64, k1: u64, data: &[u8]) -> u64 {
+    let mut v0 = k0 ^ 0x736f6d6570736575;
+    let mut v1 = k1 ^ 0x646f72616e646f6d;
+    let mut v2 = k0 ^ 0x6c7967656e657261;
+    let mut v3 = k1 ^ 0x7465646279746573;
+
+    let chunks = data.chunks_exact(8);
+    let tail = chunks.remainder();
+    for chunk in chunks {
+        let m = u64::from_le_bytes(chunk.try_into().unwrap());
+        v3 ^= m;
+        sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+        sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+        v0 ^= m;
+    }
+    let mut last = (data.len() as u64) << 56;
+    for (i, b) in tail.iter().enumerate() {
+        last |= (*b as u64) << (8 * i);
+    }
+    v3 ^= last;
+    sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+    sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+    v0 ^= last;
+
+    v2 ^= 0xff;
+    for _ in 0..4 {
+        sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+    }
+    v0 ^ v1 ^ v2 ^ v3
+}
+
+fn mask_str(s: &str, keep_first: usize, keep_last: usize, mask: char) -> String {
+    let n_chars = s.chars().count();
+    if n_chars <= keep_first + keep_last {
+        // nothing would be masked, so mask the whole value
+        return std::iter::repeat(mask).take(n_chars).collect();
+    }
+    s.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if i < keep_first || i >= n_chars - keep_last {
+                c
+            } else {
+                mask
+            }
+        })
+        .collect()
+}
+
+pub trait AnonymizeSeries: SeriesSealed {
+    /// Hash the values with a hash keyed by `salt`, to replace identifiers by tokens.
+    /// The values are hashed by their string representation, and the same value and salt
+    /// give the same token in every run. Without knowing the salt, the tokens can't be
+    /// recomputed from guessed identifiers.
+    #[cfg_attr(docsrs, doc(cfg(feature = "anonymize")))]
+    fn hash_salted(&self, salt: &str) -> Result<UInt64Chunked> {
+        let s = self.as_series();
+        let k0 = siphash24(0, 0, salt.as_bytes());
+        let k1 = siphash24(k0, 0, salt.as_bytes());
+
+        let utf8 = s.cast(&DataType::Utf8)?;
+        let mut out: UInt64Chunked = utf8
+            .utf8()?
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| siphash24(k0, k1, v.as_bytes())))
+            .collect();
+        out.rename(s.name());
+        Ok(out)
+    }
+
+    /// Replace the characters of string values by `mask`, except for the first `keep_first`
+    /// and last `keep_last` characters. Values that are not longer than `keep_first + keep_last`
+    /// characters are masked completely.
+    #[cfg_attr(docsrs, doc(cfg(feature = "anonymize")))]
+    fn mask_chars(&self, keep_first: usize, keep_last: usize, mask: char) -> Result<Utf8Chunked> {
+        let s = self.as_series();
+        let utf8 = s.cast(&DataType::Utf8)?;
+        let mut out: Utf8Chunked = utf8
+            .utf8()?
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| mask_str(v, keep_first, keep_last, mask)))
+            .collect();
+        out.rename(s.name());
+        Ok(out)
+    }
+
+    /// Generalize numeric values to the lower bound of their bucket of size `width`,
+    /// e.g. the ages `31` and `38` both become `30` for a width of `10`.
+    ///
+    /// Integers are generalized with integer arithmetic, so `width` must be a whole number
+    /// for integer dtypes. A lower bound that doesn't fit in the dtype becomes null.
+    #[cfg_attr(docsrs, doc(cfg(feature = "anonymize")))]
+    fn generalize(&self, width: f64) -> Result<Series> {
+        let s = self.as_series();
+        if !s.dtype().is_numeric() {
+            return Err(PolarsError::InvalidOperation(
+                format!("cannot generalize values of dtype {:?}", s.dtype()).into(),
+            ));
+        }
+        if width.is_nan() || width <= 0.0 {
+            return Err(PolarsError::ComputeError(
+                "the bucket width must be positive".into(),
+            ));
+        }
+        let out = match s.dtype() {
+            DataType::Float32 | DataType::Float64 => {
+                let ca = s.cast(&DataType::Float64)?;
+                ca.f64()?
+                    .apply(|v| (v / width).floor() * width)
+                    .into_series()
+            }
+            // floats can't represent all large integers, so they would move values to
+            // another bucket
+            dt => {
+                if width.fract() != 0.0 || width > u64::MAX as f64 {
+                    return Err(PolarsError::ComputeError(
+                        format!(
+                            "the bucket width of values of dtype {:?} must be a whole number",
+                            dt
+                        )
+                        .into(),
+                    ));
+                }
+                if dt.is_signed() {
+                    let ca = s.cast(&DataType::Int64)?;
+                    match i64::try_from(width as u64) {
+                        Ok(width) => ca
+                            .i64()?
+                            .apply_on_opt(|v| v.and_then(|v| v.checked_sub(v.rem_euclid(width))))
+                            .into_series(),
+                        // every value is in the bucket [i64::MIN, i64::MIN + width), whose
+                        // lower bound is below the values of the dtype
+                        Err(_) => Series::full_null(s.name(), s.len(), &DataType::Int64),
+                    }
+                } else {
+                    let width = width as u64;
+                    let ca = s.cast(&DataType::UInt64)?;
+                    ca.u64()?.apply(|v| v - v % width).into_series()
+                }
+            }
+        };
+        out.cast(s.dtype())
+    }
+}
+
+impl AnonymizeSeries for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_siphash24() {
+        // test vector of the reference implementation: key 00..0f, message 00..0e
+        let k0 = u64::from_le_bytes([0, 1, 2, 3, 4, 5, 6, 7]);
+        let k1 = u64::from_le_bytes([8, 9, 10, 11, 12, 13, 14, 15]);
+        let data = (0..15).collect::<Vec<u8>>();
+        assert_eq!(siphash24(k0, k1, &data), 0xa129ca6149be45e5);
+    }
+
+    #[test]
+    fn test_mask_chars() {
+        assert_eq!(
+            mask_str("jane.doe@mail.com", 2, 4, '*'),
+            "ja***********.com"
+        );
+        assert_eq!(mask_str("abc", 2, 2, '#'), "###");
+        assert_eq!(mask_str("ünïcode", 1, 1, '*'), "ü*****e");
+    }
+
+    #[test]
+    fn test_generalize() -> Result<()> {
+        // the large values are not rounded by a cast to float
+        let s = Series::new("a", &[Some(38i64), Some(-1), None, Some(i64::MAX)]);
+        let out = s.generalize(10.0)?;
+        assert_eq!(
+            Vec::from(out.i64()?),
+            &[Some(30), Some(-10), None, Some(9223372036854775800)]
+        );
+        let s = Series::new("a", &[u64::MAX]);
+        let out = s.generalize(10.0)?;
+        assert_eq!(Vec::from(out.u64()?), &[Some(18446744073709551610)]);
+        assert!(s.generalize(2.5).is_err());
+
+        let s = Series::new("a", &[-0.5f64, 7.5]);
+        let out = s.generalize(2.5)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(-2.5), Some(7.5)]);
+        Ok(())
+    }
+}
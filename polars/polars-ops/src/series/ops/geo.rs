@@ -0,0 +1,191 @@
+use polars_core::prelude::*;
+use std::hash::{Hash, Hasher};
+
+/// The mean radius of the earth in kilometers.
+pub const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+fn to_f64(s: &Series, len: usize) -> Result<Float64Chunked> {
+    let s = s.cast(&DataType::Float64)?;
+    let ca = s.f64()?;
+    match ca.len() {
+        l if l == len => Ok(ca.clone()),
+        1 => Ok(ca.expand_at_index(len, 0)),
+        l => Err(PolarsError::ShapeMisMatch(
+            format!(
+                "expected a coordinate column of length {} or 1, got length {}",
+                len, l
+            )
+            .into(),
+        )),
+    }
+}
+
+fn coordinates_len(columns: &[&Series]) -> usize {
+    columns
+        .iter()
+        .map(|s| s.len())
+        .find(|len| *len != 1)
+        .unwrap_or(1)
+}
+
+/// Compute the great-circle distance between the points `(lat1, lon1)` and `(lat2, lon2)`
+/// with the haversine formula. The coordinates are in degrees and the distance is in the
+/// unit of `radius`, e.g. [`EARTH_RADIUS_KM`].
+///
+/// A column of length 1 is broadcasted to the length of the other columns.
+#[cfg_attr(docsrs, doc(cfg(feature = "geo")))]
+pub fn haversine_distance_series(
+    lat1: &Series,
+    lon1: &Series,
+    lat2: &Series,
+    lon2: &Series,
+    radius: f64,
+) -> Result<Series> {
+    let len = coordinates_len(&[lat1, lon1, lat2, lon2]);
+    let lat1 = to_f64(lat1, len)?;
+    let lon1 = to_f64(lon1, len)?;
+    let lat2 = to_f64(lat2, len)?;
+    let lon2 = to_f64(lon2, len)?;
+
+    let mut out: Float64Chunked = lat1
+        .into_iter()
+        .zip(lon1.into_iter())
+        .zip(lat2.into_iter().zip(lon2.into_iter()))
+        .map(|((lat1, lon1), (lat2, lon2))| {
+            let (lat1, lon1, lat2, lon2) = (lat1?, lon1?, lat2?, lon2?);
+            let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+            let d_phi = (lat2 - lat1).to_radians();
+            let d_lambda = (lon2 - lon1).to_radians();
+            let a = (d_phi / 2.0).sin().powi(2)
+                + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+            Some(2.0 * radius * a.sqrt().min(1.0).asin())
+        })
+        .collect_trusted();
+    out.rename("distance");
+    Ok(out.into_series())
+}
+
+/// A polygon given by the `(x, y)` coordinates of its vertices, e.g. `(longitude, latitude)`.
+/// The polygon is closed implicitly, so the first vertex should not be repeated.
+#[derive(Clone, Debug)]
+pub struct Polygon {
+    vertices: Vec<(f64, f64)>,
+    // (min_x, min_y, max_x, max_y), checked before the vertices
+    bounds: (f64, f64, f64, f64),
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<(f64, f64)>) -> Self {
+        let bounds = vertices.iter().fold(
+            (
+                f64::INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::NEG_INFINITY,
+            ),
+            |(min_x, min_y, max_x, max_y), &(x, y)| {
+                (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+            },
+        );
+        Polygon { vertices, bounds }
+    }
+
+    pub fn vertices(&self) -> &[(f64, f64)] {
+        &self.vertices
+    }
+
+    /// Test if the point lies inside the polygon with the even-odd rule.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        let (min_x, min_y, max_x, max_y) = self.bounds;
+        if x < min_x || x > max_x || y < min_y || y > max_y {
+            return false;
+        }
+        let n = self.vertices.len();
+        let mut inside = false;
+        let mut j = n.wrapping_sub(1);
+        for i in 0..n {
+            let (xi, yi) = self.vertices[i];
+            let (xj, yj) = self.vertices[j];
+            if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+                inside = !inside;
+            }
+            j = i;
+        }
+        inside
+    }
+}
+
+impl PartialEq for Polygon {
+    fn eq(&self, other: &Self) -> bool {
+        self.vertices.len() == other.vertices.len()
+            && self
+                .vertices
+                .iter()
+                .zip(&other.vertices)
+                .all(|(a, b)| a.0.to_bits() == b.0.to_bits() && a.1.to_bits() == b.1.to_bits())
+    }
+}
+
+impl Eq for Polygon {}
+
+impl Hash for Polygon {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for (x, y) in &self.vertices {
+            x.to_bits().hash(state);
+            y.to_bits().hash(state);
+        }
+    }
+}
+
+/// Get the index of the first polygon in `polygons` that contains the point `(x, y)`,
+/// or null if no polygon contains it.
+#[cfg_attr(docsrs, doc(cfg(feature = "geo")))]
+pub fn point_in_polygon_series(x: &Series, y: &Series, polygons: &[Polygon]) -> Result<IdxCa> {
+    let len = coordinates_len(&[x, y]);
+    let x = to_f64(x, len)?;
+    let y = to_f64(y, len)?;
+
+    let mut out: IdxCa = x
+        .into_iter()
+        .zip(y.into_iter())
+        .map(|(x, y)| {
+            let (x, y) = (x?, y?);
+            polygons
+                .iter()
+                .position(|polygon| polygon.contains(x, y))
+                .map(|idx| idx as IdxSize)
+        })
+        .collect_trusted();
+    out.rename("polygon");
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_haversine_distance() -> Result<()> {
+        // Paris and London, the second point is broadcasted
+        let lat1 = Series::new("lat", &[48.8566, 51.5074]);
+        let lon1 = Series::new("lon", &[2.3522, -0.1278]);
+        let lat2 = Series::new("lat", &[51.5074]);
+        let lon2 = Series::new("lon", &[-0.1278]);
+        let out = haversine_distance_series(&lat1, &lon1, &lat2, &lon2, EARTH_RADIUS_KM)?;
+        let out = out.f64()?;
+        assert!((out.get(0).unwrap() - 343.56).abs() < 0.1);
+        assert_eq!(out.get(1), Some(0.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_point_in_polygon() -> Result<()> {
+        let square = Polygon::new(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+        let triangle = Polygon::new(vec![(2.0, 0.0), (4.0, 0.0), (3.0, 2.0)]);
+        let x = Series::new("x", &[Some(1.0), Some(3.0), Some(5.0), None]);
+        let y = Series::new("y", &[1.0, 0.5, 0.5, 0.5]);
+        let out = point_in_polygon_series(&x, &y, &[square, triangle])?;
+        assert_eq!(Vec::from(&out), &[Some(0), Some(1), None, None]);
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use crate::series::ops::SeriesSealed;
+use polars_core::prelude::*;
+
+/// Get `bin_count` bins of equal width that span the finite values of `ca`.
+fn equal_width_edges(ca: &Float64Chunked, bin_count: usize) -> Result<Vec<f64>> {
+    if bin_count == 0 {
+        return Err(PolarsError::ComputeError(
+            "the number of bins must be positive".into(),
+        ));
+    }
+    // an infinite value would make all bins infinitely wide
+    let (min, max) = ca
+        .into_iter()
+        .flatten()
+        .filter(|v| v.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            (min.min(v), max.max(v))
+        });
+    if min > max {
+        // no values to bin
+        return Ok(vec![]);
+    }
+    // widen a degenerate range to a width of 1 around the single value, so that the bins
+    // have a positive width. The value falls in the bin that contains the center of the range.
+    let (min, max) = if min == max {
+        (min - 0.5, max + 0.5)
+    } else {
+        (min, max)
+    };
+    let width = (max - min) / bin_count as f64;
+    let mut edges = (0..bin_count)
+        .map(|i| min + i as f64 * width)
+        .collect::<Vec<_>>();
+    edges.push(max);
+    Ok(edges)
+}
+
+fn check_edges(edges: &[f64]) -> Result<()> {
+    if edges.len() < 2 {
+        return Err(PolarsError::ComputeError(
+            "at least two bin edges are needed".into(),
+        ));
+    }
+    if edges
+        .windows(2)
+        .any(|w| w[0].partial_cmp(&w[1]) != Some(std::cmp::Ordering::Less))
+    {
+        return Err(PolarsError::ComputeError(
+            "the bin edges must be strictly increasing".into(),
+        ));
+    }
+    Ok(())
+}
+
+pub trait HistSeries: SeriesSealed {
+    /// Count the values per bin. The bins are given by their `bins` edges, or by a
+    /// `bin_count` of bins of equal width that span the finite values. Exactly one of both must
+    /// be set.
+    ///
+    /// Every bin includes its lower edge and excludes its upper edge, except for the last bin,
+    /// which includes both. Null, NaN and values outside of the bins are not counted, so with
+    /// a `bin_count` infinite values are not counted either.
+    ///
+    /// Returns a struct with the fields `lower` and `upper`, the edges of the bin, and `count`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "hist")))]
+    fn hist(&self, bins: Option<&[f64]>, bin_count: Option<usize>) -> Result<StructChunked> {
+        let s = self.as_series();
+        if !s.to_physical_repr().dtype().is_numeric() {
+            return Err(PolarsError::InvalidOperation(
+                format!("cannot compute a histogram of dtype {:?}", s.dtype()).into(),
+            ));
+        }
+        let values = s.to_physical_repr().cast(&DataType::Float64)?;
+        let values = values.f64()?;
+
+        let edges = match (bins, bin_count) {
+            (Some(bins), None) => {
+                check_edges(bins)?;
+                bins.to_vec()
+            }
+            (None, Some(bin_count)) => equal_width_edges(values, bin_count)?,
+            _ => {
+                return Err(PolarsError::ComputeError(
+                    "either the bin edges or the number of bins must be given".into(),
+                ))
+            }
+        };
+
+        let n_bins = edges.len().saturating_sub(1);
+        let mut counts = vec![0 as IdxSize; n_bins];
+        if n_bins > 0 {
+            let (first, last) = (edges[0], edges[n_bins]);
+            for v in values.into_iter().flatten() {
+                if v >= first && v <= last {
+                    // the number of edges that are <= v is at least 1
+                    let idx = edges.partition_point(|edge| *edge <= v) - 1;
+                    counts[idx.min(n_bins - 1)] += 1;
+                }
+            }
+        }
+
+        let lower = Series::new("lower", &edges[..n_bins]);
+        let upper = Series::new("upper", edges.get(1..).unwrap_or(&[]));
+        let count = IdxCa::from_vec("count", counts).into_series();
+        StructChunked::new(s.name(), &[lower, upper, count])
+    }
+}
+
+impl HistSeries for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hist() -> Result<()> {
+        let s = Series::new("a", &[Some(1.0), Some(2.5), None, Some(3.0), Some(9.0)]);
+        let out = s.hist(Some(&[0.0, 2.0, 3.0]), None)?;
+        assert_eq!(
+            Vec::from(out.field_by_name("count")?.idx()?),
+            &[Some(1), Some(2)]
+        );
+
+        let s = Series::new("a", &[0, 1, 2, 3, 4]);
+        let out = s.hist(None, Some(2))?;
+        let lower = out.field_by_name("lower")?;
+        let upper = out.field_by_name("upper")?;
+        let count = out.field_by_name("count")?;
+        assert_eq!(Vec::from(lower.f64()?), &[Some(0.0), Some(2.0)]);
+        assert_eq!(Vec::from(upper.f64()?), &[Some(2.0), Some(4.0)]);
+        assert_eq!(Vec::from(count.idx()?), &[Some(2), Some(3)]);
+
+        // the infinite value doesn't widen the range
+        let s = Series::new("a", &[Some(0.0), None, Some(f64::INFINITY), Some(4.0)]);
+        let out = s.hist(None, Some(2))?;
+        assert_eq!(
+            Vec::from(out.field_by_name("upper")?.f64()?),
+            &[Some(2.0), Some(4.0)]
+        );
+        assert_eq!(
+            Vec::from(out.field_by_name("count")?.idx()?),
+            &[Some(1), Some(1)]
+        );
+
+        let s = Series::new("a", &[0, 1, 2, 3, 4]);
+        assert!(s.hist(Some(&[1.0, 1.0]), None).is_err());
+        assert!(s.hist(None, None).is_err());
+        Ok(())
+    }
+}
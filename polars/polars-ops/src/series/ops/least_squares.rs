@@ -0,0 +1,323 @@
+use crate::series::ops::SeriesSealed;
+use polars_core::prelude::*;
+
+/// Pivots of the scaled normal equations smaller than this are treated as zero. A pivot is the
+/// fraction of a regressor that is not explained by the regressors before it. The normal
+/// equations square the condition number of the regressors, so this is well above the machine
+/// epsilon.
+const PIVOT_TOLERANCE: f64 = 1e-10;
+
+/// Solve the normal equations `a * b = rhs` for `b` by Gaussian elimination with partial
+/// pivoting. Returns `None` if `a` is singular.
+///
+/// `a` is scaled to a unit diagonal first, so that the tolerance doesn't depend on the units
+/// of the regressors.
+fn solve(mut a: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Option<Vec<f64>> {
+    let n = rhs.len();
+    let scales = (0..n).map(|i| a[i][i].sqrt()).collect::<Vec<_>>();
+    // a regressor that is zero in all observations
+    if scales.iter().any(|scale| *scale == 0.0 || scale.is_nan()) {
+        return None;
+    }
+    for ((a_row, rhs_row), row_scale) in a.iter_mut().zip(rhs.iter_mut()).zip(&scales) {
+        for (v, scale) in a_row.iter_mut().zip(&scales) {
+            *v /= row_scale * scale;
+        }
+        *rhs_row /= row_scale;
+    }
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() <= PIVOT_TOLERANCE || a[pivot][col].is_nan() {
+            return None;
+        }
+        a.swap(col, pivot);
+        rhs.swap(col, pivot);
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        let (rhs_upper, rhs_lower) = rhs.split_at_mut(col + 1);
+        for (a_row, rhs_row) in lower.iter_mut().zip(rhs_lower) {
+            let factor = a_row[col] / pivot_row[col];
+            for (v, p) in a_row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *v -= factor * p;
+            }
+            *rhs_row -= factor * rhs_upper[col];
+        }
+    }
+    let mut b = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| a[row][k] * b[k]).sum();
+        b[row] = (rhs[row] - sum) / a[row][row];
+    }
+    Some(b.iter().zip(&scales).map(|(b, scale)| b / scale).collect())
+}
+
+/// The sums `X'X` and `X'y` of the observations that are added, and their count.
+struct NormalEquations {
+    xtx: Vec<Vec<f64>>,
+    xty: Vec<f64>,
+    n_obs: usize,
+}
+
+impl NormalEquations {
+    fn new(k: usize) -> Self {
+        NormalEquations {
+            xtx: vec![vec![0.0; k]; k],
+            xty: vec![0.0; k],
+            n_obs: 0,
+        }
+    }
+
+    /// Add (`weight == 1.0`) or remove (`weight == -1.0`) an observation.
+    fn update(&mut self, row: &[f64], y: f64, weight: f64) {
+        for ((xtx_row, xty), xa) in self.xtx.iter_mut().zip(self.xty.iter_mut()).zip(row) {
+            for (v, xb) in xtx_row.iter_mut().zip(row) {
+                *v += weight * xa * xb;
+            }
+            *xty += weight * xa * y;
+        }
+        if weight > 0.0 {
+            self.n_obs += 1;
+        } else {
+            self.n_obs -= 1;
+            // drop the rounding errors of the removed observations
+            if self.n_obs == 0 {
+                *self = Self::new(self.xty.len());
+            }
+        }
+    }
+
+    /// Returns `None` if the observations don't determine the coefficients.
+    fn solve(&self) -> Option<Vec<f64>> {
+        if self.n_obs < self.xty.len() {
+            return None;
+        }
+        solve(self.xtx.clone(), self.xty.clone())
+    }
+}
+
+/// The observations of a regression, with the regressors of an observation in a row.
+struct Observations {
+    y: Vec<Option<f64>>,
+    x: Vec<Vec<Option<f64>>>,
+    fit_intercept: bool,
+}
+
+impl Observations {
+    fn new(y: &Series, x: &[Series], fit_intercept: bool) -> Result<Self> {
+        if x.is_empty() {
+            return Err(PolarsError::ComputeError(
+                "least squares needs at least one regressor".into(),
+            ));
+        }
+        let to_vec = |s: &Series| -> Result<Vec<Option<f64>>> {
+            if s.len() != y.len() {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "regressor '{}' has length {}, but the target has length {}",
+                        s.name(),
+                        s.len(),
+                        y.len()
+                    )
+                    .into(),
+                ));
+            }
+            let s = s.cast(&DataType::Float64)?;
+            Ok(s.f64()?.into_iter().collect())
+        };
+        Ok(Observations {
+            y: to_vec(y)?,
+            x: x.iter().map(to_vec).collect::<Result<_>>()?,
+            fit_intercept,
+        })
+    }
+
+    fn n_params(&self) -> usize {
+        self.x.len() + self.fit_intercept as usize
+    }
+
+    /// Write the regressors of observation `i` to `row` and return its target. Returns `None`
+    /// if the observation has a null or NaN value.
+    fn observation(&self, i: usize, row: &mut Vec<f64>) -> Option<f64> {
+        let y = self.y[i].filter(|y| !y.is_nan())?;
+        row.clear();
+        for x in &self.x {
+            row.push(x[i].filter(|v| !v.is_nan())?);
+        }
+        if self.fit_intercept {
+            row.push(1.0);
+        }
+        Some(y)
+    }
+
+    /// Fit the coefficients on all observations. Observations with a null or NaN value are
+    /// ignored. Returns `None` if the remaining observations don't determine the coefficients.
+    fn fit(&self) -> Option<Vec<f64>> {
+        let mut eqs = NormalEquations::new(self.n_params());
+        let mut row = Vec::with_capacity(self.n_params());
+        for i in 0..self.y.len() {
+            if let Some(y) = self.observation(i, &mut row) {
+                eqs.update(&row, y, 1.0);
+            }
+        }
+        eqs.solve()
+    }
+
+    /// Fit the coefficients on every window of `window_size` observations, like
+    /// [`fit`](Observations::fit). The normal equations are updated with the observation that
+    /// enters and the one that leaves the window, instead of summing every window again.
+    fn rolling_fit(&self, window_size: usize) -> Vec<Option<Vec<f64>>> {
+        let mut eqs = NormalEquations::new(self.n_params());
+        let mut row = Vec::with_capacity(self.n_params());
+        (0..self.y.len())
+            .map(|end| {
+                if let Some(y) = self.observation(end, &mut row) {
+                    eqs.update(&row, y, 1.0);
+                }
+                if end >= window_size {
+                    if let Some(y) = self.observation(end - window_size, &mut row) {
+                        eqs.update(&row, y, -1.0);
+                    }
+                }
+                if end + 1 < window_size {
+                    None
+                } else {
+                    eqs.solve()
+                }
+            })
+            .collect()
+    }
+
+    /// Collect fitted coefficients into a struct with a field per regressor, named after
+    /// the regressor, and an `intercept` field if the intercept is fitted.
+    fn into_struct(
+        self,
+        name: &str,
+        x: &[Series],
+        coefficients: Vec<Option<Vec<f64>>>,
+    ) -> Result<StructChunked> {
+        let mut fields = x.iter().map(|s| s.name()).collect::<Vec<_>>();
+        if self.fit_intercept {
+            fields.push("intercept");
+        }
+        let fields = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let mut ca: Float64Chunked = coefficients
+                    .iter()
+                    .map(|coef| coef.as_ref().map(|coef| coef[i]))
+                    .collect();
+                ca.rename(field);
+                ca.into_series()
+            })
+            .collect::<Vec<_>>();
+        StructChunked::new(name, &fields)
+    }
+}
+
+pub trait LeastSquaresSeries: SeriesSealed {
+    /// Fit this series on the regressors `x` with ordinary least squares, with an intercept
+    /// if `fit_intercept` is set. Observations with a null or NaN value are ignored.
+    ///
+    /// Returns a struct of a single row with a field per regressor, named after the regressor,
+    /// and an `intercept` field. The coefficients are null if the observations don't
+    /// determine them.
+    #[cfg_attr(docsrs, doc(cfg(feature = "least_squares")))]
+    fn least_squares(&self, x: &[Series], fit_intercept: bool) -> Result<StructChunked> {
+        let y = self.as_series();
+        let obs = Observations::new(y, x, fit_intercept)?;
+        let coefficients = vec![obs.fit()];
+        obs.into_struct(y.name(), x, coefficients)
+    }
+
+    /// Fit this series on the regressors `x` on every window of `window_size` observations,
+    /// like [`least_squares`](LeastSquaresSeries::least_squares). The coefficients of the
+    /// window that ends at an observation are in the row of that observation, so the first
+    /// `window_size - 1` rows are null.
+    #[cfg_attr(docsrs, doc(cfg(feature = "least_squares")))]
+    fn rolling_least_squares(
+        &self,
+        x: &[Series],
+        window_size: usize,
+        fit_intercept: bool,
+    ) -> Result<StructChunked> {
+        if window_size == 0 {
+            return Err(PolarsError::ComputeError(
+                "the window size must be positive".into(),
+            ));
+        }
+        let y = self.as_series();
+        let obs = Observations::new(y, x, fit_intercept)?;
+        let coefficients = obs.rolling_fit(window_size);
+        obs.into_struct(y.name(), x, coefficients)
+    }
+}
+
+impl LeastSquaresSeries for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn coefficient(out: &StructChunked, name: &str) -> Vec<Option<f64>> {
+        let s = out.field_by_name(name).unwrap();
+        s.f64()
+            .unwrap()
+            .into_iter()
+            .map(|v| v.map(|v| (v * 1e6).round() / 1e6))
+            .collect()
+    }
+
+    #[test]
+    fn test_least_squares() -> Result<()> {
+        // y = 2 * a - b + 1, the null observation is ignored
+        let a = Series::new("a", &[Some(1.0), Some(2.0), Some(3.0), None, Some(0.0)]);
+        let b = Series::new("b", &[1.0, 0.0, 4.0, 3.0, 2.0]);
+        let y = Series::new("y", &[2.0, 5.0, 3.0, 100.0, -1.0]);
+        let out = y.least_squares(&[a.clone(), b], true)?;
+        assert_eq!(coefficient(&out, "a"), &[Some(2.0)]);
+        assert_eq!(coefficient(&out, "b"), &[Some(-1.0)]);
+        assert_eq!(coefficient(&out, "intercept"), &[Some(1.0)]);
+
+        // collinear regressors
+        let mut a2 = &a * 2.0;
+        a2.rename("a2");
+        let out = y.least_squares(&[a, a2], false)?;
+        assert_eq!(coefficient(&out, "a"), &[None]);
+
+        // the tolerance is relative to the scale of the regressors
+        let small = Series::new("small", &[1e-9, 2e-9, 3e-9, 4e-9]);
+        let large = Series::new("large", &[1e9, 0.0, 4e9, 2e9]);
+        let y = &(&small * 2e9) + &(&large * 1e-9);
+        let out = y.least_squares(&[small, large], false)?;
+        assert_eq!(coefficient(&out, "small"), &[Some(2e9)]);
+        let large = out.field_by_name("large")?.f64()?.get(0).unwrap();
+        assert!((large / 1e-9 - 1.0).abs() < 1e-6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_least_squares() -> Result<()> {
+        let x = Series::new("x", &[1.0, 2.0, 3.0, 4.0]);
+        let y = Series::new("y", &[2.0, 4.0, 9.0, 12.0]);
+        let out = y.rolling_least_squares(&[x], 2, true)?;
+        assert_eq!(
+            coefficient(&out, "x"),
+            &[None, Some(2.0), Some(5.0), Some(3.0)]
+        );
+        assert_eq!(
+            coefficient(&out, "intercept"),
+            &[None, Some(0.0), Some(-6.0), Some(0.0)]
+        );
+
+        // a null observation leaves the windows it is in
+        let x = Series::new("x", &[Some(1.0), None, Some(3.0), Some(4.0), Some(5.0)]);
+        let y = Series::new("y", &[2.0, 100.0, 6.0, 8.0, 11.0]);
+        let out = y.rolling_least_squares(&[x], 3, false)?;
+        assert_eq!(
+            coefficient(&out, "x"),
+            &[None, None, Some(2.0), Some(2.0), Some(2.1)]
+        );
+        Ok(())
+    }
+}
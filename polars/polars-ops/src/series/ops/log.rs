@@ -9,6 +9,14 @@ fn exp<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Float64Chunked {
     ca.cast_and_apply_in_place(|v: f64| v.exp())
 }
 
+fn log1p<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Float64Chunked {
+    ca.cast_and_apply_in_place(|v: f64| v.ln_1p())
+}
+
+fn expm1<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Float64Chunked {
+    ca.cast_and_apply_in_place(|v: f64| v.exp_m1())
+}
+
 pub trait LogSeries: SeriesSealed {
     /// Compute the logarithm to a given base
     #[cfg_attr(docsrs, doc(cfg(feature = "log")))]
@@ -46,6 +54,42 @@ pub trait LogSeries: SeriesSealed {
         }
     }
 
+    #[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+    /// Compute the natural logarithm of one plus the values, accurate for values close to zero.
+    fn log1p(&self) -> Series {
+        let s = self.as_series().to_physical_repr();
+        let s = s.as_ref();
+
+        use DataType::*;
+        match s.dtype() {
+            Int32 => log1p(s.i32().unwrap()).into_series(),
+            Int64 => log1p(s.i64().unwrap()).into_series(),
+            UInt32 => log1p(s.u32().unwrap()).into_series(),
+            UInt64 => log1p(s.u64().unwrap()).into_series(),
+            Float32 => s.f32().unwrap().apply(|v| v.ln_1p()).into_series(),
+            Float64 => s.f64().unwrap().apply(|v| v.ln_1p()).into_series(),
+            _ => s.cast(&DataType::Float64).unwrap().log1p(),
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "log")))]
+    /// Calculate `exp(x) - 1` of all elements, accurate for values close to zero.
+    fn expm1(&self) -> Series {
+        let s = self.as_series().to_physical_repr();
+        let s = s.as_ref();
+
+        use DataType::*;
+        match s.dtype() {
+            Int32 => expm1(s.i32().unwrap()).into_series(),
+            Int64 => expm1(s.i64().unwrap()).into_series(),
+            UInt32 => expm1(s.u32().unwrap()).into_series(),
+            UInt64 => expm1(s.u64().unwrap()).into_series(),
+            Float32 => s.f32().unwrap().apply(|v| v.exp_m1()).into_series(),
+            Float64 => s.f64().unwrap().apply(|v| v.exp_m1()).into_series(),
+            _ => s.cast(&DataType::Float64).unwrap().expm1(),
+        }
+    }
+
     /// Compute the entropy as `-sum(pk * log(pk)`.
     /// where `pk` are discrete probabilities.
     #[cfg_attr(docsrs, doc(cfg(feature = "log")))]
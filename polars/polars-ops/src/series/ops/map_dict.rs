@@ -0,0 +1,145 @@
+use crate::series::ops::SeriesSealed;
+use polars_core::prelude::*;
+
+const KEY: &str = "__POLARS_MAP_KEY";
+const VALUE: &str = "__POLARS_MAP_VALUE";
+const FOUND: &str = "__POLARS_MAP_FOUND";
+
+/// What [`MapDictSeries::map_dict`] does with values that are not a key of the mapping.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MapDictDefault {
+    /// Keep the original value.
+    #[default]
+    KeepOriginal,
+    /// Set the value to null.
+    Null,
+    /// Raise an error that names the first value that is not in the mapping.
+    Error,
+}
+
+/// Create the two-column mapping of [`MapDictSeries::map_dict`] from `(key, value)` pairs, e.g.
+/// from a `HashMap`.
+pub fn mapping_from_pairs<I, K, V>(pairs: I) -> DataFrame
+where
+    I: IntoIterator<Item = (K, V)>,
+    Series: NamedFrom<Vec<K>, [K]> + NamedFrom<Vec<V>, [V]>,
+{
+    let (keys, values): (Vec<K>, Vec<V>) = pairs.into_iter().unzip();
+    DataFrame::new_no_checks(vec![Series::new("key", keys), Series::new("value", values)])
+}
+
+pub trait MapDictSeries: SeriesSealed {
+    /// Replace the values with the values of `mapping`, a [`DataFrame`] with a column of unique keys
+    /// and a column of values. The mapping is hash joined on this [`Series`], so the hash table
+    /// is built once instead of a lookup per value. `default` determines what happens to values
+    /// that are not a key of the mapping.
+    fn map_dict(&self, mapping: &DataFrame, default: MapDictDefault) -> Result<Series> {
+        let s = self.as_series();
+        if mapping.width() != 2 {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "the mapping of map_dict must have a column of keys and a column of values, got {} columns",
+                    mapping.width()
+                )
+                .into(),
+            ));
+        }
+        // categoricals of different sources can only be joined on their strings
+        let key_dtype = match s.dtype() {
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(_) => DataType::Utf8,
+            dt => dt.clone(),
+        };
+        let columns = mapping.get_columns();
+        let mut keys = columns[0].strict_cast(&key_dtype)?;
+        if keys.n_unique()? != keys.len() {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "the keys '{}' of the mapping of map_dict must be unique",
+                    keys.name()
+                )
+                .into(),
+            ));
+        }
+        keys.rename(KEY);
+        let mut values = columns[1].clone();
+        values.rename(VALUE);
+        let found = BooleanChunked::full(FOUND, true, keys.len()).into_series();
+        let right = DataFrame::new_no_checks(vec![keys, values, found]);
+
+        let mut left = s.cast(&key_dtype)?;
+        left.rename(KEY);
+        let joined = DataFrame::new_no_checks(vec![left]).left_join(&right, [KEY], [KEY])?;
+        let values = joined.column(VALUE)?;
+        let found = joined.column(FOUND)?.is_not_null();
+
+        let mut out = match default {
+            MapDictDefault::Null => values.clone(),
+            MapDictDefault::KeepOriginal => values.zip_with(&found, s)?,
+            MapDictDefault::Error => {
+                let missing = &!&found & &s.is_not_null();
+                if let Some(idx) = missing.into_iter().position(|m| m == Some(true)) {
+                    return Err(PolarsError::ComputeError(
+                        format!(
+                            "value {} at index {} of '{}' is not a key of the mapping of map_dict",
+                            s.get(idx),
+                            idx,
+                            s.name()
+                        )
+                        .into(),
+                    ));
+                }
+                values.clone()
+            }
+        };
+        out.rename(s.name());
+        Ok(out)
+    }
+}
+
+impl MapDictSeries for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_map_dict() -> Result<()> {
+        let s = Series::new("a", [Some("x"), Some("z"), None, Some("y"), Some("x")]);
+        let mapping = mapping_from_pairs([("x", 1i32), ("y", 2)]);
+
+        let out = s.map_dict(&mapping, MapDictDefault::Null)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(1), None, None, Some(2), Some(1)]
+        );
+        let out = s.map_dict(&mapping, MapDictDefault::KeepOriginal)?;
+        assert_eq!(out.dtype(), &DataType::Utf8);
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("1"), Some("z"), None, Some("2"), Some("1")]
+        );
+        let err = s
+            .map_dict(&mapping, MapDictDefault::Error)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("\"z\" at index 1"));
+        assert!(s
+            .slice(2, 3)
+            .map_dict(&mapping, MapDictDefault::Error)
+            .is_ok());
+
+        // the keys are cast to the dtype of the series
+        let s = Series::new("i", [3i64, 1, 2, 1]);
+        let mapping = mapping_from_pairs([(1i32, "one"), (2, "two")]);
+        let out = s.map_dict(&mapping, MapDictDefault::Null)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[None, Some("one"), Some("two"), Some("one")]
+        );
+        let duplicates = mapping_from_pairs([(1i32, "a"), (1, "b")]);
+        assert!(s.map_dict(&duplicates, MapDictDefault::Null).is_err());
+        Ok(())
+    }
+}
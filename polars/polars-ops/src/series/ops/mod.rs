@@ -1,16 +1,48 @@
+#[cfg(feature = "anonymize")]
+mod anonymize;
+#[cfg(feature = "geo")]
+mod geo;
+#[cfg(feature = "hist")]
+mod hist;
+#[cfg(feature = "least_squares")]
+mod least_squares;
 #[cfg(feature = "log")]
 mod log;
+#[cfg(feature = "map_dict")]
+mod map_dict;
+#[cfg(feature = "replace")]
+mod replace;
+#[cfg(feature = "rle")]
+mod rle;
 #[cfg(feature = "rolling_window")]
 mod rolling;
 mod various;
+#[cfg(feature = "weighted")]
+mod weighted;
 
+#[cfg(feature = "anonymize")]
+pub use anonymize::*;
+#[cfg(feature = "geo")]
+pub use geo::*;
+#[cfg(feature = "hist")]
+pub use hist::*;
+#[cfg(feature = "least_squares")]
+pub use least_squares::*;
 #[cfg(feature = "log")]
 pub use log::*;
+#[cfg(feature = "map_dict")]
+pub use map_dict::*;
 use polars_core::prelude::*;
+#[cfg(feature = "replace")]
+pub use replace::*;
+#[cfg(feature = "rle")]
+pub use rle::*;
 
 #[cfg(feature = "rolling_window")]
 pub use rolling::*;
 pub use various::*;
+#[cfg(feature = "weighted")]
+pub use weighted::*;
 
 pub trait SeriesSealed {
     fn as_series(&self) -> &Series;
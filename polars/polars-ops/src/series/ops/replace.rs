@@ -0,0 +1,80 @@
+use crate::series::ops::{MapDictDefault, MapDictSeries, SeriesSealed};
+use polars_core::prelude::*;
+
+/// The mapping of `old` to `new`, where a `new` of length 1 is broadcasted.
+fn replace_mapping(old: &Series, new: &Series) -> Result<DataFrame> {
+    let new = match (old.len(), new.len()) {
+        (o, n) if o == n => new.clone(),
+        (o, 1) => new.expand_at_index(0, o),
+        (o, n) => {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot replace {} old values with {} new values, the lengths must match or new must have length 1",
+                    o, n
+                )
+                .into(),
+            ))
+        }
+    };
+    Ok(DataFrame::new_no_checks(vec![old.clone(), new]))
+}
+
+pub trait ReplaceSeries: SeriesSealed {
+    /// Replace the values that are in `old` by the value at the same index of `new`. All other
+    /// values are kept, so the output dtype is the supertype of this [`Series`] and `new`.
+    fn replace(&self, old: &Series, new: &Series) -> Result<Series> {
+        let mapping = replace_mapping(old, new)?;
+        self.as_series()
+            .map_dict(&mapping, MapDictDefault::KeepOriginal)
+    }
+
+    /// Replace every value by the value of `new` at its index in `old`, and raise an error if a
+    /// value is not in `old`. The output has the dtype of `new`, or
+    /// `return_dtype` if given.
+    fn replace_strict(
+        &self,
+        old: &Series,
+        new: &Series,
+        return_dtype: Option<&DataType>,
+    ) -> Result<Series> {
+        let mapping = replace_mapping(old, new)?;
+        let out = self.as_series().map_dict(&mapping, MapDictDefault::Error)?;
+        match return_dtype {
+            Some(dtype) => out.strict_cast(dtype),
+            None => Ok(out),
+        }
+    }
+}
+
+impl ReplaceSeries for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_replace() -> Result<()> {
+        let s = Series::new("a", [Some(1i32), Some(2), None, Some(3)]);
+        let old = Series::new("", [1i32, 3]);
+
+        let out = s.replace(&old, &Series::new("", [10i32, 30]))?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.i32()?), &[Some(10), Some(2), None, Some(30)]);
+        // a different dtype and a broadcasted new value
+        let out = s.replace(&old, &Series::new("", [0.5f64]))?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(0.5), Some(2.0), None, Some(0.5)]
+        );
+        assert!(s.replace(&old, &Series::new("", [1i32, 2, 3])).is_err());
+
+        let new = Series::new("", ["one", "three"]);
+        assert!(s.replace_strict(&old, &new, None).is_err());
+        let old = Series::new("", [1i32, 2, 3]);
+        let new = Series::new("", [1u8, 2, 3]);
+        let out = s.replace_strict(&old, &new, Some(&DataType::Int64))?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(1), Some(2), None, Some(3)]);
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use crate::series::ops::SeriesSealed;
+use polars_core::prelude::*;
+
+/// Get the indices at which the runs of equal consecutive values start.
+/// Null values are equal to each other.
+fn run_starts(s: &Series) -> Result<Vec<IdxSize>> {
+    if s.is_empty() {
+        return Ok(vec![]);
+    }
+    let same = s.eq_missing(&s.shift(1))?;
+    let mut starts = vec![0 as IdxSize];
+    starts.extend(
+        same.into_iter()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, same)| !same.unwrap_or(false))
+            .map(|(idx, _)| idx as IdxSize),
+    );
+    Ok(starts)
+}
+
+pub trait RleSeries: SeriesSealed {
+    /// Encode the runs of equal consecutive values as a struct with the fields `value`,
+    /// the value of the run, and `length`, the number of values in the run.
+    #[cfg_attr(docsrs, doc(cfg(feature = "rle")))]
+    fn rle(&self) -> Result<StructChunked> {
+        let s = self.as_series();
+        let starts = run_starts(s)?;
+        let lengths = starts
+            .iter()
+            .zip(
+                starts
+                    .iter()
+                    .skip(1)
+                    .chain(std::iter::once(&(s.len() as IdxSize))),
+            )
+            .map(|(start, end)| end - start)
+            .collect::<Vec<_>>();
+
+        let starts = IdxCa::from_vec("", starts);
+        // Safety: the starts are indices of `s`
+        let mut values = unsafe { s.take_unchecked(&starts)? };
+        values.rename("value");
+        let lengths = IdxCa::from_vec("length", lengths).into_series();
+        StructChunked::new(s.name(), &[values, lengths])
+    }
+
+    /// Assign an id to every run of equal consecutive values. The ids count up from `0`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "rle")))]
+    fn rle_id(&self) -> Result<IdxCa> {
+        let s = self.as_series();
+        let same = s.eq_missing(&s.shift(1))?;
+        let mut id = 0 as IdxSize;
+        let ids = same
+            .into_iter()
+            .enumerate()
+            .map(|(idx, same)| {
+                if idx > 0 && !same.unwrap_or(false) {
+                    id += 1;
+                }
+                id
+            })
+            .collect::<Vec<_>>();
+        Ok(IdxCa::from_vec(s.name(), ids))
+    }
+}
+
+impl RleSeries for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rle() -> Result<()> {
+        let s = Series::new("a", &[Some(1), Some(1), None, None, Some(2), Some(1)]);
+        let ids = s.rle_id()?;
+        assert_eq!(
+            Vec::from(&ids),
+            &[0, 0, 1, 1, 2, 3].map(|v: IdxSize| Some(v))
+        );
+
+        let out = s.rle()?;
+        let values = out.field_by_name("value")?;
+        let lengths = out.field_by_name("length")?;
+        assert_eq!(Vec::from(values.i32()?), &[Some(1), None, Some(2), Some(1)]);
+        assert_eq!(
+            Vec::from(lengths.idx()?),
+            &[2, 2, 1, 1].map(|v: IdxSize| Some(v))
+        );
+        Ok(())
+    }
+}
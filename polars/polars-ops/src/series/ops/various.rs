@@ -6,12 +6,16 @@ use polars_core::export::ahash;
 
 pub trait SeriesMethods: SeriesSealed {
     /// Create a [`DataFrame`] with the unique `values` of this [`Series`] and a column `"counts"`
-    /// with dtype [`IdxType`]
+    /// with dtype [`IdxType`]. The values are in the order of their first occurrence if
+    /// `sorted` is set or in the [deterministic mode](polars_core::config::deterministic).
     fn value_counts(&self, multithreaded: bool, sorted: bool) -> Result<DataFrame> {
         let s = self.as_series().to_physical_repr();
         let s = s.as_ref();
         // we need to sort here as well in case of `maintain_order` because duplicates behavior is undefined
-        let groups = s.group_tuples(multithreaded, sorted);
+        let groups = s.group_tuples(
+            multithreaded,
+            sorted || polars_core::config::deterministic(),
+        );
         let values = unsafe { s.agg_first(&groups) };
         let counts = groups.group_lengths("counts");
         let cols = vec![values.into_series(), counts.into_series()];
@@ -0,0 +1,113 @@
+use polars_core::prelude::*;
+
+/// The `(value, weight)` pairs where both the value and the weight are not null.
+fn weighted_pairs(values: &Series, weights: &Series) -> Result<Vec<(f64, f64)>> {
+    if values.len() != weights.len() {
+        return Err(PolarsError::ShapeMisMatch(
+            format!(
+                "the weights have length {}, but the values have length {}",
+                weights.len(),
+                values.len()
+            )
+            .into(),
+        ));
+    }
+    let values = values.cast(&DataType::Float64)?;
+    let weights = weights.cast(&DataType::Float64)?;
+
+    let mut pairs = Vec::with_capacity(values.len());
+    for (v, w) in values.f64()?.into_iter().zip(weights.f64()?.into_iter()) {
+        if let (Some(v), Some(w)) = (v, w) {
+            if w < 0.0 {
+                return Err(PolarsError::ComputeError(
+                    "weights must not be negative".into(),
+                ));
+            }
+            pairs.push((v, w))
+        }
+    }
+    Ok(pairs)
+}
+
+/// Compute the mean of `values` weighted by `weights`.
+///
+/// Pairs where the value or the weight is null are ignored. Returns `None` if the weights
+/// of the remaining pairs sum to zero.
+#[cfg_attr(docsrs, doc(cfg(feature = "weighted")))]
+pub fn weighted_mean(values: &Series, weights: &Series) -> Result<Option<f64>> {
+    let pairs = weighted_pairs(values, weights)?;
+    let (weighted_sum, total_weight) = pairs
+        .iter()
+        .fold((0.0, 0.0), |(sum, total), (v, w)| (sum + v * w, total + w));
+    if total_weight == 0.0 {
+        Ok(None)
+    } else {
+        Ok(Some(weighted_sum / total_weight))
+    }
+}
+
+/// Compute the `quantile` of `values` weighted by `weights`: the smallest value for which
+/// the weights of the values that are not larger sum to at least `quantile` of the total weight.
+/// With equal weights this is the `lower` quantile interpolation.
+///
+/// Pairs where the value or the weight is null are ignored. Returns `None` if the weights
+/// of the remaining pairs sum to zero.
+#[cfg_attr(docsrs, doc(cfg(feature = "weighted")))]
+pub fn weighted_quantile(values: &Series, weights: &Series, quantile: f64) -> Result<Option<f64>> {
+    if !(0.0..=1.0).contains(&quantile) {
+        return Err(PolarsError::ComputeError(
+            "quantile should be between 0.0 and 1.0".into(),
+        ));
+    }
+    let mut pairs = weighted_pairs(values, weights)?;
+    pairs.retain(|(_, w)| *w > 0.0);
+    pairs.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+    let total_weight: f64 = pairs.iter().map(|(_, w)| w).sum();
+    let threshold = quantile * total_weight;
+    let mut cum_weight = 0.0;
+    for (v, w) in &pairs {
+        cum_weight += w;
+        if cum_weight >= threshold {
+            return Ok(Some(*v));
+        }
+    }
+    // rounding errors may leave the cumulative weight just below the threshold
+    Ok(pairs.last().map(|(v, _)| *v))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_weighted_mean() -> Result<()> {
+        let values = Series::new("v", &[Some(1.0), Some(2.0), None, Some(4.0)]);
+        let weights = Series::new("w", &[Some(1), Some(1), Some(10), None]);
+        assert_eq!(weighted_mean(&values, &weights)?, Some(1.5));
+
+        let weights = Series::new("w", &[3, 1, 1, 0]);
+        assert_eq!(weighted_mean(&values, &weights)?, Some(1.25));
+
+        let weights = Series::new("w", &[0, 0, 1, 0]);
+        assert_eq!(weighted_mean(&values, &weights)?, None);
+
+        let weights = Series::new("w", &[1, -1, 1, 0]);
+        assert!(weighted_mean(&values, &weights).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_weighted_quantile() -> Result<()> {
+        let values = Series::new("v", &[4.0, 1.0, 3.0, 2.0]);
+        let weights = Series::new("w", &[1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(weighted_quantile(&values, &weights, 0.5)?, Some(2.0));
+        assert_eq!(weighted_quantile(&values, &weights, 0.0)?, Some(1.0));
+        assert_eq!(weighted_quantile(&values, &weights, 1.0)?, Some(4.0));
+
+        let weights = Series::new("w", &[5.0, 1.0, 1.0, 1.0]);
+        assert_eq!(weighted_quantile(&values, &weights, 0.5)?, Some(4.0));
+        assert!(weighted_quantile(&values, &weights, 1.5).is_err());
+        Ok(())
+    }
+}
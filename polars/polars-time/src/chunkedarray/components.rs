@@ -0,0 +1,176 @@
+//! Extract all date and time components of temporal data at once.
+use super::*;
+use chrono::{Datelike, Timelike};
+use polars_arrow::export::arrow::temporal_conversions::{
+    date32_to_datetime, timestamp_ms_to_datetime, timestamp_ns_to_datetime,
+    timestamp_us_to_datetime,
+};
+
+const DATE_COMPONENTS: [&str; 3] = ["year", "month", "day"];
+const TIME_COMPONENTS: [&str; 4] = ["hour", "minute", "second", "nanosecond"];
+
+/// The [`DataType::Struct`] of the components of data of the given `dtype`. The year is an
+/// `Int32`, all other components are `UInt32`.
+pub fn temporal_components_dtype(dtype: &DataType) -> DataType {
+    let with_time = !matches!(dtype, DataType::Date);
+    let fields = DATE_COMPONENTS
+        .iter()
+        .chain(TIME_COMPONENTS.iter().take(if with_time { 4 } else { 0 }))
+        .map(|name| {
+            let dtype = if *name == "year" {
+                DataType::Int32
+            } else {
+                DataType::UInt32
+            };
+            Field::new(name, dtype)
+        })
+        .collect();
+    DataType::Struct(fields)
+}
+
+/// Convert every value once and collect the components of it.
+fn components<T, F>(
+    name: &str,
+    ca: &ChunkedArray<T>,
+    to_datetime: F,
+    with_time: bool,
+) -> StructChunked
+where
+    T: PolarsNumericType,
+    F: Fn(T::Native) -> NaiveDateTime,
+{
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let len = arr.len();
+    let mut year = Vec::with_capacity(len);
+    let mut others = (0..if with_time { 6 } else { 2 })
+        .map(|_| Vec::with_capacity(len))
+        .collect::<Vec<Vec<u32>>>();
+
+    // the values behind the null slots may be out of range, so these are not converted
+    let masked = NaiveDateTime::from_timestamp(0, 0);
+    for v in arr.iter() {
+        let dt = v.map_or(masked, |v| to_datetime(*v));
+        year.push(dt.year());
+        others[0].push(dt.month());
+        others[1].push(dt.day());
+        if with_time {
+            others[2].push(dt.hour());
+            others[3].push(dt.minute());
+            others[4].push(dt.second());
+            others[5].push(dt.nanosecond());
+        }
+    }
+    let validity = arr.validity().cloned();
+    let mut fields = Vec::with_capacity(others.len() + 1);
+    fields.push(
+        Int32Chunked::new_from_owned_with_null_bitmap("year", year, validity.clone()).into_series(),
+    );
+    let names = DATE_COMPONENTS[1..].iter().chain(TIME_COMPONENTS.iter());
+    for (name, values) in names.zip(others) {
+        fields.push(
+            UInt32Chunked::new_from_owned_with_null_bitmap(name, values, validity.clone())
+                .into_series(),
+        );
+    }
+    StructChunked::new(name, &fields).unwrap()
+}
+
+#[cfg(feature = "dtype-date")]
+pub(crate) fn date_components(ca: &DateChunked) -> StructChunked {
+    components(ca.name(), ca, date32_to_datetime, false)
+}
+
+#[cfg(feature = "dtype-datetime")]
+pub(crate) fn datetime_components(ca: &DatetimeChunked) -> StructChunked {
+    if ca.time_zone().is_some() {
+        // the components are local to the time zone, so use the kernels that handle them
+        let fields = [
+            ca.year().into_series(),
+            ca.month().into_series(),
+            ca.day().into_series(),
+            ca.hour().into_series(),
+            ca.minute().into_series(),
+            ca.second().into_series(),
+            ca.nanosecond().into_series(),
+        ];
+        let fields = fields
+            .into_iter()
+            .zip(DATE_COMPONENTS.iter().chain(TIME_COMPONENTS.iter()))
+            .map(|(mut s, name)| {
+                s.rename(name);
+                s
+            })
+            .collect::<Vec<_>>();
+        return StructChunked::new(ca.name(), &fields).unwrap();
+    }
+    let to_datetime = match ca.time_unit() {
+        TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+        TimeUnit::Microseconds => timestamp_us_to_datetime,
+        TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+    };
+    components(ca.name(), ca, to_datetime, true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use polars_arrow::export::arrow::bitmap::Bitmap;
+
+    #[test]
+    #[cfg(all(feature = "dtype-date", feature = "dtype-datetime"))]
+    fn test_components() {
+        let dates = [
+            Some(NaiveDate::from_ymd(2021, 3, 4).and_hms_nano(5, 6, 7, 8)),
+            None,
+            Some(NaiveDate::from_ymd(1960, 12, 31).and_hms(23, 59, 59)),
+        ];
+        let ca = DatetimeChunked::from_naive_datetime_options(
+            "dt",
+            dates.iter().copied(),
+            TimeUnit::Nanoseconds,
+        );
+        let out = ca.components();
+        assert_eq!(out.name(), "dt");
+        assert_eq!(
+            out.clone().into_series().dtype(),
+            &temporal_components_dtype(ca.dtype())
+        );
+        let fields = out.fields();
+        assert_eq!(
+            Vec::from(fields[0].i32().unwrap()),
+            &[Some(2021), None, Some(1960)]
+        );
+        assert_eq!(
+            Vec::from(fields[2].u32().unwrap()),
+            &[Some(4), None, Some(31)]
+        );
+        assert_eq!(
+            Vec::from(fields[6].u32().unwrap()),
+            &[Some(8), None, Some(0)]
+        );
+        assert_eq!(fields[4].name(), "minute");
+
+        // 2021-03-04 and 1969-12-31
+        let ca = Int32Chunked::new("d", &[Some(18690), None, Some(-1)]).into_date();
+        let out = ca.components();
+        assert_eq!(out.fields().len(), 3);
+        assert_eq!(
+            Vec::from(out.fields()[1].u32().unwrap()),
+            &[Some(3), None, Some(12)]
+        );
+
+        // the value behind a null slot is out of range
+        let ca = Int64Chunked::new_from_owned_with_null_bitmap(
+            "dt",
+            vec![0, i64::MAX],
+            Some(Bitmap::from([true, false])),
+        )
+        .into_datetime(TimeUnit::Milliseconds, None);
+        let out = ca.components();
+        assert_eq!(
+            Vec::from(out.fields()[0].i32().unwrap()),
+            &[Some(1970), None]
+        );
+    }
+}
@@ -48,6 +48,10 @@ pub trait DateMethods {
     /// The return value ranges from 1 to 366. (The last day of year differs by years.)
     fn ordinal(&self) -> UInt32Chunked;
 
+    /// Extract the year, month and day in a single pass over the data.
+    #[cfg(feature = "dtype-struct")]
+    fn components(&self) -> StructChunked;
+
     fn parse_from_str_slice(name: &str, v: &[&str], fmt: &str) -> DateChunked;
 }
 
@@ -93,6 +97,11 @@ impl DateMethods for DateChunked {
         self.apply_kernel_cast::<UInt32Type>(&date_to_ordinal)
     }
 
+    #[cfg(feature = "dtype-struct")]
+    fn components(&self) -> StructChunked {
+        super::components::date_components(self)
+    }
+
     fn parse_from_str_slice(name: &str, v: &[&str], fmt: &str) -> DateChunked {
         Int32Chunked::from_iter_options(
             name,
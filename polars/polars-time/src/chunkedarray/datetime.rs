@@ -119,6 +119,13 @@ pub trait DatetimeMethods: AsDatetime {
         ca.apply_kernel_cast::<UInt32Type>(&f)
     }
 
+    /// Extract the year, month, day, hour, minute, second and nanosecond in a single pass
+    /// over the data.
+    #[cfg(feature = "dtype-struct")]
+    fn components(&self) -> StructChunked {
+        super::components::datetime_components(self.as_datetime())
+    }
+
     fn parse_from_str_slice(name: &str, v: &[&str], fmt: &str, tu: TimeUnit) -> DatetimeChunked {
         let func = match tu {
             TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
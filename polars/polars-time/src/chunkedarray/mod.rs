@@ -1,4 +1,6 @@
 //! Traits and utilities for temporal data.
+#[cfg(feature = "dtype-struct")]
+mod components;
 #[cfg(feature = "dtype-date")]
 mod date;
 #[cfg(feature = "dtype-datetime")]
@@ -12,6 +14,8 @@ mod time;
 pub mod utf8;
 
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+#[cfg(feature = "dtype-struct")]
+pub use components::temporal_components_dtype;
 #[cfg(feature = "dtype-date")]
 pub use date::DateMethods;
 #[cfg(feature = "dtype-datetime")]
@@ -4,6 +4,7 @@ mod date_range;
 mod groupby;
 pub mod prelude;
 pub mod series;
+mod session;
 mod truncate;
 mod upsample;
 mod windows;
@@ -12,6 +13,7 @@ mod windows;
 pub use groupby::dynamic::*;
 
 pub use {
-    date_range::*, truncate::*, upsample::*, windows::calendar::date_range as date_range_vec,
-    windows::duration::Duration, windows::groupby::ClosedWindow, windows::window::Window,
+    date_range::*, session::session_id, truncate::*, upsample::*,
+    windows::calendar::date_range as date_range_vec, windows::duration::Duration,
+    windows::groupby::ClosedWindow, windows::window::Window,
 };
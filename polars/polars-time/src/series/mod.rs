@@ -121,6 +121,21 @@ impl AsSeries for Series {
 }
 
 pub trait TemporalMethods: AsSeries {
+    /// Extract the date (and time) components at once, see [`temporal_components_dtype`].
+    #[cfg(feature = "dtype-struct")]
+    fn components(&self) -> Result<StructChunked> {
+        let s = self.as_series();
+        match s.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => s.date().map(|ca| ca.components()),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) => s.datetime().map(|ca| ca.components()),
+            _ => Err(PolarsError::InvalidOperation(
+                format!("operation not supported on dtype {:?}", s.dtype()).into(),
+            )),
+        }
+    }
+
     /// Extract hour from underlying NaiveDateTime representation.
     /// Returns the hour number from 0 to 23.
     fn hour(&self) -> Result<UInt32Chunked> {
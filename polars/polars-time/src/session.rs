@@ -0,0 +1,129 @@
+use crate::prelude::*;
+use crate::windows::calendar::{NS_MICROSECOND, NS_MILLISECOND};
+use polars_core::export::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
+use polars_core::prelude::*;
+
+/// Assign a session id to every value: a new session starts at a value that is more than
+/// `gap` later than the previous value. The session ids count up from `0`.
+///
+/// The values must be sorted in ascending order, else an error is returned. Null values get a
+/// null session id and don't end a session. `s` may be a `Datetime` or a `Date` column, with
+/// a temporal `gap` like `"30m"`, or an integer column, with an index `gap` like `"10i"`.
+/// The `gap` must not be negative and can't use months, as their length varies.
+///
+/// To assign sessions per user, use this in a window expression over the user column.
+pub fn session_id(s: &Series, gap: Duration) -> Result<IdxCa> {
+    if gap.months() != 0 {
+        return Err(PolarsError::ComputeError(
+            "the gap of a session cannot have a month or year component".into(),
+        ));
+    }
+    if gap.negative && !gap.is_zero() {
+        return Err(PolarsError::ComputeError(
+            "the gap of a session cannot be negative".into(),
+        ));
+    }
+    let is_integer = matches!(
+        s.dtype(),
+        DataType::Int32 | DataType::Int64 | DataType::UInt32 | DataType::UInt64
+    );
+    if is_integer != gap.parsed_int {
+        return Err(PolarsError::ComputeError(
+            format!(
+                "the gap of sessions of values of dtype {:?} must be {} duration",
+                s.dtype(),
+                if is_integer {
+                    "an index, like \"10i\""
+                } else {
+                    "a temporal, like \"30m\""
+                }
+            )
+            .into(),
+        ));
+    }
+    let nsecs = gap.nanoseconds();
+    let (values, gap) = match s.dtype() {
+        DataType::Datetime(tu, _) => {
+            let gap = match tu {
+                TimeUnit::Nanoseconds => nsecs,
+                TimeUnit::Microseconds => nsecs / NS_MICROSECOND,
+                TimeUnit::Milliseconds => nsecs / NS_MILLISECOND,
+            };
+            (s.cast(&DataType::Int64)?, gap)
+        }
+        DataType::Date => {
+            let ms = s.cast(&DataType::Int64)?.i64()? * MILLISECONDS_IN_DAY;
+            (ms.into_series(), nsecs / NS_MILLISECOND)
+        }
+        DataType::Int32 | DataType::Int64 | DataType::UInt32 | DataType::UInt64 => {
+            (s.cast(&DataType::Int64)?, nsecs)
+        }
+        dt => {
+            return Err(PolarsError::InvalidOperation(
+                format!("cannot assign sessions to values of dtype {:?}", dt).into(),
+            ))
+        }
+    };
+
+    let mut session = 0 as IdxSize;
+    let mut prev = None;
+    let mut out: IdxCa = values
+        .i64()?
+        .into_iter()
+        .map(|opt_v| {
+            let v = match opt_v {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+            if let Some(prev) = prev {
+                if v < prev {
+                    return Err(PolarsError::ComputeError(
+                        "the values of sessions must be sorted in ascending order".into(),
+                    ));
+                }
+                if v - prev > gap {
+                    session += 1;
+                }
+            }
+            prev = Some(v);
+            Ok(Some(session))
+        })
+        .collect::<Result<_>>()?;
+    out.rename(s.name());
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_session_id() -> Result<()> {
+        let s = Series::new(
+            "t",
+            &[Some(0i64), Some(5), None, Some(20), Some(24), Some(40)],
+        );
+        let out = session_id(&s, Duration::parse("5i"))?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(0), Some(0), None, Some(1), Some(1), Some(2)]
+        );
+
+        let s = s.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+        let out = session_id(&s, Duration::parse("10ms"))?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(0), Some(0), None, Some(1), Some(1), Some(2)]
+        );
+        assert!(session_id(&s, Duration::parse("1mo")).is_err());
+        assert!(session_id(&s, Duration::parse("-10ms")).is_err());
+        // the unit of the gap must match the dtype
+        assert!(session_id(&s, Duration::parse("10i")).is_err());
+        let s = Series::new("t", &[0i64, 5]);
+        assert!(session_id(&s, Duration::parse("10ms")).is_err());
+
+        let s = Series::new("t", &[Some(0i64), None, Some(5), Some(3)]);
+        assert!(session_id(&s, Duration::parse("5i")).is_err());
+        Ok(())
+    }
+}
@@ -5,6 +5,8 @@ mod error;
 mod functions;
 pub mod mem;
 pub mod sort;
+#[cfg(target_family = "wasm")]
+pub mod wasm;
 
 pub use functions::*;
 
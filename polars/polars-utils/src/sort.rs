@@ -1,5 +1,11 @@
 use crate::IdxSize;
-use rayon::{prelude::*, ThreadPool};
+use rayon::prelude::*;
+
+#[cfg(not(target_family = "wasm"))]
+use rayon::ThreadPool;
+
+#[cfg(target_family = "wasm")]
+use crate::wasm::Pool as ThreadPool;
 
 /// This is a perfect sort particularly useful for an argsort of an argsort
 /// The second argsort sorts indices from `0` to `len` so can be just assigned to the
@@ -0,0 +1,39 @@
+//! `wasm32-unknown-unknown` can't spawn threads, so there is no rayon thread pool to
+//! build. [`Pool`] stands in for `rayon::ThreadPool` and runs everything on the
+//! calling thread. Parallel iterators use the global rayon pool, which falls back to
+//! the calling thread as well if it can't spawn threads.
+
+pub struct Pool;
+
+impl Pool {
+    pub fn current_num_threads(&self) -> usize {
+        1
+    }
+
+    pub fn install<OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        op()
+    }
+
+    pub fn spawn<OP>(&self, op: OP)
+    where
+        OP: FnOnce() + Send + 'static,
+    {
+        op()
+    }
+
+    pub fn join<A, B, RA, RB>(&self, oper_a: A, oper_b: B) -> (RA, RB)
+    where
+        A: FnOnce() -> RA + Send,
+        B: FnOnce() -> RB + Send,
+        RA: Send,
+        RB: Send,
+    {
+        let a = oper_a();
+        let b = oper_b();
+        (a, b)
+    }
+}
@@ -85,7 +85,7 @@
 //! let reverse = vec![true, false];
 //!
 //! let sorted = df.lazy()
-//!     .sort_by_exprs(vec![col("b"), col("a")], reverse)
+//!     .sort_by_exprs(vec![col("b"), col("a")], reverse, false, Default::default())
 //!     .collect()?;
 //!
 //! // sorted:
@@ -150,6 +150,17 @@
 //! Both of these really put strain on compile times. To keep Polars lean, we make both **opt-in**,
 //! meaning that you only pay the compilation cost, if you need it.
 //!
+//! The fastest build has only eager `DataFrame`s and csv IO:
+//!
+//! ```toml
+//! polars = { version = "0.23", default-features = false, features = ["minimal"] }
+//! ```
+//!
+//! Heavy dependencies are only compiled by the features that need them: `regex` by `strings`,
+//! `lazy_regex` and `csv-file`, `chrono` by `temporal`, `chrono-tz` by `timezones` and the
+//! parquet reader by `parquet`. Operations that are available in eager and lazy only compile
+//! their lazy part if `lazy` is activated as well.
+//!
 //! ## Compile times and opt-in features
 //! The opt-in features are (not including dtype features):
 //!
@@ -163,6 +174,9 @@
 //! * `timezones` - Activate timezone support.
 //! * `strings` - Extra string utilities for `Utf8Chunked`
 //!     - `string_justify` - `zfill`, `ljust`, `rjust`
+//!     - `string_similarity` - `levenshtein`, `jaro_winkler`, `jaccard` similarity of strings
+//!     - `ip` - Parse IPv4/IPv6 addresses and test if they lie in a network.
+//!     - `url` - Encode and decode URL components, extract domains and query parameters.
 //! * `object` - Support for generic ChunkedArrays called `ObjectChunked<T>` (generic over `T`).
 //!              These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -179,11 +193,14 @@
 //!                 Can be used for JSON and more serde supported serialization formats.
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
+//!     - `fwf` - Read fixed-width text files
 //!     - `ipc` - Arrow's IPC format serialization
-//!     - `decompress` - Automatically infer compression of csv-files and decompress them.
+//!     - `decompress` - Automatically infer compression of csv and json files and decompress them.
+//!                      Also allows writing compressed csv files.
 //!                      Supported compressions:
-//!                         * zip
+//!                         * zlib
 //!                         * gzip
+//!                         * zstd
 //!
 //! * `DataFrame` operations:
 //!     - `dynamic_groupby` - Groupby based on a time window instead of predefined keys.
@@ -193,6 +210,7 @@
 //!                And activates `pivot` and `transpose` operations
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
 //!     - `cross_join` - Create the cartesian product of two DataFrames.
+//!     - `join_where` - Join on inequality predicates.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
 //!     - `groupby_list` - Allow groupby operation on keys of type List.
 //!     - `row_hash` - Utility to hash DataFrame rows to UInt64Chunked
@@ -200,6 +218,7 @@
 //!     - `horizontal_concat` - Concat horizontally and extend with null values if lengths don't match
 //!     - `dataframe_arithmetic` - Arithmetic on (Dataframe and DataFrames) and (DataFrame on Series)
 //!     - `partition_by` - Split into multiple DataFrames partitioned by groups.
+//!     - `json_normalize` - Flatten nested `Struct` columns and lists of records.
 //! * `Series`/`Expression` operations:
 //!     - `is_in` - [Check for membership in `Series`](crate::chunked_array::ops::IsIn)
 //!     - `zip_with` - [Zip two Series/ ChunkedArrays](crate::chunked_array::ops::ChunkZip)
@@ -207,7 +226,7 @@
 //!     - `repeat_by` - [Repeat element in an Array N times, where N is given by another array.
 //!     - `is_first` - Check if element is first unique value.
 //!     - `is_last` - Check if element is last unique value.
-//!     - `checked_arithmetic` - checked arithmetic/ returning `None` on invalid operations.
+//!     - `checked_arithmetic` - checked arithmetic/ returning `None` on invalid operations and integer arithmetic with an `OverflowPolicy`.
 //!     - `dot_product` - Dot/inner product on Series and Expressions.
 //!     - `concat_str` - Concat string data in linear time.
 //!     - `reinterpret` - Utility to reinterpret bits to signed/unsigned
@@ -228,6 +247,14 @@
 //!     - `pct_change` - Compute change percentages.
 //!     - `unique_counts` - Count unique values in expressions.
 //!     - `log` - Logarithms for `Series`.
+//!     - `geo` - Haversine distances and point-in-polygon tests on coordinate columns.
+//!     - `anonymize` - Salted hashing, masking and generalization of values.
+//!     - `rle` - Run-length encoding and ids of runs of equal values.
+//!     - `weighted` - Weighted mean and weighted quantile aggregations.
+//!     - `hist` - Count the values per bin.
+//!     - `least_squares` - Ordinary least squares regression, also over rolling windows.
+//!     - `map_dict` - Remap values with a mapping of keys to values.
+//!     - `replace` - Replace values by other values, optionally strictly.
 //!     - `list_to_struct` - Convert `List` to `Struct` dtypes.
 //!     - `list_eval` - Apply expressions over list elements.
 //!     - `cumulative_eval` - Apply expressions over cumulatively increasing windows.
@@ -235,8 +262,12 @@
 //!     - `date_offset` Add an offset to dates that take months and leap years into account.
 //!     - `trigonometry` Trigonometric functions.
 //!     - `sign` Compute the element-wise sign of a Series.
+//!     - `special_functions` The error function `erf` and the log-gamma function `lgamma`.
 //! * `DataFrame` pretty printing
 //!     - `fmt` - Activate DataFrame formatting
+//! * Observability
+//!     - `tracing` - Emit [`tracing`](https://docs.rs/tracing) spans with timings and row counts
+//!                   for the optimizer passes, the operations of a query and the file readers.
 //!
 //! ## Compile times and opt-in data types
 //! As mentioned above, Polars `Series` are wrappers around
@@ -294,11 +325,15 @@
 //! ```
 //! ## Config with ENV vars
 //!
+//! The formatting related variables can also be set with [`config::FmtConfig`].
+//!
 //! * `POLARS_FMT_NO_UTF8` -> use ascii tables in favor of utf8.
 //! * `POLARS_FMT_MAX_COLS` -> maximum number of columns shown when formatting DataFrames.
 //! * `POLARS_FMT_MAX_ROWS` -> maximum number of rows shown when formatting DataFrames.
 //! * `POLARS_FMT_STR_LEN` -> maximum number of characters printed per string value.
 //! * `POLARS_TABLE_WIDTH` -> width of the tables used during DataFrame formatting.
+//! * `POLARS_FMT_FLOAT_PRECISION` -> number of decimals shown for floating point values.
+//! * `POLARS_FMT_THOUSANDS_SEPARATOR` -> character used to group the digits of numeric values per thousand.
 //! * `POLARS_MAX_THREADS` -> maximum number of threads used to initialize thread pool (on startup).
 //! * `POLARS_VERBOSE` -> print logging info to stderr
 //! * `POLARS_NO_PARTITION` -> Polars may choose to partition the groupby operation, based on data
@@ -308,20 +343,68 @@
 //! * `POLARS_PARTITION_UNIQUE_COUNT` -> at which (estimated) key count a partitioned groupby should run.
 //!                                          defaults to `1000`, any higher cardinality will run default groupby.
 //! * `POLARS_FORCE_PARTITION` -> Force partitioned groupby if the keys and aggregations allow it.
+//! * `POLARS_HASH_PARTITION_UNIQUE_COUNT` -> at which (estimated) key count the groupby partitions the data by the hash
+//!                                          of the keys and aggregates the partitions one by one. defaults to `1000000`.
+//! * `POLARS_HASH_PARTITION_COUNT` -> the number of partitions of a hash partitioned groupby.
+//! * `POLARS_GROUPBY_SPILL_DIR` -> write the partitions of a hash partitioned groupby to this directory before aggregating them.
+//!                                 requires the `ipc` feature.
 //! * `POLARS_ALLOW_EXTENSION` -> allows for `[ObjectChunked<T>]` to be used in arrow, opening up possibilities like using
 //!                               `T` in complex lazy expressions. However this does require `unsafe` code allow this.
 //! * `POLARS_NO_PARQUET_STATISTICS` -> if set, statistics in parquet files are ignored.
+//! * `POLARS_DETERMINISTIC` -> use order-stable groupbys, distincts and lazy joins. See [`config::deterministic`]
+//!                             for the operations that are not covered.
+//!
+//! ## Compile for WASM
+//! `polars-core` and the `csv-file` and `ipc` readers compile on `wasm32-unknown-unknown`, so
+//! polars can be embedded in the browser with `wasm-bindgen`:
+//!
+//! ```toml
+//! [dependencies]
+//! polars = { version = "0.23", default-features = false, features = ["csv-file", "ipc"] }
+//! ```
+//!
+//! ```text
+//! cargo build --target wasm32-unknown-unknown
+//! ```
+//!
+//! There are no threads on this target, so the thread pool runs all work on the calling thread
+//! and `POLARS_MAX_THREADS` has no effect. Files are not memory mapped but read into memory.
 //!
 //! ## User Guide
 //! If you want to read more, [check the User Guide](https://pola-rs.github.io/polars-book/).
 #![cfg_attr(docsrs, feature(doc_cfg))]
+
+#[cfg(all(
+    not(feature = "lazy"),
+    any(
+        feature = "lazy_regex",
+        feature = "arange",
+        feature = "true_div",
+        feature = "dot_diagram",
+        feature = "list_eval",
+        feature = "cumulative_eval",
+        feature = "arg_where",
+        feature = "date_offset",
+        feature = "trigonometry",
+        feature = "sign",
+        feature = "special_functions",
+    )
+))]
+compile_error!(
+    "the features 'lazy_regex', 'arange', 'true_div', 'dot_diagram', 'list_eval', \
+    'cumulative_eval', 'arg_where', 'date_offset', 'trigonometry', 'sign' and 'special_functions' \
+    only extend the lazy API, activate the 'lazy' feature as well"
+);
+
 pub mod docs;
 pub mod export;
 pub mod prelude;
 
 #[cfg(feature = "dtype-categorical")]
 pub use polars_core::toggle_string_cache;
-pub use polars_core::{chunked_array, datatypes, doc, error, frame, functions, series, testing};
+pub use polars_core::{
+    chunked_array, config, datatypes, doc, error, frame, functions, series, testing,
+};
 #[cfg(feature = "temporal")]
 pub use polars_time as time;
 
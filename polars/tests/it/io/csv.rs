@@ -521,6 +521,139 @@ fn test_comment_lines() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_skip_rows_after_header_with_comments() -> Result<()> {
+    let csv = r"a,b
+# this is a comment
+1,2
+3,4
+# this is also a comment
+5,6
+";
+
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_comment_char(Some(b'#'))
+        .with_skip_rows_after_header(1)
+        .finish()?;
+    assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(3), Some(5)]);
+    Ok(())
+}
+
+#[test]
+fn test_ragged_lines() -> Result<()> {
+    let csv = r"a,b,c
+1,2,3
+4,5
+6,7,8,9
+10,11,12
+";
+    let file = Cursor::new(csv);
+
+    let df = CsvReader::new(file.clone()).finish()?;
+    assert_eq!(df.shape(), (4, 3));
+    assert_eq!(df.column("c")?.null_count(), 1);
+
+    let df = CsvReader::new(file.clone())
+        .with_ragged_lines(RaggedLines::Error)
+        .finish();
+    assert!(df.is_err());
+    let df = CsvReader::new(file.clone())
+        .with_ragged_lines(RaggedLines::Truncate)
+        .finish();
+    assert!(df.is_err());
+
+    let (df, rejected) = CsvReader::new(file)
+        .with_ragged_lines(RaggedLines::Reject)
+        .finish_with_rejected()?;
+    assert_eq!(Vec::from(df.column("a")?.i64()?), &[Some(1), Some(10)]);
+    assert_eq!(
+        Vec::from(rejected.column("line")?.utf8()?),
+        &[Some("4,5"), Some("6,7,8,9")]
+    );
+
+    let csv = r"a,b
+1,2,3
+4,5
+";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_ragged_lines(RaggedLines::Truncate)
+        .finish()?;
+    assert_eq!(Vec::from(df.column("b")?.i64()?), &[Some(2), Some(5)]);
+    Ok(())
+}
+
+#[test]
+fn test_encodings() -> Result<()> {
+    let csv = "name,city\nJos\u{e9},K\u{f6}ln\nAnna,Z\u{fc}rich\n";
+    let expected = ["Jos\u{e9}", "Anna"];
+
+    let latin1 = csv.chars().map(|c| c as u8).collect::<Vec<_>>();
+    let df = CsvReader::new(Cursor::new(latin1))
+        .with_encoding(CsvEncoding::Latin1)
+        .finish()?;
+    assert_eq!(Vec::from(df.column("name")?.utf8()?), expected.map(Some));
+    assert_eq!(df.column("city")?.utf8()?.get(1), Some("Z\u{fc}rich"));
+
+    // with a byte order mark
+    let utf16le = "\u{feff}"
+        .encode_utf16()
+        .chain(csv.encode_utf16())
+        .flat_map(|u| u.to_le_bytes())
+        .collect::<Vec<_>>();
+    let df = CsvReader::new(Cursor::new(utf16le))
+        .with_encoding(CsvEncoding::Utf16Le)
+        .finish()?;
+    assert_eq!(Vec::from(df.column("name")?.utf8()?), expected.map(Some));
+
+    let utf16be = csv
+        .encode_utf16()
+        .flat_map(|u| u.to_be_bytes())
+        .collect::<Vec<_>>();
+    let df = CsvReader::new(Cursor::new(utf16be.clone()))
+        .with_encoding(CsvEncoding::Utf16Be)
+        .finish()?;
+    assert_eq!(
+        Vec::from(df.column("city")?.utf8()?),
+        &[Some("K\u{f6}ln"), Some("Z\u{fc}rich")]
+    );
+
+    // only the lines that are read are transcoded, a quoted line ending does not end a line
+    let csv = "name,city\n\"Jos\u{e9}\nA\",K\u{f6}ln\nAnna,Z\u{fc}rich\n";
+    let utf16le = csv
+        .encode_utf16()
+        .flat_map(|u| u.to_le_bytes())
+        .collect::<Vec<_>>();
+    let df = CsvReader::new(Cursor::new(utf16le))
+        .with_encoding(CsvEncoding::Utf16Le)
+        .with_n_rows(Some(1))
+        .finish()?;
+    assert_eq!(
+        Vec::from(df.column("name")?.utf8()?),
+        &[Some("Jos\u{e9}\nA")]
+    );
+
+    // comments, empty lines and rejected lines don't count as read rows
+    let csv = "name,city\n# K\u{f6}ln\n\nJos\u{e9},K\u{f6}ln,1\nAnna,Z\u{fc}rich\nBen,Bern\n";
+    let latin1 = csv.chars().map(|c| c as u8).collect::<Vec<_>>();
+    let df = CsvReader::new(Cursor::new(latin1))
+        .with_encoding(CsvEncoding::Latin1)
+        .with_comment_char(Some(b'#'))
+        .with_ragged_lines(RaggedLines::Reject)
+        .with_n_rows(Some(2))
+        .finish()?;
+    assert_eq!(
+        Vec::from(df.column("name")?.utf8()?),
+        &[Some("Anna"), Some("Ben")]
+    );
+
+    let out = CsvReader::new(Cursor::new(&utf16be[1..]))
+        .with_encoding(CsvEncoding::Utf16Be)
+        .finish();
+    assert!(out.is_err());
+    Ok(())
+}
+
 #[test]
 fn test_null_values_argument() -> Result<()> {
     let csv = r"1,a,foo
@@ -1036,3 +1169,68 @@ fn test_parse_dates_3380() -> Result<()> {
     assert_eq!(df.column("validdate")?.null_count(), 0);
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "decompress")]
+fn test_compressed_csv() -> Result<()> {
+    let mut df = df![
+        "a" => [1i64, 2, 3],
+        "b" => ["x", "y", "z"]
+    ]?;
+
+    for compression in [
+        CsvCompression::Gzip,
+        CsvCompression::Zlib,
+        CsvCompression::Zstd,
+        CsvCompression::Bzip2,
+    ] {
+        let mut buf = vec![];
+        CsvWriter::new(&mut buf)
+            .with_compression(Some(compression))
+            .finish(&mut df)?;
+        assert!(polars::io::csv::utils::is_compressed(&buf));
+
+        let out = CsvReader::new(Cursor::new(buf.clone())).finish()?;
+        assert!(out.frame_equal(&df));
+        let out = CsvReader::new(Cursor::new(buf.clone()))
+            .with_n_rows(Some(2))
+            .finish()?;
+        assert!(out.frame_equal(&df.head(Some(2))));
+
+        #[cfg(feature = "lazy")]
+        {
+            let path =
+                std::env::temp_dir().join(format!("polars_compressed_{:?}.csv", compression));
+            std::fs::write(&path, &buf)?;
+            let out = LazyCsvReader::new(path.to_string_lossy().to_string())
+                .finish()?
+                .filter(col("a").gt(lit(1)))
+                .collect()?;
+            std::fs::remove_file(&path)?;
+            assert!(out.frame_equal(&df.tail(Some(2))));
+        }
+    }
+
+    // a csv that starts like the header of bzip2 is not mistaken for it
+    let df = CsvReader::new(Cursor::new(b"BZh,a\n1,2\n".to_vec())).finish()?;
+    assert_eq!(df.get_column_names(), &["BZh", "a"]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_csv_dir() -> Result<()> {
+    let dir = DirectoryScan::new("../examples/datasets")
+        .recursive(false)
+        .with_include("foods*.csv")
+        .with_exclude("foods5.csv")
+        .with_filter(|path| !path.ends_with("foods4.csv"))
+        .with_path_column(Some("source"));
+    let df = LazyCsvReader::new_dir(dir)?.finish()?.collect()?;
+    assert_eq!(df.shape(), (81, 5));
+
+    let source = df.column("source")?.utf8()?;
+    assert!(source.get(0).unwrap().ends_with("foods1.csv"));
+    assert!(source.get(80).unwrap().ends_with("foods3.csv"));
+    Ok(())
+}
@@ -1,5 +1,7 @@
+use polars::io::RowCount;
 use polars::prelude::*;
 use std::io::Cursor;
+use std::path::PathBuf;
 
 #[test]
 fn test_vstack_empty_3220() -> Result<()> {
@@ -21,11 +23,161 @@ fn test_vstack_empty_3220() -> Result<()> {
 #[test]
 fn test_scan_parquet_files() -> Result<()> {
     let files_to_load_set = vec![
-        "../examples/datasets/foods1.parquet".to_string(),
-        "../examples/datasets/foods2.parquet".to_string(),
+        PathBuf::from("../examples/datasets/foods1.parquet"),
+        PathBuf::from("../examples/datasets/foods2.parquet"),
     ];
 
-    let df = LazyFrame::scan_parquet_files(files_to_load_set, Default::default())?.collect()?;
+    let df =
+        LazyFrame::scan_parquet_files(files_to_load_set.clone(), Default::default())?.collect()?;
     assert_eq!(df.shape(), (54, 4));
+
+    // the row counts and limits are computed per file
+    let args = ScanArgsParquet {
+        n_rows: Some(30),
+        row_count: Some(RowCount {
+            name: "row_nr".into(),
+            offset: 10,
+        }),
+        ..Default::default()
+    };
+    let df = LazyFrame::scan_parquet_files(files_to_load_set, args)?.collect()?;
+    assert_eq!(df.shape(), (30, 5));
+    let row_nr = df.column("row_nr")?.idx()?;
+    assert_eq!(row_nr.get(0), Some(10));
+    assert_eq!(row_nr.get(29), Some(39));
+    Ok(())
+}
+
+#[test]
+fn test_parquet_field_metadata() -> Result<()> {
+    let metadata: FieldMetadata = [("unit".to_string(), "km".to_string())].into();
+    let mut a = Series::new("a", [1.0, 2.0]);
+    a.set_metadata(metadata.clone());
+    let mut df = DataFrame::new(vec![a, Series::new("b", [1, 2])])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.column("a")?.metadata(), metadata);
+    assert!(read_df.column("b")?.metadata().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_parquet_frame_metadata() -> Result<()> {
+    let mut df = df! {
+        "a" => [1, 2, 3]
+    }?;
+    let metadata: FrameMetadata = [("source".to_string(), "sensors.csv".to_string())].into();
+    df.set_metadata(metadata.clone());
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(1))
+        .finish(&mut df)?;
+    assert_eq!(df.metadata(), &metadata);
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.metadata(), &metadata);
+    Ok(())
+}
+
+#[test]
+fn test_parquet_writer_options() -> Result<()> {
+    use polars::export::arrow::io::parquet::read::{
+        get_page_iterator, read_metadata, ColumnChunkMetaData,
+    };
+    use polars::export::arrow::io::parquet::write::Encoding;
+
+    let mut df = df! {
+        "a" => (0..1000).collect::<Vec<i64>>(),
+        "b" => (0..1000).map(|i| if i % 2 == 0 { "x" } else { "y" }).collect::<Vec<_>>()
+    }?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_compression(ParquetCompression::Zstd(Some(
+            ZstdLevel::try_new(10).unwrap(),
+        )))
+        .with_row_group_size(Some(300))
+        .with_data_page_size(Some(1024))
+        .with_dictionary_encoding("b", true)
+        .finish(&mut df)?;
+
+    let metadata = read_metadata(&mut buf)?;
+    assert_eq!(metadata.row_groups.len(), 4);
+    let row_group = &metadata.row_groups[0];
+    assert!(row_group.num_rows() <= 300);
+    let column_a = &row_group.columns()[0];
+    let column_b = &row_group.columns()[1];
+    let is_dictionary_encoded = |column: &ColumnChunkMetaData| {
+        column
+            .column_chunk()
+            .meta_data
+            .as_ref()
+            .unwrap()
+            .encodings
+            .iter()
+            .any(|e| matches!(Encoding::try_from(*e), Ok(Encoding::RleDictionary)))
+    };
+    assert!(!is_dictionary_encoded(column_a));
+    assert!(is_dictionary_encoded(column_b));
+    // 250 rows of i64 in pages of 1024 bytes
+    let n_pages = get_page_iterator(column_a, &mut buf, None, vec![])?.count();
+    assert_eq!(n_pages, 2);
+    let n_pages = get_page_iterator(column_b, &mut buf, None, vec![])?.count();
+    assert_eq!(n_pages, 1);
+
+    buf.set_position(0);
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert!(read_df.frame_equal(&df));
+
+    let mut buf = Cursor::new(Vec::new());
+    let err = ParquetWriter::new(&mut buf)
+        .with_dictionary_encoding("c", true)
+        .finish(&mut df);
+    assert!(err.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parquet_parallel_strategies() -> Result<()> {
+    let mut df = df! {
+        "a" => (0..100).collect::<Vec<i32>>(),
+        "b" => (0..100).map(|i| i as f64).collect::<Vec<_>>(),
+        "c" => (0..100).map(|i| i.to_string()).collect::<Vec<_>>()
+    }?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(10))
+        .finish(&mut df)?;
+
+    for parallel in [
+        ParallelStrategy::None,
+        ParallelStrategy::Columns,
+        ParallelStrategy::RowGroups,
+        ParallelStrategy::RowGroupsAndColumns,
+        ParallelStrategy::Auto,
+    ] {
+        buf.set_position(0);
+        let out = ParquetReader::new(&mut buf)
+            .read_parallel(parallel)
+            .finish()?;
+        assert!(out.frame_equal(&df));
+
+        buf.set_position(0);
+        let out = ParquetReader::new(&mut buf)
+            .read_parallel(parallel)
+            .with_n_rows(Some(25))
+            .with_row_count(Some(RowCount {
+                name: "row_nr".to_string(),
+                offset: 0,
+            }))
+            .finish()?;
+        assert_eq!(out.shape(), (25, 4));
+        assert_eq!(
+            Vec::from(out.column("row_nr")?.idx()?),
+            (0..25).map(Some).collect::<Vec<_>>()
+        );
+    }
     Ok(())
 }
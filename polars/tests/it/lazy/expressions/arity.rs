@@ -349,3 +349,55 @@ fn test_ternary_aggregation_set_literals() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "checked_arithmetic")]
+fn test_arithmetic_overflow_policy() -> Result<()> {
+    let df = df![
+        "a" => [i32::MAX, 1, -5],
+        "b" => [1i32, 2, 3]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("a")
+                .checked_add(col("b"), OverflowPolicy::Wrap)
+                .alias("wrap"),
+            col("a")
+                .checked_add(col("b"), OverflowPolicy::Null)
+                .alias("null"),
+            col("a")
+                .checked_add(lit(1i32), OverflowPolicy::Saturate)
+                .alias("saturate"),
+            col("a")
+                .checked_mul(col("b"), OverflowPolicy::Promote)
+                .alias("promote"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("wrap")?.i32()?),
+        &[Some(i32::MIN), Some(3), Some(-2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("null")?.i32()?),
+        &[None, Some(3), Some(-2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("saturate")?.i32()?),
+        &[Some(i32::MAX), Some(2), Some(-4)]
+    );
+    assert_eq!(
+        Vec::from(out.column("promote")?.i64()?),
+        &[Some(i32::MAX as i64), Some(2), Some(-15)]
+    );
+
+    let out = df
+        .lazy()
+        .select([col("a").checked_sub(lit(1i32), OverflowPolicy::Error)])
+        .collect();
+    assert!(out.is_ok());
+    Ok(())
+}
@@ -18,3 +18,33 @@ fn test_is_in() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_is_in_list_column() -> Result<()> {
+    let df = df![
+        "x" => [1, 2, 3],
+        "lists" => [
+            Series::new("", [1.0, 2.0]),
+            Series::new("", [1.0]),
+            Series::new("", [3.0, 4.0]),
+        ]
+    ]?;
+    let list_lit = Series::new("", [Series::new("", [2, 3])]);
+
+    let out = df
+        .lazy()
+        .select([
+            col("x").is_in(col("lists")).alias("row_wise"),
+            col("x").is_in(lit(list_lit)).alias("literal"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("row_wise")?.bool()?),
+        &[Some(true), Some(false), Some(true)]
+    );
+    assert_eq!(
+        Vec::from(out.column("literal")?.bool()?),
+        &[Some(false), Some(true), Some(true)]
+    );
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use super::*;
+
+#[test]
+fn test_map_dict_expr() -> Result<()> {
+    let df = df![
+        "country" => ["NL", "DE", "BE", "NL"],
+        "code" => [1, 2, 3, 4]
+    ]?;
+    let mut names = PlHashMap::new();
+    names.insert("NL", "Netherlands");
+    names.insert("DE", "Germany");
+    let mapping = mapping_from_pairs(names);
+
+    let q = df.clone().lazy().with_columns([
+        col("country")
+            .map_dict(mapping.clone(), MapDictDefault::Null)
+            .alias("name"),
+        col("country")
+            .map_dict(mapping.clone(), MapDictDefault::KeepOriginal)
+            .alias("name_or_code"),
+    ]);
+    assert_eq!(q.schema()?.get("name"), Some(&DataType::Utf8));
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("name")?.utf8()?),
+        &[
+            Some("Netherlands"),
+            Some("Germany"),
+            None,
+            Some("Netherlands")
+        ]
+    );
+    assert_eq!(out.column("name_or_code")?.utf8()?.get(2), Some("BE"));
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("country").map_dict(mapping, MapDictDefault::Error)])
+        .collect();
+    assert!(out.is_err());
+
+    // label encoding with a mapping frame
+    let labels = df![
+        "country" => ["BE", "DE", "NL"],
+        "label" => [0u32, 1, 2]
+    ]?;
+    let out = df
+        .lazy()
+        .select([col("country").map_dict(labels, MapDictDefault::Error)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("country")?.u32()?),
+        &[Some(2), Some(1), Some(0), Some(2)]
+    );
+    Ok(())
+}
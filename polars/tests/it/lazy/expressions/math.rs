@@ -0,0 +1,134 @@
+use super::*;
+
+#[test]
+fn test_clip() -> Result<()> {
+    let df = df![
+        "i" => [Some(-5i32), None, Some(3), Some(10)],
+        "u" => [1u32, 2, 3, 4],
+        "f" => [-1.5, 0.5, f64::NAN, 2.5]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("i").clip(-1.0, 4.0),
+            col("i").clip_min(0.0).alias("i_min"),
+            col("u").clip_max(2.0),
+            // the boundary saturates at the bounds of the integer type
+            col("u").clip_min(-10.0).alias("u_min"),
+            col("f").clip(0.0, 1.0),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("i")?.i32()?),
+        &[Some(-1), None, Some(3), Some(4)]
+    );
+    assert_eq!(
+        Vec::from(out.column("i_min")?.i32()?),
+        &[Some(0), None, Some(3), Some(10)]
+    );
+    assert_eq!(
+        Vec::from(out.column("u")?.u32()?),
+        &[Some(1), Some(2), Some(2), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("u_min")?.u32()?),
+        &[Some(1), Some(2), Some(3), Some(4)]
+    );
+    let f = out.column("f")?.f64()?;
+    assert_eq!(f.get(0), Some(0.0));
+    assert!(f.get(2).unwrap().is_nan());
+    assert_eq!(f.get(3), Some(1.0));
+
+    assert!(df.column("i")?.clip(2.0, 1.0).is_err());
+    assert!(df.column("i")?.clip_max(f64::NAN).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_log1p_expm1() -> Result<()> {
+    let df = df![
+        "a" => [Some(0i32), None, Some(1)],
+        "b" => [1e-10f32, 0.0, 1.0]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").log1p(),
+            col("a").expm1().alias("a_expm1"),
+            col("b").log1p().alias("b_log1p"),
+            col("b").expm1().log1p().alias("b_roundtrip"),
+        ])
+        .collect()?;
+
+    let a = out.column("a")?.f64()?;
+    assert_eq!(a.get(0), Some(0.0));
+    assert_eq!(a.get(1), None);
+    assert!((a.get(2).unwrap() - 2f64.ln()).abs() < 1e-12);
+    let a_expm1 = out.column("a_expm1")?.f64()?;
+    assert!((a_expm1.get(2).unwrap() - (1f64.exp() - 1.0)).abs() < 1e-12);
+    // float32 stays float32 and keeps its precision close to zero
+    let b = out.column("b_log1p")?.f32()?;
+    assert_eq!(b.get(0), Some(1e-10));
+    let b = out.column("b_roundtrip")?.f32()?;
+    assert!((b.get(2).unwrap() - 1.0).abs() < 1e-6);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "special_functions")]
+fn test_special_functions() -> Result<()> {
+    let df = df![
+        "a" => [Some(0i32), None, Some(1), Some(5)],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([col("a").erf(), col("a").lgamma().alias("lgamma")])
+        .collect()?;
+
+    let erf = out.column("a")?.f64()?;
+    assert_eq!(erf.get(0), Some(0.0));
+    assert_eq!(erf.get(1), None);
+    assert!((erf.get(2).unwrap() - 0.8427007929497149).abs() < 1e-12);
+    let lgamma = out.column("lgamma")?.f64()?;
+    assert_eq!(lgamma.get(2), Some(0.0));
+    // gamma(5) = 4!
+    assert!((lgamma.get(3).unwrap() - 24f64.ln()).abs() < 1e-12);
+    Ok(())
+}
+
+#[test]
+fn test_round_modes() -> Result<()> {
+    let df = df![
+        "a" => [0.125f64, -0.125, 1234.5678],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").round_with_mode(2, RoundMode::HalfEven),
+            col("a")
+                .round_with_mode(1, RoundMode::TowardZero)
+                .alias("toward_zero"),
+            col("a").round_sig_figs(3).alias("sig_figs"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("a")?.f64()?),
+        &[Some(0.12), Some(-0.12), Some(1234.57)]
+    );
+    assert_eq!(
+        Vec::from(out.column("toward_zero")?.f64()?),
+        &[Some(0.1), Some(-0.1), Some(1234.5)]
+    );
+    assert_eq!(
+        Vec::from(out.column("sig_figs")?.f64()?),
+        &[Some(0.125), Some(-0.125), Some(1230.0)]
+    );
+    Ok(())
+}
@@ -3,7 +3,17 @@ mod arity;
 mod expand;
 #[cfg(feature = "is_in")]
 mod is_in;
+#[cfg(feature = "map_dict")]
+mod map_dict;
+#[cfg(all(feature = "round_series", feature = "log"))]
+mod math;
+#[cfg(feature = "replace")]
+mod replace;
 mod slice;
+#[cfg(feature = "string_similarity")]
+mod string_similarity;
+#[cfg(feature = "to_dummies")]
+mod to_dummies;
 mod window;
 
 use super::*;
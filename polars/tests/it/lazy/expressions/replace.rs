@@ -0,0 +1,49 @@
+use super::*;
+
+#[test]
+fn test_replace_expr() -> Result<()> {
+    let df = df![
+        "grade" => ["A", "B", "C", "A"]
+    ]?;
+    let old = Series::new("", ["A", "B", "C"]);
+
+    let q = df.clone().lazy().select([
+        col("grade")
+            .replace(lit(Series::new("", ["A"])), lit(Series::new("", ["A+"])))
+            .alias("replaced"),
+        col("grade")
+            .replace_strict(lit(old.clone()), lit(Series::new("", [4i32, 3, 2])), None)
+            .alias("points"),
+        col("grade")
+            .replace_strict(
+                lit(old),
+                lit(Series::new("", [4i32, 3, 2])),
+                Some(DataType::Float64),
+            )
+            .alias("points_f64"),
+    ]);
+    let schema = q.schema()?;
+    assert_eq!(schema.get("points"), Some(&DataType::Int32));
+    assert_eq!(schema.get("points_f64"), Some(&DataType::Float64));
+    let out = q.collect()?;
+    assert_eq!(
+        Vec::from(out.column("replaced")?.utf8()?),
+        &[Some("A+"), Some("B"), Some("C"), Some("A+")]
+    );
+    assert_eq!(
+        Vec::from(out.column("points")?.i32()?),
+        &[Some(4), Some(3), Some(2), Some(4)]
+    );
+    assert_eq!(out.column("points_f64")?.dtype(), &DataType::Float64);
+
+    let out = df
+        .lazy()
+        .select([col("grade").replace_strict(
+            lit(Series::new("", ["A", "B"])),
+            lit(Series::new("", [4i32, 3])),
+            None,
+        )])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
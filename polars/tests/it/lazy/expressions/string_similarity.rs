@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn test_string_similarity() -> Result<()> {
+    let df = df![
+        "a" => [Some("kitten"), Some("martha"), None],
+        "b" => ["sitting", "marhta", "x"]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").str().levenshtein_distance(col("b")),
+            col("a")
+                .str()
+                .levenshtein_ratio(lit("kitten"))
+                .alias("ratio"),
+            col("a").str().jaro_winkler(col("b")).alias("jaro_winkler"),
+            col("a").str().jaccard(col("b"), 2).alias("jaccard"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("a")?.u32()?),
+        &[Some(3), Some(2), None]
+    );
+    let ratio = out.column("ratio")?.f64()?;
+    assert_eq!(ratio.get(0), Some(1.0));
+    assert!((ratio.get(1).unwrap() - 1.0 / 6.0).abs() < 1e-6);
+    assert_eq!(ratio.get(2), None);
+    let jaro_winkler = out.column("jaro_winkler")?.f64()?;
+    assert!((jaro_winkler.get(1).unwrap() - 0.961111).abs() < 1e-6);
+    // {ki, it, tt, te, en} and {si, it, tt, ti, in, ng}
+    assert_eq!(out.column("jaccard")?.f64()?.get(0), Some(2.0 / 9.0));
+    Ok(())
+}
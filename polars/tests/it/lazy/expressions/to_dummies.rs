@@ -0,0 +1,44 @@
+use super::*;
+
+#[test]
+fn test_to_dummies_expr() -> Result<()> {
+    let df = df![
+        "id" => [1, 2, 3],
+        "type" => ["A", "B", "A"],
+        "code" => ["X1", "X2", "X3"]
+    ]?;
+
+    let options = DummiesOptions::default()
+        .with_prefix("t")
+        .with_drop_first(true);
+    let q = df
+        .clone()
+        .lazy()
+        .select([col("id"), col("type").to_dummies(&["A", "B", "C"], options)])
+        .unnest(["type"]);
+    let schema = q.schema()?;
+    assert_eq!(
+        schema.iter_names().collect::<Vec<_>>(),
+        &["id", "t_B", "t_C"]
+    );
+    assert_eq!(schema.get("t_C"), Some(&DataType::UInt8));
+    let out = q.select([col("t_B"), col("t_C")]).collect()?;
+    assert_eq!(
+        Vec::from(out.column("t_B")?.u8()?),
+        &[Some(0), Some(1), Some(0)]
+    );
+    // a category that is not in the data
+    assert_eq!(out.column("t_C")?.sum::<u8>(), Some(0));
+
+    // eager, on a subset of the columns
+    let subset = ["type".to_string()];
+    let out = df.to_dummies_with_options(Some(&subset), &DummiesOptions::default())?;
+    assert_eq!(out.get_column_names(), &["id", "type_A", "type_B", "code"]);
+    let out = df.to_dummies_with_options(None, &DummiesOptions::default().with_separator("="))?;
+    assert_eq!(out.width(), 8);
+    assert!(out.column("code=X3").is_ok());
+    assert!(df
+        .to_dummies_with_options(None, &DummiesOptions::default().with_prefix("p"))
+        .is_err());
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::lazy::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[test]
 fn test_lazy_window_functions() {
@@ -401,3 +402,169 @@ fn test_window_map_empty_df_3542() -> Result<()> {
     assert_eq!(out.height(), 0);
     Ok(())
 }
+
+#[test]
+fn test_window_computed_keys() -> Result<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4, 5, 6],
+        "b" => [1, 1, 1, 2, 2, 2]
+    ]?;
+    // the computed keys have the same names as the columns they are computed from
+    let out = df
+        .lazy()
+        .select([
+            col("a").sum().over([col("a") % lit(2)]).alias("parity"),
+            col("a").sum().over([col("a")]).alias("a"),
+            col("a")
+                .max()
+                .over([col("b"), (col("a") % lit(2)).alias("odd")])
+                .alias("multiple"),
+            col("a").list().over([col("a").gt(lit(2))]).alias("list"),
+            col("a").first().over([col("a") / lit(3)]).alias("div"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("parity")?.i32()?),
+        &[Some(9), Some(12), Some(9), Some(12), Some(9), Some(12)]
+    );
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)]
+    );
+    assert_eq!(
+        Vec::from(out.column("multiple")?.i32()?),
+        &[Some(3), Some(2), Some(3), Some(6), Some(5), Some(6)]
+    );
+    assert_eq!(
+        Vec::from(out.column("div")?.i32()?),
+        &[Some(1), Some(1), Some(3), Some(3), Some(3), Some(6)]
+    );
+    assert_eq!(out.column("list")?.list()?.get(3).unwrap().len(), 4);
+
+    // the keys are evaluated once for the windows with the same partition expressions
+    static EVALUATED: AtomicUsize = AtomicUsize::new(0);
+    let key = col("b").map(
+        |s| {
+            EVALUATED.fetch_add(1, Ordering::Relaxed);
+            Ok(s)
+        },
+        GetOutput::same_type(),
+    );
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => [1, 1, 2]
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("a").sum().over([key.clone()]).alias("sum"),
+            col("a").max().over([key]).alias("max"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("sum")?.i32()?),
+        &[Some(3), Some(3), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("max")?.i32()?),
+        &[Some(2), Some(2), Some(3)]
+    );
+    assert_eq!(EVALUATED.load(Ordering::Relaxed), 1);
+
+    // different functions with the same debug representation do not share their keys
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .sum()
+                .over([col("b").map(Ok, GetOutput::same_type())])
+                .alias("b"),
+            col("a")
+                .sum()
+                .over([col("b").map(
+                    |s| Ok(s.is_null().into_series()),
+                    GetOutput::from_type(DataType::Boolean),
+                )])
+                .alias("null_b"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(3), Some(3), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("null_b")?.i32()?),
+        &[Some(6), Some(6), Some(6)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_window_keys_differ_in_function_args() -> Result<()> {
+    // the debug representation of both keys is equal, only the patterns differ
+    let out = df![
+        "a" => [1, 2, 3],
+        "s" => ["ab", "ba", "cc"]
+    ]?
+    .lazy()
+    .select([
+        col("a")
+            .sum()
+            .over([col("s").str().starts_with("a")])
+            .alias("a_prefix"),
+        col("a")
+            .sum()
+            .over([col("s").str().starts_with("b")])
+            .alias("b_prefix"),
+    ])
+    .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a_prefix")?.i32()?),
+        &[Some(1), Some(5), Some(5)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b_prefix")?.i32()?),
+        &[Some(4), Some(2), Some(4)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "diff", feature = "pct_change", feature = "dtype-datetime"))]
+fn test_diff_pct_change_window() -> Result<()> {
+    use polars_core::series::ops::NullBehavior;
+
+    let out = df![
+        "g" => ["a", "b", "a", "b", "a"],
+        "x" => [1u32, 10, 3, 5, 2],
+        "t" => [0i64, 10, 1000, 40, 3000]
+    ]?
+    .lazy()
+    .with_column(col("t").cast(DataType::Datetime(TimeUnit::Milliseconds, None)))
+    .select([
+        col("x").diff(1, NullBehavior::Ignore).over([col("g")]),
+        col("x").pct_change(1).over([col("g")]).alias("pct"),
+        col("t").diff(2, NullBehavior::Ignore).over([col("g")]),
+    ])
+    .collect()?;
+
+    let x = out.column("x")?;
+    assert_eq!(x.dtype(), &DataType::Int64);
+    assert_eq!(
+        Vec::from(x.i64()?),
+        &[None, None, Some(2), Some(-5), Some(-1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("pct")?.f64()?),
+        &[None, None, Some(2.0), Some(-0.5), Some(-1.0 / 3.0)]
+    );
+    let t = out.column("t")?;
+    assert_eq!(t.dtype(), &DataType::Duration(TimeUnit::Milliseconds));
+    assert_eq!(
+        Vec::from(t.cast(&DataType::Int64)?.i64()?),
+        &[None, None, None, None, Some(3000)]
+    );
+    Ok(())
+}
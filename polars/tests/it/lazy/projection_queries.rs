@@ -87,8 +87,11 @@ fn test_err_no_found() {
     ]
     .unwrap();
 
-    assert!(matches!(
-        df.lazy().filter(col("nope").gt(lit(2))).collect(),
-        Err(PolarsError::NotFound(_))
-    ));
+    let err = df
+        .lazy()
+        .filter(col("nope").gt(lit(2)))
+        .collect()
+        .unwrap_err();
+    assert!(matches!(err.root(), PolarsError::NotFound(_)));
+    assert_eq!(err.code(), ErrorCode::NotFound);
 }
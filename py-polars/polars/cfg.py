@@ -119,3 +119,39 @@ class Config:
         """
         os.environ["POLARS_FMT_STR_LEN"] = str(n)
         return cls
+
+    @classmethod
+    def set_float_precision(cls, precision: int | None = None) -> type[Config]:
+        """
+        Set the number of decimals used to print floating point values.
+
+        Parameters
+        ----------
+        precision
+            number of decimals to print, ``None`` resets to the default
+
+        """
+        if precision is None:
+            os.environ.pop("POLARS_FMT_FLOAT_PRECISION", None)
+        else:
+            os.environ["POLARS_FMT_FLOAT_PRECISION"] = str(precision)
+        return cls
+
+    @classmethod
+    def set_thousands_separator(cls, separator: str | None = None) -> type[Config]:
+        """
+        Set the character used to group the digits of numeric values per thousand.
+
+        Parameters
+        ----------
+        separator
+            a single character, ``None`` turns the grouping off
+
+        """
+        if separator is None:
+            os.environ.pop("POLARS_FMT_THOUSANDS_SEPARATOR", None)
+        else:
+            if len(separator) != 1:
+                raise ValueError("separator must be a single character")
+            os.environ["POLARS_FMT_THOUSANDS_SEPARATOR"] = separator
+        return cls
@@ -978,6 +978,7 @@ impl PyDataFrame {
                 SortOptions {
                     descending: reverse,
                     nulls_last,
+                    ..Default::default()
                 },
             )
             .map_err(PyPolarsErr::from)?;
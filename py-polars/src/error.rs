@@ -25,17 +25,39 @@ impl std::convert::From<PyPolarsErr> for PyErr {
 
         use PyPolarsErr::*;
         match &err {
-            Polars(err) => match err {
-                PolarsError::NotFound(name) => NotFoundError::new_err(name.clone()),
-                PolarsError::ComputeError(err) => ComputeError::new_err(err.to_string()),
-                PolarsError::NoData(err) => NoDataError::new_err(err.to_string()),
-                PolarsError::ShapeMisMatch(err) => ShapeError::new_err(err.to_string()),
-                PolarsError::SchemaMisMatch(err) => SchemaError::new_err(err.to_string()),
-                PolarsError::Io(err) => PyIOError::new_err(err.to_string()),
-                PolarsError::InvalidOperation(err) => PyValueError::new_err(err.to_string()),
-                PolarsError::ArrowError(err) => ArrowErrorException::new_err(format!("{:?}", err)),
-                PolarsError::Duplicate(err) => DuplicateError::new_err(err.to_string()),
-            },
+            Polars(err) => {
+                // the column, dtypes and plan node in which the error happened
+                let context = err.context().map(|ctx| ctx.to_string()).unwrap_or_default();
+                match err.root() {
+                    PolarsError::NotFound(name) => {
+                        NotFoundError::new_err(format!("{}{}", name, context))
+                    }
+                    PolarsError::ComputeError(err) => {
+                        ComputeError::new_err(format!("{}{}", err, context))
+                    }
+                    PolarsError::NoData(err) => NoDataError::new_err(format!("{}{}", err, context)),
+                    PolarsError::ShapeMisMatch(err) => {
+                        ShapeError::new_err(format!("{}{}", err, context))
+                    }
+                    PolarsError::SchemaMisMatch(err) => {
+                        SchemaError::new_err(format!("{}{}", err, context))
+                    }
+                    PolarsError::Io(err) => PyIOError::new_err(format!("{}{}", err, context)),
+                    PolarsError::InvalidOperation(err) => {
+                        PyValueError::new_err(format!("{}{}", err, context))
+                    }
+                    PolarsError::ArrowError(err) => {
+                        ArrowErrorException::new_err(format!("{:?}{}", err, context))
+                    }
+                    PolarsError::Duplicate(err) => {
+                        DuplicateError::new_err(format!("{}{}", err, context))
+                    }
+                    PolarsError::Cancelled(err) => {
+                        ComputeError::new_err(format!("{}{}", err, context))
+                    }
+                    PolarsError::Context { .. } => unreachable!(),
+                }
+            }
             Arrow(err) => ArrowErrorException::new_err(format!("{:?}", err)),
             _ => default(),
         }
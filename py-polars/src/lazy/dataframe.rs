@@ -333,6 +333,7 @@ impl PyLazyFrame {
             SortOptions {
                 descending: reverse,
                 nulls_last,
+                ..Default::default()
             },
         )
         .into()
@@ -346,7 +347,8 @@ impl PyLazyFrame {
     ) -> PyLazyFrame {
         let ldf = self.ldf.clone();
         let exprs = py_exprs_to_exprs(by_column);
-        ldf.sort_by_exprs(exprs, reverse, nulls_last).into()
+        ldf.sort_by_exprs(exprs, reverse, nulls_last, Default::default())
+            .into()
     }
     pub fn cache(&self) -> PyLazyFrame {
         let ldf = self.ldf.clone();
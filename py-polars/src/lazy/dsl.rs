@@ -222,6 +222,7 @@ impl PyExpr {
             .sort_with(SortOptions {
                 descending,
                 nulls_last,
+                ..Default::default()
             })
             .into()
     }
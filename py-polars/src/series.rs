@@ -550,6 +550,7 @@ impl PySeries {
             .argsort(SortOptions {
                 descending: reverse,
                 nulls_last,
+                ..Default::default()
             })
             .into_series()
             .into()